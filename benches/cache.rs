@@ -0,0 +1,39 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+
+/// # Summary
+/// Benchmarks a workload where 90 % of the numbers repeat, like a UI redrawing the same few values every frame, formatted directly with `Formatter::format` and through a `CachedFormatter`, whose repeats are a hash lookup.
+///
+/// # Arguments
+/// - `c`: the benchmark manager
+fn cache(c: &mut Criterion)
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_unit("B"); // 4 significant digits, decimal scaling
+    let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(f.clone(), 64); // same, remembers the repeated values
+    let values: Vec<f64> = (0..1000)
+        .map(|i| if i % 10 == 0 { 1234567.891 + i as f64 } else { [42069.0, 0.789, 1e9, 512.0, 99.5][i % 5] })
+        .collect(); // 90 % from 5 repeated values, 10 % unique
+
+
+    c.bench_function("90 % repeats, uncached", |b| {
+        b.iter(|| {
+            for x in &values
+            {
+                black_box(f.format(black_box(x)));
+            }
+        })
+    });
+    c.bench_function("90 % repeats, cached", |b| {
+        b.iter(|| {
+            for x in &values
+            {
+                black_box(cached.format(black_box(x)));
+            }
+        })
+    });
+}
+
+
+criterion_group!(benches, cache);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+
+/// # Summary
+/// Benchmarks `Formatter::format` for the common case of 4 significant digits with unit prefix, also compiled and in a tight loop writing into a reused String, for 14 significant digits, the most the feature `fast-format` generates from the shortest representation, for whole numbers without scaling and with an exact unit prefix, whose digits come from integer conversion, for 30 significant digits without scaling, where the integer part is long and full of group separators, and with options overridden per call, which costs no more allocations than the formatted number even with separators set at runtime.
+///
+/// # Arguments
+/// - `c`: the benchmark manager
+fn format(c: &mut Criterion)
+{
+    let common: scaler::Formatter = scaler::Formatter::new(); // 4 significant digits, decimal scaling
+    let compiled: scaler::CompiledFormatter = common.compile(); // same, precomputed
+    let mut s: String = String::with_capacity(64); // reused output of the tight loop
+    let precise: scaler::Formatter = scaler::Formatter::new()
+        .set_rounding(scaler::Rounding::SignificantDigits(14)); // 14 significant digits, decimal scaling, longest the shortest representation is used for
+    let long: scaler::Formatter = scaler::Formatter::new()
+        .set_scaling(scaler::Scaling::None)
+        .set_rounding(scaler::Rounding::SignificantDigits(30)); // 30 significant digits, no scaling
+    let unscaled: scaler::Formatter = scaler::Formatter::new()
+        .set_scaling(scaler::Scaling::None); // counters
+    let overrides: scaler::Overrides = scaler::Overrides {
+        rounding: Some(scaler::Rounding::Magnitude(-2)),
+        scaling: Some(scaler::Scaling::None),
+        ..scaler::Overrides::new()
+    }; // per call
+    let owned: scaler::Formatter = scaler::Formatter::new()
+        .set_separators(",", "."); // separators set at runtime, shared by clones
+
+
+    c.bench_function("4 significant digits", |b| b.iter(|| common.format(black_box(1234567.891))));
+    c.bench_function("4 significant digits, compiled", |b| b.iter(|| compiled.format(black_box(1234567.891))));
+    c.bench_function("4 significant digits, tight loop", |b| {
+        b.iter(|| {
+            s.clear();
+            common.format_into(black_box(1234567.891), &mut s)
+        })
+    });
+    c.bench_function("4 significant digits, tight loop, compiled", |b| {
+        b.iter(|| {
+            s.clear();
+            compiled.format_into(black_box(1234567.891), &mut s)
+        })
+    });
+    c.bench_function("14 significant digits", |b| b.iter(|| precise.format(black_box(1234567.891))));
+    c.bench_function("whole number, Scaling::None", |b| b.iter(|| unscaled.format(black_box(42069))));
+    c.bench_function("whole number, exact unit prefix", |b| b.iter(|| common.format(black_box(5000))));
+    c.bench_function("30 significant digits, Scaling::None", |b| b.iter(|| long.format(black_box(1.234_567_891_234_567_9e29))));
+    c.bench_function("per-call override", |b| b.iter(|| owned.format_overridden(black_box(1234567.891), &overrides)));
+    c.bench_function("per-call override, rounding only", |b| b.iter(|| owned.format_with_rounding(black_box(1234567.891), scaler::Rounding::Magnitude(-2))));
+}
+
+
+criterion_group!(benches, format);
+criterion_main!(benches);
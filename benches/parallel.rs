@@ -0,0 +1,37 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::io::Write;
+
+
+/// # Summary
+/// Benchmarks formatting a column of 1 million numbers serially against `Formatter::par_format_slice` and `Formatter::par_format_into_writer` on thread pools of 1, 2, 4, and 8 threads. Scaling shows on machines with as many cores.
+///
+/// # Arguments
+/// - `c`: the benchmark manager
+fn parallel(c: &mut Criterion)
+{
+    let f: scaler::Formatter = scaler::Formatter::new();
+    let values: Vec<f64> = (0..1_000_000).map(|i| (i as f64 - 500_000.0) * 1.37e3_f64.powi(i % 7)).collect(); // column to export
+    let mut group = c.benchmark_group("1 million numbers");
+
+
+    group.sample_size(10);
+    group.bench_function("format_slice", |b| b.iter(|| f.format_slice(black_box(&values))));
+    for threads in [1, 2, 4, 8]
+    {
+        let pool: rayon::ThreadPool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().expect("Building the thread pool failed.");
+        group.bench_function(format!("par_format_slice, {threads} threads"), |b| b.iter(|| pool.install(|| f.par_format_slice(black_box(&values)))));
+        group.bench_function(format!("par_format_into_writer, {threads} threads"), |b| {
+            b.iter(|| {
+                let mut out: std::io::Sink = std::io::sink();
+                pool.install(|| f.par_format_into_writer(black_box(&values), "\n", &mut out)).expect("Writing into a sink failed even though it is infallible.");
+                out.flush()
+            })
+        });
+    }
+    group.finish();
+}
+
+
+criterion_group!(benches, parallel);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+
+/// # Summary
+/// Parses a million formatted numbers with `Formatter::parse`, which creates a parser per call, with a `Parser` created once and reused, and leniently with both, where unit prefixes are also matched ignoring case.
+///
+/// # Arguments
+/// - `c`: the benchmark manager
+fn parse(c: &mut Criterion)
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_unit("B"); // 4 significant digits, decimal scaling
+    let parser: scaler::Parser = f.parser(); // same settings, created once
+    let strings: Vec<String> = (0..1_000_000).map(|i| f.format((i as f64 - 500_000.0) * 1.37e3_f64.powi(i % 7))).collect(); // log to read back
+    let mut group = c.benchmark_group("1 million strings");
+
+
+    group.sample_size(10);
+    group.bench_function("Formatter::parse", |b| b.iter(|| black_box(&strings).iter().map(|s| f.parse(s)).filter(Result::is_ok).count()));
+    group.bench_function("Parser::parse", |b| b.iter(|| black_box(&strings).iter().map(|s| parser.parse(s)).filter(Result::is_ok).count()));
+    group.bench_function("Formatter::parse_lenient", |b| b.iter(|| black_box(&strings).iter().map(|s| f.parse_lenient(s)).filter(Result::is_ok).count()));
+    group.bench_function("Parser::parse_lenient", |b| b.iter(|| black_box(&strings).iter().map(|s| parser.parse_lenient(s)).filter(Result::is_ok).count()));
+    group.finish();
+}
+
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use scaler::round::Round;
+
+
+/// # Summary
+/// Rounds a million numbers in place with the naive `iter_mut().for_each` loop over `Round::round_mag` and `Round::round_sig`, and with `round_slice_mag` and `round_slice_sig`, which calculate the power of 10 once and skip infinity and NaN.
+///
+/// # Arguments
+/// - `c`: the benchmark manager
+fn round(c: &mut Criterion)
+{
+    let values: Vec<f64> = (0..1_000_000).map(|i| (i as f64 - 500_000.0) * 1.37e-3_f64.powi(i % 7)).collect(); // numbers of many magnitudes
+    let mut scratch: Vec<f64> = values.clone(); // rounded in place, reset before every iteration
+    let mut group = c.benchmark_group("1 million numbers");
+
+
+    group.sample_size(10);
+    group.bench_function("naive loop, round_mag", |b| {
+        b.iter(|| {
+            scratch.copy_from_slice(&values);
+            black_box(&mut scratch).iter_mut().for_each(|x| *x = x.round_mag(-2));
+        })
+    });
+    group.bench_function("round_slice_mag", |b| {
+        b.iter(|| {
+            scratch.copy_from_slice(&values);
+            scaler::round::round_slice_mag(black_box(&mut scratch), -2);
+        })
+    });
+    group.bench_function("naive loop, round_sig", |b| {
+        b.iter(|| {
+            scratch.copy_from_slice(&values);
+            black_box(&mut scratch).iter_mut().for_each(|x| *x = x.round_sig(4));
+        })
+    });
+    group.bench_function("round_slice_sig", |b| {
+        b.iter(|| {
+            scratch.copy_from_slice(&values);
+            scaler::round::round_slice_sig(black_box(&mut scratch), 4);
+        })
+    });
+    group.finish();
+}
+
+
+criterion_group!(benches, round);
+criterion_main!(benches);
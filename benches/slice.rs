@@ -0,0 +1,91 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+/// # Summary
+/// Global allocator that counts allocations and reallocations, then forwards to the system allocator.
+struct CountingAllocator;
+
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0); // number of allocations and reallocations so far
+
+
+unsafe impl GlobalAlloc for CountingAllocator
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8
+    {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        return unsafe { System.alloc(layout) };
+    }
+
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout)
+    {
+        unsafe { System.dealloc(ptr, layout) };
+    }
+
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8
+    {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        return unsafe { System.realloc(ptr, layout, new_size) };
+    }
+}
+
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// # Summary
+/// Counts the heap allocations and reallocations of a closure.
+///
+/// # Arguments
+/// - `f`: the closure to count the allocations of
+///
+/// # Returns
+/// - number of allocations and reallocations
+fn allocations(f: impl FnOnce()) -> usize
+{
+    let before: usize = ALLOCATIONS.load(Ordering::Relaxed); // number of allocations before
+
+
+    f();
+    return ALLOCATIONS.load(Ordering::Relaxed) - before;
+}
+
+
+/// # Summary
+/// Formats a million values with a naive loop over `Formatter::format`, with `Formatter::format_slice`, with `Formatter::format_slice_into` into a reused Vec, and with `Formatter::format_joined`. Prints the number of heap allocations of each, then benchmarks them.
+///
+/// # Arguments
+/// - `c`: the benchmark manager
+fn slice(c: &mut Criterion)
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_unit("B"); // 4 significant digits, decimal scaling
+    let mut reused: Vec<String> = f.format_slice(&[1.0; 1_000_000]); // outputs of a previous run
+    let values: Vec<f64> = (0..1_000_000).map(|i| i as f64 * 1234.5678).collect(); // from 0 B to 1,235 TB
+
+
+    for (name, allocations) in [
+        ("naive loop", allocations(|| drop(black_box(values.iter().map(|x| f.format(x)).collect::<Vec<String>>())))),
+        ("format_slice", allocations(|| drop(black_box(f.format_slice(&values))))),
+        ("format_slice_into reused Vec", allocations(|| f.format_slice_into(&values, &mut reused))),
+        ("format_joined", allocations(|| drop(black_box(f.format_joined(&values, "\n"))))),
+    ]
+    {
+        println!("{name}: {allocations} heap allocations for {} values", values.len());
+    }
+
+    c.bench_function("1 000 000 values, naive loop", |b| b.iter(|| values.iter().map(|x| f.format(x)).collect::<Vec<String>>()));
+    c.bench_function("1 000 000 values, format_slice", |b| b.iter(|| f.format_slice(black_box(&values))));
+    c.bench_function("1 000 000 values, format_slice_into reused Vec", |b| b.iter(|| f.format_slice_into(black_box(&values), &mut reused)));
+    c.bench_function("1 000 000 values, format_joined", |b| b.iter(|| f.format_joined(black_box(&values), "\n")));
+}
+
+
+criterion_group!(name = benches; config = Criterion::default().sample_size(10); targets = slice);
+criterion_main!(benches);
@@ -0,0 +1,92 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+/// # Summary
+/// Global allocator that counts allocations and reallocations, then forwards to the system allocator.
+struct CountingAllocator;
+
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0); // number of allocations and reallocations so far
+
+
+unsafe impl GlobalAlloc for CountingAllocator
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8
+    {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        return unsafe { System.alloc(layout) };
+    }
+
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout)
+    {
+        unsafe { System.dealloc(ptr, layout) };
+    }
+
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8
+    {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        return unsafe { System.realloc(ptr, layout, new_size) };
+    }
+}
+
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// # Summary
+/// Formats all values into a reused Vec of outputs, cleared before, like a table redrawn with new values.
+///
+/// # Arguments
+/// - `outputs`: the reused outputs, keeps its capacity
+/// - `values`: the numbers to format
+/// - `format`: formats a single number
+fn format_all<S>(outputs: &mut Vec<S>, values: &[f64], format: impl Fn(f64) -> S)
+{
+    outputs.clear();
+    outputs.extend(values.iter().map(|x| format(black_box(*x))));
+}
+
+
+/// # Summary
+/// Formats a million values into a reused Vec of outputs, once as `String` and once as `SmallString`. Prints the number of heap allocations of each, then benchmarks them.
+///
+/// # Arguments
+/// - `c`: the benchmark manager
+fn small(c: &mut Criterion)
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_unit("B"); // 4 significant digits, decimal scaling
+    let mut smalls: Vec<scaler::SmallString<[u8; 32]>> = Vec::with_capacity(1_000_000); // reused outputs
+    let mut strings: Vec<String> = Vec::with_capacity(1_000_000); // reused outputs
+    let values: Vec<f64> = (0..1_000_000).map(|i| i as f64 * 1234.5678).collect(); // from 0 B to 1,235 TB
+
+
+    for (name, allocations) in [
+        ("format", {
+            let before: usize = ALLOCATIONS.load(Ordering::Relaxed);
+            format_all(&mut strings, &values, |x| f.format(x));
+            ALLOCATIONS.load(Ordering::Relaxed) - before
+        }),
+        ("format_small", {
+            let before: usize = ALLOCATIONS.load(Ordering::Relaxed);
+            format_all(&mut smalls, &values, |x| f.format_small(x));
+            ALLOCATIONS.load(Ordering::Relaxed) - before
+        }),
+    ]
+    {
+        println!("{name}: {allocations} heap allocations for {} values", values.len());
+    }
+
+    c.bench_function("1 000 000 values into reused Vec, format", |b| b.iter(|| format_all(&mut strings, &values, |x| f.format(x))));
+    c.bench_function("1 000 000 values into reused Vec, format_small", |b| b.iter(|| format_all(&mut smalls, &values, |x| f.format_small(x))));
+}
+
+
+criterion_group!(name = benches; config = Criterion::default().sample_size(10); targets = small);
+criterion_main!(benches);
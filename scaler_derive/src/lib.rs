@@ -0,0 +1,297 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+//! Derive macro `ScaledDisplay` of scaler. Use it through the feature `derive` of scaler as `scaler::ScaledDisplay`, the generated code refers to the crate `scaler`.
+use proc_macro2::TokenStream;
+use quote::quote;
+
+
+/// # Summary
+/// Formatter options set with `#[scaled(...)]` on a struct or a field, as setter calls on `scaler::Formatter`. Options of a field override the same options of its struct.
+#[derive(Clone, Default)]
+struct Options
+{
+    rounding:   Option<TokenStream>, // from `sig`, `mag`, or `decimals`
+    scaling:    Option<TokenStream>,
+    separators: Option<TokenStream>,
+    sign:       Option<TokenStream>,
+    unit:       Option<TokenStream>,
+}
+
+
+impl Options
+{
+    /// # Summary
+    /// Overrides the options of a struct with the ones set on a field.
+    ///
+    /// # Arguments
+    /// - `field`: options of the field
+    ///
+    /// # Returns
+    /// - the combined options
+    fn merged(&self, field: &Options) -> Options
+    {
+        return Options {
+            rounding:   field.rounding.clone().or_else(|| self.rounding.clone()),
+            scaling:    field.scaling.clone().or_else(|| self.scaling.clone()),
+            separators: field.separators.clone().or_else(|| self.separators.clone()),
+            sign:       field.sign.clone().or_else(|| self.sign.clone()),
+            unit:       field.unit.clone().or_else(|| self.unit.clone()),
+        };
+    }
+
+
+    /// # Summary
+    /// Generates the expression constructing the formatter with these options.
+    ///
+    /// # Returns
+    /// - `scaler::Formatter::new()` followed by the setters
+    fn formatter(&self) -> TokenStream
+    {
+        let setters: Vec<&TokenStream> = [&self.rounding, &self.scaling, &self.separators, &self.sign, &self.unit].into_iter().flatten().collect();
+
+        return quote! { ::scaler::Formatter::new() #(#setters)* };
+    }
+}
+
+
+/// # Summary
+/// Parses the `#[scaled(...)]` attributes of a struct or a field. Struct and fields share the formatter options `sig`, `mag`, `decimals`, `scaling`, `sign`, `sep`, and `unit`, named like the ones of `scale!`. Only a struct takes a template with `fmt`, only a field can be left out with `skip`.
+///
+/// # Arguments
+/// - `attrs`: attributes of the struct or field, others than `scaled` are ignored
+/// - `template`: where to store the template, or none if parsing a field
+/// - `skip`: where to store whether to leave out the field, or none if parsing a struct
+///
+/// # Returns
+/// - the options or the first invalid one
+fn parse_options(attrs: &[syn::Attribute], mut template: Option<&mut Option<syn::LitStr>>, mut skip: Option<&mut bool>) -> syn::Result<Options>
+{
+    let mut options: Options = Options::default();
+
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("scaled"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let key: String = meta.path.get_ident().map(|ident| ident.to_string()).unwrap_or_default(); // option name
+
+            match key.as_str()
+            {
+                "decimals" | "mag" | "sig" =>
+                {
+                    let value: syn::Expr = meta.value()?.parse()?;
+
+                    if options.rounding.is_some()
+                    {
+                        return Err(meta.error("Rounding set twice, expected only one of `sig`, `mag`, and `decimals`."));
+                    }
+                    options.rounding = Some(match key.as_str()
+                    {
+                        "decimals" => quote! { .set_rounding(::scaler::Rounding::Magnitude(-(#value))) },
+                        "mag" => quote! { .set_rounding(::scaler::Rounding::Magnitude(#value)) },
+                        _ => quote! { .set_rounding(::scaler::Rounding::SignificantDigits(#value)) },
+                    });
+                }
+                "fmt" if template.is_some() => **template.as_mut().expect("Template checked to be some.") = Some(meta.value()?.parse()?),
+                "scaling" =>
+                {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+
+                    options.scaling = Some(match value.value().as_str()
+                    {
+                        "binary" => quote! { .set_scaling(::scaler::Scaling::Binary(true)) },
+                        "decimal" => quote! { .set_scaling(::scaler::Scaling::Decimal(true)) },
+                        "none" => quote! { .set_scaling(::scaler::Scaling::None) },
+                        "scientific" => quote! { .set_scaling(::scaler::Scaling::Scientific) },
+                        other => return Err(syn::Error::new(value.span(), format!("Unknown scaling \"{other}\", expected \"decimal\", \"binary\", \"none\", or \"scientific\"."))),
+                    });
+                }
+                "sep" =>
+                {
+                    let content: syn::parse::ParseBuffer;
+                    let decimal: syn::LitStr;
+                    let group: syn::LitStr;
+                    let value: &syn::parse::ParseBuffer = meta.value()?;
+
+                    syn::parenthesized!(content in value);
+                    group = content.parse()?;
+                    content.parse::<syn::Token![,]>()?;
+                    decimal = content.parse()?;
+                    options.separators = Some(quote! { .set_separators(#group, #decimal) });
+                }
+                "sign" =>
+                {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+
+                    options.sign = Some(match value.value().as_str()
+                    {
+                        "always" => quote! { .set_sign(::scaler::Sign::Always) },
+                        "except_zero" => quote! { .set_sign(::scaler::Sign::ExceptZero) },
+                        "only_minus" => quote! { .set_sign(::scaler::Sign::OnlyMinus) },
+                        "space_for_positive" => quote! { .set_sign(::scaler::Sign::SpaceForPositive) },
+                        other => return Err(syn::Error::new(value.span(), format!("Unknown sign behaviour \"{other}\", expected \"always\", \"except_zero\", \"only_minus\", or \"space_for_positive\"."))),
+                    });
+                }
+                "skip" if skip.is_some() => **skip.as_mut().expect("Skip checked to be some.") = true,
+                "unit" =>
+                {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+
+                    options.unit = Some(quote! { .set_unit(#value) });
+                }
+                _ if template.is_some() => return Err(meta.error("Unknown option, expected `sig`, `mag`, `decimals`, `scaling`, `sign`, `sep`, `unit`, or `fmt` on a struct.")),
+                _ => return Err(meta.error("Unknown option, expected `sig`, `mag`, `decimals`, `scaling`, `sign`, `sep`, `unit`, or `skip` on a field.")),
+            }
+            return Ok(());
+        })?;
+    }
+
+    return Ok(options);
+}
+
+
+/// # Summary
+/// Finds the fields a template refers to, like `bytes` in "{bytes:>10} read". Doubled braces are literal braces.
+///
+/// # Arguments
+/// - `template`: the template
+///
+/// # Returns
+/// - names of the fields in order of first appearance, or an error if a placeholder names no field
+fn template_fields(template: &syn::LitStr) -> syn::Result<Vec<String>>
+{
+    let s: String = template.value();
+    let mut chars = s.chars().peekable();
+    let mut names: Vec<String> = Vec::new();
+
+
+    while let Some(c) = chars.next()
+    {
+        if c == '{' && chars.peek() == Some(&'{')
+        // literal brace
+        {
+            chars.next();
+            continue;
+        }
+        if c != '{'
+        {
+            continue;
+        }
+        let name: String = chars.by_ref().take_while(|c| *c != '}').collect::<String>().split(':').next().unwrap_or_default().trim().to_owned(); // name before the format spec
+
+        if name.is_empty() || !(name.starts_with(|c: char| c == '_' || c.is_alphabetic()) && name.chars().all(|c| c == '_' || c.is_alphanumeric()))
+        {
+            return Err(syn::Error::new(template.span(), format!("Placeholder \"{{{name}}}\" names no field, expected the name of a field like \"{{bytes}}\".")));
+        }
+        if !names.contains(&name)
+        {
+            names.push(name);
+        }
+    }
+
+    return Ok(names);
+}
+
+
+/// # Summary
+/// Generates the `Display` implementation of a struct with named numeric fields.
+///
+/// # Arguments
+/// - `input`: the struct
+///
+/// # Returns
+/// - the implementation or the first error
+fn expand(input: syn::DeriveInput) -> syn::Result<TokenStream>
+{
+    let body: TokenStream; // statements writing the struct
+    let defaults: Options; // options of the struct
+    let mut fields: Vec<(String, TokenStream)> = Vec::new(); // names and formatted values of the displayed fields
+    let mut template: Option<syn::LitStr> = None;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let name: &syn::Ident = &input.ident;
+
+
+    let named: &syn::FieldsNamed = match &input.data
+    {
+        syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(named), .. }) => named,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "`ScaledDisplay` can only be derived for structs with named fields.")),
+    };
+    defaults = parse_options(&input.attrs, Some(&mut template), None)?;
+    for field in named.named.iter()
+    {
+        let ident: &syn::Ident = field.ident.as_ref().expect("Named fields have identifiers.");
+        let mut skip: bool = false;
+        let options: Options = defaults.merged(&parse_options(&field.attrs, None, Some(&mut skip))?);
+        let formatter: TokenStream = options.formatter();
+
+        if !skip
+        {
+            fields.push((ident.to_string().trim_start_matches("r#").to_owned(), quote! { ::scaler::ToScaled::scaled_with(&self.#ident, &#formatter) }));
+        }
+    }
+
+    body = match &template
+    {
+        Some(template) =>
+        {
+            let mut arguments: Vec<TokenStream> = Vec::new(); // named arguments of the used fields only, unused ones would not compile
+
+            for used in template_fields(template)?
+            {
+                let value: &TokenStream = match fields.iter().find(|(name, _value)| *name == used)
+                {
+                    Some((_name, value)) => value,
+                    None => return Err(syn::Error::new(template.span(), format!("Template refers to \"{used}\", which is no displayed field of `{name}`."))),
+                };
+                let ident: syn::Ident = syn::Ident::new(&used, template.span());
+                arguments.push(quote! { #ident = #value });
+            }
+            quote! { ::std::write!(f, #template, #(#arguments),*) }
+        }
+        None =>
+        {
+            let lines: Vec<TokenStream> = fields
+                .iter()
+                .enumerate()
+                .map(|(i, (name, value))| {
+                    let separator: &str = if i == 0 { "" } else { "\n" }; // lines without trailing newline
+                    return quote! { ::std::write!(f, "{}{}: {}", #separator, #name, #value)?; };
+                })
+                .collect();
+            quote! { #(#lines)* ::std::result::Result::Ok(()) }
+        }
+    };
+
+    return Ok(quote! {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result
+            {
+                #body
+            }
+        }
+    });
+}
+
+
+/// # Summary
+/// Derives `Display` for a struct with named numeric fields, formatting every field with `scaler::ToScaled::scaled_with`, so integers keep all their digits, as a line "name: value", or filling a template. Options for the formatter are set with `#[scaled(...)]` on the struct as defaults and on fields to override them:
+/// - `sig = n`: round to n significant digits
+/// - `mag = n`: round to the digit at 10^n
+/// - `decimals = n`: round to n decimal places
+/// - `scaling = "decimal"`, `"binary"`, `"none"`, or `"scientific"`: decimal and binary with space before the unit prefix
+/// - `sign = "always"`, `"except_zero"`, `"only_minus"`, or `"space_for_positive"`
+/// - `sep = (group, decimal)`: group and decimal separator
+/// - `unit = "B"`: unit after the unit prefix
+/// - `fmt = "..."`, only on the struct: template like for `write!` with the formatted fields as named arguments, like "{read} of {total}"
+/// - `skip`, only on fields: leave the field out
+///
+/// Unknown options, values, and template placeholders fail to compile.
+#[proc_macro_derive(ScaledDisplay, attributes(scaled))]
+pub fn derive_scaled_display(input: proc_macro::TokenStream) -> proc_macro::TokenStream
+{
+    return match syn::parse(input).and_then(expand)
+    {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    };
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+use ::proptest::prelude::*;
+
+
+/// # Summary
+/// Group and decimal separators the generated formatters use, neither equal to each other nor digits nor anything else `Formatter::set_separators` would warn about.
+const SEPARATORS: [(&str, &str); 7] = [(".", ","), (",", "."), ("", ","), (" ", "."), ("'", "."), ("\u{202f}", ","), ("_", "·")];
+
+/// # Summary
+/// Units the generated formatters use, none of them a unit prefix.
+const UNITS: [&str; 4] = ["", "B", "Hz", "byte"];
+
+
+impl Arbitrary for Rounding
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+
+    /// # Summary
+    /// Generates roundings to both sides of the decimal separator: to the digit at 10^(-20) up to 10^20, or to 0 up to 20 significant digits.
+    ///
+    /// # Returns
+    /// - the strategy
+    ///
+    /// # Examples
+    /// ```
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(rounding in any::<scaler::Rounding>())| {
+    ///     prop_assert_eq!(rounding.to_string().parse::<scaler::Rounding>(), Ok(rounding));
+    /// });
+    /// ```
+    fn arbitrary_with(_args: Self::Parameters) -> BoxedStrategy<Self>
+    {
+        return prop_oneof![(-20_i16..=20).prop_map(Rounding::Magnitude), (0_u8..=20).prop_map(Rounding::SignificantDigits)].boxed();
+    }
+}
+
+
+impl Arbitrary for Scaling
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+
+    /// # Summary
+    /// Generates every scaling that has a string form: binary, decimal, and percent with and without space, localized compact suffixes, no scaling, scientific notation, and exponential scaling in bases 2 to 64 with steps 1 to 4 without unit prefix table. Custom strategies and unit prefix tables have no string form and are never generated.
+    ///
+    /// # Returns
+    /// - the strategy
+    ///
+    /// # Examples
+    /// ```
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(scaling in any::<scaler::Scaling>())| {
+    ///     prop_assert_eq!(scaling.to_string().parse::<scaler::Scaling>(), Ok(scaling));
+    /// });
+    /// ```
+    fn arbitrary_with(_args: Self::Parameters) -> BoxedStrategy<Self>
+    {
+        return prop_oneof![
+            any::<bool>().prop_map(Scaling::Binary),
+            Just(Scaling::CompactLocalized),
+            any::<bool>().prop_map(Scaling::Decimal),
+            (2_u32..=64, 1_u32..=4).prop_map(|(base, step)| Scaling::Exponential { base, step, prefixes: None }),
+            Just(Scaling::None),
+            any::<bool>().prop_map(Scaling::Percent),
+            Just(Scaling::Scientific),
+        ]
+        .boxed();
+    }
+}
+
+
+impl Arbitrary for Sign
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+
+    /// # Summary
+    /// Generates every sign behaviour.
+    ///
+    /// # Returns
+    /// - the strategy
+    fn arbitrary_with(_args: Self::Parameters) -> BoxedStrategy<Self>
+    {
+        return prop_oneof![Just(Sign::Always), Just(Sign::ExceptZero), Just(Sign::OnlyMinus), Just(Sign::SpaceForPositive)].boxed();
+    }
+}
+
+
+impl Arbitrary for Formatter
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+
+    /// # Summary
+    /// Generates valid formatters like `Formatter::arbitrary_valid`.
+    ///
+    /// # Returns
+    /// - the strategy
+    fn arbitrary_with(_args: Self::Parameters) -> BoxedStrategy<Self>
+    {
+        return Formatter::arbitrary_valid();
+    }
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Constructs a strategy generating valid formatters for property tests of code that embeds them: arbitrary rounding, fallback rounding, scaling, and sign like their `Arbitrary` implementations, sign before or after the number, separators from a safe set that `Formatter::set_separators` never warns about, with and without trailing zeros, up to 7 minimum integer digits, and a unit that is no unit prefix. All other options keep the defaults of `Formatter::new`.
+    ///
+    /// # Returns
+    /// - the strategy
+    ///
+    /// # Examples
+    /// ```
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(f in scaler::Formatter::arbitrary_valid(), x in any::<f64>())| {
+    ///     prop_assert_eq!(f.format_to_parts(x).to_string(), f.format(x));
+    /// });
+    /// ```
+    pub fn arbitrary_valid() -> BoxedStrategy<Formatter>
+    {
+        return (
+            any::<Rounding>(),
+            prop::option::of(any::<Rounding>()),
+            any::<Scaling>(),
+            any::<Sign>(),
+            any::<bool>(), // sign after the number
+            0..SEPARATORS.len(),
+            any::<bool>(), // trailing zeros
+            0_usize..8,
+            0..UNITS.len(),
+        )
+            .prop_map(|(rounding, fallback_rounding, scaling, sign, trailing_sign, separators, trailing_zeros, min_integer_digits, unit)| {
+                return Formatter::new()
+                    .set_fallback_rounding(fallback_rounding)
+                    .set_min_integer_digits(min_integer_digits)
+                    .set_rounding(rounding)
+                    .set_scaling(scaling)
+                    .set_separators_static(SEPARATORS[separators].0, SEPARATORS[separators].1)
+                    .set_sign(sign)
+                    .set_sign_placement(if trailing_sign { SignPlacement::Trailing } else { SignPlacement::Leading })
+                    .set_trailing_zeros(trailing_zeros)
+                    .set_unit_const(UNITS[unit]);
+            })
+            .boxed();
+    }
+}
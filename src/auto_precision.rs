@@ -0,0 +1,58 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a column of numbers with just enough precision that numbers differing in the data also differ in the output, so [10.0001, 10.0002] does not collapse to "10,00" twice and look like broken data. Starting from the configured rounding, digits are added until neighbours in sorted order format differently, at most as many as set with `Formatter::set_auto_precision_max`. The added digits are significant digits or decimal places, depending on the configured rounding. Exact duplicates are allowed to collide, as are numbers still indistinguishable at the maximum. NaN is formatted as usual, but ignored when choosing the precision. All numbers are formatted with the same precision.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    ///
+    /// # Returns
+    /// - formatted numbers in input order
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_auto_precision(&[0.101, 0.102, 0.104]), ["101,0 m", "102,0 m", "104,0 m"]); // configured precision suffices
+    /// assert_eq!(f.format_auto_precision(&[10.0001, 10.0002]), ["10,0001", "10,0002"]); // clustered, 2 digits added
+    /// assert_eq!(f.format_auto_precision(&[10.0001, 10.0002, 10.0002]), ["10,0001", "10,0002", "10,0002"]); // duplicates collide
+    /// assert_eq!(f.format_auto_precision(&[5.0, 5.0, 5.0]), ["5,000", "5,000", "5,000"]); // constant
+    /// assert_eq!(f.format_auto_precision(&[1.00001, f64::NAN, 1.00002]), ["1,00001", "NaN", "1,00002"]);
+    /// assert_eq!(f.format_auto_precision(&[]), Vec::<String>::new());
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_auto_precision_max(2);
+    /// assert_eq!(f.format_auto_precision(&[1.5, 1.503]), ["1,500", "1,503"]); // decimal places added
+    /// assert_eq!(f.format_auto_precision(&[1.5, 1.50001]), ["1,5000", "1,5000"]); // indistinguishable at the maximum
+    /// ```
+    pub fn format_auto_precision(&self, values: &[f64]) -> Vec<String>
+    {
+        let mut chosen: Formatter = self.clone(); // formatter with the precision that distinguishes all numbers or the maximum
+        let widen = |rounding: &Rounding, extra: u8| -> Rounding { match rounding { Rounding::Magnitude(precision) => Rounding::Magnitude(precision.saturating_sub(extra as i16)), Rounding::SignificantDigits(precision) => Rounding::SignificantDigits(precision.saturating_add(extra)) } }; // rounding with extra digits or decimal places
+        let mut sorted: Vec<f64> = values.iter().copied().filter(|x| !x.is_nan()).collect(); // distinct numbers in order, neighbours are the closest pairs
+
+
+        sorted.sort_by(f64::total_cmp);
+        sorted.dedup_by(|a, b| a == b); // -0 and 0 are duplicates as well
+
+        for extra in 0..=self.auto_precision_max
+        {
+            chosen.rounding = widen(&self.rounding, extra);
+            chosen.fallback_rounding = self.fallback_rounding.as_ref().map(|fallback| widen(fallback, extra)); // scientific notation gains the same digits
+            if sorted.windows(2).all(|pair| chosen.format(pair[0]) != chosen.format(pair[1]))
+            // all distinguishable
+            {
+                break;
+            }
+        }
+
+        return values.iter().map(|x| chosen.format(x)).collect();
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a `bigdecimal::BigDecimal` like `Formatter::format`, but exactly in decimal digit arithmetic without any f64, so numbers far outside the range of f64 like 10^(-500) are displayed with all their digits correct. The magnitude is determined from the digit count and the exponent, then only the leading digits are rounded, ties to even. The exponent is kept as i64, exponents beyond ±2^(53) are displayed as the nearest f64. Binary unit prefixes divide exactly, which takes time quadratic in the magnitude, and `Scaling::None` writes out every integer digit.
+    ///
+    /// # Arguments
+    /// - `d`: the decimal number to format
+    ///
+    /// # Returns
+    /// - formatted decimal number
+    ///
+    /// # Examples
+    /// ```
+    /// use bigdecimal::BigDecimal;
+    /// use std::str::FromStr;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("1234.5678").unwrap()), "1,235 k");
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("-0.00012345").unwrap()), "-123,4 µ"); // tie to even
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("0.1").unwrap()), "100,0 m");
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("0").unwrap()), "0,000");
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("1e-1000").unwrap()), "1,000 * 10^(-1000)");
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("-9.9996e1000").unwrap()), "-1,000 * 10^(1001)");
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("1.5e-1000000000000").unwrap()), "1,500 * 10^(-1000000000000)"); // never written out
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-3));
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("0.1").unwrap()), "0,100");
+    /// assert_eq!(f.format_bigdecimal(&BigDecimal::from_str("1e-1000").unwrap()), "0,000");
+    /// ```
+    #[cfg(feature = "bigdecimal")]
+    pub fn format_bigdecimal(&self, d: &bigdecimal::BigDecimal) -> String
+    {
+        let (int, scale): (num_bigint::BigInt, i64) = d.as_bigint_and_exponent(); // d = int * 10^(-scale)
+
+
+        return self.format_big(int.sign() == num_bigint::Sign::Minus, &int.magnitude().to_string(), scale.saturating_neg());
+    }
+
+
+    /// # Summary
+    /// Formats a `num_bigint::BigInt` like `Formatter::format`, but exactly in decimal digit arithmetic without any f64, so integers far beyond the range of f64 like 10^(1000) are displayed with all their digits correct. The magnitude is determined from the digit count, then only the leading digits are rounded, ties to even. Binary unit prefixes divide exactly, which takes time quadratic in the magnitude, and `Scaling::None` writes out every digit.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    ///
+    /// # Returns
+    /// - formatted integer
+    ///
+    /// # Examples
+    /// ```
+    /// use num_bigint::BigInt;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_bigint(&BigInt::from(10).pow(40)), "1,000 * 10^(40)");
+    /// assert_eq!(f.format_bigint(&BigInt::from(10).pow(40)), f.format(1e40));
+    /// assert_eq!(f.format_bigint(&BigInt::from(10).pow(1000)), "1,000 * 10^(1000)");
+    /// assert_eq!(f.format_bigint(&-(BigInt::from(10).pow(1000) - BigInt::from(1))), "-1,000 * 10^(1000)");
+    /// assert_eq!(f.format_bigint(&(BigInt::from(10).pow(1000) * 12345)), "1,234 * 10^(1004)");
+    /// assert_eq!(f.format_bigint(&BigInt::from(-1234567)), "-1,235 M");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(30));
+    /// assert_eq!(f.format_bigint(&(BigInt::from(3).pow(2000))), "1,74787125172265160965997461916 * 10^(954)");
+    /// ```
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true));
+    /// assert_eq!(f.format_bigint(&(BigInt::from(2).pow(1000))), "1,000 * 2^(1000)");
+    /// assert_eq!(f.format_bigint(&(BigInt::from(2).pow(1000))), f.format(2.0_f64.powi(1000)));
+    /// assert_eq!(f.format_bigint(&(BigInt::from(2).pow(3000))), "2,000 * 2^(2999)"); // rounded to 4 significant digits first, 1,230 * 10^(903) is just below 2^(3000)
+    /// assert_eq!(f.format_bigint(&(BigInt::from(2).pow(3000) * 3 - BigInt::from(1))), "1,500 * 2^(3001)");
+    /// assert_eq!(f.format_bigint(&(BigInt::from(2).pow(80) * 1000)), "1.000 Yi");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(3));
+    /// let s: String = f.format_bigint(&BigInt::from(10).pow(1000));
+    /// assert!(s.starts_with("10.000.000."));
+    /// assert_eq!(s.len(), 1001 + 333); // all digits and group separators
+    /// ```
+    #[cfg(feature = "num-bigint")]
+    pub fn format_bigint(&self, x: &num_bigint::BigInt) -> String
+    {
+        return self.format_big(x.sign() == num_bigint::Sign::Minus, &x.magnitude().to_string(), 0);
+    }
+
+
+    /// # Summary
+    /// Formats the decimal number `digits` * 10^(`exponent`) exactly.
+    ///
+    /// # Arguments
+    /// - `negative`: whether the number is negative
+    /// - `digits`: all digits as ASCII
+    /// - `exponent`: decimal exponent to multiply the digits with
+    ///
+    /// # Returns
+    /// - formatted number
+    fn format_big(&self, negative: bool, digits: &str, exponent: i64) -> String
+    {
+        let mut s: String = String::new(); // formatted number, result
+
+
+        self.render(Value::Big(negative, digits, exponent), &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+/// # Summary
+/// Reported by `Formatter::format_to_slice` if the formatted number does not fit into the buffer. Contains how many bytes the buffer must have at least.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BufferTooSmall
+{
+    pub required: usize, // number of bytes the formatted number needs
+}
+
+
+/// # Summary
+/// Returned by `Formatter::format_to_slice` if the number is not written into the buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FormatToSliceError
+{
+    BufferTooSmall(BufferTooSmall), // formatted number does not fit, with the required buffer size
+    NonFinite(NonFiniteError),      // number rejected by `NonFinitePolicy::Error`
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats like `Formatter::format`, but writes the UTF-8 encoded result into a caller-provided buffer without any heap allocation. If the result does not fit, nothing usable is written and the error contains the required buffer size, the output is never truncated. Infinity and not a number rejected by `NonFinitePolicy::Error` are reported instead of written.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `buf`: the buffer to write into
+    ///
+    /// # Returns
+    /// - the filled beginning of `buf` as string slice
+    /// - or `FormatToSliceError::BufferTooSmall` with the required buffer size
+    /// - or `FormatToSliceError::NonFinite` if the number is rejected by the non-finite policy
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut buf: [u8; 7] = [0; 7];
+    /// assert_eq!(f.format_to_slice(-42069, &mut buf), Err(scaler::FormatToSliceError::BufferTooSmall(scaler::BufferTooSmall {required: 8}))); // undersized
+    /// let mut buf: [u8; 8] = [0; 8]; // exactly sized
+    /// assert_eq!(f.format_to_slice(-42069, &mut buf), Ok("-42,07 k"));
+    /// let mut buf: [u8; 32] = [0; 32]; // oversized
+    /// assert_eq!(f.format_to_slice(-42069, &mut buf), Ok("-42,07 k"));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1))
+    ///     .set_separators("\u{202F}", "·"); // multi-byte separators
+    /// let mut buf: [u8; 16] = [0; 16];
+    /// assert_eq!(f.format_to_slice(1234.5, &mut buf), Ok("1\u{202F}234·5")); // 6 characters, 10 bytes
+    /// let mut buf: [u8; 9] = [0; 9];
+    /// assert_eq!(f.format_to_slice(1234.5, &mut buf), Err(scaler::FormatToSliceError::BufferTooSmall(scaler::BufferTooSmall {required: 10})));
+    /// let mut buf: [u8; 2] = [0; 2];
+    /// assert_eq!(f.format_to_slice(f64::INFINITY, &mut buf), Err(scaler::FormatToSliceError::BufferTooSmall(scaler::BufferTooSmall {required: 3}))); // "∞" is 3 bytes
+    /// let f: scaler::Formatter = f.set_non_finite_policy(scaler::NonFinitePolicy::Error);
+    /// assert_eq!(f.format_to_slice(f64::INFINITY, &mut buf), Err(scaler::FormatToSliceError::NonFinite(scaler::NonFiniteError::PositiveInfinity)));
+    /// ```
+    pub fn format_to_slice<'a, T>(&self, x: T, buf: &'a mut [u8]) -> Result<&'a str, FormatToSliceError>
+    where
+        T: Into<f64>,
+    {
+        let mut len: usize = 0; // number of bytes of the formatted number
+        let x: f64 = x.into();
+
+
+        if let Some(error) = self.rejected(x)
+        {
+            return Err(FormatToSliceError::NonFinite(error));
+        }
+        self.render(Value::Float(x), &mut |_kind, s| {
+            if len + s.len() <= buf.len()
+            // only write while everything so far fits, but keep counting to report required size
+            {
+                buf[len..len + s.len()].copy_from_slice(s.as_bytes());
+            }
+            len += s.len();
+            return Ok(());
+        })
+        .expect("Writing into a slice failed even though it is infallible.");
+
+        if buf.len() < len
+        {
+            return Err(FormatToSliceError::BufferTooSmall(BufferTooSmall { required: len }));
+        }
+        return Ok(std::str::from_utf8(&buf[..len]).expect("Formatted number is not valid UTF-8 even though it is built from string slices."));
+    }
+
+
+    /// # Summary
+    /// Calculates an upper bound of the number of bytes `Formatter::format` and `Formatter::format_to_slice` produce for any number with the current settings, to size buffers in advance.
+    ///
+    /// # Returns
+    /// - maximum number of bytes of a formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut buf: Vec<u8> = vec![0; f.max_len()];
+    /// for x in [0.0, -1.234e-300, 1e300, f64::MAX, f64::MIN_POSITIVE, f64::NAN, f64::NEG_INFINITY]
+    /// {
+    ///     assert!(f.format_to_slice(x, &mut buf).is_ok());
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// let formatters: Vec<scaler::Formatter> = vec![
+    ///     scaler::Formatter::new().set_affixes("€ ", " EUR").set_width(20, scaler::Align::Center, '·'),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::Binary(true)).set_rounding(scaler::Rounding::Magnitude(-2)),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::None).set_rounding(scaler::Rounding::SignificantDigits(17)),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::Scientific).set_rounding(scaler::Rounding::Magnitude(0)),
+    ///     scaler::Formatter::new().set_min_integer_digits(5).set_zero_pad(12, true).set_sign(scaler::Sign::Always),
+    ///     scaler::Formatter::new().set_special_strings("", "").set_machine_readable(true),
+    ///     scaler::Formatter::new().set_clamp(Some(-1e-300), Some(1e300)).set_clamp_markers(" ▼", " ▲").set_display_floor(Some(1e-200)).set_threshold_markers("≤ ", "≥ "),
+    /// ];
+    /// for f in formatters.iter() // bound holds across magnitudes
+    /// {
+    ///     let mut x: f64 = 4.9e-324;
+    ///     while x.is_finite()
+    ///     {
+    ///         for y in [x, -x]
+    ///         {
+    ///             assert!(f.format(y).len() <= f.max_len());
+    ///         }
+    ///         x *= 13.7;
+    ///     }
+    /// }
+    /// ```
+    pub fn max_len(&self) -> usize
+    {
+        let dec_places: usize; // maximum number of decimal places
+        let int_digits: usize; // maximum number of integer digits
+        let magnifier_len: usize = match &self.scaling // maximum length of unit prefix or exponent
+        {
+            Scaling::Exponential { base: _, step: _, prefixes } => " * 4294967295^(-1074)".len().max(prefixes.map_or(0, |table| " ".len() + table.prefixes.iter().map(|(_lower, _upper, prefix)| prefix.len()).max().unwrap_or(0))), // largest base and smallest binary exponent, or longest unit prefix
+            Scaling::CompactLocalized => " ".len() + self.compact_locale().compact_suffixes().iter().map(|(_lower, _upper, suffix)| suffix.len()).max().unwrap_or(0), // longest compact suffix
+            Scaling::None => 0,
+            Scaling::Percent(_) => " %".len(),
+            Scaling::Strategy(strategy) => strategy.max_suffix_len(), // as bounded by the strategy
+            _ => " * 10^(-1074)".len().max(" µ".len()), // scientific notation covers unit prefixes, -1074 is the smallest binary exponent
+        };
+        let original_len: usize = match self.show_original.0 // maximum length of the original value with parentheses
+        {
+            ShowOriginal::Grouped => " (-)".len() + 309 + 309 / 3 * self.group_separator.len() + self.decimal_separator.len() + 341, // shortest digits have at most 309 integer or 324 zeros and 17 significant fraction digits
+            ShowOriginal::Off => 0,
+            ShowOriginal::Raw => " (-.)".len() + 309 + 341,
+        };
+        let marker_len: usize = if self.display_floor.or(self.display_ceiling).is_some() { self.threshold_markers.0.len().max(self.threshold_markers.1.len()) } else { 0 }
+            + if self.clamp != (None, None) { self.clamp_markers.0.len().max(self.clamp_markers.1.len()) } else { 0 }; // maximum length of the markers of display floor or ceiling and of the clamp range, if set
+        let special_len: usize = self.special_strings.0.len().max(self.special_strings.1.len()); // maximum length of infinity or not a number
+        let unit_len: usize = if self.unit.is_empty() && self.unit_plural.is_empty() { 0 } else { " ".len() + self.unit.len().max(self.unit_plural.len()) }; // maximum length of singular or plural unit with whitespace
+
+
+        if self.machine_readable
+        // bound of the overridden options, whose scientific notation is longer than e-notation
+        {
+            return Formatter { machine_readable: false, ..self.machine_readable_formatter() }.max_len();
+        }
+        if let Some(fallback) = &self.fallback_rounding
+        // bound of either rounding, whichever a number is displayed with
+        {
+            return Formatter { fallback_rounding: None, ..self.clone() }.max_len().max(Formatter { fallback_rounding: None, rounding: fallback.clone(), ..self.clone() }.max_len());
+        }
+        int_digits = match self.scaling
+        {
+            Scaling::CompactLocalized | Scaling::Exponential { .. } | Scaling::None | Scaling::Strategy(_) => 309, // f64::MAX has 309 integer digits, the mantissa of exponential scaling is below the base to the power of the step
+            Scaling::Percent(_) => 311, // times 100
+            _ => 4, // mantissa is at most 1024 in binary scaling
+        }
+        .max(self.min_integer_digits)
+            + self.zero_pad;
+        dec_places = match (&self.scaling, &self.rounding)
+        {
+            (Scaling::None, Rounding::Magnitude(precision)) => (-(*precision as i32)).max(0) as usize,
+            (Scaling::CompactLocalized | Scaling::None, Rounding::SignificantDigits(precision)) => 324 + *precision as usize, // smallest subnormal is 10^(-324), not scaled below the first compact step
+            (Scaling::Percent(_), Rounding::Magnitude(precision)) => (-(*precision as i32) - 2).max(0) as usize,
+            (Scaling::Percent(_), Rounding::SignificantDigits(precision)) => 322 + *precision as usize, // smallest subnormal is 10^(-322) %
+            (Scaling::Binary(_), Rounding::Magnitude(precision)) => (24 - *precision as i32).max(1023) as usize, // largest unit prefix or scientific fallback, which uses the binary exponent as decimal places
+            (Scaling::Exponential { .. }, Rounding::Magnitude(precision)) => (309 - *precision as i32).max(0) as usize, // divisor is at most f64::MAX
+            (Scaling::Strategy(_), Rounding::Magnitude(precision)) => (309 - *precision as i32).max(u8::MAX as i32) as usize, // divisor is at most f64::MAX, or decimal places hinted
+            (Scaling::Strategy(_), Rounding::SignificantDigits(precision)) => (324 + *precision as usize).max(u8::MAX as usize), // mantissa may be as small as the smallest subnormal, or decimal places hinted
+            (_, Rounding::Magnitude(precision)) => (308 - *precision as i32).max(0) as usize, // scientific fallback up to 10^(308), which uses the exponent minus the precision as decimal places, beyond the largest unit prefix
+            (_, Rounding::SignificantDigits(precision)) => *precision as usize,
+        };
+
+        return if self.sign_placement == SignPlacement::Parentheses { "()".len() } else { "-".len() }
+            + marker_len
+            + self.affix_prefix.len()
+            + (int_digits
+                + int_digits / 3 * self.group_separator.len()
+                + self.decimal_separator.len()
+                + dec_places
+                + magnifier_len)
+                .max(special_len)
+            + unit_len
+            + self.affix_suffix.len()
+            + original_len
+            + self.width * self.fill_char().len_utf8();
+    }
+}
+
+
+impl std::fmt::Display for BufferTooSmall
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return write!(f, "Buffer too small for formatted number, {} bytes required.", self.required);
+    }
+}
+
+
+impl std::error::Error for BufferTooSmall {}
+
+
+impl std::fmt::Display for FormatToSliceError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            FormatToSliceError::BufferTooSmall(error) => write!(f, "{error}"),
+            FormatToSliceError::NonFinite(error) => write!(f, "{error}"),
+        };
+    }
+}
+
+
+impl std::error::Error for FormatToSliceError {}
@@ -0,0 +1,601 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `FormatterBuilder::build` and `ParserBuilder::build` if the configuration is broken. One variant per rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigError
+{
+    DigitInDecimalSeparator(String),                   // decimal separator contains a digit, contains the separator
+    DigitInGroupSeparator(String),                     // group separator contains a digit, contains the separator
+    EmptyDecimalSeparator,                             // decimal separator is empty
+    IdenticalSeparators(String),                       // group and decimal separator are the same, contains the separator
+    InvalidZeroEpsilon,                                // epsilon below which numbers are formatted as 0 is negative, infinite, or not a number
+    InvertedClamp,                                     // minimum of the clamp range is above its maximum
+    WidthBelowContent { minimum: usize, width: usize }, // width is less than every formatted finite number, so it never pads them, contains the shortest length possible and the width
+}
+
+
+/// # Summary
+/// Builds a formatter with the same setters as `Formatter`, but validates the whole configuration at once in `FormatterBuilder::build` instead of silently accepting or only warning about broken ones. Created by `Formatter::builder` or `FormatterBuilder::new`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FormatterBuilder
+{
+    formatter: Formatter, // configuration so far, not validated yet
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Starts building a formatter with default settings, validated at the end.
+    ///
+    /// # Returns
+    /// - the builder
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::builder()
+    ///     .set_separators(",", ".")
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(3))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(f.format(1234.5), "1.23 k");
+    /// ```
+    pub fn builder() -> FormatterBuilder
+    {
+        return FormatterBuilder::new();
+    }
+}
+
+
+impl FormatterBuilder
+{
+    /// # Summary
+    /// Starts building a formatter with the default settings of `Formatter::new`.
+    ///
+    /// # Returns
+    /// - the builder
+    pub fn new() -> Self
+    {
+        return Self { formatter: Formatter::new() };
+    }
+
+
+    /// # Summary
+    /// Validates the configuration and creates the formatter. Rules are checked in this order:
+    /// - the decimal separator must not be empty
+    /// - group and decimal separator must differ
+    /// - the separators must not contain digits
+    /// - the minimum of the clamp range must not be above its maximum
+    /// - the epsilon below which numbers are formatted as 0 must be finite and not negative
+    /// - a width must be at least as long as the shortest formatted finite number, affixes, minimum integer digits, zero padding, and unit included, otherwise it would only ever pad infinity, not a number, and missing numbers
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or `ConfigError` of the first rule broken
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::ConfigError;
+    /// assert_eq!(scaler::Formatter::builder().set_separators(".", ".").build(), Err(ConfigError::IdenticalSeparators(".".to_string())));
+    /// assert_eq!(
+    ///     scaler::Formatter::builder().set_affixes("EUR ", "").set_width(4, scaler::Align::Right, ' ').build(),
+    ///     Err(ConfigError::WidthBelowContent { minimum: 5, width: 4 })
+    /// );
+    /// assert_eq!(scaler::Formatter::builder().set_clamp(Some(1.0), Some(0.0)).build(), Err(ConfigError::InvertedClamp));
+    /// assert_eq!(
+    ///     scaler::Formatter::builder().set_separators("", "").build().unwrap_err().to_string(),
+    ///     "Decimal separator is empty. This may lead to ambiguous formatting."
+    /// );
+    /// ```
+    pub fn build(self) -> Result<Formatter, ConfigError>
+    {
+        let f: Formatter = self.formatter; // configuration to validate
+        let minimum: usize; // length of shortest formatted finite number
+
+
+        if let Some(problem) = separator_problem(&f.group_separator, &f.decimal_separator)
+        {
+            return Err(problem);
+        }
+        if f.clamp_inverted()
+        {
+            return Err(ConfigError::InvertedClamp);
+        }
+        if !f.zero_epsilon_valid()
+        {
+            return Err(ConfigError::InvalidZeroEpsilon);
+        }
+
+        minimum = f.zero_pad.max(f.affix_prefix.chars().count() + f.min_integer_digits.max(1) + f.affix_suffix.chars().count() + f.unit.chars().count());
+        if 0 < f.width && f.width < minimum
+        {
+            return Err(ConfigError::WidthBelowContent { minimum, width: f.width });
+        }
+
+        return Ok(f);
+    }
+
+
+    /// # Summary
+    /// Sets the affixes like `Formatter::set_affixes`.
+    pub fn set_affixes(mut self, prefix: &str, suffix: &str) -> Self
+    {
+        self.formatter = self.formatter.set_affixes(prefix, suffix);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the digits automatic precision may add like `Formatter::set_auto_precision_max`.
+    pub fn set_auto_precision_max(mut self, max: u8) -> Self
+    {
+        self.formatter = self.formatter.set_auto_precision_max(max);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how CJK positional numerals are written like `Formatter::set_cjk`.
+    pub fn set_cjk(mut self, options: CjkOptions) -> Self
+    {
+        self.formatter = self.formatter.set_cjk(options);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the range numbers are clamped to like `Formatter::set_clamp`.
+    pub fn set_clamp(mut self, min: Option<f64>, max: Option<f64>) -> Self
+    {
+        self.formatter = self.formatter.set_clamp(min, max);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the markers after clamped numbers like `Formatter::set_clamp_markers`.
+    pub fn set_clamp_markers(mut self, below: &str, above: &str) -> Self
+    {
+        self.formatter = self.formatter.set_clamp_markers(below, above);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the common scale policy like `Formatter::set_common_scale`.
+    pub fn set_common_scale(mut self, common_scale: CommonScale) -> Self
+    {
+        self.formatter = self.formatter.set_common_scale(common_scale);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the notation of complex numbers like `Formatter::set_complex_notation`.
+    pub fn set_complex_notation(mut self, notation: ComplexNotation, imaginary_unit: &str) -> Self
+    {
+        self.formatter = self.formatter.set_complex_notation(notation, imaginary_unit);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets what is displayed of a change like `Formatter::set_delta`.
+    pub fn set_delta(mut self, parts: DeltaParts, percent_decimals: u8) -> Self
+    {
+        self.formatter = self.formatter.set_delta(parts, percent_decimals);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the largest absolute value displayed as it is like `Formatter::set_display_ceiling`.
+    pub fn set_display_ceiling(mut self, ceiling: Option<f64>) -> Self
+    {
+        self.formatter = self.formatter.set_display_ceiling(ceiling);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the smallest absolute value other than 0 displayed as it is like `Formatter::set_display_floor`.
+    pub fn set_display_floor(mut self, floor: Option<f64>) -> Self
+    {
+        self.formatter = self.formatter.set_display_floor(floor);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the symbols of angles like `Formatter::set_dms_symbols`.
+    pub fn set_dms_symbols(mut self, degrees: &str, minutes: &str, seconds: &str) -> Self
+    {
+        self.formatter = self.formatter.set_dms_symbols(degrees, minutes, seconds);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the fraction digits exact expansions display at most like `Formatter::set_exact_max_digits`.
+    pub fn set_exact_max_digits(mut self, max: usize) -> Self
+    {
+        self.formatter = self.formatter.set_exact_max_digits(max);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the rounding mode of scientific notation like `Formatter::set_fallback_rounding`.
+    pub fn set_fallback_rounding(mut self, rounding: Option<Rounding>) -> Self
+    {
+        self.formatter = self.formatter.set_fallback_rounding(rounding);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets what numbers are padded with like `Formatter::set_fill_mode`.
+    pub fn set_fill_mode(mut self, fill: Fill) -> Self
+    {
+        self.formatter = self.formatter.set_fill_mode(fill);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how fractions are displayed like `Formatter::set_fraction`.
+    pub fn set_fraction(mut self, options: FractionOptions) -> Self
+    {
+        self.formatter = self.formatter.set_fraction(options);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how hexadecimal floats are displayed like `Formatter::set_hexfloat`.
+    pub fn set_hexfloat(mut self, options: HexfloatOptions) -> Self
+    {
+        self.formatter = self.formatter.set_hexfloat(options);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the locale of compact suffixes and separators like `Formatter::set_locale`.
+    pub fn set_locale(mut self, locale: Locale) -> Self
+    {
+        self.formatter = self.formatter.set_locale(locale);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether the output is meant for machines like `Formatter::set_machine_readable`.
+    pub fn set_machine_readable(mut self, machine_readable: bool) -> Self
+    {
+        self.formatter = self.formatter.set_machine_readable(machine_readable);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the minimum number of integer digits like `Formatter::set_min_integer_digits`.
+    pub fn set_min_integer_digits(mut self, min_integer_digits: usize) -> Self
+    {
+        self.formatter = self.formatter.set_min_integer_digits(min_integer_digits);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether infinity and not a number are formatted or rejected like `Formatter::set_non_finite_policy`.
+    pub fn set_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self
+    {
+        self.formatter = self.formatter.set_non_finite_policy(policy);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the placeholder for missing numbers like `Formatter::set_none_string`.
+    pub fn set_none_string(mut self, none_string: &str) -> Self
+    {
+        self.formatter = self.formatter.set_none_string(none_string);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the overflow marker like `Formatter::set_overflow_marker`.
+    pub fn set_overflow_marker(mut self, overflow_marker: char) -> Self
+    {
+        self.formatter = self.formatter.set_overflow_marker(overflow_marker);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how percentages of a whole handle division edge cases like `Formatter::set_percent_of`.
+    pub fn set_percent_of(mut self, options: PercentOfOptions) -> Self
+    {
+        self.formatter = self.formatter.set_percent_of(options);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the plural rule of the unit like `Formatter::set_plural_rule`.
+    pub fn set_plural_rule(mut self, rule: PluralRule) -> Self
+    {
+        self.formatter = self.formatter.set_plural_rule(rule);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how integers in other bases are displayed like `Formatter::set_radix`.
+    pub fn set_radix(mut self, options: RadixOptions) -> Self
+    {
+        self.formatter = self.formatter.set_radix(options);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how ranges are joined like `Formatter::set_range_separator`.
+    pub fn set_range_separator(mut self, separator: &str, swap_reversed: bool) -> Self
+    {
+        self.formatter = self.formatter.set_range_separator(separator, swap_reversed);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the time base of rates like `Formatter::set_rate_base`.
+    pub fn set_rate_base(mut self, rate_base: RateBase) -> Self
+    {
+        self.formatter = self.formatter.set_rate_base(rate_base);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether to reserve room for the widest unit prefix like `Formatter::set_reserve_prefix_width`.
+    pub fn set_reserve_prefix_width(mut self, reserve: bool) -> Self
+    {
+        self.formatter = self.formatter.set_reserve_prefix_width(reserve);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how Roman numerals are written like `Formatter::set_roman`.
+    pub fn set_roman(mut self, options: RomanOptions) -> Self
+    {
+        self.formatter = self.formatter.set_roman(options);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the rounding mode and precision like `Formatter::set_rounding`.
+    pub fn set_rounding(mut self, rounding: Rounding) -> Self
+    {
+        self.formatter = self.formatter.set_rounding(rounding);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether Rust literals get a type suffix like `Formatter::set_rust_literal_suffix`.
+    pub fn set_rust_literal_suffix(mut self, suffix: bool) -> Self
+    {
+        self.formatter = self.formatter.set_rust_literal_suffix(suffix);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the scaling mode like `Formatter::set_scaling`.
+    pub fn set_scaling(mut self, scaling: Scaling) -> Self
+    {
+        self.formatter = self.formatter.set_scaling(scaling);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the separators like `Formatter::set_separators`. Problematic separators are not warned about, `FormatterBuilder::build` rejects them.
+    pub fn set_separators(mut self, group_separator: &str, decimal_separator: &str) -> Self
+    {
+        set_text(&mut self.formatter.group_separator, group_separator);
+        set_text(&mut self.formatter.decimal_separator, decimal_separator);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the separators like `Formatter::set_separators_static`, without copying them. Problematic separators are not warned about, `FormatterBuilder::build` rejects them.
+    pub fn set_separators_static(mut self, group_separator: &'static str, decimal_separator: &'static str) -> Self
+    {
+        self.formatter = self.formatter.set_separators_const(group_separator, decimal_separator);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether and how the original value is appended like `Formatter::set_show_original`.
+    pub fn set_show_original(mut self, show: ShowOriginal, if_identical: bool) -> Self
+    {
+        self.formatter = self.formatter.set_show_original(show, if_identical);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the sign mode like `Formatter::set_sign`.
+    pub fn set_sign(mut self, sign: Sign) -> Self
+    {
+        self.formatter = self.formatter.set_sign(sign);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets where the sign is placed like `Formatter::set_sign_placement`.
+    pub fn set_sign_placement(mut self, sign_placement: SignPlacement) -> Self
+    {
+        self.formatter = self.formatter.set_sign_placement(sign_placement);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the sign position relative to the prefix affix like `Formatter::set_sign_position`.
+    pub fn set_sign_position(mut self, sign_position: SignPosition) -> Self
+    {
+        self.formatter = self.formatter.set_sign_position(sign_position);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the number of digits of sortable output like `Formatter::set_sortable_digits`.
+    pub fn set_sortable_digits(mut self, int_digits: usize, fraction_digits: usize) -> Self
+    {
+        self.formatter = self.formatter.set_sortable_digits(int_digits, fraction_digits);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the strings for infinity and not a number like `Formatter::set_special_strings`.
+    pub fn set_special_strings(mut self, infinity: &str, nan: &str) -> Self
+    {
+        self.formatter = self.formatter.set_special_strings(infinity, nan);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether a mantissa of 1 is left out before the power like `Formatter::set_suppress_unit_mantissa`.
+    pub fn set_suppress_unit_mantissa(mut self, suppress_unit_mantissa: bool) -> Self
+    {
+        self.formatter = self.formatter.set_suppress_unit_mantissa(suppress_unit_mantissa);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the markers before numbers displayed as floor or ceiling like `Formatter::set_threshold_markers`.
+    pub fn set_threshold_markers(mut self, less: &str, greater: &str) -> Self
+    {
+        self.formatter = self.formatter.set_threshold_markers(less, greater);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether trailing zeros are kept like `Formatter::set_trailing_zeros`.
+    pub fn set_trailing_zeros(mut self, trailing_zeros: bool) -> Self
+    {
+        self.formatter = self.formatter.set_trailing_zeros(trailing_zeros);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the notation of uncertainties like `Formatter::set_uncertainty_notation`.
+    pub fn set_uncertainty_notation(mut self, notation: UncertaintyNotation, plus_minus: &str) -> Self
+    {
+        self.formatter = self.formatter.set_uncertainty_notation(notation, plus_minus);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the unit like `Formatter::set_unit`.
+    pub fn set_unit(mut self, unit: &str) -> Self
+    {
+        self.formatter = self.formatter.set_unit(unit);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the unit with singular and plural like `Formatter::set_unit_plural`.
+    pub fn set_unit_plural(mut self, singular: &str, plural: &str) -> Self
+    {
+        self.formatter = self.formatter.set_unit_plural(singular, plural);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how numbers are spelt in words like `Formatter::set_words`.
+    pub fn set_words(mut self, options: WordsOptions) -> Self
+    {
+        self.formatter = self.formatter.set_words(options);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the minimum width like `Formatter::set_width`. `FormatterBuilder::build` rejects widths that could never pad a finite number.
+    pub fn set_width(mut self, width: usize, align: Align, fill: char) -> Self
+    {
+        self.formatter = self.formatter.set_width(width, align, fill);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the absolute value below which numbers are formatted as 0 like `Formatter::set_zero_epsilon`.
+    pub fn set_zero_epsilon(mut self, epsilon: f64) -> Self
+    {
+        self.formatter = self.formatter.set_zero_epsilon(epsilon);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether 0 is displayed with an exponent like `Formatter::set_zero_exponent`.
+    pub fn set_zero_exponent(mut self, zero_exponent: bool) -> Self
+    {
+        self.formatter = self.formatter.set_zero_exponent(zero_exponent);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets zero padding like `Formatter::set_zero_pad`.
+    pub fn set_zero_pad(mut self, width: usize, grouped: bool) -> Self
+    {
+        self.formatter = self.formatter.set_zero_pad(width, grouped);
+        return self;
+    }
+}
+
+
+impl std::fmt::Display for ConfigError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            ConfigError::DigitInDecimalSeparator(separator) => write!(f, "Decimal separator \"{separator}\" contains a digit. This may lead to ambiguous formatting."),
+            ConfigError::DigitInGroupSeparator(separator) => write!(f, "Group separator \"{separator}\" contains a digit. This may lead to ambiguous formatting."),
+            ConfigError::EmptyDecimalSeparator => write!(f, "Decimal separator is empty. This may lead to ambiguous formatting."),
+            ConfigError::IdenticalSeparators(separator) => write!(f, "Group separator \"{separator}\" and decimal separator \"{separator}\" are the same. This may lead to ambiguous formatting."),
+            ConfigError::InvalidZeroEpsilon => write!(f, "Epsilon below which numbers are formatted as 0 is negative, infinite, or not a number, so it snaps either nothing or every number."),
+            ConfigError::InvertedClamp => write!(f, "Minimum of the clamp range is above its maximum, so every number would be displayed as the maximum."),
+            ConfigError::WidthBelowContent { minimum, width } => write!(f, "Width {width} is less than {minimum}, the length of the shortest formatted number, so it never pads numbers."),
+        };
+    }
+}
+
+
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,347 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+
+/// index of no entry in the linked list of the cache
+const NONE: usize = usize::MAX;
+
+
+/// # Summary
+/// A formatter that remembers the formatted numbers it returned last, for output that reformats the same few values over and over, like a UI redrawing 60 times a second. Repeated numbers cost a hash lookup instead of formatting. The least recently used number is forgotten once the capacity is reached. The formatter is fixed at construction, so cached output can never be stale; to change settings, construct a new cache. Results are `Arc<str>`, so a hit is shared instead of copied and the cache can be used from several threads at once, the lock is only held for the lookup, never while formatting.
+///
+/// # Examples
+/// ```
+/// let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(scaler::Formatter::new().set_unit("B"), 64);
+/// let first: std::sync::Arc<str> = cached.format(42069);
+/// assert_eq!(&*first, "42,07 kB");
+/// assert!(std::sync::Arc::ptr_eq(&cached.format(42069.0), &first)); // cache hit, same string
+/// assert_eq!(cached.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct CachedFormatter
+{
+    cache:    Mutex<Lru>,        // formatted numbers by their bit pattern, least recently used evicted first
+    compiled: CompiledFormatter, // formats misses, precomputed once as the formatter never changes
+}
+
+
+/// # Summary
+/// Least recently used cache of formatted numbers, entries linked by index from most to least recently used.
+#[derive(Debug)]
+struct Lru
+{
+    capacity: usize,               // maximum number of entries
+    entries:  Vec<Entry>,          // slots, never more than capacity
+    head:     usize,               // most recently used entry or NONE
+    index:    HashMap<u64, usize>, // bit pattern of the number to its slot
+    tail:     usize,               // least recently used entry or NONE, evicted next
+}
+
+
+/// # Summary
+/// Formatted number in the cache, with its neighbours in recency.
+#[derive(Debug)]
+struct Entry
+{
+    key:   u64,      // bit pattern of the number
+    next:  usize,    // less recently used entry or NONE
+    prev:  usize,    // more recently used entry or NONE
+    value: Arc<str>, // the formatted number
+}
+
+
+impl CachedFormatter
+{
+    /// # Summary
+    /// Constructs a cache around a formatter. The formatter is compiled once, so misses format like `CompiledFormatter::format`.
+    ///
+    /// # Arguments
+    /// - `formatter`: formats all numbers, fixed for the lifetime of the cache
+    /// - `capacity`: maximum number of formatted numbers to remember, 0 to remember none
+    ///
+    /// # Returns
+    /// - the empty cache
+    ///
+    /// # Examples
+    /// ```
+    /// let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(scaler::Formatter::new(), 2);
+    /// assert_eq!(cached.capacity(), 2);
+    /// assert!(cached.is_empty());
+    /// assert_eq!(cached.formatter(), &scaler::Formatter::new());
+    /// ```
+    pub fn new(formatter: Formatter, capacity: usize) -> Self
+    {
+        return Self {
+            cache:    Mutex::new(Lru { capacity, entries: Vec::with_capacity(capacity.min(1024)), head: NONE, index: HashMap::new(), tail: NONE }),
+            compiled: formatter.compile(),
+        };
+    }
+
+
+    /// # Summary
+    /// Maximum number of formatted numbers the cache remembers.
+    ///
+    /// # Returns
+    /// - the capacity set at construction
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(scaler::CachedFormatter::new(scaler::Formatter::new(), 128).capacity(), 128);
+    /// ```
+    pub fn capacity(&self) -> usize
+    {
+        return self.lock().capacity;
+    }
+
+
+    /// # Summary
+    /// Forgets all formatted numbers, for example to free their memory while the output is hidden.
+    ///
+    /// # Examples
+    /// ```
+    /// let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(scaler::Formatter::new(), 8);
+    /// cached.format(1);
+    /// cached.clear();
+    /// assert!(cached.is_empty());
+    /// assert_eq!(&*cached.format(1), "1,000");
+    /// ```
+    pub fn clear(&self)
+    {
+        let mut cache: std::sync::MutexGuard<Lru> = self.lock(); // locked for the whole reset
+
+
+        cache.entries.clear();
+        cache.index.clear();
+        cache.head = NONE;
+        cache.tail = NONE;
+    }
+
+
+    /// # Summary
+    /// Formats like `Formatter::format`, or returns the string formatted for the same number before. Numbers are told apart by their bit pattern, so 0 and -0 are cached separately, and all not a numbers share a single entry however their payload is set.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be losslessly convertable to f64, references are accepted as well
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the formatted number, shared with the cache
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(f.clone(), 2);
+    /// for x in [0.789, 42069.0, 0.789, -0.0, f64::NAN, -f64::NAN]
+    /// {
+    ///     assert_eq!(&*cached.format(x), f.format(x));
+    /// }
+    /// assert_eq!(cached.len(), 2); // -0 and not a number, 0.789 and 42069 were evicted
+    /// ```
+    pub fn format<T>(&self, x: T) -> Arc<str>
+    where
+        T: IntoF64,
+    {
+        let x: f64 = x.into_f64();
+        let key: u64 = if x.is_nan() { f64::NAN.to_bits() } else { x.to_bits() }; // bit pattern, canonical for not a number as it hashes and compares consistently unlike the float
+        let value: Arc<str>; // formatted number
+
+
+        if let Some(value) = self.lock().get(key)
+        // hit
+        {
+            return value;
+        }
+        value = Arc::from(self.compiled.format(x)); // miss, formatted without holding the lock
+        return self.lock().insert(key, value);
+    }
+
+
+    /// # Summary
+    /// The formatter this cache formats with, to use the output methods that are not cached.
+    ///
+    /// # Returns
+    /// - the formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(scaler::Formatter::new(), 8);
+    /// assert_eq!(cached.formatter().format_opt(None::<f64>), "—");
+    /// ```
+    pub fn formatter(&self) -> &Formatter
+    {
+        return self.compiled.formatter();
+    }
+
+
+    /// # Summary
+    /// Whether the cache remembers no formatted number.
+    ///
+    /// # Returns
+    /// - true if empty, false otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(scaler::Formatter::new(), 8);
+    /// assert!(cached.is_empty());
+    /// cached.format(1);
+    /// assert!(!cached.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool
+    {
+        return self.lock().entries.is_empty();
+    }
+
+
+    /// # Summary
+    /// Number of formatted numbers the cache remembers, never more than its capacity.
+    ///
+    /// # Returns
+    /// - the number of entries
+    ///
+    /// # Examples
+    /// ```
+    /// let cached: scaler::CachedFormatter = scaler::CachedFormatter::new(scaler::Formatter::new(), 2);
+    /// for x in [1, 2, 1, 3, 4]
+    /// {
+    ///     cached.format(x);
+    /// }
+    /// assert_eq!(cached.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize
+    {
+        return self.lock().entries.len();
+    }
+
+
+    /// # Summary
+    /// Locks the cache. Every operation leaves it consistent before anything can panic, so a poisoned lock is used as is.
+    ///
+    /// # Returns
+    /// - the locked cache
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru>
+    {
+        return self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+    }
+}
+
+
+impl Lru
+{
+    /// # Summary
+    /// Looks up a formatted number and marks it most recently used.
+    ///
+    /// # Arguments
+    /// - `key`: bit pattern of the number
+    ///
+    /// # Returns
+    /// - the formatted number or none if not cached
+    fn get(&mut self, key: u64) -> Option<Arc<str>>
+    {
+        let slot: usize = *self.index.get(&key)?; // entry of the number
+
+
+        self.unlink(slot);
+        self.push_front(slot);
+        return Some(self.entries[slot].value.clone());
+    }
+
+
+    /// # Summary
+    /// Remembers a formatted number as most recently used, evicting the least recently used one if full. If another thread inserted the number in the meantime, its string is kept, so all callers share one.
+    ///
+    /// # Arguments
+    /// - `key`: bit pattern of the number
+    /// - `value`: the formatted number
+    ///
+    /// # Returns
+    /// - the cached formatted number, `value` if the cache remembers none
+    fn insert(&mut self, key: u64, value: Arc<str>) -> Arc<str>
+    {
+        let slot: usize; // entry to fill
+
+
+        if let Some(cached) = self.get(key)
+        // inserted by another thread meanwhile
+        {
+            return cached;
+        }
+        if self.capacity == 0
+        // remembers nothing
+        {
+            return value;
+        }
+
+        if self.entries.len() < self.capacity
+        // free slot
+        {
+            slot = self.entries.len();
+            self.entries.push(Entry { key, next: NONE, prev: NONE, value: value.clone() });
+        }
+        else
+        // evict least recently used
+        {
+            slot = self.tail;
+            self.unlink(slot);
+            self.index.remove(&self.entries[slot].key);
+            self.entries[slot].key = key;
+            self.entries[slot].value = value.clone();
+        }
+        self.index.insert(key, slot);
+        self.push_front(slot);
+        return value;
+    }
+
+
+    /// # Summary
+    /// Links an unlinked entry in as most recently used.
+    ///
+    /// # Arguments
+    /// - `slot`: the entry
+    fn push_front(&mut self, slot: usize)
+    {
+        self.entries[slot].prev = NONE;
+        self.entries[slot].next = self.head;
+        if self.head != NONE
+        {
+            self.entries[self.head].prev = slot;
+        }
+        self.head = slot;
+        if self.tail == NONE
+        {
+            self.tail = slot;
+        }
+    }
+
+
+    /// # Summary
+    /// Unlinks an entry from the recency list, its slot stays occupied.
+    ///
+    /// # Arguments
+    /// - `slot`: the entry
+    fn unlink(&mut self, slot: usize)
+    {
+        let next: usize = self.entries[slot].next; // less recently used neighbour
+        let prev: usize = self.entries[slot].prev; // more recently used neighbour
+
+
+        if prev == NONE
+        {
+            self.head = next;
+        }
+        else
+        {
+            self.entries[prev].next = next;
+        }
+        if next == NONE
+        {
+            self.tail = prev;
+        }
+        else
+        {
+            self.entries[next].prev = prev;
+        }
+    }
+}
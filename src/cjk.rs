@@ -0,0 +1,114 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// How `Formatter::format_cjk` writes numbers with Chinese and Japanese positional characters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CjkStyle
+{
+    FullKanji,   // every digit and power spelled with characters, "一万二千三百四十五" for 12345
+    MixedArabic, // Arabic digits in groups of 4 with the marker of their power of 10^4 after each group, "1万2345" for 12345
+}
+
+
+/// # Summary
+/// Markers of the powers of 10^4, from 10^0 to 10^36, enough for every i128.
+const MARKERS: [&str; 10] = ["", "万", "億", "兆", "京", "垓", "𥝱", "穣", "溝", "澗"];
+
+/// # Summary
+/// Digits from 0 to 9, ordinary and financial anti-fraud characters.
+const DIGITS: [[&str; 10]; 2] = [["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"], ["零", "壱", "弐", "参", "肆", "伍", "陸", "漆", "捌", "玖"]];
+
+/// # Summary
+/// Characters for 10^3, 10^2, and 10^1 within a group, ordinary and financial anti-fraud characters.
+const PLACES: [[(u32, &str); 3]; 2] = [[(1000, "千"), (100, "百"), (10, "十")], [(1000, "仟"), (100, "佰"), (10, "拾")]];
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats an integer with the positional characters of Chinese and Japanese business documents, grouped by powers of 10^4 instead of 10^3: 12345 as "1万2345" or fully spelled "一万二千三百四十五". Groups of 0 are left out, so 100010001 is "1億1万1" and "一億一万一". Spelled out, 1 is left out before 十, 百, and 千, but not before the markers of powers of 10^4 like 万. Financial anti-fraud characters, like "壱萬弐仟" for 12000, and the minus sign are set with `Formatter::set_cjk`. Fractional numbers have no positional form here, round them to an integer beforehand. The configured width applies, the other options do not.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `style`: Arabic digits with markers or fully spelled
+    ///
+    /// # Returns
+    /// - formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::CjkStyle;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_cjk(12345, CjkStyle::MixedArabic), "1万2345");
+    /// assert_eq!(f.format_cjk(12345, CjkStyle::FullKanji), "一万二千三百四十五");
+    /// assert_eq!(f.format_cjk(123456789012, CjkStyle::MixedArabic), "1234億5678万9012");
+    /// assert_eq!(f.format_cjk(-20000, CjkStyle::MixedArabic), "−2万");
+    /// assert_eq!(f.format_cjk(0, CjkStyle::FullKanji), "〇");
+    /// ```
+    pub fn format_cjk(&self, x: i128, style: CjkStyle) -> String
+    {
+        let digits: [&str; 10] = DIGITS[self.cjk.financial as usize]; // digits for the full style
+        let mut groups: Vec<(usize, u32)> = Vec::new(); // groups of 4 digits that are not 0 with their power of 10^4, most significant first
+        let mut n: u128 = x.unsigned_abs(); // digits not yet grouped
+        let mut s: String = String::new(); // formatted number without padding
+
+
+        for i in 0..MARKERS.len()
+        {
+            let group: u32 = (n % 10000) as u32; // least significant 4 digits not yet grouped
+
+
+            if group != 0
+            {
+                groups.push((i, group));
+            }
+            n /= 10000;
+        }
+        groups.reverse();
+
+        if x < 0
+        {
+            s.push_str(if self.cjk.spelled_minus { "マイナス" } else { "−" });
+        }
+        if groups.is_empty()
+        // 0 has no groups
+        {
+            s.push_str(if style == CjkStyle::FullKanji { digits[0] } else { "0" });
+        }
+        for (i, group) in groups
+        {
+            match style
+            {
+                CjkStyle::FullKanji =>
+                {
+                    for (value, place) in PLACES[self.cjk.financial as usize]
+                    {
+                        let digit: u32 = group / value % 10; // digit at this place
+
+
+                        if digit == 0
+                        {
+                            continue;
+                        }
+                        if 1 < digit || self.cjk.financial
+                        // 1 before places only left out with ordinary characters, financial ones spell it to prevent tampering
+                        {
+                            s.push_str(digits[digit as usize]);
+                        }
+                        s.push_str(place);
+                    }
+                    if 0 < group % 10
+                    {
+                        s.push_str(digits[(group % 10) as usize]);
+                    }
+                }
+                CjkStyle::MixedArabic => s.push_str(&group.to_string()),
+            }
+            s.push_str(if i == 1 && self.cjk.financial { "萬" } else { MARKERS[i] }); // 万 is the only marker with a financial variant
+        }
+
+        return self.pad(s);
+    }
+}
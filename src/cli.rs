@@ -0,0 +1,168 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+use std::str::FromStr;
+
+
+/// # Summary
+/// Common command line flags to configure a formatter, to flatten into the arguments of a command line tool with `#[command(flatten)]`. Convert them with `FormatterArgs::to_formatter`. Flags that contradict each other are declared as conflicts, so clap rejects them while parsing.
+///
+/// # Examples
+/// ```
+/// use clap::Parser;
+///
+/// #[derive(clap::Parser)]
+/// struct Cli
+/// {
+///     #[command(flatten)]
+///     format: scaler::FormatterArgs,
+/// }
+///
+/// let cli: Cli = Cli::try_parse_from(["tool", "--decimals", "2", "--scaling", "none", "--sign", "always"]).unwrap();
+/// let f: scaler::Formatter = cli.format.to_formatter().unwrap();
+/// assert_eq!(f.format(1234.5), "+1.234,50");
+///
+/// assert!(Cli::try_parse_from(["tool", "--decimals", "2", "--sig-digits", "3"]).is_err()); // conflicting rounding
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, ::clap::Args)]
+pub struct FormatterArgs
+{
+    /// Scale by binary unit prefixes like Ki and Mi
+    #[arg(long, conflicts_with = "scaling")]
+    pub binary: bool,
+
+    /// Round to this number of decimal places
+    #[arg(long, conflicts_with = "sig_digits", value_parser = ::clap::value_parser!(i16).range(0..))]
+    pub decimals: Option<i16>,
+
+    /// Separator between integer and fraction part
+    #[arg(long)]
+    pub decimal_sep: Option<String>,
+
+    /// Separator between groups of 3 integer digits
+    #[arg(long, conflicts_with = "no_grouping")]
+    pub group_sep: Option<String>,
+
+    /// Do not group integer digits
+    #[arg(long)]
+    pub no_grouping: bool,
+
+    /// Scaling: binary, decimal, none, or scientific, binary and decimal optionally with +space
+    #[arg(long)]
+    pub scaling: Option<Scaling>,
+
+    /// Round to this number of significant digits
+    #[arg(long)]
+    pub sig_digits: Option<u8>,
+
+    /// Sign: always, except_zero, only_minus, or space_for_positive
+    #[arg(long)]
+    pub sign: Option<Sign>,
+}
+
+
+impl FormatterArgs
+{
+    /// # Summary
+    /// Constructs the formatter the flags describe. Settings without a flag given keep the defaults of `Formatter::new`.
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or `clap::Error` of kind `ValueValidation` if the resulting separators are problematic, like equal or containing digits, so it can be reported like any other command line error
+    ///
+    /// # Examples
+    /// ```
+    /// let args: scaler::FormatterArgs = scaler::FormatterArgs { binary: true, sig_digits: Some(3), ..Default::default() };
+    /// assert_eq!(args.to_formatter().unwrap().format(1536), "1,50 Ki");
+    ///
+    /// let args: scaler::FormatterArgs = scaler::FormatterArgs { decimal_sep: Some(".".to_string()), ..Default::default() };
+    /// assert_eq!(args.to_formatter().unwrap_err().kind(), clap::error::ErrorKind::ValueValidation); // same as default group separator
+    /// ```
+    pub fn to_formatter(&self) -> Result<Formatter, ::clap::Error>
+    {
+        let mut f: Formatter = Formatter::new(); // result
+        let decimal_separator: String; // configured or default decimal separator
+        let group_separator: String; // configured or default group separator, empty without grouping
+
+
+        if self.binary
+        {
+            f = f.set_scaling(Scaling::Binary(true));
+        }
+        if let Some(decimals) = self.decimals
+        {
+            f = f.set_rounding(Rounding::Magnitude(-decimals));
+        }
+        if let Some(scaling) = &self.scaling
+        {
+            f = f.set_scaling(scaling.clone());
+        }
+        if let Some(significants) = self.sig_digits
+        {
+            f = f.set_rounding(Rounding::SignificantDigits(significants));
+        }
+        if let Some(sign) = &self.sign
+        {
+            f = f.set_sign(sign.clone());
+        }
+
+        decimal_separator = self.decimal_sep.clone().unwrap_or_else(|| f.decimal_separator.to_string());
+        group_separator = match (&self.group_sep, self.no_grouping)
+        {
+            (_, true) => String::new(),
+            (Some(group_separator), false) => group_separator.clone(),
+            (None, false) => f.group_separator.to_string(),
+        };
+        if let Some(problem) = separator_problem(&group_separator, &decimal_separator)
+        {
+            return Err(::clap::Error::raw(::clap::error::ErrorKind::ValueValidation, problem));
+        }
+
+        return Ok(f.set_separators(&group_separator, &decimal_separator));
+    }
+}
+
+
+impl ::clap::builder::ValueParserFactory for Rounding
+{
+    type Parser = ::clap::builder::ValueParser;
+
+    /// # Summary
+    /// Parses command line values with `Rounding::from_str`, so `Option<Rounding>` fields need no `value_parser` attribute.
+    ///
+    /// # Examples
+    /// ```
+    /// let arg: clap::Arg = clap::Arg::new("rounding").long("rounding").value_parser(clap::value_parser!(scaler::Rounding));
+    /// let matches: clap::ArgMatches = clap::Command::new("tool").arg(arg).try_get_matches_from(["tool", "--rounding", "sig:3"]).unwrap();
+    /// assert_eq!(matches.get_one::<scaler::Rounding>("rounding"), Some(&scaler::Rounding::SignificantDigits(3)));
+    /// ```
+    fn value_parser() -> Self::Parser
+    {
+        return ::clap::builder::ValueParser::new(Rounding::from_str);
+    }
+}
+
+
+impl ::clap::builder::ValueParserFactory for Scaling
+{
+    type Parser = ::clap::builder::ValueParser;
+
+    /// # Summary
+    /// Parses command line values with `Scaling::from_str`, so `Option<Scaling>` fields need no `value_parser` attribute.
+    fn value_parser() -> Self::Parser
+    {
+        return ::clap::builder::ValueParser::new(Scaling::from_str);
+    }
+}
+
+
+impl ::clap::builder::ValueParserFactory for Sign
+{
+    type Parser = ::clap::builder::ValueParser;
+
+    /// # Summary
+    /// Parses command line values with `Sign::from_str`, so `Option<Sign>` fields need no `value_parser` attribute.
+    fn value_parser() -> Self::Parser
+    {
+        return ::clap::builder::ValueParser::new(Sign::from_str);
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::{BINARY_PREFIXES, DECIMAL_PREFIXES};
+use crate::magnitude::{binary_magnitude, decimal_magnitude};
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats all numbers and pads them with spaces so that, printed below each other, their decimal separators line up. Numbers without decimal separator are aligned at the end of their integer digits, infinity and not a number at their end. The right side is padded as well, so all strings have the same number of characters. With `Fill::FigureSpace` set with `Formatter::set_fill_mode`, padding is U+2007 FIGURE SPACE, and U+2008 PUNCTUATION SPACE wherever another row has a group or decimal separator, so proportional fonts with tabular figures align the column glyph for glyph.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    ///
+    /// # Returns
+    /// - the formatted and padded numbers, in the same order as `values`
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_aligned(&[-1234.5, 0.5, 42.0, 1e40, f64::NAN, f64::NEG_INFINITY]), vec![
+    ///     " -1,234 k        ",
+    ///     "500,0 m          ",
+    ///     " 42,00           ",
+    ///     "  1,000 * 10^(40)",
+    ///     "NaN              ",
+    ///     " -∞              ",
+    /// ]);
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(0));
+    /// assert_eq!(f.format_aligned(&[7.0, -1234.0, 100.0]), vec![
+    ///     "     7",
+    ///     "-1.234",
+    ///     "   100",
+    /// ]);
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_aligned(&[]), Vec::<String>::new());
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_fill_mode(scaler::Fill::FigureSpace);
+    /// assert_eq!(f.format_aligned(&[7.0, -1234.0, 100.0]), vec![
+    ///     "\u{2007}\u{2007}\u{2008}\u{2007}\u{2007}7",
+    ///     "-1.234",
+    ///     "\u{2007}\u{2007}\u{2008}100",
+    /// ]);
+    /// ```
+    pub fn format_aligned(&self, values: &[f64]) -> Vec<String>
+    {
+        let mut left_max: usize = 0; // most characters left of the alignment point
+        let mut right_max: usize = 0; // most characters right of the alignment point
+        let mut separators_left: Vec<bool> = Vec::new(); // whether any row has a separator at this distance left of the alignment point, nearest first
+        let mut separators_right: Vec<bool> = Vec::new(); // whether any row has a separator at this distance right of the alignment point, nearest first
+        let mut sides: Vec<(String, usize, usize)> = Vec::with_capacity(values.len()); // formatted number, characters left and right of the alignment point
+
+
+        for x in values.iter()
+        {
+            let mut formatted: String = String::new(); // formatted number
+            let mut int_end: Option<usize> = None; // characters up to end of last integer digits or special value
+            let mut left: Option<usize> = None; // characters before decimal separator
+            let mut len: usize = 0; // characters so far
+            let mut separators: Vec<bool> = Vec::new(); // whether each character belongs to a group or decimal separator
+
+            for part in self.format_to_parts(*x).into_iter()
+            {
+                if part.kind == PartKind::DecimalSeparator && left.is_none()
+                {
+                    left = Some(len);
+                }
+                len += part.value.chars().count();
+                if matches!(part.kind, PartKind::IntegerDigits | PartKind::Special) && left.is_none()
+                {
+                    int_end = Some(len);
+                }
+                separators.extend(part.value.chars().map(|_| matches!(part.kind, PartKind::DecimalSeparator | PartKind::GroupSeparator)));
+                formatted.push_str(&part.value);
+            }
+
+            let left: usize = left.or(int_end).unwrap_or(len); // alignment point: decimal separator, else end of integer part, else end
+            left_max = left_max.max(left);
+            right_max = right_max.max(len - left);
+            for (distance, separator) in separators[..left].iter().rev().enumerate()
+            {
+                mark_separator(&mut separators_left, distance, *separator);
+            }
+            for (distance, separator) in separators[left..].iter().enumerate()
+            {
+                mark_separator(&mut separators_right, distance, *separator);
+            }
+            sides.push((formatted, left, len - left));
+        }
+
+        return sides
+            .into_iter()
+            .map(|(formatted, left, right)| match self.fill_mode
+            {
+                Fill::Character => format!("{}{}{}", " ".repeat(left_max - left), formatted, " ".repeat(right_max - right)),
+                Fill::FigureSpace => format!(
+                    "{}{}{}",
+                    (left..left_max).rev().map(|distance| figure_space(&separators_left, distance)).collect::<String>(),
+                    formatted,
+                    (right..right_max).map(|distance| figure_space(&separators_right, distance)).collect::<String>()
+                ),
+            })
+            .collect();
+    }
+
+
+    /// # Summary
+    /// Formats all numbers with the same unit prefix, so their magnitudes can be compared directly. The prefix is chosen according to the configured common scale policy from all finite numbers that are not 0 and returned separately, for example to put it into a column header. If the magnitude is out of the prefix range, the nearest prefix is used instead of scientific notation. Without binary or decimal scaling, every number is formatted individually and the returned prefix is empty.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    ///
+    /// # Returns
+    /// - the formatted numbers, in the same order as `values`
+    /// - the unit prefix shared by all numbers, may be empty
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(2))
+    ///     .set_trailing_zeros(false);
+    /// let (formatted, prefix) = f.format_common_scale(&[950.0, 1_200.0, 48_000.0]);
+    /// assert_eq!(formatted, vec!["0,95 k", "1,2 k", "48 k"]);
+    /// assert_eq!(prefix, "k");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let (formatted, prefix) = f.format_common_scale(&[0.0, -0.0015, 0.02, f64::NAN, f64::INFINITY]); // 0 and non-finite numbers do not influence the prefix
+    /// assert_eq!(formatted, vec!["0,000 m", "-1,500 m", "20,00 m", "NaN", "∞"]);
+    /// assert_eq!(prefix, "m");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_rounding(scaler::Rounding::Magnitude(0));
+    /// let (formatted, prefix) = f.format_common_scale(&[512.0, 1_536.0, 3_145_728.0]);
+    /// assert_eq!(formatted, vec!["0,00049 Mi", "0,00146 Mi", "3,00000 Mi"]);
+    /// assert_eq!(prefix, "Mi");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None);
+    /// let (formatted, prefix) = f.format_common_scale(&[950.0, 1_200.0]);
+    /// assert_eq!(formatted, vec!["950,0", "1.200"]);
+    /// assert_eq!(prefix, "");
+    /// ```
+    pub fn format_common_scale(&self, values: &[f64]) -> (Vec<String>, &'static str)
+    {
+        let mut scaled: Formatter = self.clone(); // formatter with forced unit prefix
+
+
+        match self.common_prefix(values, &self.common_scale)
+        {
+            Some(prefix) =>
+            {
+                scaled.prefix_override = Some(prefix.0);
+                return (values.iter().map(|x| scaled.format(*x)).collect(), prefix.2);
+            }
+            None => return (values.iter().map(|x| self.format(*x)).collect(), ""), // no unit prefixes to share or nothing to choose by, format as usual
+        }
+    }
+
+
+    /// # Summary
+    /// Chooses the unit prefix shared by all numbers according to a common scale policy, from all finite numbers that are not 0. If the magnitude is out of the prefix range, the nearest prefix is chosen.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to share the unit prefix
+    /// - `common_scale`: how to choose the unit prefix
+    ///
+    /// # Returns
+    /// - the unit prefix entry or none if not scaling by unit prefix or there is nothing to choose by
+    pub(crate) fn common_prefix(&self, values: &[f64], common_scale: &CommonScale) -> Option<&'static (i16, i16, &'static str)>
+    {
+        let chosen: f64; // magnitude the prefix is chosen by
+        let mut magnitudes: Vec<f64>; // magnitudes of finite numbers that are not 0
+        let prefixes: &'static [(i16, i16, &'static str)] = match self.scaling
+        {
+            Scaling::Binary(_) => &BINARY_PREFIXES,
+            Scaling::Decimal(_) => &DECIMAL_PREFIXES,
+            Scaling::Exponential { prefixes: Some(table), .. } => table.prefixes,
+            Scaling::CompactLocalized | Scaling::Exponential { prefixes: None, .. } | Scaling::None | Scaling::Percent(_) | Scaling::Scientific | Scaling::Strategy(_) => return None, // no unit prefixes to share, compact suffixes would be forced on numbers below the first step
+        };
+
+
+        magnitudes = values
+            .iter()
+            .map(|x| match self.rounding // rounded first like in format, in case rounding changes magnitude
+            {
+                Rounding::Magnitude(precision) => x.round_mag(precision),
+                Rounding::SignificantDigits(precision) => x.round_sig(precision),
+            })
+            .filter(|x| x.is_finite() && *x != 0.0)
+            .map(|x| match self.scaling
+            {
+                Scaling::Binary(_) => binary_magnitude(x) as f64,
+                Scaling::Exponential { base, .. } => x.abs().ln() / (base.max(2) as f64).ln(),
+                _ => decimal_magnitude(x) as f64,
+            })
+            .collect();
+        if magnitudes.is_empty()
+        // nothing to choose by
+        {
+            return None;
+        }
+        magnitudes.sort_by(|a, b| a.total_cmp(b));
+
+        chosen = match common_scale
+        {
+            CommonScale::Maximum => magnitudes[magnitudes.len() - 1],
+            CommonScale::Median => magnitudes[(magnitudes.len() - 1) / 2], // lower median, avoids mantissas greater than 1.000 of the middle number
+        };
+
+        return Some(
+            prefixes
+                .iter()
+                .find(|(lower, upper, _prefix)| *lower as f64 <= chosen && chosen < *upper as f64)
+                .unwrap_or(if chosen < 0.0 { &prefixes[0] } else { &prefixes[prefixes.len() - 1] }), // out of range: nearest prefix
+        );
+    }
+}
+
+
+/// # Summary
+/// Records whether a character at a distance from the alignment point is a separator, so padding at that distance in other rows becomes U+2008 PUNCTUATION SPACE.
+///
+/// # Arguments
+/// - `separators`: whether any row so far has a separator at each distance, grown as necessary
+/// - `distance`: distance of the character from the alignment point
+/// - `separator`: whether the character belongs to a group or decimal separator
+fn mark_separator(separators: &mut Vec<bool>, distance: usize, separator: bool)
+{
+    if separators.len() <= distance
+    {
+        separators.resize(distance + 1, false);
+    }
+    separators[distance] |= separator;
+}
+
+
+/// # Summary
+/// Chooses the padding character for a distance from the alignment point in a column padded with figure spaces.
+///
+/// # Arguments
+/// - `separators`: whether any row has a separator at each distance
+/// - `distance`: distance of the padding from the alignment point
+///
+/// # Returns
+/// - U+2008 PUNCTUATION SPACE if any row has a separator there, U+2007 FIGURE SPACE otherwise
+fn figure_space(separators: &[bool], distance: usize) -> char
+{
+    return if separators.get(distance).copied().unwrap_or(false) { '\u{2008}' } else { '\u{2007}' };
+}
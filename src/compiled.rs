@@ -0,0 +1,143 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::{Plan, Value};
+use crate::*;
+use std::fmt::Write;
+use std::sync::Arc;
+
+
+/// # Summary
+/// A formatter with everything that does not depend on the number precomputed, to format many numbers in a hot loop with less work per call. Created by `Formatter::compile`. Settings and precomputed state are shared, so clones are cheap and can be sent to other threads.
+#[derive(Clone, Debug)]
+pub struct CompiledFormatter
+{
+    formatter: Arc<Formatter>, // settings
+    plan:      Arc<Plan>,      // derived state
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Precomputes the derived state of this formatter, like the unit prefix for every magnitude and the power of the base to divide by, the length of the group separator, and the capacity to allocate for the result. The compiled formatter produces byte-identical output to this one. Later changes to this formatter do not affect it.
+    ///
+    /// # Returns
+    /// - the compiled formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(3))
+    ///     .set_unit("B");
+    /// let compiled: scaler::CompiledFormatter = f.compile();
+    /// assert_eq!(compiled.format(42069), "42,1 kB");
+    /// for x in [0.0, -0.789, 1e-40, 1234567.891, f64::INFINITY, f64::NAN]
+    /// {
+    ///     assert_eq!(compiled.format(x), f.format(x));
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// let compiled: scaler::CompiledFormatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .compile();
+    /// let threads: Vec<std::thread::JoinHandle<String>> = [1024, 3_000_000].into_iter()
+    ///     .map(|x| {
+    ///         let compiled: scaler::CompiledFormatter = compiled.clone(); // shares the settings
+    ///         return std::thread::spawn(move || compiled.format(x));
+    ///     })
+    ///     .collect();
+    /// assert_eq!(threads.into_iter().map(|thread| thread.join().unwrap()).collect::<Vec<String>>(), ["1,000 Ki", "2,861 Mi"]);
+    /// ```
+    pub fn compile(&self) -> CompiledFormatter
+    {
+        return CompiledFormatter { formatter: Arc::new(self.clone()), plan: Arc::new(Plan::new(self)) };
+    }
+}
+
+
+impl CompiledFormatter
+{
+    /// # Summary
+    /// Formats like `Formatter::format` with the formatter this was compiled from.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be losslessly convertable to f64, references are accepted as well
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let compiled: scaler::CompiledFormatter = scaler::Formatter::new().compile();
+    /// let numbers: Vec<f64> = vec![123.456, 0.789, 42069.0];
+    /// assert_eq!(numbers.iter().map(|x| compiled.format(x)).collect::<Vec<String>>(), ["123,5", "789,0 m", "42,07 k"]);
+    /// ```
+    pub fn format<T>(&self, x: T) -> String
+    where
+        T: IntoF64,
+    {
+        let mut s: String = String::with_capacity(self.plan.capacity()); // formatted number string, result
+        let x: f64 = x.into_f64();
+
+
+        self.formatter.expect_accepted(x);
+        self.format_into(x, &mut s).expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+
+
+    /// # Summary
+    /// Formats like `Formatter::format_into` with the formatter this was compiled from, writes the result incrementally into a sink instead of allocating a String.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `out`: the sink to write into
+    ///
+    /// # Returns
+    /// - nothing or the error of the sink
+    /// - or `std::fmt::Error` if the number is rejected by `NonFinitePolicy::Error`, before anything is written
+    ///
+    /// # Examples
+    /// ```
+    /// let compiled: scaler::CompiledFormatter = scaler::Formatter::new()
+    ///     .set_width(8, scaler::Align::Right, ' ')
+    ///     .compile();
+    /// let mut s: String = String::new();
+    /// for x in [500.0, -42069.0, 1e7]
+    /// {
+    ///     compiled.format_into(x, &mut s).unwrap();
+    ///     s.push('|');
+    /// }
+    /// assert_eq!(s, "   500,0|-42,07 k| 10,00 M|");
+    /// ```
+    pub fn format_into<T, W>(&self, x: T, out: &mut W) -> std::fmt::Result
+    where
+        T: Into<f64>,
+        W: Write + ?Sized,
+    {
+        return self.formatter.render_planned(Value::Float(x.into()), Some(&self.plan), &mut |_kind, s| out.write_str(s));
+    }
+
+
+    /// # Summary
+    /// The formatter this was compiled from, to use the output methods that are not precompiled.
+    ///
+    /// # Returns
+    /// - the formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let compiled: scaler::CompiledFormatter = f.compile();
+    /// assert_eq!(compiled.formatter(), &f);
+    /// assert_eq!(compiled.formatter().format_opt(None::<f64>), "—");
+    /// ```
+    pub fn formatter(&self) -> &Formatter
+    {
+        return &self.formatter;
+    }
+}
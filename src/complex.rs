@@ -0,0 +1,120 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a `num_complex::Complex64` in the notation set with `Formatter::set_complex_notation`, "a + bi" by default. Both parts go through the same scaling, rounding, separators, affixes, and unit as `Formatter::format`. The sign between real and imaginary part is derived from the imaginary part, so there is never "+ -". Not a number in either part results in a single not a number. The configured width applies to the complex number as a whole.
+    ///
+    /// # Arguments
+    /// - `z`: the complex number to format
+    ///
+    /// # Returns
+    /// - formatted complex number
+    ///
+    /// # Examples
+    /// ```
+    /// use num_complex::Complex64;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_complex(&Complex64::new(3.0, -4.5)), "3,000 - 4,500i");
+    /// assert_eq!(f.format_complex(&Complex64::new(-3.0, 4.5)), "-3,000 + 4,500i");
+    /// assert_eq!(f.format_complex(&Complex64::new(1500.0, 0.0025)), "1,500 k + 2,500i m");
+    /// assert_eq!(f.format_complex(&Complex64::new(1.0, f64::NEG_INFINITY)), "1,000 - ∞i");
+    /// assert_eq!(f.format_complex(&Complex64::new(f64::NAN, 1.0)), "NaN");
+    /// assert_eq!(f.format_complex(&Complex64::new(1.0, f64::NAN)), "NaN");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1))
+    ///     .set_sign(scaler::Sign::Always)
+    ///     .set_unit("V");
+    /// assert_eq!(f.format_complex(&Complex64::new(230.0, -12.25)), "+230,0 V - 12,2i V"); // sign of the imaginary part only between the parts
+    /// ```
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_complex_notation(scaler::ComplexNotation::Polar, "i")
+    ///     .set_width(22, scaler::Align::Right, '.');
+    /// assert_eq!(f.format_complex(&Complex64::new(3.0, 4.5)), "....5,408 ∠ 0,9828 rad");
+    /// assert_eq!(f.format_complex(&Complex64::new(3.0, -4.5)), "...5,408 ∠ -0,9828 rad");
+    /// assert_eq!(f.format_complex(&Complex64::new(0.0, 2000.0)), "...2,000 k ∠ 1,571 rad");
+    /// assert_eq!(f.format_complex(&Complex64::new(f64::NAN, 0.0)), "...................NaN");
+    /// ```
+    pub fn format_complex(&self, z: &num_complex::Complex64) -> String
+    {
+        let part: Formatter = Formatter { show_original: (ShowOriginal::Off, false), width: 0, ..self.clone() }; // parts are padded together, not individually
+        let s: String; // formatted complex number without padding
+
+
+        if z.re.is_nan() || z.im.is_nan()
+        // a single not a number instead of 2
+        {
+            return self.format(f64::NAN);
+        }
+
+        s = match self.complex_notation.0
+        {
+            ComplexNotation::CartesianCollapsed if z.im == 0.0 => part.format(z.re),
+            ComplexNotation::CartesianCollapsed if z.re == 0.0 => part.format_imaginary(z.im),
+            ComplexNotation::Cartesian | ComplexNotation::CartesianCollapsed => format!(
+                "{} {} {}",
+                part.format(z.re),
+                if z.im < 0.0 { "-" } else { "+" },
+                Formatter { sign: Sign::OnlyMinus, ..part.clone() }.format_imaginary(z.im.abs())
+            ),
+            ComplexNotation::Polar => format!(
+                "{} ∠ {} rad",
+                part.format(z.re.hypot(z.im)),
+                Formatter {
+                    affix_prefix: Text::Static(""),
+                    affix_suffix: Text::Static(""),
+                    scaling: Scaling::None,
+                    unit: Text::Static(""),
+                    zero_pad: 0,
+                    ..part.clone()
+                }
+                .format(z.im.atan2(z.re))
+            ),
+        };
+
+        return self.pad(s);
+    }
+
+
+    /// # Summary
+    /// Formats the imaginary part of a complex number, with the imaginary unit directly after the digits, before any unit prefix or unit.
+    ///
+    /// # Arguments
+    /// - `x`: the imaginary part to format
+    ///
+    /// # Returns
+    /// - formatted imaginary part
+    fn format_imaginary(&self, x: f64) -> String
+    {
+        let mut parts: Vec<(PartKind, String)> = Vec::new(); // pieces of the formatted number
+        let position: usize; // where to insert the imaginary unit
+
+
+        self.expect_accepted(x);
+        self.render(Value::Float(x), &mut |kind, part| {
+            parts.push((kind, part.to_string()));
+            return Ok(());
+        })
+        .expect("Writing into a Vec failed even though it is infallible.");
+
+        position = parts
+            .iter()
+            .rposition(|(kind, _)| {
+                matches!(
+                    kind,
+                    PartKind::DecimalSeparator | PartKind::ExponentDigits | PartKind::ExponentMarker | PartKind::FractionDigits | PartKind::GroupSeparator | PartKind::IntegerDigits | PartKind::Special
+                )
+            })
+            .map_or(parts.len(), |i| i + 1); // after the last digit
+        parts.insert(position, (PartKind::Literal, self.complex_notation.1.to_string()));
+
+        return parts.into_iter().map(|(_, part)| part).collect();
+    }
+}
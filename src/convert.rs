@@ -0,0 +1,85 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Numbers that `Formatter::format` accepts, converted losslessly to f64. Implemented for all types that convert to f64 without loss, f32, i8, i16, i32, u8, u16, u32, f64, and `ScaledNumber`, and for references to them, so generic code iterating over `&[T]` needs neither dereferences nor clones. Other types convertible into f64 can implement it themselves.
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new();
+/// let x: f32 = 0.5;
+/// let n: i32 = -42069;
+/// assert_eq!(f.format(&x), "500,0 m");
+/// assert_eq!(f.format(&n), "-42,07 k");
+/// assert_eq!(f.format(&&n), "-42,07 k");
+/// assert_eq!(f.format(&scaler::ScaledNumber(1234.5)), "1,234 k");
+/// let sizes: Vec<u32> = vec![1, 1000, 1_000_000];
+/// assert_eq!(sizes.iter().map(|x| f.format(x)).collect::<Vec<String>>(), ["1,000", "1,000 k", "1,000 M"]);
+/// ```
+///
+/// ```
+/// // generic code over slices of any supported number, without clones
+/// fn table<T>(f: &scaler::Formatter, values: &[T]) -> Vec<String>
+/// where
+///     T: scaler::IntoF64,
+///     for<'a> &'a T: scaler::IntoF64,
+/// {
+///     return values.iter().map(|x| f.format(x)).collect();
+/// }
+/// let f: scaler::Formatter = scaler::Formatter::new();
+/// assert_eq!(table(&f, &[1.5_f64, -2000.0]), ["1,500", "-2,000 k"]);
+/// assert_eq!(table(&f, &[3_u8, 250]), ["3,000", "250,0"]);
+/// ```
+///
+/// ```
+/// // own types opt in
+/// struct Celsius(f64);
+/// impl scaler::IntoF64 for &Celsius
+/// {
+///     fn into_f64(self) -> f64 { self.0 }
+/// }
+/// assert_eq!(scaler::Formatter::new().set_unit("°C").format(&Celsius(21.5)), "21,50 °C");
+/// ```
+pub trait IntoF64
+{
+    /// # Summary
+    /// Converts the number to f64 without loss.
+    ///
+    /// # Returns
+    /// - the number as f64
+    fn into_f64(self) -> f64;
+}
+
+
+/// # Summary
+/// Implements `IntoF64` for types that convert into f64 losslessly.
+macro_rules! impl_into_f64
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl IntoF64 for $t
+            {
+                fn into_f64(self) -> f64
+                {
+                    return self.into();
+                }
+            }
+        )*
+    };
+}
+
+
+impl_into_f64!(f32, f64, i8, i16, i32, u8, u16, u32, ScaledNumber);
+
+
+impl<T> IntoF64 for &T
+where
+    T: Copy + IntoF64,
+{
+    fn into_f64(self) -> f64
+    {
+        return (*self).into_f64();
+    }
+}
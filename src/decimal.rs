@@ -0,0 +1,109 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a `rust_decimal::Decimal` like `Formatter::format`, but exactly in decimal digit arithmetic without any f64, so sums like 0.1 + 0.2 are displayed as the 0.3 they are and all 28 significant digits are correct. Rounding is done on the decimal digits, ties to even, scaling shifts the decimal exponent, and binary unit prefixes divide exactly. Separators, sign, affixes, and unit work the same as in `Formatter::format`.
+    ///
+    /// # Arguments
+    /// - `d`: the decimal number to format
+    ///
+    /// # Returns
+    /// - formatted decimal number
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(20));
+    /// let sum: Decimal = Decimal::from_str("0.1").unwrap() + Decimal::from_str("0.2").unwrap();
+    /// assert_eq!(f.format_decimal(&sum), "0,30000000000000000000");
+    /// assert_eq!(f.format(0.1 + 0.2), "0,30000000000000004441"); // f64 is off
+    /// assert_eq!(f.format_decimal(&(Decimal::ONE / Decimal::from(3))), "0,33333333333333333333");
+    /// assert_eq!(scaler::Formatter::new().format_decimal(&sum), "300,0 m");
+    /// ```
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let d: Decimal = Decimal::from_str("1234567.89").unwrap();
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_decimal(&d), "1,235 M");
+    /// assert_eq!(f.format_decimal(&-d), "-1,235 M");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2));
+    /// assert_eq!(f.format_decimal(&d), "1,23456789 M");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(3));
+    /// assert_eq!(f.format_decimal(&d), "1,235 M");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(7));
+    /// assert_eq!(f.format_decimal(&d), "1,177376 Mi");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific);
+    /// assert_eq!(f.format_decimal(&d), "1,235 * 10^(6)");
+    /// ```
+    ///
+    /// ```
+    /// // all 28 significant digits and ties to even on the decimal digits
+    /// use rust_decimal::Decimal;
+    /// use std::str::FromStr;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(28));
+    /// assert_eq!(f.format_decimal(&Decimal::from_str("1.234567890123456789012345678").unwrap()), "1,234567890123456789012345678");
+    /// assert_eq!(f.format_decimal(&Decimal::MAX), "79.228.162.514.264.337.593.543.950.340");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(29));
+    /// assert_eq!(f.format_decimal(&Decimal::MAX), "79.228.162.514.264.337.593.543.950.335");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1));
+    /// for (s, expected) in [("0.25", "0,2"), ("0.35", "0,4"), ("-0.05", "-0,0"), ("0.0500000000000000000000000001", "0,1"), ("9.95", "10,0")]
+    /// {
+    ///     assert_eq!(f.format_decimal(&Decimal::from_str(s).unwrap()), expected, "{s}");
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// // same as format for numbers f64 displays exactly, except ties which f64 only approximates
+    /// use rust_decimal::Decimal;
+    /// let mut state: u64 = 1; // random number generator state
+    /// for scaling in [scaler::Scaling::Decimal(true), scaler::Scaling::None, scaler::Scaling::Scientific]
+    /// {
+    ///     for rounding in [scaler::Rounding::Magnitude(-3), scaler::Rounding::Magnitude(2), scaler::Rounding::SignificantDigits(2), scaler::Rounding::SignificantDigits(5)]
+    ///     {
+    ///         let f: scaler::Formatter = scaler::Formatter::new().set_scaling(scaler::Scaling::clone(&scaling)).set_rounding(rounding);
+    ///         for _ in 0..1000
+    ///         {
+    ///             state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///             let d: Decimal = Decimal::new((state >> 44) as i64 - (1 << 19), (state >> 40) as u32 % 12);
+    ///             let x: f64 = d.to_string().parse().unwrap();
+    ///             if x.to_string() == d.normalize().to_string() && !x.to_string().ends_with('5') && f.format(x).len() <= 15
+    ///             {
+    ///                 assert_eq!(f.format_decimal(&d), f.format(x), "{d}");
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn format_decimal(&self, d: &rust_decimal::Decimal) -> String
+    {
+        let mut s: String = String::new(); // formatted decimal number, result
+
+
+        self.render(Value::Exact(d.is_sign_negative(), d.mantissa().unsigned_abs(), d.scale()), &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+}
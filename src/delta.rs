@@ -0,0 +1,104 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats the change from an old to a new number, for example on dashboards. The parts set with `Formatter::set_delta` are displayed, by default the absolute change followed by the relative change in percent, "+1,200 k (+3,3 %)". Both are signed like `Sign::ExceptZero`, a change that is 0 after rounding is displayed as "±0". The absolute change goes through the configured scaling, rounding, separators, affixes, and unit, the relative change only through the separators. It is relative to the absolute value of the old number, so a decreasing change is always negative. If the old number is 0 or either is not finite, there is no relative change: it is left out next to the absolute change, and displayed as the placeholder set with `Formatter::set_none_string` on its own. The configured width applies to the result as a whole.
+    ///
+    /// # Arguments
+    /// - `old`: the number before the change
+    /// - `new`: the number after the change
+    ///
+    /// # Returns
+    /// - formatted change
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_delta(36000, 37200), "+1,200 k (+3,3 %)");
+    /// assert_eq!(f.format_delta(37200, 36000), "-1,200 k (-3,2 %)");
+    /// assert_eq!(f.format_delta(-200, -100), "+100,0 (+50,0 %)"); // relative to the absolute value
+    /// assert_eq!(f.format_delta(1000, 1000), "±0,000 (±0,0 %)");
+    /// assert_eq!(f.format_delta(0, 5), "+5,000"); // no relative change from 0
+    /// assert_eq!(f.format_delta(1.0, f64::INFINITY), "+∞");
+    /// assert_eq!(f.format_delta(1.0, f64::NAN), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_affixes("$", "")
+    ///     .set_delta(scaler::DeltaParts::AbsoluteAndPercent, 2)
+    ///     .set_width(20, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format_delta(1234.5, 1234.501), "    ±$0,00 (±0,00 %)"); // tiny change rounds to 0
+    /// assert_eq!(f.format_delta(100, 250), "+$150,00 (+150,00 %)");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_delta(scaler::DeltaParts::Percent, 0);
+    /// assert_eq!(f.format_delta(80, 100), "+25 %");
+    /// assert_eq!(f.format_delta(100.0, 99.9), "±0 %");
+    /// assert_eq!(f.format_delta(0, 100), "—");
+    /// ```
+    pub fn format_delta<T>(&self, old: T, new: T) -> String
+    where
+        T: Into<f64>,
+    {
+        let absolute: String; // formatted absolute change
+        let (old, new): (f64, f64) = (old.into(), new.into());
+        let part: Formatter = Formatter { show_original: (ShowOriginal::Off, false), width: 0, ..self.clone() }; // parts are padded together, not individually
+        let percent: Option<String>; // formatted relative change, none if undefined
+        let percent_formatter: Formatter = Formatter {
+            affix_prefix: Text::Static(""),
+            affix_suffix: Text::Static(""),
+            min_integer_digits: 0,
+            rounding: Rounding::Magnitude(-(self.delta.1 as i16)),
+            scaling: Scaling::None,
+            sign_placement: SignPlacement::Leading,
+            trailing_zeros: true,
+            unit: Text::Static("%"),
+            zero_pad: 0,
+            ..part.clone()
+        };
+        let relative: f64 = (new - old) / old.abs() * 100.0; // relative change in percent
+
+
+        absolute = part.format_change(new - old);
+        percent = if old != 0.0 && relative.is_finite() { Some(percent_formatter.format_change(relative)) } else { None };
+
+        return self.pad(match (&self.delta.0, percent)
+        {
+            (DeltaParts::Absolute, _) | (DeltaParts::AbsoluteAndPercent, None) => absolute,
+            (DeltaParts::AbsoluteAndPercent, Some(percent)) => format!("{absolute} ({percent})"),
+            (DeltaParts::Percent, Some(percent)) => percent,
+            (DeltaParts::Percent, None) => part.format_opt(None::<f64>),
+        });
+    }
+
+
+    /// # Summary
+    /// Formats a change signed like `Sign::ExceptZero`, but with "±" instead of no sign if it is 0 after rounding.
+    ///
+    /// # Arguments
+    /// - `x`: the change to format
+    ///
+    /// # Returns
+    /// - formatted change
+    fn format_change(&self, x: f64) -> String
+    {
+        let signed: FormattedParts = Formatter { sign: Sign::Always, ..self.clone() }.format_to_parts(x); // with sign where it would be
+        let zero: bool = !x.is_nan() && !Formatter { sign: Sign::ExceptZero, ..self.clone() }.format_to_parts(x).iter().any(|part| part.kind == PartKind::Sign); // 0 after rounding, as only that is unsigned
+
+
+        if !zero
+        {
+            return signed.to_string();
+        }
+
+        return signed.into_iter().map(|part| if part.kind == PartKind::Sign { "±".to_string() } else { part.value }).collect();
+    }
+}
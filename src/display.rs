@@ -0,0 +1,148 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+/// # Summary
+/// A number together with the formatter to display it with. Formats lazily when displayed, so it can be embedded in `format!` or `println!` without an intermediate String. Created by `Formatter::display` and `Formatter::display_opt`.
+#[derive(Clone, Copy, Debug)]
+pub struct Scaled<'a>
+{
+    pub(crate) formatter: &'a Formatter,
+    pub(crate) x:         Option<f64>, // number, none for placeholder
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Wraps the number so it is formatted lazily when displayed. The output is identical to `Formatter::format`.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the displayable number, borrows the formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(format!("rate: {}/s", f.display(42069)), "rate: 42,07 k/s");
+    /// assert_eq!(format!("[{}, {}]", f.display(f64::NEG_INFINITY), f.display(f64::NAN)), "[-∞, NaN]");
+    /// assert_eq!(f.display(0.789).to_string(), f.format(0.789));
+    /// ```
+    pub fn display<T>(&self, x: T) -> Scaled<'_>
+    where
+        T: Into<f64>,
+    {
+        return Scaled { formatter: self, x: Some(x.into()) };
+    }
+
+
+    /// # Summary
+    /// Wraps the optional number so it is formatted lazily when displayed. The output is identical to `Formatter::format_opt`.
+    ///
+    /// # Arguments
+    /// - `x`: the optional number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the displayable number or placeholder, borrows the formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(format!("rate: {}/s", f.display_opt(Some(42069))), "rate: 42,07 k/s");
+    /// assert_eq!(format!("rate: {}/s", f.display_opt(None::<f64>)), "rate: —/s");
+    /// assert_eq!(format!("|{:>5}|{:<5.1}|", f.display_opt(None::<f64>), f.display_opt(None::<f64>)), "|    —|—    |"); // padded, precision is ignored
+    /// assert_eq!(f.display_opt(None::<f64>).to_string(), f.format_opt(None::<f64>));
+    /// ```
+    pub fn display_opt<T>(&self, x: Option<T>) -> Scaled<'_>
+    where
+        T: Into<f64>,
+    {
+        return Scaled { formatter: self, x: x.map(Into::into) };
+    }
+}
+
+
+impl std::fmt::Display for Scaled<'_>
+{
+    /// # Summary
+    /// Formats the number, honouring the flags of the format spec. Width, fill, and alignment pad the formatted number, precision sets the number of decimal places of the mantissa. Flags that are absent fall back to the settings of the formatter.
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(format!("{:10}", f.display(1234.5)), "   1,234 k"); // formatter's alignment is right by default
+    /// assert_eq!(format!("{:<10}", f.display(1234.5)), "1,234 k   ");
+    /// assert_eq!(format!("{:^10}", f.display(1234.5)), " 1,234 k  ");
+    /// assert_eq!(format!("{:*>10}", f.display(1234.5)), "***1,234 k");
+    /// assert_eq!(format!("{:3}", f.display(1234.5)), "1,234 k"); // never truncates
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(format!("{:.1}", f.display(1234.5)), "1,2 k");
+    /// assert_eq!(format!("{:.0}", f.display(1234.5)), "1 k");
+    /// assert_eq!(format!("{:.2}", f.display(0.000789)), "789,00 µ");
+    /// assert_eq!(format!("{:.1}", f.display(999960)), "1,0 M"); // rounding carries over into next unit prefix
+    /// assert_eq!(format!("{:.2}", f.display(1e40)), "1,00 * 10^(40)");
+    /// assert_eq!(format!("{:.2}", f.display(0)), "0,00");
+    /// assert_eq!(format!("{:.2}", f.display(f64::NAN)), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None);
+    /// assert_eq!(format!("{:.1}", f.display(1234.56)), "1.234,6");
+    /// assert_eq!(format!("{:.3}", f.display(0.5)), "0,500");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(8, scaler::Align::Left, '_');
+    /// assert_eq!(format!("{:>10.1}", f.display(1234.5)), "     1,2 k");
+    /// assert_eq!(format!("{:.1}", f.display(1234.5)), "1,2 k___"); // width of formatter when absent
+    /// assert_eq!(format!("{:12}", f.display(1234.5)), "1,234 k_____"); // alignment and fill of formatter when absent
+    /// assert_eq!(format!("{}", f.display(1234.5)), f.format(1234.5));
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let mut formatter: Formatter; // formatter with flags of format spec applied
+
+
+        if f.width().is_none() && f.precision().is_none()
+        // no flags, avoid cloning
+        {
+            return self.formatter.render(self.x.map_or(Value::None, Value::Float), &mut |_kind, s| f.write_str(s));
+        }
+
+        formatter = self.formatter.clone();
+        if let (Some(decimals), Some(x)) = (f.precision(), self.x)
+        // placeholder has no decimal places
+        {
+            formatter.rounding = self.formatter.rounding_for_decimals(x, decimals);
+        }
+        if let Some(width) = f.width()
+        {
+            formatter.width = width;
+        }
+        if let Some(align) = f.align()
+        // fill can only be given together with alignment
+        {
+            formatter.align = match align
+            {
+                std::fmt::Alignment::Center => Align::Center,
+                std::fmt::Alignment::Left => Align::Left,
+                std::fmt::Alignment::Right => Align::Right,
+            };
+            formatter.fill = f.fill();
+        }
+
+        return formatter.render(self.x.map_or(Value::None, Value::Float), &mut |_kind, s| f.write_str(s));
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Which coordinate an angle of `Formatter::format_dms` is, determines the hemisphere letters replacing its sign.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis
+{
+    Latitude,  // "N" for north, "S" for south
+    Longitude, // "E" for east, "W" for west
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats an angle in decimal degrees as degrees, minutes, and seconds, for coordinates in GIS, like "48° 51′ 30,2″ N" for 48.8584 as latitude. Minutes and seconds are padded to 2 integer digits. The seconds are rounded to the digit at 10^n for `Rounding::Magnitude(n)`, and for `Rounding::SignificantDigits(n)` to n significant digits of a seconds value with 2 integer digits, so all angles get the same decimal places, at most 9 and none for positive magnitudes. The angle is rounded as a whole, exactly and ties to even, so seconds rounding up to 60 carry into the minutes and further into the degrees, like 59,99″ to "11° 00′ 00,0″". The decimal separator, the group separator of the degrees, and trailing zeros apply, the symbols are set with `Formatter::set_dms_symbols`. With an axis, the hemisphere letter after the angle replaces the sign, otherwise the sign is displayed before it like in `Formatter::format`. Negative angles rounded to 0 keep their sign or southern and western hemisphere, -0 is positive. Infinity and NaN are formatted like in `Formatter::format`. The configured width applies to the result as a whole, the other options do not.
+    ///
+    /// # Arguments
+    /// - `degrees`: the angle in decimal degrees
+    /// - `axis`: latitude or longitude for a hemisphere letter, or none for a signed angle
+    ///
+    /// # Returns
+    /// - formatted angle
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::Axis;
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_rounding(scaler::Rounding::Magnitude(-1));
+    /// assert_eq!(f.format_dms(48.8584, Some(Axis::Latitude)), "48° 51′ 30,2″ N");
+    /// assert_eq!(f.format_dms(-2.2945, Some(Axis::Longitude)), "2° 17′ 40,2″ W");
+    /// assert_eq!(f.format_dms(-2.2945, None), "-2° 17′ 40,2″");
+    /// assert_eq!(f.format_dms(10.999999, None), "11° 00′ 00,0″"); // 59,9964″ carried
+    /// assert_eq!(f.format_dms(0.0, Some(Axis::Latitude)), "0° 00′ 00,0″ N");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new() // default rounding to 4 significant digits, 2 decimal places of seconds
+    ///     .set_dms_symbols("d", "m", "s");
+    /// assert_eq!(f.format_dms(48.8584, None), "48d 51m 30,24s");
+    /// let f: scaler::Formatter = f.set_rounding(scaler::Rounding::Magnitude(0));
+    /// assert_eq!(f.format_dms(359.99999, None), "360d 00m 00s");
+    /// ```
+    pub fn format_dms(&self, degrees: f64, axis: Option<Axis>) -> String
+    {
+        let bits: u64 = degrees.to_bits();
+        let decimals: u32 = match self.rounding // decimal places of the seconds
+        {
+            Rounding::Magnitude(magnitude) => (-(magnitude as i32)).clamp(0, 9) as u32,
+            Rounding::SignificantDigits(precision) => (precision as i32 - 2).clamp(0, 9) as u32,
+        };
+        let degrees_digits: String; // integer degrees, grouped
+        let negative: bool = degrees.is_sign_negative() && degrees != 0.0; // -0 is positive
+        let per_second: u128 = 10_u128.pow(decimals); // units per second
+        let seconds: String; // seconds with decimal places
+        let sign: &str; // before the angle, empty if replaced by the hemisphere letter
+        let units: u128; // absolute angle in multiples of 10^(-decimals) seconds, rounded, if below 2^53 degrees
+
+
+        if !degrees.is_finite()
+        // no angle
+        {
+            return self.format(degrees);
+        }
+
+        if 2_f64.powi(53) <= degrees.abs()
+        // always whole degrees, possibly beyond u128
+        {
+            degrees_digits = self.group_digits(&format!("{:.0}", degrees.abs())).expect("Integer digits are always valid.");
+            units = 0;
+        }
+        else
+        {
+            let (significand, exponent): (u128, i64) = match bits >> 52 & 0x7FF
+            {
+                0 => ((bits & ((1 << 52) - 1)) as u128, -1074), // subnormal
+                biased => ((bits & ((1 << 52) - 1) | 1 << 52) as u128, biased as i64 - 1075), // with implicit leading 1
+            };
+            let product: u128 = significand * 3600 * per_second; // |degrees| in units = product * 2^exponent exactly, below 2^96
+            let all: u128 = match exponent
+            {
+                0.. => product << exponent, // only 0 for numbers below 2^53
+                -127..=-1 =>
+                {
+                    let half: u128 = 1 << (-exponent - 1);
+                    let remainder: u128 = product & ((1 << -exponent) - 1);
+                    let truncated: u128 = product >> -exponent;
+                    if half < remainder || (half == remainder && truncated % 2 == 1) { truncated + 1 } else { truncated } // ties to even
+                }
+                _ => 0, // product * 2^exponent below 2^(-31), rounds to 0
+            }; // units of the whole angle, rounded once so seconds rounding up carry
+
+            degrees_digits = self.group_int(all / (3600 * per_second));
+            units = all % (3600 * per_second);
+        }
+
+        seconds = {
+            let mut seconds: String = format!("{:02}", units % (60 * per_second) / per_second);
+            let mut fraction: String = if decimals == 0 { String::new() } else { format!("{:0width$}", units % per_second, width = decimals as usize) }; // width 0 would still display a digit
+
+            if !self.trailing_zeros
+            {
+                fraction.truncate(fraction.trim_end_matches('0').len());
+            }
+            if !fraction.is_empty()
+            {
+                seconds.push_str(&self.decimal_separator);
+                seconds.push_str(&fraction);
+            }
+            seconds
+        };
+        sign = match axis
+        {
+            Some(_) => "",
+            None => self.sign_str(negative, degrees_digits == "0" && units == 0),
+        };
+
+        return self.pad(format!(
+            "{sign}{degrees_digits}{} {:02}{} {seconds}{}{}",
+            self.dms_symbols.0,
+            units / (60 * per_second),
+            self.dms_symbols.1,
+            self.dms_symbols.2,
+            match (axis, negative)
+            {
+                (None, _) => "",
+                (Some(Axis::Latitude), false) => " N",
+                (Some(Axis::Latitude), true) => " S",
+                (Some(Axis::Longitude), false) => " E",
+                (Some(Axis::Longitude), true) => " W",
+            }
+        ));
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Size of the stack buffer `Formatter::format_defmt` formats into. Covers every formatter with unit prefixes and moderate affixes, longer results are streamed piece by piece instead.
+const DEFMT_BUFFER_LEN: usize = 64;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats like `Formatter::format`, but writes the result into a `defmt` formatter without any heap allocation, for logging on embedded targets. The number is formatted into a stack buffer with `Formatter::format_to_slice` and sent as a single `{=str}`. If the result does not fit into the buffer, for example without scaling and with many significant digits, it is streamed piece by piece instead, still without allocation.
+    ///
+    /// Note the tradeoff: defmt usually sends only an index of the interned format string and the raw bytes of primitives, formatting happens on the host. The formatted number is a string decided at runtime, so it is formatted on the target and sent in full, which costs more time on the target and more bytes on the wire. If that matters more than readable logs, log the raw f64 with `{=f64}` instead.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `fmt`: the defmt formatter to write into
+    ///
+    /// # Examples
+    /// ```
+    /// struct Reading
+    /// {
+    ///     voltage: f64,
+    /// }
+    ///
+    /// impl defmt::Format for Reading
+    /// {
+    ///     fn format(&self, fmt: defmt::Formatter)
+    ///     {
+    ///         static F: scaler::Formatter = scaler::Formatter::new().set_unit_const("V");
+    ///         defmt::write!(fmt, "voltage: ");
+    ///         F.format_defmt(self.voltage, fmt);
+    ///     }
+    /// }
+    /// ```
+    pub fn format_defmt<T>(&self, x: T, fmt: ::defmt::Formatter<'_>)
+    where
+        T: Into<f64>,
+    {
+        let mut buf: [u8; DEFMT_BUFFER_LEN] = [0; DEFMT_BUFFER_LEN]; // formatted number, if it fits
+        let x: f64 = x.into();
+
+
+        match self.format_to_slice(x, &mut buf)
+        {
+            Ok(s) => ::defmt::write!(fmt, "{=str}", s),
+            Err(FormatToSliceError::BufferTooSmall(_)) => ::defmt::write!(fmt, "{}", ::defmt::Display2Format(&self.display(x))), // too long, stream through Display instead
+            Err(FormatToSliceError::NonFinite(error)) => ::defmt::write!(fmt, "{}", ::defmt::Display2Format(&error)), // rejected, log why
+        }
+    }
+}
+
+
+impl ::defmt::Format for Scaled<'_>
+{
+    /// # Summary
+    /// Formats the number with `Formatter::format_defmt`, so `defmt::info!("{}", f.display(x))` logs it like `Formatter::format` would format it. The placeholder of `Formatter::display_opt` is sent as is.
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_unit("B");
+    /// let received: usize = 42069;
+    /// defmt::info!("Received {}.", f.display(received as f64)); // "Received 42,07 kB."
+    /// defmt::info!("Quota {}.", f.display_opt(None::<f64>)); // "Quota —."
+    /// ```
+    fn format(&self, fmt: ::defmt::Formatter<'_>)
+    {
+        match self.x
+        {
+            Some(x) => self.formatter.format_defmt(x, fmt),
+            None => ::defmt::write!(fmt, "{=str}", &self.formatter.none_string),
+        }
+    }
+}
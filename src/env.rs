@@ -0,0 +1,199 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::from_env` if an environment variable is set to a malformed value. Contains which variable and what the problem is.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnvError
+{
+    pub kind:     EnvErrorKind, // what the problem is
+    pub variable: String,       // name of the malformed variable
+}
+
+
+/// # Summary
+/// What kind of problem `EnvError` describes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnvErrorKind
+{
+    InvalidOption(ParseOptionError),    // value is no string form of its option
+    NotUnicode,                         // value is not valid unicode
+    ProblematicSeparators(ConfigError), // separators may lead to ambiguous formatting, contains the problem
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Constructs a formatter from environment variables, to change the formatting of deployed services without changing code. Unset variables keep the defaults of `Formatter::new`. With `prefix` "SCALER", the variables are:
+    /// - `SCALER_DECIMAL_SEP`: decimal separator
+    /// - `SCALER_GROUP_SEP`: group separator, empty to not group
+    /// - `SCALER_PRECISION`: rounding as parsed by `Rounding::from_str`, like "sig:4" or "mag:-2"
+    /// - `SCALER_SCALING`: scaling as parsed by `Scaling::from_str`, like "binary+space" or "none"
+    /// - `SCALER_SIGN`: sign behaviour as parsed by `Sign::from_str`, like "always"
+    ///
+    /// # Arguments
+    /// - `prefix`: prefix of the variable names, separated from them by "_"
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or `EnvError` naming the first malformed variable, separators are rejected in the cases `Formatter::set_separators` would warn about
+    ///
+    /// # Examples
+    /// ```
+    /// std::env::set_var("DOCTEST_FROM_ENV_PRECISION", "mag:-2");
+    /// std::env::set_var("DOCTEST_FROM_ENV_SCALING", "none");
+    /// std::env::set_var("DOCTEST_FROM_ENV_GROUP_SEP", ",");
+    /// std::env::set_var("DOCTEST_FROM_ENV_DECIMAL_SEP", ".");
+    /// let f: scaler::Formatter = scaler::Formatter::from_env("DOCTEST_FROM_ENV").unwrap();
+    /// assert_eq!(f.format(1234.5), "1,234.50");
+    ///
+    /// std::env::set_var("DOCTEST_FROM_ENV_SIGN", "never");
+    /// assert_eq!(
+    ///     scaler::Formatter::from_env("DOCTEST_FROM_ENV").unwrap_err().to_string(),
+    ///     "Environment variable DOCTEST_FROM_ENV_SIGN: Invalid sign \"never\", expected \"always\", \"except_zero\", \"only_minus\", or \"space_for_positive\"."
+    /// );
+    /// ```
+    pub fn from_env(prefix: &str) -> Result<Self, EnvError>
+    {
+        let decimal_separator: Option<String> = read_env(prefix, "DECIMAL_SEP")?; // configured decimal separator
+        let mut f: Formatter = Formatter::new(); // result
+        let group_separator: Option<String> = read_env(prefix, "GROUP_SEP")?; // configured group separator
+
+
+        if let Some(rounding) = parse_env::<Rounding>(prefix, "PRECISION")?
+        {
+            f = f.set_rounding(rounding);
+        }
+        if let Some(scaling) = parse_env::<Scaling>(prefix, "SCALING")?
+        {
+            f = f.set_scaling(scaling);
+        }
+        if let Some(sign) = parse_env::<Sign>(prefix, "SIGN")?
+        {
+            f = f.set_sign(sign);
+        }
+
+        if group_separator.is_some() || decimal_separator.is_some()
+        {
+            let decimal_separator: String = decimal_separator.unwrap_or_else(|| f.decimal_separator.to_string()); // configured or default
+            let group_separator: String = group_separator.unwrap_or_else(|| f.group_separator.to_string()); // configured or default
+
+            if let Some(problem) = separator_problem(&group_separator, &decimal_separator)
+            {
+                let decimal_at_fault: bool = matches!(problem, ConfigError::DigitInDecimalSeparator(_) | ConfigError::EmptyDecimalSeparator); // otherwise problem is in group separator or in both
+                return Err(EnvError {
+                    kind:     EnvErrorKind::ProblematicSeparators(problem),
+                    variable: format!("{prefix}_{}", if decimal_at_fault { "DECIMAL_SEP" } else { "GROUP_SEP" }),
+                });
+            }
+            f = f.set_separators(&group_separator, &decimal_separator);
+        }
+
+        return Ok(f);
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter from environment variables like `Formatter::from_env`, but falls back to the defaults of `Formatter::new` if any variable is malformed, so a typo in the deployment does not stop the service. The problem is logged with `log::warn!` if the feature `log` is enabled.
+    ///
+    /// # Arguments
+    /// - `prefix`: prefix of the variable names, separated from them by "_"
+    ///
+    /// # Returns
+    /// - the formatter configured by the environment or the default formatter
+    ///
+    /// # Examples
+    /// ```
+    /// std::env::set_var("DOCTEST_FROM_ENV_OR_DEFAULT_SCALING", "binary+space");
+    /// assert_eq!(scaler::Formatter::from_env_or_default("DOCTEST_FROM_ENV_OR_DEFAULT").format(1536), "1,500 Ki");
+    ///
+    /// std::env::set_var("DOCTEST_FROM_ENV_OR_DEFAULT_PRECISION", "4");
+    /// assert_eq!(scaler::Formatter::from_env_or_default("DOCTEST_FROM_ENV_OR_DEFAULT"), scaler::Formatter::new()); // malformed, defaults
+    /// ```
+    pub fn from_env_or_default(prefix: &str) -> Self
+    {
+        return Self::from_env(prefix)
+            .inspect_err(|_e| {
+                #[cfg(feature = "log")] // log if feature is enabled
+                log::warn!("{_e} Using default settings instead.");
+            })
+            .unwrap_or_default();
+    }
+}
+
+
+/// # Summary
+/// Parses an environment variable with the `FromStr` implementation of its option.
+///
+/// # Arguments
+/// - `prefix`: prefix of the variable name
+/// - `name`: variable name after the prefix
+///
+/// # Returns
+/// - the option or none if the variable is not set
+/// - or `EnvError` if the value is malformed
+fn parse_env<T>(prefix: &str, name: &str) -> Result<Option<T>, EnvError>
+where
+    T: std::str::FromStr<Err = ParseOptionError>,
+{
+    return match read_env(prefix, name)?
+    {
+        Some(value) => value.parse::<T>().map(Some).map_err(|e| EnvError { kind: EnvErrorKind::InvalidOption(e), variable: format!("{prefix}_{name}") }),
+        None => Ok(None),
+    };
+}
+
+
+/// # Summary
+/// Reads an environment variable.
+///
+/// # Arguments
+/// - `prefix`: prefix of the variable name
+/// - `name`: variable name after the prefix
+///
+/// # Returns
+/// - the value or none if the variable is not set
+/// - or `EnvError` if the value is not valid unicode
+fn read_env(prefix: &str, name: &str) -> Result<Option<String>, EnvError>
+{
+    let variable: String = format!("{prefix}_{name}"); // full variable name
+
+
+    return match std::env::var(&variable)
+    {
+        Ok(value) => Ok(Some(value)),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => Err(EnvError { kind: EnvErrorKind::NotUnicode, variable }),
+    };
+}
+
+
+impl std::fmt::Display for EnvError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "Environment variable {}: ", self.variable)?;
+        return match &self.kind
+        {
+            EnvErrorKind::InvalidOption(e) => write!(f, "{e}"),
+            EnvErrorKind::NotUnicode => write!(f, "Value is not valid unicode."),
+            EnvErrorKind::ProblematicSeparators(problem) => write!(f, "{problem}"),
+        };
+    }
+}
+
+
+impl std::error::Error for EnvError
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        return match &self.kind
+        {
+            EnvErrorKind::InvalidOption(e) => Some(e),
+            EnvErrorKind::NotUnicode => None,
+            EnvErrorKind::ProblematicSeparators(e) => Some(e),
+        };
+    }
+}
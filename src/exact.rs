@@ -0,0 +1,108 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::{Digits, Value};
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats the exact decimal expansion of the stored f64, for chasing floating-point bugs, "0,1000000000000000055511151231257827021181583404541015625" for 0.1. Every finite f64 is an integer times a power of 2, so its expansion is finite, but up to 767 significant digits and 1074 fraction digits long for subnormals. The digits are computed in decimal digit arithmetic and displayed without scaling or rounding, whatever is configured, so there are never trailing zeros. Separators, sign, affixes, unit, and padding apply like in `Formatter::format`, the fraction digits are not grouped. Expansions longer than set with `Formatter::set_exact_max_digits` are cut off and marked with "…". -0 is unsigned like in `Formatter::format`, infinity and NaN are formatted like there.
+    ///
+    /// # Arguments
+    /// - `x`: the number to expand
+    ///
+    /// # Returns
+    /// - formatted exact decimal expansion
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_exact(0.1), "0,1000000000000000055511151231257827021181583404541015625");
+    /// assert_eq!(f.format_exact(-2.5), "-2,5");
+    /// assert_eq!(f.format_exact(1e23), "99.999.999.999.999.991.611.392"); // nearest f64 below
+    /// assert_eq!(f.format_exact(0.0), "0");
+    /// assert_eq!(f.format_exact(-0.0), "0");
+    /// assert_eq!(f.format_exact(f64::INFINITY), "∞");
+    /// assert_eq!(f.format_exact(f64::NAN), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators("", ".")
+    ///     .set_unit("V");
+    /// assert_eq!(f.format_exact(f64::from_bits(1)).len(), 2 + 1074 + 2); // smallest subnormal, 2^(-1074)
+    /// assert!(f.format_exact(f64::from_bits(1)).starts_with(&format!("0.{}49406564584124654417656879286", "0".repeat(323))));
+    /// assert!(f.format_exact(f64::from_bits(1)).ends_with("4565229087538682506419718265533447265625 V"));
+    /// assert!(f.format_exact(f64::MAX).starts_with("179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558632766878171540458953514382464234321326889464182768467546703537516986049910576551282076245490090389328944075868508455133942304583236903222948165808559332123348274797826204144723168738177180919299881250404026184124858368"));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_exact_max_digits(10)
+    ///     .set_affixes("", " ")
+    ///     .set_sign_placement(scaler::SignPlacement::Trailing);
+    /// assert_eq!(f.format_exact(-0.1), "0,1000000000… -"); // cut off before the suffix and trailing sign
+    /// assert_eq!(f.format_exact(1.0 / 3.0), "0,3333333333… ");
+    /// assert_eq!(f.format_exact(0.25), "0,25 ");
+    /// ```
+    pub fn format_exact(&self, x: f64) -> String
+    {
+        let bits: u64 = x.to_bits();
+        let mut digits: String; // displayed digits without decimal point
+        let mut ellipsis_due: bool; // whether the ellipsis still has to be inserted after the fraction digits
+        let mut expansion: Digits; // exact decimal expansion of the absolute value
+        let fraction_len: usize; // number of fraction digits of the exact expansion
+        let mut fraction_seen: bool = false; // whether fraction digits have been emitted
+        let mut s: String = String::new(); // formatted expansion without padding
+        let shown_len: usize; // number of fraction digits displayed
+
+
+        if !x.is_finite()
+        // no expansion
+        {
+            return self.format(x);
+        }
+
+        fraction_len = match (bits >> 52 & 0x7FF, bits & ((1 << 52) - 1))
+        {
+            _ if x == 0.0 => 0,
+            (0, significand) => (1074 - significand.trailing_zeros() as i64) as usize, // subnormal, significand * 2^(-1074)
+            (biased, significand) => (1075 - biased as i64 - (significand | 1 << 52).trailing_zeros() as i64).max(0) as usize, // significand with implicit leading 1 * 2^(biased - 1075), 2^(-n) has exactly n fraction digits
+        };
+        shown_len = if self.exact_max_digits == 0 { fraction_len } else { fraction_len.min(self.exact_max_digits) };
+
+        expansion = Digits::from_f64(x.abs());
+        expansion.round(fraction_len); // exact, only writes out all digits
+        digits = expansion.to_string().replace('.', "");
+        digits.truncate(digits.len() - (fraction_len - shown_len)); // cut off without rounding
+
+        ellipsis_due = shown_len < fraction_len;
+        Formatter {
+            rounding: Rounding::Magnitude(-(shown_len as i16)), // digits as they are
+            scaling: Scaling::None,
+            show_original: (ShowOriginal::Off, false), // the expansion is the original
+            trailing_zeros: true, // zeros before the cut off are digits of the expansion
+            width: 0, // padded with the ellipsis
+            ..self.clone()
+        }
+        .render(Value::Big(x.is_sign_negative() && x != 0.0, &digits, -(shown_len as i64)), &mut |kind, part| {
+            if ellipsis_due && fraction_seen && kind != PartKind::FractionDigits
+            // right after the last fraction digit, before unit, affix, or trailing sign
+            {
+                s.push('…');
+                ellipsis_due = false;
+            }
+            fraction_seen |= kind == PartKind::FractionDigits;
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        if ellipsis_due
+        // fraction digits were last
+        {
+            s.push('…');
+        }
+
+        return self.pad(s);
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+
+
+/// # Summary
+/// Which fields of a line `FieldProcessor` reformats. Fields are counted from 1 like in `numfmt --field`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FieldSelector
+{
+    Index(usize),                 // a single field
+    Numeric,                      // every field that parses as a number and contains a digit, the others are left as they are
+    Range(RangeInclusive<usize>), // consecutive fields, like `2..=usize::MAX` for all from the second on
+}
+
+
+/// # Summary
+/// Reformats selected fields of text lines, like `numfmt --field` as a library call: each selected field is parsed with `Parser::parse_lenient` and replaced by the number formatted with the formatter. Everything else, including the whitespace between and around fields, is kept byte for byte. Created by `Formatter::field_processor`.
+#[derive(Clone, Debug)]
+pub struct FieldProcessor
+{
+    delimiter: Option<char>, // character between fields, none for runs of whitespace
+    formatter: Formatter,    // formats the selected fields
+    header:    usize,        // number of lines at the start of the input to keep as they are
+    parser:    Parser,       // parses the selected fields
+    selector:  FieldSelector,
+    strict:    bool,         // whether a selected field that is no number is an error instead of being kept
+}
+
+
+/// # Summary
+/// Iterator over the fields of a line, yields start and end of every field without the whitespace around it.
+struct Fields<'a>
+{
+    delimiter: Option<char>,
+    line:      &'a str,
+    position:  Option<usize>, // where to search for the next field, none if exhausted
+}
+
+
+impl Iterator for Fields<'_>
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let end: usize; // end of the field, at the delimiter or end of line
+        let start: usize = self.position?; // start of the field
+        let trimmed_start: usize; // start of the field after leading whitespace
+
+
+        match self.delimiter
+        {
+            None =>
+            // runs of whitespace separate fields, so a field never contains whitespace
+            {
+                trimmed_start = self.line.len() - self.line[start..].trim_start().len();
+                if trimmed_start == self.line.len()
+                // only whitespace left
+                {
+                    self.position = None;
+                    return None;
+                }
+                end = self.line[trimmed_start..].find(char::is_whitespace).map_or(self.line.len(), |offset| trimmed_start + offset);
+                self.position = Some(end);
+                return Some((trimmed_start, end));
+            }
+            Some(delimiter) =>
+            {
+                match self.line[start..].find(delimiter)
+                {
+                    Some(offset) =>
+                    {
+                        end = start + offset;
+                        self.position = Some(end + delimiter.len_utf8());
+                    }
+                    None =>
+                    {
+                        end = self.line.len();
+                        self.position = None;
+                    }
+                }
+                trimmed_start = end - self.line[start..end].trim_start().len();
+                return Some((trimmed_start, start + self.line[start..end].trim_end().len().max(trimmed_start - start)));
+            }
+        }
+    }
+}
+
+
+impl FieldProcessor
+{
+    /// # Summary
+    /// Reformats the selected fields of a single line, for streaming input line by line. Header lines are not skipped here, pass them on unchanged instead.
+    ///
+    /// # Arguments
+    /// - `line`: the line, without or with its line break
+    ///
+    /// # Returns
+    /// - the line with the selected fields reformatted, borrowed if nothing changed
+    /// - or `ParseError` of the first selected field that is no number if strict, with the index of the field counted from 0 and the position in `line`
+    ///
+    /// # Examples
+    /// ```
+    /// let processor: scaler::FieldProcessor = scaler::Formatter::new()
+    ///     .set_unit("B")
+    ///     .field_processor(scaler::FieldSelector::Index(2));
+    /// assert_eq!(processor.process_line("disk.img   1048576  2024-01-01"), Ok("disk.img   1,049 MB  2024-01-01".into()));
+    /// assert!(matches!(processor.process_line("notes.txt  -  2024-01-01"), Ok(std::borrow::Cow::Borrowed(_)))); // no number, unchanged
+    /// ```
+    pub fn process_line<'a>(&self, line: &'a str) -> Result<Cow<'a, str>, ParseError>
+    {
+        let mut copied: usize = 0; // end of the part of the line already in `processed`
+        let mut processed: Option<String> = None; // reformatted line so far, none as long as nothing changed
+
+
+        for (index, (start, end)) in (Fields { delimiter: self.delimiter, line, position: Some(0) }).enumerate()
+        {
+            let x: f64; // number of the field
+
+            if !match &self.selector
+            {
+                FieldSelector::Index(selected) => *selected == index + 1,
+                FieldSelector::Numeric => line[start..end].bytes().any(|b| b.is_ascii_digit()), // not words like "inf"
+                FieldSelector::Range(selected) => selected.contains(&(index + 1)),
+            }
+            // not selected
+            {
+                continue;
+            }
+            match self.parser.parse_number(line, start, end, true, 0)
+            {
+                Ok(parsed) => x = parsed,
+                Err(e) if self.strict && self.selector != FieldSelector::Numeric => return Err(ParseError { field: Some(index), ..e }),
+                Err(_) => continue, // kept as it is
+            }
+
+            let processed: &mut String = processed.get_or_insert_with(|| String::with_capacity(line.len() + 8));
+            processed.push_str(&line[copied..start]);
+            self.formatter.expect_accepted(x);
+            self.formatter.format_into(x, processed).expect("Writing into a String failed even though it is infallible.");
+            copied = end;
+        }
+
+        return Ok(match processed
+        {
+            Some(mut processed) =>
+            {
+                processed.push_str(&line[copied..]);
+                Cow::Owned(processed)
+            }
+            None => Cow::Borrowed(line),
+        });
+    }
+
+
+    /// # Summary
+    /// Reformats the selected fields of every line. Line breaks, "\n" as well as "\r\n", are kept as they are.
+    ///
+    /// # Arguments
+    /// - `input`: the lines
+    ///
+    /// # Returns
+    /// - the lines with the selected fields reformatted
+    /// - or `ParseError` of the first selected field that is no number if strict, with the index of the field counted from 0 and the position in `input`
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{FieldSelector, ParseError, ParseErrorKind};
+    /// let processor: scaler::FieldProcessor = scaler::Formatter::new()
+    ///     .field_processor(FieldSelector::Index(1))
+    ///     .set_delimiter(Some('\t'))
+    ///     .set_header(1)
+    ///     .set_strict(true);
+    /// assert_eq!(processor.process_lines("Size\tName\n1048576\tdisk.img\n512\tnotes.txt\n"), Ok("Size\tName\n1,049 M\tdisk.img\n512,0\tnotes.txt\n".to_owned()));
+    /// assert_eq!(processor.process_lines("Size\tName\n-\tdisk.img\n"), Err(ParseError { field: Some(0), kind: ParseErrorKind::NoDigits, position: 11 }));
+    /// ```
+    pub fn process_lines(&self, input: &str) -> Result<String, ParseError>
+    {
+        let mut processed: String = String::with_capacity(input.len()); // result
+        let mut start: usize = 0; // start of the current line in `input`
+
+
+        for (i, line) in input.split_inclusive('\n').enumerate()
+        {
+            if i < self.header
+            // header, kept as it is
+            {
+                processed.push_str(line);
+            }
+            else
+            {
+                processed.push_str(&self.process_line(line).map_err(|e| ParseError { position: start + e.position, ..e })?);
+            }
+            start += line.len();
+        }
+
+        return Ok(processed);
+    }
+
+
+    /// # Summary
+    /// Sets the character between fields. Without one, fields are separated by runs of whitespace like in `numfmt`, so a field never contains whitespace. With one, the whitespace around a field is kept and not part of the number.
+    ///
+    /// # Arguments
+    /// - `delimiter`: the character between fields, none for runs of whitespace
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let processor: scaler::FieldProcessor = scaler::Formatter::new()
+    ///     .field_processor(scaler::FieldSelector::Index(2))
+    ///     .set_delimiter(Some(';'));
+    /// assert_eq!(processor.process_line("a; 1 234 ;c"), Ok("a; 1,234 k ;c".into()));
+    /// ```
+    pub fn set_delimiter(mut self, delimiter: Option<char>) -> Self
+    {
+        self.delimiter = delimiter;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the number of lines at the start of the input that `FieldProcessor::process_lines` keeps as they are, like column titles.
+    ///
+    /// # Arguments
+    /// - `header`: number of header lines
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let processor: scaler::FieldProcessor = scaler::Formatter::new()
+    ///     .field_processor(scaler::FieldSelector::Numeric)
+    ///     .set_header(1);
+    /// assert_eq!(processor.process_lines("2024 1000\n2024 1000\n"), Ok("2024 1000\n2,024 k 1,000 k\n".to_owned()));
+    /// ```
+    pub fn set_header(mut self, header: usize) -> Self
+    {
+        self.header = header;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether a selected field that is no number is an error instead of being kept as it is. Has no effect with `FieldSelector::Numeric`, which selects only numbers in the first place.
+    ///
+    /// # Arguments
+    /// - `strict`: whether fields that are no number are errors
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let processor: scaler::FieldProcessor = scaler::Formatter::new()
+    ///     .field_processor(scaler::FieldSelector::Index(1));
+    /// assert_eq!(processor.process_line("n/a x"), Ok("n/a x".into()));
+    /// assert!(processor.set_strict(true).process_line("n/a x").is_err());
+    /// ```
+    pub fn set_strict(mut self, strict: bool) -> Self
+    {
+        self.strict = strict;
+        return self;
+    }
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Creates a processor that reformats the selected fields of text lines with this formatter, like `numfmt --field`. Fields are separated by runs of whitespace and selected fields that are no number are kept as they are, unless set otherwise.
+    ///
+    /// # Arguments
+    /// - `selector`: which fields to reformat
+    ///
+    /// # Returns
+    /// - the processor
+    ///
+    /// # Examples
+    /// ```
+    /// let processor: scaler::FieldProcessor = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(3))
+    ///     .field_processor(scaler::FieldSelector::Range(2..=3));
+    /// assert_eq!(processor.process_line("GET  1500 2500000  2048"), Ok("GET  1,50 k 2,50 M  2048".into()));
+    /// ```
+    pub fn field_processor(&self, selector: FieldSelector) -> FieldProcessor
+    {
+        return FieldProcessor { delimiter: None, formatter: self.clone(), header: 0, parser: self.parser(), selector, strict: false };
+    }
+}
+
+
+/// # Summary
+/// Reformats the selected fields of every line with a formatter, like `numfmt --field`, keeping everything else byte for byte. Fields are separated by runs of whitespace, selected fields that are no number are kept as they are. For other delimiters, header lines, or errors on fields that are no number, use `Formatter::field_processor`.
+///
+/// # Arguments
+/// - `input`: the lines
+/// - `selector`: which fields to reformat
+/// - `f`: the formatter
+///
+/// # Returns
+/// - the lines with the selected fields reformatted
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new().set_unit("B");
+/// assert_eq!(
+///     scaler::process_lines("rw-r--r--  4096  a.txt\nrw-r--r--  1048576  b.img\n", scaler::FieldSelector::Index(2), &f),
+///     "rw-r--r--  4,096 kB  a.txt\nrw-r--r--  1,049 MB  b.img\n"
+/// );
+/// ```
+pub fn process_lines(input: &str, selector: FieldSelector, f: &Formatter) -> String
+{
+    return f.field_processor(selector).process_lines(input).expect("Processing fields failed even though only strict processing can fail.");
+}
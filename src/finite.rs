@@ -0,0 +1,127 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::try_format_finite` for a number that is not finite, and the reason the output methods returning a String panic with `NonFinitePolicy::Error`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NonFiniteError
+{
+    Nan,              // not a number
+    NegativeInfinity, // negative infinity
+    PositiveInfinity, // positive infinity
+}
+
+
+impl NonFiniteError
+{
+    /// # Summary
+    /// Classifies a number that is not finite.
+    ///
+    /// # Arguments
+    /// - `x`: the number
+    ///
+    /// # Returns
+    /// - the error
+    /// - or none if `x` is finite
+    pub(crate) fn of(x: f64) -> Option<Self>
+    {
+        if x.is_nan()
+        {
+            return Some(NonFiniteError::Nan);
+        }
+        if x.is_infinite()
+        {
+            return Some(if x.is_sign_negative() { NonFiniteError::NegativeInfinity } else { NonFiniteError::PositiveInfinity });
+        }
+        return None;
+    }
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a number like `Formatter::format`, but rejects infinity and not a number instead of displaying them as special strings, for pipelines where a non-finite number is a bug to catch. The number is checked before any other processing, so regardless of the non-finite policy, the clamp range, and the output for machines.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - formatted number
+    /// - or `NonFiniteError` telling not a number and the infinities apart
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::NonFiniteError;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.try_format_finite(1234.5), Ok("1,234 k".to_string()));
+    /// assert_eq!(f.try_format_finite(f64::NAN), Err(NonFiniteError::Nan));
+    /// assert_eq!(f.try_format_finite(f64::NEG_INFINITY), Err(NonFiniteError::NegativeInfinity));
+    /// assert_eq!(f.try_format_finite(f32::INFINITY), Err(NonFiniteError::PositiveInfinity));
+    /// ```
+    pub fn try_format_finite<T>(&self, x: T) -> Result<String, NonFiniteError>
+    where
+        T: Into<f64>,
+    {
+        let x: f64 = x.into();
+
+
+        if let Some(error) = NonFiniteError::of(x)
+        {
+            return Err(error);
+        }
+
+        return Ok(self.format(x));
+    }
+
+
+    /// # Summary
+    /// Panics with the `NonFiniteError` if the number is rejected by the non-finite policy, for the output methods returning a String, which have no error to report it with. Checked before rendering, so the panic tells the reason instead of a failed write.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    pub(crate) fn expect_accepted(&self, x: f64)
+    {
+        if let Some(error) = self.rejected(x)
+        {
+            panic!("{error}");
+        }
+    }
+
+
+    /// # Summary
+    /// Checks a number against the non-finite policy.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - the error if `x` is not finite and `NonFinitePolicy::Error` is set
+    /// - or none if `x` is formatted
+    pub(crate) fn rejected(&self, x: f64) -> Option<NonFiniteError>
+    {
+        if self.non_finite_policy == NonFinitePolicy::Format
+        {
+            return None;
+        }
+        return NonFiniteError::of(x);
+    }
+}
+
+
+impl std::fmt::Display for NonFiniteError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            NonFiniteError::Nan => write!(f, "Number is not a number, but only finite numbers are formatted."),
+            NonFiniteError::NegativeInfinity => write!(f, "Number is negative infinity, but only finite numbers are formatted."),
+            NonFiniteError::PositiveInfinity => write!(f, "Number is positive infinity, but only finite numbers are formatted."),
+        };
+    }
+}
+
+
+impl std::error::Error for NonFiniteError {}
@@ -0,0 +1,142 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats the number so it never exceeds a character budget, for terminal columns or small displays. Tries in order until the result fits:
+    /// 1. the configured settings
+    /// 1. fewer significant digits
+    /// 1. fewer significant digits without whitespace between number and unit prefix
+    /// 1. scientific notation with fewer significant digits
+    /// 1. compact e-notation with fewer significant digits, for example "1e8"
+    ///
+    /// If nothing fits, the overflow marker is repeated `max_chars` times. Characters are counted, not bytes, so "µ" and "∞" count as 1. A configured width is reduced to the budget.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `max_chars`: maximum number of characters of the result
+    ///
+    /// # Returns
+    /// - the formatted number with at most `max_chars` characters
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let x: f64 = 123456789.123;
+    /// assert_eq!(f.format_fitting(x, 12), "123,5 M");
+    /// assert_eq!(f.format_fitting(x, 11), "123,5 M");
+    /// assert_eq!(f.format_fitting(x, 10), "123,5 M");
+    /// assert_eq!(f.format_fitting(x, 9), "123,5 M");
+    /// assert_eq!(f.format_fitting(x, 8), "123,5 M");
+    /// assert_eq!(f.format_fitting(x, 7), "123,5 M");
+    /// assert_eq!(f.format_fitting(x, 6), "123 M");
+    /// assert_eq!(f.format_fitting(x, 5), "123 M");
+    /// assert_eq!(f.format_fitting(x, 4), "123M");
+    /// assert_eq!(f.format_fitting(x, 3), "1e8");
+    /// assert_eq!(f.format_fitting(x, 2), "##");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-3));
+    /// let x: f64 = 123456789.123;
+    /// assert_eq!(f.format_fitting(x, 15), "123.456.789,123");
+    /// assert_eq!(f.format_fitting(x, 14), "123.456.789,12");
+    /// assert_eq!(f.format_fitting(x, 11), "123.456.789");
+    /// assert_eq!(f.format_fitting(x, 10), "1 * 10^(8)");
+    /// assert_eq!(f.format_fitting(x, 9), "1,23457e8");
+    /// assert_eq!(f.format_fitting(x, 4), "1e8");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_overflow_marker('*');
+    /// assert_eq!(f.format_fitting(0.000001234, 7), "1,234 µ"); // 7 characters, 8 bytes
+    /// assert_eq!(f.format_fitting(f64::NEG_INFINITY, 2), "-∞");
+    /// assert_eq!(f.format_fitting(f64::NEG_INFINITY, 1), "*");
+    /// assert_eq!(f.format_fitting(-1e40, 5), "-1e40");
+    /// assert_eq!(f.format_fitting(-1e40, 4), "****");
+    /// assert_eq!(f.format_fitting(1, 0), "");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(10, scaler::Align::Right, ' ');
+    /// for max_chars in 0..12 // never exceeds budget
+    /// {
+    ///     for x in [123456789.123, -0.000987654, 0.0, 1e300, f64::NAN]
+    ///     {
+    ///         assert!(f.format_fitting(x, max_chars).chars().count() <= max_chars);
+    ///     }
+    /// }
+    /// ```
+    pub fn format_fitting<T>(&self, x: T, max_chars: usize) -> String
+    where
+        T: Into<f64>,
+    {
+        let mut attempt: Formatter = self.clone(); // formatter with degraded settings
+        let formatted: String; // formatted number with configured settings
+        let max_significants: u8 = match self.rounding // no more significant digits than configured
+        {
+            Rounding::Magnitude(_) => 17, // f64 has at most 17 significant digits
+            Rounding::SignificantDigits(precision) => precision,
+        };
+        let mut stages: Vec<(Scaling, bool)> = vec![(self.scaling.clone(), false)]; // scaling modes to try and whether to use compact e-notation
+        let x: f64 = x.into();
+
+
+        attempt.width = self.width.min(max_chars); // padding alone must not exceed budget
+        formatted = attempt.format(x);
+        if formatted.chars().count() <= max_chars
+        // configured settings fit
+        {
+            return formatted;
+        }
+
+        match self.scaling
+        {
+            Scaling::Binary(true) => stages.push((Scaling::Binary(false), false)), // drop whitespace before unit prefix
+            Scaling::Decimal(true) => stages.push((Scaling::Decimal(false), false)),
+            _ => {}
+        }
+        stages.push((Scaling::Scientific, false));
+        stages.push((Scaling::Scientific, true));
+
+        attempt.fallback_rounding = None; // degraded precision applies to scientific notation as well
+        for (scaling, compact) in stages.into_iter()
+        {
+            attempt.scaling = scaling;
+            for significants in (1..=max_significants).rev()
+            // most significant digits that fit
+            {
+                let formatted: String; // formatted number with degraded settings
+
+                attempt.rounding = Rounding::SignificantDigits(significants);
+                if compact
+                // " * 10^(8)" becomes "e8"
+                {
+                    formatted = attempt
+                        .format_to_parts(x)
+                        .iter()
+                        .map(|part| if part.kind == PartKind::ExponentMarker { part.value.replace(" * 10^(", "e").replace(')', "") } else { part.value.clone() })
+                        .collect();
+                }
+                else
+                {
+                    formatted = attempt.format(x);
+                }
+                if formatted.chars().count() <= max_chars
+                {
+                    return formatted;
+                }
+            }
+        }
+
+        return self.overflow_marker.to_string().repeat(max_chars); // nothing fits
+    }
+}
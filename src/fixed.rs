@@ -0,0 +1,59 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a binary fixed-point number given as raw integer, for example a Q16.16 sensor reading, like `Formatter::format`. The raw integer is interpreted as `raw` / 2^(`frac_bits`). Division and rounding are done exactly in decimal digit arithmetic without any f64, so every digit is correct even for Q32.32 numbers near i64::MAX, whose decimal expansion is far longer than f64 could hold. Rounding ties to even.
+    ///
+    /// # Arguments
+    /// - `raw`: the raw fixed-point integer
+    /// - `frac_bits`: number of fraction bits, n in Qm.n
+    ///
+    /// # Returns
+    /// - formatted fixed-point number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_fixed_point(98304, 16), "1,500"); // Q16.16
+    /// assert_eq!(f.format_fixed_point(-98304, 16), "-1,500");
+    /// assert_eq!(f.format_fixed_point(1, 16), "15,26 µ");
+    /// assert_eq!(f.format_fixed_point(0, 16), "0,000");
+    /// assert_eq!(f.format_fixed_point(-1234567, 0), "-1,235 M"); // integer
+    /// assert_eq!(f.format_fixed_point(-1234567, 0), f.format_int(-1234567));
+    /// assert_eq!(f.format_fixed_point(i64::MIN, 63), "-1,000");
+    /// ```
+    ///
+    /// ```
+    /// // digits beyond f64
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(30));
+    /// assert_eq!(f.format_fixed_point(i64::MAX, 32), "2.147.483.647,99999999976716935635"); // Q32.32
+    /// assert_eq!(f.format((i64::MAX as f64) / 2_f64.powi(32)), "2.147.483.648,00000000000000000000"); // f64 rounds up
+    /// assert_eq!(f.format_fixed_point(i64::MAX, 63), "0,999999999999999999891579782751");
+    /// assert_eq!(f.format_fixed_point(1, 63), "0,000000000000000000108420217248550443400745280087");
+    /// assert_eq!(f.format_fixed_point(i64::MAX, 0), "9.223.372.036.854.775.807,00000000000");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1));
+    /// assert_eq!(f.format_fixed_point(5, 4), "0,3"); // 0,3125
+    /// assert_eq!(f.format_fixed_point(4, 4), "0,2"); // 0,25, tie to even
+    /// assert_eq!(f.format_fixed_point(-12, 4), "-0,8"); // -0,75, tie to even
+    /// ```
+    pub fn format_fixed_point(&self, raw: i64, frac_bits: u8) -> String
+    {
+        let mut s: String = String::new(); // formatted fixed-point number, result
+
+
+        self.render(Value::Fixed(raw < 0, raw.unsigned_abs() as u128, frac_bits), &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+}
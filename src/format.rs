@@ -1,5 +1,183 @@
 // Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::magnitude::{binary_magnitude, decimal_magnitude, pow10, pow2};
 use crate::*;
+use std::borrow::Cow;
+use std::fmt::Write;
+
+
+/// unit prefixes for binary mode, [lower bound magnitude; upper bound magnitude[, unit prefix
+pub(crate) const BINARY_PREFIXES: [(i16, i16, &str); 9] = [
+    (0, 10, ""),
+    (10, 20, "Ki"),
+    (20, 30, "Mi"),
+    (30, 40, "Gi"),
+    (40, 50, "Ti"),
+    (50, 60, "Pi"),
+    (60, 70, "Ei"),
+    (70, 80, "Zi"),
+    (80, 90, "Yi"),
+];
+/// SI unit prefixes for decimal mode, [lower bound magnitude; upper bound magnitude[, unit prefix
+pub(crate) const DECIMAL_PREFIXES: [(i16, i16, &str); 21] = [
+    (-30, -27, "q"),
+    (-27, -24, "r"),
+    (-24, -21, "y"),
+    (-21, -18, "z"),
+    (-18, -15, "a"),
+    (-15, -12, "f"),
+    (-12, -9, "p"),
+    (-9, -6, "n"),
+    (-6, -3, "µ"),
+    (-3, 0, "m"),
+    (0, 3, ""),
+    (3, 6, "k"),
+    (6, 9, "M"),
+    (9, 12, "G"),
+    (12, 15, "T"),
+    (15, 18, "P"),
+    (18, 21, "E"),
+    (21, 24, "Z"),
+    (24, 27, "Y"),
+    (27, 30, "R"),
+    (30, 33, "Q"),
+];
+
+
+/// # Summary
+/// Shift applied to the number to make it more readable, determines what is appended after the mantissa.
+enum Magnifier
+{
+    Exponent(u32, f64),         // scientific notation, contains base and exponent
+    None,                       // no scaling
+    Prefix(&'static str, bool), // unit prefix, contains prefix and whether or not to put space between number and unit prefix
+    Suffix(Cow<'static, str>),  // suffix chosen by a custom strategy, including any whitespace before it
+}
+
+
+/// # Summary
+/// How a finite number is displayed, decided before any piece of it is.
+struct Layout<'a>
+{
+    magnifier: Magnifier, // what to append after the mantissa
+    mantissa:  &'a str,   // ASCII digits of the absolute mantissa with at most one "." as decimal point, already with the decimal places to display
+    sign:      &'a str,   // the sign string, may be empty
+}
+
+
+/// # Summary
+/// Derived state of a formatter that does not depend on the number to format, precomputed once by `Formatter::compile` instead of on every call.
+#[derive(Clone, Debug)]
+pub(crate) struct Plan
+{
+    capacity:            usize,                               // estimated length of a formatted number in bytes
+    group_separator_len: usize,                               // number of characters of the group separator
+    prefix_override:     Option<i16>,                         // lower bound magnitude of unit prefix to use regardless of magnitude
+    prefixes:            Vec<Prefix>,                         // entries of `table` with their power of the base to divide by
+    table:               &'static [(i16, i16, &'static str)], // unit prefix table of scaling mode, empty if not scaling by unit prefix
+}
+
+
+impl Plan
+{
+    /// # Summary
+    /// Precomputes the derived state of a formatter.
+    ///
+    /// # Arguments
+    /// - `formatter`: the formatter to precompute for
+    ///
+    /// # Returns
+    /// - the precomputed state
+    pub(crate) fn new(formatter: &Formatter) -> Self
+    {
+        let base: u32; // base of the unit prefix table
+        let table: &'static [(i16, i16, &'static str)]; // unit prefix table of scaling mode, empty if not scaling by unit prefix
+
+
+        (base, table) = match formatter.scaling
+        {
+            Scaling::Binary(_) => (2, &BINARY_PREFIXES[..]),
+            Scaling::CompactLocalized => (10, formatter.compact_locale().compact_suffixes()),
+            Scaling::Decimal(_) => (10, &DECIMAL_PREFIXES[..]),
+            Scaling::Exponential { base, step: _, prefixes } => (base, prefixes.map_or(&[][..], |table| table.prefixes)),
+            Scaling::None | Scaling::Percent(_) | Scaling::Scientific | Scaling::Strategy(_) => (10, &[][..]),
+        };
+
+        return Self {
+            capacity: formatter.capacity_hint(),
+            group_separator_len: formatter.group_separator.chars().count(),
+            prefix_override: formatter.prefix_override,
+            prefixes: table.iter().map(|(lower, _upper, symbol)| Prefix::new(base, *lower, symbol)).collect(),
+            table,
+        };
+    }
+
+
+    /// # Summary
+    /// Estimated length of a formatted number in bytes, like `Formatter::capacity_hint`.
+    pub(crate) fn capacity(&self) -> usize
+    {
+        return self.capacity;
+    }
+
+
+    /// # Summary
+    /// Looks up the unit prefix for a magnitude, like `Formatter::scaling_prefix`.
+    fn prefix(&self, magnitude: f64) -> Option<Prefix>
+    {
+        return prefix_index(self.table, magnitude, self.prefix_override).map(|i| self.prefixes[i]);
+    }
+}
+
+
+/// # Summary
+/// Unit prefix to scale a number by.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Prefix
+{
+    divisor: f64,          // base^lower, to divide the number by
+    lower:   i16,          // lower bound magnitude
+    symbol:  &'static str, // unit prefix
+}
+
+
+impl Prefix
+{
+    /// # Summary
+    /// Creates a unit prefix from an entry of a unit prefix table.
+    ///
+    /// # Arguments
+    /// - `base`: base of the unit prefix table, 2 or 10, or the base of exponential scaling, below 2 treated as 2
+    /// - `lower`: lower bound magnitude of the entry
+    /// - `symbol`: unit prefix of the entry
+    ///
+    /// # Returns
+    /// - the unit prefix
+    fn new(base: u32, lower: i16, symbol: &'static str) -> Self
+    {
+        let divisor: f64 = match base.max(2) // base^lower, looked up exactly for the bases of unit prefixes, so the same on every platform
+        {
+            2 => pow2(lower.into()),
+            10 => pow10(lower),
+            base => (base as f64).powi(lower.into()),
+        };
+
+
+        return Self { divisor, lower, symbol };
+    }
+}
+
+
+/// # Summary
+/// Number to format. Integers and decimal numbers are kept exact, so they are displayed correctly even beyond the precision and range of f64.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Value<'a>
+{
+    Big(bool, &'a str, i64), // decimal number of any size, contains whether negative, all digits as ASCII, and decimal exponent to multiply them with
+    Exact(bool, u128, u32),  // decimal number, contains whether negative, all digits as integer, and number of decimal places among them
+    Fixed(bool, u128, u8),   // binary fixed-point number, contains whether negative, raw integer magnitude, and number of fraction bits to divide it by
+    Float(f64),              // any f64
+    None,                    // missing number, displayed as placeholder
+}
 
 
 impl Formatter
@@ -9,8 +187,8 @@ impl Formatter
     ///
     /// # Arguments
     /// - `x`: the number to format
-    ///     - must be copy convertable to f64, from into expects lossless conversion
-    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///     - must be losslessly convertable to f64, references are accepted as well
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour, for integers `Formatter::try_format` detects it
     ///
     /// # Returns
     /// - the formatted number
@@ -42,6 +220,24 @@ impl Formatter
     ///
     /// ```
     /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(30)); // without scaling, the true digits of the stored f64
+    /// assert_eq!(f.format(2_f64.powi(80)), "1.208.925.819.614.629.174.706.176,00000");
+    /// assert_eq!(f.format(0.1), "0,100000000000000005551115123126");
+    /// assert_eq!(Ok(f.format(1e300)), f.group_digits(&format!("100000000000000005250476025520{}", "0".repeat(271))));
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-3));
+    /// assert_eq!(f.format(1787451192016.7734375), "1.787.451.192.016,773");
+    /// assert_eq!(f.format(5e-324), "0,000");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(2));
+    /// assert_eq!(f.format(11766705409827987456.0), "11.766.705.409.827.987.500");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
     ///     .set_scaling(scaler::Scaling::Binary(true))
     ///     .set_rounding(scaler::Rounding::SignificantDigits(3)); // data sizes
     /// assert_eq!(f.format(123.456), "123");
@@ -57,218 +253,2486 @@ impl Formatter
     /// ```
     pub fn format<T>(&self, x: T) -> String
     where
-        T: Clone + Into<f64>, // T must be copy convertable to f64
-    {
-        const BINARY_PREFIXES: [(i16, i16, &str); 9] = [
-            (0, 10, ""),
-            (10, 20, "Ki"),
-            (20, 30, "Mi"),
-            (30, 40, "Gi"),
-            (40, 50, "Ti"),
-            (50, 60, "Pi"),
-            (60, 70, "Ei"),
-            (70, 80, "Zi"),
-            (80, 90, "Yi"),
-        ]; // unit prefixes for binary mode, [lower bound magnitude; upper bound magnitude[, unit prefix
-        const DECIMAL_PREFIXES: [(i16, i16, &str); 21] = [
-            (-30, -27, "q"),
-            (-27, -24, "r"),
-            (-24, -21, "y"),
-            (-21, -18, "z"),
-            (-18, -15, "a"),
-            (-15, -12, "f"),
-            (-12, -9, "p"),
-            (-9, -6, "n"),
-            (-6, -3, "µ"),
-            (-3, 0, "m"),
-            (0, 3, ""),
-            (3, 6, "k"),
-            (6, 9, "M"),
-            (9, 12, "G"),
-            (12, 15, "T"),
-            (15, 18, "P"),
-            (18, 21, "E"),
-            (21, 24, "Z"),
-            (24, 27, "Y"),
-            (27, 30, "R"),
-            (30, 33, "Q"),
-        ]; // SI unit prefixes for decimal mode, [lower bound magnitude; upper bound magnitude[, unit prefix
-        let mut dec_places: i16; // number of decimal places to use, i16 instead of u16 to allow negative values during intermediate steps
-        let magnitude: f64; // magnitude of the number, decimal 10^magnitude or binary 2^magnitude, exact f64 instead of floored i16 to enable scaling binary with rounding significant digits correctly when number is [1.000; 1.024[
-        let mut s: String; // formatted number string, result
+        T: IntoF64,
+    {
+        let mut s: String = String::with_capacity(self.capacity_hint()); // formatted number string, result
+        let x: f64 = x.into_f64();
+
+
+        self.expect_accepted(x);
+        self.format_into(x, &mut s).expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+
+
+    /// # Summary
+    /// Formats f32 like `Formatter::format`, but with the digits of its shortest representation that converts back to the same f32, instead of the bit-exact widening to f64. So 0.1_f32 is 0.1 and not 0.100000001490116..., which would show up with many significant digits.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_f32(0.1), "100,0 m");
+    /// assert_eq!(f.format_f32(16777217.0), "16,78 M"); // first integer f32 cannot represent, is 16777216
+    /// assert_eq!(f.format_f32(1e-45), "1,000 * 10^(-45)"); // smallest subnormal
+    /// assert_eq!(f.format(1e-45_f32), "1,401 * 10^(-45)"); // widened bit-exactly
+    /// assert_eq!(f.format_f32(f32::NEG_INFINITY), "-∞");
+    /// assert_eq!(f.format_f32(f32::NAN), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(11));
+    /// assert_eq!(f.format_f32(0.1), "0,10000000000");
+    /// assert_eq!(f.format(0.1_f32), "0,10000000149");
+    /// assert_eq!(f.format_f32(16777217.0), "16.777.216,000");
+    /// assert_eq!(f.format_f32(f32::MIN_POSITIVE), "0,000000000000000000000000000000000000011754944000"); // smallest normal
+    /// assert_eq!(f.format_f32(1.1754942e-38), "0,000000000000000000000000000000000000011754942000"); // largest subnormal
+    /// ```
+    pub fn format_f32(&self, x: f32) -> String
+    {
+        return self.format(x.to_string().parse::<f64>().expect("Shortest representation of f32 is not a valid f64.")); // shortest representation, correctly rounded to f64
+    }
+
+
+    /// # Summary
+    /// Formats an optional number, for example a table cell that may be empty. `Some` is formatted like `Formatter::format`, `None` is displayed as the placeholder set with `Formatter::set_none_string`, padded to the configured width like any number, but without sign, affixes, separators, or unit. Not a number is still a number and is displayed as such.
+    ///
+    /// # Arguments
+    /// - `x`: the optional number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - formatted number or placeholder
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_opt(Some(1234.5)), "1,234 k");
+    /// assert_eq!(f.format_opt(None::<f64>), "—");
+    /// assert_eq!(f.format_opt(Some(f64::NAN)), "NaN");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_affixes("$", "")
+    ///     .set_sign(scaler::Sign::Always)
+    ///     .set_unit("B")
+    ///     .set_zero_pad(10, false)
+    ///     .set_none_string("-");
+    /// assert_eq!(f.format_opt(None::<f64>), "-"); // no sign, affixes, unit, or zero padding
+    /// assert_eq!(f.format_opt(Some(12)), "+$012,00 B");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(8, scaler::Align::Right, ' ');
+    /// let cells: Vec<String> = [Some(1.5), None, Some(-2000.0)].into_iter().map(|x| f.format_opt(x)).collect();
+    /// assert_eq!(cells, ["   1,500", "       —", "-2,000 k"]);
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(5, scaler::Align::Center, '.')
+    ///     .set_none_string("");
+    /// assert_eq!(f.format_opt(None::<f64>), ".....");
+    /// ```
+    pub fn format_opt<T>(&self, x: Option<T>) -> String
+    where
+        T: Into<f64>,
+    {
+        let mut s: String = String::with_capacity(self.capacity_hint()); // formatted number or placeholder, result
+        let x: Option<f64> = x.map(Into::into);
 
 
-        let mut x: f64 = x.into(); // &T -> f64
-        if x.is_infinite() && x.is_sign_positive()
-        // edge cases
+        if let Some(x) = x
         {
-            s = "∞".to_string(); // positive infinity
-            if self.sign == Sign::Always
-            // if always sign
-            {
-                s = format!("+{s}"); // manually add plus sign
-            }
-            return s;
+            self.expect_accepted(x);
         }
-        else if x.is_infinite() && x.is_sign_negative()
+        self.render(x.map_or(Value::None, Value::Float), &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+
+
+    /// # Summary
+    /// Formats like `Formatter::format`, but writes the result incrementally into a sink instead of allocating a String. The output is byte-identical to `Formatter::format`.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `out`: the sink to write into
+    ///
+    /// # Returns
+    /// - nothing or the error of the sink
+    /// - or `std::fmt::Error` if the number is rejected by `NonFinitePolicy::Error`, before anything is written
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut s: String = "rate: ".to_string();
+    /// f.format_into(42069, &mut s).unwrap();
+    /// assert_eq!(s, "rate: 42,07 k");
+    /// ```
+    ///
+    /// ```
+    /// struct FixedBuffer // fixed capacity buffer without heap allocation
+    /// {
+    ///     buffer: [u8; 16],
+    ///     len: usize,
+    /// }
+    /// impl std::fmt::Write for FixedBuffer
+    /// {
+    ///     fn write_str(&mut self, s: &str) -> std::fmt::Result
+    ///     {
+    ///         let end: usize = self.len + s.len();
+    ///         if self.buffer.len() < end {return Err(std::fmt::Error);}
+    ///         self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+    ///         self.len = end;
+    ///         return Ok(());
+    ///     }
+    /// }
+    ///
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2));
+    /// let mut buffer: FixedBuffer = FixedBuffer {buffer: [0; 16], len: 0};
+    /// f.format_into(-1234.5, &mut buffer).unwrap();
+    /// assert_eq!(std::str::from_utf8(&buffer.buffer[..buffer.len]).unwrap(), "-1.234,50");
+    /// assert!(f.format_into(1e20, &mut buffer).is_err()); // does not fit anymore
+    /// ```
+    ///
+    /// ```
+    /// struct Speed(f64);
+    /// impl std::fmt::Display for Speed
+    /// {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    ///     {
+    ///         scaler::Formatter::new().format_into(self.0, f)?;
+    ///         return write!(f, "m/s");
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Speed(299792458.0).to_string(), "299,8 Mm/s");
+    /// assert_eq!(Speed(f64::NEG_INFINITY).to_string(), "-∞m/s");
+    /// ```
+    pub fn format_into<T, W>(&self, x: T, out: &mut W) -> std::fmt::Result
+    where
+        T: Into<f64>,
+        W: Write + ?Sized,
+    {
+        return self.render(Value::Float(x.into()), &mut |_kind, s| out.write_str(s));
+    }
+
+
+    /// # Summary
+    /// Counts the characters, not bytes, of the formatted number without padding. If this is greater than the configured width, `Formatter::format` returns the number unpadded.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - number of characters of the formatted number without padding
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(6, scaler::Align::Right, ' ');
+    /// assert_eq!(f.unpadded_len(1), 5);
+    /// assert_eq!(f.unpadded_len(0.001), 7); // "1,000 m"
+    /// assert_eq!(f.unpadded_len(1e-6), 7); // "1,000 µ", µ is 1 character
+    /// assert!(6 < f.unpadded_len(1e-6)); // overflow, returned unpadded
+    /// assert_eq!(f.format(1e-6), "1,000 µ");
+    /// ```
+    pub fn unpadded_len<T>(&self, x: T) -> usize
+    where
+        T: Into<f64>,
+    {
+        return self.unpadded_len_of(Value::Float(x.into()), None);
+    }
+
+
+    /// # Summary
+    /// Counts the characters of the formatted number without padding, like `Formatter::unpadded_len`, for any value.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    ///
+    /// # Returns
+    /// - number of characters of the formatted number without padding
+    fn unpadded_len_of(&self, x: Value, plan: Option<&Plan>) -> usize
+    {
+        let mut len: usize = 0;
+
+        self.render_unpadded(x, plan, &mut |_kind, s| {
+            len += s.chars().count();
+            return Ok(());
+        })
+        .expect("Counting characters failed even though it is infallible.");
+
+        return len;
+    }
+
+
+    /// # Summary
+    /// Determines how many decimal places a number gets under the current configuration, before formatting it, to size table columns or align editors. These are exactly the decimal places the output methods display, after rounding, the choice of unit prefix or exponent, and the display floor or ceiling. Trailing zeros left out with `Formatter::set_trailing_zeros` still count. Infinity and not a number have none.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - number of decimal places
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.decimals_for(42069), 2); // "42,07 k"
+    /// assert_eq!(f.decimals_for(99.996), 1); // "100,0", rounding carries into the next magnitude
+    /// assert_eq!(f.decimals_for(1e36), 3); // "1,000 * 10^(36)"
+    /// assert_eq!(f.decimals_for(f64::NAN), 0);
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_scaling(scaler::Scaling::None);
+    /// assert_eq!(f.decimals_for(1e20), 2);
+    /// ```
+    pub fn decimals_for<T>(&self, x: T) -> u16
+    where
+        T: Into<f64>,
+    {
+        return u16::try_from(self.layout_for(x.into()).0).unwrap_or(u16::MAX);
+    }
+
+
+    /// # Summary
+    /// Determines the unit prefix a number gets under the current configuration, before formatting it, for example to put it into a column header. This is exactly the unit prefix the output methods display, also the compact suffix of `Scaling::CompactLocalized` and "%" of `Scaling::Percent`. Scientific notation, suffixes of custom strategies, and not a number have none, neither has infinity except for "%".
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - the unit prefix, or none if the number is displayed without
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.prefix_for(42069), Some("k"));
+    /// assert_eq!(f.prefix_for(999.96), Some("k")); // "1,000 k"
+    /// assert_eq!(f.prefix_for(1), Some("")); // no unit prefix needed, but scaled
+    /// assert_eq!(f.prefix_for(1e36), None); // "1,000 * 10^(36)"
+    /// assert_eq!(f.clone().set_scaling(scaler::Scaling::Binary(true)).prefix_for(2048), Some("Ki"));
+    /// ```
+    pub fn prefix_for<T>(&self, x: T) -> Option<&'static str>
+    where
+        T: Into<f64>,
+    {
+        return self.layout_for(x.into()).1;
+    }
+
+
+    /// # Summary
+    /// Lays out a number like the output methods do, for `Formatter::decimals_for` and `Formatter::prefix_for`.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - number of decimal places and unit prefix
+    fn layout_for(&self, mut x: f64) -> (usize, Option<&'static str>)
+    {
+        let mut layout: (usize, Option<&'static str>) = (0, None); // decimal places and unit prefix
+
+
+        if x.is_nan()
+        // not a number has no layout
         {
-            s = "-∞".to_string(); // negative infinity
-            return s;
+            return layout;
         }
-        else if x.is_nan()
+        if self.machine_readable
+        // laid out by the overriding formatter, never clamped, like in `Formatter::render_unpadded`
         {
-            return "NaN".to_string(); // not a number
+            return Formatter { machine_readable: false, clamp: (None, None), display_ceiling: None, display_floor: None, zero_epsilon: Threshold(0.0), ..self.machine_readable_formatter() }.layout_for(x);
         }
 
+        x = self.snap_to_zero(x);
+        x = self.clamped(x).map_or(x, |(_marker, bound)| bound);
+        self.lay_out(Value::Float(self.display_threshold(x).map_or(x, |(_marker, bound)| bound)), None, &mut |laid_out| {
+            layout = match laid_out
+            {
+                Some(laid_out) => (laid_out.mantissa.split_once('.').map_or(0, |(_int, fraction)| fraction.len()), match laid_out.magnifier
+                {
+                    Magnifier::Prefix(prefix, _) => Some(prefix),
+                    Magnifier::Exponent(..) | Magnifier::None | Magnifier::Suffix(_) => None,
+                }),
+                None => (0, matches!(self.scaling, Scaling::Percent(_)).then_some("%")), // infinitely many percent are still percent
+            };
+            return Ok(());
+        })
+        .expect("Laying out failed even though it is infallible.");
+
+        return layout;
+    }
+
+
+    /// # Summary
+    /// Estimates the length in bytes of a formatted number, so the result string is allocated once and common numbers never reallocate.
+    ///
+    /// # Returns
+    /// - estimated length in bytes
+    pub(crate) fn capacity_hint(&self) -> usize
+    {
+        return 32 + self.affix_prefix.len() + self.affix_suffix.len() + self.unit.len().max(self.unit_plural.len()) + self.width * self.fill_char().len_utf8() + self.zero_pad; // 32 bytes fit sign, digits, separators, and unit prefix or exponent of common numbers
+    }
 
-        x = match self.rounding // rounded here already in case rounding changes magnitude
+
+    /// # Summary
+    /// Pads text composed of several formatted numbers, like a complex number, to the configured width as a whole. Never truncates.
+    ///
+    /// # Arguments
+    /// - `s`: the unpadded text
+    ///
+    /// # Returns
+    /// - the padded text
+    pub(crate) fn pad(&self, s: String) -> String
+    {
+        let padding: usize = self.width.saturating_sub(s.chars().count()); // number of fill characters to add
+        let padding_left: usize = match self.align
         {
-            Rounding::Magnitude(precision) => x.round_mag(precision), // round statically to digit at 10^magnitude
-            Rounding::SignificantDigits(precision) => x.round_sig(precision), // round dynamically to significant numbers
+            Align::Center => padding / 2, // if uneven, extra fill character goes right
+            Align::Left => 0,
+            Align::Right => padding,
         };
 
-        if x == 0.0
-        {
-            magnitude = 0.0; // 0 has default magnitude and no unit prefix, here because log(0) would shit itself
-        }
-        else
+
+        if padding == 0
         {
-            magnitude = match self.scaling // determine magnitude with rounded value in case rounding changes magnitude
-            {
-                Scaling::Binary(_) => x.abs().log2(), // if scaling binary: binary magnitude 2^magnitude
-                _ => x.abs().log10(), // usually: decimal magnitude 10^magnitude
-            }
+            return s;
         }
+        return format!("{}{s}{}", self.fill_char().to_string().repeat(padding_left), self.fill_char().to_string().repeat(padding - padding_left));
+    }
 
-        dec_places = match (&self.scaling, &self.rounding) // decimal places required depending on scaling and rounding mode
+
+    /// # Summary
+    /// Determines the character the width is padded with, depending on the fill mode set with `Formatter::set_fill_mode`.
+    ///
+    /// # Returns
+    /// - the fill character, or U+2007 FIGURE SPACE if padding with figure spaces
+    pub(crate) const fn fill_char(&self) -> char
+    {
+        return match self.fill_mode
         {
-            (Scaling::Binary(_), Rounding::Magnitude(precision)) => // convert binary magnitude to decimal magnitude, exact f64 magnitude required for this case, then business as usual
-            {
-                match BINARY_PREFIXES.iter().find(|(lower, upper, _prefix)| *lower as f64 <= magnitude && magnitude < *upper as f64) // try to find binary unit prefix for magnitude
-                {
-                    Some(_) =>{2.0_f64.powf(magnitude - magnitude.rem_euclid(10.0)).log10().floor() as i16 - precision - 1},
-                    None => {magnitude.floor() as i16} // fallback to scientific notation
-                }
-            },
-            (Scaling::Binary(_), Rounding::SignificantDigits(precision)) => // convert binary magnitude to decimal magnitude, exact f64 magnitude required for this case, then business as usual
+            Fill::Character => self.fill,
+            Fill::FigureSpace => '\u{2007}',
+        };
+    }
+
+
+    /// # Summary
+    /// Overrides the cosmetic options for output read by machines, as set with `Formatter::set_machine_readable`. Scaling by unit prefixes is turned off, scientific notation is kept.
+    ///
+    /// # Returns
+    /// - the formatter to render with
+    pub(crate) fn machine_readable_formatter(&self) -> Formatter
+    {
+        return Formatter {
+            affix_prefix:       Text::Static(""),
+            affix_suffix:       Text::Static(""),
+            decimal_separator:  Text::Static("."),
+            group_separator:    Text::Static(""),
+            min_integer_digits: 0,
+            prefix_override:    None,
+            scaling:            if self.scaling == Scaling::Scientific { Scaling::Scientific } else { Scaling::None },
+            show_original:      (ShowOriginal::Off, false),
+            sign:               if self.sign == Sign::SpaceForPositive { Sign::OnlyMinus } else { self.sign.clone() },
+            sign_placement:     SignPlacement::Leading,
+            special_strings:    (Text::Static("inf"), Text::Static("NaN")),
+            unit:               Text::Static(""),
+            unit_plural:        Text::Static(""),
+            width:              0,
+            zero_pad:           0,
+            ..self.clone()
+        };
+    }
+
+
+    /// # Summary
+    /// Scales, rounds, and displays the number in a single forward pass, then pads it to the configured width. All output methods are built on this. The pieces of the formatted number are handed to `emit` in order and are never empty, consecutive pieces may be of the same kind.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `emit`: receives the pieces of the formatted number in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    pub(crate) fn render(&self, x: Value, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        return self.render_planned(x, None, emit);
+    }
+
+
+    /// # Summary
+    /// Renders like `Formatter::render`, with the derived state precomputed by `Formatter::compile` if there is one.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    /// - `emit`: receives the pieces of the formatted number in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    pub(crate) fn render_planned(&self, x: Value, plan: Option<&Plan>, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let mut fill_buffer: [u8; 4] = [0; 4]; // fill character as string without allocation
+        let fill: &str = self.fill_char().encode_utf8(&mut fill_buffer);
+        let padding: usize; // number of fill characters to add
+        let padding_left: usize;
+
+
+        if let Value::Float(x) = x
+        // strict, rejected before any other processing, even padding
+        {
+            if self.rejected(x).is_some()
             {
-                match BINARY_PREFIXES.iter().find(|(lower, upper, _prefix)| *lower as f64 <= magnitude && magnitude < *upper as f64) // try to find binary unit prefix for magnitude
-                {
-                    Some(_) =>{-1 * (2.0_f64.powf(magnitude.rem_euclid(10.0)).log10().floor()) as i16 + *precision as i16 - 1},
-                    None => {*precision as i16 - 1} // fallback to scientific notation
-                }
+                return Err(std::fmt::Error);
             }
-            (Scaling::Decimal(_), Rounding::Magnitude(precision)) =>
+        }
+        if self.width == 0 || self.machine_readable
+        // no padding configured, skip measuring
+        {
+            return self.render_unpadded(x, plan, emit);
+        }
+
+        padding = self.width.saturating_sub(self.unpadded_len_of(x, plan)); // never truncate
+        padding_left = match self.align
+        {
+            Align::Center => padding / 2, // if uneven, extra fill character goes right
+            Align::Left => 0,
+            Align::Right => padding,
+        };
+
+        for _ in 0..padding_left
+        {
+            emit(PartKind::Padding, fill)?;
+        }
+        self.render_unpadded(x, plan, emit)?;
+        for _ in padding_left..padding
+        {
+            emit(PartKind::Padding, fill)?;
+        }
+
+        return Ok(());
+    }
+
+
+    /// # Summary
+    /// Scales, rounds, and displays the number, zero padded if configured. The pieces of the formatted number are handed to `emit` in order and are never empty, consecutive pieces may be of the same kind.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    /// - `emit`: receives the pieces of the formatted number in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn render_unpadded(&self, x: Value, plan: Option<&Plan>, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        if let Value::None = x
+        // placeholder as it is
+        {
+            if self.none_string.is_empty()
             {
-                match DECIMAL_PREFIXES.iter().find(|(lower, upper, _prefix)| *lower as f64 <= magnitude && magnitude < *upper as f64) // try to find decimal unit prefix for magnitude
-                {
-                    Some(_) =>{(magnitude - magnitude.rem_euclid(3.0)).floor() as i16 - precision},
-                    None => {magnitude.floor() as i16} // fallback to scientific notation
-                }
-            },
-            (Scaling::Decimal(_), Rounding::SignificantDigits(precision)) =>
+                return Ok(());
+            }
+            return emit(PartKind::Special, &self.none_string);
+        }
+        if self.machine_readable
+        // cosmetic options overridden, never zero padded
+        {
+            return self.machine_readable_formatter().render_number(x, None, 0, emit);
+        }
+        let snapped: Value = if let Value::Float(x) = x { Value::Float(self.snap_to_zero(x)) } else { x }; // numerical noise as 0
+        let clamped: Option<(&str, f64)> = if let Value::Float(snapped) = snapped { self.clamped(snapped) } else { None }; // marker and bound if clamped
+        let shown: Value = clamped.map_or(snapped, |(_marker, bound)| Value::Float(bound)); // number to display, the original value still shown as it is
+
+        if let Some((marker, bound)) = if let Value::Float(shown) = shown { self.display_threshold(shown) } else { None }
+        // beyond floor or ceiling, bound displayed instead
+        {
+            if !marker.is_empty()
             {
-                match DECIMAL_PREFIXES.iter().find(|(lower, upper, _prefix)| *lower as f64 <= magnitude && magnitude < *upper as f64) // try to find decimal unit prefix for magnitude
-                {
-                    Some(_) =>{-1 * magnitude.rem_euclid(3.0).floor() as i16+ *precision as i16 - 1},
-                    None => {*precision as i16 - 1} // fallback to scientific notation
-                }
+                emit(PartKind::Threshold, marker)?;
             }
-            (Scaling::None, Rounding::Magnitude(precision)) => -1 * precision,
-            (Scaling::None, Rounding::SignificantDigits(precision)) => -1 * magnitude.floor() as i16 + *precision as i16 - 1,
-            (Scaling::Scientific, Rounding::Magnitude(_)) => magnitude.floor() as i16,
-            (Scaling::Scientific, Rounding::SignificantDigits(precision)) => *precision as i16 - 1,
+            self.render_zero_padded(Value::Float(bound), plan, emit)?;
+        }
+        else
+        {
+            self.render_zero_padded(shown, plan, emit)?;
+        }
+        if let Some((marker, _bound)) = clamped
+        // clamped, marker after the number
+        {
+            if !marker.is_empty()
+            {
+                emit(PartKind::Threshold, marker)?;
+            }
+        }
+        return self.emit_original(x, plan, emit);
+    }
+
+
+    /// # Summary
+    /// Renders the number without original value, zero padded if configured.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format, no placeholder
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    /// - `emit`: receives the pieces of the formatted number in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn render_zero_padded(&self, x: Value, plan: Option<&Plan>, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let group_separator_len: usize = plan.map_or_else(|| self.group_separator.chars().count(), |plan| plan.group_separator_len);
+        let mut int_digits: usize = 0; // number of integer digits without zero padding
+        let mut len: usize = 0; // number of characters without zero padding
+        let mut zeros: usize = 0; // number of zeros to pad with
+
+
+        if self.zero_pad == 0 || matches!(x, Value::Float(x) if !x.is_finite())
+        // no zero padding configured or nothing to pad
+        {
+            return self.render_number(x, plan, 0, emit);
+        }
+
+        self.render_number(x, plan, 0, &mut |kind, s| {
+            len += s.chars().count();
+            if kind == PartKind::IntegerDigits
+            {
+                int_digits += s.chars().count();
+            }
+            return Ok(());
+        })?;
+
+        if self.zero_pad_grouped
+        // every zero may bring a group separator with it, use as many zeros as fit
+        {
+            let separators = |int_digits: usize| -> usize { int_digits.saturating_sub(1) / 3 * group_separator_len };
+            while len + zeros + 1 + separators(int_digits + zeros + 1) - separators(int_digits) <= self.zero_pad
+            {
+                zeros += 1;
+            }
+        }
+        else
+        {
+            zeros = self.zero_pad.saturating_sub(len);
+        }
+
+        return self.render_number(x, plan, zeros, emit);
+    }
+
+
+    /// # Summary
+    /// Appends the original value in parentheses if configured with `Formatter::set_show_original`. Only f64 have an original apart from the formatted number, special values never show it.
+    ///
+    /// # Arguments
+    /// - `x`: the number formatted before
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    /// - `emit`: receives the original value as a single piece
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn emit_original(&self, x: Value, plan: Option<&Plan>, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let digits: String; // shortest digits that round-trip, without sign and exponent
+        let mut original: String; // original value with parentheses, only allocated if shown
+
+
+        let x: f64 = match x
+        {
+            Value::Float(x) if x.is_finite() => x,
+            _ => return Ok(()), // exact types are displayed as they are, special values have no digits to show
         };
-        if dec_places < 0
+        if self.show_original.0 == ShowOriginal::Off || (!self.show_original.1 && self.rounding_keeps(x, plan))
+        // nothing to show
         {
-            dec_places = 0; // negative number of decimal places are not allowed
+            return Ok(());
+        }
+
+        original = String::from(" (");
+        if x < 0.0
+        // only "-", the sign styling belongs to the formatted number, -0 is unsigned like there
+        {
+            original.push('-');
+        }
+        digits = x.abs().to_string();
+        match self.show_original.0
+        {
+            ShowOriginal::Grouped => self.render_digits(&digits, &mut |_kind, s| {
+                original.push_str(s);
+                return Ok(());
+            })?,
+            ShowOriginal::Off | ShowOriginal::Raw => original.push_str(&digits),
         }
+        original.push(')');
 
-        match self.scaling //  apply magnitude shift for scaling, f64 -> String, optionally remove trailing zeros, append unit prefix
+        return emit(PartKind::Original, &original);
+    }
+
+
+    /// # Summary
+    /// Determines whether the displayed value equals the number, so showing the original would only repeat it.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format, must be finite
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    ///
+    /// # Returns
+    /// - whether rounding leaves the number unchanged
+    fn rounding_keeps(&self, x: f64, plan: Option<&Plan>) -> bool
+    {
+        let rounded: f64 = match self.rounding
+        {
+            Rounding::Magnitude(magnitude) => x.round_mag(magnitude),
+            Rounding::SignificantDigits(significants) => x.round_sig(significants),
+        };
+
+
+        if rounded != x
+        {
+            return false;
+        }
+        if let Scaling::Binary(_) = self.scaling
+        // the mantissa is rounded again to its decimal places, it is x divided by a power of 2 exactly
+        {
+            let (mantissa, dec_places, _) = self.scale(x, &self.rounding, plan);
+            return mantissa.round_mag(-(dec_places.min(i16::MAX as usize) as i16)) == mantissa;
+        }
+
+        return true;
+    }
+
+
+    /// # Summary
+    /// Scales, rounds, and displays the number in a single forward pass. The pieces of the formatted number are handed to `emit` in order and are never empty, consecutive pieces may be of the same kind.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    /// - `pad_zeros`: number of zeros to pad the integer part with
+    /// - `emit`: receives the pieces of the formatted number in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn render_number(&self, x: Value, plan: Option<&Plan>, pad_zeros: usize, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let negative: bool = match x // sign of infinity, also if rounding overflows to it
+        {
+            Value::Float(x) => x.is_sign_negative(),
+            Value::Big(negative, ..) | Value::Exact(negative, ..) | Value::Fixed(negative, ..) => negative,
+            Value::None => unreachable!("Placeholder is rendered before even though it is not a number."),
+        };
+
+
+        if matches!(x, Value::Float(x) if x.is_nan())
         {
-            Scaling::None => // no scaling
+            self.emit_opening("", emit)?; // not a number, never signed
+            emit(PartKind::Special, &self.special_strings.1)?;
+            return self.emit_closing("", emit);
+        }
+
+        return self.lay_out(x, plan, &mut |layout| match layout
+        {
+            Some(layout) => self.render_mantissa(layout.sign, layout.mantissa, layout.magnifier, pad_zeros, emit),
+            None => self.render_infinity(negative, emit),
+        });
+    }
+
+
+    /// # Summary
+    /// Displays infinity with sign, affixes, and unit.
+    ///
+    /// # Arguments
+    /// - `negative`: whether negative infinity
+    /// - `emit`: receives the pieces of the formatted number in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn render_infinity(&self, negative: bool, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let sign: &str = self.sign_str(negative, false); // infinity, signed like any other number
+        let whitespace: bool; // whether whitespace separates infinity and unit
+
+
+        self.emit_opening(sign, emit)?;
+        emit(PartKind::Special, &self.special_strings.0)?;
+        if let Scaling::Percent(whitespace_separation) = self.scaling
+        // infinitely many percent are still percent
+        {
+            if whitespace_separation
             {
-                s = format!("{:.*}", dec_places as usize, x);
-                if !self.trailing_zeros {s = s.trim_end_matches("0").trim_end_matches(".").to_string();} // remove trailing zeros and bare decimal separator
+                emit(PartKind::Literal, " ")?;
             }
-            Scaling::Binary(whitespace_separation) => // binary scaling
+            emit(PartKind::Prefix, "%")?;
+        }
+        whitespace = !matches!(self.scaling, Scaling::Binary(false) | Scaling::Decimal(false) | Scaling::Exponential { prefixes: Some(PrefixTable { space: false, .. }), .. } | Scaling::Percent(_));
+        self.emit_prefix_padding((whitespace && self.has_unit()) as usize, emit)?; // room of the unit prefix a finite number would have
+        self.emit_unit(whitespace, "inf", emit)?; // infinity is still a quantity, separated like a number without unit prefix, unit directly after "%"
+        return self.emit_closing(sign, emit);
+    }
+
+
+    /// # Summary
+    /// Scales and rounds a number and decides how it is displayed, without displaying it. Every output method displays what is decided here, so `Formatter::decimals_for` and `Formatter::prefix_for` agree with them.
+    ///
+    /// # Arguments
+    /// - `x`: the number to lay out, no placeholder and not a number
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    /// - `out`: receives the layout, or none if the number is displayed as infinity
+    ///
+    /// # Returns
+    /// - nothing or the error of `out`
+    fn lay_out(&self, x: Value, plan: Option<&Plan>, out: &mut dyn FnMut(Option<Layout>) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let dec_places: usize; // number of decimal places to use
+        let mut digits: StackString<64> = StackString::new(); // formatted mantissa digits
+        let magnifier: Magnifier; // what to append after the mantissa
+        let mantissa: f64; // number after applying magnitude shift for scaling
+        let sign: &str;
+
+
+        let x: f64 = match x
+        {
+            Value::Float(x) => x,
+            Value::Big(negative, digits, exponent) => return self.lay_out_exact(negative, Digits::from_ascii(digits, exponent), out), // exact, without f64
+            Value::Exact(negative, digits, scale) => return self.lay_out_exact(negative, Digits::new(digits, scale), out),
+            Value::Fixed(negative, raw, frac_bits) =>
             {
-                match BINARY_PREFIXES.iter().find(|(lower, upper, _prefix)| *lower as f64 <= magnitude && magnitude < *upper as f64) // try to find binary unit prefix for magnitude
-                {
-                    Some((_lower, _upper, prefix)) =>
-                    {
-                        s = format!("{:.*}", dec_places as usize, x / 2.0_f64.powf(magnitude - magnitude.rem_euclid(10.0))); // divide by 2^magnitude
-                        if !self.trailing_zeros {s = s.trim_end_matches("0").trim_end_matches(".").to_string();} // remove trailing zeros and bare decimal separator
-                        if whitespace_separation {s += " ";} // add whitespace between number and unit prefix
-                        s += prefix; // append binary unit prefix
-                        s = s.trim_end().to_string(); // remove possible trailing whitespace
-                    },
-                    None => // fallback to base 2 scientific notation
-                    {
-                        s = format!("{:.*}", dec_places as usize, x / 2.0_f64.powf(magnitude.floor())); // divide by 2^magnitude
-                        if !self.trailing_zeros {s = s.trim_end_matches("0").trim_end_matches(".").to_string();} // remove trailing zeros and bare decimal separator
-                        s += format!(" * 2^({})", magnitude.floor()).as_str();  // append base 2 multiplier
-                    }
-                }
+                let mut digits: Digits = Digits::new(raw, 0); // exact decimal expansion of raw / 2^frac_bits
+                digits.halve(frac_bits as u64);
+                return self.lay_out_exact(negative, digits, out);
             }
-            Scaling::Decimal(whitespace_separation) => // decimal scaling
+            Value::None => unreachable!("Placeholder is rendered before even though it is not a number."),
+        };
+
+        if x.is_infinite()
+        {
+            return out(None);
+        }
+
+        if let Scaling::Percent(_) = self.scaling
+        // multiplied by 100 in decimal digit arithmetic, multiplying in f64 would add noise like 7,000000000000001 %
+        {
+            return self.lay_out_exact(x.is_sign_negative() && x != 0.0, Digits::from_f64(x.abs()), out);
+        }
+        if let Scaling::None = self.scaling
+        // no scaling, produce the true digits of the stored f64 exactly, rounding in f64 would add noise to large numbers and many digits
+        {
+            if let Some((n, dec_places)) = self.round_integer(x)
+            // whole number, rounded in integer arithmetic, same result as in decimal digit arithmetic
             {
-                match DECIMAL_PREFIXES.iter().find(|(lower, upper, _prefix)| *lower as f64 <= magnitude && magnitude < *upper as f64) // try to find decimal unit prefix for magnitude
+                if write_integer(&mut digits, n, dec_places).is_ok()
                 {
-                    Some((_lower, _upper, prefix)) =>
-                    {
-                        s = format!("{:.*}", dec_places as usize, x / 10.0_f64.powf(magnitude - magnitude.rem_euclid(3.0))); // divide by 10^magnitude
-                        if !self.trailing_zeros {s = s.trim_end_matches("0").trim_end_matches(".").to_string();} // remove trailing zeros and bare decimal separator
-                        if whitespace_separation {s += " ";} // add whitespace between number and unit prefix
-                        s += prefix; // append decimal unit prefix
-                        s = s.trim_end().to_string(); // remove possible trailing whitespace
-                    },
-                    None => // fallback to base 10 scientific notation
-                    {
-                        s = format!("{:.*}", dec_places as usize, x / 10.0_f64.powf(magnitude.floor())); // divide by 10^magnitude
-                        if !self.trailing_zeros {s = s.trim_end_matches("0").trim_end_matches(".").to_string();} // remove trailing zeros and bare decimal separator
-                        s += format!(" * 10^({})", magnitude.floor()).as_str(); // append base 10 multiplier
-                    }
+                    return out(Some(Layout { magnifier: Magnifier::None, mantissa: digits.as_str(), sign: self.sign_str(x.is_sign_negative() && x != 0.0, n == 0) }));
                 }
             }
-            Scaling::Scientific => // scientific notation
+            return self.lay_out_exact(x.is_sign_negative() && x != 0.0, Digits::from_f64(x.abs()), out);
+        }
+
+        (mantissa, dec_places, magnifier) = self.scale(x, &self.rounding, plan);
+        if !mantissa.is_finite() || (!matches!(self.scaling, Scaling::Binary(_) | Scaling::Exponential { .. } | Scaling::Strategy(_)) && 15.0 < dec_places as f64 + mantissa.abs().log10().floor() + 1.0)
+        // rounding overflowed beyond f64, like f64::MAX rounded up, or more significant digits than the f64 divided by a power of 10 holds without noise, round in decimal digit arithmetic instead
+        {
+            if let Scaling::Exponential { .. } | Scaling::Strategy(_) = self.scaling
+            // powers of an arbitrary base and divisors of custom strategies have no exact decimal digit arithmetic, overflowed like f64
             {
-                s = format!("{:.*}", dec_places as usize, x / 10.0_f64.powf(magnitude.floor())); // divide by 10^magnitude
-                if !self.trailing_zeros {s = s.trim_end_matches("0").trim_end_matches(".").to_string();} // remove trailing zeros and bare decimal separator
-                s += format!(" * 10^({})", magnitude.floor()).as_str(); // append base 10 multiplier
+                return out(None);
             }
+            return self.lay_out_exact(x.is_sign_negative(), Digits::from_f64(x.abs()), out);
+        }
+
+        sign = self.sign_str(mantissa.is_sign_negative() && x != 0.0, mantissa == 0.0); // sign is handled separately, digits are generated from absolute value, -0 is unsigned like in every other scaling
+        let written: std::fmt::Result = if mantissa.fract() == 0.0 && mantissa.abs() < 2_f64.powi(53)
+        // whole mantissa, like after an exact division by the unit prefix: digits by integer conversion instead of float formatting
+        {
+            write_integer(&mut digits, mantissa.abs() as u64, dec_places)
+        }
+        else
+        {
+            #[cfg(feature = "fast-format")]
+            let written: std::fmt::Result = crate::shortest::write_fixed(&mut digits, mantissa.abs(), dec_places); // digits from shortest representation, without core::fmt
+            #[cfg(not(feature = "fast-format"))]
+            let written: std::fmt::Result = write!(digits, "{:.*}", dec_places, mantissa.abs());
+            written
+        };
+        if written.is_ok()
+        // digits formatted once for both passes, on the stack
+        {
+            return out(Some(Layout { magnifier, mantissa: digits.as_str(), sign }));
         }
+        return out(Some(Layout { magnifier, mantissa: &format!("{:.*}", dec_places, mantissa.abs()), sign })); // too many decimal places for the stack buffer
+    }
+
+
+    /// # Summary
+    /// Rounds a whole number for display without scaling in integer arithmetic, like `Formatter::lay_out_exact` does in decimal digit arithmetic, including ties to even.
+    ///
+    /// # Arguments
+    /// - `x`: the number to round
+    ///
+    /// # Returns
+    /// - the absolute rounded number and the number of decimal places to display it with
+    /// - or `None` if `x` is not a whole number below 2^(53), or rounding needs what only `Formatter::lay_out_exact` handles
+    fn round_integer(&self, x: f64) -> Option<(u64, usize)>
+    {
+        let cut: i64; // magnitude of the last digit to keep
+        let dec_places: i64; // number of decimal places to use, signed to allow negative values during intermediate steps
+        let digits_len = |n: u64| -> i64 { n.checked_ilog10().unwrap_or(0) as i64 + 1 }; // number of integer digits, 0 has 1
+        let n: u64; // absolute whole number
+        let rounded: u64; // n rounded
 
-        if self.sign == Sign::Always && x.is_sign_positive()
-        // if always sign and positive
+
+        if x.fract() != 0.0 || 2_f64.powi(53) <= x.abs() || self.rounding == Rounding::SignificantDigits(0) || self.prefix_override.is_some()
+        // not a whole number in exact integer range, unsigned 0, or 0 with forced magnitude
         {
-            s = format!("+{s}"); // manually add plus sign
+            return None;
         }
 
-        if self.group_separator != ""
-        // add thousands separators markers, done here already with default decimal separator "." in case user defined empty or otherwise trolling decimal separator
+        n = x.abs() as u64;
+        cut = match self.rounding
+        {
+            Rounding::Magnitude(precision) => precision as i64,
+            Rounding::SignificantDigits(precision) => digits_len(n) - precision as i64,
+        };
+        rounded = match u32::try_from(cut).ok().and_then(|cut| 10_u64.checked_pow(cut))
+        {
+            _ if cut <= 0 => n, // nothing to round
+            None => 0, // beyond u64, n is below half of it
+            Some(unit) =>
+            {
+                let (quotient, remainder): (u64, u64) = (n / unit, n % unit);
+                if unit / 2 < remainder || (remainder == unit / 2 && quotient % 2 == 1) { (quotient + 1) * unit } else { quotient * unit } // ties to even
+            }
+        };
+        dec_places = match self.rounding
+        {
+            Rounding::Magnitude(precision) => -(precision as i64),
+            Rounding::SignificantDigits(precision) => precision as i64 - digits_len(rounded),
+        };
+
+        return Some((rounded, dec_places.max(0) as usize));
+    }
+
+
+    /// # Summary
+    /// Rounds and scales a decimal number exactly in decimal digit arithmetic, without any f64, so all digits are correct even beyond the precision of f64, and decides how it is displayed like `Formatter::lay_out`. Mirrors `Formatter::scale` in rounding, choice of unit prefix or exponent, and decimal places.
+    ///
+    /// # Arguments
+    /// - `negative`: whether the number is negative
+    /// - `mantissa`: absolute value of the number
+    /// - `out`: receives the layout like in `Formatter::lay_out`
+    ///
+    /// # Returns
+    /// - nothing or the error of `out`
+    fn lay_out_exact(&self, negative: bool, mut mantissa: Digits, out: &mut dyn FnMut(Option<Layout>) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let dec_places: i64; // number of decimal places to use, signed to allow negative values during intermediate steps
+        let magnifier: Magnifier; // what to append after the mantissa
+        let mut rounded_magnitude: i64; // decimal or binary magnitude of the rounded number, like `Formatter::scale` determines it
+        let mut rounding: &Rounding = &self.rounding; // rounding mode, replaced by the fallback rounding in scientific notation
+        let unrounded: Option<Digits> = self.fallback_rounding.as_ref().map(|_| mantissa.clone()); // number before rounding, to round again with the fallback rounding
+
+
+        if let Scaling::Exponential { .. } | Scaling::Strategy(_) = self.scaling
+        // powers of an arbitrary base and divisors of custom strategies have no exact decimal digit arithmetic, rounded to the decimal place exactly first, then scaled in f64
         {
-            let group_separator_i_earliest: usize = s
-                .chars()
-                .position(|c| c.is_digit(10))
-                .expect(format!("Could not find first digit in `s` = \"{s}\", formerly `x` = \"{x}\".").as_str())
-                + 1; // earliest possible index of group separator, find first digit
-            let mut i: usize = s
-                .find(".") // find default decimal separator
-                .or_else(|| s.find("*")) // if none assume single digit scientific notation and start at space before multiplication sign, will result in no group separators
-                .or_else(|| s.chars().rev().position(|c| c.is_digit(10)).map(|pos| s.len() - pos)) // if none assume no decimal separator and no scientific notation and start at last digit
-                .expect(format!("Could not find last digit in `s` = \"{s}\", formerly `x` = \"{x}\".").as_str());
+            if let Rounding::Magnitude(precision) = self.rounding
+            // significant digits are those of the mantissa
+            {
+                mantissa.round_mag(precision as i64);
+            }
+            let x: f64 = mantissa.to_f64(); // rounded, correctly rounded to f64 again, including integer digits rounded away
+            return self.lay_out(Value::Float(if negative { -x } else { x }), None, out);
+        }
 
-            while group_separator_i_earliest + 3 <= i
-            // insert group separators
+        mantissa.round_to(rounding); // rounded here already in case rounding changes magnitude
+        rounded_magnitude = self.exact_magnitude(&mantissa);
+        if let (Some(fallback), Some(unrounded)) = (self.fallback_rounding.as_ref().filter(|fallback| *fallback != rounding), unrounded)
+        {
+            if match self.scaling
+            {
+                Scaling::Binary(_) => self.find_prefix(&BINARY_PREFIXES, rounded_magnitude as f64).is_none(),
+                Scaling::Decimal(_) => self.find_prefix(&DECIMAL_PREFIXES, rounded_magnitude as f64).is_none(),
+                Scaling::Scientific => true,
+                _ => false,
+            }
+            // scientific notation with a rounding of its own, round the original number once more instead of the rounded one, like `Formatter::scale`
             {
-                i -= 3; // move to previous group of 3 digits
-                s.insert_str(i, "{GROUP SEPARATOR}"); // insert group separator marker, not actual separator to avoid confusion with decimal separator replacement
+                rounding = fallback;
+                mantissa = unrounded;
+                mantissa.round_to(rounding);
+                rounded_magnitude = self.exact_magnitude(&mantissa);
             }
         }
-        s = s.replace(".", self.decimal_separator.to_string().as_str()); // replace decimal separator
-        s = s.replace("{GROUP SEPARATOR}", self.group_separator.to_string().as_str()); // replace group separator
 
-        return s;
+        if let (Some(forced), Scaling::Binary(_) | Scaling::Decimal(_) | Scaling::Scientific) = (self.prefix_override, &self.scaling)
+        // exponent of scientific notation forced for shared scaling, like `Formatter::scale`, a forced unit prefix is chosen regardless of the magnitude anyway
+        {
+            if matches!(self.scaling, Scaling::Scientific) || self.find_prefix(if let Scaling::Binary(_) = self.scaling { &BINARY_PREFIXES } else { &DECIMAL_PREFIXES }, rounded_magnitude as f64).is_none()
+            {
+                rounded_magnitude = forced as i64;
+            }
+        }
+
+        (magnifier, dec_places) = match (&self.scaling, rounding) // apply magnitude shift for scaling, same decimal places as in `Formatter::scale`
+        {
+            (Scaling::Binary(whitespace_separation), rounding) =>
+            {
+                match self.find_prefix(&BINARY_PREFIXES, rounded_magnitude as f64)
+                {
+                    Some((lower, _upper, prefix)) =>
+                    {
+                        mantissa.halve(*lower as u64);
+                        (Magnifier::Prefix(prefix, *whitespace_separation), match rounding
+                        {
+                            Rounding::Magnitude(precision) => (1_u128 << lower).ilog10() as i64 - *precision as i64 - 1,
+                            Rounding::SignificantDigits(precision) => -mantissa.magnitude() + *precision as i64 - 1,
+                        })
+                    }
+                    None => // fallback to base 2 scientific notation
+                    {
+                        if rounded_magnitude < 0
+                        {
+                            mantissa.double(rounded_magnitude.unsigned_abs());
+                        }
+                        else
+                        {
+                            mantissa.halve(rounded_magnitude as u64);
+                        }
+                        (Magnifier::Exponent(2, rounded_magnitude as f64), match rounding
+                        {
+                            Rounding::Magnitude(_) => rounded_magnitude,
+                            Rounding::SignificantDigits(precision) => *precision as i64 - 1,
+                        })
+                    }
+                }
+            }
+            (Scaling::CompactLocalized, rounding) =>
+            {
+                match self.find_prefix(self.compact_locale().compact_suffixes(), rounded_magnitude as f64)
+                {
+                    Some((lower, _upper, suffix)) =>
+                    {
+                        mantissa.shift(*lower as i64);
+                        (Magnifier::Prefix(suffix, self.compact_locale().compact_space()), match rounding
+                        {
+                            Rounding::Magnitude(precision) => *lower as i64 - *precision as i64,
+                            Rounding::SignificantDigits(precision) => -(rounded_magnitude - *lower as i64) + *precision as i64 - 1,
+                        })
+                    }
+                    None => (Magnifier::None, match rounding // below the first step, no scaling
+                    {
+                        Rounding::Magnitude(precision) => -(*precision as i64),
+                        Rounding::SignificantDigits(precision) => -rounded_magnitude + *precision as i64 - 1,
+                    }),
+                }
+            }
+            (Scaling::Decimal(whitespace_separation), rounding) =>
+            {
+                match self.find_prefix(&DECIMAL_PREFIXES, rounded_magnitude as f64)
+                {
+                    Some((lower, _upper, prefix)) =>
+                    {
+                        mantissa.shift(*lower as i64);
+                        (Magnifier::Prefix(prefix, *whitespace_separation), match rounding
+                        {
+                            Rounding::Magnitude(precision) => *lower as i64 - *precision as i64,
+                            Rounding::SignificantDigits(precision) => -(rounded_magnitude - *lower as i64) + *precision as i64 - 1,
+                        })
+                    }
+                    None => // fallback to base 10 scientific notation
+                    {
+                        mantissa.shift(rounded_magnitude);
+                        (Magnifier::Exponent(10, rounded_magnitude as f64), match rounding
+                        {
+                            Rounding::Magnitude(precision) => rounded_magnitude - *precision as i64,
+                            Rounding::SignificantDigits(precision) => *precision as i64 - 1,
+                        })
+                    }
+                }
+            }
+            (Scaling::Exponential { .. } | Scaling::Strategy(_), _) => unreachable!("Exponential scaling and custom strategies are rendered in f64 before."),
+            (Scaling::None, Rounding::Magnitude(precision)) => (Magnifier::None, -(*precision as i64)),
+            (Scaling::None, Rounding::SignificantDigits(precision)) => (Magnifier::None, -rounded_magnitude + *precision as i64 - 1),
+            (Scaling::Percent(whitespace_separation), rounding) =>
+            {
+                mantissa.shift(-2); // times 100, digit at 10^precision is at 10^(precision + 2) in the mantissa
+                (Magnifier::Prefix("%", *whitespace_separation), match rounding
+                {
+                    Rounding::Magnitude(precision) => -(*precision as i64) - 2,
+                    Rounding::SignificantDigits(precision) => -(rounded_magnitude + 2) + *precision as i64 - 1,
+                })
+            }
+            (Scaling::Scientific, rounding) =>
+            {
+                mantissa.shift(rounded_magnitude);
+                (Magnifier::Exponent(10, rounded_magnitude as f64), match rounding
+                {
+                    Rounding::Magnitude(precision) => rounded_magnitude - *precision as i64,
+                    Rounding::SignificantDigits(precision) => *precision as i64 - 1,
+                })
+            }
+        };
+        mantissa.round(dec_places.max(0) as usize); // negative number of decimal places are not allowed
+
+        return out(Some(Layout { magnifier, mantissa: &mantissa.to_string(), sign: self.sign_str(negative && *rounding != Rounding::SignificantDigits(0), mantissa.is_zero()) })); // rounded to 0 significants is unsigned 0 like in `Round::round_sig`
+    }
+
+
+    /// # Summary
+    /// Determines the magnitude of a rounded number for the unit prefix lookup, like `Formatter::rounded_magnitude` in decimal digit arithmetic.
+    ///
+    /// # Arguments
+    /// - `mantissa`: absolute value of the rounded number
+    ///
+    /// # Returns
+    /// - binary magnitude for binary scaling, decimal magnitude otherwise
+    fn exact_magnitude(&self, mantissa: &Digits) -> i64
+    {
+        if mantissa.is_zero()
+        {
+            return self.prefix_override.unwrap_or(0) as i64; // 0 has default magnitude and no unit prefix unless one is forced
+        }
+        if let Scaling::Binary(_) = self.scaling
+        {
+            return mantissa.binary_magnitude();
+        }
+
+        return mantissa.magnitude();
+    }
+
+
+    /// # Summary
+    /// Displays the scaled mantissa with sign, affixes, separators, and unit prefix or exponent in a single forward pass.
+    ///
+    /// # Arguments
+    /// - `sign`: the sign string, may be empty
+    /// - `mantissa`: ASCII digits of the absolute mantissa with at most one "." as decimal point, already with the decimal places to display
+    /// - `magnifier`: what to append after the mantissa
+    /// - `pad_zeros`: number of zeros to pad the integer part with
+    /// - `emit`: receives the pieces of the formatted number in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn render_mantissa(&self, sign: &str, mantissa: &str, magnifier: Magnifier, pad_zeros: usize, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let mut scanner: DigitScanner = DigitScanner::default(); // first pass over mantissa digits
+        let unit_mantissa: bool; // whether the mantissa is 1 and left out before the power
+
+
+        let magnifier: Magnifier = match magnifier
+        {
+            Magnifier::Exponent(..) if !self.zero_exponent && mantissa.bytes().all(|c| c == b'0' || c == b'.') => Magnifier::None, // 0 after rounding has no magnitude, display without exponent
+            magnifier => magnifier,
+        };
+        unit_mantissa = self.suppress_unit_mantissa
+            && !self.machine_readable
+            && matches!(magnifier, Magnifier::Exponent(..))
+            && mantissa.split('.').next() == Some("1")
+            && mantissa.bytes().skip(1).all(|c| c == b'0' || c == b'.'); // 1 with only zeros after the decimal point
+
+        self.emit_opening(sign, emit)?;
+
+        if !unit_mantissa
+        {
+            scanner.write_str(mantissa)?; // first pass: measure integer part and trailing zeros
+            let mut digits: DigitEmitter = DigitEmitter {
+                decimal_separator: &self.decimal_separator,
+                emit,
+                group_separator: &self.group_separator,
+                int_len: scanner.int_len(),
+                keep_len: scanner.keep_len(self.trailing_zeros),
+                leading_zeros: pad_zeros + self.min_integer_digits.saturating_sub(scanner.int_len()), // zero padding first, then zeros for minimum integer digits
+                pos: 0,
+                ungrouped_zeros: if self.zero_pad_grouped { 0 } else { pad_zeros },
+            };
+            digits.write_leading_zeros()?;
+            digits.write_str(mantissa)?; // second pass: emit digits with separators, optionally without trailing zeros and bare decimal separator
+        }
+
+        match magnifier // append unit prefix or base multiplier
+        {
+            Magnifier::Exponent(_base, exponent) if self.machine_readable =>
+            {
+                emit(PartKind::ExponentMarker, "e")?; // only base 10 without unit prefixes
+                write!(PartWriter {emit, kind: PartKind::ExponentDigits}, "{exponent}")?;
+            }
+            Magnifier::Exponent(base, exponent) =>
+            {
+                if !unit_mantissa
+                {
+                    emit(PartKind::ExponentMarker, " * ")?;
+                }
+                write!(PartWriter {emit, kind: PartKind::ExponentMarker}, "{base}")?;
+                emit(PartKind::ExponentMarker, "^(")?;
+                write!(PartWriter {emit, kind: PartKind::ExponentDigits}, "{exponent}")?;
+                emit(PartKind::ExponentMarker, ")")?;
+                self.emit_unit(true, mantissa, emit)?;
+            }
+            Magnifier::None =>
+            {
+                self.emit_prefix_padding(self.has_unit() as usize, emit)?; // below the first compact step, unit always after whitespace
+                self.emit_unit(true, mantissa, emit)?;
+            }
+            Magnifier::Prefix(prefix, whitespace_separation) =>
+            {
+                if whitespace_separation && !prefix.is_empty()
+                {
+                    emit(PartKind::Literal, " ")?; // add whitespace between number and unit prefix, but no trailing whitespace
+                }
+                if !prefix.is_empty()
+                {
+                    emit(PartKind::Prefix, prefix)?;
+                }
+                self.emit_prefix_padding(if prefix.is_empty() { (whitespace_separation && self.has_unit()) as usize } else { whitespace_separation as usize + prefix.chars().count() }, emit)?;
+                self.emit_unit(whitespace_separation && prefix.is_empty(), mantissa, emit)?; // unit directly after unit prefix
+            }
+            Magnifier::Suffix(suffix) =>
+            {
+                let symbol: &str = suffix.trim_start(); // suffix without whitespace before it
+                if symbol.len() < suffix.len()
+                {
+                    emit(PartKind::Literal, &suffix[..suffix.len() - symbol.len()])?;
+                }
+                if !symbol.is_empty()
+                {
+                    emit(PartKind::Prefix, symbol)?;
+                }
+                self.emit_unit(suffix.is_empty(), mantissa, emit)?; // unit directly after suffix like after a unit prefix
+            }
+        }
+
+        return self.emit_closing(sign, emit);
+    }
+
+
+    /// # Summary
+    /// Determines a rounding mode that displays the mantissa of the number with exactly the given number of decimal places, whatever unit prefix or exponent the number ends up with.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `decimals`: number of decimal places of the mantissa
+    ///
+    /// # Returns
+    /// - the rounding mode to format `x` with
+    pub(crate) fn rounding_for_decimals(&self, x: f64, decimals: usize) -> Rounding
+    {
+        let mut rounding: Rounding = self.rounding.clone(); // first estimate with configured rounding
+
+
+        if !x.is_finite()
+        // nothing to round
+        {
+            return rounding;
+        }
+        if self.scaling == Scaling::None
+        // mantissa is the number itself, round statically
+        {
+            return Rounding::Magnitude(-(decimals.min(i16::MAX as usize) as i16));
+        }
+        if let Scaling::Percent(_) = self.scaling
+        // mantissa is the number times 100, round statically 2 digits further
+        {
+            return Rounding::Magnitude(-(decimals.min(i16::MAX as usize - 2) as i16) - 2);
+        }
+
+        for _ in 0..2
+        // second pass in case rounding carries over into the next unit prefix or exponent
+        {
+            let (mantissa, _, _) = self.scale(x, &rounding, None);
+            let int_digits: usize = if mantissa.abs() < 1.0 { 1 } else { decimal_magnitude(mantissa) as usize + 1 }; // number of integer digits of mantissa
+            rounding = Rounding::SignificantDigits((int_digits + decimals).min(u8::MAX as usize) as u8);
+        }
+
+        return rounding;
+    }
+
+
+    /// # Summary
+    /// Rounds and scales a finite number according to rounding and scaling mode and determines how many decimal places to display.
+    ///
+    /// # Arguments
+    /// - `x`: the number to scale, must be finite
+    /// - `rounding`: rounding mode to use, usually the configured one
+    /// - `plan`: precomputed derived state of this formatter, or none to derive it on the way
+    ///
+    /// # Returns
+    /// - the mantissa, the number after rounding and applying magnitude shift for scaling
+    /// - the number of decimal places to display the mantissa with
+    /// - what to append after the mantissa
+    fn scale(&self, x: f64, rounding: &Rounding, plan: Option<&Plan>) -> (f64, usize, Magnifier)
+    {
+        let mut dec_places: i16; // number of decimal places to use, i16 instead of u16 to allow negative values during intermediate steps
+        let magnifier: Magnifier; // what to append after the mantissa
+        let mut magnitude: f64; // magnitude of the number, decimal 10^magnitude, binary 2^magnitude, or base^magnitude, floored exactly, f64 for the unit prefix lookup
+        let mantissa: f64; // number after applying magnitude shift for scaling
+        let mut prefix: Option<Prefix>; // unit prefix for magnitude, none if out of range or not scaling by unit prefix
+        let mut rounding: &Rounding = rounding; // rounding mode, replaced by the fallback rounding in scientific notation
+        let unrounded: f64 = x; // number before rounding, to round again with the fallback rounding
+
+
+        if let Scaling::Strategy(strategy) = &self.scaling
+        // custom logic chooses for the rounded number
+        {
+            return self.scale_by_choice(x, rounding, strategy.as_ref());
+        }
+
+        let mut x: f64 = x;
+        x = match rounding // rounded here already in case rounding changes magnitude
+        {
+            Rounding::Magnitude(precision) => x.round_mag(*precision), // round statically to digit at 10^magnitude
+            Rounding::SignificantDigits(precision) if *precision != 0 && matches!(self.scaling, Scaling::Exponential { .. }) => x, // significant digits of the mantissa, dividing by powers of an arbitrary base does not keep decimal digits
+            Rounding::SignificantDigits(precision) => x.round_sig(*precision), // round dynamically to significant numbers
+        };
+
+        magnitude = self.rounded_magnitude(x);
+        prefix = self.scaling_prefix(magnitude, plan); // try to find unit prefix for magnitude
+        if let Some(fallback) = self.fallback_rounding.as_ref().filter(|fallback| *fallback != rounding && prefix.is_none() && matches!(self.scaling, Scaling::Binary(_) | Scaling::Decimal(_) | Scaling::Scientific))
+        // scientific notation with a rounding of its own, round the original number once more instead of the rounded one, the notation stays even if the number now has a unit prefix
+        {
+            rounding = fallback;
+            x = match rounding
+            {
+                Rounding::Magnitude(precision) => unrounded.round_mag(*precision),
+                Rounding::SignificantDigits(precision) => unrounded.round_sig(*precision),
+            };
+            magnitude = self.rounded_magnitude(x);
+        }
+        if let (Scaling::Exponential { base, step, prefixes: _ }, Rounding::SignificantDigits(precision)) = (&self.scaling, rounding)
+        {
+            let exponent: i64 = exponential_exponent(*step, magnitude, prefix); // base^exponent to divide by
+            let rounded: f64 = divide_by_power(x.abs(), *base, exponent).round_sig(*precision); // mantissa rounded like it will be displayed
+            if rounded != 0.0 && magnitude < (exponent + exponential_magnitude(rounded, *base)) as f64
+            // rounding the mantissa carries over into the next magnitude, like 15,9999 * 16^(3) to 16,00 * 16^(3), choose unit prefix or exponent again
+            {
+                magnitude = (exponent + exponential_magnitude(rounded, *base)) as f64;
+                prefix = self.scaling_prefix(magnitude, plan);
+            }
+        }
+        if let (Scaling::Binary(_), None, Rounding::SignificantDigits(precision)) = (&self.scaling, prefix, rounding)
+        {
+            if 2.0 <= divide_by_power(x.abs(), 2, magnitude as i64).round_sig(*precision)
+            // rounding the mantissa of base 2 scientific notation carries over into the next power of 2, like 1,99987 * 2^(-10) to 2,000 * 2^(-10), as rounding the number decimally may fall just below a power of 2, choose unit prefix or exponent again
+            {
+                magnitude += 1.0;
+                if magnitude <= 1023.0
+                // the power of 2 the number is displayed as, beyond f64 only the exponent is increased
+                {
+                    x = pow2(magnitude as i64).copysign(x);
+                }
+                prefix = self.scaling_prefix(magnitude, plan);
+            }
+        }
+        if let (Some(forced), None, Scaling::Binary(_) | Scaling::Decimal(_) | Scaling::Scientific) = (self.prefix_override, prefix, &self.scaling)
+        // exponent of scientific notation forced for shared scaling, like a unit prefix
+        {
+            magnitude = forced as f64;
+        }
+
+        dec_places = match (&self.scaling, rounding) // decimal places required depending on scaling and rounding mode
+        {
+            (Scaling::Binary(_), Rounding::Magnitude(precision)) => // convert binary magnitude to decimal magnitude, then business as usual
+            {
+                match prefix
+                {
+                    Some(prefix) =>{decimal_magnitude(prefix.divisor) - precision - 1},
+                    None => {magnitude.floor() as i16} // fallback to scientific notation
+                }
+            },
+            (Scaling::Binary(_), Rounding::SignificantDigits(precision)) => // decimal magnitude of the mantissa, dividing by a power of 2 is exact, then business as usual
+            {
+                match prefix
+                {
+                    Some(prefix) =>{-decimal_magnitude(x / prefix.divisor) + *precision as i16 - 1},
+                    None => {*precision as i16 - 1} // fallback to scientific notation
+                }
+            }
+            (Scaling::CompactLocalized, Rounding::Magnitude(precision)) => prefix.map_or(0, |prefix| prefix.lower) - precision, // below the first step like no scaling
+            (Scaling::CompactLocalized, Rounding::SignificantDigits(precision)) => -((magnitude - prefix.map_or(0, |prefix| prefix.lower) as f64).floor() as i16) + *precision as i16 - 1,
+            (Scaling::Decimal(_), Rounding::Magnitude(precision)) =>
+            {
+                match prefix
+                {
+                    Some(prefix) =>{prefix.lower - precision}, // digit at 10^precision is at 10^(precision - prefix.lower) in the mantissa
+                    None => {magnitude.floor() as i16 - precision} // fallback to scientific notation, same rule with the exponent
+                }
+            },
+            (Scaling::Decimal(_), Rounding::SignificantDigits(precision)) =>
+            {
+                match prefix
+                {
+                    Some(prefix) =>{-((magnitude - prefix.lower as f64).floor() as i16) + *precision as i16 - 1},
+                    None => {*precision as i16 - 1} // fallback to scientific notation
+                }
+            }
+            (Scaling::Exponential { base, step, prefixes: _ }, Rounding::Magnitude(precision)) => // digit at 10^precision is at 10^precision / base^exponent in the mantissa
+            {
+                (exponential_exponent(*step, magnitude, prefix) as f64 * ((*base).max(2) as f64).log10()).ceil() as i16 - precision
+            }
+            (Scaling::Exponential { base, step, prefixes: _ }, Rounding::SignificantDigits(precision)) => // decimal magnitude of the mantissa is only known after dividing
+            {
+                match divide_by_power(x.abs(), *base, exponential_exponent(*step, magnitude, prefix)).round_sig(*precision)
+                {
+                    0.0 => *precision as i16 - 1,
+                    mantissa => -decimal_magnitude(mantissa) + *precision as i16 - 1,
+                }
+            }
+            (Scaling::None, Rounding::Magnitude(precision)) => -precision,
+            (Scaling::None, Rounding::SignificantDigits(precision)) => -(magnitude.floor() as i16) + *precision as i16 - 1,
+            (Scaling::Percent(_), Rounding::Magnitude(precision)) => -precision - 2, // digit at 10^precision is at 10^(precision + 2) in the mantissa
+            (Scaling::Percent(_), Rounding::SignificantDigits(precision)) => -(magnitude.floor() as i16 + 2) + *precision as i16 - 1,
+            (Scaling::Scientific, Rounding::Magnitude(precision)) => magnitude.floor() as i16 - precision,
+            (Scaling::Scientific, Rounding::SignificantDigits(precision)) => *precision as i16 - 1,
+            (Scaling::Strategy(_), _) => unreachable!("Custom strategies are scaled before."),
+        };
+        if dec_places < 0
+        {
+            dec_places = 0; // negative number of decimal places are not allowed
+        }
+
+        (mantissa, magnifier) = match self.scaling // apply magnitude shift for scaling
+        {
+            Scaling::None => (x, Magnifier::None), // no scaling
+            Scaling::Percent(whitespace_separation) => (x * 100.0, Magnifier::Prefix("%", whitespace_separation)), // only reached by `Formatter::rounding_for_decimals`, which needs the magnitude only
+            Scaling::Binary(whitespace_separation) => // binary scaling
+            {
+                match prefix
+                {
+                    Some(prefix) => (x / prefix.divisor, Magnifier::Prefix(prefix.symbol, whitespace_separation)), // divide by 2^magnitude
+                    None => (divide_by_power(x, 2, magnitude as i64), Magnifier::Exponent(2, magnitude)), // fallback to base 2 scientific notation, dividing by a power of 2 is exact, in two steps for 2^(1024)
+                }
+            }
+            Scaling::CompactLocalized => // localized compact suffixes
+            {
+                match prefix
+                {
+                    Some(prefix) => (x / prefix.divisor, Magnifier::Prefix(prefix.symbol, self.compact_locale().compact_space())), // divide by 10^magnitude
+                    None => (x, Magnifier::None), // below the first step, no scaling
+                }
+            }
+            Scaling::Decimal(whitespace_separation) => // decimal scaling
+            {
+                match prefix
+                {
+                    Some(prefix) => (x / prefix.divisor, Magnifier::Prefix(prefix.symbol, whitespace_separation)), // divide by 10^magnitude
+                    None => (divide_by_pow10(x, magnitude as i16), Magnifier::Exponent(10, magnitude)), // fallback to base 10 scientific notation
+                }
+            }
+            Scaling::Exponential { base, step, prefixes } => // exponential scaling
+            {
+                match (prefix, prefixes)
+                {
+                    (Some(prefix), Some(table)) => (divide_by_power(x, base, prefix.lower as i64), Magnifier::Prefix(prefix.symbol, table.space)), // divide by base^magnitude
+                    _ => (divide_by_power(x, base, exponential_exponent(step, magnitude, None)), Magnifier::Exponent(base.max(2), exponential_exponent(step, magnitude, None) as f64)), // exponent notation in multiples of step
+                }
+            }
+            Scaling::Scientific => (divide_by_pow10(x, magnitude as i16), Magnifier::Exponent(10, magnitude)), // scientific notation
+            Scaling::Strategy(_) => unreachable!("Custom strategies are scaled before."),
+        };
+
+        return (mantissa, dec_places as usize, magnifier);
+    }
+
+
+    /// # Summary
+    /// Determines the magnitude of a rounded number for the unit prefix lookup, in the base of the scaling.
+    ///
+    /// # Arguments
+    /// - `x`: the rounded number, must be finite
+    ///
+    /// # Returns
+    /// - decimal 10^magnitude, binary 2^magnitude, or base^magnitude, floored exactly
+    fn rounded_magnitude(&self, x: f64) -> f64
+    {
+        if x == 0.0
+        {
+            return self.prefix_override.unwrap_or(0) as f64; // 0 has default magnitude and no unit prefix unless one is forced, here because log(0) would shit itself
+        }
+
+        return match self.scaling // determine magnitude with rounded value in case rounding changes magnitude
+        {
+            Scaling::Binary(_) => binary_magnitude(x) as f64, // if scaling binary: binary magnitude 2^magnitude, from the bits of the f64
+            Scaling::Exponential { base, .. } => exponential_magnitude(x.abs(), base) as f64, // if scaling exponential: magnitude base^magnitude, floored exactly
+            _ => decimal_magnitude(x) as f64, // usually: decimal magnitude 10^magnitude, compared with exact powers of 10 instead of log10, whose rounding may flip a number at a unit prefix boundary
+        };
+    }
+
+
+    /// # Summary
+    /// Scales a number by the divisor a custom strategy chooses for it, rounded, for `Scaling::Strategy`. The decimal places are hinted by the strategy, which then replace the rounding, or derived from the rounding like for a unit prefix, with the decimal magnitude of the divisor in place of the one of the unit prefix.
+    ///
+    /// # Arguments
+    /// - `x`: the number to scale, must be finite
+    /// - `rounding`: rounding mode to use, usually the configured one
+    /// - `strategy`: the custom strategy
+    ///
+    /// # Returns
+    /// - mantissa, number of decimal places, and suffix to append
+    fn scale_by_choice(&self, x: f64, rounding: &Rounding, strategy: &(dyn ScaleStrategy + Send + Sync)) -> (f64, usize, Magnifier)
+    {
+        let choice: ScaleChoice; // what the strategy chose for the rounded number
+        let dec_places: i16; // number of decimal places to use, negative during intermediate steps
+        let rounded: f64 = match rounding // rounded before choosing in case rounding changes magnitude
+        {
+            Rounding::Magnitude(precision) => x.round_mag(*precision),
+            Rounding::SignificantDigits(precision) => x.round_sig(*precision),
+        };
+        let mut shift: f64; // decimal magnitude of the divisor
+
+
+        choice = strategy.choose(rounded);
+        shift = choice.divisor.abs().log10();
+        if (shift - shift.round()).abs() < 1e-9
+        // power of 10, exact despite the rounding error of log10
+        {
+            shift = shift.round();
+        }
+        dec_places = match (choice.decimals_hint, rounding)
+        {
+            (Some(decimals), _) => decimals as i16,
+            (None, Rounding::Magnitude(precision)) => shift.ceil() as i16 - precision, // digit at 10^precision is at 10^(precision - shift) in the mantissa
+            (None, Rounding::SignificantDigits(precision)) if rounded == 0.0 => *precision as i16 - 1,
+            (None, Rounding::SignificantDigits(precision)) => -((rounded.abs().log10() - shift).floor() as i16) + *precision as i16 - 1,
+        };
+
+        return (if choice.decimals_hint.is_some() { x } else { rounded } / choice.divisor, dec_places.max(0) as usize, Magnifier::Suffix(choice.suffix)); // hinted decimal places round the mantissa only once
+    }
+
+
+    /// # Summary
+    /// Looks up the unit prefix to scale a number by according to scaling mode, from the precomputed table if there is one.
+    ///
+    /// # Arguments
+    /// - `magnitude`: magnitude of the number in the base of the scaling mode
+    /// - `plan`: precomputed derived state of this formatter, or none to search the unit prefix table
+    ///
+    /// # Returns
+    /// - the unit prefix or none if magnitude is out of range and no prefix is forced, or not scaling by unit prefix
+    fn scaling_prefix(&self, magnitude: f64, plan: Option<&Plan>) -> Option<Prefix>
+    {
+        if let Some(plan) = plan
+        {
+            return plan.prefix(magnitude);
+        }
+
+        return match self.scaling
+        {
+            Scaling::Binary(_) => self.find_prefix(&BINARY_PREFIXES, magnitude).map(|(lower, _upper, symbol)| Prefix::new(2, *lower, symbol)),
+            Scaling::CompactLocalized => self.find_prefix(self.compact_locale().compact_suffixes(), magnitude).map(|(lower, _upper, symbol)| Prefix::new(10, *lower, symbol)),
+            Scaling::Decimal(_) => self.find_prefix(&DECIMAL_PREFIXES, magnitude).map(|(lower, _upper, symbol)| Prefix::new(10, *lower, symbol)),
+            Scaling::Exponential { base, step: _, prefixes: Some(table) } => self.find_prefix(table.prefixes, magnitude).map(|(lower, _upper, symbol)| Prefix::new(base, *lower, symbol)),
+            Scaling::Exponential { prefixes: None, .. } | Scaling::None | Scaling::Percent(_) | Scaling::Scientific | Scaling::Strategy(_) => None,
+        };
+    }
+
+
+    /// # Summary
+    /// Looks up the unit prefix for a magnitude. If a prefix is forced for shared scaling, that one is used regardless of the magnitude.
+    ///
+    /// # Arguments
+    /// - `prefixes`: the unit prefix table to search
+    /// - `magnitude`: magnitude of the number in the base of the table
+    ///
+    /// # Returns
+    /// - the unit prefix entry or none if magnitude is out of range and no prefix is forced
+    fn find_prefix(&self, prefixes: &'static [(i16, i16, &'static str)], magnitude: f64) -> Option<&'static (i16, i16, &'static str)>
+    {
+        return prefix_index(prefixes, magnitude, self.prefix_override).map(|i| &prefixes[i]);
+    }
+
+
+    /// # Summary
+    /// Determines the locale whose compact suffixes `Scaling::CompactLocalized` uses.
+    ///
+    /// # Returns
+    /// - the locale set with `Formatter::set_locale`, or English if none
+    pub(crate) fn compact_locale(&self) -> Locale
+    {
+        return self.locale.unwrap_or(Locale::EnUs);
+    }
+
+
+    /// # Summary
+    /// Emits a string of ASCII digits with at most one "." as decimal point like the digits of a formatted number: with group separators and the configured decimal separator, but without rounding or dropping any digit.
+    ///
+    /// # Arguments
+    /// - `digits`: the digits to emit, must only contain ASCII digits and at most one "."
+    /// - `emit`: receives the pieces in order
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    pub(crate) fn render_digits(&self, digits: &str, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let mut emitter: DigitEmitter = DigitEmitter {
+            decimal_separator: &self.decimal_separator,
+            emit,
+            group_separator: &self.group_separator,
+            int_len: digits.find('.').unwrap_or(digits.len()),
+            keep_len: digits.len(),
+            leading_zeros: 0,
+            pos: 0,
+            ungrouped_zeros: 0,
+        };
+
+        return emitter.write_str(digits);
+    }
+
+    /// # Summary
+    /// Determines the sign string according to sign mode.
+    ///
+    /// # Arguments
+    /// - `negative`: whether the number is negative
+    /// - `zero`: whether the number is 0 after rounding
+    ///
+    /// # Returns
+    /// - the sign string, may be empty
+    pub(crate) fn sign_str(&self, negative: bool, zero: bool) -> &'static str
+    {
+        return match (negative, &self.sign)
+        {
+            (_, Sign::ExceptZero) if zero => "",
+            (true, _) => "-",
+            (false, Sign::Always | Sign::ExceptZero) => "+",
+            (false, Sign::OnlyMinus) => "",
+            (false, Sign::SpaceForPositive) => " ",
+        };
+    }
+
+
+    /// # Summary
+    /// Emits everything before the number: prefix affix and leading sign according to sign placement and sign position.
+    ///
+    /// # Arguments
+    /// - `sign`: the sign string, may be empty
+    /// - `emit`: receives the pieces
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn emit_opening(&self, sign: &str, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let leading_sign: &str = match self.sign_placement
+        {
+            SignPlacement::Leading => sign,
+            SignPlacement::Parentheses if sign == "-" => "(", // negative enclosed in parentheses instead
+            SignPlacement::Parentheses => sign,
+            SignPlacement::Trailing => "", // sign position only concerns leading sign
+        };
+
+        if self.sign_position == SignPosition::BeforeAffix && !leading_sign.is_empty()
+        {
+            emit(PartKind::Sign, leading_sign)?;
+        }
+        if !self.affix_prefix.is_empty()
+        {
+            emit(PartKind::Affix, &self.affix_prefix)?;
+        }
+        if self.sign_position == SignPosition::AfterAffix && !leading_sign.is_empty()
+        {
+            emit(PartKind::Sign, leading_sign)?;
+        }
+
+        return Ok(());
+    }
+
+
+    /// # Summary
+    /// Emits the unit, if configured.
+    ///
+    /// # Arguments
+    /// - `whitespace`: whether to put whitespace before the unit
+    /// - `mantissa`: absolute displayed number the plural rule decides on, like "1.000" or "inf"
+    /// - `emit`: receives the pieces
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn emit_unit(&self, whitespace: bool, mantissa: &str, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        if self.unit.is_empty() && self.unit_plural.is_empty()
+        {
+            return Ok(());
+        }
+
+        if whitespace
+        {
+            emit(PartKind::Literal, " ")?;
+        }
+        if self.unit_plural.is_empty()
+        // no plural, no need to parse the number
+        {
+            emit(PartKind::Unit, &self.unit)?;
+        }
+        else
+        {
+            emit(PartKind::Unit, self.unit_for(mantissa.parse().unwrap_or(f64::NAN)))?;
+        }
+
+        return Ok(());
+    }
+
+
+    /// # Summary
+    /// Emits spaces that pad the unit prefix and the whitespace before it to the widest unit prefix of the table, if reserved with `Formatter::set_reserve_prefix_width`.
+    ///
+    /// # Arguments
+    /// - `used`: characters of the unit prefix slot already emitted, unit prefix and whitespace before it or before the unit
+    /// - `emit`: receives the padding
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn emit_prefix_padding(&self, used: usize, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        let (prefixes, whitespace_separation) = match (self.reserve_prefix_width, &self.scaling)
+        {
+            (false, _) => return Ok(()),
+            (true, Scaling::Binary(whitespace_separation)) => (&BINARY_PREFIXES[..], *whitespace_separation),
+            (true, Scaling::CompactLocalized) => (self.compact_locale().compact_suffixes(), self.compact_locale().compact_space()),
+            (true, Scaling::Decimal(whitespace_separation)) => (&DECIMAL_PREFIXES[..], *whitespace_separation),
+            (true, Scaling::Exponential { prefixes: Some(table), .. }) => (table.prefixes, table.space),
+            (true, Scaling::Exponential { prefixes: None, .. } | Scaling::None | Scaling::Percent(_) | Scaling::Scientific | Scaling::Strategy(_)) => return Ok(()), // no unit prefixes to reserve room for
+        }; // unit prefix table and whether whitespace separates number and unit prefix
+        let widest: usize = whitespace_separation as usize + prefixes.iter().map(|(_lower, _upper, prefix)| prefix.chars().count()).max().unwrap_or(0); // characters of the widest unit prefix with whitespace
+
+
+        for _ in used..widest
+        {
+            emit(PartKind::Padding, " ")?;
+        }
+
+        return Ok(());
+    }
+
+
+    /// # Summary
+    /// Determines whether a unit is configured, singular or plural.
+    ///
+    /// # Returns
+    /// - whether a unit is displayed after the number
+    fn has_unit(&self) -> bool
+    {
+        return !(self.unit.is_empty() && self.unit_plural.is_empty());
+    }
+
+
+    /// # Summary
+    /// Chooses singular or plural of the unit for a displayed number with the plural rule.
+    ///
+    /// # Arguments
+    /// - `x`: the displayed number, rounded and scaled
+    ///
+    /// # Returns
+    /// - the unit to display, may be empty
+    pub(crate) fn unit_for(&self, x: f64) -> &str
+    {
+        if self.unit_plural.is_empty() || self.plural_rule.category(x) == PluralCategory::One
+        {
+            return &self.unit;
+        }
+        return &self.unit_plural;
+    }
+
+
+    /// # Summary
+    /// Emits everything after the number: suffix affix and trailing sign according to sign placement.
+    ///
+    /// # Arguments
+    /// - `sign`: the sign string, may be empty
+    /// - `emit`: receives the pieces
+    ///
+    /// # Returns
+    /// - nothing or the error of `emit`
+    fn emit_closing(&self, sign: &str, emit: &mut dyn FnMut(PartKind, &str) -> std::fmt::Result) -> std::fmt::Result
+    {
+        if !self.affix_suffix.is_empty()
+        {
+            emit(PartKind::Affix, &self.affix_suffix)?;
+        }
+        if self.sign_placement == SignPlacement::Trailing && !sign.is_empty()
+        {
+            emit(PartKind::Sign, sign)?;
+        }
+        if self.sign_placement == SignPlacement::Parentheses && sign == "-"
+        {
+            emit(PartKind::Sign, ")")?;
+        }
+
+        return Ok(());
+    }
+}
+
+
+/// # Summary
+/// Non-negative decimal number as digits, for exact arithmetic on numbers beyond the precision or range of f64. Only the digits between the first and last non-zero one need to be stored, the decimal point may lie anywhere before, within, or after them.
+#[derive(Clone)]
+pub(crate) struct Digits
+{
+    digits:  Vec<u8>, // decimal digits as values 0 to 9, the first one at 10^(int_len - 1)
+    int_len: i64,     // position of the decimal point relative to the first digit, number of integer digits if positive
+}
+
+
+impl Digits
+{
+    /// # Summary
+    /// Creates the decimal number `digits` * 10^(-`scale`).
+    fn new(digits: u128, scale: u32) -> Self
+    {
+        let digits: Vec<u8> = digits.to_string().bytes().map(|digit| digit - b'0').collect(); // all digits, integer and fraction
+
+
+        return Self { int_len: digits.len() as i64 - scale as i64, digits };
+    }
+
+
+    /// # Summary
+    /// Creates the decimal number `digits` * 10^(`exponent`) from a string of ASCII digits.
+    fn from_ascii(digits: &str, exponent: i64) -> Self
+    {
+        let digits: Vec<u8> = digits.bytes().map(|digit| digit - b'0').collect(); // all digits, integer and fraction
+
+
+        return Self { int_len: (digits.len() as i64).saturating_add(exponent), digits };
+    }
+
+
+    /// # Summary
+    /// Creates the exact decimal expansion of a finite, non-negative f64. Every f64 is an integer times a power of 2, so its expansion is finite.
+    pub(crate) fn from_f64(x: f64) -> Self
+    {
+        let bits: u64 = x.to_bits();
+        let exponent: i64 = (bits >> 52 & 0x7FF) as i64; // biased binary exponent, 0 for subnormals
+        let mut digits: Digits; // result
+        let significand: u64 = bits & ((1 << 52) - 1); // without implicit leading 1
+
+
+        if exponent == 0
+        // subnormal, no implicit leading 1
+        {
+            digits = Digits::new(significand as u128, 0);
+            digits.halve(1074);
+        }
+        else if exponent < 1075
+        {
+            digits = Digits::new((significand | 1 << 52) as u128, 0);
+            digits.halve((1075 - exponent) as u64);
+        }
+        else
+        {
+            digits = Digits::new((significand | 1 << 52) as u128, 0);
+            digits.double((exponent - 1075) as u64);
+        }
+        return digits;
+    }
+
+
+    /// # Summary
+    /// Binary magnitude, floor of log2, must not be 0. Estimated from the decimal magnitude first, then determined exactly by scaling a copy into [1; 2[.
+    fn binary_magnitude(&self) -> i64
+    {
+        let mut exponent: i64 = (self.magnitude() as i128 * 33219280948873623 / 10_i128.pow(16)) as i64; // floor(magnitude * log2(10)), at most a few below the result
+        let mut scaled: Digits = Digits { digits: self.digits.clone(), int_len: self.int_len }; // number divided by 2^(exponent)
+
+
+        if exponent < 0
+        {
+            scaled.double(exponent.unsigned_abs());
+        }
+        else
+        {
+            scaled.halve(exponent.unsigned_abs());
+        }
+        while 0 < scaled.magnitude() || scaled.magnitude() == 0 && 2 <= scaled.leading_digit()
+        // at least 2
+        {
+            scaled.halve(1);
+            exponent += 1;
+        }
+        while scaled.magnitude() < 0
+        // below 1
+        {
+            scaled.double(1);
+            exponent -= 1;
+        }
+        return exponent;
+    }
+
+
+    /// # Summary
+    /// Multiplies by 2^(`exponent`) exactly, up to 2^(56) at a time.
+    fn double(&mut self, exponent: u64)
+    {
+        let mut remaining: u64 = exponent; // exponent still to multiply with
+
+
+        while 0 < remaining
+        {
+            let bits: u64 = remaining.min(56); // digit * 2^bits + carry stays below 10 * 2^bits, fits into u64
+            let mut carry: u64 = 0; // carry into previous digit
+            let mut carry_digits: Vec<u8> = Vec::new(); // new leading digits from the final carry, least significant first
+
+            for digit in self.digits.iter_mut().rev()
+            {
+                let current: u64 = ((*digit as u64) << bits) + carry;
+                *digit = (current % 10) as u8;
+                carry = current / 10;
+            }
+            while 0 < carry
+            {
+                carry_digits.push((carry % 10) as u8);
+                carry /= 10;
+            }
+            self.int_len += carry_digits.len() as i64;
+            self.digits.splice(0..0, carry_digits.into_iter().rev());
+            remaining -= bits;
+        }
+    }
+
+
+    /// # Summary
+    /// Divides by 2^(`exponent`) exactly, up to 2^(56) at a time. Every halving adds at most one digit.
+    fn halve(&mut self, exponent: u64)
+    {
+        let mut remaining: u64 = exponent; // exponent still to divide by
+
+
+        while 0 < remaining
+        {
+            let bits: u64 = remaining.min(56); // remainder * 10 + digit stays below 10 * 2^bits, fits into u64
+            let leading_zeros: usize; // number of leading zeros to remove
+            let mut remainder: u64 = 0; // remainder of previous digits
+
+            for digit in self.digits.iter_mut()
+            {
+                let current: u64 = remainder * 10 + *digit as u64;
+                *digit = (current >> bits) as u8;
+                remainder = current & ((1 << bits) - 1);
+            }
+            while 0 < remainder
+            // division by a power of 2 terminates, at most one new digit per halving
+            {
+                let current: u64 = remainder * 10;
+                self.digits.push((current >> bits) as u8);
+                remainder = current & ((1 << bits) - 1);
+            }
+            leading_zeros = self.digits.iter().take(self.digits.len() - 1).take_while(|digit| **digit == 0).count(); // keep at least one digit
+            self.digits.drain(..leading_zeros);
+            self.int_len -= leading_zeros as i64;
+            remaining -= bits;
+        }
+    }
+
+
+    /// # Summary
+    /// Whether the number is 0.
+    fn is_zero(&self) -> bool
+    {
+        return self.digits.iter().all(|digit| *digit == 0);
+    }
+
+
+    /// # Summary
+    /// First non-zero digit, 0 for 0.
+    fn leading_digit(&self) -> u8
+    {
+        return self.digits.iter().find(|digit| **digit != 0).copied().unwrap_or(0);
+    }
+
+
+    /// # Summary
+    /// Decimal magnitude, floor of log10, 0 for 0.
+    fn magnitude(&self) -> i64
+    {
+        return match self.digits.iter().position(|digit| *digit != 0)
+        {
+            Some(first) => self.int_len - first as i64 - 1,
+            None => 0,
+        };
+    }
+
+
+    /// # Summary
+    /// Rounds to the given number of decimal places, ties to even, and writes out all digits from the first integer digit to the last decimal place, padded with zeros where necessary.
+    pub(crate) fn round(&mut self, dec_places: usize)
+    {
+        self.round_mag(-(dec_places as i64));
+        if self.int_len < 1
+        // number below 1, leading zeros down to "0."
+        {
+            self.digits.splice(0..0, std::iter::repeat_n(0, (1 - self.int_len) as usize));
+            self.int_len = 1;
+        }
+        if (self.digits.len() as i64) < self.int_len
+        // trailing integer zeros are implied, store them so leading zeros are removed from all integer digits, like from 0 times 100
+        {
+            self.digits.resize(self.int_len as usize, 0);
+        }
+        while 1 < self.int_len && self.digits.first() == Some(&0)
+        // leading zeros of integer part
+        {
+            self.digits.remove(0);
+            self.int_len -= 1;
+        }
+        self.digits.resize(self.int_len as usize + dec_places, 0); // rounded away digits are 0
+    }
+
+
+    /// # Summary
+    /// Rounds according to a rounding mode like `Formatter::scale` does, to 0 for 0 significant digits. Rounded away digits are removed.
+    fn round_to(&mut self, rounding: &Rounding)
+    {
+        self.round_mag(match rounding
+        {
+            Rounding::Magnitude(precision) => *precision as i64,
+            Rounding::SignificantDigits(0) => i64::MAX, // rounded to 0 significants is always 0
+            Rounding::SignificantDigits(precision) => self.magnitude() - *precision as i64 + 1,
+        });
+    }
+
+
+    /// # Summary
+    /// Rounds to the digit at 10^(`magnitude`) like `Round::round_mag`, ties to even, but exactly. Rounded away digits are removed.
+    pub(crate) fn round_mag(&mut self, magnitude: i64)
+    {
+        let drop: i64 = (self.digits.len() as i64 - self.int_len).saturating_add(magnitude); // number of last digits to round away
+        let len: usize = self.digits.len(); // number of digits before rounding
+
+
+        if drop <= 0
+        // nothing to round
+        {
+            return;
+        }
+        if (len as i64) < drop
+        // less than half of the rounding unit
+        {
+            *self = Digits { digits: vec![0], int_len: 1 };
+            return;
+        }
+        self.digits = round_digits(std::mem::take(&mut self.digits), drop as usize);
+        if len < self.digits.len()
+        // carried into a new digit
+        {
+            self.int_len += 1;
+        }
+        self.digits.truncate(self.digits.len().saturating_sub(drop as usize)); // rounded away digits are 0
+        if self.digits.is_empty()
+        // nothing left
+        {
+            *self = Digits { digits: vec![0], int_len: 1 };
+        }
+    }
+
+
+    /// # Summary
+    /// Converts to the closest f64, infinity beyond its range.
+    pub(crate) fn to_f64(&self) -> f64
+    {
+        let digits: String = self.digits.iter().map(|digit| (b'0' + digit) as char).collect(); // all stored digits, integer and fraction
+
+
+        return format!("{digits}e{}", self.int_len - self.digits.len() as i64).parse().expect("Digits with exponent are not a valid f64 even though they consist of digits only.");
+    }
+
+
+    /// # Summary
+    /// Divides by 10^(`exponent`) exactly by moving the decimal point, multiplies for negative exponents.
+    fn shift(&mut self, exponent: i64)
+    {
+        self.int_len -= exponent;
+    }
+}
+
+
+impl std::fmt::Display for Digits
+{
+    /// # Summary
+    /// Writes the digits with the decimal point, requires `Digits::round` before so all digits are written out.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        for (i, digit) in self.digits.iter().enumerate()
+        {
+            if i as i64 == self.int_len
+            {
+                f.write_char('.')?;
+            }
+            f.write_char((b'0' + digit) as char)?;
+        }
+        return Ok(());
+    }
+}
+
+
+/// # Summary
+/// Computes the index of the unit prefix for a magnitude arithmetically, because the bounds of a unit prefix table are integers and contiguous in steps of equal size. Tables with steps of different size, like localized compact suffixes, are searched instead. Selects the same entry as searching for the one with lower bound ≤ magnitude < upper bound, for the table as well as for the forced prefix.
+///
+/// # Arguments
+/// - `prefixes`: the unit prefix table
+/// - `magnitude`: magnitude of the number in the base of the table
+/// - `forced`: lower bound magnitude of the unit prefix to use regardless of the magnitude, if any
+///
+/// # Returns
+/// - index of the unit prefix entry or none if magnitude is out of range, or the forced prefix is not in the table
+fn prefix_index(prefixes: &[(i16, i16, &str)], magnitude: f64, forced: Option<i16>) -> Option<usize>
+{
+    let mut i: i64; // index of the entry whose range contains the floored magnitude
+    let magnitude: f64 = forced.map_or(magnitude, f64::from); // forced prefix is the one whose range starts at its lower bound
+    let (start, step): (i64, i64) = match prefixes.first() // lower bound of the table and range of every entry
+    {
+        Some((lower, upper, _prefix)) => (*lower as i64, (*upper - *lower) as i64),
+        None => return None,
+    };
+
+
+    if !magnitude.is_finite()
+    // out of every range
+    {
+        return None;
+    }
+
+    i = (magnitude.floor() as i64 - start).div_euclid(step); // bounds are integers, so the floored magnitude lies in the same range
+    if i < 0 || prefixes.len() as i64 <= i || !(prefixes[i as usize].0 as f64 <= magnitude.floor() && magnitude.floor() < prefixes[i as usize].1 as f64)
+    // out of range, or steps of different size like localized compact suffixes, whose entry is searched instead
+    {
+        i = prefixes.iter().position(|(lower, upper, _prefix)| *lower as f64 <= magnitude.floor() && magnitude.floor() < *upper as f64)? as i64;
+    }
+    if forced.is_some_and(|forced| forced != prefixes[i as usize].0)
+    // forced magnitude is not a lower bound in the table
+    {
+        return None;
+    }
+
+    return Some(i as usize);
+}
+
+
+/// # Summary
+/// Divides a number by a power of a base, in two steps so the power neither overflows nor underflows where the quotient does not.
+///
+/// # Arguments
+/// - `x`: the number to divide
+/// - `base`: base of the power, below 2 treated as 2
+/// - `exponent`: exponent of the power
+///
+/// # Returns
+/// - x / base^exponent
+pub(crate) fn divide_by_power(x: f64, base: u32, exponent: i64) -> f64
+{
+    let base: f64 = base.max(2) as f64;
+    let half: i32 = (exponent / 2) as i32; // first step, the second one is the rest
+
+
+    return x / base.powi(half) / base.powi((exponent - half as i64) as i32);
+}
+
+
+/// # Summary
+/// Divides a number by a power of 10 looked up exactly, in two steps below 10^(-307), where the power is subnormal and too imprecise to divide by.
+///
+/// # Arguments
+/// - `x`: the number to divide
+/// - `exponent`: exponent of the power
+///
+/// # Returns
+/// - x / 10^exponent
+fn divide_by_pow10(x: f64, exponent: i16) -> f64
+{
+    if exponent < -307
+    // 10^(-exponent) overflows, 10^(exponent) is subnormal
+    {
+        return x * pow10(-exponent - 16) * pow10(16);
+    }
+    return x / pow10(exponent);
+}
+
+
+/// # Summary
+/// Determines the exponent of exponential scaling: the lower bound of the unit prefix if there is one, otherwise the magnitude floored to a multiple of the step.
+///
+/// # Arguments
+/// - `step`: exponents are multiples of it, 0 treated as 1
+/// - `magnitude`: floored magnitude of the number in the base of exponential scaling
+/// - `prefix`: unit prefix the number is scaled by, if any
+///
+/// # Returns
+/// - the exponent to divide the number by the base to the power of
+fn exponential_exponent(step: u32, magnitude: f64, prefix: Option<Prefix>) -> i64
+{
+    let step: i64 = step.max(1) as i64;
+
+
+    return match prefix
+    {
+        Some(prefix) => prefix.lower as i64,
+        None => (magnitude as i64).div_euclid(step) * step,
+    };
+}
+
+
+/// # Summary
+/// Determines the magnitude of a number in an arbitrary base, floored. Computed with logarithms and then corrected, because their quotient may be just below a whole number where the number is an exact power of the base.
+///
+/// # Arguments
+/// - `x`: the number, must be positive and finite
+/// - `base`: base of the magnitude, below 2 treated as 2
+///
+/// # Returns
+/// - the largest integer magnitude with base^magnitude ≤ x
+pub(crate) fn exponential_magnitude(x: f64, base: u32) -> i64
+{
+    let mut magnitude: i64 = (x.ln() / (base.max(2) as f64).ln()).floor() as i64; // estimate, off by at most 1
+
+
+    if !x.is_finite()
+    // rounding overflowed beyond f64, no magnitude
+    {
+        return 0;
+    }
+    if divide_by_power(x, base, magnitude + 1) >= 1.0
+    // estimate too low
+    {
+        magnitude += 1;
+    }
+    else if divide_by_power(x, base, magnitude) < 1.0
+    // estimate too high
+    {
+        magnitude -= 1;
+    }
+
+    return magnitude;
+}
+
+
+/// # Summary
+/// Rounds decimal digits to zero the last `drop` of them like `Round` rounds f64, ties to even, but exactly.
+///
+/// # Arguments
+/// - `digits`: decimal digits as values 0 to 9
+/// - `drop`: number of last digits to round away, may exceed the number of digits
+///
+/// # Returns
+/// - the rounded digits, same length, one digit longer if rounding up carried over all digits, or `[0]` if nothing is left
+fn round_digits(mut digits: Vec<u8>, drop: usize) -> Vec<u8>
+{
+    let keep: usize; // number of digits kept
+    let round_up: bool;
+
+
+    if drop == 0
+    {
+        return digits;
+    }
+    if digits.len() < drop
+    // less than half of the rounding unit
+    {
+        return vec![0];
+    }
+
+    keep = digits.len() - drop;
+    round_up = match digits[keep]
+    {
+        0..=4 => false,
+        5 if digits[keep + 1..].iter().all(|digit| *digit == 0) => keep != 0 && digits[keep - 1] % 2 == 1, // tie, to even
+        _ => true,
+    };
+    digits[keep..].fill(0);
+    if round_up
+    {
+        match digits[..keep].iter().rposition(|digit| *digit != 9)
+        {
+            Some(last) =>
+            {
+                digits[last] += 1;
+                digits[last + 1..keep].fill(0);
+            }
+            None => // all 9s or nothing kept, carry into new digit
+            {
+                digits[..keep].fill(0);
+                digits.insert(0, 1);
+            }
+        }
+    }
+    if keep == 0 && !round_up
+    // nothing left
+    {
+        return vec![0];
+    }
+
+    return digits;
+}
+
+
+/// # Summary
+/// Writes a whole number with the given number of decimal places, all of them 0, like `write!(out, "{:.*}", dec_places, n as f64)` but without float formatting.
+///
+/// # Arguments
+/// - `out`: where to write the digits to
+/// - `n`: the whole number to write
+/// - `dec_places`: number of decimal places to write
+///
+/// # Returns
+/// - nothing or the error of `out`
+fn write_integer<W>(out: &mut W, n: u64, dec_places: usize) -> std::fmt::Result
+where
+    W: Write + ?Sized,
+{
+    write!(out, "{n}")?;
+    if 0 < dec_places
+    {
+        out.write_char('.')?;
+        write_zeros(out, dec_places)?;
+    }
+
+    return Ok(());
+}
+
+
+/// # Summary
+/// Writes zeros in as few pieces as possible.
+///
+/// # Arguments
+/// - `out`: where to write the zeros to
+/// - `n`: number of zeros to write
+///
+/// # Returns
+/// - nothing or the error of `out`
+pub(crate) fn write_zeros<W>(out: &mut W, n: usize) -> std::fmt::Result
+where
+    W: Write + ?Sized,
+{
+    const ZEROS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+    let mut remaining: usize = n; // number of zeros still to write
+
+
+    while 0 < remaining
+    {
+        out.write_str(&ZEROS[..remaining.min(ZEROS.len())])?;
+        remaining -= remaining.min(ZEROS.len());
+    }
+
+    return Ok(());
+}
+
+
+/// # Summary
+/// First pass over the digits of an unsigned mantissa. Measures the integer part and the position of the last significant fraction digit, so the second pass can insert group separators and drop trailing zeros without looking ahead.
+#[derive(Default)]
+struct DigitScanner
+{
+    last_nonzero_end: usize, // index after the last fraction digit that is not 0, 0 if none
+    len:              usize, // total number of characters
+    point:            Option<usize>, // index of the decimal point, if any
+}
+
+
+impl DigitScanner
+{
+    /// # Summary
+    /// Number of integer digits.
+    fn int_len(&self) -> usize
+    {
+        return self.point.unwrap_or(self.len);
+    }
+
+
+    /// # Summary
+    /// Number of characters to keep. Without trailing zeros, trailing fraction zeros and a then bare decimal point are dropped.
+    fn keep_len(&self, trailing_zeros: bool) -> usize
+    {
+        if trailing_zeros || self.point.is_none()
+        {
+            return self.len;
+        }
+        return self.last_nonzero_end.max(self.int_len());
+    }
+}
+
+
+impl Write for DigitScanner
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result
+    {
+        for (i, c) in s.bytes().enumerate()
+        {
+            let pos: usize = self.len + i; // position in whole number
+            if c == b'.'
+            {
+                self.point = Some(pos);
+            }
+            else if self.point.is_some() && c != b'0'
+            {
+                self.last_nonzero_end = pos + 1;
+            }
+        }
+        self.len += s.len();
+
+        return Ok(());
+    }
+}
+
+
+/// # Summary
+/// Second pass over the digits of an unsigned mantissa. Emits leading zeros, integer digits with group separators, the decimal separator, and the fraction digits, stops after the characters to keep.
+struct DigitEmitter<'a>
+{
+    decimal_separator: &'a str,
+    emit:              &'a mut dyn FnMut(PartKind, &str) -> std::fmt::Result,
+    group_separator:   &'a str,
+    int_len:           usize, // number of integer digits, from first pass
+    keep_len:          usize, // number of characters to keep, from first pass
+    leading_zeros:     usize, // number of zeros to emit before the first digit
+    pos:               usize, // position in whole number of first character of next chunk
+    ungrouped_zeros:   usize, // number of leading zeros not to separate into groups
+}
+
+
+impl DigitEmitter<'_>
+{
+    /// # Summary
+    /// Whether a group separator belongs before the integer digit at position `pos` counted from the first leading zero.
+    fn separator_before(&self, pos: usize) -> bool
+    {
+        let int_len: usize = self.leading_zeros + self.int_len; // number of integer digits including leading zeros
+
+        return !self.group_separator.is_empty() && self.ungrouped_zeros < pos && pos < int_len && (int_len - pos).is_multiple_of(3);
+    }
+
+
+    /// # Summary
+    /// Emits the leading zeros, must be called before writing the digits.
+    fn write_leading_zeros(&mut self) -> std::fmt::Result
+    {
+        for pos in 0..self.leading_zeros
+        {
+            if self.separator_before(pos)
+            {
+                (self.emit)(PartKind::GroupSeparator, self.group_separator)?;
+            }
+            (self.emit)(PartKind::IntegerDigits, "0")?;
+        }
+        return Ok(());
+    }
+}
+
+
+impl Write for DigitEmitter<'_>
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result
+    {
+        let mut run_start: usize = 0; // start of not yet emitted digits in s
+        let end: usize = s.len().min(self.keep_len.saturating_sub(self.pos)); // characters after end are dropped
+
+
+        for i in 0..end
+        {
+            let pos: usize = self.pos + i; // position in whole number
+            if pos == self.int_len
+            // decimal point
+            {
+                if run_start < i
+                {
+                    (self.emit)(PartKind::IntegerDigits, &s[run_start..i])?;
+                }
+                (self.emit)(PartKind::DecimalSeparator, self.decimal_separator)?;
+                run_start = i + 1;
+            }
+            else if pos < self.int_len && self.separator_before(self.leading_zeros + pos)
+            // start of new group of 3 integer digits
+            {
+                if run_start < i
+                {
+                    (self.emit)(PartKind::IntegerDigits, &s[run_start..i])?;
+                }
+                (self.emit)(PartKind::GroupSeparator, self.group_separator)?;
+                run_start = i;
+            }
+        }
+        if run_start < end
+        // emit remaining digits of this chunk
+        {
+            let kind: PartKind = if self.pos + run_start < self.int_len { PartKind::IntegerDigits } else { PartKind::FractionDigits };
+            (self.emit)(kind, &s[run_start..end])?;
+        }
+        self.pos += s.len();
+
+        return Ok(());
+    }
+}
+
+
+/// # Summary
+/// Forwards everything written to it as pieces of a single kind.
+struct PartWriter<'a>
+{
+    emit: &'a mut dyn FnMut(PartKind, &str) -> std::fmt::Result,
+    kind: PartKind,
+}
+
+
+impl Write for PartWriter<'_>
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result
+    {
+        if s.is_empty()
+        {
+            return Ok(());
+        }
+        return (self.emit)(self.kind, s);
+    }
+}
+
+
+/// # Summary
+/// Text of at most `N` bytes on the stack, to format digits without heap allocation. Writing more than fits fails.
+pub(crate) struct StackString<const N: usize>
+{
+    buffer: [u8; N],
+    len:    usize, // number of bytes used
+}
+
+
+impl<const N: usize> StackString<N>
+{
+    /// # Summary
+    /// Creates an empty text.
+    pub(crate) fn new() -> Self
+    {
+        return StackString { buffer: [0; N], len: 0 };
+    }
+
+
+    /// # Summary
+    /// The text written so far.
+    pub(crate) fn as_str(&self) -> &str
+    {
+        return std::str::from_utf8(&self.buffer[..self.len]).expect("Stack string is not valid UTF-8 even though only whole strings are written into it.");
+    }
+}
+
+
+impl<const N: usize> Write for StackString<N>
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result
+    {
+        if N - self.len < s.len()
+        // does not fit, write nothing
+        {
+            return Err(std::fmt::Error);
+        }
+        self.buffer[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+
+        return Ok(());
     }
 }
@@ -0,0 +1,227 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a number as the nearest fraction with a bounded denominator, for imperial measurements and recipes, "2 3/8" for 2.37 in eighths. The number is rounded to the nearest multiple of 1 / `max_denominator`, ties to even numerator, then the fraction is reduced, so 0.744 in sixteenths is 12/16 and displayed as "3/4". Numbers that round to a whole number drop the fractional part. Whether to display mixed numbers or improper fractions and whether to use Unicode vulgar fraction glyphs like "¾" where one exists is set with `Formatter::set_fraction`. The integer part, or the numerator if there is none, is signed like in `Formatter::format`, all integers are grouped, affixes and unit apply, scaling and rounding do not. Negative numbers rounded to 0 keep their sign like there, -0 is unsigned. Infinity and NaN are formatted like in `Formatter::format`. The configured width applies to the result as a whole.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    /// - `max_denominator`: the finest fraction to round to, 0 is treated like 1
+    ///
+    /// # Returns
+    /// - formatted fraction
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_fraction(0.744, 16), "3/4"); // 12/16 reduced
+    /// assert_eq!(f.format_fraction(2.37, 8), "2 3/8");
+    /// assert_eq!(f.format_fraction(-2.37, 8), "-2 3/8");
+    /// assert_eq!(f.format_fraction(2.99, 16), "3"); // whole number
+    /// assert_eq!(f.format_fraction(1234.5, 2), "1.234 1/2");
+    /// assert_eq!(f.format_fraction(0.01, 16), "0");
+    /// assert_eq!(f.format_fraction(f64::INFINITY, 16), "∞");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_fraction(scaler::FractionOptions { glyphs: true, mixed: true })
+    ///     .set_unit("in");
+    /// assert_eq!(f.format_fraction(0.75, 16), "¾ in");
+    /// assert_eq!(f.format_fraction(2.375, 16), "2⅜ in");
+    /// assert_eq!(f.format_fraction(2.3125, 16), "2 5/16 in"); // no glyph
+    /// let f: scaler::Formatter = f.set_fraction(scaler::FractionOptions { glyphs: false, mixed: false });
+    /// assert_eq!(f.format_fraction(2.375, 16), "19/8 in");
+    /// assert_eq!(f.format_fraction(-0.75, 16), "-3/4 in");
+    /// ```
+    pub fn format_fraction(&self, x: f64, max_denominator: u32) -> String
+    {
+        let bits: u64 = x.to_bits();
+        let denominator: u128 = max_denominator.max(1) as u128; // of the unrounded fraction
+        let divisor: u128; // greatest common divisor of fractional numerator and denominator
+        let negative: bool = x.is_sign_negative() && x != 0.0; // -0 is unsigned
+        let numerator: u128; // absolute number in multiples of 1 / denominator, rounded
+        let whole: u128; // integer part
+        let (fraction_numerator, fraction_denominator): (u128, u128); // reduced fractional part
+
+
+        if !x.is_finite()
+        // no fraction
+        {
+            return self.format(x);
+        }
+        if 2_f64.powi(53) <= x.abs()
+        // always a whole number, possibly beyond u128
+        {
+            return self.render_fraction(negative, &format!("{:.0}", x.abs()), true, None, "");
+        }
+
+        numerator = {
+            let (significand, exponent): (u128, i64) = match bits >> 52 & 0x7FF
+            {
+                0 => ((bits & ((1 << 52) - 1)) as u128, -1074), // subnormal
+                biased => ((bits & ((1 << 52) - 1) | 1 << 52) as u128, biased as i64 - 1075), // with implicit leading 1
+            };
+            let product: u128 = significand * denominator; // |x| * denominator = product * 2^exponent exactly, below 2^85
+            match exponent
+            {
+                0.. => product << exponent, // whole number below 2^53
+                -126..=-1 =>
+                {
+                    let half: u128 = 1 << (-exponent - 1);
+                    let remainder: u128 = product & ((1 << -exponent) - 1);
+                    let truncated: u128 = product >> -exponent;
+                    if half < remainder || (half == remainder && truncated % 2 == 1) { truncated + 1 } else { truncated } // ties to even
+                }
+                _ => 0, // product * 2^exponent below 2^(-42), rounds to 0
+            }
+        };
+        whole = numerator / denominator;
+        divisor = gcd(numerator % denominator, denominator);
+        (fraction_numerator, fraction_denominator) = (numerator % denominator / divisor, denominator / divisor);
+
+        if fraction_numerator == 0
+        // whole number, fractional part dropped
+        {
+            return self.render_fraction(negative, &whole.to_string(), true, None, "");
+        }
+        if self.fraction.mixed && whole != 0
+        // mixed number, fractional part after the integer part
+        {
+            return match vulgar_fraction(fraction_numerator, fraction_denominator).filter(|_| self.fraction.glyphs)
+            {
+                Some(glyph) => self.render_fraction(negative, &whole.to_string(), true, None, glyph),
+                None => self.render_fraction(negative, &whole.to_string(), true, None, &format!(" {}/{}", self.group_int(fraction_numerator), self.group_int(fraction_denominator))),
+            };
+        }
+        return self.render_fraction(
+            negative,
+            &(whole * fraction_denominator + fraction_numerator).to_string(),
+            false,
+            vulgar_fraction(whole * fraction_denominator + fraction_numerator, fraction_denominator).filter(|_| self.fraction.glyphs),
+            &format!("/{}", self.group_int(fraction_denominator)),
+        ); // proper or improper fraction, the numerator takes the place of the integer part
+    }
+
+
+    /// # Summary
+    /// Renders an integer like `Formatter::format` without scaling and rounding, with its digits replaced by a vulgar fraction glyph or followed by the fractional part, before unit, suffix affix, and trailing sign. Pads the result as a whole.
+    ///
+    /// # Arguments
+    /// - `negative`: whether the number is negative
+    /// - `digits`: the integer to render, ASCII digits only
+    /// - `integer_part`: whether the integer is the integer part, which honours the minimum integer digits, or a numerator, which does not
+    /// - `glyph`: replaces the digits, if any
+    /// - `suffix`: put after the digits unless replaced, empty for none
+    ///
+    /// # Returns
+    /// - formatted fraction
+    fn render_fraction(&self, negative: bool, digits: &str, integer_part: bool, glyph: Option<&str>, suffix: &str) -> String
+    {
+        let mut digits_seen: bool = false; // whether digits of the integer have been emitted
+        let mut inserted: bool = false; // whether glyph or suffix have been emitted
+        let mut s: String = String::new(); // formatted fraction without padding
+
+
+        Formatter {
+            min_integer_digits: if integer_part { self.min_integer_digits } else { 0 },
+            rounding: Rounding::Magnitude(0),
+            scaling: Scaling::None,
+            show_original: (ShowOriginal::Off, false),
+            width: 0, // padded with the fractional part
+            zero_pad: 0,
+            ..self.clone()
+        }
+        .render(Value::Big(negative, digits, 0), &mut |kind, part| {
+            if matches!(kind, PartKind::GroupSeparator | PartKind::IntegerDigits)
+            {
+                digits_seen = true;
+                if let Some(glyph) = glyph
+                // digits replaced
+                {
+                    if !inserted
+                    {
+                        s.push_str(glyph);
+                        inserted = true;
+                    }
+                    return Ok(());
+                }
+            }
+            else if digits_seen && !inserted
+            // right after the last digit, before unit, affix, or trailing sign
+            {
+                s.push_str(suffix);
+                inserted = true;
+            }
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        if !inserted
+        // digits were last
+        {
+            s.push_str(suffix);
+        }
+
+        return self.pad(s);
+    }
+}
+
+
+/// # Summary
+/// Computes the greatest common divisor with Euclid's algorithm.
+///
+/// # Arguments
+/// - `a`: the first number
+/// - `b`: the second number
+///
+/// # Returns
+/// - greatest common divisor, `b` if `a` is 0
+fn gcd(mut a: u128, mut b: u128) -> u128
+{
+    while a != 0
+    {
+        (a, b) = (b % a, a);
+    }
+    return b;
+}
+
+
+/// # Summary
+/// Looks up the Unicode vulgar fraction glyph of a reduced proper fraction.
+///
+/// # Arguments
+/// - `numerator`: numerator of the fraction
+/// - `denominator`: denominator of the fraction
+///
+/// # Returns
+/// - the glyph or none if Unicode has none for the fraction
+fn vulgar_fraction(numerator: u128, denominator: u128) -> Option<&'static str>
+{
+    return match (numerator, denominator)
+    {
+        (1, 2) => Some("½"),
+        (1, 3) => Some("⅓"),
+        (2, 3) => Some("⅔"),
+        (1, 4) => Some("¼"),
+        (3, 4) => Some("¾"),
+        (1, 5) => Some("⅕"),
+        (2, 5) => Some("⅖"),
+        (3, 5) => Some("⅗"),
+        (4, 5) => Some("⅘"),
+        (1, 6) => Some("⅙"),
+        (5, 6) => Some("⅚"),
+        (1, 7) => Some("⅐"),
+        (1, 8) => Some("⅛"),
+        (3, 8) => Some("⅜"),
+        (5, 8) => Some("⅝"),
+        (7, 8) => Some("⅞"),
+        (1, 9) => Some("⅑"),
+        (1, 10) => Some("⅒"),
+        _ => None,
+    };
+}
@@ -0,0 +1,1427 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::parse`, `Formatter::parse_lenient`, and the methods of `Parser` if the string is not a number formatted with the formatter's settings. Contains what the problem is and where it is, so it can be pointed at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError
+{
+    pub field: Option<usize>, // index of the field in a delimited list parsed by `Formatter::parse_iter`, none for a single number
+    pub kind: ParseErrorKind, // what the problem is
+    pub position: usize,      // byte index into the parsed string where the problem is
+}
+
+
+/// # Summary
+/// How `parse_auto` resolves a single "." or "," followed by exactly 3 digits, which could be decimal or group separator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecimalHint
+{
+    Error,       // return `ParseErrorKind::AmbiguousDecimal`
+    PreferComma, // "," is decimal separator, "1,234" is 1.234 and "1.234" is 1234
+    PreferDot,   // "." is decimal separator, "1.234" is 1.234 and "1,234" is 1234
+}
+
+
+/// # Summary
+/// Number split up by `Parser::parse_components`, before it is converted into f64 or an integer type.
+enum Components
+{
+    Finite { digits: String, magnifier: (u32, i32), negative: bool, position: usize }, // digits with "." as decimal point, base and exponent from unit prefix or scientific notation, sign, and start of digits
+    Infinite(bool),                                                                   // infinity, whether negative
+    NaN,                                                                              // not a number
+}
+
+
+/// # Summary
+/// What kind of problem `ParseError` describes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind
+{
+    AmbiguousDecimal,      // single "." or "," followed by exactly 3 digits, could be decimal or group separator
+    Empty,                 // nothing to parse
+    InvalidExponent,       // malformed scientific notation
+    MissingPercent,        // no percent, per mille, ppm, or ppb sign where one is required
+    NoDigits,              // number without any digits
+    NotAnInteger,          // number with fraction where an integer is required
+    OutOfRange,            // number too large for f64
+    Overflow,              // integer outside the range of the integer type
+    UnexpectedChar(char),  // character that is not allowed at this point
+    UnknownPrefix(String), // text after number that is no known unit prefix
+}
+
+
+/// # Summary
+/// Signs of ratios understood by `Formatter::parse_percent`, contains sign and decimal exponent of its factor.
+const RATIO_SIGNS: [(&str, i32); 4] = [("%", -2), ("‰", -3), ("ppb", -9), ("ppm", -6)];
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Parses a number formatted with the settings of this formatter back into f64, the inverse of `Formatter::format`. Understands the configured decimal and group separators, affixes, leading and trailing signs, negative numbers in parentheses, decimal and binary unit prefixes with or without whitespace before them, the configured unit, which is optional, scientific notation in base 10 and 2, and in the base of exponential scaling, with "*" or "×" and exponents in parentheses or superscript, e-notation, and infinity and not a number as the configured special strings, "∞" and "NaN", or "inf", "infinity", and "nan" ignoring case. Affixes are optional. Group separators are only recognised between integer digits. With percent scaling, the number is parsed as ratio like with `Formatter::parse_percent`. Precision lost by rounding during formatting can of course not be restored. To parse many strings, create a `Parser` once with `Formatter::parser` and reuse it.
+    ///
+    /// Formatting and parsing round trip: for every finite number x, `f.parse(&f.format(x))` returns x after the configured rounding, that is `x.round_sig(n)` for `Rounding::SignificantDigits(n)` and `x.round_mag(m)` for `Rounding::Magnitude(m)`, regardless of separators, sign, sign placement, affixes, trailing zeros, and whitespace before unit prefixes. Infinity and not a number round trip as well. Exceptions are:
+    /// - binary scaling and exponential scaling in bases other than 10, because the mantissa is displayed with fewer decimal places than the decimal rounding produces, formatting what is parsed displays the same again though
+    /// - scientific notation with `Rounding::Magnitude`, for the same reason
+    /// - percent scaling with a suffix or a unit, because the percent sign is expected at the end
+    /// - localized compact suffixes like "Mio." or "万", which are no unit prefixes
+    /// - custom strategies and unit prefix tables of exponential scaling, whose suffixes are unknown to parsing
+    /// - more than 15 significant digits displayed, because f64 does not have that many and the additional digits are not exact
+    /// - affixes or separators that contain digits, signs, or unit prefixes, because they cannot be told apart from the number
+    ///
+    /// # Arguments
+    /// - `s`: the formatted number
+    ///
+    /// # Returns
+    /// - the number
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse("42,07 k"), Ok(42070.0));
+    /// assert_eq!(f.parse("42,07k"), Ok(42070.0)); // whitespace before unit prefix is optional
+    /// assert_eq!(f.parse("-1.234,5"), Ok(-1234.5));
+    /// assert_eq!(f.parse("+789,0 µ"), Ok(0.000789));
+    /// assert_eq!(f.parse("1,000 * 10^(-31)"), Ok(1e-31));
+    /// assert_eq!(f.parse("-∞"), Ok(f64::NEG_INFINITY));
+    /// assert!(f.parse("NaN").unwrap().is_nan());
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse(""), Err(ParseError { field: None, kind: ParseErrorKind::Empty, position: 0 }));
+    /// assert_eq!(f.parse("42,07 x"), Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix("x".to_string()), position: 6 }));
+    /// assert_eq!(f.parse("42.07"), Err(ParseError { field: None, kind: ParseErrorKind::UnexpectedChar('.'), position: 2 })); // group separator needs 3 digits after it
+    /// assert_eq!(f.parse("4a2"), Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix("a2".to_string()), position: 1 }));
+    /// assert_eq!(f.parse("-k"), Err(ParseError { field: None, kind: ParseErrorKind::NoDigits, position: 1 }));
+    /// assert_eq!(f.parse("1 * 10^(3"), Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: 9 })); // missing parenthesis
+    /// assert_eq!(f.parse("1 * 10^(3,5)"), Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: 9 })); // exponent not an integer
+    /// assert_eq!(f.parse("1 * 10^(3))"), Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: 10 }));
+    /// assert_eq!(f.parse("1 * 3^(3)"), Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: 4 })); // only bases 10, 2, and that of exponential scaling
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true));
+    /// assert!((f.parse("41,1 Ki").unwrap() - 42086.4).abs() < 1e-9);
+    /// assert_eq!(f.parse("1,000 * 2^(90)"), Ok(2_f64.powi(90)));
+    /// assert_eq!(f.parse("-1,5 * 2^(-1)"), Ok(-0.75));
+    /// assert_eq!(f.parse("1,000Mi"), Ok(1048576.0)); // whitespace is optional regardless of scaling setting
+    /// assert_eq!(f.parse("1,000 k"), Ok(1000.0)); // decimal unit prefixes are understood as well
+    /// for exponent in -5..100 // round trip of examples of set_scaling
+    /// {
+    ///     let x: f64 = 2_f64.powi(exponent);
+    ///     assert_eq!(f.parse(&f.format(x)), Ok(x));
+    ///     assert_eq!(f.parse(&f.format(-x)), Ok(-x));
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse("1,5E-3"), Ok(0.0015)); // e-notation
+    /// assert_eq!(f.parse("1,5e+3"), Ok(1500.0));
+    /// assert_eq!(f.parse("1,5E"), Ok(1.5e18)); // unit prefix exa
+    /// assert_eq!(f.parse("1,5 × 10⁻³"), Ok(0.0015)); // superscript exponent
+    /// assert_eq!(f.parse("1,5 × 2¹⁰"), Ok(1536.0));
+    /// assert_eq!(f.parse("1,000 * 10^(400)"), Err(ParseError { field: None, kind: ParseErrorKind::OutOfRange, position: 0 }));
+    /// assert_eq!(f.parse("1e99999999999"), Err(ParseError { field: None, kind: ParseErrorKind::OutOfRange, position: 2 }));
+    /// assert_eq!(f.parse("1e-400"), Ok(0.0)); // too small rounds to 0
+    /// assert_eq!(f.parse("1e3x"), Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: 3 }));
+    /// assert_eq!(f.parse("1 × 10⁻x"), Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: 10 }));
+    ///
+    /// let f: scaler::Formatter = f.set_separators(",", ".");
+    /// assert_eq!(f.parse("1.5e6"), Ok(1.5e6)); // decimal separator as configured
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(6));
+    /// let mut x: f64 = 1.234e-300;
+    /// while x < 1e300 // round trip of scientific notation, formatting the parsed number again yields the same string
+    /// {
+    ///     for s in [f.format(x), f.format(-x)]
+    ///     {
+    ///         assert_eq!(f.format(f.parse(&s).unwrap()), s);
+    ///     }
+    ///     x *= 123.456;
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators("'", ".")
+    ///     .set_affixes("$", "")
+    ///     .set_sign_placement(scaler::SignPlacement::Trailing);
+    /// assert_eq!(f.parse("$1'234.5-"), Ok(-1234.5));
+    /// assert_eq!(f.parse("1'234.5"), Ok(1234.5)); // affixes are optional
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_affixes("$", " USD")
+    ///     .set_sign_placement(scaler::SignPlacement::Parentheses);
+    /// assert_eq!(f.parse("($1,5 k USD)"), Ok(-1500.0));
+    /// assert_eq!(f.parse("$(1,5 k USD)"), Ok(-1500.0));
+    /// assert_eq!(f.parse(&f.format(-42069)), Ok(-42070.0));
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_unit("B");
+    /// assert_eq!(f.parse("12,3 MB"), Ok(12.3e6));
+    /// assert_eq!(f.parse("12,3MB"), Ok(12.3e6));
+    /// assert_eq!(f.parse("12,3 M"), Ok(12.3e6)); // unit is optional
+    /// assert_eq!(f.parse("12,3 B"), Ok(12.3));
+    /// assert_eq!(f.parse("1,5 * 10^(40) B"), Ok(1.5e40));
+    /// assert_eq!(f.parse("-∞ B"), Ok(f64::NEG_INFINITY));
+    /// assert_eq!(f.parse("12,3 MB/s"), Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix("MB/s".to_string()), position: 5 }));
+    /// assert_eq!(f.parse_lenient("12,3 MB/s"), Ok(12.3e6)); // text after unit is ignored if lenient
+    /// assert_eq!(f.parse_lenient("12,3 mB"), Ok(0.0123)); // exact unit prefix preferred
+    /// assert_eq!(f.parse_lenient("12,3 KB"), Ok(12300.0));
+    ///
+    /// let f: scaler::Formatter = f.set_unit("b");
+    /// assert_eq!(f.parse("5 mb"), Ok(0.005)); // unit taken from the end
+    /// assert_eq!(f.parse("5 m"), Ok(0.005));
+    /// assert_eq!(f.parse("5 b"), Ok(5.0));
+    ///
+    /// let f: scaler::Formatter = f.set_unit("m");
+    /// assert_eq!(f.parse("5 mm"), Ok(0.005));
+    /// assert_eq!(f.parse("5 m"), Ok(5.0)); // unit prefix and unit are the same symbol, taken as unit
+    /// assert_eq!(f.parse("5 km"), Ok(5000.0));
+    /// ```
+    ///
+    /// ```
+    /// use scaler::Round;
+    /// for (scaling, unit) in [ // round trip with unit
+    ///     (scaler::Scaling::Decimal(true), "B"),
+    ///     (scaler::Scaling::Decimal(false), "b"),
+    ///     (scaler::Scaling::Decimal(true), "m"),
+    ///     (scaler::Scaling::Decimal(false), "m"),
+    ///     (scaler::Scaling::Decimal(true), "s"),
+    ///     (scaler::Scaling::None, "Ki"),
+    ///     (scaler::Scaling::Scientific, "B"),
+    /// ]
+    /// {
+    ///     let f: scaler::Formatter = scaler::Formatter::new().set_scaling(scaling).set_unit(unit);
+    ///     for exponent in -31..=33
+    ///     {
+    ///         let power: f64 = format!("1e{exponent}").parse().unwrap(); // powi is not correctly rounded
+    ///         for x in [power, -1.5 * power, 0.0]
+    ///         {
+    ///             assert_eq!(f.parse(&f.format(x)), Ok(x.round_sig(4)), "{}", f.format(x));
+    ///         }
+    ///     }
+    ///     assert_eq!(f.parse(&f.format(f64::INFINITY)), Ok(f64::INFINITY));
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// for (scaling, exponents) in [ // round trip of examples of set_scaling
+    ///     (scaler::Scaling::Decimal(true), -31..=33),
+    ///     (scaler::Scaling::Decimal(false), -31..=33),
+    ///     (scaler::Scaling::None, -10..=10),
+    ///     (scaler::Scaling::Scientific, -31..=33),
+    /// ]
+    /// {
+    ///     let f: scaler::Formatter = scaler::Formatter::new().set_scaling(scaling);
+    ///     for exponent in exponents
+    ///     {
+    ///         let power: f64 = format!("1e{exponent}").parse().unwrap(); // powi is not correctly rounded
+    ///         for x in [power, -power]
+    ///         {
+    ///             assert_eq!(f.parse(&f.format(x)), Ok(x));
+    ///         }
+    ///     }
+    ///     for x in [10.0, 100.0, 999.0, -999.0, 64.0, 128.0, 1023.0]
+    ///     {
+    ///         assert_eq!(f.parse(&f.format(x)), Ok(x));
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{Round, Rounding, Scaling, Sign, SignPlacement};
+    /// let mut state: u64 = 42; // deterministic pseudo-random numbers, linear congruential generator
+    /// for scaling in [Scaling::Decimal(true), Scaling::Decimal(false), Scaling::None, Scaling::Scientific]
+    /// {
+    ///     for (group_separator, decimal_separator) in [(".", ","), (",", "."), ("", ","), ("'", "."), (" ", ",")]
+    ///     {
+    ///         for (sign, placement, (prefix, suffix)) in [Sign::Always, Sign::ExceptZero, Sign::OnlyMinus, Sign::SpaceForPositive]
+    ///             .into_iter()
+    ///             .flat_map(|sign| [SignPlacement::Leading, SignPlacement::Parentheses, SignPlacement::Trailing].map(move |placement| (sign.clone(), placement)))
+    ///             .flat_map(|(sign, placement)| [("", ""), ("$", ""), ("", " USD"), ("€ ", " EUR")].map(move |affixes| (sign.clone(), placement.clone(), affixes)))
+    ///         {
+    ///             for rounding in [Rounding::SignificantDigits(1), Rounding::SignificantDigits(4), Rounding::SignificantDigits(9), Rounding::SignificantDigits(15), Rounding::Magnitude(-3), Rounding::Magnitude(0), Rounding::Magnitude(2)]
+    ///             {
+    ///                 let f: scaler::Formatter = scaler::Formatter::new()
+    ///                     .set_scaling(scaling.clone())
+    ///                     .set_separators(group_separator, decimal_separator)
+    ///                     .set_sign(sign.clone())
+    ///                     .set_sign_placement(placement.clone())
+    ///                     .set_affixes(prefix, suffix)
+    ///                     .set_rounding(rounding.clone())
+    ///                     .set_trailing_zeros(state % 2 == 0);
+    ///                 for _ in 0..20
+    ///                 {
+    ///                     state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///                     let mantissa: f64 = (state >> 11) as f64 / (1_u64 << 53) as f64 * if state % 2 == 0 { 10.0 } else { -10.0 };
+    ///                     let x: f64 = mantissa * format!("1e{}", (state >> 33) % 60).parse::<f64>().unwrap() * 1e-30;
+    ///                     let expected: f64 = match rounding
+    ///                     {
+    ///                         Rounding::SignificantDigits(15) if scaling == Scaling::None => continue, // exception, rounded exactly instead of in f64, can differ in the last digit
+    ///                         Rounding::SignificantDigits(n) => x.round_sig(n),
+    ///                         Rounding::Magnitude(m) if x.abs().log10() - m as f64 >= 15.0 => continue, // exception, more than 15 significant digits
+    ///                         Rounding::Magnitude(m) => x.round_mag(m),
+    ///                     };
+    ///                     assert_eq!(f.parse(&f.format(x)), Ok(expected), "{:?}", f.format(x));
+    ///                 }
+    ///                 for x in [f64::INFINITY, f64::NEG_INFINITY]
+    ///                 {
+    ///                     assert_eq!(f.parse(&f.format(x)), Ok(x));
+    ///                 }
+    ///                 assert!(f.parse(&f.format(f64::NAN)).unwrap().is_nan());
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// for s in ["∞", "inf", "INF", "Infinity"]
+    /// {
+    ///     assert_eq!(f.parse(s), Ok(f64::INFINITY), "{s}");
+    /// }
+    /// for s in ["-∞", "-inf", "-Infinity"]
+    /// {
+    ///     assert_eq!(f.parse(s), Ok(f64::NEG_INFINITY), "{s}");
+    /// }
+    /// for s in ["NaN", "nan", "NAN"]
+    /// {
+    ///     assert!(f.parse(s).unwrap().is_nan(), "{s}");
+    /// }
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_sign(scaler::Sign::Always);
+    /// assert_eq!(f.format(f64::INFINITY), "+∞");
+    /// assert_eq!(f.parse("+∞"), Ok(f64::INFINITY));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_special_strings("unbounded", "0/0") // looks like a number, but is matched exactly first
+    ///     .set_affixes("$", "");
+    /// assert_eq!(f.parse(&f.format(f64::NEG_INFINITY)), Ok(f64::NEG_INFINITY));
+    /// assert_eq!(f.parse("-$unbounded"), Ok(f64::NEG_INFINITY));
+    /// assert!(f.parse(&f.format(f64::NAN)).unwrap().is_nan());
+    /// assert!(f.parse("0/0").unwrap().is_nan());
+    /// assert!(f.parse_lenient(" $0/0 ").unwrap().is_nan());
+    /// assert_eq!(f.parse("∞"), Ok(f64::INFINITY)); // standard strings are still understood
+    /// assert_eq!(f.parse("0"), Ok(0.0));
+    /// ```
+    pub fn parse(&self, s: &str) -> Result<f64, ParseError>
+    {
+        return self.parser().parse(s);
+    }
+
+
+    /// # Summary
+    /// Parses a number like `Formatter::parse`, but tolerates input that was not necessarily produced by this formatter, for example typed by a user. Whitespace around the number is skipped. Group separators are stripped wherever they are between digits, whether they are the configured one, ".", ",", "'", "_", or whitespace. Both "." and "," are accepted as decimal separator when it is unambiguous: if both occur, the last one is the decimal separator; if one occurs multiple times, it is a group separator; if one occurs once and is not followed by exactly 3 digits, or is preceded by more than 3 digits or a lone 0, it is the decimal separator. A single occurrence followed by exactly 3 digits is resolved by the configured separators, otherwise it is ambiguous. Unit prefixes that do not match exactly are matched ignoring case, if that is unambiguous.
+    ///
+    /// # Arguments
+    /// - `s`: the number
+    ///
+    /// # Returns
+    /// - the number
+    /// - or `ParseError` describing what and where the problem is, positions refer to `s` including skipped whitespace
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_lenient("  42,07 k\n"), Ok(42070.0));
+    /// assert_eq!(f.parse_lenient("1,234.5"), Ok(1234.5)); // last separator is the decimal separator
+    /// assert_eq!(f.parse_lenient("1.234.567"), Ok(1234567.0)); // repeated separator is a group separator
+    /// assert_eq!(f.parse_lenient("12.5"), Ok(12.5)); // not followed by 3 digits, so decimal separator
+    /// assert_eq!(f.parse_lenient("1234.567"), Ok(1234.567)); // preceded by more than 3 digits, so decimal separator
+    /// assert_eq!(f.parse_lenient("0.123"), Ok(0.123)); // preceded by a lone 0, which is never grouped, so decimal separator
+    /// assert_eq!(f.parse_lenient("1,234"), Ok(1.234)); // followed by 3 digits, resolved by configured decimal separator
+    /// assert_eq!(f.parse_lenient("1.234"), Ok(1234.0)); // resolved by configured group separator
+    /// assert_eq!(f.parse_lenient("12.34.5"), Ok(12345.0)); // group separators in wrong positions are stripped
+    /// assert_eq!(f.parse_lenient("1 000 000"), Ok(1e6));
+    /// assert_eq!(f.parse_lenient("1_000'000"), Ok(1e6));
+    /// assert_eq!(f.parse_lenient("-2,5e3"), Ok(-2500.0));
+    /// assert_eq!(f.parse_lenient("1.5K"), Ok(1500.0)); // unit prefixes ignoring case if unambiguous
+    /// assert_eq!(f.parse_lenient("2 gi"), Ok(2147483648.0));
+    /// assert_eq!(f.parse_lenient("3 m"), Ok(0.003)); // exact match first
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators("'", "·");
+    /// assert_eq!(f.parse_lenient("1.234"), Err(ParseError { field: None, kind: ParseErrorKind::AmbiguousDecimal, position: 1 }));
+    /// assert_eq!(f.parse_lenient("1,234"), Err(ParseError { field: None, kind: ParseErrorKind::AmbiguousDecimal, position: 1 }));
+    /// assert_eq!(f.parse_lenient("1·234"), Ok(1.234)); // configured decimal separator still works
+    /// assert_eq!(f.parse_lenient("1.234,5"), Ok(1234.5));
+    /// assert_eq!(f.parse_lenient("1,234.567,8"), Err(ParseError { field: None, kind: ParseErrorKind::UnexpectedChar(','), position: 9 })); // decimal separator before group separator
+    /// assert_eq!(f.parse_lenient("   "), Err(ParseError { field: None, kind: ParseErrorKind::Empty, position: 3 }));
+    /// assert_eq!(f.parse_lenient("  12 xyz"), Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix("xyz".to_string()), position: 5 }));
+    /// assert_eq!(f.parse_lenient(" 1e400"), Err(ParseError { field: None, kind: ParseErrorKind::OutOfRange, position: 1 }));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(6));
+    /// let mut x: f64 = 1.234e-30;
+    /// while x < 1e30 // everything parse understands, parse_lenient understands as well
+    /// {
+    ///     for s in [f.format(x), f.format(-x)]
+    ///     {
+    ///         assert_eq!(f.parse_lenient(&s), f.parse(&s));
+    ///     }
+    ///     x *= 12.3456;
+    /// }
+    /// ```
+    pub fn parse_lenient(&self, s: &str) -> Result<f64, ParseError>
+    {
+        return self.parser().parse_lenient(s);
+    }
+
+
+    /// # Summary
+    /// Parses a number of messy formatting with `Formatter::parse_lenient` and formats it again with this formatter, for example to clean up data of mixed locales. The precision of the input is preserved instead of applying the configured rounding: the number is rounded to the place of the last digit written in the input, trailing zeros included, shifted by the unit prefix or scientific notation. So "1,234.50" is rounded to hundredths, "1.5k" to hundreds, and "2.50e-3" to 10^(-5). Binary unit prefixes do not shift by a power of 10, for them the place is rounded down to the next power of 10, so "1.5 Ki" is rounded to hundreds. Infinity and not a number are formatted as they are.
+    ///
+    /// # Arguments
+    /// - `s`: the number to normalise
+    ///
+    /// # Returns
+    /// - the number formatted with this formatter, in the precision of the input
+    /// - or `ParseError` like `Formatter::parse_lenient`
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None);
+    /// assert_eq!(f.normalize("1,234.50"), Ok("1.234,50".to_string())); // US style in, German style out
+    /// assert_eq!(f.normalize("1 234,5"), Ok("1.234,5".to_string()));
+    /// assert_eq!(f.normalize("1'234'567"), Ok("1.234.567".to_string()));
+    /// assert_eq!(f.normalize("-0.25"), Ok("-0,25".to_string()));
+    /// assert_eq!(f.normalize("1.234"), Ok("1.234".to_string())); // ambiguous, resolved by the configured separators like in `Formatter::parse_lenient`
+    /// assert_eq!(f.normalize("1.5k"), Ok("1.500".to_string()));
+    /// assert_eq!(f.normalize("2.50e-3"), Ok("0,00250".to_string()));
+    /// assert_eq!(f.normalize("1.5 Ki"), Ok("1.500".to_string())); // 1.536, rounded to hundreds
+    /// assert_eq!(f.normalize("inf"), Ok("∞".to_string()));
+    /// assert!(f.normalize("12 parsecs").is_err());
+    /// let f: scaler::Formatter = f.set_trailing_zeros(false);
+    /// assert_eq!(f.normalize("1,234.50"), Ok("1.234,5".to_string()));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new(); // scaled output keeps the precision as well
+    /// assert_eq!(f.normalize("1,234.50"), Ok("1,23450 k".to_string()));
+    /// assert_eq!(f.normalize("1.5k"), Ok("1,5 k".to_string()));
+    /// assert_eq!(f.normalize("2.50e-3"), Ok("2,50 m".to_string()));
+    /// assert_eq!(f.normalize("0.00"), Ok("0,00".to_string()));
+    /// ```
+    pub fn normalize(&self, s: &str) -> Result<String, ParseError>
+    {
+        let end: usize = s.trim_end().len(); // end of number before trailing whitespace
+        let magnitude: i32; // place of the last digit written in the input
+        let start: usize = (s.len() - s.trim_start().len()).min(end); // start of number after leading whitespace
+        let x: f64 = self.parse_lenient(s)?; // also reports all errors
+
+
+        magnitude = match self.parser().parse_components(s, start, end, true)?
+        {
+            Components::Finite { digits, magnifier: (10, exponent), .. } => exponent.saturating_sub(fraction_len(&digits)),
+            Components::Finite { digits, magnifier: (base, exponent), .. } => (exponent as f64 * (base as f64).log10()).floor() as i32 - fraction_len(&digits), // place of binary prefix or power of other base rounded down to power of 10
+            Components::Infinite(_) | Components::NaN => return Ok(self.format(x)),
+        };
+
+        return Ok(Formatter { rounding: Rounding::Magnitude(magnitude.clamp(i16::MIN as i32, i16::MAX as i32) as i16), ..self.clone() }.format(x));
+    }
+
+
+    /// # Summary
+    /// Parses the longest number at the start of `s` like `Formatter::parse` and returns it together with the unparsed rest, for example to pull a quantity out of a log line. Whitespace after the number is left in the rest. A unit prefix or unit directly followed by another letter is not consumed, because it is the beginning of a word, so "5 Gremlins" is 5 followed by " Gremlins" and not 5 G followed by "remlins". Numbers longer than `Formatter::max_len` are not recognised, which covers everything `Formatter::format` produces.
+    ///
+    /// # Arguments
+    /// - `s`: the string starting with a number
+    ///
+    /// # Returns
+    /// - the number and the rest of `s` after it
+    /// - or `ParseError` of the longest attempt if `s` does not start with a number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_unit("B");
+    /// assert_eq!(f.parse_prefix("1,5 GiB free on /dev/sda1"), Ok((1610612736.0, " free on /dev/sda1")));
+    /// assert_eq!(f.parse_prefix("-12,5 kB/s"), Ok((-12500.0, "/s")));
+    /// assert_eq!(f.parse_prefix("42"), Ok((42.0, "")));
+    /// assert_eq!(f.parse_prefix("1.000.000 rows"), Ok((1e6, " rows")));
+    /// assert_eq!(f.parse_prefix("∞ loop"), Ok((f64::INFINITY, " loop")));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_prefix("5 Gremlins"), Ok((5.0, " Gremlins"))); // "G" is a valid prefix, but starts a word
+    /// assert_eq!(f.parse_prefix("3 min"), Ok((3.0, " min"))); // not milli
+    /// assert_eq!(f.parse_prefix("12kg"), Ok((12.0, "kg"))); // not kilo
+    /// assert_eq!(f.parse_prefix("12k, 13k"), Ok((12000.0, ", 13k")));
+    /// assert_eq!(f.parse_prefix("7 µ."), Ok((7e-6, ".")));
+    /// assert_eq!(f.parse_prefix("2e3x"), Ok((2000.0, "x")));
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_prefix(""), Err(ParseError { field: None, kind: ParseErrorKind::Empty, position: 0 }));
+    /// assert!(f.parse_prefix("free: 5").is_err());
+    /// ```
+    pub fn parse_prefix<'a>(&self, s: &'a str) -> Result<(f64, &'a str), ParseError>
+    {
+        return self.parser().parse_prefix(s);
+    }
+
+
+    /// # Summary
+    /// Parses a ratio with percent sign "%", per mille sign "‰", "ppm", or "ppb" like `Formatter::parse` and divides it by 100, 1000, 10^(6), or 10^(9) respectively. Whitespace before the sign is optional. The sign is required, so a number entered without it is caught, except for not a number, which percent scaling displays without it.
+    ///
+    /// # Arguments
+    /// - `s`: the ratio
+    ///
+    /// # Returns
+    /// - the ratio as number, "12,5 %" is 0.125
+    /// - or `ParseError` describing what and where the problem is, `ParseErrorKind::MissingPercent` if the sign is missing
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_percent("12,5 %"), Ok(0.125));
+    /// assert_eq!(f.parse_percent("12,5%"), Ok(0.125));
+    /// assert_eq!(f.parse_percent("-7 %"), Ok(-0.07)); // correctly rounded, unlike dividing by 100 after parsing
+    /// assert_eq!(f.parse_percent("(12,5 %)"), Ok(-0.125)); // accounting style
+    /// assert_eq!(f.parse_percent("1.250 %"), Ok(12.5));
+    /// assert_eq!(f.parse_percent("3‰"), Ok(0.003));
+    /// assert_eq!(f.parse_percent("+250 ppm"), Ok(0.00025));
+    /// assert_eq!(f.parse_percent("1,5 k ppb"), Ok(1.5e-6)); // unit prefixes still apply
+    /// assert_eq!(f.parse_percent("12,5"), Err(ParseError { field: None, kind: ParseErrorKind::MissingPercent, position: 4 }));
+    /// assert_eq!(f.parse_percent("12,5 % "), Err(ParseError { field: None, kind: ParseErrorKind::MissingPercent, position: 7 }));
+    /// ```
+    pub fn parse_percent(&self, s: &str) -> Result<f64, ParseError>
+    {
+        return self.parser().parse_percent(s);
+    }
+
+
+    /// # Summary
+    /// Parses a ratio like `Formatter::parse_percent`, but with the tolerance of `Formatter::parse_lenient`. A number without percent, per mille, ppm, or ppb sign is accepted as plain number.
+    ///
+    /// # Arguments
+    /// - `s`: the ratio
+    ///
+    /// # Returns
+    /// - the ratio as number, "12,5 %" is 0.125 and "12,5" is 12.5
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_percent_lenient(" 12.5 % "), Ok(0.125));
+    /// assert_eq!(f.parse_percent_lenient("-1 250 ‰"), Ok(-1.25));
+    /// assert_eq!(f.parse_percent_lenient("12,5"), Ok(12.5)); // plain number
+    /// assert_eq!(f.parse_percent_lenient("0.5"), Ok(0.5));
+    /// ```
+    pub fn parse_percent_lenient(&self, s: &str) -> Result<f64, ParseError>
+    {
+        return self.parser().parse_percent_lenient(s);
+    }
+
+
+    /// # Summary
+    /// Parses an integer like `Formatter::parse`, but in integer arithmetic instead of f64, so byte counts above 2^(53) stay exact. Unit prefixes, scientific notation, and decimal places are allowed as long as the result is a whole number, so "1,5 k" is 1500, but "1,5" is not an integer.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted integer
+    ///
+    /// # Returns
+    /// - the integer
+    /// - or `ParseError` describing what and where the problem is, `ParseErrorKind::NotAnInteger` for a fraction, `ParseErrorKind::Overflow` if it does not fit into i64
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_i64("-1,5 k"), Ok(-1500));
+    /// assert_eq!(f.parse_i64("-9,223372036854775808 E"), Ok(i64::MIN));
+    /// assert_eq!(f.parse_i64("9,223372036854775808 E"), Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position: 0 }));
+    /// assert_eq!(f.parse_i64("-1,5"), Err(ParseError { field: None, kind: ParseErrorKind::NotAnInteger, position: 1 }));
+    /// ```
+    pub fn parse_i64(&self, s: &str) -> Result<i64, ParseError>
+    {
+        return self.parser().parse_i64(s);
+    }
+
+
+    /// # Summary
+    /// Parses an integer like `Formatter::parse_u64`, but into u128.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted integer
+    ///
+    /// # Returns
+    /// - the integer
+    /// - or `ParseError` describing what and where the problem is, `ParseErrorKind::NotAnInteger` for a fraction, `ParseErrorKind::Overflow` if it is negative or does not fit into u128
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_u128("1 Yi"), Ok(1 << 80));
+    /// assert_eq!(f.parse_u128("123.456.789.012.345.678.901.234.567"), Ok(123456789012345678901234567));
+    /// assert_eq!(f.parse_u128("3,40282366920938463463374607431768211455 * 10^(38)"), Ok(u128::MAX));
+    /// assert_eq!(f.parse_u128("1,25 * 10^(1)"), Err(ParseError { field: None, kind: ParseErrorKind::NotAnInteger, position: 0 }));
+    /// assert_eq!(f.parse_u128("1,25 * 10^(2)"), Ok(125));
+    /// assert_eq!(f.parse_u128("1 * 10^(39)"), Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position: 0 }));
+    /// ```
+    pub fn parse_u128(&self, s: &str) -> Result<u128, ParseError>
+    {
+        return self.parser().parse_u128(s);
+    }
+
+
+    /// # Summary
+    /// Parses an integer like `Formatter::parse_i64`, but into u64, for example byte counts of large file systems.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted integer
+    ///
+    /// # Returns
+    /// - the integer
+    /// - or `ParseError` describing what and where the problem is, `ParseErrorKind::NotAnInteger` for a fraction, `ParseErrorKind::Overflow` if it is negative or does not fit into u64
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_u64("1,5 k"), Ok(1500));
+    /// assert_eq!(f.parse_u64("1,5 Ki"), Ok(1536));
+    /// assert_eq!(f.parse_u64("8 Ti"), Ok(8 << 40));
+    /// assert_eq!(f.parse_u64("9.007.199.254.740.993"), Ok(9007199254740993)); // 2^53 + 1, not representable in f64
+    /// assert_eq!(f.parse_u64("18,446744073709551615 E"), Ok(u64::MAX));
+    /// assert_eq!(f.parse_u64("15 Ei"), Ok(15 << 60));
+    /// assert_eq!(f.parse_u64("-0"), Ok(0));
+    /// assert_eq!(f.parse_u64("1,5"), Err(ParseError { field: None, kind: ParseErrorKind::NotAnInteger, position: 0 }));
+    /// assert_eq!(f.parse_u64("1,0001 k"), Err(ParseError { field: None, kind: ParseErrorKind::NotAnInteger, position: 0 }));
+    /// assert_eq!(f.parse_u64("1,1 Ki"), Err(ParseError { field: None, kind: ParseErrorKind::NotAnInteger, position: 0 }));
+    /// assert_eq!(f.parse_u64("16 Ei"), Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position: 0 }));
+    /// assert_eq!(f.parse_u64("-1"), Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position: 1 }));
+    /// assert_eq!(f.parse_u64("∞"), Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position: 0 }));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(0));
+    /// let mut state: u64 = 42;
+    /// for _ in 0..1000 // integers above 2^53 round trip exactly
+    /// {
+    ///     state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///     let x: u64 = state >> (state % 11);
+    ///     let s: String = f.group_int(x as u128);
+    ///     assert_eq!(f.parse_u64(&s), Ok(x), "{s}");
+    ///     assert_eq!(f.parse_u128(&s), Ok(x as u128), "{s}");
+    ///     if let Ok(y) = i64::try_from(x)
+    ///     {
+    ///         assert_eq!(f.parse_i64(&format!("-{s}")), Ok(-y), "{s}");
+    ///     }
+    ///     let x: u128 = (x as u128) << 64 | state as u128;
+    ///     assert_eq!(f.parse_u128(&f.group_int(x)), Ok(x), "{x}");
+    /// }
+    /// ```
+    pub fn parse_u64(&self, s: &str) -> Result<u64, ParseError>
+    {
+        return self.parser().parse_u64(s);
+    }
+}
+
+
+impl Parser
+{
+    /// # Summary
+    /// Parses the ratio in `s[start..end]`, shared by `Parser::parse_percent` and `Parser::parse_percent_lenient`.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string, so positions in errors refer to it
+    /// - `start`: start of the ratio
+    /// - `end`: end of the ratio
+    /// - `lenient`: whether to parse leniently and accept a missing sign
+    ///
+    /// # Returns
+    /// - the ratio as number
+    /// - or `ParseError` describing what and where the problem is
+    pub(crate) fn parse_ratio(&self, s: &str, start: usize, end: usize, lenient: bool) -> Result<f64, ParseError>
+    {
+        if let Some(inner) = s[start..end].strip_prefix('(').and_then(|rest| rest.strip_suffix(')'))
+        // negative in parentheses, enclosing the sign as well
+        {
+            return self.parse_ratio(s, start + '('.len_utf8(), start + '('.len_utf8() + inner.len(), lenient).map(|x| -x);
+        }
+        for (sign, shift) in RATIO_SIGNS.iter()
+        {
+            if let Some(number) = s[start..end].strip_suffix(sign)
+            {
+                return self.parse_number(s, start, start + number.trim_end_matches(' ').len(), lenient, *shift); // whitespace before sign is optional
+            }
+        }
+
+        if lenient || start == end
+        // plain number, or nothing at all
+        {
+            return self.parse_number(s, start, end, lenient, 0);
+        }
+        return match self.parse_number(s, start, end, false, 0)
+        {
+            Ok(x) if x.is_nan() => Ok(x), // not a number is displayed without sign
+            _ => Err(ParseError { field: None, kind: ParseErrorKind::MissingPercent, position: end }),
+        };
+    }
+
+
+    /// # Summary
+    /// Parses the number in `s[start..end]` into f64, shared by `Parser::parse`, `Parser::parse_lenient`, and the ratio parsers.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string, so positions in errors refer to it
+    /// - `start`: start of the number
+    /// - `end`: end of the number
+    /// - `lenient`: whether to recognise separators leniently or only as configured
+    /// - `shift`: additional decimal exponent to apply, for example -2 for percent
+    ///
+    /// # Returns
+    /// - the number
+    /// - or `ParseError` describing what and where the problem is
+    pub(crate) fn parse_number(&self, s: &str, start: usize, end: usize, lenient: bool, shift: i32) -> Result<f64, ParseError>
+    {
+        let x: f64; // parsed absolute value
+
+
+        let (digits, magnifier, negative, position): (String, (u32, i32), bool, usize) = match self.parse_components(s, start, end, lenient)?
+        {
+            Components::Finite { digits, magnifier, negative, position } => (digits, magnifier, negative, position),
+            Components::Infinite(negative) => return Ok(if negative { f64::NEG_INFINITY } else { f64::INFINITY }),
+            Components::NaN => return Ok(f64::NAN),
+        };
+
+        x = match magnifier
+        {
+            (2, exponent) =>
+            {
+                let binary: f64 = digits.parse::<f64>().expect("Collected digits are not a valid f64 even though they are only ASCII digits and at most one decimal point.") * 2.0_f64.powi(exponent / 2) * 2.0_f64.powi(exponent - exponent / 2); // powers of 2 are exact, in halves so neither overflows nor underflows for results in range
+                if shift == 0 || !binary.is_finite() { binary } else { format!("{binary}e{shift}").parse::<f64>().expect("Finite f64 with exponent is not a valid f64.") } // shift correctly rounded
+            }
+            (10, exponent) => format!("{digits}e{}", exponent.saturating_add(shift)).parse::<f64>().expect("Collected digits are not a valid f64 even though they are only ASCII digits and at most one decimal point."), // correctly rounded, unlike multiplying with a power of 10
+            (base, exponent) =>
+            {
+                let scaled: f64 = digits.parse::<f64>().expect("Collected digits are not a valid f64 even though they are only ASCII digits and at most one decimal point.") * (base as f64).powi(exponent); // base of exponential scaling, as exact as the mantissa it was displayed with
+                if shift == 0 || !scaled.is_finite() { scaled } else { format!("{scaled}e{shift}").parse::<f64>().expect("Finite f64 with exponent is not a valid f64.") }
+            }
+        };
+        if x.is_infinite()
+        // exponent too large, "∞" is handled before
+        {
+            return Err(ParseError { field: None, kind: ParseErrorKind::OutOfRange, position });
+        }
+        return Ok(if negative { -x } else { x });
+    }
+
+
+    /// # Summary
+    /// Parses the number in `s` into an integer magnitude and sign, shared by the integer parsers. Digits and unit prefix are combined in integer arithmetic, so no precision is lost like in f64 above 2^(53).
+    ///
+    /// # Arguments
+    /// - `s`: the whole string, so positions in errors refer to it
+    /// - `lenient`: whether to skip surrounding whitespace and recognise separators leniently like `Parser::parse_lenient`
+    ///
+    /// # Returns
+    /// - whether the number is negative, its magnitude, and the start of its digits
+    /// - or `ParseError` describing what and where the problem is, `ParseErrorKind::NotAnInteger` if a fraction remains after applying the unit prefix, `ParseErrorKind::Overflow` if the magnitude does not even fit into u128
+    pub(crate) fn parse_integer(&self, s: &str, lenient: bool) -> Result<(bool, u128, usize), ParseError>
+    {
+        let end: usize = if lenient { s.trim_end().len() } else { s.len() }; // end of number before trailing whitespace
+        let fraction_len: i32; // number of significant fraction digits
+        let magnitude: Result<u128, ParseErrorKind>; // magnitude after applying unit prefix or scientific notation
+        let mantissa: u128; // all significant digits without decimal point
+        let start: usize = if lenient { (s.len() - s.trim_start().len()).min(end) } else { 0 }; // start of number after leading whitespace
+
+
+        let (digits, magnifier, negative, position): (String, (u32, i32), bool, usize) = match self.parse_components(s, start, end, lenient)?
+        {
+            Components::Finite { digits, magnifier, negative, position } => (digits, magnifier, negative, position),
+            Components::Infinite(_) => return Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position: 0 }),
+            Components::NaN => return Err(ParseError { field: None, kind: ParseErrorKind::NotAnInteger, position: 0 }),
+        };
+
+        let (int, fraction): (&str, &str) = digits.split_once('.').unwrap_or((&digits, ""));
+        let fraction: &str = fraction.trim_end_matches('0'); // trailing zeros do not make a fraction
+        fraction_len = fraction.len().try_into().map_err(|_| ParseError { field: None, kind: ParseErrorKind::Overflow, position })?;
+        mantissa = match format!("{int}{fraction}").trim_start_matches('0')
+        {
+            "" => return Ok((negative, 0, position)), // zero, regardless of unit prefix
+            significant => significant.parse::<u128>().map_err(|_| ParseError { field: None, kind: ParseErrorKind::Overflow, position })?,
+        };
+        magnitude = match magnifier
+        {
+            (2, exponent) => scale_integer(mantissa, 5, -fraction_len).and_then(|m| scale_integer(m, 2, exponent.saturating_sub(fraction_len))), // x * 2^e / 10^f = x / 5^f * 2^(e-f)
+            (10, exponent) => scale_integer(mantissa, 10, exponent.saturating_sub(fraction_len)),
+            (base, exponent) if exponent < 0 => scale_integer(mantissa, 10, -fraction_len).and_then(|m| scale_integer(m, base as u128, exponent)), // x / 10^f / b^(-e), both divisions must be exact
+            (base, exponent) => scale_integer(mantissa, base as u128, exponent).and_then(|m| scale_integer(m, 10, -fraction_len)), // x * b^e / 10^f
+        };
+        return match magnitude
+        {
+            Ok(magnitude) => Ok((negative, magnitude, position)),
+            Err(kind) => Err(ParseError { field: None, kind, position }),
+        };
+    }
+
+
+    /// # Summary
+    /// Splits the number in `s[start..end]` into sign, digits, and unit prefix or scientific notation, shared by all parsers. Special values are recognised here as well.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string, so positions in errors refer to it
+    /// - `start`: start of the number
+    /// - `end`: end of the number
+    /// - `lenient`: whether to recognise separators leniently or only as configured
+    ///
+    /// # Returns
+    /// - the components of the number
+    /// - or `ParseError` describing what and where the problem is
+    fn parse_components(&self, s: &str, mut start: usize, mut end: usize, lenient: bool) -> Result<Components, ParseError>
+    {
+        let digits: String; // digits with "." as decimal point, for the standard library parser
+        let mut magnifier: (u32, i32) = (10, 0); // base and exponent from unit prefix or scientific notation
+        let mut negative: Option<bool>; // whether the number is negative, none if no sign found yet
+        let number_start: usize; // start of digits
+        let special: &str; // remainder without unit, compared to special values
+
+
+        if start == end
+        {
+            return Err(ParseError { field: None, kind: ParseErrorKind::Empty, position: start });
+        }
+        if self.is_special_nan(&s[start..end])
+        // exact match before anything else, the string may look like a number or a sign
+        {
+            return Ok(Components::NaN);
+        }
+
+        negative = self.parse_parentheses(s, &mut start, &mut end).or_else(|| self.parse_sign(s, &mut start, true)); // leading sign before prefix affix
+        if !self.affix_prefix.is_empty() && s[start..end].starts_with(self.affix_prefix.as_ref())
+        {
+            start += self.affix_prefix.len();
+        }
+        negative = negative.or_else(|| self.parse_parentheses(s, &mut start, &mut end)); // parentheses after prefix affix
+        negative = negative.or_else(|| self.parse_sign(&s[..end], &mut start, true)); // leading sign after prefix affix
+        negative = negative.or_else(|| self.parse_sign(&s[..end], &mut end, false)); // trailing sign
+        if !self.affix_suffix.is_empty() && s[start..end].ends_with(self.affix_suffix.as_ref())
+        {
+            end -= self.affix_suffix.len();
+        }
+
+        special = s[start..end].strip_suffix(self.unit.as_ref()).filter(|_| !self.unit.is_empty()).unwrap_or(&s[start..end]).trim_end_matches(' '); // special values, optionally with unit
+        if special == self.special_strings.0 || special == "∞" || special.eq_ignore_ascii_case("inf") || special.eq_ignore_ascii_case("infinity")
+        {
+            return Ok(Components::Infinite(negative == Some(true)));
+        }
+        if special == self.special_strings.1 || special == "NaN" || special.eq_ignore_ascii_case("nan")
+        {
+            return Ok(Components::NaN);
+        }
+
+        number_start = start;
+        (digits, start) = if lenient { self.parse_digits_lenient(s, start, end)? } else { self.parse_digits(s, start, end)? };
+        if digits.is_empty()
+        {
+            return Err(ParseError { field: None, kind: ParseErrorKind::NoDigits, position: number_start });
+        }
+
+        if start < end
+        // unit prefix or scientific notation, and unit
+        {
+            magnifier = self.parse_unit_magnifier(s, start, end, lenient)?;
+        }
+
+        return Ok(Components::Finite { digits, magnifier, negative: negative == Some(true), position: number_start });
+    }
+
+
+    /// # Summary
+    /// Collects the digits of a number, with the configured decimal separator and group separators exactly where the formatter puts them.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string
+    /// - `start`: start of the digits
+    /// - `end`: end of the number
+    ///
+    /// # Returns
+    /// - the digits with "." as decimal point, may be empty, and the position after them
+    /// - or `ParseError` if a group separator followed by digits is not followed by exactly 3 digits
+    fn parse_digits(&self, s: &str, mut start: usize, end: usize) -> Result<(String, usize), ParseError>
+    {
+        let mut digits: String = String::new(); // digits with "." as decimal point
+
+
+        while start < end
+        // digits and separators
+        {
+            let rest: &str = &s[start..end];
+            let followed_by_digit = |separator: &str| -> bool { rest[separator.len()..].starts_with(|c: char| c.is_ascii_digit()) };
+
+            if rest.starts_with(|c: char| c.is_ascii_digit())
+            {
+                digits.push_str(&rest[..1]);
+                start += 1;
+            }
+            else if !self.decimal_separator.is_empty() && rest.starts_with(self.decimal_separator.as_ref()) && !digits.contains('.') && !digits.is_empty() && followed_by_digit(&self.decimal_separator)
+            {
+                digits.push('.');
+                start += self.decimal_separator.len();
+            }
+            else if !self.group_separator.is_empty() && rest.starts_with(self.group_separator.as_ref()) && !digits.contains('.') && !digits.is_empty() && followed_by_digit(&self.group_separator)
+            // only between integer digits and followed by exactly 3 digits, otherwise it may be whitespace before unit prefix
+            {
+                let group: &str = &rest[self.group_separator.len()..];
+                if !(group.len() >= 3 && group.bytes().take(3).all(|b| b.is_ascii_digit()) && !group[3..].starts_with(|c: char| c.is_ascii_digit()))
+                {
+                    return Err(ParseError { field: None, kind: ParseErrorKind::UnexpectedChar(rest.chars().next().expect("Separator is not empty, so rest is not empty.")), position: start });
+                }
+                start += self.group_separator.len();
+            }
+            else
+            {
+                break;
+            }
+        }
+
+        return Ok((digits, start));
+    }
+
+
+    /// # Summary
+    /// Collects the digits of a number and strips every separator between them. Which separator is the decimal separator is decided as described in `Parser::parse_lenient`.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string
+    /// - `start`: start of the digits
+    /// - `end`: end of the number
+    ///
+    /// # Returns
+    /// - the digits with "." as decimal point, may be empty, and the position after them
+    /// - or `ParseError` if the decimal separator is ambiguous or followed by a group separator that could be a decimal separator
+    fn parse_digits_lenient(&self, s: &str, mut start: usize, end: usize) -> Result<(String, usize), ParseError>
+    {
+        let mut decimal: Option<usize> = None; // index into separators of the decimal separator
+        let mut digits: String = String::new(); // digits with "." as decimal point
+        let mut separators: Vec<(usize, usize, &str)> = Vec::new(); // separators between digits: index into digits, position in s, separator
+        let is_decimal_candidate = |separator: &str| -> bool { separator == "." || separator == "," || separator == self.decimal_separator }; // could be decimal separator
+
+
+        while start < end
+        // digits and separators
+        {
+            let rest: &str = &s[start..end];
+            let separator_len: usize = [self.decimal_separator.as_ref(), self.group_separator.as_ref(), ".", ",", "'", "_", " ", "\u{00A0}", "\u{202F}"]
+                .iter()
+                .filter(|separator| !separator.is_empty() && rest.starts_with(**separator))
+                .map(|separator| separator.len())
+                .max()
+                .unwrap_or(0); // longest separator at this position, 0 if none
+
+            if rest.starts_with(|c: char| c.is_ascii_digit())
+            {
+                digits.push_str(&rest[..1]);
+                start += 1;
+            }
+            else if 0 < separator_len && !digits.is_empty() && rest[separator_len..].starts_with(|c: char| c.is_ascii_digit())
+            // only between digits
+            {
+                separators.push((digits.len(), start, &rest[..separator_len]));
+                start += separator_len;
+            }
+            else
+            {
+                break;
+            }
+        }
+
+        if let Some(last) = separators.iter().rposition(|(_, _, separator)| is_decimal_candidate(separator))
+        // decide which separator is the decimal separator
+        {
+            let last_separator: &str = separators[last].2; // last separator that could be the decimal separator
+            let occurrences: usize = separators.iter().filter(|(_, _, separator)| *separator == last_separator).count(); // how often it occurs
+            let others: Option<&(usize, usize, &str)> = separators.iter().find(|(_, _, separator)| is_decimal_candidate(separator) && *separator != last_separator); // different candidate
+
+            if 1 < occurrences
+            // repeated, so group separator
+            {
+                if let Some(other) = others
+                // other candidate before repeated group separator would be decimal separator followed by group separator
+                {
+                    let after: &(usize, usize, &str) = separators.iter().find(|(_, pos, separator)| other.1 < *pos && *separator == last_separator).expect("Repeated separator occurs after different candidate, because the last candidate is the repeated one.");
+                    return Err(ParseError { field: None, kind: ParseErrorKind::UnexpectedChar(after.2.chars().next().expect("Separator is not empty.")), position: after.1 });
+                }
+            }
+            else if others.is_some()
+            // different candidates before, so last is decimal separator
+            {
+                decimal = Some(last);
+            }
+            else
+            // single occurrence, group separator only if preceded by at most 3 and followed by exactly 3 digits, and not by a lone 0
+            {
+                let (index, pos, _) = separators[last]; // index into digits and position in s
+                let group_len: usize = separators.get(last + 1).map(|(next, _, _)| *next).unwrap_or(digits.len()) - index; // digits until next separator or end
+                let lead_len: usize = index - if last == 0 { 0 } else { separators[last - 1].0 }; // digits since previous separator or start
+                if group_len != 3 || 3 < lead_len || (last == 0 && &digits[..index] == "0") || last_separator == self.decimal_separator
+                {
+                    decimal = Some(last);
+                }
+                else if last_separator != self.group_separator
+                {
+                    return Err(ParseError { field: None, kind: ParseErrorKind::AmbiguousDecimal, position: pos });
+                }
+            }
+        }
+
+        if let Some(decimal) = decimal
+        {
+            digits.insert(separators[decimal].0, '.');
+        }
+        return Ok((digits, start));
+    }
+
+
+    /// # Summary
+    /// Determines whether `s` is exactly the configured string for not a number, with or without affixes. Checked before anything else, so a string like "0/0" or "-" is not mistaken for a number or sign.
+    ///
+    /// # Arguments
+    /// - `s`: the string to look at
+    ///
+    /// # Returns
+    /// - whether `s` is not a number
+    fn is_special_nan(&self, s: &str) -> bool
+    {
+        let without_prefix: &str = s.strip_prefix(self.affix_prefix.as_ref()).unwrap_or(s); // affixes are optional
+        let without_suffix: &str = s.strip_suffix(self.affix_suffix.as_ref()).unwrap_or(s);
+        let without_affixes: &str = without_prefix.strip_suffix(self.affix_suffix.as_ref()).unwrap_or(without_prefix);
+
+
+        return !self.special_strings.1.is_empty() && [s, without_prefix, without_suffix, without_affixes].contains(&self.special_strings.1.as_ref());
+    }
+
+
+    /// # Summary
+    /// Parses parentheses enclosing `s[start..end]`, which mark a negative number in accounting style, and moves `start` and `end` inside them.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string
+    /// - `start`: start of what may be enclosed, moved past "(" if found
+    /// - `end`: end of what may be enclosed, moved before ")" if found
+    ///
+    /// # Returns
+    /// - negative if enclosed in parentheses, none otherwise
+    fn parse_parentheses(&self, s: &str, start: &mut usize, end: &mut usize) -> Option<bool>
+    {
+        if *end - *start < "()".len() || !s[*start..*end].starts_with('(') || !s[*start..*end].ends_with(')')
+        {
+            return None;
+        }
+
+        *start += '('.len_utf8();
+        *end -= ')'.len_utf8();
+        return Some(true);
+    }
+
+
+    /// # Summary
+    /// Parses a sign at the start or end of `s` and moves `pos` past it.
+    ///
+    /// # Arguments
+    /// - `s`: the string to look at
+    /// - `pos`: start of `s` if leading, end if trailing, moved past the sign if found
+    /// - `leading`: whether to look at the start or the end
+    ///
+    /// # Returns
+    /// - whether the sign is negative, none if there is no sign
+    fn parse_sign(&self, s: &str, pos: &mut usize, leading: bool) -> Option<bool>
+    {
+        let c: char = if leading { s[*pos..].chars().next()? } else { s[..*pos].chars().next_back()? }; // character where the sign would be
+        let negative: bool = match c
+        {
+            '-' => true,
+            '+' => false,
+            ' ' if self.sign == Sign::SpaceForPositive => false, // space for positive, before the number or after it with trailing sign placement
+            _ => return None,
+        };
+
+        if leading
+        {
+            *pos += c.len_utf8();
+        }
+        else
+        {
+            *pos -= c.len_utf8();
+        }
+        return Some(negative);
+    }
+
+
+    /// # Summary
+    /// Parses what follows the digits like `Parser::parse_magnifier`, followed by the configured unit. The unit is optional, a bare unit prefix is accepted as well. If unit prefix and unit can be told apart in several ways, for example "mm" with unit "m", the unit is taken from the end, so "mm" is millimetre and "m" is metre. Exact unit prefixes are preferred over ones matching ignoring case. If lenient, text after the unit is ignored.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string
+    /// - `start`: start of the magnifier
+    /// - `end`: end of the magnifier and unit
+    /// - `lenient`: whether to match unit prefixes ignoring case and ignore text after the unit
+    ///
+    /// # Returns
+    /// - base and exponent the magnifier stands for
+    /// - or `ParseError` of the most likely reading if there is no valid one
+    fn parse_unit_magnifier(&self, s: &str, start: usize, end: usize, lenient: bool) -> Result<(u32, i32), ParseError>
+    {
+        let mut error: Option<ParseError> = None; // error of the most likely reading
+        let mut unit_starts: Vec<usize> = Vec::new(); // possible starts of the unit, most likely first
+
+
+        if !self.unit.is_empty()
+        {
+            unit_starts = s[start..end]
+                .rmatch_indices(self.unit.as_ref())
+                .map(|(offset, _unit)| start + offset)
+                .filter(|unit_start| lenient || unit_start + self.unit.len() == end) // strict: unit only at the end
+                .collect();
+        }
+
+        for case_insensitive in [false, true]
+        {
+            if case_insensitive && !lenient
+            {
+                break;
+            }
+            for unit_start in unit_starts.iter()
+            // with unit
+            {
+                let magnifier_end: usize = start + s[start..*unit_start].trim_end_matches(' ').len(); // whitespace before unit without unit prefix
+                if magnifier_end == start
+                // only unit
+                {
+                    return Ok((10, 0));
+                }
+                match self.parse_magnifier(s, start, magnifier_end, case_insensitive)
+                {
+                    Ok(magnifier) => return Ok(magnifier),
+                    Err(e) => error = error.or(Some(e)),
+                }
+            }
+            match self.parse_magnifier(s, start, end, case_insensitive) // bare unit prefix
+            {
+                Ok(magnifier) => return Ok(magnifier),
+                Err(e) => error = error.or(Some(e)),
+            }
+        }
+
+        return Err(error.expect("Parsing the magnifier has been tried at least once."));
+    }
+
+
+    /// # Summary
+    /// Parses what follows the digits: a unit prefix, optionally separated by whitespace, or scientific notation. Unit prefixes must match exactly, decimal and binary unit prefixes do not overlap, so "k" is always 10^(3) and "Ki" always 2^(10), regardless of the configured scaling. Only the unit prefixes the parser accepts are understood. If lenient, a unit prefix that does not match exactly may match ignoring case, as long as only one does, so "K" is "k" and "gi" is "Gi", but "m" stays milli. "e" or "E" directly followed by an integer is e-notation, otherwise "E" is the unit prefix exa.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string
+    /// - `start`: start of the magnifier
+    /// - `end`: end of the magnifier
+    /// - `lenient`: whether to match unit prefixes ignoring case
+    ///
+    /// # Returns
+    /// - base and exponent the magnifier stands for
+    /// - or `ParseError` if it is neither a known unit prefix nor valid scientific notation
+    fn parse_magnifier(&self, s: &str, start: usize, end: usize, lenient: bool) -> Result<(u32, i32), ParseError>
+    {
+        let magnifier: &str = &s[start..end];
+        let prefix_start: usize = if magnifier.starts_with(' ') { start + 1 } else { start }; // whitespace before unit prefix is optional
+
+
+        if let Some(multiplication) = magnifier.trim_start_matches(' ').chars().next().filter(|c| *c == '*' || *c == '×')
+        // scientific notation " * 10^(n)", " * 2^(n)", or " × 10ⁿ"
+        {
+            return self.parse_exponent(s, start + magnifier.find(multiplication).expect("Magnifier starts with multiplication sign after whitespace.") + multiplication.len_utf8(), end);
+        }
+        if magnifier.starts_with(['e', 'E']) && magnifier[1..].trim_start_matches(['+', '-']).starts_with(|c: char| c.is_ascii_digit())
+        // e-notation "e-3"
+        {
+            return Ok((10, parse_int_exponent(&magnifier[1..], start + 1)?));
+        }
+
+        if let Some((_prefix, base, exponent)) = self.prefixes.exact.iter().find(|(prefix, _base, _exponent)| *prefix == &s[prefix_start..end])
+        {
+            return Ok((*base, *exponent));
+        }
+        if lenient
+        // unique match ignoring case
+        {
+            if let Some((_prefix, base, exponent)) = self.prefixes.folded.iter().find(|(prefix, _base, _exponent)| prefix.eq_ignore_ascii_case(&s[prefix_start..end]))
+            {
+                return Ok((*base, *exponent));
+            }
+        }
+        return Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix(s[prefix_start..end].to_string()), position: prefix_start });
+    }
+
+
+    /// # Summary
+    /// Parses the part of scientific notation after the multiplication sign, "10^(n)" or "2^(n)" with an integer exponent n, or "10ⁿ" and "2ⁿ" with a superscript exponent. The base of exponential scaling, like "16^(n)", is understood as well. Powers of bases that are powers of 2 or 10 are returned in base 2 or 10, so they are applied exactly.
+    ///
+    /// # Arguments
+    /// - `s`: the whole string
+    /// - `start`: position after the multiplication sign
+    /// - `end`: end of the scientific notation
+    ///
+    /// # Returns
+    /// - base and exponent
+    /// - or `ParseErrorKind::InvalidExponent` with the position where the notation breaks
+    /// - or `ParseErrorKind::OutOfRange` if the exponent does not even fit into i32
+    fn parse_exponent(&self, s: &str, start: usize, end: usize) -> Result<(u32, i32), ParseError>
+    {
+        let base: u32; // base of the power
+        let mut pos: usize = start; // position of not yet parsed part
+
+
+        while s[pos..end].starts_with(' ')
+        {
+            pos += 1;
+        }
+        let base_len: usize = s[pos..end].bytes().take_while(u8::is_ascii_digit).count(); // number of digits of the base
+        base = match s[pos..pos + base_len].parse::<u32>()
+        {
+            Ok(base) if base == 10 || base == 2 || base == self.exponent_base => base,
+            _ => return Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: pos }),
+        };
+        pos += base_len;
+
+        if s[pos..end].starts_with("^(")
+        // "^(n)"
+        {
+            pos += "^(".len();
+            let close: usize = match s[pos..end].find(')')
+            {
+                Some(offset) => pos + offset,
+                None => return Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: end }), // missing closing parenthesis
+            };
+            if close + 1 != end
+            // something after closing parenthesis
+            {
+                return Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: close + 1 });
+            }
+            return Ok(power_of(base, parse_int_exponent(&s[pos..close], pos)?));
+        }
+        else
+        // superscript
+        {
+            let mut ascii: String = String::new(); // superscript exponent translated to ASCII
+            for (offset, c) in s[pos..end].char_indices()
+            {
+                ascii.push(match c
+                {
+                    '⁻' => '-',
+                    '⁺' => '+',
+                    '⁰' => '0',
+                    '¹' => '1',
+                    '²' => '2',
+                    '³' => '3',
+                    '⁴'..='⁹' => char::from_digit(c as u32 - '⁰' as u32, 10).expect("Superscript 4 to 9 are contiguous."),
+                    _ => return Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: pos + offset }),
+                });
+            }
+            return Ok(power_of(base, parse_int_exponent(&ascii, pos)?));
+        }
+    }
+}
+
+
+/// # Summary
+/// Parses a number of unknown locale, for example from files of different countries, where "." and "," may each be decimal or group separator. Uses the heuristics of `Formatter::parse_lenient`: if both occur, the last one is the decimal separator; if one occurs multiple times, it is a group separator; if one occurs once and is not followed by exactly 3 digits, or is preceded by more than 3 digits or a lone 0, it is the decimal separator. The remaining case, a single one between at most 3 and exactly 3 digits like in "1.234", is resolved by `hint`. Unit prefixes and scientific notation are understood like by `Formatter::parse_lenient`.
+///
+/// # Arguments
+/// - `s`: the number
+/// - `hint`: how to resolve a genuinely ambiguous separator
+///
+/// # Returns
+/// - the number
+/// - or `ParseError` describing what and where the problem is, `ParseErrorKind::AmbiguousDecimal` if ambiguous and `hint` is `DecimalHint::Error`
+///
+/// # Examples
+/// ```
+/// use scaler::{parse_auto, DecimalHint, ParseError, ParseErrorKind};
+/// assert_eq!(parse_auto("1.234", DecimalHint::Error), Err(ParseError { field: None, kind: ParseErrorKind::AmbiguousDecimal, position: 1 }));
+/// assert_eq!(parse_auto("1,234", DecimalHint::Error), Err(ParseError { field: None, kind: ParseErrorKind::AmbiguousDecimal, position: 1 }));
+/// assert_eq!(parse_auto("1.234", DecimalHint::PreferComma), Ok(1234.0));
+/// assert_eq!(parse_auto("1.234", DecimalHint::PreferDot), Ok(1.234));
+/// assert_eq!(parse_auto("1,234", DecimalHint::PreferComma), Ok(1.234));
+/// assert_eq!(parse_auto("1,234", DecimalHint::PreferDot), Ok(1234.0));
+/// for hint in [DecimalHint::Error, DecimalHint::PreferComma, DecimalHint::PreferDot] // unambiguous regardless of hint
+/// {
+///     assert_eq!(parse_auto("1.234,5", hint), Ok(1234.5));
+///     assert_eq!(parse_auto("1,234.5", hint), Ok(1234.5));
+///     assert_eq!(parse_auto("1.2345", hint), Ok(1.2345));
+///     assert_eq!(parse_auto("1,2345", hint), Ok(1.2345));
+///     assert_eq!(parse_auto("1234,567", hint), Ok(1234.567));
+///     assert_eq!(parse_auto("1.234.567", hint), Ok(1234567.0));
+///     assert_eq!(parse_auto("1,234,567.89", hint), Ok(1234567.89));
+///     assert_eq!(parse_auto("-0,5", hint), Ok(-0.5));
+///     assert_eq!(parse_auto("0.123", hint), Ok(0.123));
+///     assert_eq!(parse_auto("0,123", hint), Ok(0.123));
+///     assert_eq!(parse_auto("-0.123 k", hint), Ok(-123.0));
+///     assert_eq!(parse_auto("12", hint), Ok(12.0));
+///     assert_eq!(parse_auto(" 1 234,5 k", hint), Ok(1234500.0));
+/// }
+/// ```
+pub fn parse_auto(s: &str, hint: DecimalHint) -> Result<f64, ParseError>
+{
+    let mut f: Formatter = Formatter::new(); // formatter with separators according to hint
+
+
+    (f.group_separator, f.decimal_separator) = match hint
+    {
+        DecimalHint::Error => (Text::Static(""), Text::Static("")), // neither configured, so ambiguity is reported
+        DecimalHint::PreferComma => (Text::Static("."), Text::Static(",")),
+        DecimalHint::PreferDot => (Text::Static(","), Text::Static(".")),
+    };
+    return f.parse_lenient(s);
+}
+
+
+/// # Summary
+/// Counts the fraction digits of collected digits, trailing zeros included.
+///
+/// # Arguments
+/// - `digits`: digits with "." as decimal point
+///
+/// # Returns
+/// - number of digits after the decimal point
+fn fraction_len(digits: &str) -> i32
+{
+    return digits.split_once('.').map_or(0, |(_, fraction)| fraction.len().try_into().unwrap_or(i32::MAX));
+}
+
+
+/// # Summary
+/// Parses an integer exponent with optional sign.
+///
+/// # Arguments
+/// - `exponent`: the exponent as ASCII
+/// - `pos`: position of the exponent in the parsed string
+///
+/// # Returns
+/// - the exponent
+/// - or `ParseErrorKind::InvalidExponent` with the position of the first character that makes it not an integer
+/// - or `ParseErrorKind::OutOfRange` if it does not even fit into i32
+fn parse_int_exponent(exponent: &str, pos: usize) -> Result<i32, ParseError>
+{
+    return match exponent.parse::<i32>()
+    {
+        Ok(exponent) => Ok(exponent),
+        Err(e) if matches!(e.kind(), std::num::IntErrorKind::PosOverflow | std::num::IntErrorKind::NegOverflow) => Err(ParseError { field: None, kind: ParseErrorKind::OutOfRange, position: pos }),
+        Err(_) => Err(ParseError { field: None, kind: ParseErrorKind::InvalidExponent, position: pos + exponent.find(|c: char| !c.is_ascii_digit() && c != '-' && c != '+').unwrap_or(0) }),
+    };
+}
+
+
+/// # Summary
+/// Expresses a power in base 2 or 10 if its base is a power of 2 or 10, like 16^(n) as 2^(4n) and 1000^(n) as 10^(3n), so it is applied exactly.
+///
+/// # Arguments
+/// - `base`: base of the power
+/// - `exponent`: exponent of the power
+///
+/// # Returns
+/// - base and exponent of the same power
+fn power_of(base: u32, exponent: i32) -> (u32, i32)
+{
+    if base.is_power_of_two()
+    {
+        return (2, exponent.saturating_mul(base.ilog2() as i32));
+    }
+    if 10_u32.pow(base.ilog10()) == base
+    {
+        return (10, exponent.saturating_mul(base.ilog10() as i32));
+    }
+    return (base, exponent);
+}
+
+
+/// # Summary
+/// Multiplies an integer with a power exactly.
+///
+/// # Arguments
+/// - `x`: the integer
+/// - `base`: base of the power
+/// - `exponent`: exponent of the power, negative to divide
+///
+/// # Returns
+/// - x * base^exponent
+/// - or `ParseErrorKind::Overflow` if it does not fit into u128
+/// - or `ParseErrorKind::NotAnInteger` if dividing leaves a remainder
+fn scale_integer(x: u128, base: u128, exponent: i32) -> Result<u128, ParseErrorKind>
+{
+    if 0 <= exponent
+    {
+        return base.checked_pow(exponent.unsigned_abs()).and_then(|power| x.checked_mul(power)).ok_or(ParseErrorKind::Overflow);
+    }
+    return match base.checked_pow(exponent.unsigned_abs())
+    {
+        Some(power) if x.is_multiple_of(power) => Ok(x / power),
+        _ => Err(ParseErrorKind::NotAnInteger), // power larger than u128 is larger than x, x is not 0
+    };
+}
+
+
+impl std::fmt::Display for ParseError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let pos: usize = self.position; // where the problem is
+
+
+        if let Some(field) = self.field
+        {
+            write!(f, "Field {field}: ")?;
+        }
+        return match &self.kind
+        {
+            ParseErrorKind::AmbiguousDecimal => write!(f, "Ambiguous decimal separator at position {pos}, could be a group separator."),
+            ParseErrorKind::Empty => write!(f, "Nothing to parse."),
+            ParseErrorKind::InvalidExponent => write!(f, "Invalid scientific notation at position {pos}."),
+            ParseErrorKind::MissingPercent => write!(f, "Expected \"%\", \"‰\", \"ppm\", or \"ppb\" at position {pos}."),
+            ParseErrorKind::NoDigits => write!(f, "Expected digits at position {pos}."),
+            ParseErrorKind::NotAnInteger => write!(f, "Number at position {pos} is not an integer."),
+            ParseErrorKind::OutOfRange => write!(f, "Number at position {pos} is too large for f64."),
+            ParseErrorKind::Overflow => write!(f, "Integer at position {pos} does not fit into the integer type."),
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "Unexpected character {c:?} at position {pos}."),
+            ParseErrorKind::UnknownPrefix(prefix) => write!(f, "Unknown unit prefix {prefix:?} at position {pos}."),
+        };
+    }
+}
+
+
+impl std::error::Error for ParseError {}
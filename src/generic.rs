@@ -0,0 +1,110 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats any number implementing `num_traits::ToPrimitive`, for generic code that only knows `T: Num`. Integers are formatted exactly like `Formatter::format_int`, everything else like `Formatter::format`. The conversions are tried in this order:
+    /// 1. `to_f64`, if it is not finite or has a fraction, it is formatted as f64
+    /// 1. `to_i128`, then `to_u128`, the first that succeeds is formatted exactly as integer
+    /// 1. `to_f64`, if it succeeded but is too large for the integer conversions, it is formatted as f64
+    ///
+    /// So integers whose f64 conversion is lossy keep all their digits, and f64 is never truncated to an integer.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - formatted number
+    /// - or `FormatError::Unrepresentable` if all conversions fail
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_num(&1234.5_f64), Ok("1,234 k".to_string()));
+    /// assert_eq!(f.format_num(&0.5_f32), Ok("500,0 m".to_string()));
+    /// assert_eq!(f.format_num(&-42_i8), Ok("-42,00".to_string()));
+    /// assert_eq!(f.format_num(&f64::INFINITY), Ok("∞".to_string()));
+    /// assert_eq!(f.format_num(&f64::NAN), Ok("NaN".to_string()));
+    /// assert_eq!(f.format_num(&1e300_f64), Ok("1,000 * 10^(300)".to_string())); // integral, but beyond i128 and u128
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(39));
+    /// assert_eq!(f.format_num(&u128::MAX), Ok("340.282.366.920.938.463.463.374.607.431.768.211.455".to_string()));
+    /// assert_eq!(f.format_num(&u64::MAX), Ok(f.format_int(u64::MAX)));
+    /// ```
+    ///
+    /// ```
+    /// // lossy f64 conversion, exact integer conversion
+    /// use num_traits::ToPrimitive;
+    /// use scaler::FormatError;
+    /// struct Counter(i128);
+    /// impl ToPrimitive for Counter
+    /// {
+    ///     fn to_f64(&self) -> Option<f64> { Some(self.0 as f64) }
+    ///     fn to_i64(&self) -> Option<i64> { self.0.to_i64() }
+    ///     fn to_i128(&self) -> Option<i128> { Some(self.0) }
+    ///     fn to_u64(&self) -> Option<u64> { self.0.to_u64() }
+    /// }
+    /// struct Nothing;
+    /// impl ToPrimitive for Nothing
+    /// {
+    ///     fn to_f64(&self) -> Option<f64> { None }
+    ///     fn to_i64(&self) -> Option<i64> { None }
+    ///     fn to_u64(&self) -> Option<u64> { None }
+    /// }
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(19));
+    /// assert_eq!(f.format_num(&Counter((1 << 60) + 1)), Ok("1.152.921.504.606.846.977".to_string())); // exact path
+    /// assert_eq!(f.format((((1_i128 << 60) + 1) as f64)), "1.152.921.504.606.846.976"); // f64 path would lose the last digit
+    /// assert_eq!(f.format_num(&Counter(-(1 << 100))), Ok(f.format_int(-(1_i128 << 100))));
+    /// assert_eq!(f.format_num(&Nothing), Err(FormatError::Unrepresentable));
+    /// ```
+    pub fn format_num<T>(&self, x: &T) -> Result<String, FormatError>
+    where
+        T: num_traits::ToPrimitive,
+    {
+        let float: Option<f64> = x.to_f64(); // f64 conversion, may be lossy
+        let mut s: String = String::new(); // formatted number, result
+        let value: Value; // number to format
+
+
+        if let Some(float) = float.filter(|float| !float.is_finite() || float.fract() != 0.0)
+        // not an integer, f64 is the best there is
+        {
+            value = Value::Float(float);
+        }
+        else if let Some(int) = x.to_i128()
+        {
+            value = Value::Exact(int < 0, int.unsigned_abs(), 0);
+        }
+        else if let Some(int) = x.to_u128()
+        {
+            value = Value::Exact(false, int, 0);
+        }
+        else if let Some(float) = float
+        // integer beyond i128 and u128
+        {
+            value = Value::Float(float);
+        }
+        else
+        {
+            return Err(FormatError::Unrepresentable);
+        }
+
+        if let Value::Float(float) = value
+        {
+            self.expect_accepted(float);
+        }
+        self.render(value, &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        return Ok(s);
+    }
+}
+
@@ -0,0 +1,89 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+
+/// process-wide formatter used by the free functions, default settings until replaced
+static GLOBAL_FORMATTER: OnceLock<RwLock<Arc<Formatter>>> = OnceLock::new();
+
+
+/// # Summary
+/// Formats a number with the global formatter, without constructing one. Until `set_global_formatter` is called, this is `Formatter::new`.
+///
+/// # Arguments
+/// - `x`: the number to format
+///     - must be losslessly convertable to f64, references are accepted as well
+///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+///
+/// # Returns
+/// - the formatted number
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::format(42069), "42,07 k");
+/// assert_eq!(scaler::format(&0.789), "789,0 m");
+/// ```
+pub fn format<T>(x: T) -> String
+where
+    T: IntoF64,
+{
+    return with_global(|f| f.format(x));
+}
+
+
+/// # Summary
+/// Replaces the global formatter used by `scaler::format` and `with_global`, for example to switch the whole process to its locale once at startup. Setting it after first use is allowed as well, it then applies to all subsequent calls, calls already running finish with the previous formatter.
+///
+/// # Arguments
+/// - `formatter`: the new global formatter
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::format(1234.5), "1,234 k"); // default settings
+/// scaler::set_global_formatter(scaler::Formatter::new()
+///     .set_scaling(scaler::Scaling::None)
+///     .set_rounding(scaler::Rounding::Magnitude(-2))
+///     .set_separators(",", "."));
+/// assert_eq!(scaler::format(1234.5), "1,234.50"); // replaced for all subsequent calls
+/// ```
+pub fn set_global_formatter(formatter: Formatter)
+{
+    *global().write().unwrap_or_else(PoisonError::into_inner) = Arc::new(formatter); // lock is only ever held to swap or clone the Arc, poisoning cannot leave it inconsistent
+}
+
+
+/// # Summary
+/// Calls `f` with the global formatter, for all output methods besides `scaler::format`. The formatter stays valid for the whole call even if it is replaced in the meantime, and the lock is not held while `f` runs, so `f` may call `set_global_formatter` itself.
+///
+/// # Arguments
+/// - `f`: receives the global formatter
+///
+/// # Returns
+/// - the result of `f`
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::with_global(|f| f.format_opt(None::<f64>)), "—");
+/// let width: usize = scaler::with_global(|f| f.unpadded_len(42069));
+/// assert_eq!(width, 7);
+/// ```
+pub fn with_global<F, R>(f: F) -> R
+where
+    F: FnOnce(&Formatter) -> R,
+{
+    let formatter: Arc<Formatter> = Arc::clone(&global().read().unwrap_or_else(PoisonError::into_inner)); // current formatter, lock released right away
+
+
+    return f(&formatter);
+}
+
+
+/// # Summary
+/// Initializes the global formatter with default settings on first use.
+///
+/// # Returns
+/// - the lock around the global formatter
+fn global() -> &'static RwLock<Arc<Formatter>>
+{
+    return GLOBAL_FORMATTER.get_or_init(|| RwLock::new(Arc::new(Formatter::new())));
+}
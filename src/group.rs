@@ -0,0 +1,142 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::group_digits` if the input is not an optionally signed decimal number.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GroupError
+{
+    Empty,                         // no digits at all
+    InvalidCharacter(usize, char), // character that is not allowed at this point, contains byte index and character
+    MissingFractionDigits,         // decimal point without digits after it
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Separates the digits of an existing decimal number into groups and replaces its decimal point with the configured decimal separator. Nothing is rounded, scaled, or converted to f64, every digit is kept as it is. Useful if the exact digits are already known, for example from another system.
+    ///
+    /// # Arguments
+    /// - `s`: the number, optional sign "+" or "-", integer digits, optionally "." and fraction digits
+    ///
+    /// # Returns
+    /// - the number with separators, the sign is kept as it is
+    /// - or `GroupError` if `s` is not a decimal number, exponents are not supported
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.group_digits("-12345678.9"), Ok("-12.345.678,9".to_string()));
+    /// assert_eq!(f.group_digits("+1000"), Ok("+1.000".to_string()));
+    /// assert_eq!(f.group_digits("340282366920938463463374607431768211455"), Ok("340.282.366.920.938.463.463.374.607.431.768.211.455".to_string()));
+    /// assert_eq!(f.group_digits("0.000012345678"), Ok("0,000012345678".to_string())); // fraction digits are not grouped
+    /// assert_eq!(f.group_digits("007"), Ok("007".to_string()));
+    /// ```
+    ///
+    /// ```
+    /// use scaler::GroupError;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.group_digits(""), Err(GroupError::Empty));
+    /// assert_eq!(f.group_digits("-"), Err(GroupError::Empty));
+    /// assert_eq!(f.group_digits("1e5"), Err(GroupError::InvalidCharacter(1, 'e')));
+    /// assert_eq!(f.group_digits("1.000,5"), Err(GroupError::InvalidCharacter(5, ',')));
+    /// assert_eq!(f.group_digits("1.2.3"), Err(GroupError::InvalidCharacter(3, '.')));
+    /// assert_eq!(f.group_digits(".5"), Err(GroupError::InvalidCharacter(0, '.')));
+    /// assert_eq!(f.group_digits("5."), Err(GroupError::MissingFractionDigits));
+    /// assert_eq!(f.group_digits(" 5"), Err(GroupError::InvalidCharacter(0, ' ')));
+    /// ```
+    pub fn group_digits(&self, s: &str) -> Result<String, GroupError>
+    {
+        let digits: &str; // s without sign
+        let mut grouped: String = String::new(); // result
+        let mut point: Option<usize> = None; // byte index of decimal point in digits
+        let sign: &str; // sign as given
+
+
+        sign = if s.starts_with(['+', '-']) { &s[..1] } else { "" };
+        digits = &s[sign.len()..];
+        if digits.is_empty()
+        {
+            return Err(GroupError::Empty);
+        }
+
+        for (i, c) in digits.char_indices()
+        {
+            match c
+            {
+                '0'..='9' => {}
+                '.' if point.is_none() && 0 < i => point = Some(i), // decimal point needs integer digits before it
+                _ => return Err(GroupError::InvalidCharacter(sign.len() + i, c)),
+            }
+        }
+        if point == Some(digits.len() - 1)
+        {
+            return Err(GroupError::MissingFractionDigits);
+        }
+
+        grouped.push_str(sign);
+        self.render_digits(digits, &mut |_kind, piece| {
+            grouped.push_str(piece);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+
+        return Ok(grouped);
+    }
+
+
+    /// # Summary
+    /// Separates the digits of an unsigned integer into groups. Exact for all u128, unlike `Formatter::format` which converts to f64.
+    ///
+    /// # Arguments
+    /// - `x`: the integer
+    ///
+    /// # Returns
+    /// - the integer with group separators
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.group_int(0), "0");
+    /// assert_eq!(f.group_int(999), "999");
+    /// assert_eq!(f.group_int(1000), "1.000");
+    /// assert_eq!(f.group_int(u128::MAX), "340.282.366.920.938.463.463.374.607.431.768.211.455");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators("'", ".");
+    /// assert_eq!(f.group_int(18446744073709551615), "18'446'744'073'709'551'615");
+    /// ```
+    pub fn group_int(&self, x: u128) -> String
+    {
+        let mut grouped: String = String::new(); // result
+
+        self.render_digits(&x.to_string(), &mut |_kind, piece| {
+            grouped.push_str(piece);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+
+        return grouped;
+    }
+}
+
+
+impl std::fmt::Display for GroupError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            GroupError::Empty => write!(f, "Number has no digits."),
+            GroupError::InvalidCharacter(i, c) => write!(f, "Invalid character {c:?} at byte index {i}."),
+            GroupError::MissingFractionDigits => write!(f, "Decimal point has no digits after it."),
+        };
+    }
+}
+
+
+impl std::error::Error for GroupError {}
@@ -0,0 +1,89 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats the bits of an f64 as hexadecimal float, like `%a` in C, "0x1.999999999999ap-4" for 0.1. The significand is displayed in hexadecimal with its implicit leading 1 and the binary exponent in decimal, so the output preserves the bit pattern exactly and parses back losslessly. Subnormals are displayed with a leading 0 and the exponent -1022, like "0x0.0000000000001p-1022". Case and trailing zeros are set with `Formatter::set_hexfloat`. Negative numbers including -0 are signed with "-", positive ones never. The point is always ".", independent of the configured decimal separator, and neither scaling, rounding, grouping, affixes, nor unit apply. Infinity and NaN are displayed with the special strings. The configured width applies to the result as a whole.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - formatted hexadecimal float
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_hexfloat(0.1), "0x1.999999999999ap-4");
+    /// assert_eq!(f.format_hexfloat(1.0), "0x1p+0");
+    /// assert_eq!(f.format_hexfloat(-2.5), "-0x1.4p+1");
+    /// assert_eq!(f.format_hexfloat(255.0), "0x1.fep+7");
+    /// assert_eq!(f.format_hexfloat(0.0), "0x0p+0");
+    /// assert_eq!(f.format_hexfloat(-0.0), "-0x0p+0"); // sign bit preserved
+    /// assert_eq!(f.format_hexfloat(f64::from_bits(1)), "0x0.0000000000001p-1022"); // smallest subnormal
+    /// assert_eq!(f.format_hexfloat(f64::NEG_INFINITY), "-∞");
+    /// assert_eq!(f.format_hexfloat(f64::NAN), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_hexfloat(scaler::HexfloatOptions { all_digits: true, uppercase: true })
+    ///     .set_width(24, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format_hexfloat(1.0), "    0X1.0000000000000P+0");
+    /// assert_eq!(f.format_hexfloat(-0.1), "   -0X1.999999999999AP-4");
+    /// ```
+    pub fn format_hexfloat(&self, x: f64) -> String
+    {
+        let bits: u64 = x.to_bits();
+        let biased: u64 = bits >> 52 & 0x7FF; // biased binary exponent, 0 for 0 and subnormals
+        let exponent: i64; // unbiased binary exponent
+        let mut fraction: String; // significand without leading digit as 13 hexadecimal digits
+        let mut s: String = String::new();
+        let significand: u64 = bits & ((1 << 52) - 1); // without implicit leading 1
+
+
+        if x.is_nan()
+        // never signed
+        {
+            return self.pad(self.special_strings.1.to_string());
+        }
+        if x.is_sign_negative()
+        {
+            s.push('-');
+        }
+        if x.is_infinite()
+        {
+            s.push_str(&self.special_strings.0);
+            return self.pad(s);
+        }
+
+        exponent = match (biased, significand)
+        {
+            (0, 0) => 0, // 0 has no exponent
+            (0, _) => -1022, // subnormal, leading 0
+            (biased, _) => biased as i64 - 1023,
+        };
+        fraction = format!("{significand:013x}");
+        if !self.hexfloat.all_digits
+        // minimal, like `%a`
+        {
+            fraction.truncate(fraction.trim_end_matches('0').len());
+        }
+
+        s.push_str(&format!("0x{}", if biased == 0 { 0 } else { 1 }));
+        if !fraction.is_empty()
+        {
+            s.push('.');
+            s.push_str(&fraction);
+        }
+        s.push_str(&format!("p{exponent:+}"));
+        if self.hexfloat.uppercase
+        {
+            s.make_ascii_uppercase();
+        }
+
+        return self.pad(s);
+    }
+}
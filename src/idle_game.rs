@@ -0,0 +1,221 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Suffixes of idle game notation before the generated ones, for 10^0 to 10^18.
+const BASE_SUFFIXES: [&str; 7] = ["", "K", "M", "B", "T", "Qa", "Qi"];
+
+/// # Summary
+/// Idle game notation for `PrefixTable::IDLE_GAME`, [lower bound magnitude; upper bound magnitude[, suffix, up to the magnitude of f64::MAX. Equal to `scaler::idle_game_suffix` for every entry.
+const IDLE_GAME_PREFIXES: [(i16, i16, &str); 103] = [
+    (0, 3, ""),
+    (3, 6, "K"),
+    (6, 9, "M"),
+    (9, 12, "B"),
+    (12, 15, "T"),
+    (15, 18, "Qa"),
+    (18, 21, "Qi"),
+    (21, 24, "aa"),
+    (24, 27, "ab"),
+    (27, 30, "ac"),
+    (30, 33, "ad"),
+    (33, 36, "ae"),
+    (36, 39, "af"),
+    (39, 42, "ag"),
+    (42, 45, "ah"),
+    (45, 48, "ai"),
+    (48, 51, "aj"),
+    (51, 54, "ak"),
+    (54, 57, "al"),
+    (57, 60, "am"),
+    (60, 63, "an"),
+    (63, 66, "ao"),
+    (66, 69, "ap"),
+    (69, 72, "aq"),
+    (72, 75, "ar"),
+    (75, 78, "as"),
+    (78, 81, "at"),
+    (81, 84, "au"),
+    (84, 87, "av"),
+    (87, 90, "aw"),
+    (90, 93, "ax"),
+    (93, 96, "ay"),
+    (96, 99, "az"),
+    (99, 102, "ba"),
+    (102, 105, "bb"),
+    (105, 108, "bc"),
+    (108, 111, "bd"),
+    (111, 114, "be"),
+    (114, 117, "bf"),
+    (117, 120, "bg"),
+    (120, 123, "bh"),
+    (123, 126, "bi"),
+    (126, 129, "bj"),
+    (129, 132, "bk"),
+    (132, 135, "bl"),
+    (135, 138, "bm"),
+    (138, 141, "bn"),
+    (141, 144, "bo"),
+    (144, 147, "bp"),
+    (147, 150, "bq"),
+    (150, 153, "br"),
+    (153, 156, "bs"),
+    (156, 159, "bt"),
+    (159, 162, "bu"),
+    (162, 165, "bv"),
+    (165, 168, "bw"),
+    (168, 171, "bx"),
+    (171, 174, "by"),
+    (174, 177, "bz"),
+    (177, 180, "ca"),
+    (180, 183, "cb"),
+    (183, 186, "cc"),
+    (186, 189, "cd"),
+    (189, 192, "ce"),
+    (192, 195, "cf"),
+    (195, 198, "cg"),
+    (198, 201, "ch"),
+    (201, 204, "ci"),
+    (204, 207, "cj"),
+    (207, 210, "ck"),
+    (210, 213, "cl"),
+    (213, 216, "cm"),
+    (216, 219, "cn"),
+    (219, 222, "co"),
+    (222, 225, "cp"),
+    (225, 228, "cq"),
+    (228, 231, "cr"),
+    (231, 234, "cs"),
+    (234, 237, "ct"),
+    (237, 240, "cu"),
+    (240, 243, "cv"),
+    (243, 246, "cw"),
+    (246, 249, "cx"),
+    (249, 252, "cy"),
+    (252, 255, "cz"),
+    (255, 258, "da"),
+    (258, 261, "db"),
+    (261, 264, "dc"),
+    (264, 267, "dd"),
+    (267, 270, "de"),
+    (270, 273, "df"),
+    (273, 276, "dg"),
+    (276, 279, "dh"),
+    (279, 282, "di"),
+    (282, 285, "dj"),
+    (285, 288, "dk"),
+    (288, 291, "dl"),
+    (291, 294, "dm"),
+    (294, 297, "dn"),
+    (297, 300, "do"),
+    (300, 303, "dp"),
+    (303, 306, "dq"),
+    (306, 309, "dr"),
+];
+
+
+impl PrefixTable
+{
+    /// # Summary
+    /// Suffixes of incremental and idle games for `Scaling::Exponential` in base 10 with step 3: "K", "M", "B", "T", "Qa", and "Qi" up to 10^18, then two letters generated every 10^3, "aa" for 10^21, "ab" for 10^24, up to "dr" for 10^306, so no f64 falls back to exponent notation for being too large. Numbers below 1 have no suffix and fall back to exponent notation. The suffix of any magnitude, also beyond f64, is calculated by `scaler::idle_game_suffix`. Used by `Formatter::idle_game`.
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_scaling(scaler::Scaling::Exponential { base: 10, step: 3, prefixes: Some(scaler::PrefixTable::IDLE_GAME) });
+    /// assert_eq!(f.format(1.5e9), "1,500 B");
+    /// assert_eq!(f.format(1e21), "1,000 aa");
+    /// assert_eq!(f.format(f64::MAX), "179,8 dr");
+    /// ```
+    pub const IDLE_GAME: PrefixTable = PrefixTable::new(&IDLE_GAME_PREFIXES, true);
+}
+
+
+/// # Summary
+/// Calculates the suffix of idle game notation for a power of 10, like `PrefixTable::IDLE_GAME` uses: "" below 10^3, "K", "M", "B", "T", "Qa", and "Qi" up to 10^18, then lowercase letters counting up every 10^3 from "aa" for 10^21 to "zz", followed by "aaa" and so on without end. The exponent is floored to a multiple of 3, so the suffix is the one of the number 10^exponent.
+///
+/// # Arguments
+/// - `exponent`: exponent of the power of 10
+///
+/// # Returns
+/// - the suffix
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::idle_game_suffix(0), "");
+/// assert_eq!(scaler::idle_game_suffix(19), "Qi");
+/// assert_eq!(scaler::idle_game_suffix(21), "aa");
+/// assert_eq!(scaler::idle_game_suffix(63), "ao");
+/// assert_eq!(scaler::idle_game_suffix(300), "dp");
+/// assert_eq!(scaler::idle_game_suffix(2049), "aaa"); // after "zz"
+/// ```
+pub fn idle_game_suffix(exponent: u32) -> String
+{
+    let mut len: u32 = 2; // number of letters
+    let mut n: u64 = (exponent / 3) as u64; // index of the suffix, then of the generated suffix among the ones of its length
+
+
+    if n < BASE_SUFFIXES.len() as u64
+    // not generated
+    {
+        return BASE_SUFFIXES[n as usize].to_owned();
+    }
+
+    n -= BASE_SUFFIXES.len() as u64;
+    while 26_u64.pow(len) <= n
+    // skip all suffixes with fewer letters
+    {
+        n -= 26_u64.pow(len);
+        len += 1;
+    }
+    return (0..len).rev().map(|i| (b'a' + (n / 26_u64.pow(i) % 26) as u8) as char).collect();
+}
+
+
+/// # Summary
+/// Calculates the power of 10 a suffix of idle game notation stands for, the inverse of `scaler::idle_game_suffix`, to read numbers like "1,5 aa" back: parse the mantissa and multiply by 10 to the power of the exponent of the suffix.
+///
+/// # Arguments
+/// - `suffix`: the suffix, case-sensitive
+///
+/// # Returns
+/// - exponent of the power of 10, a multiple of 3, or none if the suffix is not part of idle game notation or its exponent does not fit into u32
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::idle_game_exponent("K"), Some(3));
+/// assert_eq!(scaler::idle_game_exponent("aa"), Some(21));
+/// assert_eq!(scaler::idle_game_exponent("aaa"), Some(2049));
+/// assert_eq!(scaler::idle_game_exponent("a"), None); // generated suffixes have at least 2 letters
+/// assert_eq!(scaler::idle_game_exponent("AA"), None);
+///
+/// let f: scaler::Formatter = scaler::Formatter::idle_game();
+/// let s: String = f.format(4.2e25); // "42,00 ab"
+/// let (mantissa, suffix): (&str, &str) = s.split_once(' ').unwrap();
+/// assert_eq!(f.parse(mantissa).unwrap() * 10_f64.powi(scaler::idle_game_exponent(suffix).unwrap() as i32), 4.2e25);
+/// ```
+pub fn idle_game_exponent(suffix: &str) -> Option<u32>
+{
+    let mut index: u64; // index of the suffix
+
+
+    if let Some(i) = BASE_SUFFIXES.iter().position(|base| *base == suffix)
+    // not generated
+    {
+        return Some(i as u32 * 3);
+    }
+    if suffix.len() < 2 || !suffix.bytes().all(|c| c.is_ascii_lowercase())
+    // neither generated
+    {
+        return None;
+    }
+
+    index = BASE_SUFFIXES.len() as u64;
+    for len in 2..suffix.len() as u32
+    // all suffixes with fewer letters come before
+    {
+        index = index.checked_add(26_u64.checked_pow(len)?)?;
+    }
+    index = index.checked_add(suffix.bytes().try_fold(0_u64, |value, c| value.checked_mul(26)?.checked_add((c - b'a') as u64))?)?; // letters as digits in base 26
+    return u32::try_from(index.checked_mul(3)?).ok();
+}
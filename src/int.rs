@@ -0,0 +1,282 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::format_num` and `Formatter::try_format` if the number cannot be formatted as it is.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FormatError
+{
+    PrecisionLoss { lost: u128 }, // conversion to f64 changed the displayed number, `lost` is by how much the conversion changed the integer
+    Unrepresentable,              // number converts neither to f64 nor to i128 nor to u128
+}
+
+
+/// # Summary
+/// Integer types that `Formatter::format_int` formats without converting to f64 first, so integers beyond 2^(53) keep all their digits.
+pub trait FormatInt: Copy
+{
+    /// # Summary
+    /// Type suffix of Rust integer literals that `Formatter::format_rust_literal_int` appends, like "u64", empty for none.
+    const LITERAL_SUFFIX: &'static str = "";
+
+
+    /// # Summary
+    /// Splits the integer into sign and magnitude.
+    ///
+    /// # Returns
+    /// - whether the integer is negative
+    /// - the magnitude
+    fn sign_magnitude(self) -> (bool, u128);
+}
+
+
+/// # Summary
+/// Implements `FormatInt` for signed integer types.
+macro_rules! impl_format_int_signed
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl FormatInt for $t
+            {
+                const LITERAL_SUFFIX: &'static str = stringify!($t);
+
+
+                fn sign_magnitude(self) -> (bool, u128)
+                {
+                    return (self < 0, self.unsigned_abs() as u128);
+                }
+            }
+        )*
+    };
+}
+
+
+/// # Summary
+/// Implements `FormatInt` for unsigned integer types.
+macro_rules! impl_format_int_unsigned
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl FormatInt for $t
+            {
+                const LITERAL_SUFFIX: &'static str = stringify!($t);
+
+
+                fn sign_magnitude(self) -> (bool, u128)
+                {
+                    return (false, self as u128);
+                }
+            }
+        )*
+    };
+}
+
+
+impl_format_int_signed!(i8, i16, i32, i64, i128, isize);
+impl_format_int_unsigned!(u8, u16, u32, u64, u128, usize);
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats an integer like `Formatter::format`, but exactly in decimal digit arithmetic without any f64, so integers beyond 2^(53), for example byte counters or 128 bit nanosecond totals, are displayed with all their digits correct. The unit prefix is chosen by the magnitude of the integer after rounding, rounding is done on the decimal digits, ties to even, and binary unit prefixes divide exactly. Within 2^(53) the result is identical to `Formatter::format`, except binary scaling of mantissas that are exact powers of 10, where `Formatter::format` can show one decimal place too many due to f64 rounding.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    ///
+    /// # Returns
+    /// - formatted integer
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(20));
+    /// assert_eq!(f.format_int(u64::MAX), "18.446.744.073.709.551.615");
+    /// assert_eq!(f.format(u64::MAX as f64), "18.446.744.073.709.551.616"); // f64 is off by one
+    /// assert_eq!(f.format_int(i64::MIN), "-9.223.372.036.854.775.808,0");
+    /// assert_eq!(f.format_int(u128::MAX), "340.282.366.920.938.463.460.000.000.000.000.000.000");
+    /// assert_eq!(f.format_int(9007199254740993_u64), "9.007.199.254.740.993,0000"); // 2^53 + 1
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(20));
+    /// assert_eq!(f.format_int(u64::MAX), "18,446744073709551615 E");
+    /// assert_eq!(f.format_int(12345678901234567890123_u128), "12,345678901234567890 Z");
+    /// assert_eq!(f.format_int(-12345678901234567890123_i128), "-12,345678901234567890 Z");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(20));
+    /// assert_eq!(f.format_int(u64::MAX), "1,8446744073709551615 * 10^(19)");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(3));
+    /// assert_eq!(f.format_int(12345678901234567500_u64), "12.345.678.901.234.568.000");
+    /// assert_eq!(f.format_int(12345678901234566500_u64), "12.345.678.901.234.566.000"); // ties to even
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(39));
+    /// assert_eq!(f.format_int(170_141_183_460_469_231_731_687_303_715_884_105_727_i128), "170.141.183.460.469.231.731.687.303.715.884.105.727");
+    /// assert_eq!(f.format_int(i128::MIN), "-170.141.183.460.469.231.731.687.303.715.884.105.728");
+    /// assert_eq!(f.format_int(u128::MAX), "340.282.366.920.938.463.463.374.607.431.768.211.455");
+    /// assert_eq!(f.format_int(10_u128.pow(38)), "100.000.000.000.000.000.000.000.000.000.000.000.000");
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_int(u128::MAX), "3,403 * 10^(38)"); // rounding up beyond u128::MAX
+    /// assert_eq!(f.format_int(i128::MIN), "-1,701 * 10^(38)");
+    /// assert_eq!(f.format_int(999_999_999_999_999_999_999_u128), "1,000 Z"); // rounded into the next unit prefix
+    /// assert_eq!(f.format_int(999_499_999_999_999_999_999_u128), "999,5 E");
+    /// assert_eq!(f.format_int(999_949_999_999_999_999_999_999_999_999_999_u128), "999,9 Q");
+    /// assert_eq!(f.format_int(999_950_000_000_000_000_000_000_000_000_000_u128), "1,000 * 10^(33)"); // tie, to even
+    /// for (exponent, expected) in [(0, "1,000"), (3, "1,000 k"), (17, "100,0 P"), (21, "1,000 Z"), (30, "1,000 Q"), (38, "1,000 * 10^(38)")]
+    /// {
+    ///     assert_eq!(f.format_int(10_u128.pow(exponent)), expected);
+    ///     if 4 < exponent
+    ///     {
+    ///         assert_eq!(f.format_int(10_u128.pow(exponent) - 1), expected); // 9s round up
+    ///     }
+    /// }
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(30));
+    /// assert_eq!(f.format_int(1_u128 << 80), "1,00000000000000000000000000000 Yi");
+    /// assert_eq!(f.format_int((1_u128 << 80) + 1), "1,00000000000000000000000082718 Yi"); // exact binary division
+    /// assert_eq!(f.format_int(u128::MAX), "1,00000000000000000000000000000 * 2^(128)"); // rounded beyond u128::MAX
+    /// assert_eq!(f.format_int(3_u128 << 100), "1,50000000000000000000000000000 * 2^(101)");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_int(12_345_000_000_000_000_001_u64), "12,35 E"); // rounded exactly, f64 would see a tie and round to even
+    /// assert_eq!(f.format(12_345_000_000_000_000_001_u64 as f64), "12,34 E");
+    /// let mut state: u64 = 42;
+    /// for scaling in [scaler::Scaling::Binary(true), scaler::Scaling::Decimal(false), scaler::Scaling::None, scaler::Scaling::Scientific]
+    /// {
+    ///     for rounding in [scaler::Rounding::SignificantDigits(1), scaler::Rounding::SignificantDigits(4), scaler::Rounding::SignificantDigits(12), scaler::Rounding::Magnitude(-2), scaler::Rounding::Magnitude(0), scaler::Rounding::Magnitude(3)]
+    ///     {
+    ///         let f: scaler::Formatter = scaler::Formatter::new()
+    ///             .set_scaling(scaling.clone())
+    ///             .set_rounding(rounding.clone());
+    ///         for _ in 0..100 // identical to f64 where f64 is exact
+    ///         {
+    ///             state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    ///             let x: i64 = (state >> (11 + state % 53)) as i64 * if state % 3 == 0 { -1 } else { 1 };
+    ///             if scaling == scaler::Scaling::Binary(true) && x.unsigned_abs().to_string().trim_end_matches('0') == "1" || 15 < f.format(x as f64).chars().filter(char::is_ascii_digit).count()
+    ///             {
+    ///                 continue; // exceptions, f64 rounding in `Formatter::format` and f64 not having more than 15 significant digits
+    ///             }
+    ///             assert_eq!(f.format_int(x), f.format(x as f64), "{x} {scaling:?} {rounding:?}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn format_int<T>(&self, x: T) -> String
+    where
+        T: FormatInt,
+    {
+        let (negative, magnitude): (bool, u128) = x.sign_magnitude();
+        let mut s: String = String::new(); // formatted integer, result
+
+        self.render(Value::Exact(negative, magnitude, 0), &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats an integer like `Formatter::format`, but detects if converting it to f64 first changes the result. The conversion to f64 is lossy for integers beyond 2^(53), but often the lost digits are discarded by the configured rounding anyway. The rule is: the conversion is accepted if and only if it is exact, or formatting the converted f64 with `Formatter::format` results in exactly the same string as formatting the integer itself with `Formatter::format_int`. The result is always the exact one. Otherwise the loss is surfaced as error, including the rare case where the lost digits are below the rounding position but the conversion moved the integer onto or across a tie. Use `Formatter::format_int` to format the integer exactly regardless.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    ///
+    /// # Returns
+    /// - formatted integer
+    /// - or `FormatError::PrecisionLoss` with the amount the conversion changed the integer by if that changed the result
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::FormatError;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(4));
+    /// assert_eq!(f.try_format(u64::MAX), Ok("18,45 E".to_string())); // loss of 1 is far below the rounding
+    /// assert_eq!(f.try_format(-1234_i32), Ok("-1,234 k".to_string())); // no loss at all
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(20));
+    /// assert_eq!(f.try_format(u64::MAX), Err(FormatError::PrecisionLoss { lost: 1 })); // 18,446744073709551615 E would become 18,446744073709551616 E
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(15));
+    /// assert_eq!(f.try_format(u128::MAX), Ok("3,40282366920938 * 10^(38)".to_string())); // rounded away
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(39));
+    /// assert_eq!(f.try_format(u128::MAX), Err(FormatError::PrecisionLoss { lost: 1 }));
+    /// assert_eq!(f.try_format(1_u128 << 100), Ok(f.format_int(1_u128 << 100))); // power of 2, f64 is exact
+    /// ```
+    ///
+    /// ```
+    /// // both sides of the rule
+    /// use scaler::FormatError;
+    /// let x: u64 = (1 << 53) + 1; // 9.007.199.254.740.993, f64 has 9.007.199.254.740.992
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(0));
+    /// assert_eq!(f.try_format(x), Err(FormatError::PrecisionLoss { lost: 1 }));
+    /// assert_eq!(f.try_format(-(x as i64)), Err(FormatError::PrecisionLoss { lost: 1 }));
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(1));
+    /// assert_eq!(f.try_format(x), Ok("9.007.199.254.740.990".to_string())); // lost digit is rounded away
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.try_format(12_345_000_000_000_000_001_u64), Err(FormatError::PrecisionLoss { lost: 1 })); // f64 lands on the tie, rounds to 12,34 E instead of 12,35 E
+    /// assert_eq!(f.try_format(12_345_000_000_000_000_000_u64), Ok("12,34 E".to_string()));
+    /// ```
+    pub fn try_format<T>(&self, x: T) -> Result<String, FormatError>
+    where
+        T: FormatInt,
+    {
+        let exact: String = self.format_int(x); // formatted integer without f64
+        let float: f64; // magnitude converted to f64, may be lossy
+        let lost: u128; // by how much the conversion changed the magnitude
+        let plain: Formatter = Formatter { show_original: (ShowOriginal::Off, false), ..self.clone() }; // compared like the exact path, which never shows the original
+        let (negative, magnitude): (bool, u128) = x.sign_magnitude();
+
+
+        float = magnitude as f64;
+        lost = if float < 2.0_f64.powi(128) { (float as u128).abs_diff(magnitude) } else { u128::MAX - magnitude + 1 }; // rounded up to 2^(128), beyond u128
+        if lost == 0 || plain.format(if negative { -float } else { float }) == exact
+        // conversion exact or loss vanishes in rounding
+        {
+            return Ok(exact);
+        }
+
+        return Err(FormatError::PrecisionLoss { lost });
+    }
+}
+
+
+impl std::fmt::Display for FormatError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            FormatError::PrecisionLoss { lost } => write!(f, "Converting the number to f64 changed it by {lost} and the formatted result with it."),
+            FormatError::Unrepresentable => write!(f, "Number converts neither to f64 nor to i128 nor to u128."),
+        };
+    }
+}
+
+
+impl std::error::Error for FormatError {}
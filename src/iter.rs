@@ -0,0 +1,183 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Iterator adapter that formats every number of the inner iterator to a String. Created by `ScaledIteratorExt::scaled`.
+#[derive(Clone, Debug)]
+pub struct ScaledIter<'a, I>
+{
+    formatter: &'a Formatter,
+    inner:     I,
+}
+
+
+/// # Summary
+/// Iterator adapter that wraps every number of the inner iterator to be formatted lazily when displayed. Created by `ScaledIteratorExt::scaled_display`.
+#[derive(Clone, Debug)]
+pub struct ScaledDisplayIter<'a, I>
+{
+    formatter: &'a Formatter,
+    inner:     I,
+}
+
+
+/// # Summary
+/// Extension trait to format the numbers of any iterator on the fly, without an intermediate collection.
+pub trait ScaledIteratorExt: Iterator + Sized
+{
+    /// # Summary
+    /// Formats every number with `Formatter::format`.
+    ///
+    /// # Arguments
+    /// - `f`: the formatter to format with
+    ///
+    /// # Returns
+    /// - iterator over the formatted numbers
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::ScaledIteratorExt;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!([1.0, 1234.5, -0.5].iter().copied().scaled(&f).collect::<Vec<String>>().join(", "), "1,000, 1,234 k, -500,0 m");
+    /// assert_eq!((1_u32..=3).scaled(&f).rev().collect::<Vec<String>>(), vec!["3,000", "2,000", "1,000"]);
+    /// assert_eq!((0_u32..10).scaled(&f).len(), 10); // size hint passes through
+    /// ```
+    fn scaled(self, f: &Formatter) -> ScaledIter<'_, Self>;
+
+
+    /// # Summary
+    /// Wraps every number with `Formatter::display`, so it is formatted only when displayed.
+    ///
+    /// # Arguments
+    /// - `f`: the formatter to format with
+    ///
+    /// # Returns
+    /// - iterator over the displayable numbers
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::ScaledIteratorExt;
+    /// use std::fmt::Write;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut s: String = String::new();
+    /// for x in [1.0, 1234.5, -0.5].into_iter().scaled_display(&f)
+    /// {
+    ///     write!(s, "[{x:>9}]").unwrap();
+    /// }
+    /// assert_eq!(s, "[    1,000][  1,234 k][ -500,0 m]");
+    /// ```
+    fn scaled_display(self, f: &Formatter) -> ScaledDisplayIter<'_, Self>;
+}
+
+
+impl<I> ScaledIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Into<f64>,
+{
+    fn scaled(self, f: &Formatter) -> ScaledIter<'_, Self>
+    {
+        return ScaledIter { formatter: f, inner: self };
+    }
+
+
+    fn scaled_display(self, f: &Formatter) -> ScaledDisplayIter<'_, Self>
+    {
+        return ScaledDisplayIter { formatter: f, inner: self };
+    }
+}
+
+
+impl<I> Iterator for ScaledIter<'_, I>
+where
+    I: Iterator,
+    I::Item: Into<f64>,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        return self.inner.next().map(|x| self.formatter.format(x.into()));
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<I> DoubleEndedIterator for ScaledIter<'_, I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Into<f64>,
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        return self.inner.next_back().map(|x| self.formatter.format(x.into()));
+    }
+}
+
+
+impl<I> ExactSizeIterator for ScaledIter<'_, I>
+where
+    I: ExactSizeIterator,
+    I::Item: Into<f64>,
+{
+}
+
+
+impl<I> std::iter::FusedIterator for ScaledIter<'_, I>
+where
+    I: std::iter::FusedIterator,
+    I::Item: Into<f64>,
+{
+}
+
+
+impl<'a, I> Iterator for ScaledDisplayIter<'a, I>
+where
+    I: Iterator,
+    I::Item: Into<f64>,
+{
+    type Item = Scaled<'a>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        return self.inner.next().map(|x| self.formatter.display(x));
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        return self.inner.size_hint();
+    }
+}
+
+
+impl<I> DoubleEndedIterator for ScaledDisplayIter<'_, I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Into<f64>,
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        return self.inner.next_back().map(|x| self.formatter.display(x));
+    }
+}
+
+
+impl<I> ExactSizeIterator for ScaledDisplayIter<'_, I>
+where
+    I: ExactSizeIterator,
+    I::Item: Into<f64>,
+{
+}
+
+
+impl<I> std::iter::FusedIterator for ScaledDisplayIter<'_, I>
+where
+    I: std::iter::FusedIterator,
+    I::Item: Into<f64>,
+{
+}
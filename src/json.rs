@@ -0,0 +1,98 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a `serde_json::Number` according to what it holds: u64 and i64 exactly like `Formatter::format_int`, so integers beyond 2^(53) keep all their digits, and f64 like `Formatter::format`.
+    ///
+    /// # Arguments
+    /// - `n`: the JSON number to format
+    ///
+    /// # Returns
+    /// - formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let document: serde_json::Value = serde_json::from_str(r#"{"bytes": 9007199254740993, "delta": -42069, "ratio": 0.25}"#).unwrap();
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(17));
+    /// assert_eq!(f.format_json_number(document["bytes"].as_number().unwrap()), "9.007.199.254.740.993,0"); // 2^53 + 1, exact
+    /// assert_eq!(f.format(document["bytes"].as_f64().unwrap()), "9.007.199.254.740.992,0"); // f64 is off by one
+    /// assert_eq!(f.format_json_number(document["delta"].as_number().unwrap()), "-42.069,000000000000");
+    /// assert_eq!(f.format_json_number(document["ratio"].as_number().unwrap()), "0,25000000000000000");
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_json_number(&serde_json::Number::from(u64::MAX)), "18,45 E");
+    /// assert_eq!(f.format_json_number(&serde_json::Number::from(i64::MIN)), "-9,223 E");
+    /// ```
+    pub fn format_json_number(&self, n: &serde_json::Number) -> String
+    {
+        if let Some(x) = n.as_u64()
+        {
+            return self.format_int(x);
+        }
+        if let Some(x) = n.as_i64()
+        {
+            return self.format_int(x);
+        }
+        return self.format(n.as_f64().expect("JSON number is neither u64 nor i64 nor f64."));
+    }
+
+
+    /// # Summary
+    /// Parses a number like `Formatter::parse` into a `serde_json::Number`, which stays an integer if the parsed number is integral and fits into u64 or i64. Integers are parsed exactly like `Formatter::parse_u64` and `Formatter::parse_i64`, everything else like `Formatter::parse`.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted number
+    ///
+    /// # Returns
+    /// - the JSON number
+    /// - or `ParseError` describing what and where the problem is, `ParseErrorKind::OutOfRange` for infinity and `ParseErrorKind::NoDigits` for not a number, which JSON cannot represent
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_json_number("1,5 k"), Ok(serde_json::Number::from(1500_u64))); // integral, so integer
+    /// assert_eq!(f.parse_json_number("-42,07 k"), Ok(serde_json::Number::from(-42070_i64)));
+    /// assert_eq!(f.parse_json_number("9,007199254740993 P"), Ok(serde_json::Number::from(9007199254740993_u64))); // exact beyond 2^53
+    /// assert_eq!(f.parse_json_number("1,5"), Ok(serde_json::Number::from_f64(1.5).unwrap()));
+    /// assert_eq!(f.parse_json_number("20 E"), Ok(serde_json::Number::from_f64(2e19).unwrap())); // beyond u64
+    /// assert_eq!(f.parse_json_number("∞"), Err(ParseError { field: None, kind: ParseErrorKind::OutOfRange, position: 0 }));
+    /// assert_eq!(f.parse_json_number("NaN"), Err(ParseError { field: None, kind: ParseErrorKind::NoDigits, position: 0 }));
+    /// assert!(f.parse_json_number("12 parsecs").is_err());
+    /// ```
+    ///
+    /// ```
+    /// // round trip through JSON
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(17));
+    /// let n: serde_json::Number = f.parse_json_number("9.007.199.254.740.993,0").unwrap();
+    /// assert_eq!(serde_json::to_string(&n).unwrap(), "9007199254740993");
+    /// assert_eq!(f.format_json_number(&n), "9.007.199.254.740.993,0");
+    /// ```
+    pub fn parse_json_number(&self, s: &str) -> Result<serde_json::Number, ParseError>
+    {
+        let x: f64; // number that is not an integer fitting into u64 or i64
+
+
+        if let Ok(int) = self.parse_u64(s)
+        {
+            return Ok(serde_json::Number::from(int));
+        }
+        if let Ok(int) = self.parse_i64(s)
+        {
+            return Ok(serde_json::Number::from(int));
+        }
+
+        x = self.parse(s)?;
+        return serde_json::Number::from_f64(x).ok_or(ParseError {
+            field: None,
+            kind: if x.is_nan() { ParseErrorKind::NoDigits } else { ParseErrorKind::OutOfRange },
+            position: 0,
+        });
+    }
+}
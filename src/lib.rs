@@ -1,10 +1,182 @@
 // Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod auto_precision;
+#[cfg(feature = "num-bigint")]
+mod big;
+pub mod buffer;
+pub use buffer::*;
+pub mod builder;
+pub use builder::*;
+pub mod cache;
+pub use cache::*;
+pub mod cjk;
+pub use cjk::*;
+#[cfg(feature = "clap")]
+pub mod cli;
+#[cfg(feature = "clap")]
+pub use cli::*;
+mod column;
+pub mod compiled;
+pub use compiled::*;
+pub mod convert;
+pub use convert::*;
+#[cfg(feature = "num-complex")]
+mod complex;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+mod delta;
+pub mod dms;
+pub use dms::*;
+/// # Summary
+/// Derives `Display` for a struct with named numeric fields, every field formatted as a line "name: value" or filled into a template set with `#[scaled(fmt = "...")]`. Formatter options are set with `#[scaled(...)]` on the struct as defaults and on fields to override them: `sig`, `mag`, `decimals`, `scaling`, `sign`, `sep`, and `unit`, named like the options of `scale!`, with scaling and sign as strings. Fields are left out with `#[scaled(skip)]`. Unknown options, values, and template placeholders fail to compile.
+///
+/// # Examples
+/// ```
+/// #[derive(scaler::ScaledDisplay)]
+/// #[scaled(sig = 3)]
+/// struct Transfer
+/// {
+///     #[scaled(scaling = "binary", unit = "B")]
+///     bytes:    u64,
+///     #[scaled(decimals = 1, scaling = "none", unit = "s")]
+///     duration: f64,
+///     #[scaled(skip)]
+///     id:       u32,
+///     retries:  u8,
+/// }
+///
+/// let t: Transfer = Transfer { bytes: 1_536_000, duration: 12.345, id: 7, retries: 2 };
+/// assert_eq!(t.to_string(), "bytes: 1,47 MiB\nduration: 12,3 s\nretries: 2,00");
+/// ```
+///
+/// ```
+/// #[derive(scaler::ScaledDisplay)]
+/// #[scaled(fmt = "{read} of {total} ({ratio:>5})", scaling = "binary", unit = "B")]
+/// struct Progress
+/// {
+///     read:  u64,
+///     total: u64,
+///     #[scaled(scaling = "none", sig = 2, unit = "")]
+///     ratio: f64,
+/// }
+///
+/// assert_eq!(Progress { read: 512, total: 2048, ratio: 0.25 }.to_string(), "512,0 B of 2,000 KiB ( 0,25)"); // width and alignment apply to the formatted field
+/// ```
+#[cfg(feature = "derive")]
+pub use scaler_derive::ScaledDisplay;
+pub mod display;
+pub use display::*;
+#[cfg(feature = "defmt")]
+mod embedded;
+pub mod env;
+pub use env::*;
+mod exact;
+pub mod fields;
+pub use fields::*;
+pub mod finite;
+pub use finite::*;
 mod format;
-// mod from_str;
+mod fitting;
+mod fixed;
+mod fraction;
+#[cfg(feature = "num-traits")]
+mod generic;
+pub mod from_str;
+pub use from_str::*;
+pub mod global;
+pub use global::*;
+pub mod group;
+pub use group::*;
+mod hexfloat;
+pub mod idle_game;
+pub use idle_game::*;
+pub mod int;
+pub use int::*;
+pub mod iter;
+pub use iter::*;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "macros")]
+mod macros;
+mod magnitude;
+mod markup;
+pub mod number;
+pub use number::*;
 pub mod options;
 pub use options::*;
+pub mod overrides;
+pub use overrides::*;
+#[cfg(feature = "rayon")]
+mod parallel;
+pub mod parse_iter;
+pub use parse_iter::*;
+pub mod parser;
+pub use parser::*;
+pub mod parts;
+pub use parts::*;
+mod percent;
+pub mod picture;
+pub use picture::*;
+pub mod prelude;
+pub mod presets;
+pub use presets::*;
+#[cfg(feature = "uom")]
+pub mod quantity;
+#[cfg(feature = "uom")]
+pub use quantity::*;
+mod radix;
+#[cfg(feature = "num-rational")]
+mod ratio;
+mod range;
+mod rate;
+mod rational;
+pub mod roman;
+pub use roman::*;
 pub mod round;
 pub use round::*;
+mod rust_literal;
+pub mod scaled_value;
+pub use scaled_value::*;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "fast-format")]
+mod shortest;
+mod slice;
+#[cfg(feature = "smallstr")]
+mod small;
+#[cfg(feature = "smallstr")]
+pub use smallstr::SmallString;
+mod sortable;
+pub mod spec;
+pub use spec::*;
+pub mod strategy;
+pub use strategy::*;
+#[cfg(feature = "tracing")]
+pub mod telemetry;
+#[cfg(feature = "tracing")]
+pub use telemetry::*;
+pub mod template;
+pub use template::*;
+mod threshold;
+use threshold::Threshold;
+mod text;
+pub mod ticks;
+pub use ticks::*;
+pub mod to_scaled;
+pub use to_scaled::*;
+mod uncertainty;
+mod width_hint;
+#[cfg(feature = "words")]
+mod words;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "wasm")]
+pub use wasm::*;
+pub mod write;
+pub use write::*;
+use text::Text;
 
 
 /// # Summary
@@ -12,32 +184,1121 @@ pub use round::*;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Formatter
 {
-    decimal_separator: String,
-    group_separator:   String,
-    rounding:          Rounding,
-    scaling:           Scaling,
-    sign:              Sign,
-    trailing_zeros:    bool,
+    affix_prefix:           Text,
+    affix_suffix:           Text,
+    align:                  Align,
+    auto_precision_max:     u8, // digits format_auto_precision may add
+    cjk:                    CjkOptions, // financial anti-fraud characters and spelled minus of CJK positional numerals
+    clamp:                  (Option<Threshold>, Option<Threshold>), // minimum and maximum numbers are clamped to before formatting
+    clamp_markers:          (Text, Text), // after numbers clamped to the minimum and to the maximum
+    common_scale:           CommonScale,
+    complex_notation:       (ComplexNotation, Text), // notation and imaginary unit of complex numbers
+    decimal_separator:      Text,
+    delta:                  (DeltaParts, u8), // parts of changes and decimal places of their percentage
+    display_ceiling:        Option<Threshold>, // absolute displayed value above which numbers are displayed as the ceiling
+    display_floor:          Option<Threshold>, // absolute displayed value below which numbers other than 0 are displayed as the floor
+    dms_symbols:            (Text, Text, Text), // after degrees, minutes, and seconds of angles
+    exact_max_digits:       usize, // fraction digits format_exact displays before cutting off, 0 for all
+    fallback_rounding:      Option<Rounding>, // rounding of scientific notation and the fallback to it, none for the main rounding
+    fill:                   char,
+    fill_mode:              Fill, // whether to pad with the fill character or figure spaces
+    fraction:               FractionOptions, // Unicode vulgar fraction glyphs and mixed numbers instead of improper fractions
+    group_separator:        Text,
+    hexfloat:               HexfloatOptions, // uppercase and all 13 fraction digits of hexadecimal floats
+    locale:                 Option<Locale>, // locale of compact suffixes, none for English
+    machine_readable:       bool, // whether cosmetic options are overridden so str::parse::<f64> reads the output
+    min_integer_digits:     usize,
+    non_finite_policy:      NonFinitePolicy, // whether infinity and not a number are formatted or rejected
+    none_string:            Text, // placeholder for missing numbers
+    overflow_marker:        char,
+    percent_of:             PercentOfOptions, // whether 0 of 0 is displayed as placeholder and whether percentages of a whole are clamped to [0; 100]
+    plural_rule:            PluralRule, // chooses between singular and plural of the unit
+    prefix_override:        Option<i16>, // lower bound magnitude of unit prefix, or exponent of scientific notation if not a lower bound, to use regardless of magnitude, set internally for shared scaling
+    radix:                  RadixOptions, // group size, whether to prefix like "0x", and minimum number of digits of integers in other bases
+    range_separator:        (Text, bool), // separator between the ends of a range and whether to swap reversed ranges
+    rate_base:              RateBase,
+    reserve_prefix_width:   bool, // whether to pad the unit prefix to the widest one of the table
+    roman:                  RomanOptions, // lowercase, additive notation, and notation above 3999 of Roman numerals
+    rounding:               Rounding,
+    rust_literal_suffix:    bool, // whether Rust literals get a type suffix like "_f64"
+    scaling:                Scaling,
+    show_original:          (ShowOriginal, bool), // whether and how to append the original value and whether even if the rounded value is identical
+    sign:                   Sign,
+    sign_placement:         SignPlacement,
+    sign_position:          SignPosition,
+    sortable_digits:        (usize, usize),
+    special_strings:        (Text, Text), // infinity and not a number
+    suppress_unit_mantissa: bool, // whether a mantissa of 1 is left out before the power in scientific notation
+    threshold_markers:      (Text, Text), // before numbers displayed as floor or ceiling, if less and if greater than it
+    trailing_zeros:         bool,
+    uncertainty_notation:   (UncertaintyNotation, Text), // notation of uncertainties and plus-minus sign
+    unit:                   Text,
+    unit_plural:            Text, // plural of the unit, empty if the same as the singular
+    width:                  usize,
+    words:                  WordsOptions, // fractional part, hyphenation, and "and" after hundreds of numbers in words
+    zero_epsilon:           Threshold, // absolute value below which numbers are formatted as exactly 0
+    zero_exponent:          bool, // whether 0 keeps its exponent in scientific notation
+    zero_pad:               usize,
+    zero_pad_grouped:       bool,
 }
 
 
 impl Formatter
 {
     /// # Summary
-    /// Constructs default Formatter with only sign when negative, decimal scaling, rounding to 4 significant digits, "." as thousand separator, "," as decimal separator, and trailing zeros enabled.
+    /// Constructs default Formatter with only sign when negative, leading sign before affixes, decimal scaling, rounding to 4 significant digits, "." as thousand separator, "," as decimal separator, no affixes, trailing zeros enabled, no unit and no plural of it, no minimum integer digits, no padding, columns aligned with spaces, unit prefixes only as wide as they are, no zero padding, no locale with English compact suffixes, common scale by maximum magnitude, at most 6 digits added for automatic precision, CJK positional numerals with ordinary characters and "−" as minus, complex numbers as "a + bi", changes as "+a (+b %)" with 1 decimal place, no display floor or ceiling with "< " and "> " as their markers, no clamping and no markers of clamped numbers, angles with "°", "′", and "″", exact decimal expansions in full, scientific notation rounded like everything else, fractions as mixed numbers in ASCII, hexadecimal floats in lowercase without trailing zeros, "—" as placeholder for missing numbers, "#" as overflow marker, 0 of 0 as 0 % and percentages of a whole unclamped, "∞" and "NaN" as special strings that infinity and not a number are formatted as rather than rejected, uncertainties as "(a ± b)", ranges as "a – b" with thin spaces in their given order, rates per second, Roman numerals in uppercase subtractive notation up to 3999, Rust literals with type suffix, numbers in words with hyphens and fractional parts digit by digit, integers in other bases in groups of 4 digits without prefix, 0 without exponent and no numbers snapped to it, a mantissa of 1 before powers, output for humans rather than machines without the original value, and 9 integer and 4 fraction digits for sortable output. For common recipes, start from the presets `Formatter::bytes`, `Formatter::currency`, `Formatter::machine`, `Formatter::percent`, `Formatter::plain`, or `Formatter::si` instead.
     ///
     /// # Returns
     /// - Formatter
-    pub fn new() -> Self
+    ///
+    /// # Examples
+    /// ```
+    /// static F: scaler::Formatter = scaler::Formatter::new() // constant, constructed at compile time
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(3));
+    /// assert_eq!(F.format(1234.5), "1,23 k");
+    /// ```
+    pub const fn new() -> Self
+    {
+        return Self {
+            affix_prefix:           Text::Static(""),
+            affix_suffix:           Text::Static(""),
+            align:                  Align::Right,
+            auto_precision_max:     6,
+            cjk:                    CjkOptions::new(),
+            clamp:                  (None, None),
+            clamp_markers:          (Text::Static(""), Text::Static("")),
+            common_scale:           CommonScale::Maximum,
+            complex_notation:       (ComplexNotation::Cartesian, Text::Static("i")),
+            decimal_separator:      Text::Static(","),
+            delta:                  (DeltaParts::AbsoluteAndPercent, 1),
+            display_ceiling:        None,
+            display_floor:          None,
+            dms_symbols:            (Text::Static("°"), Text::Static("′"), Text::Static("″")),
+            exact_max_digits:       0,
+            fallback_rounding:      None,
+            fill:                   ' ',
+            fill_mode:              Fill::Character,
+            fraction:               FractionOptions::new(),
+            group_separator:        Text::Static("."),
+            hexfloat:               HexfloatOptions::new(),
+            locale:                 None,
+            machine_readable:       false,
+            min_integer_digits:     0,
+            non_finite_policy:      NonFinitePolicy::Format,
+            none_string:            Text::Static("—"),
+            overflow_marker:        '#',
+            percent_of:             PercentOfOptions::new(),
+            plural_rule:            PluralRule::English,
+            prefix_override:        None,
+            radix:                  RadixOptions::new(),
+            range_separator:        (Text::Static("\u{2009}–\u{2009}"), false),
+            rate_base:              RateBase::PerSecond,
+            reserve_prefix_width:   false,
+            roman:                  RomanOptions::new(),
+            rounding:               Rounding::SignificantDigits(4),
+            rust_literal_suffix:    true,
+            scaling:                Scaling::Decimal(true),
+            show_original:          (ShowOriginal::Off, false),
+            sign:                   Sign::OnlyMinus,
+            sign_placement:         SignPlacement::Leading,
+            sign_position:          SignPosition::BeforeAffix,
+            sortable_digits:        (9, 4),
+            special_strings:        (Text::Static("∞"), Text::Static("NaN")),
+            suppress_unit_mantissa: false,
+            threshold_markers:      (Text::Static("< "), Text::Static("> ")),
+            trailing_zeros:         true,
+            uncertainty_notation:   (UncertaintyNotation::PlusMinus, Text::Static("±")),
+            unit:                   Text::Static(""),
+            unit_plural:            Text::Static(""),
+            width:                  0,
+            words:                  WordsOptions::new(),
+            zero_epsilon:           Threshold(0.0),
+            zero_exponent:          false,
+            zero_pad:               0,
+            zero_pad_grouped:       false,
+        };
+    }
+
+
+    /// # Summary
+    /// Sets the affixes, literal text put before and after the number, for example a currency symbol. The affixes are not affected by scaling, rounding, or separators.
+    ///
+    /// # Arguments
+    /// - `prefix`: text put before the number, empty for none
+    /// - `suffix`: text put after the number and its unit prefix, empty for none
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_affixes("$", "");
+    /// assert_eq!(f.format(1234.5), "$1.234,50");
+    /// assert_eq!(f.format(-5), "-$5,00");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_affixes("", " €");
+    /// assert_eq!(f.format(1234.5), "1,234 k €");
+    /// assert_eq!(f.format(-5), "-5,000 €");
+    /// ```
+    pub fn set_affixes(mut self, prefix: &str, suffix: &str) -> Self
+    {
+        set_text(&mut self.affix_prefix, prefix);
+        set_text(&mut self.affix_suffix, suffix);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the affixes like `Formatter::set_affixes`, but in constant context, so a formatter with affixes can be a `static`. The text is stored without allocation.
+    ///
+    /// # Arguments
+    /// - `prefix`: text put before the number, empty for none
+    /// - `suffix`: text put after the number and its unit prefix, empty for none
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static F: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_affixes_const("", " €");
+    /// assert_eq!(F.format(1234.5), "1,234 k €");
+    /// ```
+    pub const fn set_affixes_const(mut self, prefix: &'static str, suffix: &'static str) -> Self
+    {
+        replace_text(&mut self.affix_prefix, prefix);
+        replace_text(&mut self.affix_suffix, suffix);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how many digits `Formatter::format_auto_precision` may add to the configured precision to distinguish numbers, 6 by default. The digits are significant digits or decimal places, depending on the configured rounding.
+    ///
+    /// # Arguments
+    /// - `max`: maximum number of digits to add, 0 for the configured precision only
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_auto_precision_max(1);
+    /// assert_eq!(f.format_auto_precision(&[10.01, 10.02]), ["10,01", "10,02"]);
+    /// assert_eq!(f.format_auto_precision(&[10.001, 10.002]), ["10,001", "10,002"]);
+    /// assert_eq!(f.format_auto_precision(&[10.0001, 10.0002]), ["10,000", "10,000"]); // would need 2 digits
+    /// ```
+    pub const fn set_auto_precision_max(mut self, max: u8) -> Self
+    {
+        self.auto_precision_max = max;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_cjk` writes Chinese and Japanese positional numerals. By default with ordinary characters and "−" before negative numbers.
+    ///
+    /// # Arguments
+    /// - `options`: whether to use financial anti-fraud characters and whether to write "マイナス" instead of "−" before negative numbers
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{CjkOptions, CjkStyle};
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_cjk(CjkOptions { financial: true, spelled_minus: true });
+    /// assert_eq!(f.format_cjk(12010, CjkStyle::FullKanji), "壱萬弐仟壱拾");
+    /// assert_eq!(f.format_cjk(-12010, CjkStyle::MixedArabic), "マイナス1萬2010");
+    /// ```
+    pub const fn set_cjk(mut self, options: CjkOptions) -> Self
+    {
+        self.cjk = options;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the range numbers are clamped to before rounding and scaling, for gauges and progress displays that must not show glitched sensor readings or overshooting percentages. Numbers below the minimum are formatted as the minimum, numbers above the maximum as the maximum, with the markers set with `Formatter::set_clamp_markers` after them. Unlike the display floor and ceiling, the bounds are signed and compared to the number as it is, before the multiplication by 100 of `Scaling::Percent`. Infinity is clamped to the respective bound if there is one, not a number is never clamped. Numbers at a bound are not clamped and get no marker. Clamped numbers are still subject to the display floor and ceiling, the original value set with `Formatter::set_show_original` is the number before clamping. Applies to f64, exact types like decimals are always displayed as they are. Output for machines is never clamped. No clamping by default. A minimum above the maximum is rejected by `FormatterBuilder::build`, set directly, the maximum wins. A bound of not a number clamps nothing.
+    ///
+    /// # Arguments
+    /// - `min`: the smallest number displayed, or none for no minimum
+    /// - `max`: the largest number displayed, or none for no maximum
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_clamp(Some(0.0), Some(1.0))
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_scaling(scaler::Scaling::Percent(true));
+    /// assert_eq!(f.format(0.42), "42 %");
+    /// assert_eq!(f.format(1.07), "100 %"); // overshoot
+    /// assert_eq!(f.format(-0.01), "0 %");
+    /// assert_eq!(f.format(f64::NAN), "NaN");
+    /// ```
+    pub const fn set_clamp(mut self, min: Option<f64>, max: Option<f64>) -> Self
+    {
+        self.clamp = (
+            match min
+            {
+                Some(min) => Some(Threshold(min)),
+                None => None,
+            },
+            match max
+            {
+                Some(max) => Some(Threshold(max)),
+                None => None,
+            },
+        );
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the markers after numbers clamped with `Formatter::set_clamp`, to tell readers the true number is beyond the displayed one, for example "▼" and "▲" or "<" and ">". By default none.
+    ///
+    /// # Arguments
+    /// - `below`: after numbers below the minimum, displayed as the minimum
+    /// - `above`: after numbers above the maximum, displayed as the maximum
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_clamp(Some(-40.0), Some(125.0))
+    ///     .set_clamp_markers(" ▼", " ▲")
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_unit("°C");
+    /// assert_eq!(f.format(21.5), "21,50 °C");
+    /// assert_eq!(f.format(3000.0), "125,0 °C ▲");
+    /// assert_eq!(f.format(-273.15), "-40,00 °C ▼");
+    /// ```
+    pub fn set_clamp_markers(mut self, below: &str, above: &str) -> Self
+    {
+        set_text(&mut self.clamp_markers.0, below);
+        set_text(&mut self.clamp_markers.1, above);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_common_scale` chooses the one unit prefix shared by all numbers.
+    ///
+    /// # Arguments
+    /// - `common_scale`: new common scale policy
+    ///     - `Maximum`
+    ///         - Uses the unit prefix of the number with the largest magnitude.
+    ///     - `Median`
+    ///         - Uses the unit prefix of the number with the median magnitude, single outliers do not dominate.
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(2))
+    ///     .set_trailing_zeros(false);
+    /// assert_eq!(f.format_common_scale(&[950.0, 1_200.0, 2_000_000.0]), (vec!["0,00095 M".to_string(), "0,0012 M".to_string(), "2 M".to_string()], "M"));
+    /// let f: scaler::Formatter = f.set_common_scale(scaler::CommonScale::Median);
+    /// assert_eq!(f.format_common_scale(&[950.0, 1_200.0, 2_000_000.0]), (vec!["0,95 k".to_string(), "1,2 k".to_string(), "2.000 k".to_string()], "k"));
+    /// ```
+    pub const fn set_common_scale(mut self, common_scale: CommonScale) -> Self
+    {
+        self.common_scale = common_scale;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_complex` displays complex numbers, behind the feature `num-complex`. Both parts go through the same scaling, rounding, and separators. In cartesian notation the sign between the parts is derived from the imaginary part, the imaginary unit directly follows its digits, before any unit prefix or unit. In polar notation the magnitude is formatted like any number, the angle in radians without scaling, affixes, or unit.
+    ///
+    /// # Arguments
+    /// - `notation`: cartesian, cartesian leaving out parts that are 0, or polar
+    /// - `imaginary_unit`: symbol of the imaginary unit, for example "i" or "j"
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "num-complex")]
+    /// # {
+    /// use num_complex::Complex64;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_complex(&Complex64::new(3.0, -4.5)), "3,000 - 4,500i");
+    /// assert_eq!(f.format_complex(&Complex64::new(0.0, 4.5)), "0,000 + 4,500i");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_complex_notation(scaler::ComplexNotation::CartesianCollapsed, "j");
+    /// assert_eq!(f.format_complex(&Complex64::new(3.0, -4.5)), "3,000 - 4,500j");
+    /// assert_eq!(f.format_complex(&Complex64::new(0.0, 4.5)), "4,500j");
+    /// assert_eq!(f.format_complex(&Complex64::new(0.0, -4.5)), "-4,500j");
+    /// assert_eq!(f.format_complex(&Complex64::new(3.0, 0.0)), "3,000");
+    /// assert_eq!(f.format_complex(&Complex64::new(0.0, 0.0)), "0,000");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_complex_notation(scaler::ComplexNotation::Polar, "i");
+    /// assert_eq!(f.format_complex(&Complex64::new(3.0, 4.5)), "5,408 ∠ 0,9828 rad");
+    /// assert_eq!(f.format_complex(&Complex64::new(-3.0, 0.0)), "3,000 ∠ 3,142 rad");
+    /// # }
+    /// ```
+    pub fn set_complex_notation(mut self, notation: ComplexNotation, imaginary_unit: &str) -> Self
+    {
+        self.complex_notation.0 = notation;
+        set_text(&mut self.complex_notation.1, imaginary_unit);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the notation of complex numbers like `Formatter::set_complex_notation`, but in constant context, so such a formatter can be a `static`.
+    ///
+    /// # Arguments
+    /// - `notation`: how to display complex numbers
+    /// - `imaginary_unit`: symbol of the imaginary unit, for example "i" or "j"
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "num-complex")]
+    /// # {
+    /// static F: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_complex_notation_const(scaler::ComplexNotation::Cartesian, "j");
+    /// assert_eq!(F.format_complex(&num_complex::Complex64::new(3.0, -4.5)), "3,000 - 4,500j");
+    /// # }
+    /// ```
+    pub const fn set_complex_notation_const(mut self, notation: ComplexNotation, imaginary_unit: &'static str) -> Self
+    {
+        self.complex_notation.0 = notation;
+        replace_text(&mut self.complex_notation.1, imaginary_unit);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets what `Formatter::format_delta` displays of a change.
+    ///
+    /// # Arguments
+    /// - `parts`: new parts to display
+    ///     - `Absolute`
+    ///         - Only the absolute change.
+    ///     - `AbsoluteAndPercent`
+    ///         - Absolute change and relative change in percent in parentheses.
+    ///     - `Percent`
+    ///         - Only the relative change in percent.
+    /// - `percent_decimals`: number of decimal places of the relative change
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_delta(36000, 37200), "+1,200 k (+3,3 %)");
+    /// let f: scaler::Formatter = f.set_delta(scaler::DeltaParts::Absolute, 1);
+    /// assert_eq!(f.format_delta(36000, 37200), "+1,200 k");
+    /// let f: scaler::Formatter = f.set_delta(scaler::DeltaParts::Percent, 3);
+    /// assert_eq!(f.format_delta(36000, 37200), "+3,333 %");
+    /// ```
+    pub const fn set_delta(mut self, parts: DeltaParts, percent_decimals: u8) -> Self
+    {
+        self.delta = (parts, percent_decimals);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the largest absolute value displayed as it is, for dashboards that prefer "> 999 T" over a number too large to read. Numbers beyond it are displayed as the ceiling with the greater marker set with `Formatter::set_threshold_markers` before it, formatted like any other number, so with sign, affixes, and unit. Numbers are compared as displayed, after the multiplication by 100 of `Scaling::Percent`, but before rounding. The marker states the true relation of the number to the displayed bound, so negative numbers beyond the ceiling are less than it: "< -999 T". Infinity and not a number are displayed as they are. Applies to f64, exact types like decimals are always displayed as they are. Output for machines is never clamped. No ceiling by default.
+    ///
+    /// # Arguments
+    /// - `ceiling`: the largest absolute value displayed as it is, its sign is ignored, or none for no ceiling
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_display_ceiling(Some(999e12))
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(3));
+    /// assert_eq!(f.format(999e12), "999 T");
+    /// assert_eq!(f.format(1e18), "> 999 T");
+    /// assert_eq!(f.format(-1e18), "< -999 T");
+    /// assert_eq!(f.format(f64::INFINITY), "∞");
+    /// ```
+    pub const fn set_display_ceiling(mut self, ceiling: Option<f64>) -> Self
+    {
+        self.display_ceiling = match ceiling
+        {
+            Some(ceiling) => Some(Threshold(ceiling.abs())),
+            None => None,
+        };
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the smallest absolute value other than 0 displayed as it is, for dashboards that prefer "< 0,01 %" over "0,00 %". Numbers closer to 0 are displayed as the floor with the less marker set with `Formatter::set_threshold_markers` before it, formatted like any other number, so with sign, affixes, and unit. Exact 0 is displayed as it is. Numbers are compared as displayed, after the multiplication by 100 of `Scaling::Percent`, but before rounding. The marker states the true relation of the number to the displayed bound, so negative numbers below the floor are greater than it: "> -0,01 %". Applies to f64, exact types like decimals are always displayed as they are. Output for machines is never clamped. No floor by default. If the floor is above the ceiling, the floor wins.
+    ///
+    /// # Arguments
+    /// - `floor`: the smallest absolute value displayed as it is, its sign is ignored, or none for no floor
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_display_floor(Some(0.01))
+    ///     .set_rounding(scaler::Rounding::Magnitude(-4))
+    ///     .set_scaling(scaler::Scaling::Percent(true));
+    /// assert_eq!(f.format(0.000000097), "< 0,01 %"); // 0,0000097 %
+    /// assert_eq!(f.format(-0.000000097), "> -0,01 %");
+    /// assert_eq!(f.format(0.0002), "0,02 %");
+    /// assert_eq!(f.format(0), "0,00 %");
+    /// ```
+    pub const fn set_display_floor(mut self, floor: Option<f64>) -> Self
+    {
+        self.display_floor = match floor
+        {
+            Some(floor) => Some(Threshold(floor.abs())),
+            None => None,
+        };
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the symbols `Formatter::format_dms` puts after degrees, minutes, and seconds of angles. By default "°", "′", and "″", for ASCII output for example "d", "m", and "s".
+    ///
+    /// # Arguments
+    /// - `degrees`: after the degrees
+    /// - `minutes`: after the minutes
+    /// - `seconds`: after the seconds
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_rounding(scaler::Rounding::Magnitude(0));
+    /// assert_eq!(f.format_dms(48.8584, None), "48° 51′ 30″");
+    /// let f: scaler::Formatter = f.set_dms_symbols("d", "m", "s");
+    /// assert_eq!(f.format_dms(48.8584, None), "48d 51m 30s");
+    /// let f: scaler::Formatter = f.set_dms_symbols("°", "'", "\"");
+    /// assert_eq!(f.format_dms(48.8584, None), "48° 51' 30\"");
+    /// ```
+    pub fn set_dms_symbols(mut self, degrees: &str, minutes: &str, seconds: &str) -> Self
+    {
+        set_text(&mut self.dms_symbols.0, degrees);
+        set_text(&mut self.dms_symbols.1, minutes);
+        set_text(&mut self.dms_symbols.2, seconds);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how many fraction digits `Formatter::format_exact` displays at most. Longer expansions, like the 1074 fraction digits of the smallest subnormal, are cut off without rounding and marked with "…". All digits by default.
+    ///
+    /// # Arguments
+    /// - `max`: maximum number of fraction digits, 0 for all of them
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_exact_max_digits(20);
+    /// assert_eq!(f.format_exact(0.1), "0,10000000000000000555…");
+    /// assert_eq!(f.format_exact(0.5), "0,5"); // short enough
+    /// assert_eq!(f.format_exact(f64::MAX).len(), 309 + 102); // integer digits are never cut off
+    /// ```
+    pub const fn set_exact_max_digits(mut self, max: usize) -> Self
+    {
+        self.exact_max_digits = max;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets a rounding mode of its own for numbers displayed in scientific notation: those beyond the unit prefixes of `Scaling::Binary` and `Scaling::Decimal` that fall back to it, and all numbers of `Scaling::Scientific`. The audience of 10^(34) rarely needs as many digits as the one of 42,07 k. Numbers are rounded once, with this rounding instead of the main one, so 9,96 * 10^(35) rounded to 2 significant digits is "1,0 * 10^(36)". Whether a number falls back is decided with the main rounding. None by default, which rounds scientific notation like every other number.
+    ///
+    /// # Arguments
+    /// - `rounding`: rounding mode of scientific notation, or none for the main rounding
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(4))
+    ///     .set_fallback_rounding(Some(scaler::Rounding::SignificantDigits(2)));
+    /// assert_eq!(f.format(1e36), "1,0 * 10^(36)");
+    /// assert_eq!(f.format(9.96e35), "1,0 * 10^(36)");
+    /// assert_eq!(f.format(1.2345e-40), "1,2 * 10^(-40)");
+    /// assert_eq!(f.format(42069), "42,07 k"); // unit prefix, main rounding
+    /// assert_eq!(f.clone().set_scaling(scaler::Scaling::Binary(true)).format(2_f64.powi(100) * 1.5), "1,5 * 2^(100)");
+    /// assert_eq!(f.clone().set_scaling(scaler::Scaling::Scientific).format(42069), "4,2 * 10^(4)");
+    /// assert_eq!(f.clone().set_fallback_rounding(None).format(1e36), "1,000 * 10^(36)");
+    /// ```
+    pub const fn set_fallback_rounding(mut self, rounding: Option<Rounding>) -> Self
+    {
+        self.fallback_rounding = rounding;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets what numbers are padded with, for `Formatter::set_width` and `Formatter::format_aligned`. Proportional fonts with tabular figures make digits equally wide, but not spaces, so columns padded with spaces look ragged. U+2007 FIGURE SPACE is exactly as wide as a digit and U+2008 PUNCTUATION SPACE as a separator, so columns padded with them align glyph for glyph.
+    ///
+    /// # Arguments
+    /// - `fill`: what to pad with
+    ///     - `Character`: The fill character set with `Formatter::set_width`, and spaces in `Formatter::format_aligned`.
+    ///     - `FigureSpace`: U+2007 for both, and in `Formatter::format_aligned` U+2008 wherever another row has a group or decimal separator.
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1))
+    ///     .set_fill_mode(scaler::Fill::FigureSpace);
+    /// assert_eq!(f.format_aligned(&[1234.5, 7.0, 42.25]), vec![
+    ///     "1.234,5",
+    ///     "\u{2007}\u{2008}\u{2007}\u{2007}7,0",
+    ///     "\u{2007}\u{2008}\u{2007}42,2",
+    /// ]);
+    /// assert_eq!(f.set_width(6, scaler::Align::Right, ' ').format(7), "\u{2007}\u{2007}\u{2007}7,0");
+    /// ```
+    pub const fn set_fill_mode(mut self, fill: Fill) -> Self
+    {
+        self.fill_mode = fill;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_fraction` displays fractions. By default as mixed numbers in ASCII, like "2 3/8".
+    ///
+    /// # Arguments
+    /// - `options`: whether to use Unicode vulgar fraction glyphs and whether to display mixed numbers instead of improper fractions
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{Formatter, FractionOptions};
+    /// let f: Formatter = Formatter::new();
+    /// assert_eq!(f.format_fraction(1.75, 8), "1 3/4");
+    /// let f: Formatter = f.set_fraction(FractionOptions { glyphs: true, mixed: true });
+    /// assert_eq!(f.format_fraction(1.75, 8), "1¾");
+    /// let f: Formatter = f.set_fraction(FractionOptions { glyphs: false, mixed: false });
+    /// assert_eq!(f.format_fraction(1.75, 8), "7/4");
+    /// let f: Formatter = f.set_fraction(FractionOptions { glyphs: true, mixed: false });
+    /// assert_eq!(f.format_fraction(1.75, 8), "7/4");
+    /// assert_eq!(f.format_fraction(0.75, 8), "¾");
+    /// ```
+    pub const fn set_fraction(mut self, options: FractionOptions) -> Self
+    {
+        self.fraction = options;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_hexfloat` displays hexadecimal floats. By default in lowercase and without trailing zeros, like `%a` in C.
+    ///
+    /// # Arguments
+    /// - `options`: whether to display all 13 hexadecimal fraction digits and whether to display in uppercase
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{Formatter, HexfloatOptions};
+    /// let f: Formatter = Formatter::new();
+    /// assert_eq!(f.format_hexfloat(1.5), "0x1.8p+0");
+    /// let f: Formatter = f.set_hexfloat(HexfloatOptions { all_digits: false, uppercase: true });
+    /// assert_eq!(f.format_hexfloat(1.5), "0X1.8P+0");
+    /// let f: Formatter = f.set_hexfloat(HexfloatOptions { all_digits: true, uppercase: false });
+    /// assert_eq!(f.format_hexfloat(1.5), "0x1.8000000000000p+0");
+    /// ```
+    pub const fn set_hexfloat(mut self, options: HexfloatOptions) -> Self
+    {
+        self.hexfloat = options;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the locale: its group and decimal separators and its compact suffixes for `Scaling::CompactLocalized`, like "Mio." and "Mrd." in German, "万" and "億" in steps of 10^4 in Japanese, or "लाख" and "करोड़" in steps of 10^2 in Hindi. Below the first step, numbers are not scaled, beyond the last they get the largest suffix. The suffix is chosen after rounding, so 999.999 rounded to 2 significant digits is "1,0 Mio." in German. The separators can be changed afterwards with `Formatter::set_separators`. Without locale, compact suffixes are the English ones, "K", "M", "B", and "T".
+    ///
+    /// # Arguments
+    /// - `locale`: new locale
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{Formatter, Locale, Rounding, Scaling};
+    /// let f: Formatter = Formatter::new().set_rounding(Rounding::SignificantDigits(2)).set_scaling(Scaling::CompactLocalized);
+    /// assert_eq!(f.format(1234567), "1,2M");
+    /// assert_eq!(f.clone().set_locale(Locale::DeDe).format(1234567), "1,2 Mio.");
+    /// assert_eq!(f.clone().set_locale(Locale::DeDe).format(999999), "1,0 Mio."); // suffix chosen after rounding
+    /// assert_eq!(f.clone().set_locale(Locale::DeDe).format(3.4e9), "3,4 Mrd.");
+    /// assert_eq!(f.clone().set_locale(Locale::DeDe).format(12345), "12.000"); // below the first step
+    /// assert_eq!(f.clone().set_locale(Locale::EnUs).format(1234567), "1.2M");
+    /// assert_eq!(f.clone().set_locale(Locale::JaJp).format(12000), "1.2万");
+    /// assert_eq!(f.clone().set_locale(Locale::JaJp).format(123456789), "1.2億");
+    /// assert_eq!(f.set_locale(Locale::HiIn).format(120000), "1.2 लाख");
+    /// ```
+    pub const fn set_locale(mut self, locale: Locale) -> Self
+    {
+        self.locale = Some(locale);
+        replace_text(&mut self.group_separator, locale.separators().0);
+        replace_text(&mut self.decimal_separator, locale.separators().1);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether the output is meant for machines, like CSV files read back by programs, and guaranteed to parse with `str::parse::<f64>`. This overrides the cosmetic options: "." as decimal separator, no group separator, no unit prefixes, scientific notation as "1.234e3", "inf" and "NaN" as special strings, the sign leading and never a space, and no affixes, unit, minimum integer digits, padding, or zero padding. Rounding, trailing zeros, and whether to display "+" are still honoured, so the precision chosen for humans carries over. Without scientific notation, numbers are displayed with all their integer digits. The placeholder of `Formatter::format_opt` is kept, as a missing number has no f64 to parse to.
+    ///
+    /// # Arguments
+    /// - `machine_readable`: whether or not to override the cosmetic options
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_affixes("$", "")
+    ///     .set_unit("USD")
+    ///     .set_width(12, scaler::Align::Right, ' ')
+    ///     .set_machine_readable(true);
+    /// assert_eq!(f.format(1234.5), "1234");
+    /// assert_eq!(f.format(-0.0123456), "-0.01235");
+    /// assert_eq!(f.format(1e20), "100000000000000000000");
+    /// assert_eq!(f.format(f64::NEG_INFINITY), "-inf");
+    /// assert_eq!(f.format(f64::NAN), "NaN");
+    /// assert_eq!(f.format(-0.0123456).parse::<f64>(), Ok(-0.01235));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_sign(scaler::Sign::Always)
+    ///     .set_machine_readable(true);
+    /// assert_eq!(f.format(1234.5), "+1.234e3");
+    /// assert_eq!(f.format(-1e-300), "-1.000e-300");
+    /// assert_eq!(f.format(1e-300).parse::<f64>(), Ok(1e-300));
+    /// assert_eq!(f.format(0), "+0.000");
+    /// ```
+    pub const fn set_machine_readable(mut self, machine_readable: bool) -> Self
+    {
+        self.machine_readable = machine_readable;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the minimum number of integer digits. If the integer part of the number has fewer digits, it is filled up with leading zeros after rounding and scaling. The zeros are put after the sign and are separated into groups like the other integer digits. In scientific notation, the minimum applies to the mantissa.
+    ///
+    /// # Arguments
+    /// - `min_integer_digits`: minimum number of digits before the decimal separator, 0 for no minimum
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_min_integer_digits(3);
+    /// assert_eq!(f.format(7), "007,000");
+    /// assert_eq!(f.format(-7), "-007,000");
+    /// assert_eq!(f.format(1234.5), "001,234 k");
+    /// assert_eq!(f.format(123456), "123,5 k"); // already long enough
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_min_integer_digits(3);
+    /// assert_eq!(f.format(0.5), "000,5000");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_min_integer_digits(7);
+    /// assert_eq!(f.format(7), "0.000.007");
+    /// assert_eq!(f.format(-1234), "-0.001.234");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_min_integer_digits(3);
+    /// assert_eq!(f.format(1234), "001,234 * 10^(3)");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_min_integer_digits(0);
+    /// assert_eq!(f.format(7), "7,000");
+    /// ```
+    pub const fn set_min_integer_digits(mut self, min_integer_digits: usize) -> Self
+    {
+        self.min_integer_digits = min_integer_digits;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether infinity and not a number are formatted or rejected, for pipelines where a non-finite number reaching the formatter is a bug to catch rather than a "NaN" to embed silently in a report. Rejected numbers are rejected before any other processing, so before the clamp range or the placeholder of missing numbers apply. `Formatter::try_format_finite` always rejects them and reports which one it was, so call sites that can switch should prefer it, this option makes the existing call sites strict. Formatted by default.
+    ///
+    /// # Arguments
+    /// - `policy`: new non-finite policy
+    ///     - `Error`
+    ///         - Rejects infinity and not a number. The output methods returning a String like `Formatter::format` panic with the `NonFiniteError`, the fallible ones report it instead: `Formatter::format_into` and `Display` with `std::fmt::Error`, `Formatter::format_to_slice` with `FormatToSliceError::NonFinite`, and `WriteScaled` with `std::io::ErrorKind::InvalidInput`.
+    ///     - `Format`
+    ///         - Displays infinity and not a number as the special strings set with `Formatter::set_special_strings`.
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```should_panic
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_non_finite_policy(scaler::NonFinitePolicy::Error);
+    /// assert_eq!(f.format(1.5), "1,500");
+    /// f.format(f64::NAN); // panics
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_non_finite_policy(scaler::NonFinitePolicy::Error);
+    /// let mut s: String = String::new();
+    /// assert_eq!(f.format_into(f64::NAN, &mut s), Err(std::fmt::Error));
+    /// assert_eq!(s, ""); // nothing written
+    /// ```
+    pub const fn set_non_finite_policy(mut self, policy: NonFinitePolicy) -> Self
+    {
+        self.non_finite_policy = policy;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the placeholder `Formatter::format_opt` and `Formatter::display_opt` display instead of a missing number. It is displayed as it is, without sign, affixes, separators, or unit, but padded to the configured width like any number.
+    ///
+    /// # Arguments
+    /// - `none_string`: new placeholder, may be empty
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_none_string("n/a");
+    /// assert_eq!(f.format_opt(None::<f64>), "n/a");
+    /// assert_eq!(f.format_opt(Some(1)), "1,000");
+    /// ```
+    pub fn set_none_string(mut self, none_string: &str) -> Self
+    {
+        set_text(&mut self.none_string, none_string);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the placeholder for missing numbers like `Formatter::set_none_string`, but in constant context, so such a formatter can be a `static`.
+    ///
+    /// # Arguments
+    /// - `none_string`: new placeholder, may be empty
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static F: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_none_string_const("n/a");
+    /// assert_eq!(F.format_opt(None::<f64>), "n/a");
+    /// ```
+    pub const fn set_none_string_const(mut self, none_string: &'static str) -> Self
+    {
+        replace_text(&mut self.none_string, none_string);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the character `Formatter::format_fitting` fills the budget with if the number does not fit in any way.
+    ///
+    /// # Arguments
+    /// - `overflow_marker`: new overflow marker
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_overflow_marker('?');
+    /// assert_eq!(f.format_fitting(-1e40, 4), "????");
+    /// ```
+    pub const fn set_overflow_marker(mut self, overflow_marker: char) -> Self
+    {
+        self.overflow_marker = overflow_marker;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_percent_of` handles the edge cases of dividing a part by a whole. By default, 0 of 0 is displayed as 0 % and percentages are not clamped.
+    ///
+    /// # Arguments
+    /// - `options`: whether to clamp percentages to [0 %; 100 %] and whether to display 0 of 0 as the placeholder set with `Formatter::set_none_string` instead of 0 %
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1));
+    /// assert_eq!(f.format_percent_of(0.0, 0.0), "0,0 %");
+    /// assert_eq!(f.format_percent_of(12.0, 10.0), "120,0 %");
+    /// let f: scaler::Formatter = f.set_percent_of(scaler::PercentOfOptions { clamp: true, zero_of_zero_placeholder: true });
+    /// assert_eq!(f.format_percent_of(0.0, 0.0), "—");
+    /// assert_eq!(f.format_percent_of(12.0, 10.0), "100,0 %");
+    /// ```
+    pub const fn set_percent_of(mut self, options: PercentOfOptions) -> Self
+    {
+        self.percent_of = options;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the plural rule choosing between singular and plural of the unit set with `Formatter::set_unit_plural`, English by default. A custom rule receives the absolute displayed number, rounded and scaled, and may implement the CLDR rules of any language. Only `PluralCategory::One` selects the singular, as there are only two forms of the unit so far. Closures without captured variables are accepted. Formatters compare their custom rules by name.
+    ///
+    /// # Arguments
+    /// - `rule`: built-in rule or custom rule with its name
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{PluralCategory, PluralRule};
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1))
+    ///     .set_unit_plural("octet", "octets")
+    ///     .set_plural_rule(PluralRule::Custom { name: "fr", rule: |x| if x.abs() < 2.0 { PluralCategory::One } else { PluralCategory::Other } });
+    /// assert_eq!(f.format(0), "0,0 octet");
+    /// assert_eq!(f.format(1.5), "1,5 octet");
+    /// assert_eq!(f.format(2), "2,0 octets");
+    /// assert_ne!(f, f.clone().set_plural_rule(PluralRule::English));
+    /// ```
+    pub const fn set_plural_rule(mut self, rule: PluralRule) -> Self
+    {
+        self.plural_rule = rule;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_radix` and `Formatter::format_radix_signed` display integers in other bases. The digits are grouped with the configured group separator, "_" is common for code, "DEAD_BEEF". By default, in groups of 4 digits without prefix and without zero padding.
+    ///
+    /// # Arguments
+    /// - `options`: group size, whether to prefix the digits with "0b", "0o", or "0x" like Rust literals, and minimum number of digits
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{Formatter, Radix, RadixOptions};
+    /// let f: Formatter = Formatter::new()
+    ///     .set_separators("_", ".")
+    ///     .set_radix(RadixOptions { group_size: 4, min_digits: 8, prefix: true });
+    /// assert_eq!(f.format_radix(0xBEEF, Radix::Hex), "0x0000_BEEF");
+    /// assert_eq!(f.format_radix(0x1_DEAD_BEEF, Radix::HexLower), "0x1_dead_beef"); // more digits than the minimum
+    /// let f: Formatter = f.set_radix(RadixOptions { group_size: 8, ..RadixOptions::new() });
+    /// assert_eq!(f.format_radix(0b1010_0101_1111, Radix::Binary), "1010_01011111");
+    /// let f: Formatter = f.set_radix(RadixOptions { group_size: 0, ..RadixOptions::new() });
+    /// assert_eq!(f.format_radix(0o755, Radix::Octal), "755");
+    /// ```
+    pub const fn set_radix(mut self, options: RadixOptions) -> Self
+    {
+        self.radix = options;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_range` joins the ends of a range.
+    ///
+    /// # Arguments
+    /// - `separator`: text between the ends, including any whitespace
+    /// - `swap_reversed`: whether to swap the ends of a range whose start is greater than its end, or to keep their order
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_range_separator(" to ", true);
+    /// assert_eq!(f.format_range(3400, 1200), "1,200 to 3,400 k");
+    /// let f: scaler::Formatter = f.set_range_separator("...", false);
+    /// assert_eq!(f.format_range(3400, 1200), "3,400...1,200 k");
+    /// ```
+    pub fn set_range_separator(mut self, separator: &str, swap_reversed: bool) -> Self
+    {
+        set_text(&mut self.range_separator.0, separator);
+        self.range_separator.1 = swap_reversed;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how ranges are joined like `Formatter::set_range_separator`, but in constant context, so such a formatter can be a `static`.
+    ///
+    /// # Arguments
+    /// - `separator`: text between the ends, including any whitespace
+    /// - `swap_reversed`: whether to swap the ends of a range whose start is greater than its end
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static F: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_range_separator_const("-", true);
+    /// assert_eq!(F.format_range(3400, 1200), "1,200-3,400 k");
+    /// ```
+    pub const fn set_range_separator_const(mut self, separator: &'static str, swap_reversed: bool) -> Self
+    {
+        replace_text(&mut self.range_separator.0, separator);
+        self.range_separator.1 = swap_reversed;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the time base `Formatter::format_rate` displays rates in.
+    ///
+    /// # Arguments
+    /// - `rate_base`: new time base
+    ///     - `Auto`
+    ///         - Per second, per minute if less than 1 per second, per hour if less than 1 per minute, so tiny rates do not need many decimal places.
+    ///     - `PerHour`
+    ///         - Per hour, "/h".
+    ///     - `PerMinute`
+    ///         - Per minute, "/min".
+    ///     - `PerSecond`
+    ///         - Per second, "/s".
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rate_base(scaler::RateBase::PerMinute);
+    /// assert_eq!(f.format_rate(120.0, std::time::Duration::from_secs(60)), "120,0 /min");
+    /// ```
+    pub const fn set_rate_base(mut self, rate_base: RateBase) -> Self
+    {
+        self.rate_base = rate_base;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether to reserve room for the widest unit prefix of the table in every number, so in a column of numbers with and without unit prefix the digits do not drift. The unit prefix and the whitespace before it are padded with spaces to the widest unit prefix, counted in characters, so "µ" is as wide as "k". A unit comes after the reserved room. Scientific notation, including the fallback beyond the unit prefixes, and not a number are not padded. Has no effect without scaling by unit prefixes.
+    ///
+    /// # Arguments
+    /// - `reserve`: whether to pad every unit prefix to the widest one
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_reserve_prefix_width(true);
+    /// assert_eq!(f.format(999), "999,0  ");
+    /// assert_eq!(f.format(1234), "1,234 k");
+    /// assert_eq!(f.format(0.000001), "1,000 µ");
+    /// assert_eq!(f.format(1e40), "1,000 * 10^(40)"); // scientific notation fallback, not padded
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_unit("B")
+    ///     .set_reserve_prefix_width(true);
+    /// assert_eq!(f.format(512), "512,0   B");
+    /// assert_eq!(f.format(1536), "1,500 KiB");
+    /// ```
+    pub const fn set_reserve_prefix_width(mut self, reserve: bool) -> Self
+    {
+        self.reserve_prefix_width = reserve;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_roman` writes Roman numerals. By default in uppercase and subtractive notation, and only up to 3999.
+    ///
+    /// # Arguments
+    /// - `options`: whether to write additive notation like "IIII" for 4, how to write numbers above 3999, and whether to write in lowercase
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{Formatter, RomanLarge, RomanOptions};
+    /// let f: Formatter = Formatter::new()
+    ///     .set_roman(RomanOptions { additive: true, ..RomanOptions::new() });
+    /// assert_eq!(f.format_roman(4), Ok("IIII".to_string()));
+    /// let f: Formatter = f.set_roman(RomanOptions { additive: false, large: RomanLarge::Overline, lowercase: true });
+    /// assert_eq!(f.format_roman(4), Ok("iv".to_string()));
+    /// assert_eq!(f.format_roman(5001), Ok("v\u{0305}i".to_string()));
+    /// ```
+    pub const fn set_roman(mut self, options: RomanOptions) -> Self
     {
-        return Self {
-            decimal_separator: ",".to_string(),
-            group_separator:   ".".to_string(),
-            rounding:          Rounding::SignificantDigits(4),
-            scaling:           Scaling::Decimal(true),
-            sign:              Sign::OnlyMinus,
-            trailing_zeros:    true,
-        };
+        self.roman = options;
+        return self;
     }
 
 
@@ -49,6 +1310,7 @@ impl Formatter
     ///     - `Magnitude`
     ///         - Round to digit at magnitude 10^m.
     ///         - Contains m.
+    ///         - Scaled by a unit prefix or exponent of 10^p, the mantissa has p - m decimal places, at least 0, so the last displayed digit is the one at 10^m or a coarser one. For example, 0.789 with m = -2 is "790 m", as milli has no digit at 10^(-2).
     ///     - `SignificantDigits`
     ///         - Round to n significant numbers.
     ///         - Contains n.
@@ -162,13 +1424,39 @@ impl Formatter
     /// assert_eq!(f.format(0.789), "0,78900");
     /// assert_eq!(f.format(42069), "42.069");
     /// ```
-    pub fn set_rounding(mut self, rounding: Rounding) -> Self
+    pub const fn set_rounding(mut self, rounding: Rounding) -> Self
     {
         self.rounding = rounding;
         return self;
     }
 
 
+    /// # Summary
+    /// Sets whether `Formatter::format_rust_literal` and `Formatter::format_rust_literal_int` append the type suffix, like "_f64" or "_u64". Without it, float literals still keep ".0" or their exponent, so they do not turn into integers, but integer literals take the type the surrounding code infers. With the suffix by default.
+    ///
+    /// # Arguments
+    /// - `suffix`: whether to append the type suffix
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rust_literal_suffix(false);
+    /// assert_eq!(f.format_rust_literal(2.0), "2.0");
+    /// assert_eq!(f.format_rust_literal_int(42069_u64), "42_069");
+    /// let f: scaler::Formatter = f.set_rust_literal_suffix(true);
+    /// assert_eq!(f.format_rust_literal(2.0), "2.0_f64");
+    /// assert_eq!(f.format_rust_literal_int(42069_u64), "42_069_u64");
+    /// ```
+    pub const fn set_rust_literal_suffix(mut self, suffix: bool) -> Self
+    {
+        self.rust_literal_suffix = suffix;
+        return self;
+    }
+
+
     /// # Summary
     /// Sets the scaling mode.
     ///
@@ -182,9 +1470,18 @@ impl Formatter
     ///         - Scales by factor 10^(3) = 1000.
     ///         - If no prefix for that magnitude defined: Fallback to scientific notation.
     ///         - Contains whether or not to put space between number and unit prefix.
+    ///     - `Exponential`
+    ///         - Scales by powers of any base, with exponents that are multiples of the step.
+    ///         - Without unit prefix table: always exponent notation.
+    ///         - With unit prefix table: like `Binary` and `Decimal`, fallback to exponent notation.
+    ///         - Significant digits are those of the mantissa.
     ///     - `None`
     ///         - no scaling
     ///         - no fallback to scientific notation
+    ///     - `Percent`
+    ///         - Multiplies ratios by 100 exactly and displays them with "%" instead of a unit prefix.
+    ///         - Rounding to a magnitude refers to the ratio, so 10^(-3) displays 1 decimal place of the percentage.
+    ///         - Contains whether or not to put space between number and "%".
     ///     - `Scientific`
     ///         - always scientific notation
     ///
@@ -312,6 +1609,25 @@ impl Formatter
     /// assert_eq!(f.format(1000), "1,000k");
     /// ```
     ///
+    /// ## Exponential
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///    .set_scaling(scaler::Scaling::Exponential { base: 16, step: 1, prefixes: None });
+    /// assert_eq!(f.format(0.5), "8,000 * 16^(-1)");
+    /// assert_eq!(f.format(1), "1,000 * 16^(0)");
+    /// assert_eq!(f.format(255), "15,94 * 16^(1)");
+    /// assert_eq!(f.format(16384), "4,000 * 16^(3)");
+    /// assert_eq!(f.format(65535), "1,000 * 16^(4)"); // mantissa rounded up to the next power
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///    .set_scaling(scaler::Scaling::Exponential { base: 10, step: 3, prefixes: None }); // engineering notation
+    /// assert_eq!(f.format(-0.0012), "-1,200 * 10^(-3)");
+    /// assert_eq!(f.format(-12345), "-12,35 * 10^(3)");
+    /// ```
+    ///
     /// ## None
     ///
     /// ```
@@ -338,6 +1654,19 @@ impl Formatter
     /// assert_eq!(f.format(-1e10), "-10.000.000.000");
     /// ```
     ///
+    /// ## Percent
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///    .set_scaling(scaler::Scaling::Percent(true));
+    /// assert_eq!(f.format(0.07), "7,000 %");
+    /// assert_eq!(f.format(0.125), "12,50 %");
+    /// assert_eq!(f.format(-1.5), "-150,0 %");
+    /// assert_eq!(f.format(f64::INFINITY), "∞ %");
+    /// assert_eq!(f.clone().set_rounding(scaler::Rounding::Magnitude(-3)).format(0.12345), "12,3 %");
+    /// assert_eq!(f.set_scaling(scaler::Scaling::Percent(false)).format(1), "100,0%");
+    /// ```
+    ///
     /// ## Scientific
     ///
     /// ```
@@ -359,9 +1688,9 @@ impl Formatter
     /// assert_eq!(f.format(-10), "-1,000 * 10^(1)");
     /// assert_eq!(f.format(-100), "-1,000 * 10^(2)");
     /// ```
-    pub fn set_scaling(mut self, scaling: Scaling) -> Self
+    pub const fn set_scaling(mut self, scaling: Scaling) -> Self
     {
-        self.scaling = scaling;
+        std::mem::forget(std::mem::replace(&mut self.scaling, scaling)); // cannot be dropped in constant context, only leaks memory if a strategy had been set at runtime before
         return self;
     }
 
@@ -417,50 +1746,105 @@ impl Formatter
     pub fn set_separators(mut self, group_separator: &str, decimal_separator: &str) -> Self
     {
         #[cfg(feature = "warn_about_problematic_separators")] // warn if feature is enabled
-        {
-            if decimal_separator == ""
-            {
-                log::warn!("Decimal separator is empty. This may lead to ambiguous formatting.");
-            }
-            else if group_separator == decimal_separator
-            {
-                log::warn!(
-                    "Group separator \"{}\" and decimal separator \"{}\" are the same. This may lead to ambiguous formatting.",
-                    group_separator,
-                    decimal_separator
-                );
-            }
-            else if group_separator.contains("0")
-                || group_separator.contains("1")
-                || group_separator.contains("2")
-                || group_separator.contains("3")
-                || group_separator.contains("4")
-                || group_separator.contains("5")
-                || group_separator.contains("6")
-                || group_separator.contains("7")
-                || group_separator.contains("8")
-                || group_separator.contains("9")
-            {
-                log::warn!("Group separator \"{}\" contains a digit. This may lead to ambiguous formatting.", group_separator);
-            }
-            else if decimal_separator.contains("0")
-                || decimal_separator.contains("1")
-                || decimal_separator.contains("2")
-                || decimal_separator.contains("3")
-                || decimal_separator.contains("4")
-                || decimal_separator.contains("5")
-                || decimal_separator.contains("6")
-                || decimal_separator.contains("7")
-                || decimal_separator.contains("8")
-                || decimal_separator.contains("9")
-            {
-                log::warn!("Decimal separator \"{}\" contains a digit. This may lead to ambiguous formatting.", decimal_separator);
-            }
-        }
+        warn_about_separators(group_separator, decimal_separator);
+
+        set_text(&mut self.group_separator, group_separator);
+        set_text(&mut self.decimal_separator, decimal_separator);
+
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the separators like `Formatter::set_separators`, but in constant context, so a formatter with other separators can be a `static`, shared by all threads and constructed only once. As there is no logging in constant context, problematic separators are not warned about.
+    ///
+    /// # Arguments
+    /// - `group_separator`: separator between groups of 3 digits in the integer part
+    /// - `decimal_separator`: separator between integer and fraction part
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static F: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_separators_const(",", ".")
+    ///     .set_unit_const("€");
+    /// let threads: Vec<std::thread::JoinHandle<String>> = [1234.5, -0.125].into_iter().map(|x| std::thread::spawn(move || F.format(x))).collect();
+    /// assert_eq!(threads.into_iter().map(|thread| thread.join().unwrap()).collect::<Vec<String>>(), ["1,234.50 €", "-0.12 €"]);
+    /// ```
+    pub const fn set_separators_const(mut self, group_separator: &'static str, decimal_separator: &'static str) -> Self
+    {
+        replace_text(&mut self.group_separator, group_separator);
+        replace_text(&mut self.decimal_separator, decimal_separator);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the separators like `Formatter::set_separators`, but borrows them for the whole program instead of copying them, so it never allocates. Unlike `Formatter::set_separators_const`, problematic separators are still warned about.
+    ///
+    /// # Arguments
+    /// - `group_separator`: separator between groups of 3 digits in the integer part
+    /// - `decimal_separator`: separator between integer and fraction part
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_scaling(scaler::Scaling::None);
+    /// assert_eq!(f.clone().set_separators_static("\u{202F}", ".").format(1234567.5), "1\u{202F}234\u{202F}568");
+    /// assert_eq!(f.clone().set_separators_static("\u{202F}", "."), f.set_separators("\u{202F}", ".")); // equal to copied separators
+    /// ```
+    pub fn set_separators_static(mut self, group_separator: &'static str, decimal_separator: &'static str) -> Self
+    {
+        #[cfg(feature = "warn_about_problematic_separators")] // warn if feature is enabled
+        warn_about_separators(group_separator, decimal_separator);
 
-        self.group_separator = group_separator.to_string();
-        self.decimal_separator = decimal_separator.to_string();
+        self.group_separator = Text::Static(group_separator);
+        self.decimal_separator = Text::Static(decimal_separator);
+        return self;
+    }
 
+
+    /// # Summary
+    /// Sets whether to append the original value in parentheses, unscaled and unrounded, so reports carry both the readable figure and its exact source, like "42,07 k (42.069)". The original follows everything else, including unit, suffix affix, and a trailing sign, and is signed with "-" if negative, whatever the sign mode. Special values and the exact output methods like `Formatter::format_int` never show it, neither do composite outputs like ranges, changes, or uncertainties. By default, the original is not shown.
+    ///
+    /// # Arguments
+    /// - `show`: whether and how to display the original, `ShowOriginal::Grouped` with the shortest digits that parse back to the same f64 grouped like the formatted number, `ShowOriginal::Raw` as Rust displays it
+    /// - `if_identical`: whether to show the original even if the formatted number displays it without rounding
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_show_original(scaler::ShowOriginal::Grouped, false);
+    /// assert_eq!(f.format(42069), "42,07 k (42.069)");
+    /// assert_eq!(f.format(0.1234567), "123,5 m (0,1234567)");
+    /// assert_eq!(f.format(1500), "1,500 k"); // nothing rounded away
+    /// assert_eq!(f.format(f64::NAN), "NaN");
+    /// assert_eq!(f.clone().set_rounding(scaler::Rounding::SignificantDigits(2)).format(-1234), "-1,2 k (-1.234)");
+    /// assert_eq!(f.set_show_original(scaler::ShowOriginal::Grouped, true).format(1500), "1,500 k (1.500)");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_show_original(scaler::ShowOriginal::Raw, false)
+    ///     .set_sign(scaler::Sign::Always)
+    ///     .set_unit("B");
+    /// assert_eq!(f.format(42069), "+42,07 kB (42069)");
+    /// assert_eq!(f.format(-1234.5), "-1,234 kB (-1234.5)");
+    /// ```
+    pub const fn set_show_original(mut self, show: ShowOriginal, if_identical: bool) -> Self
+    {
+        self.show_original = (show, if_identical);
         return self;
     }
 
@@ -471,7 +1855,9 @@ impl Formatter
     /// # Arguments
     /// - `sign`: new sign mode
     ///     - Always: Always show sign, even when number is positive.
+    ///     - ExceptZero: Show sign when number is positive or negative, but not when it is 0 after rounding.
     ///     - OnlyMinus: Only show sign when number is negative.
+    ///     - SpaceForPositive: Show space instead of plus sign when number is positive, keeps positive and negative numbers aligned.
     ///
     /// # Returns
     /// - modified self
@@ -489,20 +1875,310 @@ impl Formatter
     /// assert_eq!(f.format(std::f64::INFINITY), "+∞");
     /// ```
     ///
-    /// ## OnlyMinus
+    /// ## ExceptZero
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///    .set_sign(scaler::Sign::ExceptZero);
+    /// assert_eq!(f.format(std::f64::NEG_INFINITY), "-∞");
+    /// assert_eq!(f.format(-1), "-1,000");
+    /// assert_eq!(f.format(0), "0,000");
+    /// assert_eq!(f.format(1), "+1,000");
+    /// assert_eq!(f.format(std::f64::INFINITY), "+∞");
+    /// let f: scaler::Formatter = f
+    ///    .set_scaling(scaler::Scaling::None)
+    ///    .set_rounding(scaler::Rounding::Magnitude(-1));
+    /// assert_eq!(f.format(0.04), "0,0"); // 0 after rounding
+    /// assert_eq!(f.format(-0.04), "0,0");
+    /// assert_eq!(f.format(0.06), "+0,1");
+    /// ```
+    ///
+    /// ## OnlyMinus
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///    .set_sign(scaler::Sign::OnlyMinus);
+    /// assert_eq!(f.format(std::f64::NEG_INFINITY), "-∞");
+    /// assert_eq!(f.format(-1), "-1,000");
+    /// assert_eq!(f.format(0), "0,000");
+    /// assert_eq!(f.format(1), "1,000");
+    /// assert_eq!(f.format(std::f64::INFINITY), "∞");
+    /// ```
+    ///
+    /// ## SpaceForPositive
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///    .set_sign(scaler::Sign::SpaceForPositive);
+    /// assert_eq!(f.format(std::f64::NEG_INFINITY), "-∞");
+    /// assert_eq!(f.format(-1), "-1,000");
+    /// assert_eq!(f.format(0), " 0,000");
+    /// assert_eq!(f.format(1), " 1,000");
+    /// assert_eq!(f.format(std::f64::INFINITY), " ∞");
+    /// ```
+    pub const fn set_sign(mut self, sign: Sign) -> Self
+    {
+        self.sign = sign;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the sign placement, whether the sign leads or trails the number. Trailing signs are common in ERP exports and fixed-width bank files.
+    ///
+    /// # Arguments
+    /// - `sign_placement`: new sign placement
+    ///     - Leading: Put sign before number.
+    ///     - Parentheses: Enclose negative numbers in parentheses instead of putting a minus before them, accounting style. "(" goes where the leading sign would be, ")" after unit prefix and suffix affix. Other signs are leading.
+    ///     - Trailing: Put sign after number, unit prefix, and suffix affix. Overrides sign position.
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ## Leading
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_sign_placement(scaler::SignPlacement::Leading);
+    /// assert_eq!(f.format(-1234.5), "-1.234,50");
+    /// assert_eq!(f.format(1234.5), "1.234,50");
+    /// ```
+    ///
+    /// ## Trailing
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_sign_placement(scaler::SignPlacement::Trailing);
+    /// assert_eq!(f.format(-1234.5), "1.234,50-");
+    /// assert_eq!(f.format(1234.5), "1.234,50");
+    /// assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(1234.5), "1.234,50+");
+    /// assert_eq!(f.clone().set_sign(scaler::Sign::SpaceForPositive).format(1234.5), "1.234,50 ");
+    /// assert_eq!(f.clone().set_affixes("$", " USD").format(-5), "$5,00 USD-");
+    /// assert_eq!(f.format(f64::NEG_INFINITY), "∞-");
+    /// ```
+    ///
+    /// ## Parentheses
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_sign_placement(scaler::SignPlacement::Parentheses);
+    /// assert_eq!(f.format(-1234.5), "(1.234,50)");
+    /// assert_eq!(f.format(1234.5), "1.234,50");
+    /// assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(1234.5), "+1.234,50");
+    /// assert_eq!(f.clone().set_affixes("$", " USD").format(-5), "($5,00 USD)");
+    /// assert_eq!(f.set_sign_position(scaler::SignPosition::AfterAffix).set_affixes("$", " USD").format(-5), "$(5,00 USD)");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_sign_placement(scaler::SignPlacement::Trailing);
+    /// assert_eq!(f.format(-42069), "42,07 k-");
+    /// ```
+    pub const fn set_sign_placement(mut self, sign_placement: SignPlacement) -> Self
+    {
+        self.sign_placement = sign_placement;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the sign position relative to the prefix affix. Only makes a difference if a prefix affix is set.
+    ///
+    /// # Arguments
+    /// - `sign_position`: new sign position
+    ///     - AfterAffix: Put sign after prefix affix, immediately before the first digit.
+    ///     - BeforeAffix: Put sign before prefix affix.
+    /// - Only applies to leading signs, trailing sign placement overrides it.
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ## AfterAffix
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_sign_position(scaler::SignPosition::AfterAffix);
+    /// let f_prefix: scaler::Formatter = f.clone().set_affixes("$", "");
+    /// let f_suffix: scaler::Formatter = f.clone().set_affixes("", " $");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::Always).format(-5), "$-5,00");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::Always).format(5), "$+5,00");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::OnlyMinus).format(-5), "$-5,00");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::OnlyMinus).format(5), "$5,00");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::Always).format(-5), "-5,00 $");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::Always).format(5), "+5,00 $");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::OnlyMinus).format(-5), "-5,00 $");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::OnlyMinus).format(5), "5,00 $");
+    /// ```
+    ///
+    /// ## BeforeAffix
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_sign_position(scaler::SignPosition::BeforeAffix);
+    /// let f_prefix: scaler::Formatter = f.clone().set_affixes("$", "");
+    /// let f_suffix: scaler::Formatter = f.clone().set_affixes("", " $");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::Always).format(-5), "-$5,00");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::Always).format(5), "+$5,00");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::OnlyMinus).format(-5), "-$5,00");
+    /// assert_eq!(f_prefix.clone().set_sign(scaler::Sign::OnlyMinus).format(5), "$5,00");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::Always).format(-5), "-5,00 $");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::Always).format(5), "+5,00 $");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::OnlyMinus).format(-5), "-5,00 $");
+    /// assert_eq!(f_suffix.clone().set_sign(scaler::Sign::OnlyMinus).format(5), "5,00 $");
+    /// ```
+    pub const fn set_sign_position(mut self, sign_position: SignPosition) -> Self
+    {
+        self.sign_position = sign_position;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the number of integer and fraction digits `Formatter::format_sortable` pads to. Numbers with more integer digits are clamped to the largest representable number.
+    ///
+    /// # Arguments
+    /// - `int_digits`: number of digits before the decimal separator
+    /// - `fraction_digits`: number of digits after the decimal separator, 0 for no decimal separator
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_sortable_digits(4, 2);
+    /// assert_eq!(f.format_sortable(3.14159), "2+0003.14");
+    /// assert_eq!(f.format_sortable(-3.14159), "1-9996.85");
+    /// ```
+    pub const fn set_sortable_digits(mut self, int_digits: usize, fraction_digits: usize) -> Self
+    {
+        self.sortable_digits = (int_digits, fraction_digits);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the strings displayed instead of infinity and not a number, for example "inf" and "nan" for ASCII-only output or "n/a" for tables. Infinity is still signed, affixed, and followed by the unit like any other number. Parsing accepts the configured strings in addition to the standard ones.
+    ///
+    /// # Arguments
+    /// - `infinity`: string for infinity, without sign
+    /// - `nan`: string for not a number
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_special_strings("inf", "n/a");
+    /// assert_eq!(f.format(f64::INFINITY), "inf");
+    /// assert_eq!(f.format(f64::NEG_INFINITY), "-inf");
+    /// assert_eq!(f.format(f64::NAN), "n/a");
+    /// assert_eq!(f.format(1), "1,000");
+    /// ```
+    pub fn set_special_strings(mut self, infinity: &str, nan: &str) -> Self
+    {
+        set_text(&mut self.special_strings.0, infinity);
+        set_text(&mut self.special_strings.1, nan);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the strings for infinity and not a number like `Formatter::set_special_strings`, but in constant context, so such a formatter can be a `static`.
+    ///
+    /// # Arguments
+    /// - `infinity`: string for infinity, without sign
+    /// - `nan`: string for not a number
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static F: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_special_strings_const("inf", "nan");
+    /// assert_eq!(F.format(f64::NEG_INFINITY), "-inf");
+    /// ```
+    pub const fn set_special_strings_const(mut self, infinity: &'static str, nan: &'static str) -> Self
+    {
+        replace_text(&mut self.special_strings.0, infinity);
+        replace_text(&mut self.special_strings.1, nan);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether a mantissa of 1 is left out in scientific notation, so powers read like labels of a logarithmic axis: "10^(3)" instead of "1,000 * 10^(3)", and "-10^(3)" for -1000. This applies to scientific notation, the fallback of binary and decimal scaling to it, and exponential scaling without unit prefix, whenever the mantissa is 1 after rounding, also if it only rounds to 1 with the configured precision. `Formatter::format_html` displays the power alone as superscript, like "10<sup>3</sup>". Output for machines keeps its mantissa, as "e3" would not parse. Disabled by default.
+    ///
+    /// # Arguments
+    /// - `suppress_unit_mantissa`: whether or not to leave out a mantissa of 1 before the power
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_suppress_unit_mantissa(true);
+    /// assert_eq!(f.format(1000), "10^(3)");
+    /// assert_eq!(f.format(-1000), "-10^(3)");
+    /// assert_eq!(f.format(0.001), "10^(-3)");
+    /// assert_eq!(f.format(1500), "1,500 * 10^(3)");
+    /// assert_eq!(f.format_html(1000), "10<sup>3</sup>");
+    /// assert_eq!(f.clone().set_rounding(scaler::Rounding::SignificantDigits(3)).format(999.99), "10^(3)"); // rounds to 1,00 * 10^(3)
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_suppress_unit_mantissa(true);
+    /// assert_eq!(f.format(2_f64.powi(100)), "2^(100)"); // fallback beyond the unit prefixes
+    /// assert_eq!(f.format(1024), "1,000 Ki"); // unit prefixes keep the mantissa
+    /// ```
+    pub const fn set_suppress_unit_mantissa(mut self, suppress_unit_mantissa: bool) -> Self
+    {
+        self.suppress_unit_mantissa = suppress_unit_mantissa;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the markers before numbers displayed as the floor set with `Formatter::set_display_floor` or the ceiling set with `Formatter::set_display_ceiling`, for example to translate them or to use "≤" instead. By default "< " and "> ".
+    ///
+    /// # Arguments
+    /// - `less`: before numbers less than the displayed bound, positive ones below the floor and negative ones beyond the ceiling
+    /// - `greater`: before numbers greater than the displayed bound, positive ones beyond the ceiling and negative ones below the floor
+    ///
+    /// # Returns
+    /// - modified self
     ///
+    /// # Examples
     /// ```
     /// let f: scaler::Formatter = scaler::Formatter::new()
-    ///    .set_sign(scaler::Sign::OnlyMinus);
-    /// assert_eq!(f.format(std::f64::NEG_INFINITY), "-∞");
-    /// assert_eq!(f.format(-1), "-1,000");
-    /// assert_eq!(f.format(0), "0,000");
-    /// assert_eq!(f.format(1), "1,000");
-    /// assert_eq!(f.format(std::f64::INFINITY), "∞");
+    ///     .set_display_floor(Some(1e-3))
+    ///     .set_display_ceiling(Some(1e3))
+    ///     .set_threshold_markers("unter ", "über ");
+    /// assert_eq!(f.format(1e-6), "unter 1,000 m");
+    /// assert_eq!(f.format(1e6), "über 1,000 k");
     /// ```
-    pub fn set_sign(mut self, sign: Sign) -> Self
+    pub fn set_threshold_markers(mut self, less: &str, greater: &str) -> Self
     {
-        self.sign = sign;
+        set_text(&mut self.threshold_markers.0, less);
+        set_text(&mut self.threshold_markers.1, greater);
         return self;
     }
 
@@ -585,6 +2261,8 @@ impl Formatter
     /// assert_eq!(f.format(1.23), "1,23");
     /// assert_eq!(f.format(1.234), "1,234");
     /// assert_eq!(f.format(1.2345), "1,234");
+    /// assert_eq!(f.format(1000), "1 k");
+    /// assert_eq!(f.format(0.789), "789 m");
     /// ```
     ///
     /// ```
@@ -596,6 +2274,13 @@ impl Formatter
     /// assert_eq!(f.format(1.23), "1,23");
     /// assert_eq!(f.format(1.234), "1,234");
     /// assert_eq!(f.format(1.2345), "1,234");
+    /// assert_eq!(f.format(0.789), "0,789");
+    /// assert_eq!(f.format(1000), "1.000"); // only fraction zeros are trailing zeros
+    /// assert_eq!(f.format(1200), "1.200");
+    /// let f: scaler::Formatter = f.set_separators(",", "."); // trimmed before separators are inserted
+    /// assert_eq!(f.format(1000), "1,000");
+    /// assert_eq!(f.format(12.5), "12.5");
+    /// assert_eq!(f.format(2), "2"); // no dangling decimal separator
     /// ```
     ///
     /// ```
@@ -608,18 +2293,386 @@ impl Formatter
     /// assert_eq!(f.format(1.234), "1,234 * 10^(0)");
     /// assert_eq!(f.format(1.2345), "1,234 * 10^(0)");
     /// ```
-    pub fn set_trailing_zeros(mut self, trailing_zeros: bool) -> Self
+    pub const fn set_trailing_zeros(mut self, trailing_zeros: bool) -> Self
     {
         self.trailing_zeros = trailing_zeros;
         return self;
     }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_uncertainty` displays a value with its uncertainty. In both notations, value and error share one unit prefix and the error determines the precision.
+    ///
+    /// # Arguments
+    /// - `notation`: new uncertainty notation
+    ///     - `Concise`
+    ///         - Error as digits of the last places of the value in parentheses, "1,235(2) k".
+    ///     - `PlusMinus`
+    ///         - Value and error separated by the plus-minus sign, parenthesized if followed by a unit prefix or unit, "(1,235 ± 0,002) k".
+    /// - `plus_minus`: symbol between value and error in plus-minus notation, for example "±" or "+/-"
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_uncertainty_notation(scaler::UncertaintyNotation::PlusMinus, "+/-");
+    /// assert_eq!(f.format_uncertainty(1234.56, 2.3), "(1,235 +/- 0,002) k");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_uncertainty_notation(scaler::UncertaintyNotation::Concise, "±");
+    /// assert_eq!(f.format_uncertainty(1234.56, 2.3), "1,235(2) k");
+    /// ```
+    pub fn set_uncertainty_notation(mut self, notation: UncertaintyNotation, plus_minus: &str) -> Self
+    {
+        self.uncertainty_notation.0 = notation;
+        set_text(&mut self.uncertainty_notation.1, plus_minus);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the notation of uncertainties like `Formatter::set_uncertainty_notation`, but in constant context, so such a formatter can be a `static`.
+    ///
+    /// # Arguments
+    /// - `notation`: how to display a value with its uncertainty
+    /// - `plus_minus`: symbol between value and error in plus-minus notation
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static F: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_uncertainty_notation_const(scaler::UncertaintyNotation::Concise, "±");
+    /// assert_eq!(F.format_uncertainty(9.81, 0.12), "9,81(12)");
+    /// ```
+    pub const fn set_uncertainty_notation_const(mut self, notation: UncertaintyNotation, plus_minus: &'static str) -> Self
+    {
+        self.uncertainty_notation.0 = notation;
+        replace_text(&mut self.uncertainty_notation.1, plus_minus);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the unit put after the unit prefix, for example "B" for bytes or "m" for metres. With decimal or binary scaling it combines with the unit prefix to "kB" or "KiB", whitespace separation applies to both together. Without unit prefix, the unit is separated by whitespace as well, so there is no number directly followed by a unit. The unit is put before the suffix affix. Clears the plural set with `Formatter::set_unit_plural`.
+    ///
+    /// # Arguments
+    /// - `unit`: unit symbol, empty for none
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_unit("B");
+    /// assert_eq!(f.format(12300000), "12,30 MB");
+    /// assert_eq!(f.format(123), "123,0 B");
+    /// assert_eq!(f.format(1e40), "1,000 * 10^(40) B");
+    /// assert_eq!(f.format(f64::NAN), "NaN"); // not a number has no unit
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(false))
+    ///     .set_unit("B")
+    ///     .set_affixes("", "/s");
+    /// assert_eq!(f.format(1536), "1,500KiB/s");
+    /// assert_eq!(f.format(-f64::INFINITY), "-∞B/s");
+    /// ```
+    pub fn set_unit(mut self, unit: &str) -> Self
+    {
+        set_text(&mut self.unit, unit);
+        set_text(&mut self.unit_plural, "");
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the unit like `Formatter::set_unit`, but in constant context, so a formatter with unit can be a `static`.
+    ///
+    /// # Arguments
+    /// - `unit`: unit symbol, empty for none
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static BYTES: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_unit_const("B");
+    /// assert_eq!(BYTES.format(1536), "1,500 KiB");
+    /// ```
+    pub const fn set_unit_const(mut self, unit: &'static str) -> Self
+    {
+        replace_text(&mut self.unit, unit);
+        replace_text(&mut self.unit_plural, "");
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets a unit with distinct singular and plural, for example "byte" and "bytes", so there is no "1 bytes". Which one is displayed, is decided by the plural rule set with `Formatter::set_plural_rule` on the displayed number: rounded and scaled, without unit prefix or exponent. So with the default English rule "1 byte" and "1,000 kbyte" are singular, as they are one byte and one kilobyte, and "1,001 kbyte" or "0 bytes" are plural. Trailing zeros do not count, "1,000 byte" is singular as well. Infinity is plural. Every plural category but `PluralCategory::One` selects the plural. Like the unit set with `Formatter::set_unit` otherwise.
+    ///
+    /// # Arguments
+    /// - `singular`: unit for the singular, empty for none
+    /// - `plural`: unit for every other number
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_trailing_zeros(false)
+    ///     .set_unit_plural("byte", "bytes");
+    /// assert_eq!(f.format(0), "0 bytes");
+    /// assert_eq!(f.format(1), "1 byte");
+    /// assert_eq!(f.format(-1), "-1 byte");
+    /// assert_eq!(f.format(1.0001), "1 byte"); // 1 after rounding
+    /// assert_eq!(f.format(1.001), "1,001 bytes");
+    /// assert_eq!(f.format(2), "2 bytes");
+    /// assert_eq!(f.format(1000), "1 kbyte"); // one kilobyte
+    /// assert_eq!(f.format(1500), "1,5 kbytes");
+    /// assert_eq!(f.format(f64::INFINITY), "∞ bytes");
+    /// let f: scaler::Formatter = f.set_trailing_zeros(true);
+    /// assert_eq!(f.format(1), "1,000 byte"); // trailing zeros do not count
+    /// assert_eq!(f.format(1), f.clone().set_unit_plural("byte", "byte").format(1));
+    /// ```
+    pub fn set_unit_plural(mut self, singular: &str, plural: &str) -> Self
+    {
+        set_text(&mut self.unit, singular);
+        set_text(&mut self.unit_plural, plural);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets a unit with distinct singular and plural like `Formatter::set_unit_plural`, but in constant context, so such a formatter can be a `static`.
+    ///
+    /// # Arguments
+    /// - `singular`: unit for the singular, empty for none
+    /// - `plural`: unit for every other number
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// static REQUESTS: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_unit_plural_const("request", "requests");
+    /// assert_eq!(REQUESTS.format(1), "1 request");
+    /// assert_eq!(REQUESTS.format(20), "20 requests");
+    /// ```
+    pub const fn set_unit_plural_const(mut self, singular: &'static str, plural: &'static str) -> Self
+    {
+        replace_text(&mut self.unit, singular);
+        replace_text(&mut self.unit_plural, plural);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the width to pad the formatted number to, applied as the very last step. Width is counted in characters, not bytes, so "µ" and "∞" count as 1. Numbers longer than the width are returned unpadded, never truncated; `Formatter::unpadded_len` detects that.
+    ///
+    /// # Arguments
+    /// - `width`: minimum number of characters, 0 for no padding
+    /// - `align`: where to put the number within the width
+    ///     - Center: Pad both sides, if uneven one more fill character on the right.
+    ///     - Left: Pad right.
+    ///     - Right: Pad left.
+    /// - `fill`: character to pad with
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(12, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format(1234), "     1,234 k");
+    /// assert_eq!(f.format(0.000001), "     1,000 µ");
+    /// assert_eq!(f.format(f64::NEG_INFINITY), "          -∞");
+    /// assert_eq!(f.format(1e40), "1,000 * 10^(40)"); // too long, not truncated
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(12, scaler::Align::Left, '_');
+    /// assert_eq!(f.format(1234), "1,234 k_____");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_width(10, scaler::Align::Center, '·');
+    /// assert_eq!(f.format(1234), "·1,234 k··");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_separators("\u{202F}", "·") // multi-byte separators
+    ///     .set_width(12, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format(1234567), "   1\u{202F}235\u{202F}000");
+    /// assert_eq!(f.format(1234567).chars().count(), 12);
+    /// ```
+    pub const fn set_width(mut self, width: usize, align: Align, fill: char) -> Self
+    {
+        self.width = width;
+        self.align = align;
+        self.fill = fill;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets how `Formatter::format_words` spells numbers, behind the feature `words`.
+    ///
+    /// # Arguments
+    /// - `options`: how to spell numbers
+    ///     - `and_after_hundreds`: whether to put "and" after hundreds and before the last tens and ones in British style, "one hundred and twenty", "one thousand and one"
+    ///     - `fraction`: how to spell the fractional part
+    ///         - `Digits`
+    ///             - Digit by digit, "point five six".
+    ///         - `Fraction`
+    ///             - As fraction of a power of 10 like on cheques, "and 56/100".
+    ///     - `hyphenate`: whether to join tens and ones with a hyphen, "twenty-one", or a space, "twenty one"
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "words")]
+    /// # {
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_words(121.5), "one hundred twenty-one point five");
+    /// let f: scaler::Formatter = f
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_words(scaler::WordsOptions { and_after_hundreds: true, fraction: scaler::WordsFraction::Fraction, hyphenate: false });
+    /// assert_eq!(f.format_words(121.5), "one hundred and twenty one and 50/100");
+    /// # }
+    /// ```
+    pub const fn set_words(mut self, options: WordsOptions) -> Self
+    {
+        self.words = options;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the absolute value below which numbers are formatted as exactly 0, for results of floating-point computations that should be 0 but come out as numerical noise like -3.2e-17, which would otherwise be displayed as "-32,00 a". Numbers closer to 0 than epsilon become 0 before rounding, scaling, and sign handling, so they are displayed like 0, never as "-0", and `Sign::ExceptZero` leaves them unsigned. Numbers at epsilon are displayed as they are. The comparison is with the number as it is, before the multiplication by 100 of `Scaling::Percent`, and before clamping with `Formatter::set_clamp`. The original value set with `Formatter::set_show_original` is the number before snapping. Applies to f64, exact types like decimals are always displayed as they are. Output for machines is never snapped. 0 by default, which snaps nothing. Epsilon must be finite and not negative, `FormatterBuilder::build` rejects others; set directly, a negative epsilon or not a number snaps nothing and infinity snaps every finite number.
+    ///
+    /// # Arguments
+    /// - `epsilon`: the absolute value below which numbers are formatted as 0
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format(-3.2e-17), "-32,00 a");
+    /// let f: scaler::Formatter = f.set_zero_epsilon(1e-12);
+    /// assert_eq!(f.format(-3.2e-17), "0,000");
+    /// assert_eq!(f.format(1e-12), "1,000 p"); // at epsilon, not snapped
+    /// assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(-3.2e-17), "+0,000");
+    /// ```
+    pub const fn set_zero_epsilon(mut self, epsilon: f64) -> Self
+    {
+        self.zero_epsilon = Threshold(epsilon);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether 0 is displayed with an exponent, "0,000 * 10^(0)", or without, "0,000". 0 has no magnitude, so by default scientific notation and the fallback of binary and decimal scaling to it display 0 like every other scaling does. This applies to every number that is 0 after rounding, including -0 and numbers that underflow to 0 like 1e-400, which are unsigned like in every other scaling. Disabled by default.
+    ///
+    /// # Arguments
+    /// - `zero_exponent`: whether or not to display 0 with an exponent in scientific notation
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific);
+    /// assert_eq!(f.format(0), "0,000");
+    /// assert_eq!(f.format(-0.0), "0,000");
+    /// assert_eq!(f.format(1e-400), "0,000"); // underflows to 0
+    /// assert_eq!(f.format(1), "1,000 * 10^(0)");
+    /// assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(0), "+0,000");
+    /// assert_eq!(f.clone().set_trailing_zeros(false).format(0), "0");
+    /// let f: scaler::Formatter = f.set_zero_exponent(true);
+    /// assert_eq!(f.format(0), "0,000 * 10^(0)");
+    /// assert_eq!(f.format(-0.0), "0,000 * 10^(0)");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2));
+    /// assert_eq!(f.format(0.004), "0,00"); // 0 after rounding, to the hundredth
+    /// assert_eq!(f.format(0.006), "1 * 10^(-2)");
+    /// ```
+    pub const fn set_zero_exponent(mut self, zero_exponent: bool) -> Self
+    {
+        self.zero_exponent = zero_exponent;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the width to pad the formatted number to with zeros, like printf's `%08.2f`. The zeros go between sign and prefix affix on the left and the digits on the right, so they never end up before the sign. Width is counted in characters, not bytes. Numbers longer than the width and infinity or NaN are not zero padded. Applied before padding with `Formatter::set_width`.
+    ///
+    /// # Arguments
+    /// - `width`: minimum number of characters, 0 for no zero padding
+    /// - `grouped`: whether to separate the padding zeros into groups like the other integer digits
+    ///     - If a group separator would exceed the width, the number is padded with as many zeros as fit, so the result can be shorter than the width.
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_zero_pad(8, false);
+    /// assert_eq!(f.format(3.5), "0003,500");
+    /// assert_eq!(f.format(-3.5), "-003,500");
+    /// assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(3.5), "+003,500");
+    /// assert_eq!(f.format(-3500), "-3,500 k"); // already as wide as the width
+    /// assert_eq!(f.format(-1e-6), "-1,000 µ");
+    /// assert_eq!(f.format(f64::NEG_INFINITY), "-∞");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_affixes("$", "");
+    /// assert_eq!(f.clone().set_zero_pad(10, false).format(-1234), "-$0001.234");
+    /// assert_eq!(f.clone().set_zero_pad(11, true).format(-1234), "-$0.001.234");
+    /// assert_eq!(f.clone().set_zero_pad(10, true).format(-1234), "-$001.234"); // next zero would need a separator, too wide
+    /// assert_eq!(f.clone().set_zero_pad(3, true).format(-1234), "-$1.234");
+    /// ```
+    pub const fn set_zero_pad(mut self, width: usize, grouped: bool) -> Self
+    {
+        self.zero_pad = width;
+        self.zero_pad_grouped = grouped;
+        return self;
+    }
 }
 
 
 impl Default for Formatter
 {
     /// # Summary
-    /// Constructs default Formatter with only sign when negative, decimal scaling, rounding to 4 significant digits, "." as thousand separator, and "," as decimal separator.
+    /// Constructs default Formatter with only sign when negative, leading sign before affixes, decimal scaling, rounding to 4 significant digits, "." as thousand separator, "," as decimal separator, no affixes, trailing zeros enabled, no unit and no plural of it, no minimum integer digits, no padding, columns aligned with spaces, unit prefixes only as wide as they are, no zero padding, no locale with English compact suffixes, common scale by maximum magnitude, at most 6 digits added for automatic precision, CJK positional numerals with ordinary characters and "−" as minus, complex numbers as "a + bi", changes as "+a (+b %)" with 1 decimal place, no display floor or ceiling with "< " and "> " as their markers, no clamping and no markers of clamped numbers, angles with "°", "′", and "″", exact decimal expansions in full, scientific notation rounded like everything else, fractions as mixed numbers in ASCII, hexadecimal floats in lowercase without trailing zeros, "—" as placeholder for missing numbers, "#" as overflow marker, 0 of 0 as 0 % and percentages of a whole unclamped, "∞" and "NaN" as special strings that infinity and not a number are formatted as rather than rejected, uncertainties as "(a ± b)", ranges as "a – b" with thin spaces in their given order, rates per second, Roman numerals in uppercase subtractive notation up to 3999, Rust literals with type suffix, numbers in words with hyphens and fractional parts digit by digit, integers in other bases in groups of 4 digits without prefix, 0 without exponent and no numbers snapped to it, a mantissa of 1 before powers, output for humans rather than machines without the original value, and 9 integer and 4 fraction digits for sortable output.
     ///
     /// # Returns
     /// - default Formatter
@@ -630,4 +2683,87 @@ impl Default for Formatter
 }
 
 
+/// # Summary
+/// Replaces a text option in constant context. Only static text is replaced, as text set at runtime cannot be dropped there. That text is overridden instead and dropped by the next runtime setter or with the formatter, so nothing leaks.
+///
+/// # Arguments
+/// - `text`: the text option to replace
+/// - `new`: the new text
+const fn replace_text(text: &mut Text, new: &'static str)
+{
+    match text
+    {
+        Text::Shared(_, old) => *old = Some(new),
+        Text::Static(old) => *old = new,
+    }
+}
+
+
+/// # Summary
+/// Sets a text option at runtime. Allocates only if the text changes to something not empty, so setting the current text again or clearing it is free.
+///
+/// # Arguments
+/// - `text`: the text option to set
+/// - `new`: the new text
+fn set_text(text: &mut Text, new: &str)
+{
+    if **text == *new
+    {
+        return;
+    }
+    *text = if new.is_empty() { Text::Static("") } else { Text::Shared(new.into(), None) };
+}
+
+
+/// # Summary
+/// Checks separators for what may lead to ambiguous formatting, like an empty decimal separator, equal separators, or digits in a separator.
+///
+/// # Arguments
+/// - `group_separator`: separator between groups of 3 digits in the integer part
+/// - `decimal_separator`: separator between integer and fraction part
+///
+/// # Returns
+/// - the problem or none if the separators are fine
+pub(crate) fn separator_problem(group_separator: &str, decimal_separator: &str) -> Option<ConfigError>
+{
+    let contains_digit = |s: &str| -> bool { s.contains(|c: char| c.is_ascii_digit()) };
+
+
+    if decimal_separator.is_empty()
+    {
+        return Some(ConfigError::EmptyDecimalSeparator);
+    }
+    if group_separator == decimal_separator
+    {
+        return Some(ConfigError::IdenticalSeparators(group_separator.to_owned()));
+    }
+    if contains_digit(group_separator)
+    {
+        return Some(ConfigError::DigitInGroupSeparator(group_separator.to_owned()));
+    }
+    if contains_digit(decimal_separator)
+    {
+        return Some(ConfigError::DigitInDecimalSeparator(decimal_separator.to_owned()));
+    }
+
+    return None;
+}
+
+
+/// # Summary
+/// Warns about separators that may lead to ambiguous formatting, see `separator_problem`.
+///
+/// # Arguments
+/// - `group_separator`: separator between groups of 3 digits in the integer part
+/// - `decimal_separator`: separator between integer and fraction part
+#[cfg(feature = "warn_about_problematic_separators")]
+fn warn_about_separators(group_separator: &str, decimal_separator: &str)
+{
+    if let Some(problem) = separator_problem(group_separator, decimal_separator)
+    {
+        log::warn!("{problem}");
+    }
+}
+
+
 // inspiration: https://github.com/kurtlawrence/numfmt/
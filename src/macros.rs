@@ -0,0 +1,115 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+
+
+/// # Summary
+/// Formats a number for quick logging. Without options, it formats with the global formatter like `scaler::format`. With options, it builds a one-off formatter from `Formatter::new` at the call site, every option expands to the corresponding setter, so unknown options fail to compile.
+///
+/// Options, separated by commas:
+/// - `sig = n`: round to n significant digits
+/// - `mag = n`: round to the digit at 10^n
+/// - `decimals = n`: round to n decimal places
+/// - `scaling = decimal`, `binary`, `none`, or `scientific`: decimal and binary with space before the unit prefix
+/// - `sign = always`, `except_zero`, `only_minus`, or `space_for_positive`
+/// - `sep = (group, decimal)`: group and decimal separator
+/// - `unit = "B"`: unit after the unit prefix
+///
+/// # Arguments
+/// - `x`: the number to format, like for `Formatter::format`
+/// - options to configure the one-off formatter
+///
+/// # Returns
+/// - the formatted number
+///
+/// # Examples
+/// ```
+/// use scaler::scale;
+/// println!("Downloaded {}.", scale!(42069)); // "Downloaded 42,07 k."
+/// assert_eq!(scale!(42069), "42,07 k");
+/// println!("Downloaded {}.", scale!(1536, sig = 3, scaling = binary, sign = always, unit = "B")); // "Downloaded +1,50 KiB."
+/// assert_eq!(scale!(1536, sig = 3, scaling = binary, sign = always, unit = "B"), "+1,50 KiB");
+/// assert_eq!(scale!(1234.5, decimals = 2, scaling = none, sep = (",", ".")), "1,234.50");
+/// assert_eq!(scale!(1234.5, mag = 2, scaling = none), "1.200");
+/// assert_eq!(scale!(0.000123, sig = 2, scaling = scientific,), "1,2 * 10^(-4)");
+/// ```
+///
+/// ```compile_fail
+/// let s: String = scaler::scale!(1, precision = 3); // unknown option
+/// ```
+///
+/// ```compile_fail
+/// let s: String = scaler::scale!(1, scaling = metric); // unknown scaling
+/// ```
+///
+/// ```compile_fail
+/// let s: String = scaler::scale!(1, sign = never); // unknown sign behaviour
+/// ```
+#[macro_export]
+macro_rules! scale
+{
+    ($x:expr $(,)?) => {
+        $crate::format($x)
+    };
+    ($x:expr, $($options:tt)+) => {
+        $crate::__scale_options!($crate::Formatter::new(); $($options)+).format($x)
+    };
+}
+
+
+/// # Summary
+/// Applies the options of `scale!` one after another to a formatter, not to be used directly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __scale_options
+{
+    ($f:expr; $(,)?) => {
+        $f
+    };
+    ($f:expr; decimals = $decimals:expr $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_rounding($crate::Rounding::Magnitude(-($decimals))); $($($rest)*)?)
+    };
+    ($f:expr; mag = $magnitude:expr $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_rounding($crate::Rounding::Magnitude($magnitude)); $($($rest)*)?)
+    };
+    ($f:expr; scaling = binary $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_scaling($crate::Scaling::Binary(true)); $($($rest)*)?)
+    };
+    ($f:expr; scaling = decimal $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_scaling($crate::Scaling::Decimal(true)); $($($rest)*)?)
+    };
+    ($f:expr; scaling = none $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_scaling($crate::Scaling::None); $($($rest)*)?)
+    };
+    ($f:expr; scaling = scientific $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_scaling($crate::Scaling::Scientific); $($($rest)*)?)
+    };
+    ($f:expr; scaling = $other:tt $($rest:tt)*) => {
+        compile_error!(concat!("Unknown scaling `", stringify!($other), "`, expected `decimal`, `binary`, `none`, or `scientific`."))
+    };
+    ($f:expr; sep = ($group:expr, $decimal:expr) $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_separators($group, $decimal); $($($rest)*)?)
+    };
+    ($f:expr; sig = $significants:expr $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_rounding($crate::Rounding::SignificantDigits($significants)); $($($rest)*)?)
+    };
+    ($f:expr; sign = always $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_sign($crate::Sign::Always); $($($rest)*)?)
+    };
+    ($f:expr; sign = except_zero $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_sign($crate::Sign::ExceptZero); $($($rest)*)?)
+    };
+    ($f:expr; sign = only_minus $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_sign($crate::Sign::OnlyMinus); $($($rest)*)?)
+    };
+    ($f:expr; sign = space_for_positive $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_sign($crate::Sign::SpaceForPositive); $($($rest)*)?)
+    };
+    ($f:expr; sign = $other:tt $($rest:tt)*) => {
+        compile_error!(concat!("Unknown sign behaviour `", stringify!($other), "`, expected `always`, `except_zero`, `only_minus`, or `space_for_positive`."))
+    };
+    ($f:expr; unit = $unit:expr $(, $($rest:tt)*)?) => {
+        $crate::__scale_options!($f.set_unit($unit); $($($rest)*)?)
+    };
+    ($f:expr; $key:ident $($rest:tt)*) => {
+        compile_error!(concat!("Unknown option `", stringify!($key), "`, expected `sig`, `mag`, `decimals`, `scaling`, `sign`, `sep`, or `unit`."))
+    };
+}
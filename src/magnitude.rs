@@ -0,0 +1,135 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::StackString;
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+
+/// smallest decimal magnitude of a positive f64, 10^(-324) rounds to 0 but the smallest subnormal is about 4.9 * 10^(-324)
+const POW10_MIN: i16 = -324;
+
+/// largest decimal magnitude of a finite f64
+const POW10_MAX: i16 = 308;
+
+
+/// # Summary
+/// Powers of 10 from 10^(-324) to 10^(308), each the f64 closest to the exact power. Parsed once instead of computed with `powi` or `powf`, whose results may differ in the last bit across platforms.
+///
+/// # Returns
+/// - the table, index is the exponent minus `POW10_MIN`
+fn pow10_table() -> &'static [f64; (POW10_MAX - POW10_MIN + 1) as usize]
+{
+    static TABLE: OnceLock<[f64; (POW10_MAX - POW10_MIN + 1) as usize]> = OnceLock::new(); // parsing is correctly rounded everywhere
+
+
+    return TABLE.get_or_init(|| {
+        std::array::from_fn(|i| {
+            let mut s: StackString<8> = StackString::new(); // power written like "1e-324", without heap allocation
+
+
+            write!(s, "1e{}", i as i16 + POW10_MIN).expect("Power of 10 does not fit into the stack string even though it is sized for \"1e-324\".");
+            return s.as_str().parse::<f64>().expect("Power of 10 is not a valid f64 even though it is written as one.");
+        })
+    });
+}
+
+
+/// # Summary
+/// Looks up 10^exponent as the f64 closest to it, identical on every platform.
+///
+/// # Arguments
+/// - `exponent`: exponent of the power, clamped to -324..=308, beyond that the power is 0 or infinite anyway
+///
+/// # Returns
+/// - 10^exponent
+pub(crate) fn pow10(exponent: i16) -> f64
+{
+    if POW10_MAX < exponent
+    // beyond f64
+    {
+        return f64::INFINITY;
+    }
+    return pow10_table()[(exponent.max(POW10_MIN) - POW10_MIN) as usize];
+}
+
+
+/// # Summary
+/// Constructs 2^exponent from its bits, exact down to the smallest subnormal.
+///
+/// # Arguments
+/// - `exponent`: exponent of the power
+///
+/// # Returns
+/// - 2^exponent, 0 below 2^(-1074) and infinity beyond 2^(1023)
+pub(crate) fn pow2(exponent: i64) -> f64
+{
+    return match exponent
+    {
+        ..-1074 => 0.0,
+        -1074..=-1023 => f64::from_bits(1 << (exponent + 1074)), // subnormal, a single mantissa bit
+        -1022..=1023 => f64::from_bits(((exponent + 1023) as u64) << 52), // normal, biased exponent only
+        _ => f64::INFINITY,
+    };
+}
+
+
+/// # Summary
+/// Determines the binary magnitude of a number, floor of log2, exactly from the exponent and mantissa bits instead of with `log2`, which may round up just below a power of 2.
+///
+/// # Arguments
+/// - `x`: the number, any besides not a number
+///
+/// # Returns
+/// - the largest integer magnitude with 2^magnitude ≤ |x|, 0 for 0, 1024 for infinity
+pub(crate) fn binary_magnitude(x: f64) -> i16
+{
+    let bits: u64 = x.abs().to_bits();
+    let exponent: i16 = (bits >> 52) as i16; // biased exponent, 0 for subnormals
+
+
+    if bits == 0
+    // 0 has no magnitude
+    {
+        return 0;
+    }
+    if exponent == 0
+    // subnormal, magnitude from the highest mantissa bit
+    {
+        return bits.ilog2() as i16 - 1074;
+    }
+    return exponent - 1023;
+}
+
+
+/// # Summary
+/// Determines the decimal magnitude of a number, floor of log10, exactly instead of with `log10`, which may round up just below a power of 10 and differs across platforms. Estimated from the binary magnitude, then corrected by comparing with the powers of 10 as f64. So the f64 closest to a power of 10, like 1e23, has the magnitude of that power even if it is stored slightly below it, and every other number the magnitude of its exact value.
+///
+/// # Arguments
+/// - `x`: the number, any besides not a number
+///
+/// # Returns
+/// - the largest integer magnitude with 10^magnitude ≤ |x| in f64, 0 for 0, 308 for infinity
+pub(crate) fn decimal_magnitude(x: f64) -> i16
+{
+    let x: f64 = x.abs();
+    let mut magnitude: i16 = (binary_magnitude(x) as i32 * 78913).div_euclid(1 << 18) as i16; // floor(binary magnitude * log10(2)), at most 1 below the result
+
+
+    if x == 0.0
+    // 0 has no magnitude
+    {
+        return 0;
+    }
+    magnitude = magnitude.clamp(POW10_MIN, POW10_MAX);
+    while POW10_MIN < magnitude && x < pow10(magnitude)
+    // estimate too high
+    {
+        magnitude -= 1;
+    }
+    while magnitude < POW10_MAX && pow10(magnitude + 1) <= x
+    // estimate too low
+    {
+        magnitude += 1;
+    }
+
+    return magnitude;
+}
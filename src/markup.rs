@@ -0,0 +1,118 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats like `Formatter::format`, but as HTML for web pages. Scientific notation uses a multiplication sign and puts the exponent into `<sup>`, the unit prefix is wrapped in `<span class="si-prefix">`, and the whitespace between number and unit prefix is a non-breaking space so they never end up on different lines. Nothing else is escaped, affixes and separators are inserted as they are, so the caller is responsible for the context the output is put into.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the formatted number as HTML
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_html(42069), r#"42,07&nbsp;<span class="si-prefix">k</span>"#);
+    /// assert_eq!(f.format_html(1e36), "1,000 × 10<sup>36</sup>"); // scientific fallback
+    /// assert_eq!(f.format_html(-1e-36), "-1,000 × 10<sup>-36</sup>");
+    /// assert_eq!(f.format_html(123.456), "123,5");
+    /// assert_eq!(f.format_html(f64::NEG_INFINITY), "-∞");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(false));
+    /// assert_eq!(f.format_html(42069), r#"41,08<span class="si-prefix">Ki</span>"#);
+    /// assert_eq!(f.format_html(0.789), "1,578 × 2<sup>-1</sup>"); // binary fallback
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_affixes("<b>", "</b>"); // affixes are not escaped
+    /// assert_eq!(f.format_html(299792458), "<b>2,998 × 10<sup>8</sup></b>");
+    /// ```
+    pub fn format_html<T>(&self, x: T) -> String
+    where
+        T: Into<f64>,
+    {
+        let mut html: String = String::new(); // formatted number as HTML, result
+
+        for part in self.format_to_parts(x).into_iter()
+        {
+            match part.kind
+            {
+                PartKind::ExponentMarker => html.push_str(&part.value.replace(" * ", " × ").replace("^(", "<sup>").replace(')', "</sup>")), // " * 10^(" and ")"
+                PartKind::Literal => html.push_str(&part.value.replace(' ', "&nbsp;")), // keep number and unit prefix on the same line
+                PartKind::Prefix => html.push_str(&format!(r#"<span class="si-prefix">{}</span>"#, part.value)),
+                PartKind::Threshold => html.push_str(&part.value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace(' ', "&nbsp;")), // "< " would open a tag, kept on the same line as the number
+                _ => html.push_str(&part.value),
+            }
+        }
+
+        return html;
+    }
+
+
+    /// # Summary
+    /// Formats like `Formatter::format`, but lets `style` decorate every piece of the formatted number, for example with ANSI escape codes to colour the sign or dim the unit prefix. The pieces are the same as returned by `Formatter::format_to_parts` and are handed over in order, the results are concatenated.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `style`: receives kind and text of each piece, returns what to put into the output instead
+    ///
+    /// # Returns
+    /// - the formatted and decorated number
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::PartKind;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let style = |kind: PartKind, s: &str| -> String
+    /// {
+    ///     return match kind
+    ///     {
+    ///         PartKind::Sign => format!("\x1b[31m{s}\x1b[0m"), // red
+    ///         PartKind::Prefix => format!("\x1b[2m{s}\x1b[0m"), // dim
+    ///         _ => s.to_string(),
+    ///     };
+    /// };
+    /// assert_eq!(f.format_styled(-42069, style), "\x1b[31m-\x1b[0m42,07 \x1b[2mk\x1b[0m");
+    /// assert_eq!(f.format_styled(123.456, style), "123,5"); // digits untouched
+    /// ```
+    ///
+    /// ```
+    /// let formatters: Vec<scaler::Formatter> = vec![
+    ///     scaler::Formatter::new(),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::Binary(false)).set_sign(scaler::Sign::Always),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::None).set_affixes("$", "").set_width(12, scaler::Align::Center, '*'),
+    /// ];
+    /// let mut x: f64 = 1.234e-40;
+    /// while x < 1e40 // identity style reproduces formatted number
+    /// {
+    ///     for f in formatters.iter()
+    ///     {
+    ///         for y in [x, -x, 0.0, f64::INFINITY, f64::NAN]
+    ///         {
+    ///             assert_eq!(f.format_styled(y, |_kind, s| s.to_string()), f.format(y));
+    ///         }
+    ///     }
+    ///     x *= 7.3;
+    /// }
+    /// ```
+    pub fn format_styled<T, F>(&self, x: T, style: F) -> String
+    where
+        T: Into<f64>,
+        F: Fn(PartKind, &str) -> String,
+    {
+        return self.format_to_parts(x).iter().map(|part| style(part.kind, &part.value)).collect();
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// A number that is parsed from and displayed as scaled text, for example in configuration files or command line arguments. Parses with `Formatter::parse_lenient` of the default formatter, so common human forms like "1.5k", "2 GiB", or "3e6" are understood, and displays with the default formatter.
+///
+/// # Examples
+/// ```
+/// let n: scaler::ScaledNumber = "512Mi".parse().unwrap();
+/// assert_eq!(*n, 536870912.0);
+/// assert_eq!(n.to_string(), "536,9 M");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct ScaledNumber(pub f64);
+
+
+impl ScaledNumber
+{
+    /// # Summary
+    /// Wraps the number so it is displayed with a custom formatter instead of the default one.
+    ///
+    /// # Arguments
+    /// - `f`: the formatter to display with
+    ///
+    /// # Returns
+    /// - the displayable number, borrows the formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let n: scaler::ScaledNumber = "2 GiB".parse().unwrap();
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(2));
+    /// assert_eq!(format!("{} B", n.with(&f)), "2,0 Gi B");
+    /// ```
+    pub fn with(self, f: &Formatter) -> Scaled<'_>
+    {
+        return f.display(self.0);
+    }
+}
+
+
+impl std::ops::Deref for ScaledNumber
+{
+    type Target = f64;
+
+    fn deref(&self) -> &Self::Target
+    {
+        return &self.0;
+    }
+}
+
+
+impl std::fmt::Display for ScaledNumber
+{
+    /// # Summary
+    /// Formats the number with the default formatter, honouring the flags of the format spec like `Scaled` does.
+    ///
+    /// # Examples
+    /// ```
+    /// let n: scaler::ScaledNumber = scaler::ScaledNumber::from(1234.5);
+    /// assert_eq!(n.to_string(), "1,234 k");
+    /// assert_eq!(format!("{:>9.1}", n), "    1,2 k");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let formatter: Formatter = Formatter::default(); // default formatter to display with
+
+
+        return std::fmt::Display::fmt(&formatter.display(self.0), f);
+    }
+}
+
+
+impl From<f64> for ScaledNumber
+{
+    fn from(x: f64) -> Self
+    {
+        return Self(x);
+    }
+}
+
+
+impl From<ScaledNumber> for f64
+{
+    fn from(n: ScaledNumber) -> Self
+    {
+        return n.0;
+    }
+}
+
+
+impl std::str::FromStr for ScaledNumber
+{
+    type Err = ParseError;
+
+    /// # Summary
+    /// Parses the number with `Formatter::parse_lenient` of the default formatter. A trailing "B" or "b" for bytes is ignored, so "2 GiB" and "2 gib" are the same as "2 Gi".
+    ///
+    /// # Arguments
+    /// - `s`: the number
+    ///
+    /// # Returns
+    /// - the number
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::ScaledNumber;
+    /// for (s, x) in [
+    ///     ("1.5k", 1500.0),
+    ///     ("1,5 k", 1500.0),
+    ///     ("1.5K", 1500.0), // unit prefixes ignoring case if unambiguous
+    ///     ("2 GiB", 2147483648.0),
+    ///     ("2giB", 2147483648.0),
+    ///     ("2 gib", 2147483648.0), // bytes unit ignoring case as well
+    ///     ("2 GIB", 2147483648.0),
+    ///     ("2 kb", 2000.0),
+    ///     ("2 GB", 2e9),
+    ///     ("512Mi", 536870912.0),
+    ///     ("512 MI", 536870912.0),
+    ///     ("3e6", 3e6),
+    ///     ("-4,2 µ", -4.2e-6),
+    ///     ("1 000 000", 1e6),
+    ///     ("  7 m ", 0.007), // exact match first, "m" is milli
+    ///     ("100B", 100.0),
+    ///     ("100b", 100.0),
+    /// ]
+    /// {
+    ///     assert_eq!(s.parse::<ScaledNumber>(), Ok(ScaledNumber(x)), "{s}");
+    /// }
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind, ScaledNumber};
+    /// assert_eq!("".parse::<ScaledNumber>(), Err(ParseError { field: None, kind: ParseErrorKind::Empty, position: 0 }));
+    /// assert_eq!("12 xB".parse::<ScaledNumber>(), Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix("x".to_string()), position: 3 }));
+    /// let x: f64 = "1.5k".parse::<ScaledNumber>().unwrap().into();
+    /// assert_eq!(x, 1500.0);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let number: &str = s.trim_end(); // without trailing whitespace, so the bytes unit is found
+
+
+        return Formatter::default().parse_lenient(number.strip_suffix(['B', 'b']).unwrap_or(number)).map(Self);
+    }
+}
@@ -1,7 +1,135 @@
 // Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::ScaleStrategy;
+use std::sync::Arc;
 
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum Align
+{
+    Center, // pad both sides, if uneven one more fill character on the right
+    Left,   // pad right
+    Right,  // pad left
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum CommonScale
+{
+    Maximum, // unit prefix of the number with the largest magnitude, no mantissa gets more than 3 integer digits
+    Median,  // unit prefix of the number with the median magnitude, robust against outliers
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum ComplexNotation
+{
+    Cartesian,          // real part and imaginary part, "a + bi"
+    CartesianCollapsed, // real part and imaginary part, but a part that is exactly 0 is left out, "a" or "bi"
+    Polar,              // magnitude and angle in radians, "r ∠ φ rad"
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum DeltaParts
+{
+    Absolute,           // only the absolute change, "+1,200 k"
+    AbsoluteAndPercent, // absolute change and relative change in percent, "+1,200 k (+3,4 %)"
+    Percent,            // only the relative change in percent, "+3,4 %"
+}
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum Fill
+{
+    Character,   // pad with the fill character of `Formatter::set_width`, align columns with spaces
+    FigureSpace, // pad with U+2007 FIGURE SPACE of digit width, in columns U+2008 PUNCTUATION SPACE where another row has a separator
+}
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum Locale
+{
+    DeDe, // German, Germany: "1.234,5", "1,2 Mio.", "3,4 Mrd."
+    EnUs, // English, United States: "1,234.5", "1.2K", "3.4B"
+    HiIn, // Hindi, India: "1,234.5", "1.2 लाख", "3.4 करोड़", in steps of 10^2 above thousands
+    JaJp, // Japanese, Japan: "1,234.5", "1.2万", "3.4億", in steps of 10^4
+}
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum NonFinitePolicy
+{
+    Error,  // reject infinity and not a number, the output methods returning a String panic, the others return an error
+    Format, // display infinity and not a number as the special strings
+}
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum PluralCategory
+{
+    Few,   // CLDR category "few", like 2 to 4 in Czech
+    Many,  // CLDR category "many", like 5 and more in Polish
+    One,   // CLDR category "one", singular
+    Other, // CLDR category "other", plural
+    Two,   // CLDR category "two", dual
+    Zero,  // CLDR category "zero"
+}
+
+
+#[derive(Clone, Copy, Debug)]
+pub enum PluralRule
+{
+    Custom
+    {
+        name: &'static str,              // identifies the rule, rules with the same name are equal, like "fr" for French
+        rule: fn(f64) -> PluralCategory,    // maps the displayed number to its plural category, like the CLDR rule of a language
+    },
+    English, // 1 and -1 are singular, everything else is plural, including 0
+}
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum Radix
+{
+    Binary,   // base 2, prefix "0b"
+    Hex,      // base 16 with uppercase digits, prefix "0x", "DEAD.BEEF"
+    HexLower, // base 16 with lowercase digits, prefix "0x", "dead.beef"
+    Octal,    // base 8, prefix "0o"
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum RateBase
+{
+    Auto,      // per second, per minute if less than 1 per second, per hour if less than 1 per minute
+    PerHour,   // per hour, "/h"
+    PerMinute, // per minute, "/min"
+    PerSecond, // per second, "/s"
+}
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum RomanLarge
+{
+    Overline,    // thousands above 3999 as numeral with overlines, "I̅V̅" for 4000
+    Parentheses, // thousands above 3999 as numeral in parentheses, "(IV)" for 4000
+    Unsupported, // numbers above 3999 are out of range
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
 pub enum Rounding
 {
     Magnitude(i16),        // round statically to digit at 10^n, contains precision n
@@ -9,19 +137,935 @@ pub enum Rounding
 }
 
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub enum Scaling
 {
-    Binary(bool),  // scaling by 2^10 = 1.024 until no more prefixes, then fallback to scientific notation, contains whether or not to put space between number and unit prefix
-    Decimal(bool), // scaling by 10^3 = 1.000 until no more prefixes, then fallback to scientific notation, contains whether or not to put space between number and unit prefix
-    None,          // no scaling, no fallback to scientific notation
-    Scientific,    // always scientific notation
+    Binary(bool),     // scaling by 2^10 = 1.024 until no more prefixes, then fallback to scientific notation, contains whether or not to put space between number and unit prefix
+    CompactLocalized, // compact suffixes of the locale set with `Formatter::set_locale` like "Mio." or "万", in its steps, below the first step no scaling, beyond the last the largest suffix
+    Decimal(bool),    // scaling by 10^3 = 1.000 until no more prefixes, then fallback to scientific notation, contains whether or not to put space between number and unit prefix
+    Exponential
+    {
+        base:     u32,                 // base of the powers to scale by, like 16 for "4,000 * 16^(3)", below 2 treated as 2
+        step:     u32,                 // exponents are multiples of it, like 3 for engineering notation in base 10, 0 treated as 1
+        prefixes: Option<PrefixTable>, // unit prefixes for powers of the base, then fallback to exponent notation, or none for exponent notation always
+    },
+    None,             // no scaling, no fallback to scientific notation, rounds the exact decimal expansion of the f64
+    Percent(bool),    // ratio multiplied by 100 exactly and displayed with "%" instead of a unit prefix, otherwise like no scaling, contains whether or not to put space between number and "%"
+    Scientific,       // always scientific notation
+    Strategy(Arc<dyn ScaleStrategy + Send + Sync>), // divisor and suffix chosen by custom logic for every number, equal only to the same shared strategy, not to a copy of it
+}
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum ShowOriginal
+{
+    Grouped, // shortest digits that round-trip, grouped with the configured separators, "42.069"
+    Off,     // only the formatted number
+    Raw,     // as Rust displays the f64, "42069"
 }
 
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
 pub enum Sign
 {
-    Always,    // always show sign
-    OnlyMinus, // only show sign when negative
+    Always,           // always show sign
+    ExceptZero,       // show sign when positive or negative, but not when rounded to 0, for changes where 0 is neither
+    OnlyMinus,        // only show sign when negative
+    SpaceForPositive, // show space instead of plus sign when positive, keeps positive and negative numbers aligned
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum SignPlacement
+{
+    Leading,     // sign before number
+    Parentheses, // negative numbers enclosed in parentheses instead of minus, "(" where the leading sign would be and ")" where the trailing sign would be, other signs leading
+    Trailing,    // sign after number, unit prefix, and suffix affix
 }
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum SignPosition
+{
+    AfterAffix,  // sign after prefix affix, immediately before the first digit
+    BeforeAffix, // sign before prefix affix
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum UncertaintyNotation
+{
+    Concise,   // error as digits of the last places of the value in parentheses, "1,235(2) k"
+    PlusMinus, // value and error separated by the plus-minus sign, "(1,235 ± 0,002) k"
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize, ::serde::Serialize), serde(rename_all = "snake_case"))]
+pub enum WordsFraction
+{
+    Digits,   // fractional part spelt digit by digit, "point five six"
+    Fraction, // fractional part as fraction of a power of 10 like on cheques, "and 56/100"
+}
+
+
+/// # Summary
+/// How `Formatter::format_cjk` writes Chinese and Japanese positional numerals, set with `Formatter::set_cjk`. Construct with a struct expression based on `CjkOptions::new`.
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new().set_cjk(scaler::CjkOptions { spelled_minus: true, ..scaler::CjkOptions::new() });
+/// assert_eq!(f.format_cjk(-12345, scaler::CjkStyle::MixedArabic), "マイナス1万2345");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CjkOptions
+{
+    pub financial:     bool, // whether to use the financial anti-fraud characters of cheques and contracts, "壱弐参…", "拾佰仟", and "萬", which cannot be altered by adding strokes, and to write 1 before 拾, 佰, and 仟 as well
+    pub spelled_minus: bool, // whether to write "マイナス" instead of "−" before negative numbers
+}
+
+
+/// # Summary
+/// How `Formatter::format_fraction` displays fractions, set with `Formatter::set_fraction`. Construct with a struct expression based on `FractionOptions::new`.
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new().set_fraction(scaler::FractionOptions { glyphs: true, ..scaler::FractionOptions::new() });
+/// assert_eq!(f.format_fraction(2.375, 8), "2⅜");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FractionOptions
+{
+    pub glyphs: bool, // whether to use Unicode vulgar fraction glyphs like "¾" for the fractions that have one, directly after the integer part, fractions without glyph stay ASCII
+    pub mixed:  bool, // whether to display an integer part and a proper fraction like "2 3/8" instead of an improper fraction like "19/8"
+}
+
+
+/// # Summary
+/// How `Formatter::format_hexfloat` displays hexadecimal floats, set with `Formatter::set_hexfloat`. Construct with a struct expression based on `HexfloatOptions::new`.
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new().set_hexfloat(scaler::HexfloatOptions { uppercase: true, ..scaler::HexfloatOptions::new() });
+/// assert_eq!(f.format_hexfloat(0.1), "0X1.999999999999AP-4");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HexfloatOptions
+{
+    pub all_digits: bool, // whether to display all 13 hexadecimal fraction digits of the 52 bit significand, including trailing zeros, so all numbers line up
+    pub uppercase:  bool, // whether to display digits, "X", and "P" in uppercase, like `%A` in C
+}
+
+
+/// # Summary
+/// Returned by parsing `Locale`, `Rounding`, `Scaling`, or `Sign` from a string if it is none of their string forms. Contains what was rejected and what would have been valid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseOptionError
+{
+    pub option: &'static str, // name of the option, like "rounding"
+    pub valid:  &'static str, // description of the valid string forms
+    pub value:  String,       // the rejected string
+}
+
+
+/// # Summary
+/// How `Formatter::format_percent_of` handles the edge cases of a percentage of a whole, set with `Formatter::set_percent_of`. Construct with a struct expression based on `PercentOfOptions::new`.
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new().set_percent_of(scaler::PercentOfOptions { clamp: true, ..scaler::PercentOfOptions::new() });
+/// assert_eq!(f.format_percent_of(12.0, 10.0), "100,0 %");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PercentOfOptions
+{
+    pub clamp:                    bool, // whether to clamp percentages to [0 %; 100 %], for progress bars
+    pub zero_of_zero_placeholder: bool, // whether to display 0 of 0 as the placeholder set with `Formatter::set_none_string` instead of 0 %, as there is no ratio
+}
+
+
+/// # Summary
+/// Unit prefixes for powers of an arbitrary base, used by `Scaling::Exponential`. Constructed with `PrefixTable::new`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrefixTable
+{
+    pub(crate) prefixes: &'static [(i16, i16, &'static str)], // [lower bound exponent; upper bound exponent[, unit prefix
+    pub(crate) space:    bool,                                // whether or not to put space between number and unit prefix
+}
+
+
+/// # Summary
+/// How `Formatter::format_radix` and its relatives display integers in other bases, set with `Formatter::set_radix`. Construct with a struct expression based on `RadixOptions::new`, which is also possible in constant context.
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new().set_radix(scaler::RadixOptions { prefix: true, ..scaler::RadixOptions::new() });
+/// assert_eq!(f.format_radix(0xDEAD_BEEF, scaler::Radix::Hex), "0xDEAD.BEEF");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RadixOptions
+{
+    pub group_size: usize, // number of digits per group counted from the right, 0 for no grouping
+    pub min_digits: usize, // minimum number of digits, zero padded and grouped like the other digits, like 8 for 32 bit registers in hexadecimal
+    pub prefix:     bool,  // whether to prefix the digits with "0b", "0o", or "0x" like Rust literals
+}
+
+
+/// # Summary
+/// How `Formatter::format_roman` writes Roman numerals, set with `Formatter::set_roman`. Construct with a struct expression based on `RomanOptions::new`.
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new().set_roman(scaler::RomanOptions { lowercase: true, ..scaler::RomanOptions::new() });
+/// assert_eq!(f.format_roman(1994), Ok("mcmxciv".to_string()));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RomanOptions
+{
+    pub additive:  bool,       // whether to write 4 as "IIII" and 9 as "VIIII" instead of "IV" and "IX", like on clock faces
+    pub large:     RomanLarge, // how to write numbers above 3999, if at all
+    pub lowercase: bool,       // whether to write "mcmxciv" instead of "MCMXCIV"
+}
+
+
+/// # Summary
+/// How `Formatter::format_words` spells numbers, set with `Formatter::set_words`. Construct with a struct expression based on `WordsOptions::new`.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "words")]
+/// # {
+/// let f: scaler::Formatter = scaler::Formatter::new().set_words(scaler::WordsOptions { hyphenate: false, ..scaler::WordsOptions::new() });
+/// assert_eq!(f.format_words(21.5), "twenty one point five zero");
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WordsOptions
+{
+    pub and_after_hundreds: bool,          // whether to put "and" after hundreds and before the last tens and ones in British style, "one hundred and twenty", "one thousand and one"
+    pub fraction:           WordsFraction, // how to spell the fractional part
+    pub hyphenate:          bool,          // whether to join tens and ones with a hyphen, "twenty-one", or a space, "twenty one"
+}
+
+
+impl CjkOptions
+{
+    /// # Summary
+    /// Constructs the default options of CJK positional numerals: ordinary characters and "−" before negative numbers.
+    ///
+    /// # Returns
+    /// - CjkOptions
+    pub const fn new() -> Self
+    {
+        return Self { financial: false, spelled_minus: false };
+    }
+}
+
+
+impl Default for CjkOptions
+{
+    /// # Summary
+    /// Constructs the default options of CJK positional numerals, same as `CjkOptions::new`.
+    ///
+    /// # Returns
+    /// - default CjkOptions
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl FractionOptions
+{
+    /// # Summary
+    /// Constructs the default options of fractions: mixed numbers in ASCII, like "2 3/8".
+    ///
+    /// # Returns
+    /// - FractionOptions
+    pub const fn new() -> Self
+    {
+        return Self { glyphs: false, mixed: true };
+    }
+}
+
+
+impl Default for FractionOptions
+{
+    /// # Summary
+    /// Constructs the default options of fractions, same as `FractionOptions::new`.
+    ///
+    /// # Returns
+    /// - default FractionOptions
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl HexfloatOptions
+{
+    /// # Summary
+    /// Constructs the default options of hexadecimal floats: lowercase and no trailing zeros, like `%a` in C.
+    ///
+    /// # Returns
+    /// - HexfloatOptions
+    pub const fn new() -> Self
+    {
+        return Self { all_digits: false, uppercase: false };
+    }
+}
+
+
+impl Default for HexfloatOptions
+{
+    /// # Summary
+    /// Constructs the default options of hexadecimal floats, same as `HexfloatOptions::new`.
+    ///
+    /// # Returns
+    /// - default HexfloatOptions
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl PercentOfOptions
+{
+    /// # Summary
+    /// Constructs the default options of percentages of a whole: 0 of 0 as 0 % and no clamping.
+    ///
+    /// # Returns
+    /// - PercentOfOptions
+    pub const fn new() -> Self
+    {
+        return Self { clamp: false, zero_of_zero_placeholder: false };
+    }
+}
+
+
+impl Default for PercentOfOptions
+{
+    /// # Summary
+    /// Constructs the default options of percentages of a whole, same as `PercentOfOptions::new`.
+    ///
+    /// # Returns
+    /// - default PercentOfOptions
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl PrefixTable
+{
+    /// # Summary
+    /// Constructs a unit prefix table for `Scaling::Exponential`, in constant context so it can be part of a `static` formatter. Every entry covers the exponents of the base from its lower bound up to its upper bound, excluded. Numbers are divided by the base to the power of the lower bound of their entry and displayed with its unit prefix, numbers beyond the table fall back to exponent notation.
+    ///
+    /// # Arguments
+    /// - `prefixes`: [lower bound exponent; upper bound exponent[ and unit prefix, ascending, contiguous, and all of the same width
+    /// - `space`: whether or not to put space between number and unit prefix
+    ///
+    /// # Returns
+    /// - the unit prefix table
+    ///
+    /// # Panics
+    /// - if the table is empty, or its entries are not ascending, contiguous, and of the same width, at compile time in constant context
+    ///
+    /// # Examples
+    /// ```
+    /// static SEXAGESIMAL: scaler::Formatter = scaler::Formatter::new().set_scaling(scaler::Scaling::Exponential {
+    ///     base:     60,
+    ///     step:     1,
+    ///     prefixes: Some(scaler::PrefixTable::new(&[(0, 1, "s"), (1, 2, "min"), (2, 3, "h")], true)),
+    /// });
+    /// assert_eq!(SEXAGESIMAL.format(45), "45,00 s");
+    /// assert_eq!(SEXAGESIMAL.format(5400), "1,500 h");
+    /// assert_eq!(SEXAGESIMAL.format(777600), "3,600 * 60^(3)"); // beyond the table
+    /// ```
+    pub const fn new(prefixes: &'static [(i16, i16, &'static str)], space: bool) -> Self
+    {
+        let mut i: usize = 1; // index of the entry to check against its predecessor
+
+
+        assert!(!prefixes.is_empty() && prefixes[0].0 < prefixes[0].1, "Unit prefix table is empty or its first entry covers no exponent.");
+        while i < prefixes.len()
+        {
+            assert!(
+                prefixes[i].0 == prefixes[i - 1].1 && prefixes[i].1 - prefixes[i].0 == prefixes[0].1 - prefixes[0].0,
+                "Unit prefix table is not ascending, contiguous, and of the same width."
+            );
+            i += 1;
+        }
+
+        return Self { prefixes, space };
+    }
+}
+
+
+impl RadixOptions
+{
+    /// # Summary
+    /// Constructs the default options of integers in other bases: groups of 4 digits, no prefix, and no zero padding.
+    ///
+    /// # Returns
+    /// - RadixOptions
+    pub const fn new() -> Self
+    {
+        return Self { group_size: 4, min_digits: 0, prefix: false };
+    }
+}
+
+
+impl Default for RadixOptions
+{
+    /// # Summary
+    /// Constructs the default options of integers in other bases, same as `RadixOptions::new`.
+    ///
+    /// # Returns
+    /// - default RadixOptions
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl RomanOptions
+{
+    /// # Summary
+    /// Constructs the default options of Roman numerals: uppercase, subtractive notation, and only up to 3999.
+    ///
+    /// # Returns
+    /// - RomanOptions
+    pub const fn new() -> Self
+    {
+        return Self { additive: false, large: RomanLarge::Unsupported, lowercase: false };
+    }
+}
+
+
+impl Default for RomanOptions
+{
+    /// # Summary
+    /// Constructs the default options of Roman numerals, same as `RomanOptions::new`.
+    ///
+    /// # Returns
+    /// - default RomanOptions
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl WordsOptions
+{
+    /// # Summary
+    /// Constructs the default options of numbers in words: fractional part digit by digit, tens and ones joined with a hyphen, and no "and" after hundreds.
+    ///
+    /// # Returns
+    /// - WordsOptions
+    pub const fn new() -> Self
+    {
+        return Self { and_after_hundreds: false, fraction: WordsFraction::Digits, hyphenate: true };
+    }
+}
+
+
+impl Default for WordsOptions
+{
+    /// # Summary
+    /// Constructs the default options of numbers in words, same as `WordsOptions::new`.
+    ///
+    /// # Returns
+    /// - default WordsOptions
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl Locale
+{
+    /// # Summary
+    /// Compact suffixes of the locale for `Scaling::CompactLocalized`, as unit prefix table of powers of 10. The steps between the entries may differ, the last entry is open-ended.
+    ///
+    /// # Returns
+    /// - [lower bound exponent; upper bound exponent[ and suffix, ascending and contiguous
+    pub(crate) const fn compact_suffixes(self) -> &'static [(i16, i16, &'static str)]
+    {
+        return match self
+        {
+            Locale::DeDe => &[(6, 9, "Mio."), (9, 12, "Mrd."), (12, i16::MAX, "Bio.")], // thousands are not abbreviated
+            Locale::EnUs => &[(3, 6, "K"), (6, 9, "M"), (9, 12, "B"), (12, i16::MAX, "T")],
+            Locale::HiIn => &[(3, 5, "हज़ार"), (5, 7, "लाख"), (7, 9, "करोड़"), (9, 11, "अरब"), (11, i16::MAX, "खरब")],
+            Locale::JaJp => &[(4, 8, "万"), (8, 12, "億"), (12, 16, "兆"), (16, i16::MAX, "京")],
+        };
+    }
+
+
+    /// # Summary
+    /// Whether the locale puts space between number and compact suffix.
+    ///
+    /// # Returns
+    /// - whether to put space before the suffix
+    pub(crate) const fn compact_space(self) -> bool
+    {
+        return matches!(self, Locale::DeDe | Locale::HiIn);
+    }
+
+
+    /// # Summary
+    /// Group and decimal separator of the locale.
+    ///
+    /// # Returns
+    /// - group separator and decimal separator
+    pub(crate) const fn separators(self) -> (&'static str, &'static str)
+    {
+        return match self
+        {
+            Locale::DeDe => (".", ","),
+            Locale::EnUs | Locale::HiIn | Locale::JaJp => (",", "."), // Indian grouping of 2 digits above thousands is not supported, grouped by 3
+        };
+    }
+}
+
+
+impl PluralCategory
+{
+    /// # Summary
+    /// Plural rule of English, `PluralRule::English`, the default of `Formatter::set_plural_rule`: 1 and -1 are singular, everything else is plural, including 0.
+    ///
+    /// # Arguments
+    /// - `x`: the displayed number
+    ///
+    /// # Returns
+    /// - `PluralCategory::One` or `PluralCategory::Other`
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::PluralCategory;
+    /// assert_eq!(PluralCategory::english(1.0), PluralCategory::One);
+    /// assert_eq!(PluralCategory::english(-1.0), PluralCategory::One);
+    /// assert_eq!(PluralCategory::english(0.0), PluralCategory::Other);
+    /// assert_eq!(PluralCategory::english(1.5), PluralCategory::Other);
+    /// ```
+    pub fn english(x: f64) -> PluralCategory
+    {
+        if x.abs() == 1.0
+        {
+            return PluralCategory::One;
+        }
+        return PluralCategory::Other;
+    }
+}
+
+
+impl PluralRule
+{
+    /// # Summary
+    /// Determines the plural category of a displayed number with this rule.
+    ///
+    /// # Arguments
+    /// - `x`: the displayed number
+    ///
+    /// # Returns
+    /// - plural category
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{PluralCategory, PluralRule};
+    /// let french: PluralRule = PluralRule::Custom { name: "fr", rule: |x| if x.abs() < 2.0 { PluralCategory::One } else { PluralCategory::Other } };
+    /// assert_eq!(french.category(1.5), PluralCategory::One);
+    /// assert_eq!(PluralRule::English.category(1.5), PluralCategory::Other);
+    /// ```
+    pub fn category(&self, x: f64) -> PluralCategory
+    {
+        return match self
+        {
+            PluralRule::Custom { name: _, rule } => rule(x),
+            PluralRule::English => PluralCategory::english(x),
+        };
+    }
+}
+
+
+impl PartialEq for PluralRule
+{
+    /// # Summary
+    /// Compares plural rules by their name, as functions cannot be compared reliably. Custom rules are equal if their names are, and never equal to a built-in rule.
+    ///
+    /// # Arguments
+    /// - `other`: the other plural rule
+    ///
+    /// # Returns
+    /// - whether the plural rules are equal
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{PluralCategory, PluralRule};
+    /// assert_eq!(PluralRule::Custom { name: "fr", rule: |x| if x.abs() < 2.0 { PluralCategory::One } else { PluralCategory::Other } }, PluralRule::Custom { name: "fr", rule: |_| PluralCategory::One });
+    /// assert_ne!(PluralRule::Custom { name: "en", rule: PluralCategory::english }, PluralRule::English);
+    /// ```
+    fn eq(&self, other: &Self) -> bool
+    {
+        return match (self, other)
+        {
+            (PluralRule::Custom { name: name_self, rule: _ }, PluralRule::Custom { name: name_other, rule: _ }) => name_self == name_other,
+            (PluralRule::English, PluralRule::English) => true,
+            _ => false,
+        };
+    }
+}
+
+
+impl Eq for PluralRule {}
+
+
+impl std::fmt::Display for Locale
+{
+    /// # Summary
+    /// Writes the string form parsed by `Locale::from_str`, the BCP 47 language tag like "de-DE".
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(scaler::Locale::JaJp.to_string(), "ja-JP");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            Locale::DeDe => write!(f, "de-DE"),
+            Locale::EnUs => write!(f, "en-US"),
+            Locale::HiIn => write!(f, "hi-IN"),
+            Locale::JaJp => write!(f, "ja-JP"),
+        };
+    }
+}
+
+
+impl std::str::FromStr for Locale
+{
+    type Err = ParseOptionError;
+
+    /// # Summary
+    /// Parses the BCP 47 language tag written by `Display`. Case and surrounding whitespace are ignored, "_" is accepted instead of "-" like in POSIX locales.
+    ///
+    /// # Arguments
+    /// - `s`: the language tag, "de-DE", "en-US", "hi-IN", or "ja-JP"
+    ///
+    /// # Returns
+    /// - the locale
+    /// - or `ParseOptionError` listing the valid string forms
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::Locale;
+    /// assert_eq!("de-DE".parse::<Locale>(), Ok(Locale::DeDe));
+    /// assert_eq!("ja_jp".parse::<Locale>(), Ok(Locale::JaJp));
+    /// assert_eq!("fr-FR".parse::<Locale>().unwrap_err().to_string(), "Invalid locale \"fr-FR\", expected \"de-DE\", \"en-US\", \"hi-IN\", or \"ja-JP\".");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        return match s.trim().to_ascii_lowercase().replace('_', "-").as_str()
+        {
+            "de-de" => Ok(Locale::DeDe),
+            "en-us" => Ok(Locale::EnUs),
+            "hi-in" => Ok(Locale::HiIn),
+            "ja-jp" => Ok(Locale::JaJp),
+            _ => Err(ParseOptionError { option: "locale", valid: "\"de-DE\", \"en-US\", \"hi-IN\", or \"ja-JP\"", value: s.to_owned() }),
+        };
+    }
+}
+
+
+impl std::fmt::Display for Rounding
+{
+    /// # Summary
+    /// Writes the string form parsed by `Rounding::from_str`, "significant:n" or "magnitude:n".
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(scaler::Rounding::SignificantDigits(4).to_string(), "significant:4");
+    /// assert_eq!(scaler::Rounding::Magnitude(-2).to_string(), "magnitude:-2");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            Rounding::Magnitude(magnitude) => write!(f, "magnitude:{magnitude}"),
+            Rounding::SignificantDigits(significants) => write!(f, "significant:{significants}"),
+        };
+    }
+}
+
+
+impl std::str::FromStr for Rounding
+{
+    type Err = ParseOptionError;
+
+    /// # Summary
+    /// Parses the string form written by `Display`, for example from environment variables or command line flags. Case and surrounding whitespace are ignored, "sig" and "mag" are accepted as abbreviations.
+    ///
+    /// # Arguments
+    /// - `s`: the string form, "significant:n" with n from 0 to 255 or "magnitude:n" with n from -32768 to 32767
+    ///
+    /// # Returns
+    /// - the rounding
+    /// - or `ParseOptionError` listing the valid string forms
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::Rounding;
+    /// let env: &str = "SCALER_ROUNDING=Sig:3"; // like read from the environment
+    /// let (_key, value) = env.split_once('=').unwrap();
+    /// assert_eq!(value.parse::<Rounding>(), Ok(Rounding::SignificantDigits(3)));
+    /// assert_eq!(" magnitude:-2 ".parse::<Rounding>(), Ok(Rounding::Magnitude(-2)));
+    /// assert_eq!("MAG:+3".parse::<Rounding>(), Ok(Rounding::Magnitude(3)));
+    /// assert_eq!(
+    ///     "significant:256".parse::<Rounding>().unwrap_err().to_string(),
+    ///     "Invalid rounding \"significant:256\", expected \"significant:n\" with n from 0 to 255 or \"magnitude:n\" with n from -32768 to 32767, or \"sig\" and \"mag\" for short."
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let error = || ParseOptionError {
+            option: "rounding",
+            valid:  "\"significant:n\" with n from 0 to 255 or \"magnitude:n\" with n from -32768 to 32767, or \"sig\" and \"mag\" for short",
+            value:  s.to_owned(),
+        };
+
+
+        let (kind, precision) = s.trim().split_once(':').ok_or_else(error)?;
+        return match kind.to_ascii_lowercase().as_str()
+        {
+            "mag" | "magnitude" => precision.parse::<i16>().map(Rounding::Magnitude).map_err(|_| error()),
+            "sig" | "significant" => precision.parse::<u8>().map(Rounding::SignificantDigits).map_err(|_| error()),
+            _ => Err(error()),
+        };
+    }
+}
+
+
+impl std::fmt::Display for Scaling
+{
+    /// # Summary
+    /// Writes the string form parsed by `Scaling::from_str`, "binary", "compact", "decimal", "none", "percent", or "scientific", with "+space" appended if there is space between number and unit prefix or "%", or "exponential:" with base and step like "exponential:16:1". Unit prefix tables are static and have no string form, so they are left out. Strategies are code and written as "strategy", which does not parse.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(scaler::Scaling::Binary(true).to_string(), "binary+space");
+    /// assert_eq!(scaler::Scaling::Decimal(false).to_string(), "decimal");
+    /// assert_eq!(scaler::Scaling::Percent(true).to_string(), "percent+space");
+    /// assert_eq!(scaler::Scaling::Scientific.to_string(), "scientific");
+    /// assert_eq!(scaler::Scaling::Exponential { base: 16, step: 1, prefixes: None }.to_string(), "exponential:16:1");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            Scaling::Binary(space) => write!(f, "binary{}", if *space { "+space" } else { "" }),
+            Scaling::CompactLocalized => write!(f, "compact"),
+            Scaling::Decimal(space) => write!(f, "decimal{}", if *space { "+space" } else { "" }),
+            Scaling::Exponential { base, step, prefixes: _ } => write!(f, "exponential:{base}:{step}"),
+            Scaling::None => write!(f, "none"),
+            Scaling::Percent(space) => write!(f, "percent{}", if *space { "+space" } else { "" }),
+            Scaling::Scientific => write!(f, "scientific"),
+            Scaling::Strategy(_) => write!(f, "strategy"),
+        };
+    }
+}
+
+
+impl PartialEq for Scaling
+{
+    /// # Summary
+    /// Compares scalings variant by variant. Strategies cannot be compared by their logic, so they are equal only if they are the same shared strategy, like clones of the same `Arc`.
+    ///
+    /// # Arguments
+    /// - `other`: the scaling to compare with
+    ///
+    /// # Returns
+    /// - whether both scalings are the same
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ScaleChoice, ScaleStrategy, Scaling};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// struct Unscaled;
+    ///
+    /// impl ScaleStrategy for Unscaled
+    /// {
+    ///     fn choose(&self, _x: f64) -> ScaleChoice
+    ///     {
+    ///         return ScaleChoice { divisor: 1.0, suffix: "".into(), decimals_hint: None };
+    ///     }
+    /// }
+    ///
+    /// let strategy: Arc<Unscaled> = Arc::new(Unscaled);
+    /// assert_eq!(Scaling::Strategy(strategy.clone()), Scaling::Strategy(strategy)); // same strategy
+    /// assert_ne!(Scaling::Strategy(Arc::new(Unscaled)), Scaling::Strategy(Arc::new(Unscaled))); // copies
+    /// assert_eq!(Scaling::Decimal(true), Scaling::Decimal(true));
+    /// ```
+    fn eq(&self, other: &Self) -> bool
+    {
+        return match (self, other)
+        {
+            (Scaling::Binary(a), Scaling::Binary(b)) | (Scaling::Decimal(a), Scaling::Decimal(b)) | (Scaling::Percent(a), Scaling::Percent(b)) => a == b,
+            (Scaling::CompactLocalized, Scaling::CompactLocalized) | (Scaling::None, Scaling::None) | (Scaling::Scientific, Scaling::Scientific) => true,
+            (Scaling::Exponential { base: a_base, step: a_step, prefixes: a_prefixes }, Scaling::Exponential { base: b_base, step: b_step, prefixes: b_prefixes }) => a_base == b_base && a_step == b_step && a_prefixes == b_prefixes,
+            (Scaling::Strategy(a), Scaling::Strategy(b)) => std::ptr::addr_eq(Arc::as_ptr(a), Arc::as_ptr(b)), // same allocation, regardless of vtable
+            _ => false,
+        };
+    }
+}
+
+
+impl Eq for Scaling {}
+
+
+impl std::str::FromStr for Scaling
+{
+    type Err = ParseOptionError;
+
+    /// # Summary
+    /// Parses the string form written by `Display`, for example from environment variables or command line flags. Case and surrounding whitespace are ignored, "bin", "dec", "exp", "pct", and "sci" are accepted as abbreviations. Exponential scaling is parsed without unit prefix table.
+    ///
+    /// # Arguments
+    /// - `s`: the string form, "binary", "compact", "decimal", "none", "percent", or "scientific", for binary, decimal, and percent optionally with "+space", or "exponential:" with base and step
+    ///
+    /// # Returns
+    /// - the scaling
+    /// - or `ParseOptionError` listing the valid string forms
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::Scaling;
+    /// assert_eq!("binary+space".parse::<Scaling>(), Ok(Scaling::Binary(true)));
+    /// assert_eq!("DEC".parse::<Scaling>(), Ok(Scaling::Decimal(false)));
+    /// assert_eq!("sci".parse::<Scaling>(), Ok(Scaling::Scientific));
+    /// assert_eq!("percent+space".parse::<Scaling>(), Ok(Scaling::Percent(true)));
+    /// assert_eq!("exp:60:1".parse::<Scaling>(), Ok(Scaling::Exponential { base: 60, step: 1, prefixes: None }));
+    /// assert_eq!(
+    ///     "none+space".parse::<Scaling>().unwrap_err().to_string(),
+    ///     "Invalid scaling \"none+space\", expected \"binary\", \"compact\", \"decimal\", \"none\", \"percent\", or \"scientific\", \"+space\" appended to binary, decimal, and percent for space before the unit prefix or \"%\", \"exponential:\" with base and step like \"exponential:16:1\", or \"bin\", \"dec\", \"exp\", \"pct\", and \"sci\" for short."
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let lowercase: String = s.trim().to_ascii_lowercase(); // case insensitive
+        let (kind, space) = match lowercase.strip_suffix("+space")
+        {
+            Some(kind) => (kind, true),
+            None => (lowercase.as_str(), false),
+        };
+        let exponential: Option<(u32, u32)> = match kind.split(':').collect::<Vec<&str>>().as_slice()
+        {
+            ["exp" | "exponential", base, step] => base.parse().ok().zip(step.parse().ok()),
+            _ => None,
+        }; // base and step, if exponential
+
+
+        return match (kind, space)
+        {
+            ("bin" | "binary", _) => Ok(Scaling::Binary(space)),
+            ("compact", false) => Ok(Scaling::CompactLocalized),
+            ("dec" | "decimal", _) => Ok(Scaling::Decimal(space)),
+            ("none", false) => Ok(Scaling::None),
+            ("pct" | "percent", _) => Ok(Scaling::Percent(space)),
+            ("sci" | "scientific", false) => Ok(Scaling::Scientific),
+            (_, false) if exponential.is_some() =>
+            {
+                let (base, step): (u32, u32) = exponential.expect("Exponential scaling is missing even though it has just been checked.");
+                Ok(Scaling::Exponential { base, step, prefixes: None })
+            }
+            _ => Err(ParseOptionError {
+                option: "scaling",
+                valid:  "\"binary\", \"compact\", \"decimal\", \"none\", \"percent\", or \"scientific\", \"+space\" appended to binary, decimal, and percent for space before the unit prefix or \"%\", \"exponential:\" with base and step like \"exponential:16:1\", or \"bin\", \"dec\", \"exp\", \"pct\", and \"sci\" for short",
+                value:  s.to_owned(),
+            }),
+        };
+    }
+}
+
+
+impl std::fmt::Display for Sign
+{
+    /// # Summary
+    /// Writes the string form parsed by `Sign::from_str`, the variant in snake case.
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(scaler::Sign::OnlyMinus.to_string(), "only_minus");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            Sign::Always => write!(f, "always"),
+            Sign::ExceptZero => write!(f, "except_zero"),
+            Sign::OnlyMinus => write!(f, "only_minus"),
+            Sign::SpaceForPositive => write!(f, "space_for_positive"),
+        };
+    }
+}
+
+
+impl std::str::FromStr for Sign
+{
+    type Err = ParseOptionError;
+
+    /// # Summary
+    /// Parses the string form written by `Display`, for example from environment variables or command line flags. Case and surrounding whitespace are ignored, "-" is accepted instead of "_".
+    ///
+    /// # Arguments
+    /// - `s`: the string form, "always", "except_zero", "only_minus", or "space_for_positive"
+    ///
+    /// # Returns
+    /// - the sign behaviour
+    /// - or `ParseOptionError` listing the valid string forms
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::Sign;
+    /// assert_eq!("always".parse::<Sign>(), Ok(Sign::Always));
+    /// assert_eq!("Space-For-Positive".parse::<Sign>(), Ok(Sign::SpaceForPositive));
+    /// assert_eq!("except-zero".parse::<Sign>(), Ok(Sign::ExceptZero));
+    /// assert_eq!("never".parse::<Sign>().unwrap_err().to_string(), "Invalid sign \"never\", expected \"always\", \"except_zero\", \"only_minus\", or \"space_for_positive\".");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        return match s.trim().to_ascii_lowercase().replace('-', "_").as_str()
+        {
+            "always" => Ok(Sign::Always),
+            "except_zero" => Ok(Sign::ExceptZero),
+            "only_minus" => Ok(Sign::OnlyMinus),
+            "space_for_positive" => Ok(Sign::SpaceForPositive),
+            _ => Err(ParseOptionError { option: "sign", valid: "\"always\", \"except_zero\", \"only_minus\", or \"space_for_positive\"", value: s.to_owned() }),
+        };
+    }
+}
+
+
+impl std::fmt::Display for ParseOptionError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return write!(f, "Invalid {} {:?}, expected {}.", self.option, self.value, self.valid);
+    }
+}
+
+
+impl std::error::Error for ParseOptionError {}
@@ -0,0 +1,512 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Options to override for single calls of `Formatter::format_overridden`, every option that is none keeps the formatter's setting. Texts are borrowed for the whole program, so overrides borrow no heap data and applying them never allocates. Construct with a struct expression based on `Overrides::new`. As a scaling may own a shared `ScaleStrategy`, such an expression cannot be evaluated at compile time; overrides used in many places can be stored in a `std::sync::LazyLock`.
+///
+/// # Examples
+/// ```
+/// static PRECISE: std::sync::LazyLock<scaler::Overrides> = std::sync::LazyLock::new(|| scaler::Overrides {
+///     rounding: Some(scaler::Rounding::SignificantDigits(6)),
+///     unit: Some(("m", "")),
+///     ..scaler::Overrides::new()
+/// });
+/// assert_eq!(scaler::Formatter::new().format_overridden(1234.5678, &PRECISE), "1,23457 km");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Overrides
+{
+    pub affixes:            Option<(&'static str, &'static str)>, // prefix and suffix like `Formatter::set_affixes`
+    pub machine_readable:   Option<bool>,
+    pub min_integer_digits: Option<usize>,
+    pub rounding:           Option<Rounding>,
+    pub scaling:            Option<Scaling>,
+    pub separators:         Option<(&'static str, &'static str)>, // group and decimal separator like `Formatter::set_separators`, not checked for problems
+    pub sign:               Option<Sign>,
+    pub sign_placement:     Option<SignPlacement>,
+    pub sign_position:      Option<SignPosition>,
+    pub special_strings:    Option<(&'static str, &'static str)>, // infinity and not a number like `Formatter::set_special_strings`
+    pub trailing_zeros:     Option<bool>,
+    pub unit:               Option<(&'static str, &'static str)>, // singular and plural like `Formatter::set_unit_plural`, empty plural if the same as the singular
+    pub width:              Option<(usize, Align, char)>, // width, alignment, and fill character like `Formatter::set_width`
+    pub zero_exponent:      Option<bool>,
+    pub zero_pad:           Option<(usize, bool)>, // width and whether grouped like `Formatter::set_zero_pad`
+}
+
+
+impl Overrides
+{
+    /// # Summary
+    /// Constructs overrides that override nothing.
+    ///
+    /// # Returns
+    /// - Overrides
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_overridden(42069, &scaler::Overrides::new()), f.format(42069));
+    /// ```
+    pub const fn new() -> Self
+    {
+        return Self {
+            affixes:            None,
+            machine_readable:   None,
+            min_integer_digits: None,
+            rounding:           None,
+            scaling:            None,
+            separators:         None,
+            sign:               None,
+            sign_placement:     None,
+            sign_position:      None,
+            special_strings:    None,
+            trailing_zeros:     None,
+            unit:               None,
+            width:              None,
+            zero_exponent:      None,
+            zero_pad:           None,
+        };
+    }
+}
+
+
+impl Default for Overrides
+{
+    /// # Summary
+    /// Constructs overrides that override nothing, same as `Overrides::new`.
+    ///
+    /// # Returns
+    /// - default Overrides
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+/// # Summary
+/// Partial formatter configuration, for settings layered from several sources like built-in defaults, a settings file, and per-widget overrides. Every option that is none keeps the setting of the formatter it is applied to with `Formatter::apply`, and layers are combined with `FormatterOverrides::merge`. Unlike `Overrides`, texts are owned, so overrides can be read from configuration files with the feature `serde`, where every field is optional and the rounding can also be given as `decimals` or `significant_digits`, like `decimals = 2`.
+///
+/// # Examples
+/// ```
+/// let defaults: scaler::FormatterOverrides = scaler::FormatterOverrides { unit: Some("B".to_string()), ..Default::default() };
+/// let user: scaler::FormatterOverrides = scaler::FormatterOverrides { scaling: Some(scaler::Scaling::Binary(true)), ..Default::default() };
+/// let widget: scaler::FormatterOverrides = scaler::FormatterOverrides { rounding: Some(scaler::Rounding::SignificantDigits(2)), ..Default::default() };
+/// let f: scaler::Formatter = scaler::Formatter::new().apply(&defaults.merge(user).merge(widget));
+/// assert_eq!(f.format(1536), "1,5 KiB");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FormatterOverrides
+{
+    pub affix_prefix:       Option<String>,
+    pub affix_suffix:       Option<String>,
+    pub align:              Option<Align>,
+    pub decimal_separator:  Option<String>,
+    pub fill:               Option<char>,
+    pub group_separator:    Option<String>,
+    pub infinity:           Option<String>,
+    pub machine_readable:   Option<bool>,
+    pub min_integer_digits: Option<usize>,
+    pub nan:                Option<String>,
+    pub none_string:        Option<String>, // placeholder for missing numbers
+    pub rounding:           Option<Rounding>,
+    pub scaling:            Option<Scaling>,
+    pub sign:               Option<Sign>,
+    pub sign_placement:     Option<SignPlacement>,
+    pub sign_position:      Option<SignPosition>,
+    pub trailing_zeros:     Option<bool>,
+    pub unit:               Option<String>, // overriding the unit without its plural clears the plural, like `Formatter::set_unit`
+    pub unit_plural:        Option<String>,
+    pub width:              Option<usize>,
+    pub zero_exponent:      Option<bool>,
+    pub zero_pad:           Option<usize>,
+    pub zero_pad_grouped:   Option<bool>,
+}
+
+
+impl FormatterOverrides
+{
+    /// # Summary
+    /// Layers other overrides on top of these, option by option. Where both set an option, the other overrides win, where only one does, its setting is kept. The unit and its plural belong together, so if the other overrides set the unit, their plural is used even if they set none.
+    ///
+    /// # Arguments
+    /// - `other`: the overrides to layer on top, taking precedence
+    ///
+    /// # Returns
+    /// - the combined overrides
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{FormatterOverrides, Rounding, Sign};
+    /// let file: FormatterOverrides = FormatterOverrides { rounding: Some(Rounding::Magnitude(-2)), sign: Some(Sign::Always), ..Default::default() };
+    /// let widget: FormatterOverrides = FormatterOverrides { rounding: Some(Rounding::SignificantDigits(3)), ..Default::default() };
+    /// let merged: FormatterOverrides = file.merge(widget);
+    /// assert_eq!(merged.rounding, Some(Rounding::SignificantDigits(3))); // right-hand side wins
+    /// assert_eq!(merged.sign, Some(Sign::Always)); // kept from the left-hand side
+    /// ```
+    pub fn merge(self, other: FormatterOverrides) -> Self
+    {
+        return Self {
+            affix_prefix:       other.affix_prefix.or(self.affix_prefix),
+            affix_suffix:       other.affix_suffix.or(self.affix_suffix),
+            align:              other.align.or(self.align),
+            decimal_separator:  other.decimal_separator.or(self.decimal_separator),
+            fill:               other.fill.or(self.fill),
+            group_separator:    other.group_separator.or(self.group_separator),
+            infinity:           other.infinity.or(self.infinity),
+            machine_readable:   other.machine_readable.or(self.machine_readable),
+            min_integer_digits: other.min_integer_digits.or(self.min_integer_digits),
+            nan:                other.nan.or(self.nan),
+            none_string:        other.none_string.or(self.none_string),
+            rounding:           other.rounding.or(self.rounding),
+            scaling:            other.scaling.or(self.scaling),
+            sign:               other.sign.or(self.sign),
+            sign_placement:     other.sign_placement.or(self.sign_placement),
+            sign_position:      other.sign_position.or(self.sign_position),
+            trailing_zeros:     other.trailing_zeros.or(self.trailing_zeros),
+            unit_plural:        if other.unit.is_some() { other.unit_plural } else { other.unit_plural.or(self.unit_plural) }, // plural of another unit does not fit
+            unit:               other.unit.or(self.unit),
+            width:              other.width.or(self.width),
+            zero_exponent:      other.zero_exponent.or(self.zero_exponent),
+            zero_pad:           other.zero_pad.or(self.zero_pad),
+            zero_pad_grouped:   other.zero_pad_grouped.or(self.zero_pad_grouped),
+        };
+    }
+}
+
+
+/// # Summary
+/// Formats a number with a one-off formatter configured by a closure, for a single call site that does not need a formatter variable. The closure receives `Formatter::new` and returns the formatter to format with.
+///
+/// # Arguments
+/// - `x`: the number to format
+///     - must be losslessly convertable to f64, references are accepted as well
+///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+/// - `configure`: receives a formatter with default settings and returns it configured
+///
+/// # Returns
+/// - the formatted number
+///
+/// # Examples
+/// ```
+/// use scaler::{Rounding, Scaling};
+/// assert_eq!(scaler::format_with(123456.0, |f| f.set_rounding(Rounding::SignificantDigits(2)).set_scaling(Scaling::None)), "120.000");
+/// assert_eq!(scaler::format_with(42069, |f| f), "42,07 k"); // unchanged defaults
+/// ```
+pub fn format_with<T, F>(x: T, configure: F) -> String
+where
+    T: IntoF64,
+    F: FnOnce(Formatter) -> Formatter,
+{
+    return configure(Formatter::new()).format(x);
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Constructs a formatter with the settings of this one, overridden by every option the overrides set, like calling the setters for them. Separators are set together with `Formatter::set_separators`, so problematic combinations are warned about.
+    ///
+    /// # Arguments
+    /// - `overrides`: options to override, none keeps the setting of this formatter
+    ///
+    /// # Returns
+    /// - the formatter with the overrides applied
+    ///
+    /// # Examples
+    /// ```
+    /// let base: scaler::Formatter = scaler::Formatter::new().set_unit_plural("byte", "bytes");
+    /// let overrides: scaler::FormatterOverrides = scaler::FormatterOverrides {
+    ///     decimal_separator: Some(".".to_string()),
+    ///     group_separator: Some(",".to_string()),
+    ///     scaling: Some(scaler::Scaling::None),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(base.apply(&overrides).format(1234), "1,234 bytes");
+    /// assert_eq!(base.apply(&overrides), base.clone().set_separators(",", ".").set_scaling(scaler::Scaling::None));
+    /// assert_eq!(base.apply(&scaler::FormatterOverrides::default()), base); // nothing overridden
+    /// ```
+    pub fn apply(&self, overrides: &FormatterOverrides) -> Formatter
+    {
+        let mut f: Formatter = self.clone(); // shares the texts that are not overridden
+
+
+        if let Some(prefix) = &overrides.affix_prefix
+        {
+            set_text(&mut f.affix_prefix, prefix);
+        }
+        if let Some(suffix) = &overrides.affix_suffix
+        {
+            set_text(&mut f.affix_suffix, suffix);
+        }
+        if let Some(align) = &overrides.align
+        {
+            f.align = align.clone();
+        }
+        if overrides.decimal_separator.is_some() || overrides.group_separator.is_some()
+        // checked for problems together
+        {
+            f = f.set_separators(
+                overrides.group_separator.as_deref().unwrap_or(&self.group_separator),
+                overrides.decimal_separator.as_deref().unwrap_or(&self.decimal_separator),
+            );
+        }
+        if let Some(fill) = overrides.fill
+        {
+            f.fill = fill;
+        }
+        if let Some(infinity) = &overrides.infinity
+        {
+            set_text(&mut f.special_strings.0, infinity);
+        }
+        if let Some(machine_readable) = overrides.machine_readable
+        {
+            f.machine_readable = machine_readable;
+        }
+        if let Some(min_integer_digits) = overrides.min_integer_digits
+        {
+            f.min_integer_digits = min_integer_digits;
+        }
+        if let Some(nan) = &overrides.nan
+        {
+            set_text(&mut f.special_strings.1, nan);
+        }
+        if let Some(none_string) = &overrides.none_string
+        {
+            set_text(&mut f.none_string, none_string);
+        }
+        if let Some(rounding) = &overrides.rounding
+        {
+            f.rounding = rounding.clone();
+        }
+        if let Some(scaling) = &overrides.scaling
+        {
+            f.scaling = scaling.clone();
+        }
+        if let Some(sign) = &overrides.sign
+        {
+            f.sign = sign.clone();
+        }
+        if let Some(sign_placement) = &overrides.sign_placement
+        {
+            f.sign_placement = sign_placement.clone();
+        }
+        if let Some(sign_position) = &overrides.sign_position
+        {
+            f.sign_position = sign_position.clone();
+        }
+        if let Some(trailing_zeros) = overrides.trailing_zeros
+        {
+            f.trailing_zeros = trailing_zeros;
+        }
+        if let Some(unit) = &overrides.unit
+        // plural of the previous unit does not fit
+        {
+            f = f.set_unit(unit);
+        }
+        if let Some(unit_plural) = &overrides.unit_plural
+        {
+            set_text(&mut f.unit_plural, unit_plural);
+        }
+        if let Some(width) = overrides.width
+        {
+            f.width = width;
+        }
+        if let Some(zero_exponent) = overrides.zero_exponent
+        {
+            f.zero_exponent = zero_exponent;
+        }
+        if let Some(zero_pad) = overrides.zero_pad
+        {
+            f.zero_pad = zero_pad;
+        }
+        if let Some(zero_pad_grouped) = overrides.zero_pad_grouped
+        {
+            f.zero_pad_grouped = zero_pad_grouped;
+        }
+
+        return f;
+    }
+
+
+    /// # Summary
+    /// Formats a number with this formatter tweaked by a closure, so an app-wide base configuration can be adjusted per call. The closure receives a clone, this formatter stays unchanged.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be losslessly convertable to f64, references are accepted as well
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `configure`: receives a clone of this formatter and returns it configured
+    ///
+    /// # Returns
+    /// - the formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let base: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators(",", ".")
+    ///     .set_unit("B");
+    /// assert_eq!(base.format_with_overrides(1536, |f| f.set_scaling(scaler::Scaling::Binary(true))), "1.500 KiB");
+    /// assert_eq!(base.format(1536), "1.536 kB"); // base unchanged
+    /// ```
+    pub fn format_with_overrides<T, F>(&self, x: T, configure: F) -> String
+    where
+        T: IntoF64,
+        F: FnOnce(Formatter) -> Formatter,
+    {
+        return configure(self.clone()).format(x);
+    }
+
+
+    /// # Summary
+    /// Formats a number like `Formatter::format`, but with other options for this call only, like `Formatter::format_with_overrides` with the setters for every overridden option. Cloning this formatter does not allocate, as its texts are shared, and overridden texts are borrowed, so only the formatted number is allocated.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be losslessly convertable to f64, references are accepted as well
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `overrides`: options to override, none keeps the setting of this formatter
+    ///
+    /// # Returns
+    /// - the formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let base: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators(",", ".")
+    ///     .set_unit("B");
+    /// let overrides: scaler::Overrides = scaler::Overrides {
+    ///     scaling: Some(scaler::Scaling::Binary(true)),
+    ///     sign: Some(scaler::Sign::Always),
+    ///     ..scaler::Overrides::new()
+    /// };
+    /// assert_eq!(base.format_overridden(1536, &overrides), "+1.500 KiB");
+    /// assert_eq!(base.format_overridden(1536, &overrides), base.clone().set_scaling(scaler::Scaling::Binary(true)).set_sign(scaler::Sign::Always).format(1536));
+    /// assert_eq!(base.format(1536), "1.536 kB"); // base unchanged
+    /// ```
+    pub fn format_overridden<T>(&self, x: T, overrides: &Overrides) -> String
+    where
+        T: IntoF64,
+    {
+        let mut f: Formatter = self.clone(); // shares the texts, does not allocate
+
+
+        if let Some((prefix, suffix)) = overrides.affixes
+        {
+            (f.affix_prefix, f.affix_suffix) = (Text::Static(prefix), Text::Static(suffix));
+        }
+        if let Some(machine_readable) = overrides.machine_readable
+        {
+            f.machine_readable = machine_readable;
+        }
+        if let Some(min_integer_digits) = overrides.min_integer_digits
+        {
+            f.min_integer_digits = min_integer_digits;
+        }
+        if let Some(rounding) = &overrides.rounding
+        {
+            f.rounding = rounding.clone();
+        }
+        if let Some(scaling) = &overrides.scaling
+        {
+            f.scaling = scaling.clone();
+        }
+        if let Some((group_separator, decimal_separator)) = overrides.separators
+        {
+            (f.group_separator, f.decimal_separator) = (Text::Static(group_separator), Text::Static(decimal_separator));
+        }
+        if let Some(sign) = &overrides.sign
+        {
+            f.sign = sign.clone();
+        }
+        if let Some(sign_placement) = &overrides.sign_placement
+        {
+            f.sign_placement = sign_placement.clone();
+        }
+        if let Some(sign_position) = &overrides.sign_position
+        {
+            f.sign_position = sign_position.clone();
+        }
+        if let Some((infinity, nan)) = overrides.special_strings
+        {
+            f.special_strings = (Text::Static(infinity), Text::Static(nan));
+        }
+        if let Some(trailing_zeros) = overrides.trailing_zeros
+        {
+            f.trailing_zeros = trailing_zeros;
+        }
+        if let Some((singular, plural)) = overrides.unit
+        {
+            (f.unit, f.unit_plural) = (Text::Static(singular), Text::Static(plural));
+        }
+        if let Some((width, align, fill)) = &overrides.width
+        {
+            (f.width, f.align, f.fill) = (*width, align.clone(), *fill);
+        }
+        if let Some(zero_exponent) = overrides.zero_exponent
+        {
+            f.zero_exponent = zero_exponent;
+        }
+        if let Some((zero_pad, grouped)) = overrides.zero_pad
+        {
+            (f.zero_pad, f.zero_pad_grouped) = (zero_pad, grouped);
+        }
+
+        return f.format(x);
+    }
+
+
+    /// # Summary
+    /// Formats a number like `Formatter::format`, but with another rounding for this call only, like `Formatter::format_overridden` with only the rounding overridden.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be losslessly convertable to f64, references are accepted as well
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `rounding`: the rounding for this call
+    ///
+    /// # Returns
+    /// - the formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_with_rounding(1234.5678, scaler::Rounding::SignificantDigits(6)), "1,23457 k");
+    /// assert_eq!(f.format_with_rounding(1234.5678, scaler::Rounding::Magnitude(0)), f.clone().set_rounding(scaler::Rounding::Magnitude(0)).format(1234.5678));
+    /// assert_eq!(f.format(1234.5678), "1,235 k"); // unchanged
+    /// ```
+    pub fn format_with_rounding<T>(&self, x: T, rounding: Rounding) -> String
+    where
+        T: IntoF64,
+    {
+        return Formatter { rounding, ..self.clone() }.format(x);
+    }
+
+
+    /// # Summary
+    /// Formats a number like `Formatter::format`, but with another scaling for this call only, like `Formatter::format_overridden` with only the scaling overridden.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be losslessly convertable to f64, references are accepted as well
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    /// - `scaling`: the scaling for this call
+    ///
+    /// # Returns
+    /// - the formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_unit("B");
+    /// assert_eq!(f.format_with_scaling(1536, scaler::Scaling::Binary(true)), "1,500 KiB");
+    /// assert_eq!(f.format_with_scaling(1536, scaler::Scaling::None), "1.536 B");
+    /// assert_eq!(f.format(1536), "1,536 kB"); // unchanged
+    /// ```
+    pub fn format_with_scaling<T>(&self, x: T, scaling: Scaling) -> String
+    where
+        T: IntoF64,
+    {
+        return Formatter { scaling, ..self.clone() }.format(x);
+    }
+}
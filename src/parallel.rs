@@ -0,0 +1,86 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+use rayon::prelude::*;
+
+
+/// number of values every task of `Formatter::par_format_into_writer` formats into one scratch buffer, large enough to outweigh scheduling, small enough to balance load
+const CHUNK_LEN: usize = 4096;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats every number of a slice like `Formatter::format_slice`, but in parallel on the rayon thread pool. The result is in input order and identical to `Formatter::format_slice`.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    ///
+    /// # Returns
+    /// - the formatted numbers in input order
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let values: Vec<f64> = (0..100_000).map(|i| (i as f64 - 50_000.0) * 1.37e3_f64.powi(i % 7)).collect();
+    /// assert_eq!(f.par_format_slice(&values), f.format_slice(&values));
+    /// ```
+    pub fn par_format_slice(&self, values: &[f64]) -> Vec<String>
+    {
+        return values.par_iter().map(|x| self.format(x)).collect();
+    }
+
+
+    /// # Summary
+    /// Formats every number of a slice and writes it followed by `separator` into a byte sink, for example a column of a CSV export. Chunks of numbers are formatted in parallel on the rayon thread pool into scratch buffers that are reused for the whole slice, then written in input order. The output is identical to writing `Formatter::format` and `separator` for every number in order.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    /// - `separator`: written after every number, like "\n"
+    /// - `out`: the sink to write into
+    ///
+    /// # Returns
+    /// - nothing or the error of the sink, what was written before stays written
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut csv: Vec<u8> = Vec::new();
+    /// f.par_format_into_writer(&[1.0, 1234.5, -0.5], ";", &mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "1,000;1,234 k;-500,0 m;");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let values: Vec<f64> = (0..100_000).map(|i| (i as f64 - 50_000.0) * 1.37e3_f64.powi(i % 7)).collect();
+    /// let mut out: Vec<u8> = Vec::new();
+    /// f.par_format_into_writer(&values, "\n", &mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), values.iter().map(|x| f.format(x) + "\n").collect::<String>());
+    /// ```
+    pub fn par_format_into_writer<W>(&self, values: &[f64], separator: &str, out: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write + ?Sized,
+    {
+        let mut buffers: Vec<String> = vec![String::new(); rayon::current_num_threads() * 4]; // scratch buffer per chunk of a batch, several per thread to balance load
+
+
+        for batch in values.chunks(CHUNK_LEN * buffers.len())
+        // format a batch of chunks in parallel, then write them in order
+        {
+            buffers.par_iter_mut().zip(batch.par_chunks(CHUNK_LEN)).for_each(|(buffer, chunk)| {
+                buffer.clear(); // keeps capacity from previous batches
+                for x in chunk
+                {
+                    self.expect_accepted(*x);
+                    self.format_into(*x, buffer).expect("Writing into a String failed even though it is infallible.");
+                    buffer.push_str(separator);
+                }
+            });
+            for buffer in buffers.iter().take(batch.len().div_ceil(CHUNK_LEN))
+            {
+                out.write_all(buffer.as_bytes())?;
+            }
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Iterator that parses the fields of a delimited list of formatted numbers one by one. Created by `Formatter::parse_iter`.
+#[derive(Clone, Debug)]
+pub struct ParseIter<'a>
+{
+    delimiter:  char,
+    field:      usize,         // index of the next field
+    parser:     Parser,
+    position:   Option<usize>, // start of the next field, none if exhausted
+    s:          &'a str,
+    skip_empty: bool,
+}
+
+
+impl<'a> ParseIter<'a>
+{
+    /// # Summary
+    /// Sets whether fields that are empty or only whitespace are skipped instead of yielding `ParseErrorKind::Empty`. Skipped fields still count for the field index in errors.
+    ///
+    /// # Arguments
+    /// - `skip_empty`: whether to skip empty fields
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let numbers: Vec<f64> = f.parse_iter("1; ; 2;", ';')
+    ///     .set_skip_empty(true)
+    ///     .collect::<Result<Vec<f64>, scaler::ParseError>>()
+    ///     .unwrap();
+    /// assert_eq!(numbers, vec![1.0, 2.0]);
+    /// ```
+    pub fn set_skip_empty(mut self, skip_empty: bool) -> Self
+    {
+        self.skip_empty = skip_empty;
+        return self;
+    }
+}
+
+
+impl Iterator for ParseIter<'_>
+{
+    type Item = Result<f64, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        loop
+        {
+            let field_end: usize; // end of the field, at the delimiter or end of string
+            let field_start: usize = self.position?; // start of the field
+            let number_end: usize; // end of the number before trailing whitespace
+            let number_start: usize; // start of the number after leading whitespace
+
+
+            match self.s[field_start..].find(self.delimiter)
+            {
+                Some(offset) =>
+                {
+                    field_end = field_start + offset;
+                    self.position = Some(field_end + self.delimiter.len_utf8());
+                }
+                None =>
+                {
+                    field_end = self.s.len();
+                    self.position = None;
+                }
+            }
+            number_start = field_end - self.s[field_start..field_end].trim_start().len();
+            number_end = field_start + self.s[field_start..field_end].trim_end().len().max(number_start - field_start);
+            self.field += 1;
+
+            if self.skip_empty && number_start == number_end
+            {
+                continue;
+            }
+            return Some(self.parser.parse_number(self.s, number_start, number_end, false, 0).map_err(|e| ParseError { field: Some(self.field - 1), ..e }));
+        }
+    }
+}
+
+
+impl std::iter::FusedIterator for ParseIter<'_> {}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Parses a list of formatted numbers separated by `delimiter`, like `Formatter::parse` for each field. Whitespace around each field is skipped, so line breaks including "\r\n" work with '\n' as delimiter. An empty field yields `ParseErrorKind::Empty` instead of being skipped, so a missing value is noticed, unless `ParseIter::set_skip_empty` says otherwise. The delimiter must not occur in the formatted numbers, for example ',' with "," as decimal separator.
+    ///
+    /// # Arguments
+    /// - `s`: the list
+    /// - `delimiter`: the character between fields
+    ///
+    /// # Returns
+    /// - iterator over the parsed fields, errors contain the field index and positions refer to `s`
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let numbers: Vec<Result<f64, scaler::ParseError>> = f.parse_iter("1,5 k; 2,3 M; 800", ';').collect();
+    /// assert_eq!(numbers, vec![Ok(1500.0), Ok(2.3e6), Ok(800.0)]);
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut numbers: scaler::ParseIter = f.parse_iter("1;;x;4;", ';'); // trailing delimiter is an empty last field
+    /// assert_eq!(numbers.next(), Some(Ok(1.0)));
+    /// assert_eq!(numbers.next(), Some(Err(ParseError { field: Some(1), kind: ParseErrorKind::Empty, position: 2 })));
+    /// assert_eq!(numbers.next(), Some(Err(ParseError { field: Some(2), kind: ParseErrorKind::NoDigits, position: 3 })));
+    /// assert_eq!(numbers.next(), Some(Ok(4.0)));
+    /// assert_eq!(numbers.next(), Some(Err(ParseError { field: Some(4), kind: ParseErrorKind::Empty, position: 7 })));
+    /// assert_eq!(numbers.next(), None);
+    /// assert_eq!(numbers.next(), None);
+    /// assert_eq!(f.parse_iter("", ';').collect::<Vec<_>>(), vec![Err(ParseError { field: Some(0), kind: ParseErrorKind::Empty, position: 0 })]);
+    /// assert_eq!(f.parse_iter("", ';').set_skip_empty(true).count(), 0);
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators(",", ".");
+    /// let numbers: Vec<f64> = f.parse_iter("1.5 k\r\n-2,000\r\n3 µ\r\n", '\n')
+    ///     .set_skip_empty(true)
+    ///     .collect::<Result<Vec<f64>, scaler::ParseError>>()
+    ///     .unwrap();
+    /// assert_eq!(numbers, vec![1500.0, -2000.0, 3e-6]);
+    /// ```
+    pub fn parse_iter<'a>(&'a self, s: &'a str, delimiter: char) -> ParseIter<'a>
+    {
+        return ParseIter { delimiter, field: 0, parser: self.parser(), position: Some(0), s, skip_empty: false };
+    }
+
+
+    /// # Summary
+    /// Parses a list of formatted numbers like `Formatter::parse_iter` and collects them, stopping at the first error.
+    ///
+    /// # Arguments
+    /// - `s`: the list
+    /// - `delimiter`: the character between fields
+    ///
+    /// # Returns
+    /// - all numbers
+    /// - or the first `ParseError`, with field index and position in `s`
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.parse_all(" 1,5 k ; 2,3 M;800 ", ';'), Ok(vec![1500.0, 2.3e6, 800.0]));
+    /// assert_eq!(f.parse_all("1\r\n2\r\n", '\n'), Err(ParseError { field: Some(2), kind: ParseErrorKind::Empty, position: 6 }));
+    /// assert_eq!(f.parse_all("1 k; 2 parsecs; x", ';'), Err(ParseError { field: Some(1), kind: ParseErrorKind::UnknownPrefix("parsecs".to_string()), position: 7 }));
+    /// assert_eq!(f.parse_all("1 k; 2 parsecs; x", ';').unwrap_err().to_string(), "Field 1: Unknown unit prefix \"parsecs\" at position 7.");
+    /// ```
+    pub fn parse_all(&self, s: &str, delimiter: char) -> Result<Vec<f64>, ParseError>
+    {
+        return self.parse_iter(s, delimiter).collect();
+    }
+}
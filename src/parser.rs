@@ -0,0 +1,579 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::{BINARY_PREFIXES, DECIMAL_PREFIXES};
+use crate::*;
+use std::sync::OnceLock;
+
+
+/// # Summary
+/// Parses numbers with the settings of a formatter that matter for parsing: separators, affixes, sign, special strings, unit, and the base of exponential scaling, plus which unit prefixes are accepted and whether parsing is lenient. The unit prefix tables are compiled once and shared, so parsers are cheap to create and clone, and a parser can be reused in hot loops without deriving anything per call. Created by `Formatter::parser` or `ParserBuilder::build`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parser
+{
+    pub(crate) affix_prefix:      Text,
+    pub(crate) affix_suffix:      Text,
+    pub(crate) decimal_separator: Text,
+    pub(crate) exponent_base:     u32,               // base of scientific notation understood besides 10 and 2, that of exponential scaling
+    pub(crate) group_separator:   Text,
+    pub(crate) lenient:           bool,              // whether `Parser::parse`, the ratio, and the integer parsers tolerate input like `Parser::parse_lenient`
+    pub(crate) max_len:           usize,             // longest number `Parser::parse_prefix` tries
+    pub(crate) percent:           bool,              // whether `Parser::parse` and `Parser::parse_lenient` parse ratios like `Parser::parse_percent`, for percent scaling
+    pub(crate) prefixes:          &'static Prefixes, // accepted unit prefixes
+    pub(crate) sign:              Sign,
+    pub(crate) special_strings:   (Text, Text), // infinity and not a number
+    pub(crate) unit:              Text,
+}
+
+
+/// # Summary
+/// Builds a parser independently of a formatter, with only the settings that matter for parsing, validated in `ParserBuilder::build`. Created by `Parser::builder` or `ParserBuilder::new`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParserBuilder
+{
+    formatter: Formatter,    // settings shared with formatting so far, not validated yet
+    lenient:   bool,
+    prefixes:  (bool, bool), // whether decimal and binary unit prefixes are accepted
+}
+
+
+/// # Summary
+/// Unit prefixes a parser accepts, compiled once per combination of decimal and binary unit prefixes.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Prefixes
+{
+    pub(crate) exact:  Vec<(&'static str, u32, i32)>, // symbol, base, and exponent of every accepted unit prefix
+    pub(crate) folded: Vec<(&'static str, u32, i32)>, // those no other accepted unit prefix equals ignoring case, for lenient matching
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Creates a parser with the settings of this formatter, the same one `Formatter::parse` and its siblings use internally. It accepts decimal and binary unit prefixes and is strict. Later changes to this formatter do not affect it.
+    ///
+    /// # Returns
+    /// - the parser
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_unit("B");
+    /// let parser: scaler::Parser = f.parser();
+    /// for s in ["1,5 GiB", "-12,5 kB", "42", "1.000.000 B", "∞", "x"]
+    /// {
+    ///     assert_eq!(parser.parse(s), f.parse(s));
+    /// }
+    /// ```
+    pub fn parser(&self) -> Parser
+    {
+        return Parser::with_settings(self, false, (true, true));
+    }
+}
+
+
+impl Parser
+{
+    /// # Summary
+    /// Starts building a parser with the parsing settings of `Formatter::new`, decimal and binary unit prefixes, and strict parsing.
+    ///
+    /// # Returns
+    /// - the builder
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Parser::builder()
+    ///     .set_separators(",", ".")
+    ///     .set_lenient(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(parser.parse(" 1,234.5 k "), Ok(1234500.0));
+    /// ```
+    pub fn builder() -> ParserBuilder
+    {
+        return ParserBuilder::new();
+    }
+
+
+    /// # Summary
+    /// Parses a number like `Formatter::parse` with the settings of this parser. If the parser is lenient, parses like `Parser::parse_lenient` instead. If it was created from a formatter with percent scaling, parses ratios like `Parser::parse_percent`.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted number
+    ///
+    /// # Returns
+    /// - the number
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let parser: scaler::Parser = scaler::Formatter::new().parser();
+    /// assert_eq!(parser.parse("42,07 k"), Ok(42070.0));
+    /// assert_eq!(parser.parse(" 42,07 k"), Err(ParseError { field: None, kind: ParseErrorKind::NoDigits, position: 0 }));
+    ///
+    /// let parser: scaler::Parser = scaler::Parser::builder().set_lenient(true).build().unwrap();
+    /// assert_eq!(parser.parse(" 42,07 K"), Ok(42070.0));
+    /// ```
+    pub fn parse(&self, s: &str) -> Result<f64, ParseError>
+    {
+        if self.lenient
+        {
+            return self.parse_lenient(s);
+        }
+        if self.percent
+        {
+            return self.parse_ratio(s, 0, s.len(), false);
+        }
+        return self.parse_number(s, 0, s.len(), false, 0);
+    }
+
+
+    /// # Summary
+    /// Parses a number like `Formatter::parse_lenient` with the settings of this parser, whether the parser is lenient or not. If it was created from a formatter with percent scaling, parses ratios like `Parser::parse_percent_lenient`.
+    ///
+    /// # Arguments
+    /// - `s`: the number
+    ///
+    /// # Returns
+    /// - the number
+    /// - or `ParseError` describing what and where the problem is, positions refer to `s` including skipped whitespace
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Formatter::new().parser();
+    /// assert_eq!(parser.parse_lenient("  1,234.5\n"), Ok(1234.5));
+    /// assert_eq!(parser.parse_lenient("2 gi"), Ok(2147483648.0));
+    /// ```
+    pub fn parse_lenient(&self, s: &str) -> Result<f64, ParseError>
+    {
+        let start: usize = s.len() - s.trim_start().len(); // start of number after leading whitespace
+        let end: usize = s.trim_end().len().max(start); // end of number before trailing whitespace
+
+
+        if self.percent
+        {
+            return self.parse_ratio(s, start, end, true);
+        }
+        return self.parse_number(s, start, end, true, 0);
+    }
+
+
+    /// # Summary
+    /// Parses the longest number at the start of `s` like `Formatter::parse_prefix` with the settings of this parser. Always strict, because a lenient number could swallow the rest.
+    ///
+    /// # Arguments
+    /// - `s`: the string starting with a number
+    ///
+    /// # Returns
+    /// - the number and the rest of `s` after it
+    /// - or `ParseError` of the longest attempt if `s` does not start with a number
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Formatter::new().set_unit("B").parser();
+    /// assert_eq!(parser.parse_prefix("1,5 GiB free on /dev/sda1"), Ok((1610612736.0, " free on /dev/sda1")));
+    /// assert_eq!(parser.parse_prefix("5 Gremlins"), Ok((5.0, " Gremlins")));
+    /// ```
+    pub fn parse_prefix<'a>(&self, s: &'a str) -> Result<(f64, &'a str), ParseError>
+    {
+        let mut error: Option<ParseError> = None; // error of longest attempt
+
+
+        for end in (1..=s.len().min(self.max_len)).rev().filter(|end| s.is_char_boundary(*end))
+        // longest number first
+        {
+            let last: char = s[..end].chars().next_back().unwrap_or_default(); // last character of number candidate
+            let next: Option<char> = s[end..].chars().next(); // first character of rest
+
+
+            if last.is_whitespace() || (last.is_alphabetic() && next.is_some_and(char::is_alphabetic))
+            // whitespace belongs to the rest, a letter must not be split off a word
+            {
+                continue;
+            }
+            match self.parse_number(s, 0, end, false, 0)
+            {
+                Ok(x) => return Ok((x, &s[end..])),
+                Err(e) if error.is_none() => error = Some(e),
+                Err(_) => {}
+            }
+        }
+
+        return Err(error.unwrap_or(ParseError { field: None, kind: ParseErrorKind::Empty, position: 0 }));
+    }
+
+
+    /// # Summary
+    /// Parses a ratio like `Formatter::parse_percent` with the settings of this parser. If the parser is lenient, parses like `Parser::parse_percent_lenient` instead.
+    ///
+    /// # Arguments
+    /// - `s`: the ratio with sign
+    ///
+    /// # Returns
+    /// - the ratio as number, 0.5 for "50 %"
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Formatter::new().parser();
+    /// assert_eq!(parser.parse_percent("12,5 %"), Ok(0.125));
+    /// assert!(parser.parse_percent("12,5").is_err());
+    /// ```
+    pub fn parse_percent(&self, s: &str) -> Result<f64, ParseError>
+    {
+        if self.lenient
+        {
+            return self.parse_percent_lenient(s);
+        }
+        return self.parse_ratio(s, 0, s.len(), false);
+    }
+
+
+    /// # Summary
+    /// Parses a ratio like `Formatter::parse_percent_lenient` with the settings of this parser, whether the parser is lenient or not.
+    ///
+    /// # Arguments
+    /// - `s`: the ratio, sign optional
+    ///
+    /// # Returns
+    /// - the ratio as number
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Formatter::new().parser();
+    /// assert_eq!(parser.parse_percent_lenient(" 12.5% "), Ok(0.125));
+    /// assert_eq!(parser.parse_percent_lenient("0.5"), Ok(0.5));
+    /// ```
+    pub fn parse_percent_lenient(&self, s: &str) -> Result<f64, ParseError>
+    {
+        let start: usize = s.len() - s.trim_start().len(); // start of ratio after leading whitespace
+        let end: usize = s.trim_end().len().max(start); // end of ratio before trailing whitespace
+
+
+        return self.parse_ratio(s, start, end, true);
+    }
+
+
+    /// # Summary
+    /// Parses an integer like `Formatter::parse_i64` with the settings of this parser, leniently if the parser is lenient.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted integer
+    ///
+    /// # Returns
+    /// - the integer
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Formatter::new().parser();
+    /// assert_eq!(parser.parse_i64("-1,5 k"), Ok(-1500));
+    /// ```
+    pub fn parse_i64(&self, s: &str) -> Result<i64, ParseError>
+    {
+        let (negative, magnitude, position): (bool, u128, usize) = self.parse_integer(s, self.lenient)?;
+
+
+        return i128::try_from(magnitude)
+            .ok()
+            .map(|magnitude| if negative { -magnitude } else { magnitude })
+            .and_then(|x| i64::try_from(x).ok())
+            .ok_or(ParseError { field: None, kind: ParseErrorKind::Overflow, position });
+    }
+
+
+    /// # Summary
+    /// Parses an integer like `Formatter::parse_u128` with the settings of this parser, leniently if the parser is lenient.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted integer
+    ///
+    /// # Returns
+    /// - the integer
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Formatter::new().parser();
+    /// assert_eq!(parser.parse_u128("1 Yi"), Ok(1 << 80));
+    /// ```
+    pub fn parse_u128(&self, s: &str) -> Result<u128, ParseError>
+    {
+        let (negative, magnitude, position): (bool, u128, usize) = self.parse_integer(s, self.lenient)?;
+
+
+        if negative && magnitude != 0
+        // "-0" is still 0
+        {
+            return Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position });
+        }
+        return Ok(magnitude);
+    }
+
+
+    /// # Summary
+    /// Parses an integer like `Formatter::parse_u64` with the settings of this parser, leniently if the parser is lenient.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted integer
+    ///
+    /// # Returns
+    /// - the integer
+    /// - or `ParseError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Formatter::new().parser();
+    /// assert_eq!(parser.parse_u64("1,5 Gi"), Ok(1610612736));
+    /// assert!(parser.parse_u64("16 Ei").is_err()); // 2^64 does not fit
+    /// ```
+    pub fn parse_u64(&self, s: &str) -> Result<u64, ParseError>
+    {
+        let (negative, magnitude, position): (bool, u128, usize) = self.parse_integer(s, self.lenient)?;
+
+
+        if negative && magnitude != 0
+        // "-0" is still 0
+        {
+            return Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position });
+        }
+        return u64::try_from(magnitude).map_err(|_| ParseError { field: None, kind: ParseErrorKind::Overflow, position });
+    }
+
+
+    /// # Summary
+    /// Takes over the parsing settings of a formatter.
+    ///
+    /// # Arguments
+    /// - `formatter`: the formatter to take the settings from
+    /// - `lenient`: whether to parse leniently by default
+    /// - `prefixes`: whether decimal and binary unit prefixes are accepted
+    ///
+    /// # Returns
+    /// - the parser
+    fn with_settings(formatter: &Formatter, lenient: bool, prefixes: (bool, bool)) -> Self
+    {
+        return Self {
+            affix_prefix: formatter.affix_prefix.clone(),
+            affix_suffix: formatter.affix_suffix.clone(),
+            decimal_separator: formatter.decimal_separator.clone(),
+            exponent_base: if let Scaling::Exponential { base, .. } = formatter.scaling { base } else { 10 },
+            group_separator: formatter.group_separator.clone(),
+            lenient,
+            max_len: formatter.max_len(),
+            percent: matches!(formatter.scaling, Scaling::Percent(_)),
+            prefixes: Prefixes::compiled(prefixes),
+            sign: formatter.sign.clone(),
+            special_strings: formatter.special_strings.clone(),
+            unit: formatter.unit.clone(),
+        };
+    }
+}
+
+
+impl Default for ParserBuilder
+{
+    /// # Summary
+    /// Starts building a parser like `ParserBuilder::new`.
+    ///
+    /// # Returns
+    /// - the builder
+    fn default() -> Self
+    {
+        return Self::new();
+    }
+}
+
+
+impl ParserBuilder
+{
+    /// # Summary
+    /// Starts building a parser with the parsing settings of `Formatter::new`, decimal and binary unit prefixes, and strict parsing.
+    ///
+    /// # Returns
+    /// - the builder
+    pub fn new() -> Self
+    {
+        return Self { formatter: Formatter::new(), lenient: false, prefixes: (true, true) };
+    }
+
+
+    /// # Summary
+    /// Validates the configuration and creates the parser. The separators must follow the rules of `FormatterBuilder::build`: the decimal separator must not be empty, group and decimal separator must differ, and they must not contain digits.
+    ///
+    /// # Returns
+    /// - the parser
+    /// - or `ConfigError` of the first rule broken
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::ConfigError;
+    /// assert_eq!(scaler::Parser::builder().set_separators(".", ".").build(), Err(ConfigError::IdenticalSeparators(".".to_string())));
+    /// assert_eq!(scaler::Parser::builder().build(), Ok(scaler::Formatter::new().parser()));
+    /// ```
+    pub fn build(self) -> Result<Parser, ConfigError>
+    {
+        if let Some(problem) = separator_problem(&self.formatter.group_separator, &self.formatter.decimal_separator)
+        {
+            return Err(problem);
+        }
+
+        return Ok(Parser::with_settings(&self.formatter, self.lenient, self.prefixes));
+    }
+
+
+    /// # Summary
+    /// Sets the affixes like `Formatter::set_affixes`. They are optional when parsing.
+    pub fn set_affixes(mut self, prefix: &str, suffix: &str) -> Self
+    {
+        self.formatter = self.formatter.set_affixes(prefix, suffix);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets whether `Parser::parse`, `Parser::parse_percent`, and the integer parsers tolerate input like `Parser::parse_lenient`. `Parser::parse_prefix` is always strict.
+    ///
+    /// # Arguments
+    /// - `lenient`: whether to parse leniently
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// let parser: scaler::Parser = scaler::Parser::builder()
+    ///     .set_lenient(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(parser.parse_u64(" 1_000 "), Ok(1000));
+    /// assert_eq!(parser.parse_percent("0,5"), Ok(0.5));
+    /// ```
+    pub fn set_lenient(mut self, lenient: bool) -> Self
+    {
+        self.lenient = lenient;
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets which unit prefixes are accepted. Numbers with unit prefixes that are not accepted are rejected with `ParseErrorKind::UnknownPrefix`, so for example "1 Ki" is not silently read as 1024 where only decimal unit prefixes make sense. Scientific notation and e-notation are always accepted.
+    ///
+    /// # Arguments
+    /// - `decimal`: whether decimal unit prefixes like "k" and "µ" are accepted
+    /// - `binary`: whether binary unit prefixes like "Ki" are accepted
+    ///
+    /// # Returns
+    /// - modified self
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{ParseError, ParseErrorKind};
+    /// let parser: scaler::Parser = scaler::Parser::builder()
+    ///     .set_prefixes(true, false)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(parser.parse("1,5 k"), Ok(1500.0));
+    /// assert_eq!(parser.parse("1,5 Ki"), Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix("Ki".to_string()), position: 4 }));
+    /// assert_eq!(parser.parse("1,5E3"), Ok(1500.0));
+    ///
+    /// let parser: scaler::Parser = scaler::Parser::builder()
+    ///     .set_prefixes(false, true)
+    ///     .set_lenient(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(parser.parse("1 ki"), Ok(1024.0));
+    /// assert!(parser.parse("1 k").is_err());
+    /// ```
+    pub fn set_prefixes(mut self, decimal: bool, binary: bool) -> Self
+    {
+        self.prefixes = (decimal, binary);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the separators like `Formatter::set_separators`. Problematic separators are not warned about, `ParserBuilder::build` rejects them.
+    pub fn set_separators(mut self, group_separator: &str, decimal_separator: &str) -> Self
+    {
+        set_text(&mut self.formatter.group_separator, group_separator);
+        set_text(&mut self.formatter.decimal_separator, decimal_separator);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the sign mode like `Formatter::set_sign`. With `Sign::SpaceForPositive`, a leading space is read as positive sign.
+    pub fn set_sign(mut self, sign: Sign) -> Self
+    {
+        self.formatter = self.formatter.set_sign(sign);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the accepted strings for infinity and not a number like `Formatter::set_special_strings`. "∞" and "NaN", and "inf", "infinity", and "nan" ignoring case are accepted as well.
+    pub fn set_special_strings(mut self, infinity: &str, nan: &str) -> Self
+    {
+        self.formatter = self.formatter.set_special_strings(infinity, nan);
+        return self;
+    }
+
+
+    /// # Summary
+    /// Sets the unit like `Formatter::set_unit`. It is optional when parsing.
+    pub fn set_unit(mut self, unit: &str) -> Self
+    {
+        self.formatter = self.formatter.set_unit(unit);
+        return self;
+    }
+}
+
+
+impl Prefixes
+{
+    /// # Summary
+    /// Compiles the table of accepted unit prefixes on first use and shares it afterwards.
+    ///
+    /// # Arguments
+    /// - `prefixes`: whether decimal and binary unit prefixes are accepted
+    ///
+    /// # Returns
+    /// - the compiled table
+    fn compiled(prefixes: (bool, bool)) -> &'static Self
+    {
+        static TABLES: OnceLock<[Prefixes; 4]> = OnceLock::new(); // one per combination, index is decimal + 2 * binary
+
+
+        return &TABLES.get_or_init(|| [(false, false), (true, false), (false, true), (true, true)].map(|(decimal, binary)| Self::new(decimal, binary)))[prefixes.0 as usize + 2 * prefixes.1 as usize];
+    }
+
+
+    /// # Summary
+    /// Compiles the table of accepted unit prefixes.
+    ///
+    /// # Arguments
+    /// - `decimal`: whether decimal unit prefixes are accepted
+    /// - `binary`: whether binary unit prefixes are accepted
+    ///
+    /// # Returns
+    /// - the table
+    fn new(decimal: bool, binary: bool) -> Self
+    {
+        let exact: Vec<(&'static str, u32, i32)> = DECIMAL_PREFIXES
+            .iter()
+            .filter(|_| decimal)
+            .map(|(lower, _upper, prefix)| (*prefix, 10, *lower as i32))
+            .chain(BINARY_PREFIXES.iter().filter(|_| binary).map(|(lower, _upper, prefix)| (*prefix, 2, *lower as i32)))
+            .filter(|(prefix, _base, _exponent)| !prefix.is_empty())
+            .collect(); // without the empty prefix of magnitude 0
+        let folded: Vec<(&'static str, u32, i32)> = exact
+            .iter()
+            .filter(|(prefix, _base, _exponent)| exact.iter().filter(|(other, _base, _exponent)| other.eq_ignore_ascii_case(prefix)).count() == 1)
+            .copied()
+            .collect(); // unique ignoring case, so "m" and "M" are left out
+
+
+        return Self { exact, folded };
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+/// # Summary
+/// Kind of a piece of formatted output. Every formatted number is a sequence of such pieces, concatenated they form the formatted number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PartKind
+{
+    Affix,            // prefix or suffix affix
+    DecimalSeparator, // separates integer and fractional part
+    ExponentDigits,   // exponent of scientific notation
+    ExponentMarker,   // multiplication sign, base, and parentheses of scientific notation
+    FractionDigits,   // digits after decimal separator
+    GroupSeparator,   // separates groups of integer digits
+    IntegerDigits,    // digits before decimal separator
+    Literal,          // whitespace between number and unit prefix or unit
+    Original,         // original value in parentheses after the formatted number, including the whitespace before
+    Padding,          // fill characters to reach configured width
+    Prefix,           // unit prefix
+    Sign,             // plus, minus, or space
+    Special,          // infinity, not a number, or placeholder for a missing number
+    Threshold,        // marker before a number displayed as the floor or ceiling it is beyond, like "< ", or after a clamped number
+    Unit,             // unit after unit prefix
+}
+
+
+/// # Summary
+/// A piece of formatted output together with its kind.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Part
+{
+    pub kind:  PartKind,
+    pub value: String,
+}
+
+
+/// # Summary
+/// A formatted number split into its pieces in order. Displaying it concatenates the pieces and yields exactly what `Formatter::format` returns.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FormattedParts(pub Vec<Part>);
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats like `Formatter::format`, but returns the pieces of the formatted number separately, for example to style the unit prefix differently from the digits. Consecutive pieces always differ in kind, so digits between two group separators form a single piece.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the pieces of the formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::{Part, PartKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let part = |kind: PartKind, value: &str| Part {kind, value: value.to_string()};
+    /// assert_eq!(f.format_to_parts(-42069).0, vec![
+    ///     part(PartKind::Sign, "-"),
+    ///     part(PartKind::IntegerDigits, "42"),
+    ///     part(PartKind::DecimalSeparator, ","),
+    ///     part(PartKind::FractionDigits, "07"),
+    ///     part(PartKind::Literal, " "),
+    ///     part(PartKind::Prefix, "k"),
+    /// ]);
+    /// assert_eq!(f.format_to_parts(1e36).0, vec![
+    ///     part(PartKind::IntegerDigits, "1"),
+    ///     part(PartKind::DecimalSeparator, ","),
+    ///     part(PartKind::FractionDigits, "000"),
+    ///     part(PartKind::ExponentMarker, " * 10^("),
+    ///     part(PartKind::ExponentDigits, "36"),
+    ///     part(PartKind::ExponentMarker, ")"),
+    /// ]);
+    /// assert_eq!(f.format_to_parts(f64::NAN).0, vec![part(PartKind::Special, "NaN")]);
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{Part, PartKind};
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_affixes("$", "");
+    /// let part = |kind: PartKind, value: &str| Part {kind, value: value.to_string()};
+    /// assert_eq!(f.format_to_parts(1234567).0, vec![
+    ///     part(PartKind::Affix, "$"),
+    ///     part(PartKind::IntegerDigits, "1"),
+    ///     part(PartKind::GroupSeparator, "."),
+    ///     part(PartKind::IntegerDigits, "235"),
+    ///     part(PartKind::GroupSeparator, "."),
+    ///     part(PartKind::IntegerDigits, "000"),
+    /// ]);
+    /// ```
+    ///
+    /// ```
+    /// let formatters: Vec<scaler::Formatter> = vec![
+    ///     scaler::Formatter::new(),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::Binary(false)).set_sign(scaler::Sign::Always),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::None).set_rounding(scaler::Rounding::Magnitude(-3)),
+    ///     scaler::Formatter::new().set_scaling(scaler::Scaling::Scientific).set_trailing_zeros(false),
+    ///     scaler::Formatter::new().set_separators("'", ".").set_affixes("€ ", " EUR").set_sign_placement(scaler::SignPlacement::Trailing),
+    /// ];
+    /// let mut x: f64 = 1.234e-40;
+    /// while x < 1e40 // concatenated parts always equal formatted number
+    /// {
+    ///     for f in formatters.iter()
+    ///     {
+    ///         for y in [x, -x, 0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN]
+    ///         {
+    ///             assert_eq!(f.format_to_parts(y).to_string(), f.format(y));
+    ///         }
+    ///     }
+    ///     x *= 7.3;
+    /// }
+    /// ```
+    pub fn format_to_parts<T>(&self, x: T) -> FormattedParts
+    where
+        T: Into<f64>,
+    {
+        let mut parts: Vec<Part> = Vec::new();
+        let x: f64 = x.into();
+
+
+        self.expect_accepted(x);
+        self.render(Value::Float(x), &mut |kind, value| {
+            match parts.last_mut()
+            {
+                Some(last) if last.kind == kind => last.value.push_str(value), // merge consecutive pieces of same kind
+                _ => parts.push(Part { kind, value: value.to_string() }),
+            }
+            return Ok(());
+        })
+        .expect("Collecting parts failed even though it is infallible.");
+
+        return FormattedParts(parts);
+    }
+}
+
+
+impl std::fmt::Display for FormattedParts
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        for part in self.0.iter()
+        {
+            f.write_str(&part.value)?;
+        }
+        return Ok(());
+    }
+}
+
+
+impl std::ops::Deref for FormattedParts
+{
+    type Target = [Part];
+
+    fn deref(&self) -> &Self::Target
+    {
+        return &self.0;
+    }
+}
+
+
+impl IntoIterator for FormattedParts
+{
+    type IntoIter = std::vec::IntoIter<Part>;
+    type Item = Part;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        return self.0.into_iter();
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats which percentage a part is of a whole, like progress or shares of a total, "12,50 %" for 1 of 8. The ratio is multiplied by 100 and formatted without scaling, affixes, and unit, but with "%" and the configured rounding, separators, sign, trailing zeros, and width. The division edge cases are handled explicitly: 0 of 0 is displayed as 0 % or the placeholder set with `Formatter::set_none_string`, a part of 0 other than 0 as infinity with the sign of the part, and negative ratios keep their sign. With clamping, the percentage is limited to [0 %; 100 %], as needed by progress bars. Both set with `Formatter::set_percent_of`. If either is NaN or both are infinite, the percentage is NaN.
+    ///
+    /// # Arguments
+    /// - `part`: the part of the whole
+    /// - `whole`: the whole, 100 %
+    ///
+    /// # Returns
+    /// - formatted percentage
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_percent_of(1.0, 8.0), "12,50 %");
+    /// assert_eq!(f.format_percent_of(3.0, 2.0), "150,0 %");
+    /// assert_eq!(f.format_percent_of(-1.0, 4.0), "-25,00 %");
+    /// assert_eq!(f.format_percent_of(0.0, 0.0), "0,000 %");
+    /// assert_eq!(f.format_percent_of(5.0, 0.0), "∞ %");
+    /// assert_eq!(f.format_percent_of(-5.0, 0.0), "-∞ %");
+    /// assert_eq!(f.format_percent_of(f64::NAN, 1.0), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_percent_of(scaler::PercentOfOptions { clamp: true, zero_of_zero_placeholder: true });
+    /// assert_eq!(f.format_percent_of(37.0, 50.0), "74 %");
+    /// assert_eq!(f.format_percent_of(60.0, 50.0), "100 %"); // clamped
+    /// assert_eq!(f.format_percent_of(-5.0, 50.0), "0 %");
+    /// assert_eq!(f.format_percent_of(0.0, 0.0), "—"); // placeholder
+    /// ```
+    pub fn format_percent_of(&self, part: f64, whole: f64) -> String
+    {
+        let mut percent: f64; // ratio in percent
+        let percent_formatter: Formatter = Formatter {
+            affix_prefix: Text::Static(""),
+            affix_suffix: Text::Static(""),
+            scaling: Scaling::None,
+            unit: Text::Static("%"),
+            unit_plural: Text::Static(""),
+            ..self.clone()
+        };
+
+
+        if part == 0.0 && whole == 0.0 && self.percent_of.zero_of_zero_placeholder
+        // no ratio at all
+        {
+            return percent_formatter.format_opt(None::<f64>);
+        }
+
+        percent = match (part, whole)
+        {
+            (part, whole) if part.is_nan() || whole.is_nan() => f64::NAN,
+            (0.0, 0.0) => 0.0, // nothing of nothing
+            (part, 0.0) => f64::INFINITY.copysign(part), // sign of the part, not of the zero
+            (part, whole) => part / whole * 100.0,
+        };
+        if self.percent_of.clamp
+        // for progress bars
+        {
+            percent = percent.clamp(0.0, 100.0);
+        }
+
+        return percent_formatter.format(percent);
+    }
+}
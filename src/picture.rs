@@ -0,0 +1,334 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::from_picture` if the mask uses something it does not support. Contains what the problem is and where it is, so it can be pointed at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PictureError
+{
+    pub kind:     PictureErrorKind, // what the problem is
+    pub position: usize,            // byte index into the mask where the problem is
+}
+
+
+/// # Summary
+/// What kind of problem `PictureError` describes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PictureErrorKind
+{
+    MixedFraction,        // forced and optional digits in the same fraction, like "0.0#"
+    NegativeMismatch,     // negative section that is not the positive one in parentheses or with a minus
+    NoDigits,             // section without "0" or "#"
+    UnexpectedChar(char), // token that is not supported, or not allowed at this point
+    UnterminatedQuote,    // quoted literal text without closing quote
+}
+
+
+/// # Summary
+/// Positive section of a mask, split into literal text and digit placeholders.
+struct Section<'a>
+{
+    body:     Vec<(usize, char)>, // digit placeholders and separators with their byte index into the mask
+    percent:  Option<bool>,       // whether "%" follows the number, and if so whether with space between
+    prefix:   String,             // literal text before the number, without quotes
+    raw_head: &'a str,            // section as written up to the first digit placeholder, to compare the negative section with
+    raw_tail: &'a str,            // section as written from the first digit placeholder on
+    suffix:   String,             // literal text after the number and "%", without quotes
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Constructs a formatter from a spreadsheet-style picture mask, as found in report templates, like "#.##0,00" or "$#,##0.00;($#,##0.00)". The mask consists of literal text, the number, and more literal text:
+    ///
+    /// - `0`: forced digit, integer zeros are the minimum number of integer digits, fraction zeros are displayed even if they are trailing zeros
+    /// - `#`: optional digit, fraction hashes are displayed only if they are not trailing zeros
+    /// - separators between digit placeholders: the last one is the decimal separator and any other the group separator. A single separator followed by exactly 3 digit placeholders and preceded only by "#" is the group separator, like in "#,##0", otherwise the decimal separator. Digits are grouped by 3 wherever the group separator is in the mask.
+    /// - `%`: right after the number, optionally after a space, multiplies by 100 and displays "%" with `Scaling::Percent`
+    /// - literal text before and after the number: prefix and suffix affix, text in double quotes is taken as it is
+    /// - `;`: separates the negative section, which must be the positive one enclosed in parentheses or with a minus before or after it, before or after the prefix text
+    ///
+    /// The number is not scaled and rounded to the last digit placeholder. Without group separator in the mask, digits are not grouped. Without decimal separator, it is "," unless that is the group separator, then ".". Everything else, like sign and unit, are the defaults of `Formatter::new` and can be changed with the setters afterwards.
+    ///
+    /// # Arguments
+    /// - `mask`: the picture mask
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or `PictureError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::from_picture("#.##0,00").unwrap();
+    /// assert_eq!(f.format(1234.5), "1.234,50");
+    ///
+    /// let f: scaler::Formatter = scaler::Formatter::from_picture("$#,##0.00;($#,##0.00)").unwrap();
+    /// assert_eq!(f.format(-1234.5), "($1,234.50)");
+    /// assert_eq!(f.parse("($1,234.50)"), Ok(-1234.5));
+    ///
+    /// let f: scaler::Formatter = scaler::Formatter::from_picture("0.0 %").unwrap();
+    /// assert_eq!(f.format(0.125), "12.5 %");
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{PictureError, PictureErrorKind};
+    /// assert_eq!(scaler::Formatter::from_picture("0.00E+00"), Err(PictureError { kind: PictureErrorKind::UnexpectedChar('E'), position: 4 }));
+    /// assert_eq!(scaler::Formatter::from_picture("0.0#"), Err(PictureError { kind: PictureErrorKind::MixedFraction, position: 3 }));
+    /// assert_eq!(scaler::Formatter::from_picture("0;[Red]0"), Err(PictureError { kind: PictureErrorKind::NegativeMismatch, position: 2 }));
+    /// assert_eq!(scaler::Formatter::from_picture("EUR").unwrap_err().to_string(), "No digit placeholder \"0\" or \"#\" in the section at position 0.");
+    /// ```
+    pub fn from_picture(mask: &str) -> Result<Self, PictureError>
+    {
+        let decimal: Option<(usize, char)>; // decimal separator and its index into the body
+        let mut f: Formatter; // result
+        let fraction: &[(usize, char)]; // digit placeholders after the decimal separator
+        let group: Option<char>; // group separator
+        let integer: &[(usize, char)]; // digit placeholders and group separators before the decimal separator
+        let (positive, negative): (&str, Option<&str>) = match mask.split_once(';')
+        {
+            Some((positive, negative)) => (positive, Some(negative)),
+            None => (mask, None),
+        }; // sections
+        let section: Section; // positive section
+        let separators: Vec<(usize, usize, char)>; // separators with their index into the body and byte index into the mask
+
+
+        section = parse_section(positive)?;
+        separators = section.body.iter().enumerate().filter(|(_, (_, c))| *c != '0' && *c != '#').map(|(i, (pos, c))| (i, *pos, *c)).collect();
+        for window in separators.windows(2)
+        // separators must be between digit placeholders
+        {
+            if window[0].0 + 1 == window[1].0
+            {
+                return Err(PictureError { kind: PictureErrorKind::UnexpectedChar(window[1].2), position: window[1].1 });
+            }
+        }
+
+        (group, decimal) = match separators.as_slice()
+        {
+            [] => (None, None),
+            [(i, _, c)] if section.body.len() - i - 1 == 3 && section.body[..*i].iter().all(|(_, c)| *c == '#') => (Some(*c), None), // like "#,##0"
+            [(i, _, c)] => (None, Some((*i, *c))),
+            [.., (i, _, c)] if separators.iter().filter(|(_, _, other)| other == c).count() == 1 => (Some(separators[0].2), Some((*i, *c))), // last one, if unique, is the decimal separator
+            [.., (_, _, c)] => (Some(*c), None), // repeated, only group separators
+        };
+        if let Some((_, pos, c)) = separators.iter().find(|(_, _, c)| Some(*c) != group && Some(*c) != decimal.map(|(_, c)| c))
+        // third kind of separator
+        {
+            return Err(PictureError { kind: PictureErrorKind::UnexpectedChar(*c), position: *pos });
+        }
+
+        (integer, fraction) = match decimal
+        {
+            Some((i, _)) => (&section.body[..i], &section.body[i + 1..]),
+            None => (&section.body[..], &[][..]),
+        };
+        if let Some(window) = integer.iter().filter(|(_, c)| Some(*c) != group).collect::<Vec<_>>().windows(2).find(|window| window[0].1 == '0' && window[1].1 == '#')
+        // integer zeros are right-aligned
+        {
+            return Err(PictureError { kind: PictureErrorKind::UnexpectedChar('#'), position: window[1].0 });
+        }
+        if let Some(window) = fraction.windows(2).find(|window| window[0].1 == '#' && window[1].1 == '0')
+        // fraction zeros are left-aligned
+        {
+            return Err(PictureError { kind: PictureErrorKind::UnexpectedChar('0'), position: window[1].0 });
+        }
+        if let (Some(_), Some((pos, _))) = (fraction.iter().find(|(_, c)| *c == '0'), fraction.iter().find(|(_, c)| *c == '#'))
+        // trailing zeros are either all displayed or none
+        {
+            return Err(PictureError { kind: PictureErrorKind::MixedFraction, position: *pos });
+        }
+
+        f = Formatter::new()
+            .set_affixes(&section.prefix, &section.suffix)
+            .set_min_integer_digits(integer.iter().filter(|(_, c)| *c == '0').count())
+            .set_rounding(Rounding::Magnitude(-(fraction.len().min(i16::MAX as usize - 2) as i16) - if section.percent.is_some() { 2 } else { 0 }))
+            .set_scaling(section.percent.map_or(Scaling::None, Scaling::Percent))
+            .set_trailing_zeros(fraction.iter().all(|(_, c)| *c == '0'));
+        f = match (group, decimal)
+        {
+            (Some(group), Some((_, decimal))) => f.set_separators(&group.to_string(), &decimal.to_string()),
+            (Some(','), None) => f.set_separators(",", "."),
+            (Some(group), None) => f.set_separators(&group.to_string(), ","),
+            (None, Some((_, decimal))) => f.set_separators("", &decimal.to_string()),
+            (None, None) => f.set_separators("", ","),
+        };
+
+        if let Some(negative) = negative
+        // sign placement and position from how the negative section encloses the positive one
+        {
+            let (head, tail): (&str, &str) = (section.raw_head, section.raw_tail);
+            let position: usize = positive.len() + ";".len(); // start of the negative section
+            let (sign_placement, sign_position): (SignPlacement, SignPosition) = if negative == format!("({positive})")
+            {
+                (SignPlacement::Parentheses, SignPosition::BeforeAffix)
+            }
+            else if negative == format!("{head}({tail})")
+            {
+                (SignPlacement::Parentheses, SignPosition::AfterAffix)
+            }
+            else if negative == format!("-{positive}")
+            {
+                (SignPlacement::Leading, SignPosition::BeforeAffix)
+            }
+            else if negative == format!("{head}-{tail}")
+            {
+                (SignPlacement::Leading, SignPosition::AfterAffix)
+            }
+            else if negative == format!("{positive}-")
+            {
+                (SignPlacement::Trailing, SignPosition::BeforeAffix)
+            }
+            else if let Some(offset) = negative.find(';')
+            // at most positive and negative section
+            {
+                return Err(PictureError { kind: PictureErrorKind::UnexpectedChar(';'), position: position + offset });
+            }
+            else
+            {
+                return Err(PictureError { kind: PictureErrorKind::NegativeMismatch, position });
+            };
+            f = f.set_sign_placement(sign_placement).set_sign_position(sign_position);
+        }
+
+        return Ok(f);
+    }
+}
+
+
+/// # Summary
+/// Splits a section of a mask into literal text before the number, the number, "%", and literal text after it.
+///
+/// # Arguments
+/// - `section`: the section, starting at byte index 0 of the mask
+///
+/// # Returns
+/// - the section
+/// - or `PictureError` describing what and where the problem is
+fn parse_section(section: &str) -> Result<Section<'_>, PictureError>
+{
+    let first: usize; // index of the first digit placeholder
+    let last: usize; // index of the last digit placeholder
+    let mut percent: Option<bool> = None; // whether "%" follows the number, and if so whether with space between
+    let mut prefix: String = String::new();
+    let mut quote: Option<usize> = None; // byte index of the opening quote, if within quoted text
+    let mut suffix: String = String::new();
+    let mut tokens: Vec<(usize, char, bool)> = Vec::new(); // characters with their byte index and whether they are quoted
+
+
+    for (pos, c) in section.char_indices()
+    {
+        match (c, quote)
+        {
+            ('"', None) => quote = Some(pos),
+            ('"', Some(_)) => quote = None,
+            (c, quote) => tokens.push((pos, c, quote.is_some())),
+        }
+    }
+    if let Some(pos) = quote
+    {
+        return Err(PictureError { kind: PictureErrorKind::UnterminatedQuote, position: pos });
+    }
+
+    (first, last) = match (tokens.iter().position(|(_, c, quoted)| is_placeholder(*c, *quoted)), tokens.iter().rposition(|(_, c, quoted)| is_placeholder(*c, *quoted)))
+    {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Err(PictureError { kind: PictureErrorKind::NoDigits, position: 0 }),
+    };
+
+    for (pos, c, quoted) in &tokens[..first]
+    {
+        if !quoted && is_reserved(*c)
+        {
+            return Err(PictureError { kind: PictureErrorKind::UnexpectedChar(*c), position: *pos });
+        }
+        prefix.push(*c);
+    }
+    for (pos, c, quoted) in &tokens[first..=last]
+    {
+        if *quoted || (!is_placeholder(*c, false) && (c.is_alphanumeric() || is_reserved(*c)))
+        // separators are single characters that are no letters, digits, or tokens
+        {
+            return Err(PictureError { kind: PictureErrorKind::UnexpectedChar(if *quoted { '"' } else { *c }), position: *pos });
+        }
+    }
+    for (pos, c, quoted) in &tokens[last + 1..]
+    {
+        if !quoted && *c == '%' && percent.is_none() && (suffix.is_empty() || suffix == " ")
+        // percent right after the number
+        {
+            percent = Some(suffix == " ");
+            suffix.clear();
+        }
+        else if !quoted && is_reserved(*c)
+        {
+            return Err(PictureError { kind: PictureErrorKind::UnexpectedChar(*c), position: *pos });
+        }
+        else
+        {
+            suffix.push(*c);
+        }
+    }
+
+    return Ok(Section {
+        body: tokens[first..=last].iter().map(|(pos, c, _)| (*pos, *c)).collect(),
+        percent,
+        prefix,
+        raw_head: &section[..tokens[first].0],
+        raw_tail: &section[tokens[first].0..],
+        suffix,
+    });
+}
+
+
+/// # Summary
+/// Decides whether a character of a mask is a digit placeholder.
+///
+/// # Arguments
+/// - `c`: the character
+/// - `quoted`: whether it is within quoted literal text
+///
+/// # Returns
+/// - whether it is "0" or "#" outside of quotes
+fn is_placeholder(c: char, quoted: bool) -> bool
+{
+    return !quoted && (c == '0' || c == '#');
+}
+
+
+/// # Summary
+/// Decides whether a character of a mask is a token that is not supported as literal text without quotes, like digits, "?" for aligned digits, "@" for text, or "[" for colours and conditions.
+///
+/// # Arguments
+/// - `c`: the character
+///
+/// # Returns
+/// - whether it must be quoted to be literal text
+fn is_reserved(c: char) -> bool
+{
+    return c.is_ascii_digit() || matches!(c, '#' | '%' | '*' | '?' | '@' | '[' | '\\' | ']' | '_');
+}
+
+
+impl std::fmt::Display for PictureError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let pos: usize = self.position; // where the problem is
+
+
+        return match &self.kind
+        {
+            PictureErrorKind::MixedFraction => write!(f, "Optional digit at position {pos} after forced fraction digits, trailing zeros are either all displayed or none."),
+            PictureErrorKind::NegativeMismatch => write!(f, "Negative section at position {pos} is not the positive one in parentheses or with a minus."),
+            PictureErrorKind::NoDigits => write!(f, "No digit placeholder \"0\" or \"#\" in the section at position {pos}."),
+            PictureErrorKind::UnexpectedChar(c) => write!(f, "Unexpected character {c:?} at position {pos}."),
+            PictureErrorKind::UnterminatedQuote => write!(f, "Quote at position {pos} is never closed."),
+        };
+    }
+}
+
+
+impl std::error::Error for PictureError {}
@@ -0,0 +1,11 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+//! Glob import of the formatter and the extension traits, so `use scaler::prelude::*;` enables `42069.scaled()`, `iter.scaled(&f)`, and `writer.write_scaled(&f, x)` at once.
+//!
+//! # Examples
+//! ```
+//! use scaler::prelude::*;
+//! let f: Formatter = Formatter::new().set_unit("B");
+//! assert_eq!(1536.scaled_with(&f), "1,536 kB");
+//! assert_eq!([1.5, 2500.0].into_iter().scaled(&f).collect::<Vec<String>>(), ["1,500 B", "2,500 kB"]);
+//! ```
+pub use crate::{Formatter, IntoF64, ScaledIteratorExt, ToScaled, WriteScaled};
@@ -0,0 +1,237 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+/// # Summary
+/// Formats a byte count with binary unit prefixes, unit "B", and 3 significant digits, like `Formatter::bytes`. The unit prefix is chosen by the exact byte count, so 1024 B is "1,00 KiB", and only then the mantissa is divided exactly and rounded, ties to even, so counts beyond 2^(53) are not rounded by a conversion to f64 first. Mantissas of 1000 and more, which the binary unit prefixes allow up to 1023, keep all their integer digits instead of being rounded to 3 significant digits, and mantissas rounding up to 1024 move to the next unit prefix. For other settings, start from `Formatter::bytes` and adjust it.
+///
+/// # Arguments
+/// - `bytes`: the byte count
+///
+/// # Returns
+/// - the formatted byte count
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::format_bytes(0), "0,00 B");
+/// assert_eq!(scaler::format_bytes(512), "512 B");
+/// assert_eq!(scaler::format_bytes(1023), "1.023 B"); // all integer digits, not 1.020
+/// assert_eq!(scaler::format_bytes(1024), "1,00 KiB");
+/// assert_eq!(scaler::format_bytes(1100), "1,07 KiB");
+/// assert_eq!(scaler::format_bytes(1536), "1,50 KiB");
+/// assert_eq!(scaler::format_bytes(1_048_063), "1.023 KiB"); // 1023,49 KiB
+/// assert_eq!(scaler::format_bytes(1_048_064), "1,00 MiB"); // 1023,5 KiB rounds to 1024 KiB
+/// assert_eq!(scaler::format_bytes(1 << 60), "1,00 EiB");
+/// assert_eq!(scaler::format_bytes(u64::MAX), "16,0 EiB");
+/// ```
+pub fn format_bytes(bytes: u64) -> String
+{
+    const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"]; // unit prefix and unit for 1024^i
+    let mut i: usize = (bytes.max(1).ilog2() / 10) as usize; // unit prefix chosen by the exact byte count
+    let rounding: Rounding; // 3 significant digits, but all integer digits from 1000 on
+    let mut s: String = String::new(); // formatted byte count, result
+
+
+    if i < UNITS.len() - 1 && 2047 << (10 * i) <= (bytes as u128) << 1
+    // mantissa of at least 1023,5 rounds to 1024, next unit prefix
+    {
+        i += 1;
+    }
+    rounding = if 1000 << (10 * i) <= bytes as u128 { Rounding::Magnitude(0) } else { Rounding::SignificantDigits(3) };
+
+    Formatter::bytes()
+        .set_rounding(rounding)
+        .set_scaling(Scaling::None)
+        .set_unit_const(UNITS[i])
+        .render(Value::Fixed(false, bytes as u128, 10 * i as u8), &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+    return s;
+}
+
+
+/// # Summary
+/// Formats a byte count like `scaler::format_bytes`, but with decimal unit prefixes, the settings of `Formatter::bytes_si`, so 1 kB is 1000 B.
+///
+/// # Arguments
+/// - `bytes`: the byte count
+///
+/// # Returns
+/// - the formatted byte count
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::format_bytes_si(0), "0,00 B");
+/// assert_eq!(scaler::format_bytes_si(1023), "1,02 kB");
+/// assert_eq!(scaler::format_bytes_si(1024), "1,02 kB");
+/// assert_eq!(scaler::format_bytes_si(1536), "1,54 kB");
+/// assert_eq!(scaler::format_bytes_si(u64::MAX), "18,4 EB");
+/// ```
+pub fn format_bytes_si(bytes: u64) -> String
+{
+    static F: Formatter = Formatter::bytes_si();
+    return F.format_int(bytes);
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Constructs a formatter for byte counts: binary scaling with space, unit "B", and rounding to 3 significant digits. Otherwise the defaults of `Formatter::new`.
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::bytes();
+    /// assert_eq!(f.format(1536), "1,50 KiB");
+    /// assert_eq!(f.format(3221225472_u32), "3,00 GiB");
+    /// assert_eq!(f.format(512), "512 B");
+    /// ```
+    pub const fn bytes() -> Self
+    {
+        return Self::new().set_scaling(Scaling::Binary(true)).set_rounding(Rounding::SignificantDigits(3)).set_unit_const("B");
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter for byte counts like `Formatter::bytes`, but with decimal scaling, so 1 kB is 1000 B.
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::bytes_si();
+    /// assert_eq!(f.format(1536), "1,54 kB");
+    /// assert_eq!(f.format(512), "512 B");
+    /// ```
+    pub const fn bytes_si() -> Self
+    {
+        return Self::new().set_rounding(Rounding::SignificantDigits(3)).set_unit_const("B");
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter for the huge numbers of incremental and idle games: scaling in steps of 10^3 with the suffixes of `PrefixTable::IDLE_GAME`, "K", "M", "B", "T", "Qa", "Qi", then "aa", "ab", and so on, which never fall back to scientific notation for large numbers. Otherwise the defaults of `Formatter::new`, including rounding to 4 significant digits.
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::idle_game();
+    /// assert_eq!(f.format(1234.5), "1,234 K");
+    /// assert_eq!(f.format(1e21), "1,000 aa");
+    /// assert_eq!(f.format(1e24), "1,000 ab");
+    /// assert_eq!(f.format(42), "42,00");
+    /// ```
+    pub const fn idle_game() -> Self
+    {
+        return Self::new().set_scaling(Scaling::Exponential { base: 10, step: 3, prefixes: Some(PrefixTable::IDLE_GAME) });
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter for amounts of money: no scaling, rounding to 2 decimal places, grouped integer digits, and the currency symbol after the number separated by a space. Otherwise the defaults of `Formatter::new`.
+    ///
+    /// # Arguments
+    /// - `symbol`: currency symbol, for example "€"
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::currency("€");
+    /// assert_eq!(f.format(1234.5), "1.234,50 €");
+    /// assert_eq!(f.format(-0.5), "-0,50 €");
+    /// assert_eq!(f.format(1234567), "1.234.567,00 €");
+    /// ```
+    pub fn currency(symbol: &str) -> Self
+    {
+        return Self::new().set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(-2)).set_affixes("", &format!(" {symbol}"));
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter for percentages: no scaling, rounding to 1 decimal place, and unit "%". The number must already be in percent, format ratios with `Scaling::Percent` instead, or part and whole with `Formatter::format_percent_of`. `Formatter::parse_percent` reads the output back as ratio. Otherwise the defaults of `Formatter::new`.
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::percent();
+    /// assert_eq!(f.format(12.5), "12,5 %");
+    /// assert_eq!(f.format(0.125 * 100.0), "12,5 %"); // ratio multiplied by 100
+    /// assert_eq!(f.parse_percent(&f.format(99.95)), Ok(1.0)); // rounded to 100,0 %
+    /// ```
+    pub const fn percent() -> Self
+    {
+        return Self::new().set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(-1)).set_unit_const("%");
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter for output that must parse with `str::parse::<f64>`, like CSV files: the cosmetic options are overridden with `Formatter::set_machine_readable`, so only rounding, trailing zeros, the "+" sign, and scientific notation take effect. Otherwise the defaults of `Formatter::new`, including rounding to 4 significant digits.
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::machine();
+    /// assert_eq!(f.format(1234.5), "1234");
+    /// assert_eq!(f.format(0.0123456), "0.01235");
+    /// assert_eq!(f.format(f64::INFINITY), "inf");
+    /// let f: scaler::Formatter = f.set_scaling(scaler::Scaling::Scientific);
+    /// assert_eq!(f.format(0.0123456), "1.235e-2");
+    /// ```
+    pub const fn machine() -> Self
+    {
+        return Self::new().set_machine_readable(true);
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter for output read by machines: no scaling, no group separator, and "." as decimal separator. Otherwise the defaults of `Formatter::new`, including rounding to 4 significant digits. Unlike `Formatter::machine`, other options still apply.
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::plain();
+    /// assert_eq!(f.format(1234.5), "1234");
+    /// assert_eq!(f.format(0.0123456), "0.01235");
+    /// assert_eq!(f.format(-1e6), "-1000000");
+    /// ```
+    pub const fn plain() -> Self
+    {
+        return Self::new().set_scaling(Scaling::None).set_separators_const("", ".");
+    }
+
+
+    /// # Summary
+    /// Constructs a formatter with decimal SI unit prefixes, the same as `Formatter::new`. Spells out the intent where presets are chosen between.
+    ///
+    /// # Returns
+    /// - Formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::si();
+    /// assert_eq!(f.format(1234.5), "1,234 k");
+    /// assert_eq!(f.format(0.000789), "789,0 µ");
+    /// assert_eq!(f, scaler::Formatter::new());
+    /// ```
+    pub const fn si() -> Self
+    {
+        return Self::new();
+    }
+}
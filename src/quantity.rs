@@ -0,0 +1,117 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// `uom` quantities that `Formatter::format_quantity` formats together with their unit. Implemented for the storage types f32 and f64 of `uom::si` and the quantities whose unit takes unit prefixes without ambiguity: amount of substance, capacitance, electric charge, electric current, electric potential, electrical resistance, energy, force, frequency, inductance, information, length, luminous intensity, mass, power, pressure, thermodynamic temperature, and time. Integer and rational storage types are not supported, neither are compound units like m² that unit prefixes would apply to only partially.
+pub trait FormatQuantity
+{
+    /// # Summary
+    /// Extracts the value in the unprefixed unit of the quantity, so a unit prefix is never applied twice, for example gram instead of the SI base unit kilogram and byte instead of bit.
+    ///
+    /// # Returns
+    /// - value in the unprefixed unit
+    /// - abbreviation of the unprefixed unit from uom's unit metadata
+    fn value_unit(&self) -> (f64, &'static str);
+}
+
+
+/// # Summary
+/// Implements `FormatQuantity` for the quantity in both storage types f32 and f64, with the given unprefixed unit.
+macro_rules! impl_format_quantity
+{
+    ($($quantity:ident: $module:ident::$unit:ident),*) =>
+    {
+        $(
+            impl FormatQuantity for uom::si::f32::$quantity
+            {
+                fn value_unit(&self) -> (f64, &'static str)
+                {
+                    return (self.get::<uom::si::$module::$unit>() as f64, <uom::si::$module::$unit as uom::si::Unit>::abbreviation());
+                }
+            }
+
+            impl FormatQuantity for uom::si::f64::$quantity
+            {
+                fn value_unit(&self) -> (f64, &'static str)
+                {
+                    return (self.get::<uom::si::$module::$unit>(), <uom::si::$module::$unit as uom::si::Unit>::abbreviation());
+                }
+            }
+        )*
+    };
+}
+
+
+impl_format_quantity!(
+    AmountOfSubstance: amount_of_substance::mole,
+    Capacitance: capacitance::farad,
+    ElectricCharge: electric_charge::coulomb,
+    ElectricCurrent: electric_current::ampere,
+    ElectricPotential: electric_potential::volt,
+    ElectricalResistance: electrical_resistance::ohm,
+    Energy: energy::joule,
+    Force: force::newton,
+    Frequency: frequency::hertz,
+    Inductance: inductance::henry,
+    Information: information::byte,
+    Length: length::meter,
+    LuminousIntensity: luminous_intensity::candela,
+    Mass: mass::gram,
+    Power: power::watt,
+    Pressure: pressure::pascal,
+    ThermodynamicTemperature: thermodynamic_temperature::kelvin,
+    Time: time::second
+);
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a `uom` quantity like `Formatter::format`, with the abbreviation of its unit from uom's unit metadata instead of the configured unit. The value is taken in the unprefixed unit, so the unit prefix chosen by scaling is never applied twice, regardless of which unit the quantity was created with. Mass is therefore formatted in gram and information in byte, for which binary scaling results in "KiB", "MiB", and so on.
+    ///
+    /// # Arguments
+    /// - `q`: the quantity to format
+    ///
+    /// # Returns
+    /// - formatted quantity with unit
+    ///
+    /// # Examples
+    /// ```
+    /// use uom::si::f64::{Information, Length, Mass, Time};
+    /// use uom::si::{information, length, mass, time};
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_quantity(&Length::new::<length::kilometer>(3.5)), "3,500 km");
+    /// assert_eq!(f.format_quantity(&Length::new::<length::millimeter>(3500.0)), "3,500 m");
+    /// assert_eq!(f.format_quantity(&Length::new::<length::foot>(1.0)), "304,8 mm");
+    /// assert_eq!(f.format_quantity(&Time::new::<time::millisecond>(12.5)), "12,50 ms");
+    /// assert_eq!(f.format_quantity(&Time::new::<time::second>(-0.000_002)), "-2,000 µs");
+    /// assert_eq!(f.format_quantity(&Mass::new::<mass::kilogram>(1.5)), "1,500 kg"); // not "1,500 kkg"
+    /// assert_eq!(f.format_quantity(&Information::new::<information::gigabyte>(1.0)), "1,000 GB");
+    /// assert_eq!(f.format_quantity(&Information::new::<information::bit>(4.0)), "500,0 mB");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true));
+    /// assert_eq!(f.format_quantity(&Information::new::<information::gibibyte>(1.0)), "1,000 GiB");
+    /// assert_eq!(f.format_quantity(&Information::new::<information::kilobyte>(1.536)), "1,500 KiB");
+    /// ```
+    ///
+    /// ```
+    /// use uom::si::f32::{Length, Time};
+    /// use uom::si::{length, time};
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_unit("ignored")
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-1));
+    /// assert_eq!(f.format_quantity(&Length::new::<length::kilometer>(1.5)), "1.500,0 m");
+    /// assert_eq!(f.format_quantity(&Time::new::<time::minute>(2.0)), "120,0 s");
+    /// ```
+    pub fn format_quantity<Q>(&self, q: &Q) -> String
+    where
+        Q: FormatQuantity,
+    {
+        let (value, unit): (f64, &'static str) = q.value_unit();
+
+
+        return Formatter { unit: Text::Static(unit), ..self.clone() }.format(value);
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats an integer in another base, for registers and addresses, "DEAD.BEEF" for 0xDEAD_BEEF. The digits are grouped with the configured group separator in groups set with `Formatter::set_radix`, 4 digits by default, and optionally prefixed with "0b", "0o", or "0x" and zero padded to a minimum number of digits. Neither scaled nor rounded, and sign, affixes, and unit are left out. The configured width applies to the result as a whole.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    /// - `radix`: the base and, for hexadecimal, the case of the digits
+    ///
+    /// # Returns
+    /// - formatted integer
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_radix(0xDEAD_BEEF, scaler::Radix::Hex), "DEAD.BEEF");
+    /// assert_eq!(f.format_radix(0xDEAD_BEEF, scaler::Radix::HexLower), "dead.beef");
+    /// assert_eq!(f.format_radix(0xBEEF, scaler::Radix::Hex), "BEEF");
+    /// assert_eq!(f.format_radix(0x1_0000, scaler::Radix::Hex), "1.0000");
+    /// assert_eq!(f.format_radix(0, scaler::Radix::Hex), "0");
+    /// assert_eq!(f.format_radix(u64::MAX as u128, scaler::Radix::Hex), "FFFF.FFFF.FFFF.FFFF");
+    /// assert_eq!(f.format_radix(0o755, scaler::Radix::Octal), "755");
+    /// assert_eq!(f.format_radix(10, scaler::Radix::Binary), "1010");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators("_", ".")
+    ///     .set_radix(scaler::RadixOptions { group_size: 4, min_digits: 8, prefix: true })
+    ///     .set_width(12, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format_radix(0, scaler::Radix::Hex), " 0x0000_0000"); // zero padding before padding to width
+    /// assert_eq!(f.format_radix(0xDEAD_BEEF, scaler::Radix::Hex), " 0xDEAD_BEEF");
+    /// assert_eq!(f.format_radix(0xF_DEAD_BEEF, scaler::Radix::Hex), "0xF_DEAD_BEEF"); // wider than both
+    /// ```
+    pub fn format_radix(&self, x: u128, radix: Radix) -> String
+    {
+        return self.radix_digits(x, radix, self.radix.min_digits);
+    }
+
+
+    /// # Summary
+    /// Formats the bits of an unsigned integer, for bitmask debugging, zero padded to the given bit width so flags line up across lines, "0000.0000.1011.0010" for 0b1011_0010 in 16 bits. Shorthand for `Formatter::format_radix` in binary with at least `width` digits, a larger minimum set with `Formatter::set_radix` still applies. Integers wider than `width` keep all their bits.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    /// - `width`: bit width to zero pad to, like the width of the type
+    ///
+    /// # Returns
+    /// - formatted bits
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_bits_of(0b1011_0010_1111_u32, 16), "0000.1011.0010.1111");
+    /// assert_eq!(f.format_bits_of(0b101_u8, 8), "0000.0101");
+    /// assert_eq!(f.format_bits_of(true, 1), "1");
+    /// assert_eq!(f.format_bits_of(0x1FF_u16, 8), "1.1111.1111"); // wider than 8 bits
+    /// let f: scaler::Formatter = f.set_radix(scaler::RadixOptions { group_size: 8, min_digits: 0, prefix: true }); // bytes
+    /// assert_eq!(f.format_bits_of(0x80_01_u16, 16), "0b10000000.00000001");
+    /// ```
+    pub fn format_bits_of<T>(&self, x: T, width: u8) -> String
+    where
+        T: Into<u64>,
+    {
+        return self.radix_digits(x.into() as u128, Radix::Binary, self.radix.min_digits.max(width as usize));
+    }
+
+
+    /// # Summary
+    /// Formats a signed integer in another base as two's complement of the given bit width, like a register holding it, "FFFF.FFFE" for -2 in 32 bits. Only the lowest bits of the integer are displayed, like casting with `as` keeps them, and the digits are zero padded to the whole bit width. Otherwise like `Formatter::format_radix`.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    /// - `radix`: the base and, for hexadecimal, the case of the digits
+    /// - `bits`: bit width, clamped to 1 to 128
+    ///
+    /// # Returns
+    /// - formatted two's complement
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_radix_signed(-2, scaler::Radix::Hex, 32), "FFFF.FFFE");
+    /// assert_eq!(f.format_radix_signed(2, scaler::Radix::Hex, 32), "0000.0002");
+    /// assert_eq!(f.format_radix_signed(-1, scaler::Radix::Hex, 8), "FF");
+    /// assert_eq!(f.format_radix_signed(-129, scaler::Radix::Hex, 8), "7F"); // lowest 8 bits, like -129_i32 as i8
+    /// assert_eq!(f.format_radix_signed(i64::MIN as i128, scaler::Radix::Hex, 64), "8000.0000.0000.0000");
+    /// assert_eq!(f.format_radix_signed(-1, scaler::Radix::Octal, 8), "377"); // 8 bits need 3 octal digits
+    /// assert_eq!(f.format_radix_signed(-1, scaler::Radix::Binary, 4), "1111");
+    /// assert_eq!(f.format_radix_signed(-1, scaler::Radix::Hex, 0), "1"); // clamped to 1 bit
+    /// ```
+    pub fn format_radix_signed(&self, x: i128, radix: Radix, bits: u32) -> String
+    {
+        let bits: u32 = bits.clamp(1, 128);
+        let digit_bits: u32 = match radix // bits per digit
+        {
+            Radix::Binary => 1,
+            Radix::Hex | Radix::HexLower => 4,
+            Radix::Octal => 3,
+        };
+
+
+        return self.radix_digits(x as u128 & (u128::MAX >> (128 - bits)), radix, self.radix.min_digits.max(bits.div_ceil(digit_bits) as usize));
+    }
+
+
+    /// # Summary
+    /// Displays an unsigned integer in another base with prefix, grouping, and padding.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    /// - `radix`: the base and, for hexadecimal, the case of the digits
+    /// - `min_digits`: minimum number of digits, zero padded
+    ///
+    /// # Returns
+    /// - formatted integer
+    fn radix_digits(&self, x: u128, radix: Radix, min_digits: usize) -> String
+    {
+        let digits: String; // digits without padding
+        let group_size: usize = self.radix.group_size;
+        let len: usize; // number of digits with padding
+        let prefix: &str;
+        let mut s: String = String::new();
+
+
+        (digits, prefix) = match radix
+        {
+            Radix::Binary => (format!("{x:b}"), "0b"),
+            Radix::Hex => (format!("{x:X}"), "0x"),
+            Radix::HexLower => (format!("{x:x}"), "0x"),
+            Radix::Octal => (format!("{x:o}"), "0o"),
+        };
+        len = digits.len().max(min_digits);
+
+        if self.radix.prefix
+        {
+            s.push_str(prefix);
+        }
+        for (i, digit) in std::iter::repeat_n('0', len - digits.len()).chain(digits.chars()).enumerate()
+        {
+            if 0 < i && group_size != 0 && (len - i).is_multiple_of(group_size)
+            // start of new group, counted from the right
+            {
+                s.push_str(&self.group_separator);
+            }
+            s.push(digit);
+        }
+
+        return self.pad(s);
+    }
+}
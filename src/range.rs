@@ -0,0 +1,130 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a range of numbers with one unit prefix, chosen by the end with the larger magnitude, so the ends never mix units. The unit prefix, unit, and suffix affix are only displayed after the end, the ends are joined by the separator set with `Formatter::set_range_separator`, "–" between thin spaces by default. If both ends are equal after rounding, the range collapses to a single number. A reversed range, whose start is greater than its end, is swapped or kept in order as configured. Without binary or decimal scaling, both ends are formatted individually. The configured width applies to the range as a whole.
+    ///
+    /// # Arguments
+    /// - `start`: the start of the range
+    /// - `end`: the end of the range
+    ///
+    /// # Returns
+    /// - formatted range
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(2))
+    ///     .set_trailing_zeros(false);
+    /// assert_eq!(f.format_range(1200, 3400), "1,2\u{2009}–\u{2009}3,4 k");
+    /// assert_eq!(f.format_range(980, 1200), "0,98\u{2009}–\u{2009}1,2 k"); // not "980 – 1,2 k"
+    /// assert_eq!(f.format_range(-0.5, 2000.0), "-0,0005\u{2009}–\u{2009}2 k");
+    /// assert_eq!(f.format_range(1234, 1198), "1,2 k"); // equal after rounding
+    /// assert_eq!(f.format_range(3400, 1200), "3,4\u{2009}–\u{2009}1,2 k"); // kept in order
+    /// let f: scaler::Formatter = f.set_range_separator(" – ", true);
+    /// assert_eq!(f.format_range(3400, 1200), "1,2 – 3,4 k"); // swapped
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_affixes("$", " net")
+    ///     .set_unit("USD")
+    ///     .set_range_separator("-", false)
+    ///     .set_width(24, scaler::Align::Left, '.');
+    /// assert_eq!(f.format_range(1200, 3400), "$1.200-$3.400 USD net...");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_range_separator(" – ", false); // edge cases
+    /// assert_eq!(f.format_range(0, 0), "0,000");
+    /// assert_eq!(f.format_range(0.0, f64::INFINITY), "0,000 – ∞");
+    /// assert_eq!(f.format_range(f64::NAN, 1e6), "NaN – 1,000 M");
+    /// ```
+    pub fn format_range<T>(&self, start: T, end: T) -> String
+    where
+        T: Into<f64>,
+    {
+        let end_formatted: String; // end of the range with unit prefix, unit, and suffix affix
+        let mut part: Formatter = Formatter { show_original: (ShowOriginal::Off, false), width: 0, ..self.clone() }; // ends are padded together, not individually
+        let mut number_seen: bool = false; // whether the digits of the start were passed already
+        let mut start_formatted: String = String::new(); // start of the range up to its digits
+        let (mut start, mut end): (f64, f64) = (start.into(), end.into());
+
+
+        if self.range_separator.1 && end < start
+        // reversed range, swap
+        {
+            (start, end) = (end, start);
+        }
+        if let Some(prefix) = self.common_prefix(&[start, end], &CommonScale::Maximum)
+        // shared unit prefix
+        {
+            part.prefix_override = Some(prefix.0);
+        }
+
+        end_formatted = part.format(end);
+        if part.format(start) == end_formatted
+        // equal after rounding, collapse
+        {
+            return self.pad(end_formatted);
+        }
+
+        for p in part.format_to_parts(start).into_iter()
+        {
+            match p.kind
+            {
+                PartKind::Literal | PartKind::Prefix | PartKind::Unit => continue, // only after the end
+                PartKind::Affix if number_seen => continue, // suffix affix only after the end
+                PartKind::Affix | PartKind::Padding | PartKind::Sign => {}
+                _ => number_seen = true,
+            }
+            start_formatted.push_str(&p.value);
+        }
+
+        return self.pad(format!("{start_formatted}{}{end_formatted}", self.range_separator.0));
+    }
+
+
+    /// # Summary
+    /// Formats a range like `Formatter::format_range`, but either end may be open. A range open at the end is displayed as "≥ start", a range open at the start as "≤ end". Without both ends, the placeholder set with `Formatter::set_none_string` is displayed like `Formatter::format_opt` does.
+    ///
+    /// # Arguments
+    /// - `start`: the start of the range, none if open
+    /// - `end`: the end of the range, none if open
+    ///
+    /// # Returns
+    /// - formatted range
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(2))
+    ///     .set_range_separator(" – ", false);
+    /// assert_eq!(f.format_range_opt(Some(1200), None), "≥ 1,2 k");
+    /// assert_eq!(f.format_range_opt(None, Some(3400)), "≤ 3,4 k");
+    /// assert_eq!(f.format_range_opt(Some(1200), Some(3400)), "1,2 – 3,4 k");
+    /// assert_eq!(f.format_range_opt(None::<f64>, None), "—");
+    /// let f: scaler::Formatter = f.set_width(9, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format_range_opt(Some(1200), None), "  ≥ 1,2 k");
+    /// ```
+    pub fn format_range_opt<T>(&self, start: Option<T>, end: Option<T>) -> String
+    where
+        T: Into<f64>,
+    {
+        let part: Formatter = Formatter { show_original: (ShowOriginal::Off, false), width: 0, ..self.clone() }; // padded as a whole, not individually
+
+        return match (start, end)
+        {
+            (Some(start), Some(end)) => self.format_range(start, end),
+            (Some(start), None) => self.pad(format!("≥ {}", part.format(start.into()))),
+            (None, Some(end)) => self.pad(format!("≤ {}", part.format(end.into()))),
+            (None, None) => self.format_opt(None::<f64>),
+        };
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a count over an elapsed time as a rate, for example throughput in progress bars. The rate is formatted like `Formatter::format` with the time base appended to the unit, "1,235 M/s", or "1,235 MB/s" with the unit "B". The time base is set with `Formatter::set_rate_base`, per second by default. Sub-second elapsed times are resolved to the nanosecond. If no time has elapsed, the rate is infinite and displayed as such with the time base, signed like the count, unless nothing was counted either, then it is not a number and displayed as such with unit and time base, like a count that is not a number.
+    ///
+    /// # Arguments
+    /// - `count`: the number of items, bytes, or events
+    /// - `elapsed`: the time they took
+    ///
+    /// # Returns
+    /// - formatted rate
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_rate(2_469_134.0, Duration::from_secs(2)), "1,235 M/s");
+    /// assert_eq!(f.format_rate(1234.6, Duration::from_millis(1)), "1,235 M/s"); // sub-second
+    /// assert_eq!(f.format_rate(1.0, Duration::from_nanos(1)), "1,000 G/s");
+    /// assert_eq!(f.format_rate(5.0, Duration::from_secs(1)), "5,000 /s");
+    /// assert_eq!(f.format_rate(5.0, Duration::ZERO), "∞ /s");
+    /// assert_eq!(f.format_rate(-5.0, Duration::ZERO), "-∞ /s");
+    /// assert_eq!(f.format_rate(0.0, Duration::ZERO), "NaN /s"); // 0 / 0
+    /// assert_eq!(f.format_rate(f64::NAN, Duration::ZERO), "NaN /s");
+    /// let f: scaler::Formatter = f.set_unit("B");
+    /// assert_eq!(f.format_rate(2_469_134.0, Duration::from_secs(2)), "1,235 MB/s");
+    /// ```
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(2))
+    ///     .set_rate_base(scaler::RateBase::Auto);
+    /// assert_eq!(f.format_rate(3.2, Duration::from_secs(3600)), "3,2 /h"); // instead of "890 µ/s"
+    /// assert_eq!(f.format_rate(3.0, Duration::from_secs(60)), "3,0 /min");
+    /// assert_eq!(f.format_rate(3.0, Duration::from_secs(1)), "3,0 /s");
+    /// assert_eq!(f.format_rate(0.0, Duration::from_secs(1)), "0,0 /s");
+    /// let f: scaler::Formatter = f.set_rate_base(scaler::RateBase::PerHour);
+    /// assert_eq!(f.format_rate(3.0, Duration::from_secs(1)), "11 k/h");
+    /// ```
+    pub fn format_rate(&self, count: f64, elapsed: std::time::Duration) -> String
+    {
+        let base: &str; // time base appended to the unit
+        let mut rate: f64 = count * 1e9 / elapsed.as_nanos() as f64; // per second, from nanoseconds as fractions of a second like 0.001 are inexact
+
+
+        if rate.is_infinite() && !elapsed.is_zero()
+        // count too large to be multiplied first
+        {
+            rate = count / elapsed.as_secs_f64();
+        }
+        if elapsed.is_zero() && !count.is_nan() && count != 0.0
+        // no time elapsed, infinite, 0 / 0 is not a number
+        {
+            rate = f64::INFINITY.copysign(count);
+        }
+
+        (base, rate) = match self.rate_base
+        {
+            RateBase::Auto if rate != 0.0 && rate.abs() * 60.0 < 1.0 => ("/h", rate * 3600.0), // less than 1 per minute
+            RateBase::Auto if rate != 0.0 && rate.abs() < 1.0 => ("/min", rate * 60.0), // less than 1 per second
+            RateBase::Auto | RateBase::PerSecond => ("/s", rate),
+            RateBase::PerHour => ("/h", rate * 3600.0),
+            RateBase::PerMinute => ("/min", rate * 60.0),
+        };
+
+        return Formatter {
+            special_strings: if rate.is_nan() { (self.special_strings.0.clone(), Text::from(format!("{} {}{base}", self.special_strings.1, self.unit_for(f64::NAN)))) } else { self.special_strings.clone() }, // not a number is displayed without unit, but is still a rate
+            unit:            Text::from(format!("{}{base}", self.unit)),
+            unit_plural:     if self.unit_plural.is_empty() { Text::Static("") } else { Text::from(format!("{}{base}", self.unit_plural)) },
+            ..self.clone()
+        }
+        .format(rate);
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a `num_rational::Ratio<i64>` like `Formatter::format`, but rounds exactly on the rational instead of collapsing it to f64 first, so numbers just beside a tie are never collapsed onto it and misrounded. The decimal digits are generated by long division until the rounding position, the exact remainder decides the rounding, ties to even. A denominator of 0 is formatted like division by 0 in f64.
+    ///
+    /// # Arguments
+    /// - `r`: the rational number to format
+    ///
+    /// # Returns
+    /// - formatted rational number
+    ///
+    /// # Examples
+    /// ```
+    /// use num_rational::Ratio;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(6));
+    /// assert_eq!(f.format_ratio(&Ratio::new(1, 3)), "333,333 m");
+    /// assert_eq!(f.format_ratio(&Ratio::new(-2, 3)), "-666,667 m");
+    /// assert_eq!(f.format_ratio(&Ratio::new(1_000_000, 7)), "142,857 k");
+    /// assert_eq!(f.format_ratio(&Ratio::new(1, 7_000_000_000)), "142,857 p");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(6));
+    /// assert_eq!(f.format_ratio(&Ratio::new(1, 3)), "0,333333");
+    /// assert_eq!(f.format_ratio(&Ratio::new(0, 3)), "0,00000");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(19));
+    /// assert_eq!(f.format_ratio(&Ratio::from_integer(i64::MAX)), "9.223.372.036.854.775.807");
+    /// assert_eq!(f.format_ratio(&Ratio::new_raw(1, 0)), "∞");
+    /// assert_eq!(f.format_ratio(&Ratio::new_raw(-1, 0)), "-∞");
+    /// assert_eq!(f.format_ratio(&Ratio::new_raw(0, 0)), "NaN");
+    /// assert_eq!(f.format_ratio(&Ratio::new_raw(1, -4)), "-0,2500000000000000000");
+    /// ```
+    ///
+    /// ```
+    /// // ties to even on the exact remainder
+    /// use num_rational::Ratio;
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2));
+    /// assert_eq!(f.format_ratio(&Ratio::new(1, 8)), "0,12"); // tie, 0,125 to even
+    /// assert_eq!(f.format_ratio(&Ratio::new(3, 8)), "0,38"); // tie, 0,375 to even
+    /// assert_eq!(f.format_ratio(&Ratio::new(-1, 8)), "-0,12");
+    /// assert_eq!(f.format_ratio(&Ratio::new(31, 200)), "0,16"); // tie, 0,155 to even
+    /// assert_eq!(f.format_ratio(&Ratio::new(1_000_000_001, 8_000_000_000)), "0,13"); // just above the tie
+    /// assert_eq!(f.format_ratio(&Ratio::new(1_000_000_000_000_000_001, 8_000_000_000_000_000_000)), "0,13"); // just above the tie
+    /// assert_eq!(f.format(1_000_000_000_000_000_001.0 / 8_000_000_000_000_000_000.0), "0,12"); // f64 collapses to the tie
+    /// assert_eq!(f.format_ratio(&Ratio::new(999_999_999, 8_000_000_000)), "0,12"); // just below the tie
+    /// assert_eq!(f.format_ratio(&Ratio::new(1, 3_000)), "0,00");
+    /// assert_eq!(f.format_ratio(&Ratio::new(199, 2)), "99,50");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_rounding(scaler::Rounding::Magnitude(1));
+    /// assert_eq!(f.format_ratio(&Ratio::new(25, 1)), "20"); // tie to even
+    /// assert_eq!(f.format_ratio(&Ratio::new(251, 10)), "30");
+    /// assert_eq!(f.format_ratio(&Ratio::new(4, 1)), "0");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(4));
+    /// assert_eq!(f.format_ratio(&Ratio::new(3_000_000, 7)), "418,6 Ki");
+    /// ```
+    pub fn format_ratio(&self, r: &num_rational::Ratio<i64>) -> String
+    {
+        let denominator: u128 = r.denom().unsigned_abs() as u128;
+        let mut digits: String; // digits of the quotient so far, then one more digit and a non-zero digit if there is any remainder
+        let mut exponent: i64 = 0; // decimal exponent of the last digit
+        let mut magnitude: Option<i64>; // magnitude of the quotient, as soon as the first non-zero digit is known
+        let negative: bool = *r.numer() != 0 && (*r.numer() < 0) != (*r.denom() < 0);
+        let mut remainder: u128; // remainder of the long division
+        let mut s: String = String::new(); // formatted rational number, result
+
+
+        if denominator == 0
+        // division by 0, like f64
+        {
+            return self.format(match r.numer().signum()
+            {
+                0 => f64::NAN,
+                1 => f64::INFINITY,
+                _ => f64::NEG_INFINITY,
+            });
+        }
+
+        digits = (r.numer().unsigned_abs() as u128 / denominator).to_string();
+        remainder = r.numer().unsigned_abs() as u128 % denominator;
+        magnitude = if digits != "0" { Some(digits.len() as i64 - 1) } else { None };
+        loop
+        {
+            let last: i64 = match (&self.rounding, magnitude) // last digit needed: one beyond the rounding position, the remainder decides the rest
+            {
+                (Rounding::Magnitude(precision), _) => *precision as i64 - 1,
+                (Rounding::SignificantDigits(0), _) => i64::MAX, // rounded to 0 significants is always 0
+                (Rounding::SignificantDigits(precision), Some(magnitude)) => magnitude - *precision as i64,
+                (Rounding::SignificantDigits(_), None) => i64::MIN, // first significant digit still to come
+            };
+            if remainder == 0 || exponent <= last
+            {
+                break;
+            }
+
+            remainder *= 10;
+            digits.push((b'0' + (remainder / denominator) as u8) as char);
+            remainder %= denominator;
+            exponent -= 1;
+            if magnitude.is_none() && !digits.ends_with('0')
+            {
+                magnitude = Some(exponent);
+            }
+        }
+        if remainder != 0
+        // more digits follow, a non-zero digit marks that the quotient is above the digits so far, decides rounding exactly
+        {
+            digits.push('1');
+            exponent -= 1;
+        }
+
+        self.render(Value::Big(negative, &digits, exponent), &mut |_kind, part| {
+            s.push_str(part);
+            return Ok(());
+        })
+        .expect("Writing into a String failed even though it is infallible.");
+        return s;
+    }
+}
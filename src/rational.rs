@@ -0,0 +1,125 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats the exact rational number the stored f64 represents, for debugging and teaching, "3.602.879.701.896.397 / 36.028.797.018.963.968" for 0.1. Every finite f64 is an integer times a power of 2, so the fraction is reduced to an odd numerator over a power of 2 as denominator, up to 2^(1074) for the smallest subnormal. Numerator and denominator are computed exactly, without rounding, and both grouped with the configured group separator. Whole numbers, including numbers beyond 2^(53), have the denominator 1 and are displayed as the numerator alone. The sign is displayed before the numerator like in `Formatter::format`, -0 is unsigned like there. Infinity and NaN are formatted like in `Formatter::format`. The configured width applies to the result as a whole, the other options do not.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - formatted fraction
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_rational(0.1), "3.602.879.701.896.397 / 36.028.797.018.963.968");
+    /// assert_eq!(f.format_rational(-2.5), "-5 / 2");
+    /// assert_eq!(f.format_rational(3.0), "3");
+    /// assert_eq!(f.format_rational(1e23), "99.999.999.999.999.991.611.392"); // nearest f64 below
+    /// assert_eq!(f.format_rational(-0.0), "0");
+    /// assert_eq!(f.format_rational(f64::NAN), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_separators("", ".");
+    /// assert_eq!(f.format_rational(1.0 / 3.0), "6004799503160661 / 18014398509481984");
+    /// assert!(f.format_rational(f64::from_bits(1)).starts_with("1 / 2024022533073106183524953467189173070495566497641421")); // 2^(-1074)
+    /// ```
+    pub fn format_rational(&self, x: f64) -> String
+    {
+        let bits: u64 = x.to_bits();
+        let mut exponent: i32; // binary exponent of the odd numerator
+        let mut s: String; // formatted fraction without padding
+        let mut significand: u64; // odd numerator before shifting, or 0
+
+
+        if !x.is_finite()
+        // no fraction
+        {
+            return self.format(x);
+        }
+
+        (significand, exponent) = match bits >> 52 & 0x7FF
+        {
+            0 => (bits & ((1 << 52) - 1), -1074), // subnormal
+            biased => (bits & ((1 << 52) - 1) | 1 << 52, biased as i32 - 1075), // with implicit leading 1
+        };
+        if significand == 0
+        // 0 is whole
+        {
+            exponent = 0;
+        }
+        else
+        // reduce, the denominator has no other prime factor than 2
+        {
+            exponent += significand.trailing_zeros() as i32;
+            significand >>= significand.trailing_zeros();
+        }
+
+        s = self.sign_str(x < 0.0, x == 0.0).to_owned();
+        s.push_str(&self.group_digits(&shifted_digits(significand, exponent.max(0) as u32)).expect("Integer digits are always valid."));
+        if exponent < 0
+        // denominator other than 1
+        {
+            s.push_str(" / ");
+            s.push_str(&self.group_digits(&shifted_digits(1, -exponent as u32)).expect("Integer digits are always valid."));
+        }
+
+        return self.pad(s);
+    }
+}
+
+
+/// # Summary
+/// Calculates the decimal digits of an integer times a power of 2 exactly, in base 10^9 limbs, for numerators and denominators beyond u128.
+///
+/// # Arguments
+/// - `n`: the integer
+/// - `shift`: the exponent of the power of 2
+///
+/// # Returns
+/// - the decimal digits of n * 2^shift without leading zeros, "0" for 0
+fn shifted_digits(n: u64, shift: u32) -> String
+{
+    const BASE: u64 = 1_000_000_000; // limbs are below 2^30, so shifted by 32 bits they fit u64 with carry
+    let mut limbs: Vec<u64> = vec![n % BASE, n / BASE % BASE, n / BASE / BASE]; // least significant first
+    let mut remaining: u32 = shift; // bits still to shift by
+    let mut s: String; // decimal digits
+
+
+    while 0 < remaining
+    {
+        let step: u32 = remaining.min(32);
+        let mut carry: u64 = 0;
+
+        for limb in limbs.iter_mut()
+        {
+            let product: u64 = (*limb << step) + carry;
+            *limb = product % BASE;
+            carry = product / BASE;
+        }
+        while carry != 0
+        {
+            limbs.push(carry % BASE);
+            carry /= BASE;
+        }
+        remaining -= step;
+    }
+    while 1 < limbs.len() && limbs.last() == Some(&0)
+    // leading zero limbs
+    {
+        limbs.pop();
+    }
+
+    s = limbs.last().expect("Limbs are never empty.").to_string();
+    for limb in limbs.iter().rev().skip(1)
+    {
+        s.push_str(&format!("{limb:09}"));
+    }
+    return s;
+}
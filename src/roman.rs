@@ -0,0 +1,135 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::format_roman` if the number has no Roman numeral.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RomanError
+{
+    OutOfRange, // 0, negative, or beyond the largest numeral of the configured notation for large numbers
+}
+
+
+/// # Summary
+/// Values and symbols of the Roman numerals, largest first, subtractive pairs included.
+const NUMERALS: [(i64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats an integer as Roman numeral, for page numbers, outline levels, and clock faces, "MCMXCIV" for 1994. Numbers from 1 to 3999 are written in standard subtractive notation, or in additive notation like "IIII" for 4 and "VIIII" for 9 as on old inscriptions and clock faces. Lowercase, additive notation, and how to write numbers above 3999 are set with `Formatter::set_roman`. Above 3999, the thousands are written as numeral with overlines, "I̅V̅" for 4000, or in parentheses, "(IV)" for 4000, up to 3.999.999. The configured width applies, the other options do not.
+    ///
+    /// # Arguments
+    /// - `n`: the number to format
+    ///
+    /// # Returns
+    /// - formatted Roman numeral
+    /// - or `RomanError::OutOfRange` for 0, negative numbers, and numbers beyond the largest numeral
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_roman(1994), Ok("MCMXCIV".to_string()));
+    /// assert_eq!(f.format_roman(3999), Ok("MMMCMXCIX".to_string()));
+    /// assert_eq!(f.format_roman(0), Err(scaler::RomanError::OutOfRange));
+    /// assert_eq!(f.format_roman(-5), Err(scaler::RomanError::OutOfRange));
+    /// assert_eq!(f.format_roman(4000), Err(scaler::RomanError::OutOfRange));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_roman(scaler::RomanOptions { additive: true, large: scaler::RomanLarge::Parentheses, lowercase: true })
+    ///     .set_width(8, scaler::Align::Left, ' ');
+    /// assert_eq!(f.format_roman(4), Ok("iiii    ".to_string()));
+    /// assert_eq!(f.format_roman(1994), Ok("mdcccclxxxxiiii".to_string()));
+    /// assert_eq!(f.format_roman(4009), Ok("(iiii)viiii".to_string()));
+    /// ```
+    pub fn format_roman(&self, n: i64) -> Result<String, RomanError>
+    {
+        let largest: i64 = if self.roman.large == RomanLarge::Unsupported { 3999 } else { 3_999_999 }; // largest number with a numeral
+        let mut s: String; // formatted numeral without padding
+
+
+        if !(1..=largest).contains(&n)
+        {
+            return Err(RomanError::OutOfRange);
+        }
+
+        s = match self.roman.large
+        {
+            _ if n <= 3999 => roman_numeral(n, self.roman.additive),
+            RomanLarge::Overline => roman_numeral(n / 1000, self.roman.additive).chars().flat_map(|c| [c, '\u{0305}']).chain(roman_numeral(n % 1000, self.roman.additive).chars()).collect(), // combining overline after every letter of the thousands
+            RomanLarge::Parentheses => format!("({}){}", roman_numeral(n / 1000, self.roman.additive), roman_numeral(n % 1000, self.roman.additive)),
+            RomanLarge::Unsupported => unreachable!("Numbers above 3999 are out of range without notation for large numbers."),
+        };
+        if self.roman.lowercase
+        {
+            s.make_ascii_lowercase();
+        }
+
+        return Ok(self.pad(s));
+    }
+}
+
+
+impl std::fmt::Display for RomanError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            RomanError::OutOfRange => write!(f, "Number has no Roman numeral."),
+        };
+    }
+}
+
+
+impl std::error::Error for RomanError {}
+
+
+/// # Summary
+/// Writes a number below 4000 as Roman numeral, greedily from the largest numeral.
+///
+/// # Arguments
+/// - `n`: the number, 0 results in an empty numeral
+/// - `additive`: whether to skip the subtractive pairs like "IV"
+///
+/// # Returns
+/// - the numeral in uppercase
+fn roman_numeral(mut n: i64, additive: bool) -> String
+{
+    let mut s: String = String::new();
+
+
+    for (value, symbol) in NUMERALS
+    {
+        if additive && symbol.len() == 2
+        // subtractive pair
+        {
+            continue;
+        }
+        while value <= n
+        {
+            s.push_str(symbol);
+            n -= value;
+        }
+    }
+
+    return s;
+}
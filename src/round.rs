@@ -1,4 +1,8 @@
 // Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::{Digits, StackString};
+use crate::magnitude::decimal_magnitude;
+use crate::{Formatter, Rounding};
+use std::fmt::Write;
 
 
 /// # Summary
@@ -6,7 +10,7 @@
 pub trait Round
 {
     /// # Summary
-    /// Rounds a number x to a specific magnitude m where x ≈ 10^m. Meaning if x shall be rounded to whole numbers, magnitude is 0. If x shall be rounded to 10s, magnitude is 1. If x shall be rounded to 0.1s, magnitude is -1.
+    /// Rounds a number x to a specific magnitude m where x ≈ 10^m. Meaning if x shall be rounded to whole numbers, magnitude is 0. If x shall be rounded to 10s, magnitude is 1. If x shall be rounded to 0.1s, magnitude is -1. The exact value of the f64 is rounded, ties to even, so numbers stored slightly above or below a tie round accordingly. The result is the f64 closest to the rounded decimal number.
     ///
     /// # Arguments
     /// - `magnitude`: the magnitude to round to
@@ -25,6 +29,8 @@ pub trait Round
     /// assert_eq!(x.round_mag(0), 42.0);
     /// assert_eq!(x.round_mag(1), 40.0);
     /// assert_eq!(x.round_mag(2), 0.0);
+    /// assert_eq!(f64::MAX.round_mag(-5), f64::MAX); // already whole
+    /// assert_eq!(f64::MIN_POSITIVE.round_mag(-310), 2.23e-308); // exact decimal expansion
     /// ```
     /// ```
     /// use scaler::round::Round;
@@ -53,7 +59,7 @@ pub trait Round
     /// assert_eq!(x.round_sig(1), 100.0);
     /// assert_eq!(x.round_sig(2), 120.0);
     /// assert_eq!(x.round_sig(3), 123.0);
-    /// assert_eq!(x.round_sig(4), 123.4);
+    /// assert_eq!(x.round_sig(4), 123.5); // stored as 123.4500000000000028..., above the tie
     /// assert_eq!(x.round_sig(5), 123.45);
     /// assert_eq!(x.round_sig(6), 123.450);
     /// ```
@@ -66,30 +72,372 @@ pub trait Round
     /// assert_eq!(x.round_sig(2), 0.79);
     /// assert_eq!(x.round_sig(3), 0.789);
     /// assert_eq!(x.round_sig(4), 0.7890);
+    /// assert_eq!(4.5317e-17_f64.round_sig(1), 5e-17); // closest f64 to the rounded number, so it equals parsing "5e-17"
     /// ```
     fn round_sig(&self, significants: u8) -> Self;
 }
 
 
-impl Round for f64 // TODO implement for all number types
+/// # Summary
+/// Adds 2 measured values and propagates their significant figures like in lab reports: the sum is rounded to the decimal place of the least precise summand. 0 counts as of magnitude 0, so (0.0, 3) is 0.00. The sum is returned rounded, so chained calls round after every step like in textbooks.
+///
+/// # Arguments
+/// - `a`: the first value and its number of significant digits
+/// - `b`: the second value and its number of significant digits
+///
+/// # Returns
+/// - the rounded sum and its number of significant digits, the fewer significant digits of both if it is infinite or NaN
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::round::add_sig(scaler::round::add_sig((12.11, 4), (18.0, 3)), (1.013, 4)), (31.1, 3));
+/// assert_eq!(scaler::round::add_sig((9.96, 3), (0.05, 1)), (10.01, 4)); // the sum gains a digit
+/// assert_eq!(scaler::round::add_sig((1200.0, 2), (34.0, 2)), (1200.0, 2)); // hundreds are the least precise place
+/// ```
+pub fn add_sig(a: (f64, u8), b: (f64, u8)) -> (f64, u8)
 {
-    fn round_mag(&self, magnitude: i16) -> Self
+    return round_to_place(a.0 + b.0, last_place(a).max(last_place(b)), a.1.min(b.1));
+}
+
+
+/// # Summary
+/// Subtracts 2 measured values and propagates their significant figures like `add_sig`: the difference is rounded to the decimal place of the less precise value, so cancellation loses significant digits.
+///
+/// # Arguments
+/// - `a`: the minuend and its number of significant digits
+/// - `b`: the subtrahend and its number of significant digits
+///
+/// # Returns
+/// - the rounded difference and its number of significant digits, the fewer significant digits of both if it is infinite or NaN
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::round::sub_sig((25.624, 5), (25.1, 3)), (0.5, 1));
+/// assert_eq!(scaler::round::sub_sig((100.0, 4), (0.25, 2)), (99.8, 3)); // to tenths, ties to even
+/// assert_eq!(scaler::round::sub_sig((1.0, 2), (1.0, 2)), (0.0, 2)); // 0.0
+/// ```
+pub fn sub_sig(a: (f64, u8), b: (f64, u8)) -> (f64, u8)
+{
+    return round_to_place(a.0 - b.0, last_place(a).max(last_place(b)), a.1.min(b.1));
+}
+
+
+/// # Summary
+/// Multiplies 2 measured values and propagates their significant figures like in lab reports: the product keeps the fewer significant digits of both factors.
+///
+/// # Arguments
+/// - `a`: the first factor and its number of significant digits
+/// - `b`: the second factor and its number of significant digits
+///
+/// # Returns
+/// - the product rounded to the fewer significant digits and their number
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::round::mul_sig((4.56, 3), (1.4, 2)), (6.4, 2));
+/// assert_eq!(scaler::round::mul_sig((2.0, 1), (3.0, 4)), (6.0, 1));
+/// assert_eq!(scaler::round::mul_sig((0.0, 3), (1.4, 2)), (0.0, 2));
+/// ```
+pub fn mul_sig(a: (f64, u8), b: (f64, u8)) -> (f64, u8)
+{
+    return ((a.0 * b.0).round_sig(a.1.min(b.1)), a.1.min(b.1));
+}
+
+
+/// # Summary
+/// Divides 2 measured values and propagates their significant figures like `mul_sig`: the quotient keeps the fewer significant digits of both.
+///
+/// # Arguments
+/// - `a`: the dividend and its number of significant digits
+/// - `b`: the divisor and its number of significant digits
+///
+/// # Returns
+/// - the quotient rounded to the fewer significant digits and their number, infinite or NaN if divided by 0
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::round::div_sig((25.0, 2), (3.0, 1)), (8.0, 1));
+/// assert_eq!(scaler::round::div_sig((1.000, 4), (3.00, 3)), (0.333, 3));
+/// assert_eq!(scaler::round::div_sig((1.0, 2), (0.0, 1)), (f64::INFINITY, 1));
+/// ```
+pub fn div_sig(a: (f64, u8), b: (f64, u8)) -> (f64, u8)
+{
+    return ((a.0 / b.0).round_sig(a.1.min(b.1)), a.1.min(b.1));
+}
+
+
+/// # Summary
+/// Rounds every number of a slice in place to a magnitude like `Round::round_mag`, for preprocessing whole arrays before aggregation or display. The power of 10 is calculated once for the whole slice instead of once per number, the results are identical to the scalar method. Infinity and NaN are left unchanged.
+///
+/// # Arguments
+/// - `values`: the numbers to round
+/// - `magnitude`: the magnitude to round to
+///
+/// # Examples
+/// ```
+/// let mut values: [f64; 5] = [42.069, -0.05, 2.5, f64::NAN, f64::NEG_INFINITY];
+/// scaler::round::round_slice_mag(&mut values, -1);
+/// assert_eq!(values[..3], [42.1, -0.1, 2.5]); // -0.05 is stored slightly beyond the tie
+/// assert!(values[3].is_nan());
+/// assert_eq!(values[4], f64::NEG_INFINITY);
+/// ```
+pub fn round_slice_mag(values: &mut [f64], magnitude: i16)
+{
+    let power: f64 = power_of_10(magnitude); // same for every number
+
+
+    for x in values.iter_mut()
     {
-        let x_rounded: Self;
+        if x.is_finite()
+        {
+            *x = round_mag_with_power(*x, magnitude, power);
+        }
+    }
+}
+
+
+/// # Summary
+/// Rounds every number of a slice to a magnitude like `round_slice_mag`, but into an existing Vec and leaves the source as it is. Afterwards `dst` contains exactly the rounded numbers in input order, its capacity is reused.
+///
+/// # Arguments
+/// - `dst`: receives the rounded numbers, previous content is replaced
+/// - `src`: the numbers to round
+/// - `magnitude`: the magnitude to round to
+///
+/// # Examples
+/// ```
+/// let mut rounded: Vec<f64> = vec![1.0; 10];
+/// scaler::round::round_slice_mag_to(&mut rounded, &[1234.5, 1250.0, f64::INFINITY], 2);
+/// assert_eq!(rounded, [1200.0, 1200.0, f64::INFINITY]); // ties to even
+/// ```
+pub fn round_slice_mag_to(dst: &mut Vec<f64>, src: &[f64], magnitude: i16)
+{
+    let power: f64 = power_of_10(magnitude); // same for every number
+
+
+    dst.clear();
+    dst.extend(src.iter().map(|x| if x.is_finite() { round_mag_with_power(*x, magnitude, power) } else { *x }));
+}
 
 
-        if *self == 0 as Self
-        // rounded 0 is always 0
+/// # Summary
+/// Rounds every number of a slice in place to a number of significant digits like `Round::round_sig`, for preprocessing whole arrays before aggregation or display. The results are identical to the scalar method, except that infinity and NaN are left unchanged even when rounding to 0 significant digits.
+///
+/// # Arguments
+/// - `values`: the numbers to round
+/// - `significants`: the number of significant digits to round to, rounding to 0 significant digits returns 0 for finite numbers
+///
+/// # Examples
+/// ```
+/// let mut values: [f64; 5] = [123.45, -0.0789, 0.0, f64::INFINITY, f64::NAN];
+/// scaler::round::round_slice_sig(&mut values, 2);
+/// assert_eq!(values[..4], [120.0, -0.079, 0.0, f64::INFINITY]);
+/// assert!(values[4].is_nan());
+/// ```
+pub fn round_slice_sig(values: &mut [f64], significants: u8)
+{
+    for x in values.iter_mut()
+    {
+        if x.is_finite()
         {
-            return 0 as Self;
+            *x = x.round_sig(significants);
         }
+    }
+}
+
+
+/// # Summary
+/// Rounds every number of a slice to a number of significant digits like `round_slice_sig`, but into an existing Vec and leaves the source as it is. Afterwards `dst` contains exactly the rounded numbers in input order, its capacity is reused.
+///
+/// # Arguments
+/// - `dst`: receives the rounded numbers, previous content is replaced
+/// - `src`: the numbers to round
+/// - `significants`: the number of significant digits to round to
+///
+/// # Examples
+/// ```
+/// let mut rounded: Vec<f64> = Vec::new();
+/// scaler::round::round_slice_sig_to(&mut rounded, &[0.123456, 98765.0, f64::NAN], 3);
+/// assert_eq!(rounded[..2], [0.123, 98800.0]);
+/// assert!(rounded[2].is_nan());
+/// ```
+pub fn round_slice_sig_to(dst: &mut Vec<f64>, src: &[f64], significants: u8)
+{
+    dst.clear();
+    dst.extend(src.iter().map(|x| if x.is_finite() { x.round_sig(significants) } else { *x }));
+}
+
+
+/// # Summary
+/// Finds the decimal place of the last significant digit of a measured value, like -2 for 12.11 with 4 significant digits. 0 counts as of magnitude 0.
+///
+/// # Arguments
+/// - `x`: the value and its number of significant digits
+///
+/// # Returns
+/// - magnitude of the last significant digit
+fn last_place(x: (f64, u8)) -> i32
+{
+    return magnitude(x.0) - i32::from(x.1) + 1;
+}
+
+
+/// # Summary
+/// Finds the magnitude of the leading digit of a number like `Round::round_sig` does, 0 for 0.
+///
+/// # Arguments
+/// - `x`: the number
+///
+/// # Returns
+/// - magnitude of the leading digit
+fn magnitude(x: f64) -> i32
+{
+    if x == 0.0
+    {
+        return 0;
+    }
+    return decimal_magnitude(x).into();
+}
+
 
+/// # Summary
+/// Calculates 10^|m| as f64 for the magnitude m numbers are rounded to, exact up to 10^(22), infinite beyond f64. Hoisted out of loops by the slice functions, as it only depends on the magnitude.
+///
+/// # Arguments
+/// - `magnitude`: the magnitude to round to
+///
+/// # Returns
+/// - the power of 10 to scale by
+fn power_of_10(magnitude: i16) -> f64
+{
+    return 10_f64.powi(i32::from(magnitude).abs());
+}
 
-        x_rounded = (*self * Self::powi(10 as Self, (-magnitude).into())).round_ties_even() * Self::powi(10 as Self, magnitude.into()); // multiply by 10^(-magnitude), round, multiply by 10^(magnitude)
 
+/// # Summary
+/// Rounds a number to a magnitude like `Round::round_mag`, with the power of 10 to scale by already calculated, so rounding a whole slice to the same magnitude calculates it once.
+///
+/// # Arguments
+/// - `x`: the number to round
+/// - `magnitude`: the magnitude to round to
+/// - `power`: 10^|magnitude| from `power_of_10`
+///
+/// # Returns
+/// - the rounded number
+fn round_mag_with_power(x: f64, magnitude: i16, power: f64) -> f64
+{
+    let mut digits: Digits; // exact decimal expansion, for rounding close to ties
+    let mut s: StackString<32> = StackString::new(); // rounded integer with exponent, at most 16 digits, sign, and "e-324"
+    let scaled_exactly: bool; // whether scaling by 10^(-magnitude) had no rounding error
+    let x_rounded: f64;
+    let x_scaled: f64;
+
+
+    if x == 0.0
+    // rounded 0 is always 0
+    {
+        return 0.0;
+    }
+    if !x.is_finite() || magnitude < -324
+    // nothing to round, or rounding finer than the smallest subnormal changes nothing
+    {
+        return x;
+    }
+
+
+    x_scaled = if magnitude < 0 { x * power } else { x / power }; // divide by 10^magnitude, inexact by a few ulps at most
+    if 2_f64.powi(54) <= x_scaled.abs() && x_scaled.is_finite()
+    // digits end long before magnitude, rounding moves x by less than a quarter ulp, already rounded
+    {
+        return x;
+    }
+    scaled_exactly = match magnitude
+    {
+        0 => true,
+        -22..=-1 => x.mul_add(power, -x_scaled) == 0.0, // no remainder of the multiplication
+        1..=22 => x_scaled.mul_add(power, -x) == 0.0, // no remainder of the division
+        _ => false,
+    };
+    if x_scaled.abs() < 2_f64.powi(53) && (scaled_exactly || x_scaled.abs() * 16.0 * f64::EPSILON < (x_scaled.abs().fract() - 0.5).abs())
+    // scaled exactly, or far enough from a tie that the error of scaling cannot change the rounding direction
+    {
+        if (-22..=22).contains(&magnitude)
+        // rounded integer and power of 10 are both exact, so a single multiplication or division is correctly rounded as well, without the detour through a string
+        {
+            return if magnitude < 0 { x_scaled.round_ties_even() / power } else { x_scaled.round_ties_even() * power };
+        }
+        write!(s, "{}e{magnitude}", x_scaled.round_ties_even()).expect("Rounded integer with exponent does not fit into the stack string even though it is sized for 2^(53).");
+        x_rounded = s.as_str().parse::<f64>().expect("Rounded integer with exponent is not a valid f64 even though it is finite."); // multiply by 10^(magnitude), correctly rounded unlike multiplying with a power of 10, so the result is the closest f64 to the rounded decimal number
         return x_rounded;
     }
 
+    digits = Digits::from_f64(x.abs()); // close to a tie or 10^(-magnitude) beyond f64, round the exact decimal expansion instead
+    digits.round_mag(magnitude.into());
+    return digits.to_f64().copysign(x);
+}
+
+
+/// # Summary
+/// Rounds the result of an addition or subtraction to the decimal place of the less precise operand and counts its significant digits.
+///
+/// # Arguments
+/// - `x`: the unrounded result
+/// - `place`: magnitude of the last significant digit
+/// - `fallback`: number of significant digits if `x` is infinite or NaN
+///
+/// # Returns
+/// - the rounded result and its number of significant digits
+fn round_to_place(x: f64, place: i32, fallback: u8) -> (f64, u8)
+{
+    let x_rounded: f64;
+
+
+    if !x.is_finite()
+    // no decimal places
+    {
+        return (x, fallback);
+    }
+
+    x_rounded = x.round_mag(place.clamp(i16::MIN.into(), i16::MAX.into()) as i16);
+    return (x_rounded, (magnitude(x_rounded) - place + 1).clamp(0, u8::MAX.into()) as u8);
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a measured value with its own number of significant digits, like the results of `add_sig` and `mul_sig`, regardless of the rounding of this formatter. Like `Formatter::format_with_rounding` with `Rounding::SignificantDigits`, so trailing zeros show the precision if enabled.
+    ///
+    /// # Arguments
+    /// - `value`: the value to format
+    /// - `sig`: number of significant digits of the value
+    ///
+    /// # Returns
+    /// - the formatted value
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_scaling(scaler::Scaling::None);
+    /// let (sum, sig): (f64, u8) = scaler::round::add_sig((12.11, 4), (18.0, 3));
+    /// assert_eq!(f.format_sig(sum, sig), "30,1");
+    /// assert_eq!(f.format_sig(6.4, 2), "6,4");
+    /// assert_eq!(f.format_sig(6.0, 3), "6,00"); // trailing zeros are significant
+    /// assert_eq!(f.format(6.0), "6,000"); // default rounding unchanged
+    /// ```
+    pub fn format_sig(&self, value: f64, sig: u8) -> String
+    {
+        return self.format_with_rounding(value, Rounding::SignificantDigits(sig));
+    }
+}
+
+
+impl Round for f64 // TODO implement for all number types
+{
+    fn round_mag(&self, magnitude: i16) -> Self
+    {
+        return round_mag_with_power(*self, magnitude, power_of_10(magnitude));
+    }
+
 
     fn round_sig(&self, significants: u8) -> Self
     {
@@ -104,7 +452,7 @@ impl Round for f64 // TODO implement for all number types
         }
 
 
-        magnitude = self.abs().log10().floor() as i16; // current magnitude of x
+        magnitude = decimal_magnitude(*self); // current magnitude of x, exact so numbers just below a power of 10 keep all their significant digits
         x_rounded = self.round_mag(magnitude - i16::from(significants) + 1); // round to significants
 
         return x_rounded;
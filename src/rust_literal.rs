@@ -0,0 +1,167 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a number as Rust float literal, for generated code, fixtures, and snapshot tests, like "1_234_567.5_f64" for 1234567.5. The digits are the shortest ones that parse back to the identical f64, so removing the type suffix and the underscores and parsing with `str::parse::<f64>` yields the number bit for bit, including the sign of -0. The integer digits are grouped by 3 with "_", the decimal separator is always ".", and numbers below 10^(-4) or from 10^(16) are written in scientific notation like "1.5e-7_f64". Integers keep ".0", so the literal stays a float without suffix. Whether the suffix "_f64" is appended is set with `Formatter::set_rust_literal_suffix`. Infinity and NaN are written as the expressions "f64::INFINITY", "f64::NEG_INFINITY", and "f64::NAN", the payload and sign of NaN are not preserved. Neither scaling, rounding, sign, affixes, unit, nor width apply, so the output is always a literal and nothing else.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - Rust float literal or constant
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_rust_literal(1234567.5), "1_234_567.5_f64");
+    /// assert_eq!(f.format_rust_literal(0.1), "0.1_f64"); // shortest digits
+    /// assert_eq!(f.format_rust_literal(-42.0), "-42.0_f64");
+    /// assert_eq!(f.format_rust_literal(-0.0), "-0.0_f64");
+    /// assert_eq!(f.format_rust_literal(1.5e-7), "1.5e-7_f64");
+    /// assert_eq!(f.format_rust_literal(f64::MAX), "1.7976931348623157e308_f64");
+    /// assert_eq!(f.format_rust_literal(f64::NEG_INFINITY), "f64::NEG_INFINITY");
+    /// assert_eq!(f.format_rust_literal(f64::NAN), "f64::NAN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rust_literal_suffix(false)
+    ///     .set_width(20, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format_rust_literal(1234567.5), "1_234_567.5");
+    /// assert_eq!(f.format_rust_literal(1.0 / 3.0), "0.3333333333333333");
+    /// assert_eq!(f.format_rust_literal(9007199254740993.0), "9_007_199_254_740_992.0"); // nearest f64
+    /// assert_eq!(f.format_rust_literal(1e16), "1e16");
+    /// ```
+    pub fn format_rust_literal(&self, x: f64) -> String
+    {
+        let digits: String = format!("{:?}", x.abs()); // shortest representation that parses back, with ".0" or exponent
+        let exponent_start: usize = digits.find('e').unwrap_or(digits.len());
+        let integer_end: usize = digits.find('.').unwrap_or(exponent_start);
+        let mut s: String = String::new();
+
+
+        if x.is_nan()
+        {
+            return "f64::NAN".to_owned();
+        }
+        if x.is_infinite()
+        {
+            return if x.is_sign_negative() { "f64::NEG_INFINITY" } else { "f64::INFINITY" }.to_owned();
+        }
+
+        if x.is_sign_negative()
+        // including -0, so it parses back with its sign
+        {
+            s.push('-');
+        }
+        s.push_str(&underscored(&digits[..integer_end]));
+        s.push_str(&digits[integer_end..]);
+        if self.rust_literal_suffix
+        {
+            s.push_str("_f64");
+        }
+
+        return s;
+    }
+
+
+    /// # Summary
+    /// Formats an integer as Rust integer literal, for generated code, fixtures, and snapshot tests, like "42_069_u64" for 42069_u64. All digits are kept, grouped by 3 with "_", and the type suffix is the type of the integer, appended if set with `Formatter::set_rust_literal_suffix`. Negative integers are written with a leading "-". Neither scaling, rounding, sign, affixes, unit, nor width apply. For hexadecimal, octal, and binary literals, use `Formatter::format_rust_literal_radix`.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    ///
+    /// # Returns
+    /// - Rust integer literal
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_rust_literal_int(42069_u64), "42_069_u64");
+    /// assert_eq!(f.format_rust_literal_int(-7_i8), "-7_i8");
+    /// assert_eq!(f.format_rust_literal_int(u128::MAX), "340_282_366_920_938_463_463_374_607_431_768_211_455_u128");
+    /// let f: scaler::Formatter = f.set_rust_literal_suffix(false);
+    /// assert_eq!(f.format_rust_literal_int(1_000_000_usize), "1_000_000");
+    /// ```
+    pub fn format_rust_literal_int<T>(&self, x: T) -> String
+    where
+        T: FormatInt,
+    {
+        let (negative, magnitude): (bool, u128) = x.sign_magnitude();
+        let mut s: String = String::new();
+
+
+        if negative
+        {
+            s.push('-');
+        }
+        s.push_str(&underscored(&magnitude.to_string()));
+        if self.rust_literal_suffix && !T::LITERAL_SUFFIX.is_empty()
+        {
+            s.push('_');
+            s.push_str(T::LITERAL_SUFFIX);
+        }
+
+        return s;
+    }
+
+
+    /// # Summary
+    /// Formats an integer as Rust integer literal in another base, like "0xDEAD_BEEF". Like `Formatter::format_radix`, with the group size and minimum number of digits set with `Formatter::set_radix`, but always with the prefix "0x", "0o", or "0b", "_" as group separator, and without width. No type suffix is appended, as the type of the integer is unknown.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    /// - `radix`: the base and, for hexadecimal, the case of the digits
+    ///
+    /// # Returns
+    /// - Rust integer literal
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_rust_literal_radix(0xDEAD_BEEF, scaler::Radix::Hex), "0xDEAD_BEEF");
+    /// assert_eq!(f.format_rust_literal_radix(0o755, scaler::Radix::Octal), "0o755");
+    /// let f: scaler::Formatter = f.set_radix(scaler::RadixOptions { group_size: 8, min_digits: 16, prefix: false });
+    /// assert_eq!(f.format_rust_literal_radix(0b1010_0101, scaler::Radix::Binary), "0b00000000_10100101");
+    /// ```
+    pub fn format_rust_literal_radix(&self, x: u128, radix: Radix) -> String
+    {
+        return Formatter {
+            group_separator: Text::Static("_"),
+            radix: RadixOptions { prefix: true, ..self.radix },
+            width: 0,
+            ..self.clone()
+        }
+        .format_radix(x, radix);
+    }
+}
+
+
+/// # Summary
+/// Groups decimal integer digits by 3 with "_", as allowed in Rust literals.
+///
+/// # Arguments
+/// - `digits`: the integer digits
+///
+/// # Returns
+/// - grouped digits
+fn underscored(digits: &str) -> String
+{
+    let mut s: String = String::with_capacity(digits.len() + digits.len() / 3);
+
+
+    for (i, digit) in digits.chars().enumerate()
+    {
+        if 0 < i && (digits.len() - i).is_multiple_of(3)
+        // start of new group, counted from the right
+        {
+            s.push('_');
+        }
+        s.push(digit);
+    }
+
+    return s;
+}
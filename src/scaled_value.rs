@@ -0,0 +1,146 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::{BINARY_PREFIXES, DECIMAL_PREFIXES};
+use crate::magnitude::{pow10, pow2};
+use crate::*;
+
+
+/// # Summary
+/// A formatted number split into what it is scaled by, for example to put "MiB" into a column header and only the mantissas into the cells. Created by `Formatter::format_scaled`. Displaying it yields exactly what `Formatter::format` returns. Either the unit prefix is not empty or there is an exponent, never both.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScaledValue
+{
+    pub exponent_fallback:    Option<(u32, i32)>, // base and exponent of scientific notation, none with unit prefix or without scaling
+    pub factor:               f64,                // what the mantissa is scaled by, like 10^(3) for "k", 2^(10) for "Ki", or base^(exponent) in scientific notation
+    pub mantissa:             f64,                // rounded mantissa with sign, infinity or NaN as is
+    pub prefix:               &'static str,       // unit prefix, empty for none or a suffix of a custom strategy
+    pub rounded_mantissa_str: String,             // displayed mantissa with separators, without sign, affixes, or padding
+    formatted:                String,             // the whole formatted number
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a number like `Formatter::format`, but returns the rounded mantissa, the unit prefix, and the factor between them separately. Everything is taken from the same formatting pass, so rounding and unit prefix always agree with the displayed number, and mantissa times factor is the rounded number. Infinity and NaN have factor 1 and no unit prefix.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the scaled number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Binary(true))
+    ///     .set_unit("B");
+    /// let scaled: scaler::ScaledValue = f.format_scaled(5_000_000);
+    /// assert_eq!(scaled.mantissa, 4.768);
+    /// assert_eq!(scaled.rounded_mantissa_str, "4,768");
+    /// assert_eq!(scaled.prefix, "Mi");
+    /// assert_eq!(scaled.factor, 1048576.0);
+    /// assert_eq!(scaled.exponent_fallback, None);
+    /// assert_eq!(scaled.to_string(), "4,768 MiB");
+    /// assert_eq!(scaled.to_string(), f.format(5_000_000));
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let scaled: scaler::ScaledValue = f.format_scaled(-1234567.0);
+    /// assert_eq!((scaled.mantissa, scaled.prefix, scaled.factor), (-1.235, "M", 1e6));
+    /// let scaled: scaler::ScaledValue = f.format_scaled(1e40); // beyond the unit prefixes
+    /// assert_eq!((scaled.mantissa, scaled.prefix, scaled.exponent_fallback), (1.0, "", Some((10, 40))));
+    /// assert_eq!(scaled.to_string(), "1,000 * 10^(40)");
+    /// let scaled: scaler::ScaledValue = f.format_scaled(f64::NEG_INFINITY);
+    /// assert_eq!((scaled.mantissa, scaled.rounded_mantissa_str.as_str(), scaled.factor), (f64::NEG_INFINITY, "∞", 1.0));
+    /// let scaled: scaler::ScaledValue = f.clone().set_scaling(scaler::Scaling::None).format_scaled(1234567.0);
+    /// assert_eq!((scaled.mantissa, scaled.rounded_mantissa_str.as_str(), scaled.factor), (1235000.0, "1.235.000", 1.0));
+    /// ```
+    pub fn format_scaled<T>(&self, x: T) -> ScaledValue
+    where
+        T: Into<f64>,
+    {
+        let x: f64 = x.into();
+        let mut base: Option<u32> = None; // base of scientific notation
+        let mut digits: String = String::new(); // mantissa with "." as decimal separator, for parsing
+        let mut exponent: Option<i32> = None;
+        let mut negative: bool = false;
+        let parts: FormattedParts = self.format_to_parts(x);
+        let mut prefix: &'static str = "";
+        let mut rounded_mantissa_str: String = String::new();
+        let mut special: Option<f64> = None; // infinity or NaN as is
+
+
+        for part in parts.iter()
+        {
+            match part.kind
+            {
+                PartKind::DecimalSeparator =>
+                {
+                    digits.push('.');
+                    rounded_mantissa_str.push_str(&part.value);
+                }
+                PartKind::ExponentDigits => exponent = part.value.parse().ok(),
+                PartKind::ExponentMarker => base = base.or_else(|| part.value.trim_matches(|c: char| !c.is_ascii_digit()).parse().ok()), // " * 10^(" holds the base
+                PartKind::FractionDigits | PartKind::IntegerDigits =>
+                {
+                    digits.push_str(&part.value);
+                    rounded_mantissa_str.push_str(&part.value);
+                }
+                PartKind::GroupSeparator => rounded_mantissa_str.push_str(&part.value),
+                PartKind::Prefix =>
+                {
+                    prefix = match self.scaling
+                    {
+                        Scaling::Binary(_) => BINARY_PREFIXES.iter(),
+                        Scaling::CompactLocalized => self.compact_locale().compact_suffixes().iter(),
+                        Scaling::Exponential { prefixes: Some(table), .. } => table.prefixes.iter(),
+                        Scaling::Strategy(_) => [].iter(), // suffixes of custom strategies are no unit prefixes
+                        _ => DECIMAL_PREFIXES.iter(),
+                    }
+                    .find(|(_lower, _upper, p)| *p == part.value)
+                    .map(|(_lower, _upper, p)| *p)
+                    .unwrap_or("");
+                }
+                PartKind::Sign => negative |= part.value == "-",
+                PartKind::Special =>
+                {
+                    special = Some(x);
+                    rounded_mantissa_str.push_str(&part.value);
+                }
+                PartKind::Affix | PartKind::Literal | PartKind::Original | PartKind::Padding | PartKind::Threshold | PartKind::Unit => {}
+            }
+        }
+
+        return ScaledValue {
+            exponent_fallback:    base.zip(exponent),
+            factor:               match (base.zip(exponent), &self.scaling)
+            {
+                (Some((base, exponent)), _) => (base as f64).powi(exponent),
+                (None, Scaling::Binary(_)) => pow2(BINARY_PREFIXES.iter().find(|(_lower, _upper, p)| *p == prefix).map_or(0, |(lower, _upper, _p)| *lower).into()),
+                (None, Scaling::CompactLocalized) => pow10(self.compact_locale().compact_suffixes().iter().find(|(_lower, _upper, p)| *p == prefix).map_or(0, |(lower, _upper, _p)| *lower)),
+                (None, Scaling::Decimal(_)) => pow10(DECIMAL_PREFIXES.iter().find(|(_lower, _upper, p)| *p == prefix).map_or(0, |(lower, _upper, _p)| *lower)),
+                (None, Scaling::Exponential { base, step: _, prefixes: Some(table) }) => ((*base).max(2) as f64).powi(table.prefixes.iter().find(|(_lower, _upper, p)| *p == prefix).map_or(0, |(lower, _upper, _p)| *lower) as i32),
+                (None, Scaling::Strategy(strategy)) if x.is_finite() => strategy.choose(match &self.rounding { Rounding::Magnitude(precision) => x.round_mag(*precision), Rounding::SignificantDigits(precision) => x.round_sig(*precision) }).divisor, // chosen for the rounded number like when formatting
+                (None, _) => 1.0,
+            },
+            mantissa:             special.unwrap_or_else(|| if negative { -digits.parse::<f64>().unwrap_or(1.0) } else { digits.parse().unwrap_or(1.0) }), // no digits if a mantissa of 1 is left out
+            prefix,
+            rounded_mantissa_str,
+            formatted:            parts.to_string(),
+        };
+    }
+}
+
+
+impl std::fmt::Display for ScaledValue
+{
+    /// # Summary
+    /// Writes the number exactly like `Formatter::format` formatted it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return f.write_str(&self.formatted);
+    }
+}
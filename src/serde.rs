@@ -0,0 +1,1133 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+//! Helpers to deserialize human readable numbers like "512Mi" or "1,5 G" in configuration files with serde. Plain numbers are accepted as well. Formatters themselves can be stored in configuration files, too. For output read by humans, numbers can be serialized as formatted strings like "1,235 M".
+use crate::*;
+
+
+/// # Summary
+/// Visitor accepting numbers and strings parsed with `Formatter::parse_lenient` of the default formatter.
+struct ScaledVisitor;
+
+
+/// # Summary
+/// Visitor accepting non-negative integers and strings parsed exactly into u64, with an optional trailing "B" or "b" for bytes.
+struct BytesVisitor;
+
+
+/// # Summary
+/// f64 deserialized with `from_scaled`, to deserialize it inside of `Option` and `Vec`.
+struct ScaledF64(f64);
+
+
+/// # Summary
+/// Selects the formatter `as_scaled_with` and its variants serialize with. Implement it for a marker type and pass that as type parameter, like `#[serde(serialize_with = "scaler::serde::as_scaled_with::<Bytes, _>")]`. The selection is resolved at compile time, every field can have its own formatter without any global state. As `Formatter::new` and all setters have `const fn` counterparts, the formatter is usually a `static`; formatters constructed at runtime can be stored in a `std::sync::LazyLock`.
+///
+/// # Examples
+/// ```
+/// struct Bytes;
+///
+/// impl scaler::serde::SelectFormatter for Bytes
+/// {
+///     fn formatter() -> &'static scaler::Formatter
+///     {
+///         static F: scaler::Formatter = scaler::Formatter::new()
+///             .set_scaling(scaler::Scaling::Binary(true))
+///             .set_unit_const("B");
+///         return &F;
+///     }
+/// }
+///
+/// #[derive(serde::Serialize)]
+/// struct Report
+/// {
+///     #[serde(serialize_with = "scaler::serde::as_scaled_with::<Bytes, _>")]
+///     size: f64,
+/// }
+/// assert_eq!(serde_json::to_string(&Report { size: 1536.0 }).unwrap(), r#"{"size":"1,500 KiB"}"#);
+/// ```
+pub trait SelectFormatter
+{
+    /// # Summary
+    /// The formatter to serialize with.
+    ///
+    /// # Returns
+    /// - the formatter
+    fn formatter() -> &'static Formatter;
+}
+
+
+/// # Summary
+/// Human readable form of `Formatter` in configuration files. Every setting is a plain field named like its setter, missing ones keep their default, unknown ones are rejected.
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct FormatterConfig
+{
+    affix_prefix:             String,
+    affix_suffix:             String,
+    align:                    Align,
+    auto_precision_max:       u8,
+    cjk_financial:            bool,
+    cjk_spelled_minus:        bool,
+    clamp_above:              String,
+    clamp_below:              String,
+    clamp_max:                Option<f64>,
+    clamp_min:                Option<f64>,
+    common_scale:             CommonScale,
+    complex_notation:         ComplexNotation,
+    decimal_separator:        String,
+    delta_parts:              DeltaParts,
+    delta_percent_decimals:   u8,
+    display_ceiling:          Option<f64>,
+    display_floor:            Option<f64>,
+    dms_degrees:              String,
+    dms_minutes:              String,
+    dms_seconds:              String,
+    exact_max_digits:         usize,
+    fallback_rounding:        Option<Rounding>,
+    fill:                     char,
+    fill_mode:                Fill,
+    group_separator:          String,
+    fraction_glyphs:          bool,
+    fraction_mixed:           bool,
+    hexfloat_all_digits:      bool,
+    hexfloat_uppercase:       bool,
+    imaginary_unit:           String,
+    infinity:                 String,
+    locale:                   Option<Locale>,
+    machine_readable:         bool,
+    min_integer_digits:       usize,
+    nan:                      String,
+    non_finite_policy:        NonFinitePolicy,
+    none_string:              String,
+    overflow_marker:          char,
+    percent_of_clamp:         bool,
+    percent_of_placeholder:   bool,
+    plus_minus:               String,
+    radix_group_size:         usize,
+    radix_min_digits:         usize,
+    radix_prefix:             bool,
+    range_separator:          String,
+    rate_base:                RateBase,
+    reserve_prefix_width:     bool,
+    roman_additive:           bool,
+    roman_large:              RomanLarge,
+    roman_lowercase:          bool,
+    rounding:                 Rounding,
+    rust_literal_suffix:      bool,
+    scaling:                  ScalingConfig,
+    show_original:            ShowOriginal,
+    show_original_identical:  bool,
+    sign:                     Sign,
+    sign_placement:           SignPlacement,
+    sign_position:            SignPosition,
+    sortable_fraction_digits: usize,
+    sortable_int_digits:      usize,
+    suppress_unit_mantissa:   bool,
+    threshold_greater:        String,
+    threshold_less:           String,
+    swap_reversed_ranges:     bool,
+    trailing_zeros:           bool,
+    uncertainty_notation:     UncertaintyNotation,
+    unit:                     String,
+    unit_plural:              String,
+    width:                    usize,
+    words_and_after_hundreds: bool,
+    words_fraction:           WordsFraction,
+    words_hyphenate:          bool,
+    zero_epsilon:             f64,
+    zero_exponent:            bool,
+    zero_pad:                 usize,
+    zero_pad_grouped:         bool,
+}
+
+
+/// # Summary
+/// Human readable form of `Scaling` in configuration files, with the whitespace separation named instead of a bare bool, like `{"decimal": {"space": true}}`. Unit prefix tables of exponential scaling are static and not stored, exponential scaling is read back without one. Custom strategies are code and stored as the default decimal scaling.
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+enum ScalingConfig
+{
+    Binary
+    {
+        space: bool, // whether or not to put space between number and unit prefix
+    },
+    CompactLocalized,
+    Decimal
+    {
+        space: bool, // whether or not to put space between number and unit prefix
+    },
+    Exponential
+    {
+        base: u32, // base of the powers to scale by
+        step: u32, // exponents are multiples of it
+    },
+    None,
+    Percent
+    {
+        space: bool, // whether or not to put space between number and "%"
+    },
+    Scientific,
+}
+
+
+/// # Summary
+/// Human readable form of `FormatterOverrides` in configuration files. Every field is optional and named like in `FormatterConfig`, missing ones are not overridden, unknown ones are rejected. The rounding can also be given as number of decimal places or significant digits, but only one of the three.
+#[derive(Default, ::serde::Deserialize, ::serde::Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct FormatterOverridesConfig
+{
+    #[serde(skip_serializing_if = "Option::is_none")]
+    affix_prefix:       Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    affix_suffix:       Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    align:              Option<Align>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decimal_separator:  Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decimals:           Option<i16>, // rounding to decimal places
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill:               Option<char>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_separator:    Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    infinity:           Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    machine_readable:   Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_integer_digits: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nan:                Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    none_string:        Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rounding:           Option<Rounding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scaling:            Option<ScalingConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sign:               Option<Sign>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sign_placement:     Option<SignPlacement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sign_position:      Option<SignPosition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    significant_digits: Option<u8>, // rounding to significant digits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trailing_zeros:     Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit:               Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_plural:        Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width:              Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zero_exponent:      Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zero_pad:           Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zero_pad_grouped:   Option<bool>,
+}
+
+
+/// # Summary
+/// Deserializes f64 from a number or from a string parsed with `Formatter::parse_lenient` of the default formatter. Use with `#[serde(deserialize_with = "scaler::serde::from_scaled")]`.
+///
+/// # Arguments
+/// - `deserializer`: the deserializer
+///
+/// # Returns
+/// - the number
+/// - or the deserializer's error, containing the offending string and what is wrong with it
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Config
+/// {
+///     #[serde(deserialize_with = "scaler::serde::from_scaled")]
+///     rate: f64,
+/// }
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"rate": "1,5 k"}"#).unwrap().rate, 1500.0);
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"rate": " 2.5M "}"#).unwrap().rate, 2.5e6);
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"rate": 0.25}"#).unwrap().rate, 0.25);
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"rate": -3}"#).unwrap().rate, -3.0);
+/// assert_eq!(toml::from_str::<Config>(r#"rate = "4 µ""#).unwrap().rate, 4e-6);
+/// assert_eq!(toml::from_str::<Config>("rate = 7").unwrap().rate, 7.0);
+/// assert_eq!(
+///     serde_json::from_str::<Config>(r#"{"rate": "12 parsecs"}"#).err().unwrap().to_string(),
+///     "\"12 parsecs\" is not a number: Unknown unit prefix \"parsecs\" at position 3. at line 1 column 21"
+/// );
+/// assert!(toml::from_str::<Config>(r#"rate = "12 parsecs""#).err().unwrap().to_string().contains("\"12 parsecs\" is not a number: Unknown unit prefix \"parsecs\" at position 3."));
+/// ```
+pub fn from_scaled<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+{
+    return deserializer.deserialize_any(ScaledVisitor);
+}
+
+
+/// # Summary
+/// Deserializes an optional f64 like `from_scaled`, null as none. Use with `#[serde(deserialize_with = "scaler::serde::from_scaled_opt")]`, together with `#[serde(default)]` if the field may be missing.
+///
+/// # Arguments
+/// - `deserializer`: the deserializer
+///
+/// # Returns
+/// - the number or none
+/// - or the deserializer's error, containing the offending string and what is wrong with it
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Config
+/// {
+///     #[serde(default, deserialize_with = "scaler::serde::from_scaled_opt")]
+///     limit: Option<f64>,
+/// }
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"limit": "1,5 k"}"#).unwrap().limit, Some(1500.0));
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"limit": null}"#).unwrap().limit, None);
+/// assert_eq!(serde_json::from_str::<Config>("{}").unwrap().limit, None);
+/// ```
+pub fn from_scaled_opt<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+{
+    return Ok(<Option<ScaledF64> as ::serde::Deserialize>::deserialize(deserializer)?.map(|x| x.0));
+}
+
+
+/// # Summary
+/// Deserializes a sequence of f64 like `from_scaled`. Use with `#[serde(deserialize_with = "scaler::serde::from_scaled_vec")]`.
+///
+/// # Arguments
+/// - `deserializer`: the deserializer
+///
+/// # Returns
+/// - the numbers
+/// - or the deserializer's error, containing the offending string and what is wrong with it
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Deserialize)]
+/// struct Config
+/// {
+///     #[serde(deserialize_with = "scaler::serde::from_scaled_vec")]
+///     thresholds: Vec<f64>,
+/// }
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"thresholds": ["1 k", 2500, "3,5 M"]}"#).unwrap().thresholds, [1e3, 2.5e3, 3.5e6]);
+/// ```
+pub fn from_scaled_vec<'de, D>(deserializer: D) -> Result<Vec<f64>, D::Error>
+where
+    D: ::serde::Deserializer<'de>,
+{
+    return Ok(<Vec<ScaledF64> as ::serde::Deserialize>::deserialize(deserializer)?.into_iter().map(|x| x.0).collect());
+}
+
+
+/// # Summary
+/// Serializes f64 as string formatted with the global formatter, see `scaler::format`, for output read by humans. Infinity and not a number are serialized as the formatter's special strings, not as null like JSON would. `from_scaled` reads it back within the precision of the rounding. Use with `#[serde(serialize_with = "scaler::serde::as_scaled")]`.
+///
+/// # Arguments
+/// - `x`: the number
+/// - `serializer`: the serializer
+///
+/// # Returns
+/// - the serializer's result
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Report
+/// {
+///     #[serde(serialize_with = "scaler::serde::as_scaled", deserialize_with = "scaler::serde::from_scaled")]
+///     rate: f64,
+/// }
+/// assert_eq!(serde_json::to_string(&Report { rate: 1234567.0 }).unwrap(), r#"{"rate":"1,235 M"}"#);
+/// assert_eq!(serde_json::to_string(&Report { rate: f64::NEG_INFINITY }).unwrap(), r#"{"rate":"-∞"}"#);
+/// assert_eq!(serde_json::to_string(&Report { rate: f64::NAN }).unwrap(), r#"{"rate":"NaN"}"#);
+/// assert_eq!(serde_json::from_str::<Report>(r#"{"rate":"1,235 M"}"#).unwrap().rate, 1235000.0); // rounded
+/// ```
+pub fn as_scaled<S>(x: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+{
+    return serializer.serialize_str(&crate::format(x));
+}
+
+
+/// # Summary
+/// Serializes an optional f64 like `as_scaled`, none as null. Use with `#[serde(serialize_with = "scaler::serde::as_scaled_opt")]`.
+///
+/// # Arguments
+/// - `x`: the number or none
+/// - `serializer`: the serializer
+///
+/// # Returns
+/// - the serializer's result
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Report
+/// {
+///     #[serde(serialize_with = "scaler::serde::as_scaled_opt")]
+///     limit: Option<f64>,
+/// }
+/// assert_eq!(serde_json::to_string(&Report { limit: Some(0.5) }).unwrap(), r#"{"limit":"500,0 m"}"#);
+/// assert_eq!(serde_json::to_string(&Report { limit: None }).unwrap(), r#"{"limit":null}"#);
+/// ```
+pub fn as_scaled_opt<S>(x: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+{
+    return match x
+    {
+        Some(x) => as_scaled(x, serializer),
+        None => serializer.serialize_none(),
+    };
+}
+
+
+/// # Summary
+/// Serializes an optional f64 like `as_scaled_with`, none as null. Use with `#[serde(serialize_with = "scaler::serde::as_scaled_opt_with::<F, _>")]`.
+///
+/// # Arguments
+/// - `x`: the number or none
+/// - `serializer`: the serializer
+///
+/// # Returns
+/// - the serializer's result
+pub fn as_scaled_opt_with<F, S>(x: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    F: SelectFormatter,
+    S: ::serde::Serializer,
+{
+    return match x
+    {
+        Some(x) => as_scaled_with::<F, S>(x, serializer),
+        None => serializer.serialize_none(),
+    };
+}
+
+
+/// # Summary
+/// Serializes a sequence of f64 like `as_scaled`. Use with `#[serde(serialize_with = "scaler::serde::as_scaled_vec")]`.
+///
+/// # Arguments
+/// - `x`: the numbers
+/// - `serializer`: the serializer
+///
+/// # Returns
+/// - the serializer's result
+///
+/// # Examples
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Report
+/// {
+///     #[serde(serialize_with = "scaler::serde::as_scaled_vec")]
+///     latencies: Vec<f64>,
+/// }
+/// assert_eq!(serde_json::to_string(&Report { latencies: vec![0.0123, 0.456, f64::INFINITY] }).unwrap(), r#"{"latencies":["12,30 m","456,0 m","∞"]}"#);
+/// ```
+pub fn as_scaled_vec<S>(x: &[f64], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ::serde::Serializer,
+{
+    return with_global(|f| serializer.collect_seq(x.iter().map(|x| f.format(x))));
+}
+
+
+/// # Summary
+/// Serializes a sequence of f64 like `as_scaled_with`. Use with `#[serde(serialize_with = "scaler::serde::as_scaled_vec_with::<F, _>")]`.
+///
+/// # Arguments
+/// - `x`: the numbers
+/// - `serializer`: the serializer
+///
+/// # Returns
+/// - the serializer's result
+pub fn as_scaled_vec_with<F, S>(x: &[f64], serializer: S) -> Result<S::Ok, S::Error>
+where
+    F: SelectFormatter,
+    S: ::serde::Serializer,
+{
+    return serializer.collect_seq(x.iter().map(|x| F::formatter().format(x)));
+}
+
+
+/// # Summary
+/// Serializes f64 as string formatted with the formatter selected by `F`, see `SelectFormatter`, for output read by humans. Infinity and not a number are serialized as the formatter's special strings. Use with `#[serde(serialize_with = "scaler::serde::as_scaled_with::<F, _>")]`.
+///
+/// # Arguments
+/// - `x`: the number
+/// - `serializer`: the serializer
+///
+/// # Returns
+/// - the serializer's result
+pub fn as_scaled_with<F, S>(x: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    F: SelectFormatter,
+    S: ::serde::Serializer,
+{
+    return serializer.serialize_str(&F::formatter().format(x));
+}
+
+
+/// # Summary
+/// Serializes and deserializes u64 byte counts exactly, without the precision loss of f64 above 2^(53). Use with `#[serde(with = "scaler::serde::bytes_u64")]`.
+///
+/// # Examples
+/// ```
+/// #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+/// struct Config
+/// {
+///     #[serde(with = "scaler::serde::bytes_u64")]
+///     max_size: u64,
+/// }
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"max_size": "512Mi"}"#).unwrap().max_size, 536870912);
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"max_size": "1,5 G"}"#).unwrap().max_size, 1500000000);
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"max_size": "2 GiB"}"#).unwrap().max_size, 2147483648);
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"max_size": "2 gib"}"#).unwrap().max_size, 2147483648);
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"max_size": 9007199254740993}"#).unwrap().max_size, 9007199254740993);
+/// assert_eq!(toml::from_str::<Config>(r#"max_size = "8 TiB""#).unwrap().max_size, 8 << 40);
+/// assert_eq!(toml::from_str::<Config>("max_size = 4096").unwrap().max_size, 4096);
+/// assert_eq!(
+///     serde_json::from_str::<Config>(r#"{"max_size": "1,5"}"#).err().unwrap().to_string(),
+///     "\"1,5\" is not a number of bytes: Number at position 0 is not an integer. at line 1 column 18"
+/// );
+/// assert!(serde_json::from_str::<Config>(r#"{"max_size": "12 parsecs"}"#).err().unwrap().to_string().starts_with("\"12 parsecs\" is not a number of bytes: "));
+/// assert_eq!(serde_json::from_str::<Config>(r#"{"max_size": 1.5e9}"#).unwrap().max_size, 1500000000);
+/// assert!(serde_json::from_str::<Config>(r#"{"max_size": -1}"#).is_err());
+/// assert!(serde_json::from_str::<Config>(r#"{"max_size": 0.5}"#).is_err());
+/// ```
+///
+/// ```
+/// #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+/// struct Config
+/// {
+///     #[serde(with = "scaler::serde::bytes_u64")]
+///     max_size: u64,
+/// }
+/// assert_eq!(serde_json::to_string(&Config { max_size: 536870912 }).unwrap(), r#"{"max_size":"512 MiB"}"#); // largest binary prefix that keeps the number exact
+/// assert_eq!(serde_json::to_string(&Config { max_size: 1536 }).unwrap(), r#"{"max_size":"1536 B"}"#);
+/// assert_eq!(toml::to_string(&Config { max_size: 8 << 40 }).unwrap(), "max_size = \"8 TiB\"\n");
+/// let mut state: u64 = 42;
+/// for _ in 0..1000 // round trip
+/// {
+///     state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+///     let config: Config = Config { max_size: state >> (state % 64) << (state % 61) };
+///     assert_eq!(serde_json::from_str::<Config>(&serde_json::to_string(&config).unwrap()).unwrap(), config);
+/// }
+/// ```
+pub mod bytes_u64
+{
+    /// # Summary
+    /// Deserializes u64 from a non-negative integer or from a string parsed exactly, like `Formatter::parse_u64`, but leniently like `Formatter::parse_lenient`. A trailing "B" or "b" for bytes is ignored.
+    ///
+    /// # Arguments
+    /// - `deserializer`: the deserializer
+    ///
+    /// # Returns
+    /// - the number of bytes
+    /// - or the deserializer's error, containing the offending string and what is wrong with it
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        return deserializer.deserialize_any(super::BytesVisitor);
+    }
+
+
+    /// # Summary
+    /// Serializes u64 as string with the largest binary unit prefix that keeps it exact and "B" for bytes, for example "512 MiB" or "1536 B".
+    ///
+    /// # Arguments
+    /// - `bytes`: the number of bytes
+    /// - `serializer`: the serializer
+    ///
+    /// # Returns
+    /// - the serializer's result
+    pub fn serialize<S>(bytes: &u64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        let mut mantissa: u64 = *bytes; // bytes divided by unit prefix
+        let mut prefix: &str = ""; // largest exact binary unit prefix
+
+
+        for (_, _, binary_prefix) in crate::format::BINARY_PREFIXES.iter().skip_while(|(_, _, binary_prefix)| binary_prefix.is_empty())
+        // Ki, Mi, ... in ascending order
+        {
+            if mantissa == 0 || !mantissa.is_multiple_of(1024)
+            {
+                break;
+            }
+            mantissa /= 1024;
+            prefix = binary_prefix;
+        }
+
+        return serializer.serialize_str(&format!("{mantissa} {prefix}B"));
+    }
+}
+
+
+impl ::serde::de::Visitor<'_> for ScaledVisitor
+{
+    type Value = f64;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return write!(f, "a number or a string containing a number like \"1,5 k\"");
+    }
+
+    fn visit_f64<E: ::serde::de::Error>(self, x: f64) -> Result<Self::Value, E>
+    {
+        return Ok(x);
+    }
+
+    fn visit_i64<E: ::serde::de::Error>(self, x: i64) -> Result<Self::Value, E>
+    {
+        return Ok(x as f64);
+    }
+
+    fn visit_u64<E: ::serde::de::Error>(self, x: u64) -> Result<Self::Value, E>
+    {
+        return Ok(x as f64);
+    }
+
+    fn visit_str<E: ::serde::de::Error>(self, s: &str) -> Result<Self::Value, E>
+    {
+        return Formatter::default().parse_lenient(s).map_err(|e| E::custom(format!("{s:?} is not a number: {e}")));
+    }
+}
+
+
+impl<'de> ::serde::Deserialize<'de> for ScaledF64
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        return from_scaled(deserializer).map(ScaledF64);
+    }
+}
+
+
+impl ::serde::de::Visitor<'_> for BytesVisitor
+{
+    type Value = u64;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return write!(f, "a non-negative integer or a string containing a number of bytes like \"512 MiB\"");
+    }
+
+    fn visit_f64<E: ::serde::de::Error>(self, x: f64) -> Result<Self::Value, E>
+    {
+        if x.fract() == 0.0 && (0.0..18446744073709551616.0).contains(&x)
+        // whole number that fits, exact because f64 is exact at this magnitude
+        {
+            return Ok(x as u64);
+        }
+        return Err(E::invalid_value(::serde::de::Unexpected::Float(x), &self));
+    }
+
+    fn visit_i64<E: ::serde::de::Error>(self, x: i64) -> Result<Self::Value, E>
+    {
+        return u64::try_from(x).map_err(|_| E::invalid_value(::serde::de::Unexpected::Signed(x), &self));
+    }
+
+    fn visit_u64<E: ::serde::de::Error>(self, x: u64) -> Result<Self::Value, E>
+    {
+        return Ok(x);
+    }
+
+    fn visit_str<E: ::serde::de::Error>(self, s: &str) -> Result<Self::Value, E>
+    {
+        let number: &str = s.trim_end(); // without trailing whitespace, so the bytes unit is found
+
+
+        return Formatter::default()
+            .parser()
+            .parse_integer(number.strip_suffix(['B', 'b']).unwrap_or(number), true)
+            .and_then(|(negative, magnitude, position)| match u64::try_from(magnitude)
+            {
+                Ok(bytes) if !negative || bytes == 0 => Ok(bytes),
+                _ => Err(ParseError { field: None, kind: ParseErrorKind::Overflow, position }),
+            })
+            .map_err(|e| E::custom(format!("{s:?} is not a number of bytes: {e}")));
+    }
+}
+
+
+impl FormatterConfig
+{
+    /// # Summary
+    /// Copies the settings of a formatter.
+    ///
+    /// # Arguments
+    /// - `f`: the formatter
+    ///
+    /// # Returns
+    /// - the settings in human readable form
+    fn new(f: &Formatter) -> Self
+    {
+        return Self {
+            affix_prefix:             f.affix_prefix.to_string(),
+            affix_suffix:             f.affix_suffix.to_string(),
+            align:                    f.align.clone(),
+            auto_precision_max:       f.auto_precision_max,
+            cjk_financial:            f.cjk.financial,
+            cjk_spelled_minus:        f.cjk.spelled_minus,
+            clamp_above:              f.clamp_markers.1.to_string(),
+            clamp_below:              f.clamp_markers.0.to_string(),
+            clamp_max:                f.clamp.1.map(|threshold| threshold.0),
+            clamp_min:                f.clamp.0.map(|threshold| threshold.0),
+            common_scale:             f.common_scale.clone(),
+            complex_notation:         f.complex_notation.0.clone(),
+            decimal_separator:        f.decimal_separator.to_string(),
+            delta_parts:              f.delta.0.clone(),
+            delta_percent_decimals:   f.delta.1,
+            display_ceiling:          f.display_ceiling.map(|threshold| threshold.0),
+            display_floor:            f.display_floor.map(|threshold| threshold.0),
+            dms_degrees:              f.dms_symbols.0.to_string(),
+            dms_minutes:              f.dms_symbols.1.to_string(),
+            dms_seconds:              f.dms_symbols.2.to_string(),
+            exact_max_digits:         f.exact_max_digits,
+            fallback_rounding:        f.fallback_rounding.clone(),
+            fill:                     f.fill,
+            fill_mode:                f.fill_mode,
+            group_separator:          f.group_separator.to_string(),
+            fraction_glyphs:          f.fraction.glyphs,
+            fraction_mixed:           f.fraction.mixed,
+            hexfloat_all_digits:      f.hexfloat.all_digits,
+            hexfloat_uppercase:       f.hexfloat.uppercase,
+            imaginary_unit:           f.complex_notation.1.to_string(),
+            infinity:                 f.special_strings.0.to_string(),
+            locale:                   f.locale,
+            machine_readable:         f.machine_readable,
+            min_integer_digits:       f.min_integer_digits,
+            nan:                      f.special_strings.1.to_string(),
+            non_finite_policy:        f.non_finite_policy,
+            none_string:              f.none_string.to_string(),
+            overflow_marker:          f.overflow_marker,
+            percent_of_clamp:         f.percent_of.clamp,
+            percent_of_placeholder:   f.percent_of.zero_of_zero_placeholder,
+            plus_minus:               f.uncertainty_notation.1.to_string(),
+            radix_group_size:         f.radix.group_size,
+            radix_min_digits:         f.radix.min_digits,
+            radix_prefix:             f.radix.prefix,
+            range_separator:          f.range_separator.0.to_string(),
+            rate_base:                f.rate_base.clone(),
+            reserve_prefix_width:     f.reserve_prefix_width,
+            roman_additive:           f.roman.additive,
+            roman_large:              f.roman.large,
+            roman_lowercase:          f.roman.lowercase,
+            rounding:                 f.rounding.clone(),
+            rust_literal_suffix:      f.rust_literal_suffix,
+            scaling:                  ScalingConfig::new(&f.scaling),
+            show_original:            f.show_original.0,
+            show_original_identical:  f.show_original.1,
+            sign:                     f.sign.clone(),
+            sign_placement:           f.sign_placement.clone(),
+            sign_position:            f.sign_position.clone(),
+            sortable_fraction_digits: f.sortable_digits.1,
+            sortable_int_digits:      f.sortable_digits.0,
+            suppress_unit_mantissa:   f.suppress_unit_mantissa,
+            threshold_greater:        f.threshold_markers.1.to_string(),
+            threshold_less:           f.threshold_markers.0.to_string(),
+            swap_reversed_ranges:     f.range_separator.1,
+            trailing_zeros:           f.trailing_zeros,
+            uncertainty_notation:     f.uncertainty_notation.0.clone(),
+            unit:                     f.unit.to_string(),
+            unit_plural:              f.unit_plural.to_string(),
+            width:                    f.width,
+            words_and_after_hundreds: f.words.and_after_hundreds,
+            words_fraction:           f.words.fraction.clone(),
+            words_hyphenate:          f.words.hyphenate,
+            zero_epsilon:             f.zero_epsilon.0,
+            zero_exponent:            f.zero_exponent,
+            zero_pad:                 f.zero_pad,
+            zero_pad_grouped:         f.zero_pad_grouped,
+        };
+    }
+
+
+    /// # Summary
+    /// Creates the formatter with these settings. Separators are validated like `Formatter::set_separators` warns about them, but problematic ones are rejected, and so are a clamp range with its minimum above its maximum and an epsilon of numerical noise that is negative, infinite, or not a number.
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or the description of the problem with the separators, the clamp range, or the epsilon
+    fn build(self) -> Result<Formatter, String>
+    {
+        if let Some(problem) = separator_problem(&self.group_separator, &self.decimal_separator)
+        {
+            return Err(problem.to_string());
+        }
+        if matches!((self.clamp_min, self.clamp_max), (Some(min), Some(max)) if max < min)
+        {
+            return Err(ConfigError::InvertedClamp.to_string());
+        }
+        if !(self.zero_epsilon.is_finite() && 0.0 <= self.zero_epsilon)
+        {
+            return Err(ConfigError::InvalidZeroEpsilon.to_string());
+        }
+
+        return Ok(Formatter {
+            affix_prefix:           Text::from(self.affix_prefix),
+            affix_suffix:           Text::from(self.affix_suffix),
+            align:                  self.align,
+            auto_precision_max:     self.auto_precision_max,
+            cjk:                    CjkOptions { financial: self.cjk_financial, spelled_minus: self.cjk_spelled_minus },
+            clamp:                  (self.clamp_min.map(Threshold), self.clamp_max.map(Threshold)),
+            clamp_markers:          (Text::from(self.clamp_below), Text::from(self.clamp_above)),
+            common_scale:           self.common_scale,
+            complex_notation:       (self.complex_notation, Text::from(self.imaginary_unit)),
+            decimal_separator:      Text::from(self.decimal_separator),
+            delta:                  (self.delta_parts, self.delta_percent_decimals),
+            display_ceiling:        self.display_ceiling.map(|ceiling| Threshold(ceiling.abs())),
+            display_floor:          self.display_floor.map(|floor| Threshold(floor.abs())),
+            dms_symbols:            (Text::from(self.dms_degrees), Text::from(self.dms_minutes), Text::from(self.dms_seconds)),
+            exact_max_digits:       self.exact_max_digits,
+            fallback_rounding:      self.fallback_rounding,
+            fill:                   self.fill,
+            fill_mode:              self.fill_mode,
+            group_separator:        Text::from(self.group_separator),
+            fraction:               FractionOptions { glyphs: self.fraction_glyphs, mixed: self.fraction_mixed },
+            hexfloat:               HexfloatOptions { all_digits: self.hexfloat_all_digits, uppercase: self.hexfloat_uppercase },
+            locale:                 self.locale,
+            machine_readable:       self.machine_readable,
+            min_integer_digits:     self.min_integer_digits,
+            non_finite_policy:      self.non_finite_policy,
+            none_string:            Text::from(self.none_string),
+            overflow_marker:        self.overflow_marker,
+            percent_of:             PercentOfOptions { clamp: self.percent_of_clamp, zero_of_zero_placeholder: self.percent_of_placeholder },
+            plural_rule:            PluralRule::English, // custom rules are code and not serialised, always the default
+            prefix_override:        None,
+            radix:                  RadixOptions { group_size: self.radix_group_size, min_digits: self.radix_min_digits, prefix: self.radix_prefix },
+            range_separator:        (Text::from(self.range_separator), self.swap_reversed_ranges),
+            rate_base:              self.rate_base,
+            reserve_prefix_width:   self.reserve_prefix_width,
+            roman:                  RomanOptions { additive: self.roman_additive, large: self.roman_large, lowercase: self.roman_lowercase },
+            rounding:               self.rounding,
+            rust_literal_suffix:    self.rust_literal_suffix,
+            scaling:                self.scaling.build(),
+            show_original:          (self.show_original, self.show_original_identical),
+            sign:                   self.sign,
+            sign_placement:         self.sign_placement,
+            sign_position:          self.sign_position,
+            sortable_digits:        (self.sortable_int_digits, self.sortable_fraction_digits),
+            special_strings:        (Text::from(self.infinity), Text::from(self.nan)),
+            suppress_unit_mantissa: self.suppress_unit_mantissa,
+            threshold_markers:      (Text::from(self.threshold_less), Text::from(self.threshold_greater)),
+            trailing_zeros:         self.trailing_zeros,
+            uncertainty_notation:   (self.uncertainty_notation, Text::from(self.plus_minus)),
+            unit:                   Text::from(self.unit),
+            unit_plural:            Text::from(self.unit_plural),
+            width:                  self.width,
+            words:                  WordsOptions { and_after_hundreds: self.words_and_after_hundreds, fraction: self.words_fraction, hyphenate: self.words_hyphenate },
+            zero_epsilon:           Threshold(self.zero_epsilon),
+            zero_exponent:          self.zero_exponent,
+            zero_pad:               self.zero_pad,
+            zero_pad_grouped:       self.zero_pad_grouped,
+        });
+    }
+}
+
+
+impl Default for FormatterConfig
+{
+    fn default() -> Self
+    {
+        return Self::new(&Formatter::new());
+    }
+}
+
+
+impl FormatterOverridesConfig
+{
+    /// # Summary
+    /// Copies the overridden options, with the rounding as decimal places or significant digits where possible.
+    ///
+    /// # Arguments
+    /// - `overrides`: the overrides
+    ///
+    /// # Returns
+    /// - the overrides in human readable form
+    fn new(overrides: &FormatterOverrides) -> Self
+    {
+        let mut config: Self = Self {
+            affix_prefix:       overrides.affix_prefix.clone(),
+            affix_suffix:       overrides.affix_suffix.clone(),
+            align:              overrides.align.clone(),
+            decimal_separator:  overrides.decimal_separator.clone(),
+            fill:               overrides.fill,
+            group_separator:    overrides.group_separator.clone(),
+            infinity:           overrides.infinity.clone(),
+            machine_readable:   overrides.machine_readable,
+            min_integer_digits: overrides.min_integer_digits,
+            nan:                overrides.nan.clone(),
+            none_string:        overrides.none_string.clone(),
+            scaling:            overrides.scaling.as_ref().map(ScalingConfig::new),
+            sign:               overrides.sign.clone(),
+            sign_placement:     overrides.sign_placement.clone(),
+            sign_position:      overrides.sign_position.clone(),
+            trailing_zeros:     overrides.trailing_zeros,
+            unit:               overrides.unit.clone(),
+            unit_plural:        overrides.unit_plural.clone(),
+            width:              overrides.width,
+            zero_exponent:      overrides.zero_exponent,
+            zero_pad:           overrides.zero_pad,
+            zero_pad_grouped:   overrides.zero_pad_grouped,
+            ..Default::default()
+        };
+
+
+        match &overrides.rounding
+        {
+            Some(Rounding::Magnitude(magnitude)) if *magnitude <= 0 && magnitude.checked_neg().is_some() => config.decimals = Some(-magnitude),
+            Some(Rounding::SignificantDigits(significant_digits)) => config.significant_digits = Some(*significant_digits),
+            rounding => config.rounding = rounding.clone(), // rounding to tens or more, or none
+        }
+
+        return config;
+    }
+
+
+    /// # Summary
+    /// Creates the overrides with these options. Separators given both are validated like `Formatter::set_separators` warns about them, but problematic ones are rejected.
+    ///
+    /// # Returns
+    /// - the overrides
+    /// - or the description of the problem with the rounding or the separators
+    fn build(self) -> Result<FormatterOverrides, String>
+    {
+        let rounding: Option<Rounding> = match (self.decimals, self.rounding, self.significant_digits)
+        {
+            (None, None, None) => None,
+            (Some(decimals), None, None) => Some(Rounding::Magnitude(decimals.saturating_neg())),
+            (None, Some(rounding), None) => Some(rounding),
+            (None, None, Some(significant_digits)) => Some(Rounding::SignificantDigits(significant_digits)),
+            _ => return Err("Rounding is given more than once, use only one of \"decimals\", \"rounding\", and \"significant_digits\".".to_string()),
+        };
+
+
+        if let Some(problem) = self.group_separator.as_deref().zip(self.decimal_separator.as_deref()).and_then(|(group_separator, decimal_separator)| separator_problem(group_separator, decimal_separator))
+        // only both together can be checked
+        {
+            return Err(problem.to_string());
+        }
+
+        return Ok(FormatterOverrides {
+            affix_prefix:       self.affix_prefix,
+            affix_suffix:       self.affix_suffix,
+            align:              self.align,
+            decimal_separator:  self.decimal_separator,
+            fill:               self.fill,
+            group_separator:    self.group_separator,
+            infinity:           self.infinity,
+            machine_readable:   self.machine_readable,
+            min_integer_digits: self.min_integer_digits,
+            nan:                self.nan,
+            none_string:        self.none_string,
+            rounding,
+            scaling:            self.scaling.map(ScalingConfig::build),
+            sign:               self.sign,
+            sign_placement:     self.sign_placement,
+            sign_position:      self.sign_position,
+            trailing_zeros:     self.trailing_zeros,
+            unit:               self.unit,
+            unit_plural:        self.unit_plural,
+            width:              self.width,
+            zero_exponent:      self.zero_exponent,
+            zero_pad:           self.zero_pad,
+            zero_pad_grouped:   self.zero_pad_grouped,
+        });
+    }
+}
+
+
+impl ScalingConfig
+{
+    /// # Summary
+    /// Converts a scaling into its human readable form.
+    ///
+    /// # Arguments
+    /// - `scaling`: the scaling
+    ///
+    /// # Returns
+    /// - the scaling in human readable form
+    fn new(scaling: &Scaling) -> Self
+    {
+        return match scaling
+        {
+            Scaling::Binary(space) => ScalingConfig::Binary { space: *space },
+            Scaling::CompactLocalized => ScalingConfig::CompactLocalized,
+            Scaling::Decimal(space) => ScalingConfig::Decimal { space: *space },
+            Scaling::Exponential { base, step, prefixes: _ } => ScalingConfig::Exponential { base: *base, step: *step },
+            Scaling::None => ScalingConfig::None,
+            Scaling::Percent(space) => ScalingConfig::Percent { space: *space },
+            Scaling::Scientific => ScalingConfig::Scientific,
+            Scaling::Strategy(_) => ScalingConfig::Decimal { space: true }, // strategies are code and not serialised, always the default
+        };
+    }
+
+
+    /// # Summary
+    /// Converts the human readable form back into a scaling.
+    ///
+    /// # Returns
+    /// - the scaling
+    fn build(self) -> Scaling
+    {
+        return match self
+        {
+            ScalingConfig::Binary { space } => Scaling::Binary(space),
+            ScalingConfig::CompactLocalized => Scaling::CompactLocalized,
+            ScalingConfig::Decimal { space } => Scaling::Decimal(space),
+            ScalingConfig::Exponential { base, step } => Scaling::Exponential { base, step, prefixes: None },
+            ScalingConfig::None => Scaling::None,
+            ScalingConfig::Percent { space } => Scaling::Percent(space),
+            ScalingConfig::Scientific => Scaling::Scientific,
+        };
+    }
+}
+
+
+impl ::serde::Serialize for Formatter
+{
+    /// # Summary
+    /// Serializes the settings with every setting as a plain field named like its setter, enums in snake case.
+    ///
+    /// # Arguments
+    /// - `serializer`: the serializer
+    ///
+    /// # Returns
+    /// - the serializer's result
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_separators(",", ".");
+    /// let toml: String = toml::to_string(&f).unwrap();
+    /// assert!(toml.contains("decimal_separator = \".\"\n"));
+    /// assert!(toml.contains("sign = \"only_minus\"\n"));
+    /// assert!(toml.contains("[rounding]\nmagnitude = -2\n"));
+    /// assert!(toml.contains("[scaling.decimal]\nspace = true\n"));
+    /// assert_eq!(toml::from_str::<scaler::Formatter>(&toml).unwrap(), f); // round trip
+    /// ```
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        return FormatterConfig::new(self).serialize(serializer);
+    }
+}
+
+
+impl<'de> ::serde::Deserialize<'de> for Formatter
+{
+    /// # Summary
+    /// Deserializes the settings written by `Serialize`. Missing settings keep their default, unknown ones are rejected. Separators that `Formatter::set_separators` would warn about, like ones containing digits, are rejected with an error.
+    ///
+    /// # Arguments
+    /// - `deserializer`: the deserializer
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or the deserializer's error
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = toml::from_str(r#"
+    ///     decimal_separator = "."
+    ///     group_separator = ","
+    ///     rounding = { significant_digits = 3 }
+    ///     scaling = { binary = { space = false } }
+    ///     unit = "B"
+    /// "#).unwrap();
+    /// assert_eq!(f.format(1536), "1.50KiB");
+    /// assert_eq!(serde_json::from_str::<scaler::Formatter>(r#"{"scaling": "none", "sign": "always"}"#).unwrap().format(1234), "+1.234");
+    /// assert_eq!(serde_json::from_str::<scaler::Formatter>("{}").unwrap(), scaler::Formatter::new()); // all defaults
+    /// assert_eq!(
+    ///     serde_json::from_str::<scaler::Formatter>(r#"{"group_separator": "1"}"#).err().unwrap().to_string(),
+    ///     "Group separator \"1\" contains a digit. This may lead to ambiguous formatting."
+    /// );
+    /// assert!(serde_json::from_str::<scaler::Formatter>(r#"{"precision": 3}"#).err().unwrap().to_string().starts_with("unknown field `precision`"));
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        return FormatterConfig::deserialize(deserializer)?.build().map_err(<D::Error as ::serde::de::Error>::custom);
+    }
+}
+
+
+impl ::serde::Serialize for FormatterOverrides
+{
+    /// # Summary
+    /// Serializes only the overridden options, named like the settings of `Formatter`, with the rounding as `decimals` or `significant_digits` where possible.
+    ///
+    /// # Arguments
+    /// - `serializer`: the serializer
+    ///
+    /// # Returns
+    /// - the serializer's result
+    ///
+    /// # Examples
+    /// ```
+    /// let overrides: scaler::FormatterOverrides = scaler::FormatterOverrides {
+    ///     rounding: Some(scaler::Rounding::Magnitude(-2)),
+    ///     unit: Some("€".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(toml::to_string(&overrides).unwrap(), "decimals = 2\nunit = \"€\"\n");
+    /// assert_eq!(serde_json::to_string(&scaler::FormatterOverrides::default()).unwrap(), "{}");
+    /// ```
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        return FormatterOverridesConfig::new(self).serialize(serializer);
+    }
+}
+
+
+impl<'de> ::serde::Deserialize<'de> for FormatterOverrides
+{
+    /// # Summary
+    /// Deserializes the overridden options written by `Serialize`. Every field is optional, missing ones are not overridden, unknown ones are rejected. The rounding is given as `decimals`, `significant_digits`, or `rounding` like for `Formatter`, more than one of them is rejected. Separators that `Formatter::set_separators` would warn about are rejected if both are given.
+    ///
+    /// # Arguments
+    /// - `deserializer`: the deserializer
+    ///
+    /// # Returns
+    /// - the overrides
+    /// - or the deserializer's error
+    ///
+    /// # Examples
+    /// ```
+    /// let overrides: scaler::FormatterOverrides = toml::from_str("decimals = 2").unwrap();
+    /// assert_eq!(overrides, scaler::FormatterOverrides { rounding: Some(scaler::Rounding::Magnitude(-2)), ..Default::default() });
+    /// assert_eq!(scaler::Formatter::new().set_scaling(scaler::Scaling::None).apply(&overrides).format(1234.5), "1.234,50");
+    ///
+    /// let overrides: scaler::FormatterOverrides = toml::from_str(r#"
+    ///     significant_digits = 3
+    ///     scaling = { binary = { space = false } }
+    ///     unit = "B"
+    /// "#).unwrap();
+    /// assert_eq!(scaler::Formatter::new().apply(&overrides).format(1536), "1,50KiB");
+    /// assert!(toml::from_str::<scaler::FormatterOverrides>("decimals = 2\nsignificant_digits = 3").err().unwrap().to_string().contains("Rounding is given more than once"));
+    /// assert!(serde_json::from_str::<scaler::FormatterOverrides>(r#"{"precision": 3}"#).err().unwrap().to_string().starts_with("unknown field `precision`"));
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        return FormatterOverridesConfig::deserialize(deserializer)?.build().map_err(<D::Error as ::serde::de::Error>::custom);
+    }
+}
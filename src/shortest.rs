@@ -0,0 +1,150 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::write_zeros;
+use std::fmt::Write;
+
+
+/// # Summary
+/// Writes a finite, non-negative number with exactly `dec_places` decimal places like `write!(out, "{:.*}", dec_places, x)`, but generates the digits with ryu's shortest representation and rounds on that digit string. Falls back to the std formatting machinery wherever the digit string could round differently than the exact binary value, which is more than 14 significant digits to display and exact ties in the digit string, so the output is always identical.
+///
+/// # Arguments
+/// - `out`: where to write the digits to
+/// - `x`: the number to write, finite and non-negative
+/// - `dec_places`: number of decimal places to write
+///
+/// # Returns
+/// - nothing or the error of `out`
+pub(crate) fn write_fixed<W>(out: &mut W, x: f64, dec_places: usize) -> std::fmt::Result
+where
+    W: Write + ?Sized,
+{
+    let mut buffer: ryu::Buffer = ryu::Buffer::new(); // shortest representation
+    let mut digits: [u8; 17] = [b'0'; 17]; // significant ASCII digits of shortest representation, ryu never needs more than 17
+    let mut digits_len: usize = 0; // number of significant digits
+    let digits_str: &str; // significant digits after rounding
+    let exponent: i64; // exponent of ryu's scientific notation, 0 if none
+    let mut int_len: i64 = 0; // number of digits before the decimal point, may be negative for leading fraction zeros or greater than the number of digits
+    let keep: i64; // number of significant digits to keep after rounding, negative if even the first is more than 1 place below the last decimal place
+    let mantissa: &str; // ryu's representation without exponent
+    let mut seen_point: bool = false; // whether the decimal point was passed already
+
+
+    if !x.is_finite() || x.is_sign_negative() || i64::try_from(dec_places).is_err()
+    // not what the digit string path handles
+    {
+        return write!(out, "{:.*}", dec_places, x);
+    }
+
+    let s: &str = buffer.format_finite(x);
+    (mantissa, exponent) = match s.split_once('e')
+    {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().expect("Exponent of ryu's scientific notation is not a valid integer.")),
+        None => (s, 0),
+    };
+    for c in mantissa.bytes()
+    {
+        if c == b'.'
+        {
+            seen_point = true;
+        }
+        else if digits_len == 0 && c == b'0'
+        // leading zero, only moves the decimal point
+        {
+            if !seen_point
+            {
+                continue;
+            }
+            int_len -= 1;
+        }
+        else
+        {
+            digits[digits_len] = c;
+            digits_len += 1;
+            if !seen_point
+            {
+                int_len += 1;
+            }
+        }
+    }
+    if digits_len == 0
+    // 0 has no significant digits
+    {
+        int_len = 0;
+    }
+    int_len += exponent;
+
+    keep = int_len + dec_places as i64;
+    if 14 < keep
+    // with at most 14 significant digits no rounding boundary fits between shortest representation and exact binary value, beyond that they may round differently
+    {
+        return write!(out, "{:.*}", dec_places, x);
+    }
+    if keep < digits_len as i64
+    // round on the digit string
+    {
+        let round_up: bool; // whether to round up
+
+        if keep < 0
+        // all digits are more than 1 place below the last decimal place to display
+        {
+            round_up = false;
+        }
+        else if digits[keep as usize] == b'5' && digits[keep as usize + 1..digits_len].iter().all(|digit| *digit == b'0')
+        // tie in the digit string, only the exact binary value can decide
+        {
+            return write!(out, "{:.*}", dec_places, x);
+        }
+        else
+        {
+            round_up = b'5' <= digits[keep as usize];
+        }
+
+        digits_len = keep.max(0) as usize;
+        if round_up
+        // increment last kept digit, carry over 9s
+        {
+            loop
+            {
+                if digits_len == 0
+                // carried out of all digits, like 9,99 to 10,0, remaining digits are all 0
+                {
+                    digits[0] = b'1';
+                    digits_len = 1;
+                    int_len += 1;
+                    break;
+                }
+                if digits[digits_len - 1] < b'9'
+                {
+                    digits[digits_len - 1] += 1;
+                    break;
+                }
+                digits_len -= 1; // 9 becomes trailing 0, added back as padding
+            }
+        }
+    }
+
+    digits_str = std::str::from_utf8(&digits[..digits_len]).expect("Digits are not valid UTF-8 even though they are ASCII.");
+    if int_len <= 0
+    // no integer digits
+    {
+        out.write_char('0')?;
+    }
+    else
+    {
+        out.write_str(&digits_str[..digits_len.min(int_len as usize)])?;
+        write_zeros(out, (int_len as usize).saturating_sub(digits_len))?; // integer digits beyond the significant ones
+    }
+    if 0 < dec_places
+    {
+        out.write_char('.')?;
+        write_zeros(out, (-int_len).clamp(0, dec_places as i64) as usize)?; // leading fraction zeros
+        if int_len.max(0) < digits_len as i64
+        // significant fraction digits
+        {
+            out.write_str(&digits_str[int_len.max(0) as usize..digits_len.min((int_len + dec_places as i64) as usize)])?;
+        }
+        write_zeros(out, (int_len + dec_places as i64 - (digits_len as i64).max(int_len)).max(0) as usize)?; // trailing fraction zeros
+    }
+
+    return Ok(());
+}
+
@@ -0,0 +1,102 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats every number of a slice like `Formatter::format`, in input order. The output Vec is allocated once with the right length, every result once with enough capacity to be reused by `Formatter::format_slice_into`.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    ///
+    /// # Returns
+    /// - the formatted numbers in input order
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_slice(&[1.0, 1234.5, -0.5, f64::NAN]), ["1,000", "1,234 k", "-500,0 m", "NaN"]);
+    /// assert!(f.format_slice(&[]).is_empty());
+    /// let values: Vec<f64> = (0..1000).map(|i| (i as f64 - 500.0) * 1.37e3_f64.powi(i % 7)).collect();
+    /// assert_eq!(f.format_slice(&values), values.iter().map(|x| f.format(x)).collect::<Vec<String>>());
+    /// ```
+    pub fn format_slice(&self, values: &[f64]) -> Vec<String>
+    {
+        let mut out: Vec<String> = Vec::with_capacity(values.len()); // formatted numbers, result
+
+        self.format_slice_into(values, &mut out);
+        return out;
+    }
+
+
+    /// # Summary
+    /// Formats every number of a slice like `Formatter::format_slice`, but into an existing Vec, for example a table redrawn with new values. Afterwards `out` contains exactly the formatted numbers in input order. The Strings already in `out` serve as scratch buffers, they are cleared and overwritten in place and keep their capacity, so formatting into a reused Vec usually allocates nothing.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    /// - `out`: receives the formatted numbers, previous content is replaced
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut cells: Vec<String> = Vec::new();
+    /// f.format_slice_into(&[1.0, 1234.5, -0.5], &mut cells);
+    /// assert_eq!(cells, ["1,000", "1,234 k", "-500,0 m"]);
+    /// f.format_slice_into(&[2e6, 3.0], &mut cells); // reuses the Strings, drops the surplus one
+    /// assert_eq!(cells, ["2,000 M", "3,000"]);
+    /// ```
+    pub fn format_slice_into(&self, values: &[f64], out: &mut Vec<String>)
+    {
+        let reused: usize = out.len().min(values.len()); // number of Strings to overwrite in place
+
+
+        out.truncate(reused);
+        for (s, x) in out.iter_mut().zip(values)
+        {
+            s.clear(); // keeps capacity
+            self.expect_accepted(*x);
+            self.format_into(*x, s).expect("Writing into a String failed even though it is infallible.");
+        }
+        out.reserve_exact(values.len() - reused);
+        out.extend(values[reused..].iter().map(|x| self.format(x))); // capacity of every new String fits common numbers, so reusing it later does not reallocate
+    }
+
+
+    /// # Summary
+    /// Formats every number of a slice into one String, separated by `separator`, like joining the results of `Formatter::format_slice` but without a String per number.
+    ///
+    /// # Arguments
+    /// - `values`: the numbers to format
+    /// - `separator`: put between every two numbers
+    ///
+    /// # Returns
+    /// - the formatted numbers in input order, separated
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_joined(&[1.0, 1234.5, -0.5], "; "), "1,000; 1,234 k; -500,0 m");
+    /// assert_eq!(f.format_joined(&[42.0], "; "), "42,00");
+    /// assert_eq!(f.format_joined(&[], "; "), "");
+    /// let values: Vec<f64> = (0..1000).map(|i| i as f64 * 1.37e3).collect();
+    /// assert_eq!(f.format_joined(&values, "\n"), f.format_slice(&values).join("\n"));
+    /// ```
+    pub fn format_joined(&self, values: &[f64], separator: &str) -> String
+    {
+        let mut s: String = String::new(); // formatted numbers, result
+
+
+        for (i, x) in values.iter().enumerate()
+        {
+            if 0 < i
+            {
+                s.push_str(separator);
+            }
+            self.expect_accepted(*x);
+            self.format_into(*x, &mut s).expect("Writing into a String failed even though it is infallible.");
+        }
+
+        return s;
+    }
+}
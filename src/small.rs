@@ -0,0 +1,52 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats like `Formatter::format`, but into a `SmallString` that keeps up to 32 bytes inline instead of on the heap. Typical formatted numbers are shorter, so formatting many of them, for example into a table, allocates nothing at all. Longer results move to the heap transparently. The result derefs to `str`.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let s: scaler::SmallString<[u8; 32]> = f.format_small(1234567.891);
+    /// assert_eq!(s, "1,235 M");
+    /// assert!(!s.spilled()); // inline, no allocation
+    /// assert_eq!(s.len(), f.format(1234567.891).len());
+    /// let s: scaler::SmallString<[u8; 32]> = f.set_scaling(scaler::Scaling::None).format_small(-1e30);
+    /// assert_eq!(s, "-1.000.000.000.000.000.000.000.000.000.000"); // longer than 32 bytes
+    /// assert!(s.spilled()); // on the heap
+    /// ```
+    ///
+    /// ```
+    /// // reused table of outputs
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_unit("B");
+    /// let mut cells: Vec<scaler::SmallString<[u8; 32]>> = Vec::with_capacity(3);
+    /// for row in [[1.0, 1500.0, 2e6], [3.0, 4500.0, 6e6]]
+    /// {
+    ///     cells.clear(); // keeps capacity, formatting allocates nothing
+    ///     cells.extend(row.iter().map(|x| f.format_small(x)));
+    /// }
+    /// assert_eq!(cells, ["3,000 B", "4,500 kB", "6,000 MB"]);
+    /// ```
+    pub fn format_small<T>(&self, x: T) -> SmallString<[u8; 32]>
+    where
+        T: IntoF64,
+    {
+        let mut s: SmallString<[u8; 32]> = SmallString::new(); // formatted number string, result
+        let x: f64 = x.into_f64();
+
+
+        self.expect_accepted(x);
+        self.format_into(x, &mut s).expect("Writing into a SmallString failed even though it is infallible.");
+        return s;
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats the number as fixed-width string whose lexicographic order equals the numeric order, for example to embed numbers in file names. There is no scaling, grouping, or affixes, the decimal separator is always ".", and integer and fraction part are zero padded to the configured number of sortable digits.
+    ///
+    /// The first character is an ordering character, followed by the sign:
+    /// - `0-∞`: negative infinity
+    /// - `1-`: negative numbers, digits are nines-complemented so larger magnitudes sort first
+    /// - `2+`: 0 and positive numbers
+    /// - `3+∞`: positive infinity
+    /// - `4NaN`: not a number, always last
+    ///
+    /// Numbers are rounded to the number of fraction digits. Numbers too large for the number of integer digits are clamped to the largest representable number, so they still sort correctly relative to smaller numbers but not among each other.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the sortable formatted number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_sortable(42069), "2+000042069.0000");
+    /// assert_eq!(f.format_sortable(-42069), "1-999957930.9999");
+    /// assert_eq!(f.format_sortable(0.5), "2+000000000.5000");
+    /// assert_eq!(f.format_sortable(-0.0), "2+000000000.0000");
+    /// assert_eq!(f.format_sortable(1e20), "2+999999999.9999"); // clamped
+    /// assert_eq!(f.format_sortable(f64::NEG_INFINITY), "0-∞");
+    /// assert_eq!(f.format_sortable(f64::INFINITY), "3+∞");
+    /// assert_eq!(f.format_sortable(f64::NAN), "4NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_sortable_digits(3, 0);
+    /// assert_eq!(f.format_sortable(7), "2+007");
+    /// assert_eq!(f.format_sortable(-7), "1-992");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut seed: u64 = 42;
+    /// let mut random = || -> f64 // xorshift, deterministic
+    /// {
+    ///     seed ^= seed << 13;
+    ///     seed ^= seed >> 7;
+    ///     seed ^= seed << 17;
+    ///     return (seed as f64 / u64::MAX as f64 - 0.5) * 10_f64.powi((seed % 12) as i32);
+    /// };
+    /// let mut values: Vec<f64> = (0..1000).map(|_| random()).collect();
+    /// values.extend([0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 1e-9, -1e-9]);
+    /// let mut strings: Vec<String> = values.iter().map(|x| f.format_sortable(*x)).collect();
+    /// values.sort_by(|a, b| a.total_cmp(b)); // NaN last
+    /// strings.sort();
+    /// assert_eq!(strings, values.iter().map(|x| f.format_sortable(*x)).collect::<Vec<String>>()); // string order equals numeric order
+    /// ```
+    pub fn format_sortable<T>(&self, x: T) -> String
+    where
+        T: Into<f64>,
+    {
+        let (int_digits, fraction_digits) = self.sortable_digits;
+        let mut digits: String; // zero padded digits of absolute value
+        let max: String = format!("{}{}{}", "9".repeat(int_digits), if 0 < fraction_digits { "." } else { "" }, "9".repeat(fraction_digits)); // largest representable number
+        let x: f64 = x.into();
+
+
+        if x.is_nan()
+        {
+            return "4NaN".to_string();
+        }
+        if x == f64::NEG_INFINITY
+        {
+            return "0-∞".to_string();
+        }
+        if x == f64::INFINITY
+        {
+            return "3+∞".to_string();
+        }
+
+        digits = format!("{:0width$.fraction_digits$}", x.abs(), width = int_digits + if 0 < fraction_digits { 1 + fraction_digits } else { 0 });
+        if max.len() < digits.len()
+        // too large, clamp
+        {
+            digits = max;
+        }
+
+        if x < 0.0
+        // nines-complement, numbers rounded to 0 become largest complement and still sort before 0
+        {
+            return format!("1-{}", digits.chars().map(|c| if c == '.' { c } else { (b'9' - c as u8 + b'0') as char }).collect::<String>());
+        }
+        return format!("2+{digits}");
+    }
+}
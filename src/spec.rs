@@ -0,0 +1,499 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::magnitude::decimal_magnitude;
+use crate::round::Round;
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::from_spec` and `printf` if the specification does not follow its grammar. Contains what the problem is and where it is, so it can be pointed at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpecError
+{
+    pub kind:     SpecErrorKind, // what the problem is
+    pub position: usize,         // byte index into the specification where the problem is
+}
+
+
+/// # Summary
+/// What kind of problem `SpecError` describes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SpecErrorKind
+{
+    MissingConversion,           // printf specification without conversion character
+    MissingDigits,               // "." or ":" without a number after it
+    Overflow,                    // number too large for its setting
+    UnexpectedChar(char),        // character that is not allowed at this point
+    UnsupportedConversion(char), // printf conversion character outside of the supported subset, like "d" or "x"
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Constructs a formatter from a compact specification string inspired by d3-format, for example from a command line argument. All parts are optional, but must appear in this order:
+    ///
+    /// `[[fill]align][sign][0][width][,][precision][~][type]`
+    ///
+    /// - `fill`: any character to pad with, only together with `align`, default " "
+    /// - `align`: `<` left, `>` right, `^` center, default right
+    /// - `sign`: `-` only minus, `+` always, ` ` space for positive, default only minus
+    /// - `0`: pads with zeros to `width` between sign and digits instead of padding with `fill`
+    /// - `width`: minimum number of characters
+    /// - `,`: groups integer digits, without it there is no group separator
+    /// - `precision`: `.n` for n significant digits, `:m` with optional sign for rounding to the digit at 10^m, default 4 significant digits
+    /// - `~`: removes trailing zeros
+    /// - `type`: `s` decimal unit prefixes, `b` binary unit prefixes, uppercase `S` and `B` without space before the unit prefix, `e` scientific notation, `n` no scaling, `%` percent with space before "%", default `s`
+    ///
+    /// Everything else, like separators, unit, and affixes, are the defaults of `Formatter::new` and can be changed with the setters afterwards.
+    ///
+    /// # Arguments
+    /// - `spec`: the specification
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or `SpecError` describing what and where the problem is
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::from_spec("+.3~s").unwrap();
+    /// assert_eq!(f.format(123456.789), "+123 k");
+    /// assert_eq!(f.format(1500), "+1,5 k");
+    ///
+    /// let f: scaler::Formatter = scaler::Formatter::from_spec("*>12,:-2n").unwrap();
+    /// assert_eq!(f.format(123456.789), "**123.456,79");
+    /// assert_eq!(f, scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_scaling(scaler::Scaling::None)
+    ///     .set_width(12, scaler::Align::Right, '*'));
+    /// ```
+    ///
+    /// ```
+    /// use scaler::{SpecError, SpecErrorKind};
+    /// assert_eq!(scaler::Formatter::from_spec(".4x"), Err(SpecError { kind: SpecErrorKind::UnexpectedChar('x'), position: 2 }));
+    /// assert_eq!(scaler::Formatter::from_spec(".s"), Err(SpecError { kind: SpecErrorKind::MissingDigits, position: 1 }));
+    /// assert_eq!(scaler::Formatter::from_spec(".300"), Err(SpecError { kind: SpecErrorKind::Overflow, position: 1 }));
+    /// assert_eq!(scaler::Formatter::from_spec("~,").unwrap_err().to_string(), "Unexpected character ',' at position 1.");
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Self, SpecError>
+    {
+        let mut align: Align = Align::Right; // alignment of padding
+        let mut f: Formatter = Formatter::new(); // result
+        let mut fill: char = ' '; // padding character
+        let mut grouping: bool = false; // whether to group integer digits
+        let mut pos: usize = 0; // byte index of next character to parse
+        let mut width: usize = 0; // minimum number of characters
+        let mut zero_pad: bool = false; // whether width is zero padding
+
+
+        let mut lookahead: std::str::Chars = spec.chars(); // first 2 characters, fill and alignment or only alignment
+        let first: Option<char> = lookahead.next();
+        if let (Some(c), Some(a)) = (first, lookahead.next().and_then(parse_align))
+        {
+            (fill, align) = (c, a);
+            pos += c.len_utf8() + 1;
+        }
+        else if let Some(a) = first.and_then(parse_align)
+        {
+            align = a;
+            pos += 1;
+        }
+
+        if let Some(sign) = match spec[pos..].chars().next()
+        {
+            Some('-') => Some(Sign::OnlyMinus),
+            Some('+') => Some(Sign::Always),
+            Some(' ') => Some(Sign::SpaceForPositive),
+            _ => None,
+        }
+        {
+            f = f.set_sign(sign);
+            pos += 1;
+        }
+
+        if spec[pos..].starts_with('0')
+        {
+            zero_pad = true;
+            pos += 1;
+        }
+
+        if let Some((number, len)) = parse_number::<usize>(spec, pos, false)?
+        {
+            width = number;
+            pos += len;
+        }
+
+        if spec[pos..].starts_with(',')
+        {
+            grouping = true;
+            pos += 1;
+        }
+
+        if spec[pos..].starts_with('.')
+        {
+            let (significants, len) = parse_number::<u8>(spec, pos + 1, false)?.ok_or(SpecError { kind: SpecErrorKind::MissingDigits, position: pos + 1 })?; // number of significant digits
+
+            f = f.set_rounding(Rounding::SignificantDigits(significants));
+            pos += 1 + len;
+        }
+        else if spec[pos..].starts_with(':')
+        {
+            let (magnitude, len) = parse_number::<i16>(spec, pos + 1, true)?.ok_or(SpecError { kind: SpecErrorKind::MissingDigits, position: pos + 1 })?; // magnitude of digit to round to
+
+            f = f.set_rounding(Rounding::Magnitude(magnitude));
+            pos += 1 + len;
+        }
+
+        if spec[pos..].starts_with('~')
+        {
+            f = f.set_trailing_zeros(false);
+            pos += 1;
+        }
+
+        if let Some(scaling) = match spec[pos..].chars().next()
+        {
+            Some('B') => Some(Scaling::Binary(false)),
+            Some('b') => Some(Scaling::Binary(true)),
+            Some('e') => Some(Scaling::Scientific),
+            Some('n') => Some(Scaling::None),
+            Some('%') => Some(Scaling::Percent(true)),
+            Some('S') => Some(Scaling::Decimal(false)),
+            Some('s') => Some(Scaling::Decimal(true)),
+            _ => None,
+        }
+        {
+            f = f.set_scaling(scaling);
+            pos += 1;
+        }
+
+        if let Some(c) = spec[pos..].chars().next()
+        // anything left over is out of order or unknown
+        {
+            return Err(SpecError { kind: SpecErrorKind::UnexpectedChar(c), position: pos });
+        }
+
+        if !grouping
+        {
+            f.group_separator = Text::Static("");
+        }
+        f = f.set_width(if zero_pad { 0 } else { width }, align, fill);
+        if zero_pad
+        {
+            f = f.set_zero_pad(width, grouping);
+        }
+        return Ok(f);
+    }
+
+
+    /// # Summary
+    /// Describes this formatter as specification string for `Formatter::from_spec`, the inverse of it. Only the settings the grammar covers are described, so `Formatter::from_spec(&f.to_spec())` equals `f` for every formatter constructed by `Formatter::from_spec`, but not for formatters with for example a unit. Grouping is described as on if there is any group separator. If both width and zero padding are set, only zero padding is described. Exponential scaling is described as scientific notation, its closest type.
+    ///
+    /// # Returns
+    /// - the specification
+    ///
+    /// # Examples
+    /// ```
+    /// assert_eq!(scaler::Formatter::new().to_spec(), ",.4s");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_scaling(scaler::Scaling::Binary(false))
+    ///     .set_sign(scaler::Sign::Always)
+    ///     .set_trailing_zeros(false)
+    ///     .set_width(10, scaler::Align::Center, '_');
+    /// assert_eq!(f.to_spec(), "_^+10,:-2~B");
+    /// assert_eq!(scaler::Formatter::from_spec(&f.to_spec()), Ok(f));
+    /// ```
+    pub fn to_spec(&self) -> String
+    {
+        let mut spec: String = String::new(); // result
+
+
+        if self.fill != ' '
+        {
+            spec.push(self.fill);
+        }
+        if self.fill != ' ' || self.align != Align::Right
+        {
+            spec.push(match self.align
+            {
+                Align::Center => '^',
+                Align::Left => '<',
+                Align::Right => '>',
+            });
+        }
+        match self.sign
+        {
+            Sign::Always | Sign::ExceptZero => spec.push('+'), // not covered by the grammar, closest is always
+            Sign::OnlyMinus => {}
+            Sign::SpaceForPositive => spec.push(' '),
+        }
+        if 0 < self.zero_pad || self.zero_pad_grouped
+        {
+            spec.push('0');
+        }
+        if 0 < self.zero_pad
+        {
+            spec.push_str(&self.zero_pad.to_string());
+        }
+        else if 0 < self.width
+        {
+            spec.push_str(&self.width.to_string());
+        }
+        if !self.group_separator.is_empty()
+        {
+            spec.push(',');
+        }
+        spec.push_str(&match self.rounding
+        {
+            Rounding::Magnitude(magnitude) => format!(":{magnitude}"),
+            Rounding::SignificantDigits(significants) => format!(".{significants}"),
+        });
+        if !self.trailing_zeros
+        {
+            spec.push('~');
+        }
+        spec.push(match self.scaling
+        {
+            Scaling::Binary(false) => 'B',
+            Scaling::Binary(true) => 'b',
+            Scaling::Decimal(false) => 'S',
+            Scaling::Decimal(true) => 's',
+            Scaling::CompactLocalized => 'S', // no type for compact suffixes, nearest are unit prefixes
+            Scaling::Exponential { .. } | Scaling::Scientific => 'e', // no type for exponential scaling, nearest is scientific notation
+            Scaling::None => 'n',
+            Scaling::Percent(_) => '%', // whitespace separation is lost, like for exponential scaling the nearest type
+            Scaling::Strategy(_) => 's', // no type for custom strategies, nearest is the default decimal scaling
+        });
+
+        return spec;
+    }
+}
+
+
+/// # Summary
+/// Formats a number following a printf-style conversion specification, for people used to C. Only a subset of C is supported, and every part of it is mapped onto the options of `Formatter` instead of a formatting engine of its own, so the output is the one of the formatter: with "," as decimal separator, scientific notation as "1,5 * 10^(3)" instead of "1.5e+03", and "∞" and "NaN" for special values. The specification is a single conversion without text around it:
+///
+/// `%[flags][width][.precision]conversion`
+///
+/// | part        | C meaning                                       | formatter option                                                                                 |
+/// |-------------|-------------------------------------------------|--------------------------------------------------------------------------------------------------|
+/// | `-`         | left-justify                                    | `Align::Left`, overrides `0`                                                                     |
+/// | `+`         | always sign                                     | `Sign::Always`, overrides space                                                                  |
+/// | space       | space for positive                              | `Sign::SpaceForPositive`                                                                         |
+/// | `0`         | pad with zeros                                  | `Formatter::set_zero_pad` to `width`                                                             |
+/// | `width`     | minimum number of characters                    | `Formatter::set_width`, padded with spaces                                                       |
+/// | `.precision`| digits, "." alone is 0                          | see conversion                                                                                   |
+/// | `f`         | fixed, precision decimal places, default 6      | `Scaling::None`, `Rounding::Magnitude(-precision)`                                               |
+/// | `e`, `E`    | scientific, precision decimal places, default 6 | `Scaling::Scientific`, `Rounding::SignificantDigits(precision + 1)`, `Formatter::set_zero_exponent` |
+/// | `g`, `G`    | shortest, precision significant digits, default 6 | `Rounding::SignificantDigits(precision)` at least 1 without trailing zeros, `Scaling::Scientific` if the rounded number has a decimal magnitude below -4 or of at least precision, `Scaling::None` otherwise |
+/// | `h`         | SI unit prefixes, nonstandard                   | `Scaling::Decimal(true)`, `Rounding::SignificantDigits(precision)` at least 1, default 4         |
+/// | `H`         | binary unit prefixes, nonstandard               | `Scaling::Binary(true)`, `Rounding::SignificantDigits(precision)` at least 1, default 4          |
+///
+/// Digits are never grouped, like in C. The notation has no exponent letter, so `E` and `G` format like `e` and `g`. Everything else, like the `#` and `'` flags, `*` width, length modifiers, and the conversions `d`, `x`, `s` and so on, is rejected. To format many numbers the same way or to change more settings, configure a `Formatter` instead, `Formatter::from_spec` offers a compact specification covering all of its conversions.
+///
+/// # Arguments
+/// - `spec`: the specification
+/// - `x`: the number to format
+///
+/// # Returns
+/// - the formatted number
+/// - or `SpecError` describing what and where the problem is
+///
+/// # Examples
+/// ```
+/// assert_eq!(scaler::printf("%.2f", 1234.5678), Ok("1234,57".to_owned()));
+/// assert_eq!(scaler::printf("%+10.3e", -0.00123456), Ok("-1,235 * 10^(-3)".to_owned()));
+/// assert_eq!(scaler::printf("%g", 0.0001), Ok("0,0001".to_owned()));
+/// assert_eq!(scaler::printf("%g", 123456789.0), Ok("1,23457 * 10^(8)".to_owned()));
+/// assert_eq!(scaler::printf("%-8.3h|", 42069.0), Err(scaler::SpecError { kind: scaler::SpecErrorKind::UnexpectedChar('|'), position: 6 }));
+/// assert_eq!(scaler::printf("%-8.3h", 42069.0), Ok("42,1 k  ".to_owned()));
+/// assert_eq!(scaler::printf("%H", 1048576.0), Ok("1,000 Mi".to_owned()));
+/// ```
+///
+/// ```
+/// use scaler::{SpecError, SpecErrorKind};
+/// assert_eq!(scaler::printf("%d", 42.0), Err(SpecError { kind: SpecErrorKind::UnsupportedConversion('d'), position: 1 }));
+/// assert_eq!(scaler::printf("%#x", 42.0), Err(SpecError { kind: SpecErrorKind::UnexpectedChar('#'), position: 1 }));
+/// assert_eq!(scaler::printf("%10.", 42.0), Err(SpecError { kind: SpecErrorKind::MissingConversion, position: 4 }));
+/// assert_eq!(scaler::printf(".2f", 42.0), Err(SpecError { kind: SpecErrorKind::UnexpectedChar('.'), position: 0 }));
+/// assert_eq!(scaler::printf("%.255e", 42.0), Err(SpecError { kind: SpecErrorKind::Overflow, position: 2 }));
+/// ```
+pub fn printf(spec: &str, x: f64) -> Result<String, SpecError>
+{
+    let mut align: Align = Align::Right; // alignment of padding
+    let conversion: char; // conversion character
+    let mut f: Formatter = Formatter::new(); // formatter the specification maps onto
+    let mut pos: usize = 1; // byte index of next character to parse, after "%"
+    let mut precision: Option<u8> = None; // precision if given
+    let mut precision_pos: usize = 0; // byte index of the precision, to point at if it is too large
+    let mut sign: Sign = Sign::OnlyMinus; // sign from flags
+    let mut width: usize = 0; // minimum number of characters
+    let mut zero_pad: bool = false; // whether width is zero padding
+
+
+    match spec.chars().next()
+    {
+        Some('%') => {}
+        Some(c) => return Err(SpecError { kind: SpecErrorKind::UnexpectedChar(c), position: 0 }),
+        None => return Err(SpecError { kind: SpecErrorKind::MissingConversion, position: 0 }),
+    }
+
+    while let Some(flag) = spec[pos..].chars().next().filter(|c| ['+', '-', ' ', '0'].contains(c))
+    // flags in any order, repeatable like in C
+    {
+        match flag
+        {
+            '+' => sign = Sign::Always,
+            '-' => align = Align::Left,
+            ' ' if sign != Sign::Always => sign = Sign::SpaceForPositive,
+            '0' => zero_pad = true,
+            _ => {}
+        }
+        pos += 1;
+    }
+    zero_pad &= align == Align::Right; // left-justified numbers are never zero padded
+
+    if let Some((number, len)) = parse_number::<usize>(spec, pos, false)?
+    {
+        width = number;
+        pos += len;
+    }
+
+    if spec[pos..].starts_with('.')
+    {
+        precision_pos = pos + 1;
+        match parse_number::<u8>(spec, pos + 1, false)?
+        {
+            Some((number, len)) =>
+            {
+                precision = Some(number);
+                pos += 1 + len;
+            }
+            None =>
+            // "." alone is precision 0
+            {
+                precision = Some(0);
+                pos += 1;
+            }
+        }
+    }
+
+    conversion = spec[pos..].chars().next().ok_or(SpecError { kind: SpecErrorKind::MissingConversion, position: pos })?;
+    f = match conversion
+    {
+        'e' | 'E' =>
+        {
+            let significants: u8 = precision.unwrap_or(6).checked_add(1).ok_or(SpecError { kind: SpecErrorKind::Overflow, position: precision_pos })?; // digit before the decimal separator and the decimal places
+
+            f.set_rounding(Rounding::SignificantDigits(significants)).set_scaling(Scaling::Scientific).set_zero_exponent(true)
+        }
+        'f' => f.set_rounding(Rounding::Magnitude(-i16::from(precision.unwrap_or(6)))).set_scaling(Scaling::None),
+        'g' | 'G' =>
+        {
+            let significants: u8 = precision.unwrap_or(6).max(1); // 0 is treated as 1 like in C
+            let magnitude: i16 = if x.is_finite() { decimal_magnitude(x.round_sig(significants)) } else { 0 }; // magnitude after rounding, so 99999.95 with 6 significant digits is 99999.9 and 999999.5 is 1e6
+
+            f.set_rounding(Rounding::SignificantDigits(significants))
+                .set_scaling(if (-4..i16::from(significants)).contains(&magnitude) { Scaling::None } else { Scaling::Scientific })
+                .set_trailing_zeros(false)
+        }
+        'h' => f.set_rounding(Rounding::SignificantDigits(precision.unwrap_or(4).max(1))).set_scaling(Scaling::Decimal(true)),
+        'H' => f.set_rounding(Rounding::SignificantDigits(precision.unwrap_or(4).max(1))).set_scaling(Scaling::Binary(true)),
+        c if c.is_ascii_alphabetic() => return Err(SpecError { kind: SpecErrorKind::UnsupportedConversion(c), position: pos }),
+        c => return Err(SpecError { kind: SpecErrorKind::UnexpectedChar(c), position: pos }),
+    };
+    pos += conversion.len_utf8();
+
+    if let Some(c) = spec[pos..].chars().next()
+    // text after the conversion
+    {
+        return Err(SpecError { kind: SpecErrorKind::UnexpectedChar(c), position: pos });
+    }
+
+    f.group_separator = Text::Static("");
+    f = f.set_sign(sign).set_width(if zero_pad { 0 } else { width }, align, ' ');
+    if zero_pad
+    {
+        f = f.set_zero_pad(width, false);
+    }
+    return Ok(f.format(x));
+}
+
+
+/// # Summary
+/// Parses an alignment character of a specification.
+///
+/// # Arguments
+/// - `c`: the character
+///
+/// # Returns
+/// - the alignment or none if `c` is no alignment character
+fn parse_align(c: char) -> Option<Align>
+{
+    return match c
+    {
+        '<' => Some(Align::Left),
+        '>' => Some(Align::Right),
+        '^' => Some(Align::Center),
+        _ => None,
+    };
+}
+
+
+/// # Summary
+/// Parses the number starting at `start` of a specification, ASCII digits with an optional sign before them if `signed`.
+///
+/// # Arguments
+/// - `spec`: the specification
+/// - `start`: byte index where the number may start
+/// - `signed`: whether the number may start with "+" or "-"
+///
+/// # Returns
+/// - the number and its length in bytes, or none if there are no digits
+/// - or `SpecErrorKind::Overflow` if the number does not fit into its type
+fn parse_number<T>(spec: &str, start: usize, signed: bool) -> Result<Option<(T, usize)>, SpecError>
+where
+    T: std::str::FromStr,
+{
+    let rest: &str = &spec[start..]; // from start of number
+    let mut len: usize = 0; // length of number in bytes
+
+
+    if signed && rest.starts_with(['+', '-'])
+    {
+        len = 1;
+    }
+    let digits_len: usize = rest[len..].bytes().take_while(u8::is_ascii_digit).count(); // number of digits
+    if digits_len == 0
+    {
+        return Ok(None);
+    }
+    len += digits_len;
+
+    return match rest[..len].parse::<T>()
+    {
+        Ok(number) => Ok(Some((number, len))),
+        Err(_) => Err(SpecError { kind: SpecErrorKind::Overflow, position: start }),
+    };
+}
+
+
+impl std::fmt::Display for SpecError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let pos: usize = self.position; // where the problem is
+
+
+        return match &self.kind
+        {
+            SpecErrorKind::MissingConversion => write!(f, "Expected conversion character at position {pos}."),
+            SpecErrorKind::MissingDigits => write!(f, "Expected digits at position {pos}."),
+            SpecErrorKind::Overflow => write!(f, "Number at position {pos} is too large."),
+            SpecErrorKind::UnexpectedChar(c) => write!(f, "Unexpected character {c:?} at position {pos}."),
+            SpecErrorKind::UnsupportedConversion(c) => write!(f, "Conversion {c:?} at position {pos} is not supported, expected one of \"eEfgGhH\"."),
+        };
+    }
+}
+
+
+impl std::error::Error for SpecError {}
@@ -0,0 +1,73 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use std::borrow::Cow;
+
+
+/// # Summary
+/// What a `ScaleStrategy` chooses for a number: the divisor to scale it by, the suffix to append, and optionally the number of decimal places to display.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScaleChoice
+{
+    pub decimals_hint: Option<u8>,        // decimal places of the mantissa, or none to derive them from the rounding of the formatter
+    pub divisor:       f64,               // the number is divided by it, 1 for no scaling, finite and not 0
+    pub suffix:        Cow<'static, str>, // appended after the mantissa as is, including any whitespace, empty for none
+}
+
+
+/// # Summary
+/// Custom scaling logic for `Scaling::Strategy`, for rules no unit prefix table can express, like the unit the user selected last or a threshold depending on the currency. The formatter rounds the number with its rounding first, so the choice sees the number like it will be displayed, then divides it by the chosen divisor, derives the decimal places from the rounding, and appends the suffix. Decimal places hinted by the choice replace the rounding, the number is then only rounded to them. A unit follows a suffix directly and is separated by whitespace if the suffix is empty. Infinity and not a number are never handed to the strategy.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+/// use scaler::{ScaleChoice, ScaleStrategy};
+///
+/// #[derive(Debug)]
+/// struct Millions; // monetary values in millions above 10 million only, always with 2 decimal places
+///
+/// impl ScaleStrategy for Millions
+/// {
+///     fn choose(&self, x: f64) -> ScaleChoice
+///     {
+///         if x.abs() < 1e7
+///         {
+///             return ScaleChoice { divisor: 1.0, suffix: Cow::Borrowed(""), decimals_hint: Some(2) };
+///         }
+///         return ScaleChoice { divisor: 1e6, suffix: Cow::Borrowed(" Mio."), decimals_hint: Some(2) };
+///     }
+///
+///
+///     fn max_suffix_len(&self) -> usize
+///     {
+///         return " Mio.".len();
+///     }
+/// }
+///
+/// let f: scaler::Formatter = scaler::Formatter::new()
+///     .set_rounding(scaler::Rounding::Magnitude(-2))
+///     .set_scaling(scaler::Scaling::Strategy(std::sync::Arc::new(Millions)));
+/// assert_eq!(f.format(1234567.891), "1.234.567,89");
+/// assert_eq!(f.format(-45678901.0), "-45,68 Mio.");
+/// ```
+pub trait ScaleStrategy: std::fmt::Debug
+{
+    /// # Summary
+    /// Chooses how to scale a number.
+    ///
+    /// # Arguments
+    /// - `x`: the finite number, already rounded with the rounding of the formatter
+    ///
+    /// # Returns
+    /// - divisor, suffix, and optionally the decimal places to display the mantissa with
+    fn choose(&self, x: f64) -> ScaleChoice;
+
+
+    /// # Summary
+    /// Bounds the length of the suffixes this strategy chooses, so `Formatter::max_len` can bound the length of formatted numbers. Output with longer suffixes does not fit buffers sized with `Formatter::max_len`, and writing into them fails instead.
+    ///
+    /// # Returns
+    /// - maximum length of a suffix in bytes, by default 16
+    fn max_suffix_len(&self) -> usize
+    {
+        return 16;
+    }
+}
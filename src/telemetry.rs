@@ -0,0 +1,126 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+/// # Summary
+/// A number together with the formatter to record it with as `tracing` field. Formats lazily when the subscriber records it, without an intermediate String, integers exactly. Created by `Formatter::traced` and `Formatter::traced_int`, wrapped in `tracing::field::DisplayValue`, because `tracing::field::Value` can only be implemented by tracing itself.
+#[derive(Clone, Copy, Debug)]
+pub struct Traced<'a>
+{
+    formatter: &'a Formatter,
+    x:         Value<'static>, // float or exact integer
+}
+
+
+impl std::fmt::Display for Traced<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return self.formatter.render(self.x, &mut |_kind, part| f.write_str(part));
+    }
+}
+
+
+/// # Summary
+/// Records numbers onto a span twice, raw under their name for queries and formatted under their name with "_human" appended for people reading traces, like "bytes" = 381203512345 and "bytes_human" = "381,2 GB". Both fields have to be declared when the span is created, for example as `tracing::field::Empty`, fields a span does not declare are not recorded.
+#[derive(Clone, Copy, Debug)]
+pub struct ScaledFields<'a>
+{
+    formatter: &'a Formatter,
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Wraps the number so it is formatted lazily when a `tracing` subscriber records it, like `info!(bytes = f.traced(x))`. The output is identical to `Formatter::format`. `info!(bytes = %f.display(x))` records the same.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - the field value, borrows the formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_unit("s");
+    /// let elapsed: f64 = 0.0123;
+    /// tracing::info!(elapsed = f.traced(elapsed), "Request handled.");
+    /// assert_eq!(f.traced(elapsed).to_string(), "12,30 ms");
+    /// ```
+    pub fn traced<T>(&self, x: T) -> ::tracing::field::DisplayValue<Traced<'_>>
+    where
+        T: Into<f64>,
+    {
+        return ::tracing::field::display(Traced { formatter: self, x: Value::Float(x.into()) });
+    }
+
+
+    /// # Summary
+    /// Wraps the integer so it is formatted lazily when a `tracing` subscriber records it, like `Formatter::traced`, but exactly like `Formatter::format_int`, so u64 byte counters need no conversion.
+    ///
+    /// # Arguments
+    /// - `x`: the integer to format
+    ///
+    /// # Returns
+    /// - the field value, borrows the formatter
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_unit("B");
+    /// let bytes: u64 = 381203512345;
+    /// tracing::info!(bytes, bytes_human = f.traced_int(bytes), "Upload finished.");
+    /// assert_eq!(f.traced_int(bytes).to_string(), "381,2 GB");
+    /// ```
+    pub fn traced_int<T>(&self, x: T) -> ::tracing::field::DisplayValue<Traced<'_>>
+    where
+        T: FormatInt,
+    {
+        let (negative, magnitude): (bool, u128) = x.sign_magnitude();
+
+        return ::tracing::field::display(Traced { formatter: self, x: Value::Exact(negative, magnitude, 0) });
+    }
+}
+
+
+impl<'a> ScaledFields<'a>
+{
+    /// # Summary
+    /// Constructs the helper recording numbers formatted with the given formatter.
+    ///
+    /// # Arguments
+    /// - `formatter`: the formatter for the "_human" fields
+    ///
+    /// # Returns
+    /// - the helper, borrows the formatter
+    pub fn new(formatter: &'a Formatter) -> Self
+    {
+        return ScaledFields { formatter };
+    }
+
+
+    /// # Summary
+    /// Records a number onto a span, raw under `name` and formatted under `name` with "_human" appended. Integers are formatted exactly like `Formatter::format_int`, floats like `Formatter::format`.
+    ///
+    /// # Arguments
+    /// - `span`: the span to record onto, which declares both fields
+    /// - `name`: name of the raw field
+    /// - `x`: the number
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_unit("B");
+    /// let span: tracing::Span = tracing::info_span!("upload", bytes = tracing::field::Empty, bytes_human = tracing::field::Empty);
+    /// scaler::ScaledFields::new(&f).record(&span, "bytes", 381203512345_u64); // "bytes" = 381203512345, "bytes_human" = "381,2 GB"
+    /// ```
+    pub fn record<T>(&self, span: &::tracing::Span, name: &str, x: T)
+    where
+        T: ToScaled + ::tracing::Value,
+    {
+        span.record(name, &x);
+        span.record(format!("{name}_human").as_str(), x.scaled_with(self.formatter).as_str());
+    }
+}
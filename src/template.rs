@@ -0,0 +1,141 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Returned by `Formatter::format_template` if the template is malformed or refers to a value that was not given.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TemplateError
+{
+    MissingValue(String),                         // placeholder without value, contains its name
+    UnknownStyle { name: String, style: String }, // style after ":" other than "common" or "%", contains name and style of the placeholder
+    UnmatchedBrace(usize),                        // "}" outside of a placeholder that is not doubled, contains its byte index
+    UnterminatedPlaceholder(usize),               // "{" without closing "}", contains its byte index
+}
+
+
+/// # Summary
+/// Piece of a parsed template.
+enum Segment<'a>
+{
+    Common(f64),      // value sharing the unit prefix of its group
+    Literal(&'a str), // text as is, escaped braces already halved
+    Percent(f64),     // value formatted as percentage
+    Plain(f64),       // value formatted as usual
+}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats several numbers into a template, like status lines that mix them with text. Every `{name}` is replaced by its value formatted as usual, `{name:%}` by its value as percentage without unit and affixes, so 0.125 becomes "12,50 %", and `{name:common}` by its value with the unit prefix shared by all values marked so, chosen by the largest of them like `Formatter::format_range` does. Doubled braces "{{" and "}}" are literal braces. Values not used by the template are ignored.
+    ///
+    /// # Arguments
+    /// - `template`: the text with placeholders
+    /// - `values`: name and number of every value
+    ///
+    /// # Returns
+    /// - the template with all placeholders replaced
+    /// - or `TemplateError` with the name of the placeholder without value, or where the template is malformed
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::SignificantDigits(3))
+    ///     .set_unit("B");
+    /// assert_eq!(
+    ///     f.format_template("{used:common} of {total:common} ({pct:%}) used", &[("used", 850e6), ("total", 2e9), ("pct", 0.425)]),
+    ///     Ok("0,850 GB of 2,00 GB (42,5 %) used".to_owned())
+    /// );
+    /// assert_eq!(f.format_template("{{{free}}}", &[("free", 1150e6)]), Ok("{1,15 GB}".to_owned()));
+    /// assert_eq!(f.format_template("{free} left", &[]), Err(scaler::TemplateError::MissingValue("free".to_owned())));
+    /// ```
+    pub fn format_template(&self, template: &str, values: &[(&str, f64)]) -> Result<String, TemplateError>
+    {
+        let mut common: Formatter = self.clone(); // formatter with the unit prefix shared by the group
+        let mut formatted: String = String::with_capacity(template.len()); // result
+        let percent: Formatter = Formatter {
+            affix_prefix: Text::Static(""),
+            affix_suffix: Text::Static(""),
+            scaling: Scaling::Percent(true),
+            unit: Text::Static(""),
+            unit_plural: Text::Static(""),
+            ..self.clone()
+        }; // formatter for percentages, the unit and affixes of the other values do not apply to them
+        let mut rest: &str = template; // not yet parsed part of the template
+        let mut segments: Vec<Segment> = Vec::new(); // parsed template
+
+
+        while let Some(i) = rest.find(['{', '}'])
+        {
+            let position: usize = template.len() - rest.len() + i; // byte index of the brace in the template
+
+            segments.push(Segment::Literal(&rest[..i]));
+            if rest[i..].starts_with("{{") || rest[i..].starts_with("}}")
+            // escaped brace
+            {
+                segments.push(Segment::Literal(&rest[i..i + 1]));
+                rest = &rest[i + 2..];
+                continue;
+            }
+            if rest[i..].starts_with('}')
+            {
+                return Err(TemplateError::UnmatchedBrace(position));
+            }
+
+            let end: usize = rest[i..].find('}').ok_or(TemplateError::UnterminatedPlaceholder(position))? + i; // index of the closing brace
+            let (name, style): (&str, Option<&str>) = match rest[i + 1..end].split_once(':') // placeholder content, optionally with style
+            {
+                Some((name, style)) => (name, Some(style)),
+                None => (&rest[i + 1..end], None),
+            };
+            let x: f64 = values.iter().find(|(n, _x)| *n == name).map(|(_n, x)| *x).ok_or_else(|| TemplateError::MissingValue(name.to_owned()))?; // value of the placeholder
+            segments.push(match style
+            {
+                None => Segment::Plain(x),
+                Some("common") => Segment::Common(x),
+                Some("%") => Segment::Percent(x),
+                Some(style) => return Err(TemplateError::UnknownStyle { name: name.to_owned(), style: style.to_owned() }),
+            });
+            rest = &rest[end + 1..];
+        }
+        segments.push(Segment::Literal(rest));
+
+        let group: Vec<f64> = segments.iter().filter_map(|segment| if let Segment::Common(x) = segment { Some(*x) } else { None }).collect(); // values sharing the unit prefix
+        if let Some(prefix) = self.common_prefix(&group, &CommonScale::Maximum)
+        {
+            common.prefix_override = Some(prefix.0);
+        }
+
+        for segment in segments
+        {
+            match segment
+            {
+                Segment::Common(x) => formatted.push_str(&common.format(x)),
+                Segment::Literal(s) => formatted.push_str(s),
+                Segment::Percent(x) => formatted.push_str(&percent.format(x)),
+                Segment::Plain(x) => formatted.push_str(&self.format(x)),
+            }
+        }
+
+        return Ok(formatted);
+    }
+}
+
+
+impl std::fmt::Display for TemplateError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return match self
+        {
+            TemplateError::MissingValue(name) => write!(f, "No value for placeholder {name:?}."),
+            TemplateError::UnknownStyle { name, style } => write!(f, "Placeholder {name:?} has unknown style {style:?}, expected \"common\" or \"%\"."),
+            TemplateError::UnmatchedBrace(i) => write!(f, "Unmatched \"}}\" at byte index {i}, write \"}}}}\" for a literal one."),
+            TemplateError::UnterminatedPlaceholder(i) => write!(f, "Placeholder at byte index {i} is not closed with \"}}\"."),
+        };
+    }
+}
+
+
+impl std::error::Error for TemplateError {}
@@ -0,0 +1,175 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use std::sync::Arc;
+
+
+/// # Summary
+/// Text option of a formatter, like a separator or the unit. Static text is borrowed and text set at runtime is copied once and then shared, so cloning a formatter never allocates. Used instead of `Cow<'static, str>`, whose owned text would be copied on every clone, while setters keep taking any `&str` rather than only static or owned text.
+#[derive(Clone)]
+pub(crate) enum Text
+{
+    Shared(Arc<str>, Option<&'static str>), // set at runtime, shared between clones, and static text that overrides it if set in constant context afterwards, where the shared text cannot be dropped
+    Static(&'static str),
+}
+
+
+impl AsRef<str> for Text
+{
+    /// # Summary
+    /// Borrows the text.
+    ///
+    /// # Returns
+    /// - the text
+    fn as_ref(&self) -> &str
+    {
+        return self;
+    }
+}
+
+
+impl std::ops::Deref for Text
+{
+    type Target = str;
+
+
+    /// # Summary
+    /// Borrows the text.
+    ///
+    /// # Returns
+    /// - the text
+    fn deref(&self) -> &str
+    {
+        return match self
+        {
+            Text::Shared(_, Some(text)) => text,
+            Text::Shared(text, None) => text,
+            Text::Static(text) => text,
+        };
+    }
+}
+
+
+impl std::fmt::Debug for Text
+{
+    /// # Summary
+    /// Debug formats the text like a str, regardless of where it is stored.
+    ///
+    /// # Arguments
+    /// - `f`: formatter to write into
+    ///
+    /// # Returns
+    /// - nothing or error
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return std::fmt::Debug::fmt(&**self, f);
+    }
+}
+
+
+impl std::fmt::Display for Text
+{
+    /// # Summary
+    /// Displays the text.
+    ///
+    /// # Arguments
+    /// - `f`: formatter to write into
+    ///
+    /// # Returns
+    /// - nothing or error
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        return std::fmt::Display::fmt(&**self, f);
+    }
+}
+
+
+impl Eq for Text {}
+
+
+impl From<String> for Text
+{
+    /// # Summary
+    /// Moves text set at runtime into shared storage, empty text is borrowed instead.
+    ///
+    /// # Arguments
+    /// - `text`: the text
+    ///
+    /// # Returns
+    /// - the text option
+    fn from(text: String) -> Self
+    {
+        if text.is_empty()
+        // nothing to share
+        {
+            return Text::Static("");
+        }
+        return Text::Shared(Arc::from(text), None);
+    }
+}
+
+
+impl PartialEq for Text
+{
+    /// # Summary
+    /// Compares texts by content, regardless of where they are stored.
+    ///
+    /// # Arguments
+    /// - `other`: the other text
+    ///
+    /// # Returns
+    /// - whether the texts are equal
+    fn eq(&self, other: &Self) -> bool
+    {
+        return **self == **other;
+    }
+}
+
+
+impl PartialEq<str> for Text
+{
+    /// # Summary
+    /// Compares the text with a str.
+    ///
+    /// # Arguments
+    /// - `other`: the str
+    ///
+    /// # Returns
+    /// - whether the texts are equal
+    fn eq(&self, other: &str) -> bool
+    {
+        return **self == *other;
+    }
+}
+
+
+impl PartialEq<&str> for Text
+{
+    /// # Summary
+    /// Compares the text with a str.
+    ///
+    /// # Arguments
+    /// - `other`: the str
+    ///
+    /// # Returns
+    /// - whether the texts are equal
+    fn eq(&self, other: &&str) -> bool
+    {
+        return **self == **other;
+    }
+}
+
+
+impl PartialEq<Text> for &str
+{
+    /// # Summary
+    /// Compares a str with the text.
+    ///
+    /// # Arguments
+    /// - `other`: the text
+    ///
+    /// # Returns
+    /// - whether the texts are equal
+    fn eq(&self, other: &Text) -> bool
+    {
+        return **self == **other;
+    }
+}
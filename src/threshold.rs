@@ -0,0 +1,136 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Floor or ceiling of the displayed values, bound of the clamp range, or epsilon of numerical noise, compared by bit pattern so the formatter stays `Eq`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Threshold(pub(crate) f64);
+
+
+impl PartialEq for Threshold
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        return self.0.to_bits() == other.0.to_bits();
+    }
+}
+
+
+impl Eq for Threshold {}
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Determines whether a number is displayed as the floor or ceiling it is beyond. The number is compared as it would be displayed, so multiplied by 100 for percent. The marker states the true relation of the number to the displayed bound, so negative numbers get the opposite marker of positive ones: -0.001 with a floor of 0.01 is "> -0,01", above -0.01, and -1e18 with a ceiling of 999e12 is "< -999 T", below -999e12.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - the marker and the number to format instead, signed like `x` and divided by 100 again for percent
+    /// - or none if `x` is displayed as it is, like 0, infinity, not a number, or numbers within the thresholds
+    pub(crate) fn display_threshold(&self, x: f64) -> Option<(&str, f64)>
+    {
+        let percent: bool = matches!(self.scaling, Scaling::Percent(_)); // whether numbers are displayed multiplied by 100
+        let displayed: f64 = if percent { x.abs() * 100.0 } else { x.abs() }; // absolute number as displayed
+        let bound: f64; // floor or ceiling as displayed
+        let below: bool; // whether below the floor instead of above the ceiling
+
+
+        if x == 0.0 || !x.is_finite()
+        // exact 0 has no magnitude to clamp, special values are displayed as they are
+        {
+            return None;
+        }
+        match (self.display_floor, self.display_ceiling)
+        {
+            (Some(Threshold(floor)), _) if displayed < floor =>
+            {
+                bound = floor;
+                below = true;
+            }
+            (_, Some(Threshold(ceiling))) if ceiling < displayed =>
+            {
+                bound = ceiling;
+                below = false;
+            }
+            _ => return None,
+        }
+
+        return Some((
+            if below == x.is_sign_positive() { &self.threshold_markers.0 } else { &self.threshold_markers.1 },
+            (if percent { bound / 100.0 } else { bound }).copysign(x),
+        ));
+    }
+
+
+    /// # Summary
+    /// Determines whether a number is clamped to the minimum or maximum set with `Formatter::set_clamp`. The minimum is applied first, so with a minimum above the maximum the maximum wins. Not a number is never clamped, and neither are numbers by a bound of not a number.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - the marker and the bound to format instead
+    /// - or none if `x` is within the range or at one of its bounds
+    pub(crate) fn clamped(&self, x: f64) -> Option<(&str, f64)>
+    {
+        let mut clamped: Option<(&str, f64)> = None; // marker and bound so far
+
+
+        if let Some(Threshold(min)) = self.clamp.0
+        {
+            if x < min
+            {
+                clamped = Some((&self.clamp_markers.0, min));
+            }
+        }
+        if let Some(Threshold(max)) = self.clamp.1
+        {
+            if max < clamped.map_or(x, |(_marker, min)| min)
+            {
+                clamped = Some((&self.clamp_markers.1, max));
+            }
+        }
+
+        return clamped;
+    }
+
+
+    /// # Summary
+    /// Determines whether the minimum of the clamp range is above its maximum, which `FormatterBuilder::build` rejects.
+    ///
+    /// # Returns
+    /// - whether both bounds are set and the minimum is above the maximum
+    pub(crate) fn clamp_inverted(&self) -> bool
+    {
+        return matches!(self.clamp, (Some(Threshold(min)), Some(Threshold(max))) if max < min);
+    }
+
+
+    /// # Summary
+    /// Snaps numerical noise closer to 0 than the epsilon set with `Formatter::set_zero_epsilon` to exactly 0.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - 0, unsigned, if `x` is closer to 0 than epsilon, otherwise `x`
+    pub(crate) fn snap_to_zero(&self, x: f64) -> f64
+    {
+        return if x.abs() < self.zero_epsilon.0 { 0.0 } else { x };
+    }
+
+
+    /// # Summary
+    /// Determines whether the epsilon set with `Formatter::set_zero_epsilon` is finite and not negative, which `FormatterBuilder::build` requires.
+    ///
+    /// # Returns
+    /// - whether epsilon is valid
+    pub(crate) fn zero_epsilon_valid(&self) -> bool
+    {
+        return self.zero_epsilon.0.is_finite() && 0.0 <= self.zero_epsilon.0;
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Generates nice tick values and their labels for a chart axis. The step between ticks is 1, 2, or 5 times a power of 10, chosen so there are about `target_count` ticks, and the range is extended to the next multiples of the step, so the ticks cover it completely. Ticks are the f64 closest to the exact multiples of the step, without accumulated float noise, also for steps far below 10^(-22). Every label is formatted with `f`, but rounded to the decimal place of the step and in the unit prefix or exponent of the tick farthest from 0, so all labels share one unit and show exactly the digits that change from tick to tick, also 0, which keeps an exponent only as configured with `Formatter::set_zero_exponent`. Steps never fall below the spacing of f64 around the range, so a range barely wider than f64 epsilon around a large number, or of subnormals, still yields distinct ticks. Multiples beyond f64 are left out instead of displayed as infinity. If minimum and maximum are equal, the only tick is that number, formatted with `f` as is, and if either is not finite, there are no ticks. A reversed range is swapped.
+///
+/// # Arguments
+/// - `min`: the lower end of the data
+/// - `max`: the upper end of the data
+/// - `target_count`: the desired number of ticks, the result may have a few more or less, at least 2 and at most 2^(16) are aimed for
+/// - `f`: the formatter for the labels, its rounding and fallback rounding are replaced
+///
+/// # Returns
+/// - ticks in ascending order with their labels
+///
+/// # Examples
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new();
+/// let labels = |min: f64, max: f64, target_count: usize| -> Vec<String> { scaler::ticks(min, max, target_count, &f).into_iter().map(|(_tick, label)| label).collect() };
+/// assert_eq!(labels(0.0, 1.0, 5), ["0,0", "0,2", "0,4", "0,6", "0,8", "1,0"]);
+/// assert_eq!(labels(-3.0, 7.0, 5), ["-4", "-2", "0", "2", "4", "6", "8"]);
+/// assert_eq!(labels(0.001, 0.002, 5), ["1,0 m", "1,2 m", "1,4 m", "1,6 m", "1,8 m", "2,0 m"]);
+/// assert_eq!(labels(0.0, 1e9, 5), ["0,0 G", "0,2 G", "0,4 G", "0,6 G", "0,8 G", "1,0 G"]);
+/// assert_eq!(labels(0.0, 1000.0, 3), ["0,0 k", "0,5 k", "1,0 k"]);
+/// assert_eq!(labels(1e300, 1.7e308, 5), ["0,0", "0,5 * 10^(308)", "1,0 * 10^(308)", "1,5 * 10^(308)"]);
+/// ```
+///
+/// ```
+/// let f: scaler::Formatter = scaler::Formatter::new()
+///     .set_scaling(scaler::Scaling::None);
+/// assert_eq!(scaler::ticks(0.0, 1.0, 5, &f).iter().map(|(tick, _label)| *tick).collect::<Vec<f64>>(), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]); // exact multiples, no 0.6000000000000001
+/// assert_eq!(scaler::ticks(7.0, 3.0, 2, &f), scaler::ticks(3.0, 7.0, 2, &f)); // swapped
+/// assert_eq!(scaler::ticks(1e15, 1e15 + 0.5, 10, &f), [(1e15, "1.000.000.000.000.000,0".to_string()), (1e15 + 0.5, "1.000.000.000.000.000,5".to_string())]); // not 0,1 apart, below f64 spacing
+/// assert_eq!(scaler::ticks(5.0, 5.0, 5, &f), [(5.0, "5,000".to_string())]);
+/// assert_eq!(scaler::ticks(0.0, f64::INFINITY, 5, &f), []);
+/// ```
+pub fn ticks(min: f64, max: f64, target_count: usize, f: &Formatter) -> Vec<(f64, String)>
+{
+    let big: f64; // largest absolute value of the range
+    let (exponent, mantissa): (i32, i64); // step is mantissa * 10^exponent
+    let farthest: f64; // tick farthest from 0, its unit prefix or exponent is shared by all labels
+    let mut first: i64; // first tick in steps
+    let mut label: Formatter; // f rounded to the decimal place of the step, in the unit of the farthest tick
+    let mut last: i64; // last tick in steps
+    let min_step: f64; // spacing of f64 around the range, smaller steps would repeat ticks
+    let (min, max): (f64, f64) = if max < min { (max, min) } else { (min, max) };
+    let step: f64; // only to estimate the first and last tick, as subnormal steps are inexact
+    let target: usize = target_count.clamp(2, TICKS_MAX); // aimed for number of ticks, so an absurd target count cannot exhaust memory
+
+
+    if !min.is_finite() || !max.is_finite()
+    // no axis to tick
+    {
+        return Vec::new();
+    }
+    if min == max
+    // no range, single tick
+    {
+        return vec![(min, f.format(min))];
+    }
+
+    big = min.abs().max(max.abs());
+    min_step = (big * f64::EPSILON).max(if big.next_up().is_finite() { big.next_up() - big } else { big - big.next_down() }); // relative spacing, and the absolute one for subnormals where the relative one is 0
+    (mantissa, exponent) = nice_step((max / (target - 1) as f64 - min / (target - 1) as f64).max(min_step), min_step); // divided first, so the range cannot overflow
+    step = tick(1, mantissa, exponent);
+    (first, last) = if exponent < -280
+    // subnormal steps have few significant digits, divide in the normal range instead
+    {
+        ((min * 1e300 / tick(1, mantissa, exponent + 300)).floor() as i64, (max * 1e300 / tick(1, mantissa, exponent + 300)).ceil() as i64)
+    }
+    else
+    {
+        ((min / step).floor() as i64, (max / step).ceil() as i64)
+    };
+    while min < tick(first, mantissa, exponent)
+    // quotient rounded up across a multiple
+    {
+        first -= 1;
+    }
+    while first < last && tick(first + 1, mantissa, exponent) <= min
+    // quotient rounded down across a multiple
+    {
+        first += 1;
+    }
+    while tick(last, mantissa, exponent) < max
+    // quotient rounded down across a multiple
+    {
+        last += 1;
+    }
+    while first < last && max <= tick(last - 1, mantissa, exponent)
+    // quotient rounded up across a multiple
+    {
+        last -= 1;
+    }
+    while first < last && tick(first, mantissa, exponent).is_infinite()
+    // multiple beyond f64
+    {
+        first += 1;
+    }
+    while first < last && tick(last, mantissa, exponent).is_infinite()
+    // multiple beyond f64
+    {
+        last -= 1;
+    }
+    last = last.min(first.saturating_add(4 * target as i64)); // never more than a few ticks beyond the target, whatever rounding did
+    label = f.clone().set_rounding(Rounding::Magnitude(exponent as i16));
+    label.fallback_rounding = None; // scientific notation rounded to the step as well
+    farthest = if tick(first, mantissa, exponent).abs() < tick(last, mantissa, exponent).abs() { tick(last, mantissa, exponent) } else { tick(first, mantissa, exponent) };
+    label.prefix_override = match (label.prefix_for(farthest), label.format_scaled(farthest).exponent_fallback)
+    {
+        (Some(_), _) => label.common_prefix(&[farthest], &CommonScale::Maximum).map(|prefix| prefix.0), // unit prefix, none for compact suffixes, which would be forced on numbers below the first step
+        (None, Some((_base, exponent))) => i16::try_from(exponent).ok(), // exponent of scientific notation
+        (None, None) => None, // without scaling, or suffixes of custom strategies
+    };
+
+    return (first..=last)
+        .map(|k| {
+            let x: f64 = tick(k, mantissa, exponent);
+            (x, label.format(x))
+        })
+        .collect();
+}
+
+
+/// # Summary
+/// Largest target count `ticks` aims for, far beyond any axis, but small enough that the ticks always fit into memory.
+const TICKS_MAX: usize = 1 << 16;
+
+
+/// # Summary
+/// Rounds a raw step to the nearest of 1, 2, or 5 times a power of 10, but never below the minimum.
+///
+/// # Arguments
+/// - `raw`: the step giving exactly the target number of ticks, must be positive and finite
+/// - `min`: the smallest acceptable step
+///
+/// # Returns
+/// - the mantissa 1, 2, or 5 and the exponent of the nice step
+fn nice_step(raw: f64, min: f64) -> (i64, i32)
+{
+    let mut exponent: i32 = raw.log10().floor() as i32;
+    let fraction: f64 = raw / power_of_10(exponent); // raw step in [1, 10), about
+    let mut mantissa: i64 = match fraction
+    {
+        ..1.5 => 1,
+        ..3.0 => 2,
+        ..7.0 => 5,
+        _ => 10,
+    };
+
+
+    if mantissa == 10
+    {
+        (mantissa, exponent) = (1, exponent + 1);
+    }
+    while tick(1, mantissa, exponent) < min
+    // next step up the ladder
+    {
+        (mantissa, exponent) = match mantissa
+        {
+            1 => (2, exponent),
+            2 => (5, exponent),
+            _ => (1, exponent + 1),
+        };
+    }
+
+    return (mantissa, exponent);
+}
+
+
+/// # Summary
+/// Calculates a power of 10 as the closest f64, exactly from 10^(-22) to 10^(22) and correctly rounded beyond, where repeated multiplication would accumulate errors and subnormal results would lose all digits.
+///
+/// # Arguments
+/// - `exponent`: the exponent
+///
+/// # Returns
+/// - the power of 10, 0 or infinity beyond f64
+fn power_of_10(exponent: i32) -> f64
+{
+    if (0..=22).contains(&exponent)
+    // exact
+    {
+        return 10_f64.powi(exponent);
+    }
+    return format!("1e{exponent}").parse::<f64>().expect("Power of 10 is not a valid f64.");
+}
+
+
+/// # Summary
+/// Calculates a multiple of a step, correctly rounded as long as the digits fit into the f64 mantissa, so ticks carry no accumulated float noise.
+///
+/// # Arguments
+/// - `k`: the multiple
+/// - `mantissa`: mantissa of the step, 1, 2, or 5
+/// - `exponent`: exponent of the step
+///
+/// # Returns
+/// - k * mantissa * 10^exponent
+fn tick(k: i64, mantissa: i64, exponent: i32) -> f64
+{
+    let digits: i64 = k.saturating_mul(mantissa);
+
+
+    if digits.unsigned_abs() > 1 << f64::MANTISSA_DIGITS
+    // digits not exact, multiply the step instead
+    {
+        return k as f64 * (mantissa as f64 * power_of_10(exponent));
+    }
+    return match exponent
+    {
+        -22..=-1 => digits as f64 / power_of_10(-exponent), // both exact, single correctly rounded division
+        0..=22 => digits as f64 * power_of_10(exponent),    // both exact, single correctly rounded multiplication
+        _ => format!("{digits}e{exponent}").parse::<f64>().expect("Tick is not a valid f64."), // power of 10 not exact, parsing rounds correctly
+    };
+}
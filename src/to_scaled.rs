@@ -0,0 +1,97 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Extension trait to format a number with the number as receiver, like `42069.scaled()`. Implemented for all primitive numbers: integers are formatted exactly with `Formatter::format_int`, so integers beyond 2^(53) keep all their digits, f32 by its shortest representation with `Formatter::format_f32`, and f64 with `Formatter::format`. Also available through `scaler::prelude`.
+///
+/// # Examples
+/// ```
+/// use scaler::ToScaled;
+/// assert_eq!(42069.scaled(), "42,07 k");
+/// assert_eq!(0.1_f32.scaled(), "100,0 m"); // not 100,0 m from 0.100000001490116
+/// assert_eq!(u64::MAX.scaled_with(&scaler::Formatter::new().set_scaling(scaler::Scaling::None).set_rounding(scaler::Rounding::Magnitude(0))), "18.446.744.073.709.551.615"); // exact
+///
+/// let sizes: Vec<u64> = vec![512, 1536, 1 << 40];
+/// assert_eq!(sizes.iter().map(ToScaled::scaled).collect::<Vec<String>>(), ["512,0", "1,536 k", "1,100 T"]);
+/// let f: scaler::Formatter = scaler::Formatter::bytes();
+/// assert_eq!(sizes.iter().map(|x| x.scaled_with(&f)).collect::<Vec<String>>().join(", "), "512 B, 1,50 KiB, 1,00 TiB");
+/// ```
+pub trait ToScaled
+{
+    /// # Summary
+    /// Formats the number with the global formatter, see `scaler::format`.
+    ///
+    /// # Returns
+    /// - the formatted number
+    fn scaled(&self) -> String;
+
+
+    /// # Summary
+    /// Formats the number with the given formatter.
+    ///
+    /// # Arguments
+    /// - `f`: the formatter to format with
+    ///
+    /// # Returns
+    /// - the formatted number
+    fn scaled_with(&self, f: &Formatter) -> String;
+}
+
+
+/// # Summary
+/// Implements `ToScaled` for integer types through the exact path of `Formatter::format_int`.
+macro_rules! impl_to_scaled_int
+{
+    ($($t:ty),*) =>
+    {
+        $(
+            impl ToScaled for $t
+            {
+                fn scaled(&self) -> String
+                {
+                    return with_global(|f| f.format_int(*self));
+                }
+
+
+                fn scaled_with(&self, f: &Formatter) -> String
+                {
+                    return f.format_int(*self);
+                }
+            }
+        )*
+    };
+}
+
+
+impl_to_scaled_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+
+impl ToScaled for f32
+{
+    fn scaled(&self) -> String
+    {
+        return with_global(|f| f.format_f32(*self));
+    }
+
+
+    fn scaled_with(&self, f: &Formatter) -> String
+    {
+        return f.format_f32(*self);
+    }
+}
+
+
+impl ToScaled for f64
+{
+    fn scaled(&self) -> String
+    {
+        return with_global(|f| f.format(*self));
+    }
+
+
+    fn scaled_with(&self, f: &Formatter) -> String
+    {
+        return f.format(*self);
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::DECIMAL_PREFIXES;
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Formats a measured value with its uncertainty, in the notation set with `Formatter::set_uncertainty_notation`, "(a ± b)" by default. The error determines the precision instead of the configured rounding: it is rounded to 1 significant digit, or 2 if its leading digit is 1, and the value is rounded to the same decimal place. Both share one unit prefix, chosen by the larger of them, or one exponent in scientific notation. Binary and exponential scaling display them without unit prefix or exponent, as uncertainties are decimal. Negative errors are treated as their absolute value. An error of 0 means the value is exact, so it is formatted like `Formatter::format` without uncertainty. If value or error is not finite, both are formatted individually like `Formatter::format` and joined by the plus-minus sign in either notation. The configured width applies to the result as a whole.
+    ///
+    /// # Arguments
+    /// - `value`: the measured value
+    /// - `error`: its uncertainty
+    ///
+    /// # Returns
+    /// - formatted value with uncertainty
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.format_uncertainty(1234.56, 2.3), "(1,235 ± 0,002) k");
+    /// assert_eq!(f.format_uncertainty(1234.56, 1.5), "(1,2346 ± 0,0015) k"); // leading digit 1, 2 significant digits
+    /// assert_eq!(f.format_uncertainty(1234.56, 0.096), "(1,23456 ± 0,00010) k"); // rounding carries over to a leading 1
+    /// assert_eq!(f.format_uncertainty(-0.0123, 0.0004), "(-12,3 ± 0,4) m");
+    /// assert_eq!(f.format_uncertainty(9.81, 0.12), "9,81 ± 0,12"); // no unit prefix, no parentheses
+    /// assert_eq!(f.format_uncertainty(0.5, 30.0), "0 ± 30"); // error larger than value
+    /// assert_eq!(f.format_uncertainty(1234.56, -2.3), "(1,235 ± 0,002) k");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_uncertainty_notation(scaler::UncertaintyNotation::Concise, "±");
+    /// assert_eq!(f.format_uncertainty(1234.56, 2.3), "1,235(2) k");
+    /// assert_eq!(f.format_uncertainty(1234.56, 1.5), "1,2346(15) k");
+    /// assert_eq!(f.format_uncertainty(9.81, 0.12), "9,81(12)");
+    /// assert_eq!(f.format_uncertainty(12345.0, 200.0), "12,3(2) k");
+    /// let f: scaler::Formatter = f.set_scaling(scaler::Scaling::None);
+    /// assert_eq!(f.format_uncertainty(12345.0, 200.0), "12.300(200)"); // error beyond the decimal separator, in full
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_unit("g")
+    ///     .set_width(22, scaler::Align::Right, ' ');
+    /// assert_eq!(f.format_uncertainty(1234.56, 2.3), "    (1,235 ± 0,002) kg");
+    /// assert_eq!(f.format_uncertainty(9.81, 0.12), "       (9,81 ± 0,12) g");
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_scaling(scaler::Scaling::Scientific)
+    ///     .set_affixes("~", "");
+    /// assert_eq!(f.format_uncertainty(1234.56, 2.3), "~(1,235 ± 0,002) * 10^(3)");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new(); // edge cases
+    /// assert_eq!(f.format_uncertainty(1234.56, 0.0), "1,235 k"); // exact
+    /// assert_eq!(f.format_uncertainty(1234.56, f64::INFINITY), "1,235 k ± ∞");
+    /// assert_eq!(f.format_uncertainty(1234.56, f64::NAN), "1,235 k ± NaN");
+    /// assert_eq!(f.format_uncertainty(f64::NAN, 2.3), "NaN ± 2,300");
+    /// let f: scaler::Formatter = f.set_uncertainty_notation(scaler::UncertaintyNotation::Concise, "+/-");
+    /// assert_eq!(f.format_uncertainty(f64::INFINITY, 2.3), "∞ +/- 2,300");
+    /// ```
+    pub fn format_uncertainty(&self, value: f64, error: f64) -> String
+    {
+        let error: f64 = error.abs();
+        let error_rounded: f64;
+        let last: i16; // magnitude of the last displayed digit, determined by the error
+        let magnifier: String; // unit prefix or exponent and unit after the numbers
+        let mantissa: Formatter; // formatter of the unscaled numbers
+        let part: Formatter = Formatter { show_original: (ShowOriginal::Off, false), width: 0, ..self.clone() }; // parts are padded together, not individually
+        let s: String; // formatted value with uncertainty without padding
+        let shift: i16; // decimal magnitude shared by value and error
+        let value_formatted: String; // value scaled and rounded, without unit prefix
+        let value_rounded: f64;
+
+
+        if !value.is_finite() || !error.is_finite()
+        // no precision to derive, format individually
+        {
+            return self.pad(format!("{} {} {}", part.format(value), self.uncertainty_notation.1, part.format(error)));
+        }
+        if error == 0.0
+        // exact value
+        {
+            return self.format(value);
+        }
+
+        last = match decimal_magnitude(error)
+        {
+            (magnitude, 1) => magnitude - 1, // leading digit 1, keep 2 significant digits
+            (magnitude, _) => magnitude,
+        };
+        error_rounded = error.round_mag(last);
+        value_rounded = value.round_mag(last);
+
+        (shift, magnifier) = match self.scaling
+        {
+            Scaling::Decimal(whitespace_separation) =>
+            {
+                let chosen: i16 = decimal_magnitude(value_rounded.abs().max(error_rounded)).0; // magnitude the prefix is chosen by
+                let prefix: &(i16, i16, &str) = DECIMAL_PREFIXES
+                    .iter()
+                    .find(|(lower, upper, _prefix)| *lower <= chosen && chosen < *upper)
+                    .unwrap_or(if chosen < 0 { &DECIMAL_PREFIXES[0] } else { &DECIMAL_PREFIXES[DECIMAL_PREFIXES.len() - 1] }); // out of range: nearest prefix
+                (prefix.0, self.uncertainty_magnifier(prefix.2, whitespace_separation))
+            }
+            Scaling::Scientific =>
+            {
+                let chosen: i16 = decimal_magnitude(value_rounded.abs().max(error_rounded)).0; // magnitude the exponent is chosen by
+                (chosen, self.uncertainty_magnifier(&format!(" * 10^({chosen})"), false))
+            }
+            Scaling::Percent(whitespace_separation) => (-2, self.uncertainty_magnifier("%", whitespace_separation)), // times 100
+            Scaling::Binary(_) | Scaling::CompactLocalized | Scaling::Exponential { .. } | Scaling::None | Scaling::Strategy(_) => (0, self.uncertainty_magnifier("", true)),
+        };
+
+        mantissa = Formatter {
+            affix_prefix: Text::Static(""),
+            affix_suffix: Text::Static(""),
+            rounding: Rounding::Magnitude(last - shift),
+            scaling: Scaling::None,
+            unit: Text::Static(""),
+            zero_pad: 0,
+            ..part.clone()
+        };
+        value_formatted = mantissa.format(value_rounded / 10_f64.powi(shift as i32));
+
+        s = match self.uncertainty_notation.0
+        {
+            UncertaintyNotation::Concise => format!("{value_formatted}({}){magnifier}", (error_rounded / 10_f64.powi(last.min(shift) as i32)).round() as u64), // error in units of the last displayed digit, or in full if beyond the decimal separator
+            UncertaintyNotation::PlusMinus =>
+            {
+                let error: String = Formatter { sign: Sign::OnlyMinus, ..mantissa }.format(error_rounded / 10_f64.powi(shift as i32));
+                if magnifier.is_empty()
+                {
+                    format!("{value_formatted} {} {error}", self.uncertainty_notation.1)
+                }
+                else
+                {
+                    format!("({value_formatted} {} {error}){magnifier}", self.uncertainty_notation.1)
+                }
+            }
+        };
+
+        return self.pad(format!("{}{s}{}", self.affix_prefix, self.affix_suffix));
+    }
+
+
+    /// # Summary
+    /// Assembles what follows value and error: unit prefix or exponent and unit, separated like `Formatter::format` separates them.
+    ///
+    /// # Arguments
+    /// - `magnifier`: unit prefix or exponent, may be empty
+    /// - `whitespace_separation`: whether or not to put space between number and unit prefix
+    ///
+    /// # Returns
+    /// - the text after the numbers, may be empty
+    fn uncertainty_magnifier(&self, magnifier: &str, whitespace_separation: bool) -> String
+    {
+        let mut s: String = String::new();
+
+
+        if whitespace_separation && !magnifier.is_empty()
+        {
+            s.push(' ');
+        }
+        s.push_str(magnifier);
+        if !self.unit.is_empty()
+        {
+            if magnifier.starts_with(' ') || (whitespace_separation && magnifier.is_empty())
+            // exponent or no unit prefix, separated like a number without unit prefix
+            {
+                s.push(' ');
+            }
+            s.push_str(if self.unit_plural.is_empty() { &self.unit } else { &self.unit_plural }); // uncertain value is not exactly 1, plural
+        }
+
+        return s;
+    }
+}
+
+
+/// # Summary
+/// Determines the decimal magnitude and leading digit of a number from its shortest representation, exact even where `log10` is off by one ulp.
+///
+/// # Arguments
+/// - `x`: the number, must be finite and positive
+///
+/// # Returns
+/// - the decimal magnitude, x ≈ 10^magnitude
+/// - the leading digit
+fn decimal_magnitude(x: f64) -> (i16, u8)
+{
+    let s: String = format!("{x:e}"); // like "1.5e-3"
+    let (mantissa, exponent) = s.split_once('e').expect("Scientific representation of f64 has no exponent.");
+
+    return (exponent.parse().expect("Exponent of f64 is not an integer."), mantissa.as_bytes()[0] - b'0');
+}
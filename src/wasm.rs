@@ -0,0 +1,141 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+use ::wasm_bindgen::prelude::*;
+
+
+/// # Summary
+/// Formatter for JavaScript and TypeScript through `wasm-bindgen`, so a browser frontend formats exactly like the Rust backend. Options are given as JS object with the string forms of the options, unknown keys are ignored, missing keys keep the defaults of `Formatter::new`:
+/// - `decimalSeparator`: decimal separator
+/// - `groupSeparator`: group separator, empty to not group
+/// - `precision`: rounding as parsed by `Rounding::from_str`, like "sig:4" or "mag:-2"
+/// - `scaling`: scaling as parsed by `Scaling::from_str`, like "binary+space" or "none"
+/// - `sign`: sign behaviour as parsed by `Sign::from_str`, like "always"
+///
+/// Errors are thrown as JS `Error` with the message of the Rust error.
+///
+/// # Examples
+/// ```js
+/// import { JsFormatter } from "scaler";
+///
+/// const f = new JsFormatter({ scaling: "binary+space", precision: "sig:3", groupSeparator: ",", decimalSeparator: "." });
+/// f.format(1536); // "1.50 Ki"
+/// f.parse("1.50 Ki"); // 1536
+/// new JsFormatter({ sign: "never" }); // throws Error: Invalid sign "never", expected "always", "except_zero", "only_minus", or "space_for_positive".
+/// ```
+#[wasm_bindgen]
+pub struct JsFormatter
+{
+    formatter: Formatter, // configured formatter
+}
+
+
+#[wasm_bindgen]
+impl JsFormatter
+{
+    /// # Summary
+    /// Constructs a formatter from a JS options object, or with default settings without one.
+    ///
+    /// # Arguments
+    /// - `options`: JS object with the string forms of the options, or undefined
+    ///
+    /// # Returns
+    /// - the formatter
+    /// - or `JsError` if an option is no string, is malformed, or the separators are problematic, validated like `FormatterBuilder::build`
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: Option<::js_sys::Object>) -> Result<JsFormatter, JsError>
+    {
+        let mut builder: FormatterBuilder = Formatter::builder(); // formatter to validate
+        let decimal_separator: Option<String>; // configured decimal separator
+        let group_separator: Option<String>; // configured group separator
+
+
+        let options: ::js_sys::Object = match options
+        {
+            Some(options) => options,
+            None => return Ok(JsFormatter { formatter: Formatter::new() }),
+        };
+
+        if let Some(precision) = read_option(&options, "precision")?
+        {
+            builder = builder.set_rounding(precision.parse::<Rounding>()?);
+        }
+        if let Some(scaling) = read_option(&options, "scaling")?
+        {
+            builder = builder.set_scaling(scaling.parse::<Scaling>()?);
+        }
+        if let Some(sign) = read_option(&options, "sign")?
+        {
+            builder = builder.set_sign(sign.parse::<Sign>()?);
+        }
+
+        decimal_separator = read_option(&options, "decimalSeparator")?;
+        group_separator = read_option(&options, "groupSeparator")?;
+        if group_separator.is_some() || decimal_separator.is_some()
+        {
+            let default: Formatter = Formatter::new(); // default separators for the one not given
+
+            builder = builder.set_separators(
+                group_separator.as_deref().unwrap_or(default.group_separator.as_ref()),
+                decimal_separator.as_deref().unwrap_or(default.decimal_separator.as_ref()),
+            );
+        }
+
+        return Ok(JsFormatter { formatter: builder.build()? });
+    }
+
+
+    /// # Summary
+    /// Formats a number like `Formatter::format`.
+    ///
+    /// # Arguments
+    /// - `x`: the number to format
+    ///
+    /// # Returns
+    /// - the formatted number
+    pub fn format(&self, x: f64) -> String
+    {
+        return self.formatter.format(x);
+    }
+
+
+    /// # Summary
+    /// Parses a number formatted by this formatter like `Formatter::parse`.
+    ///
+    /// # Arguments
+    /// - `s`: the formatted number
+    ///
+    /// # Returns
+    /// - the number
+    /// - or `JsError` describing what and where the problem is
+    pub fn parse(&self, s: &str) -> Result<f64, JsError>
+    {
+        return Ok(self.formatter.parse(s)?);
+    }
+}
+
+
+/// # Summary
+/// Reads a string option from a JS options object.
+///
+/// # Arguments
+/// - `options`: the options object
+/// - `key`: name of the option
+///
+/// # Returns
+/// - the option or none if it is undefined or null
+/// - or `JsError` if it is no string
+fn read_option(options: &::js_sys::Object, key: &str) -> Result<Option<String>, JsError>
+{
+    let value: JsValue = ::js_sys::Reflect::get(options, &JsValue::from_str(key)).map_err(|_| JsError::new(&format!("Option {key} could not be read.")))?; // option as JS value
+
+
+    if value.is_undefined() || value.is_null()
+    {
+        return Ok(None);
+    }
+    return match value.as_string()
+    {
+        Some(s) => Ok(Some(s)),
+        None => Err(JsError::new(&format!("Option {key} must be a string."))),
+    };
+}
@@ -0,0 +1,247 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::{divide_by_power, exponential_magnitude};
+use crate::magnitude::{binary_magnitude, decimal_magnitude, pow10, pow2};
+use crate::*;
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Bounds the length of the output of `Formatter::format` for all numbers in a range, to size fixed buffers and table columns ahead of time. The output only changes its length where the magnitude of the number, of its mantissa, or its clamping to the display floor, the display ceiling, or the clamp range changes, so the formatter formats the ends of the range and the numbers at every such boundary within it, with the longer of singular and plural unit and all trailing zeros. Padding with zeros is bounded by its width, as leaving out trailing zeros can make room for more of them. The original value is bounded by its longest digits in the range. The bound counts characters, not bytes, and includes padding to the configured width. A custom `Scaling::Strategy` is bounded by `Formatter::max_len` instead, reversed ends are swapped, and not a number as an end bounds the whole f64 domain like `Formatter::max_width`.
+    ///
+    /// # Arguments
+    /// - `min`: lower end of the range, included
+    /// - `max`: upper end of the range, included
+    ///
+    /// # Returns
+    /// - maximum number of characters of any number in the range formatted
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_unit("B");
+    /// assert_eq!(f.width_hint(1.0, 1e6), 8); // "1,000 MB"
+    /// assert_eq!(f.width_hint(-1e6, -1.0), 9); // "-1,000 MB"
+    /// assert_eq!(f.width_hint(0.0, 1e6), 19); // "1,000 * 10^(-300) B", tiny numbers fall back to scientific notation
+    /// assert_eq!(f.width_hint(1.0, 999.0), 7); // "999 B" is shorter than "1,000 B"
+    /// assert_eq!(f.clone().set_width(12, scaler::Align::Right, ' ').width_hint(1.0, 999.0), 12); // padded
+    ///
+    /// let f: scaler::Formatter = scaler::Formatter::new().set_scaling(scaler::Scaling::None).set_rounding(scaler::Rounding::Magnitude(-2));
+    /// assert_eq!(f.width_hint(0.0, 1e6), 12); // "1.000.000,00"
+    /// assert!(f.format(123456.789).chars().count() <= f.width_hint(0.0, 1e6));
+    /// ```
+    pub fn width_hint(&self, min: f64, max: f64) -> usize
+    {
+        let (from, to): (f64, f64) = if max < min { (max, min) } else { (min, max) }; // ends in ascending order
+        let pessimistic: Formatter; // formatter whose output is at least as long as the configured one for every number, without the original value
+        let mut width: usize = self.width; // bound so far, padded at least to the width
+        let zero_padded: usize; // bound of numbers padded with zeros, the threshold marker goes before the padding and the clamp marker after it
+
+
+        if min.is_nan() || max.is_nan()
+        // no range, bound everything
+        {
+            return self.max_width();
+        }
+        if let Scaling::Strategy(_) = self.scaling
+        // suffixes unknown, bytes bound characters
+        {
+            return self.max_len();
+        }
+
+        pessimistic = Formatter {
+            non_finite_policy: NonFinitePolicy::Format,
+            show_original: (ShowOriginal::Off, false),
+            suppress_unit_mantissa: false,
+            trailing_zeros: true,
+            unit: if self.unit_plural.chars().count() <= self.unit.chars().count() { self.unit.clone() } else { self.unit_plural.clone() },
+            unit_plural: Text::Static(""),
+            width: 0,
+            zero_pad: 0,
+            ..self.clone()
+        };
+        zero_padded = match (self.zero_pad, self.display_floor.or(self.display_ceiling))
+        {
+            (0, _) => 0,
+            _ if self.machine_readable => 0,
+            (zero_pad, None) => zero_pad,
+            (zero_pad, Some(_)) => zero_pad + self.threshold_markers.0.chars().count().max(self.threshold_markers.1.chars().count()),
+        } + if self.zero_pad == 0 || self.machine_readable || self.clamp == (None, None) { 0 } else { self.clamp_markers.0.chars().count().max(self.clamp_markers.1.chars().count()) };
+        for (negative, lower, upper) in [(false, from.max(0.0), to), (true, (-to).max(0.0), -from)]
+        // positive and negative numbers, by absolute value
+        {
+            if upper < lower
+            // no numbers of this sign in the range
+            {
+                continue;
+            }
+            let original_width: usize = self.original_width(lower, upper, negative); // bound of the original value, independent of the formatted number
+
+            for x in self.width_candidates(lower, upper)
+            {
+                width = width.max(pessimistic.unpadded_len(if negative { -x } else { x }).max(zero_padded) + original_width);
+            }
+        }
+
+        return width;
+    }
+
+
+    /// # Summary
+    /// Bounds the length of the output of `Formatter::format` for any f64, including infinity and not a number, like `Formatter::width_hint` over the whole domain. The bound counts characters; a byte buffer for `Formatter::format_to_slice` holds any output if it is `Formatter::max_len` bytes long, or 4 times this bound.
+    ///
+    /// # Returns
+    /// - maximum number of characters of any f64 formatted
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// assert_eq!(f.max_width(), 18); // "-4,941 * 10^(-324)"
+    /// assert_eq!(f.max_width(), f.format(-f64::from_bits(1)).chars().count()); // smallest subnormal
+    /// assert!(f.max_width() <= f.max_len());
+    /// ```
+    pub fn max_width(&self) -> usize
+    {
+        return self.width_hint(f64::NEG_INFINITY, f64::INFINITY).max(Formatter { non_finite_policy: NonFinitePolicy::Format, ..self.clone() }.unpadded_len(f64::NAN)); // bounded as if formatted, even if rejected
+    }
+
+
+    /// # Summary
+    /// Lists the numbers of a range whose output is at least as long as the one of every other number in it: the ends and the numbers around every boundary where the magnitude of the number or of its mantissa, or its clamping to the display floor, the display ceiling, or the clamp range, changes. Between these boundaries the output has the same length, numbers rounded across a boundary are displayed like the boundary.
+    ///
+    /// # Arguments
+    /// - `lower`: lower end of the range, not negative
+    /// - `upper`: upper end of the range, at least `lower`
+    ///
+    /// # Returns
+    /// - the numbers, all within the range
+    fn width_candidates(&self, lower: f64, upper: f64) -> Vec<f64>
+    {
+        let mut candidates: Vec<f64> = vec![lower, upper, upper.min(f64::MAX)]; // ends, infinity displayed like the largest finite number too
+        let smallest: f64 = lower.max(f64::from_bits(1)); // smallest number with a magnitude
+        let largest: f64 = upper.min(f64::MAX); // largest number with a magnitude
+        let mut around = |x: f64, ulps: u64| {
+            for bits in x.to_bits().saturating_sub(ulps)..=x.to_bits() + ulps
+            {
+                candidates.push(f64::from_bits(bits));
+            }
+        }; // adds a boundary and its neighbours, as the boundary is only known up to a few ulps
+
+
+        if largest < smallest
+        // only 0 or infinity
+        {
+            return candidates;
+        }
+        for magnitude in decimal_magnitude(smallest)..=decimal_magnitude(largest) + 1
+        // decimal places and integer digits change with the decimal magnitude in every scaling
+        {
+            around(pow10(magnitude), 1);
+        }
+        match &self.scaling
+        {
+            Scaling::Binary(_) =>
+            {
+                for magnitude in (binary_magnitude(smallest) as i64).div_euclid(10) * 10..=binary_magnitude(largest) as i64 + 1
+                // unit prefixes and exponent of the fallback, from the unit prefix of the smallest number
+                {
+                    around(pow2(magnitude), 1);
+                    if magnitude.rem_euclid(10) == 0
+                    // mantissa of a unit prefix reaching 10, 100, 1000
+                    {
+                        for j in 1..=3
+                        {
+                            around(pow2(magnitude) * pow10(j), 1);
+                        }
+                    }
+                }
+            }
+            Scaling::Exponential { base, step, prefixes } =>
+            {
+                let span: i64 = prefixes.map_or(0, |table| table.prefixes.iter().map(|(lower, upper, _prefix)| (*upper - *lower) as i64).max().unwrap_or(0)).max((*step).max(1) as i64); // largest exponent span one divisor covers
+                let mantissa_digits: i16 = (span as f64 * ((*base).max(2) as f64).log10()).ceil().min(309.0) as i16; // magnitude the mantissa stays below
+                let carries: Vec<f64> = match self.rounding // smallest mantissas whose significant digits round up to a power of the base, like 15 to 20 * 16^(0), displayed as 0,9 * 16^(1)
+                {
+                    Rounding::SignificantDigits(precision) if precision != 0 => (1..=span)
+                        .map(|j| {
+                            let power: f64 = divide_by_power(1.0, *base, -j);
+                            let unit: f64 = pow10(decimal_magnitude(f64::from_bits(power.to_bits() - 1)) - precision as i16 + 1); // last significant digit just below the power
+                            return ((power / unit).ceil() - 0.5) * unit;
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+
+                for magnitude in exponential_magnitude(smallest, *base) - span..=exponential_magnitude(largest, *base) + 1
+                // unit prefixes, exponent, and mantissa digits, from the divisor of the smallest number
+                {
+                    let power: f64 = divide_by_power(1.0, *base, -magnitude);
+
+                    around(power, 2);
+                    for j in 1..=mantissa_digits
+                    {
+                        around(power * pow10(j), 2);
+                    }
+                    for carry in carries.iter()
+                    {
+                        around(power * carry, 2);
+                    }
+                }
+            }
+            _ => {} // decimal magnitude covers all other scalings
+        }
+        for (threshold, percent) in [self.display_floor, self.display_ceiling].into_iter().flatten().map(|threshold| (threshold.0.abs(), matches!(self.scaling, Scaling::Percent(_))))
+        // clamped numbers are displayed like the floor or ceiling
+        {
+            around(if percent { threshold / 100.0 } else { threshold }, 2);
+        }
+        for bound in [self.clamp.0, self.clamp.1].into_iter().flatten()
+        // clamped numbers are displayed like the bound
+        {
+            around(bound.0.abs(), 2);
+        }
+
+        candidates.retain(|x| lower <= *x && *x <= upper);
+        return candidates;
+    }
+
+
+    /// # Summary
+    /// Bounds the length of the original value appended with `Formatter::set_show_original` for a range of numbers of one sign. The shortest digits that round-trip are at most 17 significant digits, written out without exponent, so the bound grows with the number of integer digits or leading zeros.
+    ///
+    /// # Arguments
+    /// - `lower`: lower end of the range, not negative
+    /// - `upper`: upper end of the range, at least `lower`
+    /// - `negative`: whether the numbers are negative
+    ///
+    /// # Returns
+    /// - maximum number of characters of the original value with parentheses
+    fn original_width(&self, lower: f64, upper: f64, negative: bool) -> usize
+    {
+        let decimal_separator: usize = if self.show_original.0 == ShowOriginal::Grouped { self.decimal_separator.chars().count() } else { 1 }; // length of the decimal point
+        let group_separator: usize = if self.show_original.0 == ShowOriginal::Grouped { self.group_separator.chars().count() } else { 0 }; // length of a group separator
+        let mut width: usize = 0;
+
+
+        if self.show_original.0 == ShowOriginal::Off || self.machine_readable
+        // never shown
+        {
+            return 0;
+        }
+
+        for magnitude in decimal_magnitude(lower.max(f64::from_bits(1)))..=decimal_magnitude(upper.min(f64::MAX).max(f64::from_bits(1)))
+        {
+            let int_digits: usize = if magnitude < 0 { 1 } else { magnitude as usize + 1 }; // "0" or the integer digits
+            let dec_places: usize = if magnitude < 0 { (-magnitude - 1) as usize + 17 } else { 17_usize.saturating_sub(magnitude as usize + 1) }; // leading zeros and significant fraction digits
+
+            width = width.max(
+                " ()".len()
+                    + negative as usize
+                    + int_digits
+                    + (int_digits - 1) / 3 * group_separator
+                    + if dec_places == 0 { 0 } else { decimal_separator + dec_places },
+            );
+        }
+
+        return width;
+    }
+}
@@ -0,0 +1,296 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::*;
+
+
+/// # Summary
+/// Words of a language to spell numbers with. Languages building numbers from hundreds and groups of thousands like English only need another table.
+struct Vocabulary
+{
+    and:     &'static str,
+    hundred: &'static str,
+    minus:   &'static str,
+    ones:    [&'static str; 20], // 0 to 19
+    point:   &'static str,
+    scales:  [&'static str; 7], // names of the groups of thousands, 10^(0) to 10^(18), enough for u64
+    tens:    [&'static str; 10], // 0 to 90
+}
+
+
+/// # Summary
+/// English with the short scale, where a billion is 10^(9).
+const ENGLISH: Vocabulary = Vocabulary {
+    and:     "and",
+    hundred: "hundred",
+    minus:   "minus",
+    ones:    [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ],
+    point:   "point",
+    scales:  ["", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion"],
+    tens:    ["", "ten", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"],
+};
+
+
+impl Formatter
+{
+    /// # Summary
+    /// Spells a number in English words, for example for cheques or screen readers, "one thousand two hundred thirty-four point five six". The words follow the configured rounding, so with the default of 4 significant digits, 1234.56 is spelt "one thousand two hundred thirty-five" and 0 "zero point zero zero zero"; set `Rounding::Magnitude(0)` for exact whole numbers, or `Rounding::Magnitude(-2)` for cents. The number is neither scaled nor grouped, and affixes and unit are left out. The fractional part is spelt as set with `Formatter::set_words`, digit by digit by default, and includes trailing zeros if they are enabled. Negative numbers are preceded by "minus", unless they are 0 after rounding. Integer parts up to 2^(64) - 1, beyond 18 quintillion, are spelt, larger numbers and infinity or NaN are formatted like `Formatter::format` instead. The configured width applies to the result as a whole. Behind the feature `words`.
+    ///
+    /// # Arguments
+    /// - `x`: the number to spell
+    ///
+    /// # Returns
+    /// - number in words
+    ///
+    /// # Examples
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new(); // 4 significant digits
+    /// assert_eq!(f.format_words(1234.56), "one thousand two hundred thirty-five");
+    /// assert_eq!(f.format_words(999_999_999_999_999_u64 as f64), "one quadrillion");
+    /// assert_eq!(f.format_words(0), "zero point zero zero zero");
+    /// let f: scaler::Formatter = f.set_rounding(scaler::Rounding::Magnitude(0)); // exact whole numbers
+    /// assert_eq!(f.format_words(999_999_999_999_999_u64 as f64), "nine hundred ninety-nine trillion nine hundred ninety-nine billion nine hundred ninety-nine million nine hundred ninety-nine thousand nine hundred ninety-nine");
+    /// assert_eq!(f.format_words(0), "zero");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_trailing_zeros(false);
+    /// assert_eq!(f.format_words(1234.56), "one thousand two hundred thirty-four point five six");
+    /// assert_eq!(f.format_words(0), "zero");
+    /// assert_eq!(f.format_words(0.05), "zero point zero five");
+    /// assert_eq!(f.format_words(-7), "minus seven");
+    /// assert_eq!(f.format_words(-0.001), "zero"); // 0 after rounding
+    /// assert_eq!(f.format_words(13), "thirteen");
+    /// assert_eq!(f.format_words(19), "nineteen");
+    /// assert_eq!(f.format_words(20), "twenty");
+    /// assert_eq!(f.format_words(21), "twenty-one");
+    /// assert_eq!(f.format_words(99), "ninety-nine");
+    /// assert_eq!(f.format_words(100), "one hundred");
+    /// assert_eq!(f.format_words(101), "one hundred one");
+    /// assert_eq!(f.format_words(999), "nine hundred ninety-nine");
+    /// assert_eq!(f.format_words(1000), "one thousand");
+    /// assert_eq!(f.format_words(1001), "one thousand one");
+    /// assert_eq!(f.format_words(1_000_010), "one million ten");
+    /// assert_eq!(f.format_words(1e15), "one quadrillion");
+    /// assert_eq!(f.format_words(1e15 + 1.0), "one quadrillion one");
+    /// assert_eq!(f.format_words(1.2e18), "one quintillion two hundred quadrillion");
+    /// assert_eq!(f.format_words(1e20), "100 E"); // beyond u64
+    /// assert_eq!(f.format_words(f64::NAN), "NaN");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(-2))
+    ///     .set_words(scaler::WordsOptions { fraction: scaler::WordsFraction::Fraction, ..scaler::WordsOptions::new() }); // cheques
+    /// assert_eq!(f.format_words(1234.56), "one thousand two hundred thirty-four and 56/100");
+    /// assert_eq!(f.format_words(1234), "one thousand two hundred thirty-four and 00/100");
+    /// let f: scaler::Formatter = f.set_trailing_zeros(false);
+    /// assert_eq!(f.format_words(1234), "one thousand two hundred thirty-four");
+    /// assert_eq!(f.format_words(0.5), "zero and 5/10");
+    /// ```
+    ///
+    /// ```
+    /// let f: scaler::Formatter = scaler::Formatter::new()
+    ///     .set_rounding(scaler::Rounding::Magnitude(0))
+    ///     .set_words(scaler::WordsOptions { and_after_hundreds: true, fraction: scaler::WordsFraction::Digits, hyphenate: false }) // British
+    ///     .set_width(40, scaler::Align::Left, '.');
+    /// assert_eq!(f.format_words(121), "one hundred and twenty one..............");
+    /// assert_eq!(f.format_words(1001), "one thousand and one....................");
+    /// assert_eq!(f.format_words(1100), "one thousand one hundred................");
+    /// assert_eq!(f.format_words(2_000_099), "two million and ninety nine.............");
+    /// ```
+    pub fn format_words<T>(&self, x: T) -> String
+    where
+        T: Into<f64>,
+    {
+        let digits: String; // rounded number without scaling and grouping, like "-1234.56"
+        let fraction: &str; // digits after the decimal separator, may be empty
+        let integer: u64;
+        let integer_digits: &str; // digits before the decimal separator
+        let negative: bool;
+        let mut s: String = String::new();
+        let vocabulary: &Vocabulary = &ENGLISH; // only language so far
+        let x: f64 = x.into();
+
+
+        if !x.is_finite()
+        // nothing to spell
+        {
+            return self.format(x);
+        }
+
+        digits = Formatter {
+            affix_prefix:       Text::Static(""),
+            affix_suffix:       Text::Static(""),
+            decimal_separator:  Text::Static("."),
+            group_separator:    Text::Static(""),
+            min_integer_digits: 0,
+            prefix_override:    None,
+            scaling:            Scaling::None,
+            show_original:      (ShowOriginal::Off, false),
+            sign:               Sign::OnlyMinus,
+            sign_placement:     SignPlacement::Leading,
+            unit:               Text::Static(""),
+            width:              0,
+            zero_pad:           0,
+            ..self.clone()
+        }
+        .format(x);
+        (integer_digits, fraction) = digits.trim_start_matches('-').split_once('.').unwrap_or((digits.trim_start_matches('-'), ""));
+        integer = match integer_digits.parse()
+        {
+            Ok(integer) => integer,
+            Err(_) => return self.format(x), // beyond u64
+        };
+        negative = digits.starts_with('-') && (integer != 0 || fraction.bytes().any(|digit| digit != b'0'));
+
+        if negative
+        {
+            s.push_str(vocabulary.minus);
+            s.push(' ');
+        }
+        s.push_str(&vocabulary.integer(integer, self.words.hyphenate, self.words.and_after_hundreds));
+        if !fraction.is_empty()
+        {
+            match self.words.fraction
+            {
+                WordsFraction::Digits =>
+                {
+                    s.push(' ');
+                    s.push_str(vocabulary.point);
+                    for digit in fraction.bytes()
+                    {
+                        s.push(' ');
+                        s.push_str(vocabulary.ones[(digit - b'0') as usize]);
+                    }
+                }
+                WordsFraction::Fraction => s.push_str(&format!(" {} {fraction}/1{}", vocabulary.and, "0".repeat(fraction.len()))),
+            }
+        }
+
+        return self.pad(s);
+    }
+}
+
+
+impl Vocabulary
+{
+    /// # Summary
+    /// Spells an integer in groups of thousands, leaving out groups that are 0.
+    ///
+    /// # Arguments
+    /// - `n`: the integer to spell
+    /// - `hyphenate`: whether to join tens and ones with a hyphen instead of a space
+    /// - `and_after_hundreds`: whether to put "and" before the last tens and ones
+    ///
+    /// # Returns
+    /// - integer in words
+    fn integer(&self, n: u64, hyphenate: bool, and_after_hundreds: bool) -> String
+    {
+        let mut groups: Vec<String> = Vec::new(); // spelt groups of thousands, least significant first
+        let mut rest: u64 = n;
+
+
+        if n == 0
+        {
+            return self.ones[0].to_string();
+        }
+
+        for scale in self.scales.iter()
+        {
+            let group: u64 = rest % 1000;
+            rest /= 1000;
+
+            if group != 0
+            {
+                let mut words: String = self.below_thousand(group as usize, hyphenate, and_after_hundreds);
+                if !scale.is_empty()
+                {
+                    words.push(' ');
+                    words.push_str(scale);
+                }
+                groups.push(words);
+            }
+        }
+        if and_after_hundreds && 1000 <= n && (1..100).contains(&(n % 1000))
+        // only tens and ones after higher groups, "one thousand and one"
+        {
+            groups[0] = format!("{} {}", self.and, groups[0]);
+        }
+
+        groups.reverse();
+        return groups.join(" ");
+    }
+
+
+    /// # Summary
+    /// Spells a group of hundreds, tens, and ones.
+    ///
+    /// # Arguments
+    /// - `n`: the group to spell, must be in 1..1000
+    /// - `hyphenate`: whether to join tens and ones with a hyphen instead of a space
+    /// - `and_after_hundreds`: whether to put "and" between hundreds and the following tens and ones
+    ///
+    /// # Returns
+    /// - group in words
+    fn below_thousand(&self, n: usize, hyphenate: bool, and_after_hundreds: bool) -> String
+    {
+        let (hundreds, rest): (usize, usize) = (n / 100, n % 100);
+        let mut s: String = String::new();
+
+
+        if hundreds != 0
+        {
+            s.push_str(self.ones[hundreds]);
+            s.push(' ');
+            s.push_str(self.hundred);
+            if rest != 0
+            {
+                s.push(' ');
+                if and_after_hundreds
+                {
+                    s.push_str(self.and);
+                    s.push(' ');
+                }
+            }
+        }
+
+        if rest != 0 && rest < 20
+        // unique words
+        {
+            s.push_str(self.ones[rest]);
+        }
+        else if rest != 0
+        {
+            s.push_str(self.tens[rest / 10]);
+            if rest % 10 != 0
+            {
+                s.push(if hyphenate { '-' } else { ' ' });
+                s.push_str(self.ones[rest % 10]);
+            }
+        }
+
+        return s;
+    }
+}
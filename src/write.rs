@@ -0,0 +1,184 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+use crate::format::Value;
+use crate::*;
+
+
+/// # Summary
+/// Extension trait to format numbers directly into byte sinks like files, sockets, or `Vec<u8>`, without building a String per number.
+pub trait WriteScaled
+{
+    /// # Summary
+    /// Formats the number like `Formatter::format` and writes the UTF-8 encoded result into self. The pieces of the formatted number are written as they are produced, so wrap unbuffered sinks in `std::io::BufWriter` or use `ScaledWriter`.
+    ///
+    /// # Arguments
+    /// - `f`: the formatter to format with
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - number of bytes written
+    /// - or the error of the sink
+    /// - or `std::io::ErrorKind::InvalidInput` with the `NonFiniteError` if the number is rejected by `NonFinitePolicy::Error`, nothing is written
+    ///
+    /// # Examples
+    /// ```
+    /// use scaler::WriteScaled;
+    /// use std::io::Write;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut csv: Vec<u8> = Vec::new();
+    /// assert_eq!(csv.write_scaled(&f, 123456).unwrap(), 7);
+    /// csv.write_all(b";").unwrap();
+    /// csv.write_scaled(&f, -1e-6).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "123,5 k;-1,000 µ");
+    /// ```
+    ///
+    /// ```
+    /// use scaler::WriteScaled;
+    /// use std::io::Write;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let values: Vec<f64> = (0..10000).map(|i| (i as f64 - 5000.0) * 1.37e3_f64.powi(i % 7)).collect();
+    /// let mut out: Vec<u8> = Vec::new();
+    /// for x in values.iter()
+    /// {
+    ///     out.write_scaled(&f, *x).unwrap();
+    ///     out.write_all(b"\n").unwrap();
+    /// }
+    /// assert_eq!(String::from_utf8(out).unwrap(), values.iter().map(|x| f.format(*x) + "\n").collect::<String>());
+    /// ```
+    fn write_scaled<T>(&mut self, f: &Formatter, x: T) -> std::io::Result<usize>
+    where
+        T: Into<f64>;
+}
+
+
+/// # Summary
+/// Wraps a byte sink and formats numbers into a reused scratch buffer first, so every number reaches the sink in a single write and no allocation happens after the first numbers.
+#[derive(Debug)]
+pub struct ScaledWriter<W>
+{
+    inner:   W,      // sink to write into
+    scratch: String, // reused buffer for the formatted number
+}
+
+
+impl<W> ScaledWriter<W>
+where
+    W: std::io::Write,
+{
+    /// # Summary
+    /// Wraps a byte sink.
+    ///
+    /// # Arguments
+    /// - `inner`: the sink to write into
+    ///
+    /// # Returns
+    /// - ScaledWriter
+    pub fn new(inner: W) -> Self
+    {
+        return Self { inner, scratch: String::new() };
+    }
+
+
+    /// # Summary
+    /// Unwraps the sink.
+    ///
+    /// # Returns
+    /// - the sink
+    pub fn into_inner(self) -> W
+    {
+        return self.inner;
+    }
+
+
+    /// # Summary
+    /// Gives access to the sink, for example to write delimiters between the numbers.
+    ///
+    /// # Returns
+    /// - the sink
+    pub fn get_mut(&mut self) -> &mut W
+    {
+        return &mut self.inner;
+    }
+
+
+    /// # Summary
+    /// Formats the number like `Formatter::format` into the scratch buffer and writes it into the sink at once.
+    ///
+    /// # Arguments
+    /// - `f`: the formatter to format with
+    /// - `x`: the number to format
+    ///     - must be convertable to f64, from into expects lossless conversion
+    ///     - lossy conversion must be explicitly handled by caller beforehand to avoid unexpected behaviour
+    ///
+    /// # Returns
+    /// - number of bytes written
+    /// - or the error of the sink
+    /// - or `std::io::ErrorKind::InvalidInput` with the `NonFiniteError` if the number is rejected by `NonFinitePolicy::Error`, nothing is written
+    ///
+    /// # Examples
+    /// ```
+    /// use std::io::Write;
+    /// let f: scaler::Formatter = scaler::Formatter::new();
+    /// let mut w: scaler::ScaledWriter<Vec<u8>> = scaler::ScaledWriter::new(Vec::new());
+    /// for x in [1.0, 1e3, 1e6]
+    /// {
+    ///     w.write_scaled(&f, x).unwrap();
+    ///     w.get_mut().write_all(b"\n").unwrap();
+    /// }
+    /// assert_eq!(String::from_utf8(w.into_inner()).unwrap(), "1,000\n1,000 k\n1,000 M\n");
+    /// ```
+    pub fn write_scaled<T>(&mut self, f: &Formatter, x: T) -> std::io::Result<usize>
+    where
+        T: Into<f64>,
+    {
+        let x: f64 = x.into();
+
+
+        if let Some(rejected) = f.rejected(x)
+        {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, rejected));
+        }
+        self.scratch.clear();
+        f.format_into(x, &mut self.scratch).expect("Writing into a String failed even though it is infallible.");
+        self.inner.write_all(self.scratch.as_bytes())?;
+        return Ok(self.scratch.len());
+    }
+}
+
+
+impl<W> WriteScaled for W
+where
+    W: std::io::Write + ?Sized,
+{
+    fn write_scaled<T>(&mut self, f: &Formatter, x: T) -> std::io::Result<usize>
+    where
+        T: Into<f64>,
+    {
+        let mut error: Option<std::io::Error> = None; // error of the sink, formatting itself cannot fail
+        let mut len: usize = 0; // number of bytes written
+        let x: f64 = x.into();
+
+
+        if let Some(rejected) = f.rejected(x)
+        {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, rejected));
+        }
+        let result: std::fmt::Result = f.render(Value::Float(x), &mut |_kind, s| {
+            if let Err(e) = self.write_all(s.as_bytes())
+            {
+                error = Some(e);
+                return Err(std::fmt::Error);
+            }
+            len += s.len();
+            return Ok(());
+        });
+
+        return match (result, error)
+        {
+            (_, Some(e)) => Err(e),
+            (Err(_), None) => Err(std::io::Error::other("Formatting failed.")),
+            (Ok(()), None) => Ok(len),
+        };
+    }
+}
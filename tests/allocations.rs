@@ -0,0 +1,196 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+
+/// # Summary
+/// Global allocator that counts allocations, reallocations, and deallocations per thread, so tests running in parallel do not disturb each other, then forwards to the system allocator.
+struct CountingAllocator;
+
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) }; // number of allocations and reallocations of this thread so far
+    static DEALLOCATIONS: Cell<usize> = const { Cell::new(0) }; // number of deallocations of this thread so far
+}
+
+
+unsafe impl GlobalAlloc for CountingAllocator
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8
+    {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        return unsafe { System.alloc(layout) };
+    }
+
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout)
+    {
+        DEALLOCATIONS.with(|deallocations| deallocations.set(deallocations.get() + 1));
+        unsafe { System.dealloc(ptr, layout) };
+    }
+
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8
+    {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        return unsafe { System.realloc(ptr, layout, new_size) };
+    }
+}
+
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+
+/// # Summary
+/// Counts the heap allocations and reallocations of formatting a number.
+///
+/// # Arguments
+/// - `format`: formats the number
+///
+/// # Returns
+/// - number of allocations and reallocations
+fn allocations<S>(format: impl FnOnce() -> S) -> usize
+{
+    let after: usize; // number of allocations after formatting
+    let before: usize = ALLOCATIONS.with(Cell::get); // number of allocations before formatting
+    let s: S; // formatted number, freed only after counting
+
+
+    s = format();
+    after = ALLOCATIONS.with(Cell::get);
+    drop(s);
+
+    return after - before;
+}
+
+
+/// # Summary
+/// Common configurations with numbers to format, none of them without scaling, whose exact digit arithmetic needs buffers.
+///
+/// # Returns
+/// - formatters with the number to format
+fn cases() -> Vec<(scaler::Formatter, f64)>
+{
+    return vec![
+        (scaler::Formatter::new(), 1234567.891),
+        (scaler::Formatter::new(), -0.000_012_5),
+        (scaler::Formatter::new(), 0.0),
+        (scaler::Formatter::new(), 1e300),
+        (scaler::Formatter::new(), f64::NEG_INFINITY),
+        (scaler::Formatter::new().set_unit("B").set_scaling(scaler::Scaling::Binary(true)), 1536.0),
+        (scaler::Formatter::new().set_rounding(scaler::Rounding::Magnitude(-2)).set_sign(scaler::Sign::Always), 42069.123),
+        (scaler::Formatter::new().set_scaling(scaler::Scaling::Scientific).set_rounding(scaler::Rounding::SignificantDigits(10)), 6.022_140_76e23),
+        (scaler::Formatter::new().set_width(12, scaler::Align::Right, ' '), 1234.5),
+        (scaler::Formatter::new().set_zero_pad(8, true), 12.5),
+        (scaler::Formatter::new().set_trailing_zeros(false), 1.5),
+    ];
+}
+
+
+#[test]
+fn const_setters_do_not_leak_text_set_at_runtime()
+{
+    let allocated: usize; // number of allocations of building and dropping the formatters
+    let before: (usize, usize) = (ALLOCATIONS.with(Cell::get), DEALLOCATIONS.with(Cell::get)); // number of allocations and deallocations before
+    let freed: usize; // number of deallocations of building and dropping the formatters
+
+
+    {
+        let f: scaler::Formatter = scaler::Formatter::new()
+            .set_affixes("$", " USD")
+            .set_separators(",", ".")
+            .set_special_strings("inf", "nan")
+            .set_unit("B")
+            .set_none_string("n/a"); // texts set at runtime
+        let f: scaler::Formatter = f
+            .set_affixes_const("", "")
+            .set_separators_const("\u{202F}", ",")
+            .set_special_strings_const("∞", "NaN")
+            .set_unit_plural_const("byte", "bytes")
+            .set_none_string_const("—"); // static texts override them, twice for the unit
+        assert_eq!(f.format(1536), "1,536 kbytes");
+        assert_eq!(f.format_opt(None::<f64>), "—");
+        let f: scaler::Formatter = f.set_unit("B").set_separators(".", ","); // runtime setters after the const setters
+        assert_eq!(f.format(1536), "1,536 kB");
+    }
+    allocated = ALLOCATIONS.with(Cell::get) - before.0;
+    freed = DEALLOCATIONS.with(Cell::get) - before.1;
+
+    assert_eq!(freed, allocated, "Allocated {allocated} times but freed only {freed} times, texts set at runtime leaked.");
+}
+
+
+#[test]
+fn format_allocates_at_most_once()
+{
+    for (f, x) in cases()
+    {
+        let n: usize = allocations(|| f.format(x));
+        assert!(n <= 1, "Formatting {x} allocated {n} times, expected at most once.");
+    }
+}
+
+
+#[cfg(feature = "smallstr")]
+#[test]
+fn format_small_does_not_allocate()
+{
+    for (f, x) in cases()
+    {
+        let n: usize = allocations(|| f.format_small(x));
+        assert_eq!(n, 0, "Formatting {x} into a SmallString allocated {n} times, expected not at all.");
+    }
+}
+
+
+#[test]
+fn formatter_with_static_text_does_not_allocate()
+{
+    let f: scaler::Formatter = scaler::Formatter::new(); // default separators are borrowed
+
+
+    assert_eq!(allocations(scaler::Formatter::new), 0);
+    assert_eq!(allocations(|| f.clone()), 0);
+    assert_eq!(allocations(|| scaler::Formatter::new().set_separators_static(",", ".")), 0);
+    assert_eq!(allocations(|| scaler::Formatter::new().set_separators(".", ",")), 0); // unchanged separators are kept
+    assert_eq!(allocations(|| scaler::Formatter::new().set_separators("", ",")), 0); // empty separator needs no copy
+    assert_eq!(allocations(|| scaler::Formatter::new().set_separators(",", ".")), 2); // changed separators are copied
+}
+
+
+#[test]
+fn per_call_overrides_allocate_like_prepared_formatters()
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_separators(",", ".").set_unit("B"); // texts set at runtime
+    let overrides: scaler::Overrides = scaler::Overrides {
+        rounding: Some(scaler::Rounding::Magnitude(-2)),
+        scaling: Some(scaler::Scaling::Binary(true)),
+        separators: Some(("\u{202F}", ",")),
+        unit: Some(("byte", "bytes")),
+        ..scaler::Overrides::new()
+    };
+    let prepared: scaler::Formatter = f
+        .clone()
+        .set_rounding(scaler::Rounding::Magnitude(-2))
+        .set_scaling(scaler::Scaling::Binary(true))
+        .set_separators("\u{202F}", ",")
+        .set_unit_plural("byte", "bytes"); // same options, set beforehand
+
+
+    assert_eq!(allocations(|| f.clone()), 0); // texts are shared
+    for x in [1536.0, -0.125, 0.0, 1e300, f64::NAN]
+    {
+        let expected: usize = allocations(|| prepared.format(x));
+        let n: usize = allocations(|| f.format_overridden(x, &overrides));
+        assert_eq!(n, expected, "Formatting {x} with overrides allocated {n} times, expected {expected} times like a prepared formatter.");
+    }
+    for (f, x) in cases()
+    {
+        let n: usize = allocations(|| f.format_with_rounding(x, scaler::Rounding::SignificantDigits(3)));
+        assert!(n <= 1, "Formatting {x} with other rounding allocated {n} times, expected at most once.");
+        let n: usize = allocations(|| f.format_with_scaling(x, scaler::Scaling::Binary(false)));
+        assert!(n <= 1, "Formatting {x} with other scaling allocated {n} times, expected at most once.");
+    }
+}
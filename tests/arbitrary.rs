@@ -0,0 +1,139 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![cfg(feature = "proptest")]
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use proptest::prelude::*;
+use scaler::round::Round;
+use scaler::*;
+
+
+/// # Summary
+/// Finite and infinite numbers of every magnitude and both signs, NaN, and subnormals, like `any::<f64>()`, plus numbers rounding up to a power of 10 or a unit prefix.
+///
+/// # Returns
+/// - the strategy
+fn number() -> BoxedStrategy<f64>
+{
+    return prop_oneof![
+        any::<f64>(),
+        (-323_i32..=308, prop_oneof![Just(999.5), Just(999.95), Just(1023.5), Just(1.0)], any::<bool>()).prop_map(|(exponent, mantissa, negative)| {
+            let x: f64 = mantissa * format!("1e{exponent}").parse::<f64>().unwrap(); // exact nearest power of 10
+            return if negative { -x } else { x };
+        }),
+    ]
+    .boxed();
+}
+
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+
+
+    #[test]
+    fn parts_make_up_output(f in Formatter::arbitrary_valid(), x in number())
+    {
+        let parts: FormattedParts = f.format_to_parts(x);
+
+        prop_assert_eq!(parts.to_string(), f.format(x));
+        prop_assert!(!parts.to_string().is_empty());
+        prop_assert!(parts.iter().filter(|part| part.kind == PartKind::DecimalSeparator).count() <= 1, "decimal separator more than once in {:?}", f.format(x));
+    }
+
+
+    #[test]
+    fn integer_digits_grouped_by_3(rounding in any::<Rounding>(), scaling in any::<Scaling>(), sign in any::<Sign>(), x in number())
+    {
+        let f: Formatter = Formatter::new().set_rounding(rounding).set_scaling(scaling).set_sign(sign);
+        let mut groups: Vec<usize> = vec![0]; // lengths of the groups of integer digits
+
+        for part in f.format_to_parts(x).iter()
+        {
+            match part.kind
+            {
+                PartKind::DecimalSeparator => break,
+                PartKind::GroupSeparator => groups.push(0),
+                PartKind::IntegerDigits => *groups.last_mut().expect("Groups are never empty.") += part.value.len(),
+                _ => {}
+            }
+        }
+        prop_assert!((1..=3).contains(&groups[0]) || (groups.len() == 1 && groups[0] == 0), "groups of {:?} digits in {:?}", groups, f.format(x));
+        prop_assert!(groups[1..].iter().all(|len| *len == 3), "groups of {:?} digits in {:?}", groups, f.format(x));
+    }
+
+
+    #[test]
+    fn sign_follows_sign_behaviour(rounding in any::<Rounding>(), scaling in any::<Scaling>(), sign in any::<Sign>(), x in number())
+    {
+        let expected: &str;
+        let f: Formatter = Formatter::new().set_rounding(rounding.clone()).set_scaling(scaling).set_sign(sign.clone());
+        let parts: FormattedParts = f.format_to_parts(x);
+        let signs: Vec<&str> = parts.iter().filter(|part| part.kind == PartKind::Sign).map(|part| part.value.as_str()).collect();
+        let negative: bool = x < 0.0 && (x.is_infinite() || rounding != Rounding::SignificantDigits(0)); // -0 and finite numbers rounded to 0 significant digits are unsigned
+        let zero: bool = x.is_finite() && parts.iter().filter(|part| matches!(part.kind, PartKind::IntegerDigits | PartKind::FractionDigits)).all(|part| part.value.bytes().all(|digit| digit == b'0')); // 0 after rounding
+
+        expected = match (&sign, negative)
+        {
+            _ if x.is_nan() => "", // never signed
+            (Sign::ExceptZero, _) if zero => "",
+            (_, true) => "-",
+            (Sign::Always | Sign::ExceptZero, false) => "+",
+            (Sign::OnlyMinus, false) => "",
+            (Sign::SpaceForPositive, false) => " ",
+        };
+        prop_assert_eq!(signs, if expected.is_empty() { Vec::new() } else { vec![expected] }, "formatting {:?}", f.format(x));
+    }
+
+
+    #[test]
+    fn machine_readable_parses_back(rounding in any::<Rounding>(), scaling in any::<Scaling>(), x in number())
+    {
+        let f: Formatter = Formatter::new().set_rounding(rounding.clone()).set_scaling(scaling).set_machine_readable(true);
+        let rounded: f64 = match rounding
+        {
+            Rounding::Magnitude(magnitude) => x.round_mag(magnitude),
+            Rounding::SignificantDigits(significants) => x.round_sig(significants),
+        };
+        let parsed: f64 = f.format(x).parse::<f64>().map_err(|e| TestCaseError::fail(format!("{:?} does not parse: {e}", f.format(x))))?;
+
+        prop_assert!(parsed == rounded || (parsed.is_nan() && x.is_nan()), "{:?} parses to {:e} instead of {:e}", f.format(x), parsed, rounded);
+    }
+
+
+    #[test]
+    fn rust_literal_parses_back(suffix in any::<bool>(), x in number())
+    {
+        let f: Formatter = Formatter::new().set_rust_literal_suffix(suffix);
+        let literal: String = f.format_rust_literal(x);
+        let digits: &str = if suffix { literal.strip_suffix("_f64").unwrap_or(&literal) } else { &literal };
+
+        if x.is_finite()
+        {
+            let parsed: f64 = digits.replace('_', "").parse::<f64>().map_err(|e| TestCaseError::fail(format!("{literal:?} does not parse: {e}")))?;
+            prop_assert_eq!(parsed.to_bits(), x.to_bits(), "{:?} parses to {:e} instead of {:e}", literal, parsed, x);
+        }
+        else
+        {
+            prop_assert!(literal.starts_with("f64::"), "{:?} is no constant", literal);
+        }
+    }
+
+
+    #[test]
+    fn options_round_trip_as_strings(rounding in any::<Rounding>(), scaling in any::<Scaling>(), sign in any::<Sign>())
+    {
+        prop_assert_eq!(rounding.to_string().parse::<Rounding>(), Ok(rounding));
+        prop_assert_eq!(scaling.to_string().parse::<Scaling>(), Ok(scaling));
+        prop_assert_eq!(sign.to_string().parse::<Sign>(), Ok(sign));
+    }
+}
+
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))] // every bound formats hundreds of candidates
+
+
+    #[test]
+    fn bounded_by_max_width(f in Formatter::arbitrary_valid(), x in number())
+    {
+        prop_assert!(f.format(x).chars().count() <= f.max_width(), "{:?} is longer than {}", f.format(x), f.max_width());
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, CjkOptions, CommonScale, ComplexNotation, ConfigError, DeltaParts, Fill, Formatter, FormatterBuilder, FractionOptions, HexfloatOptions, Locale, NonFinitePolicy, PercentOfOptions, PluralRule, RadixOptions, RateBase, RomanLarge, RomanOptions, Rounding, Scaling, ShowOriginal, Sign, SignPlacement, SignPosition, UncertaintyNotation, WordsFraction, WordsOptions};
+
+
+#[test]
+fn builds_same_as_setters()
+{
+    let built: Formatter = FormatterBuilder::new()
+        .set_affixes("[", "]")
+        .set_auto_precision_max(2)
+        .set_cjk(CjkOptions { financial: true, spelled_minus: true })
+        .set_clamp(Some(-1.0), Some(1e15))
+        .set_clamp_markers("▼", "▲")
+        .set_common_scale(CommonScale::Median)
+        .set_complex_notation(ComplexNotation::Polar, "j")
+        .set_delta(DeltaParts::Percent, 2)
+        .set_display_ceiling(Some(1e12))
+        .set_display_floor(Some(1e-3))
+        .set_exact_max_digits(40)
+        .set_fallback_rounding(Some(Rounding::SignificantDigits(2)))
+        .set_fill_mode(Fill::FigureSpace)
+        .set_fraction(FractionOptions { glyphs: true, mixed: false })
+        .set_hexfloat(HexfloatOptions { all_digits: true, uppercase: true })
+        .set_locale(Locale::DeDe)
+        .set_machine_readable(true)
+        .set_min_integer_digits(2)
+        .set_non_finite_policy(NonFinitePolicy::Error)
+        .set_none_string("n/a")
+        .set_overflow_marker('?')
+        .set_percent_of(PercentOfOptions { clamp: true, zero_of_zero_placeholder: true })
+        .set_plural_rule(PluralRule::English)
+        .set_radix(RadixOptions { group_size: 8, min_digits: 16, prefix: true })
+        .set_range_separator(" to ", true)
+        .set_rate_base(RateBase::Auto)
+        .set_reserve_prefix_width(true)
+        .set_roman(RomanOptions { additive: true, large: RomanLarge::Overline, lowercase: true })
+        .set_rounding(Rounding::Magnitude(-1))
+        .set_rust_literal_suffix(false)
+        .set_scaling(Scaling::Binary(false))
+        .set_separators("'", ".")
+        .set_show_original(ShowOriginal::Raw, true)
+        .set_sign(Sign::Always)
+        .set_sign_placement(SignPlacement::Trailing)
+        .set_sign_position(SignPosition::AfterAffix)
+        .set_sortable_digits(3, 1)
+        .set_special_strings("inf", "nan")
+        .set_suppress_unit_mantissa(true)
+        .set_threshold_markers("≤ ", "≥ ")
+        .set_trailing_zeros(false)
+        .set_uncertainty_notation(UncertaintyNotation::Concise, "+/-")
+        .set_unit("B")
+        .set_unit_plural("byte", "bytes")
+        .set_width(12, Align::Center, '*')
+        .set_words(WordsOptions { and_after_hundreds: true, fraction: WordsFraction::Fraction, hyphenate: false })
+        .set_zero_epsilon(1e-12)
+        .set_zero_exponent(true)
+        .set_zero_pad(8, true)
+        .build()
+        .unwrap(); // every setter once
+    let set: Formatter = Formatter::new()
+        .set_affixes("[", "]")
+        .set_auto_precision_max(2)
+        .set_cjk(CjkOptions { financial: true, spelled_minus: true })
+        .set_clamp(Some(-1.0), Some(1e15))
+        .set_clamp_markers("▼", "▲")
+        .set_common_scale(CommonScale::Median)
+        .set_complex_notation(ComplexNotation::Polar, "j")
+        .set_delta(DeltaParts::Percent, 2)
+        .set_display_ceiling(Some(1e12))
+        .set_display_floor(Some(1e-3))
+        .set_exact_max_digits(40)
+        .set_fallback_rounding(Some(Rounding::SignificantDigits(2)))
+        .set_fill_mode(Fill::FigureSpace)
+        .set_fraction(FractionOptions { glyphs: true, mixed: false })
+        .set_hexfloat(HexfloatOptions { all_digits: true, uppercase: true })
+        .set_locale(Locale::DeDe)
+        .set_machine_readable(true)
+        .set_min_integer_digits(2)
+        .set_non_finite_policy(NonFinitePolicy::Error)
+        .set_none_string("n/a")
+        .set_overflow_marker('?')
+        .set_percent_of(PercentOfOptions { clamp: true, zero_of_zero_placeholder: true })
+        .set_plural_rule(PluralRule::English)
+        .set_radix(RadixOptions { group_size: 8, min_digits: 16, prefix: true })
+        .set_range_separator(" to ", true)
+        .set_rate_base(RateBase::Auto)
+        .set_reserve_prefix_width(true)
+        .set_roman(RomanOptions { additive: true, large: RomanLarge::Overline, lowercase: true })
+        .set_rounding(Rounding::Magnitude(-1))
+        .set_rust_literal_suffix(false)
+        .set_scaling(Scaling::Binary(false))
+        .set_separators("'", ".")
+        .set_show_original(ShowOriginal::Raw, true)
+        .set_sign(Sign::Always)
+        .set_sign_placement(SignPlacement::Trailing)
+        .set_sign_position(SignPosition::AfterAffix)
+        .set_sortable_digits(3, 1)
+        .set_special_strings("inf", "nan")
+        .set_suppress_unit_mantissa(true)
+        .set_threshold_markers("≤ ", "≥ ")
+        .set_trailing_zeros(false)
+        .set_uncertainty_notation(UncertaintyNotation::Concise, "+/-")
+        .set_unit("B")
+        .set_unit_plural("byte", "bytes")
+        .set_width(12, Align::Center, '*')
+        .set_words(WordsOptions { and_after_hundreds: true, fraction: WordsFraction::Fraction, hyphenate: false })
+        .set_zero_epsilon(1e-12)
+        .set_zero_exponent(true)
+        .set_zero_pad(8, true); // same with the infallible setters
+
+
+    assert_eq!(built, set);
+    assert_eq!(Formatter::builder().build(), Ok(Formatter::new()));
+    assert_eq!(Formatter::builder().set_separators_static(",", ".").build(), Ok(Formatter::new().set_separators(",", ".")));
+}
+
+
+#[test]
+fn rejects_every_rule()
+{
+    for (builder, expected) in [
+        (Formatter::builder().set_separators(".", ""), ConfigError::EmptyDecimalSeparator),
+        (Formatter::builder().set_separators("", ""), ConfigError::EmptyDecimalSeparator), // empty before identical
+        (Formatter::builder().set_separators(",", ","), ConfigError::IdenticalSeparators(",".to_string())),
+        (Formatter::builder().set_separators_static(" ", " "), ConfigError::IdenticalSeparators(" ".to_string())),
+        (Formatter::builder().set_separators("1", ","), ConfigError::DigitInGroupSeparator("1".to_string())),
+        (Formatter::builder().set_separators(".", "x0"), ConfigError::DigitInDecimalSeparator("x0".to_string())),
+        (Formatter::builder().set_clamp(Some(1.0), Some(-1.0)), ConfigError::InvertedClamp),
+        (Formatter::builder().set_clamp(Some(0.0), Some(-0.5)).set_separators(".", "."), ConfigError::IdenticalSeparators(".".to_string())), // separators before clamp range
+        (Formatter::builder().set_zero_epsilon(-1e-12), ConfigError::InvalidZeroEpsilon),
+        (Formatter::builder().set_zero_epsilon(f64::INFINITY), ConfigError::InvalidZeroEpsilon),
+        (Formatter::builder().set_zero_epsilon(f64::NAN), ConfigError::InvalidZeroEpsilon),
+        (Formatter::builder().set_zero_epsilon(f64::NAN).set_clamp(Some(1.0), Some(0.0)), ConfigError::InvertedClamp), // clamp range before epsilon
+        (Formatter::builder().set_affixes("$", "").set_width(1, Align::Right, ' '), ConfigError::WidthBelowContent { minimum: 2, width: 1 }),
+        (Formatter::builder().set_min_integer_digits(5).set_width(4, Align::Left, ' '), ConfigError::WidthBelowContent { minimum: 5, width: 4 }),
+        (Formatter::builder().set_unit("kWh").set_width(3, Align::Right, ' '), ConfigError::WidthBelowContent { minimum: 4, width: 3 }),
+        (Formatter::builder().set_zero_pad(10, false).set_width(9, Align::Right, ' '), ConfigError::WidthBelowContent { minimum: 10, width: 9 }),
+    ]
+    {
+        assert_eq!(builder.clone().build(), Err(expected.clone()), "{builder:?} is not rejected with {expected:?}.");
+    }
+}
+
+
+#[test]
+fn accepts_boundaries()
+{
+    assert!(Formatter::builder().set_separators("", ",").build().is_ok()); // no grouping is fine
+    assert!(Formatter::builder().set_width(1, Align::Right, ' ').build().is_ok()); // at least 1 digit
+    assert!(Formatter::builder().set_width(0, Align::Right, ' ').set_affixes("EUR ", "").build().is_ok()); // no width
+    assert!(Formatter::builder().set_affixes("$", "").set_width(2, Align::Right, ' ').build().is_ok());
+    assert!(Formatter::builder().set_zero_pad(10, false).set_width(10, Align::Right, ' ').build().is_ok());
+    assert!(Formatter::builder().set_clamp(Some(1.0), Some(1.0)).build().is_ok()); // single number
+    assert!(Formatter::builder().set_clamp(Some(1.0), None).build().is_ok());
+    assert!(Formatter::builder().set_clamp(Some(f64::NAN), Some(-1.0)).build().is_ok()); // not a number clamps nothing
+    assert!(Formatter::builder().set_zero_epsilon(0.0).build().is_ok());
+    assert!(Formatter::builder().set_zero_epsilon(f64::MAX).build().is_ok());
+}
+
+
+#[test]
+fn messages_are_helpful()
+{
+    assert_eq!(ConfigError::DigitInGroupSeparator("1".to_string()).to_string(), "Group separator \"1\" contains a digit. This may lead to ambiguous formatting.");
+    assert_eq!(
+        ConfigError::WidthBelowContent { minimum: 5, width: 4 }.to_string(),
+        "Width 4 is less than 5, the length of the shortest formatted number, so it never pads numbers."
+    );
+}
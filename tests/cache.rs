@@ -0,0 +1,98 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{CachedFormatter, Formatter, Rounding, Scaling};
+use std::sync::Arc;
+
+
+#[test]
+fn same_output_as_formatter()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::SignificantDigits(3)).set_scaling(Scaling::Binary(true)).set_unit("B");
+    let cached: CachedFormatter = CachedFormatter::new(f.clone(), 4);
+
+
+    for _ in 0..3
+    {
+        for x in [0.0, -0.0, 1.0, 1536.0, -3670016.0, 1e300, f64::MIN_POSITIVE, f64::INFINITY, f64::NEG_INFINITY, f64::NAN]
+        {
+            assert_eq!(&*cached.format(x), f.format(x), "{x}");
+        }
+    }
+    assert_eq!(cached.len(), 4);
+}
+
+
+#[test]
+fn least_recently_used_is_evicted()
+{
+    let cached: CachedFormatter = CachedFormatter::new(Formatter::new(), 2);
+    let one: Arc<str> = cached.format(1); // cached first
+
+
+    cached.format(2);
+    assert!(Arc::ptr_eq(&cached.format(1), &one)); // 1 used more recently than 2
+    cached.format(3); // evicts 2
+    assert!(Arc::ptr_eq(&cached.format(1), &one));
+    cached.format(4); // evicts 3
+    cached.format(5); // evicts 1
+    assert!(!Arc::ptr_eq(&cached.format(1), &one));
+    assert_eq!(cached.len(), 2);
+}
+
+
+#[test]
+fn keys_are_bit_patterns()
+{
+    let cached: CachedFormatter = CachedFormatter::new(Formatter::new().set_scaling(Scaling::None), 8);
+    let payload: f64 = f64::from_bits(f64::NAN.to_bits() | 0x42); // not a number with another payload
+    let nan: Arc<str> = cached.format(f64::NAN);
+
+
+    assert!(Arc::ptr_eq(&cached.format(payload), &nan)); // all not a numbers share one entry
+    assert!(Arc::ptr_eq(&cached.format(-f64::NAN), &nan));
+    assert_eq!(cached.len(), 1);
+    cached.format(0.0);
+    cached.format(-0.0);
+    assert_eq!(cached.len(), 3); // 0 and -0 differ
+    cached.format(1_u8);
+    cached.format(1.0);
+    assert_eq!(cached.len(), 4); // same number from different types
+}
+
+
+#[test]
+fn capacity_zero_remembers_nothing()
+{
+    let cached: CachedFormatter = CachedFormatter::new(Formatter::new(), 0);
+
+
+    assert_eq!(&*cached.format(42069), "42,07 k");
+    assert_eq!(&*cached.format(42069), "42,07 k");
+    assert!(cached.is_empty());
+}
+
+
+#[test]
+fn shared_between_threads()
+{
+    let cached: Arc<CachedFormatter> = Arc::new(CachedFormatter::new(Formatter::new(), 16));
+    let threads: Vec<std::thread::JoinHandle<()>> = (0..4)
+        .map(|_| {
+            let cached: Arc<CachedFormatter> = cached.clone();
+            return std::thread::spawn(move || {
+                for i in 0..1000
+                {
+                    let x: f64 = (i % 20) as f64 * 1000.0;
+                    assert_eq!(&*cached.format(x), Formatter::new().format(x));
+                }
+            });
+        })
+        .collect();
+
+
+    for thread in threads
+    {
+        thread.join().unwrap();
+    }
+    assert_eq!(cached.len(), 16);
+}
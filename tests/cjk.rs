@@ -0,0 +1,74 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, CjkOptions, CjkStyle, Formatter};
+
+
+/// numbers and how they are written mixed with Arabic digits, fully spelled, and fully spelled with financial characters
+const NUMERALS: [(i128, &str, &str, &str); 13] = [
+    (0, "0", "〇", "零"),
+    (1, "1", "一", "壱"),
+    (10, "10", "十", "壱拾"),
+    (11, "11", "十一", "壱拾壱"),
+    (110, "110", "百十", "壱佰壱拾"),
+    (1999, "1999", "千九百九十九", "壱仟玖佰玖拾玖"),
+    (10000, "1万", "一万", "壱萬"),
+    (10001, "1万1", "一万一", "壱萬壱"),
+    (200_000, "20万", "二十万", "弐拾萬"),
+    (100010001, "1億1万1", "一億一万一", "壱億壱萬壱"),
+    (10_000_000_000_000_000, "1京", "一京", "壱京"),
+    (12_345_678_901_234_567, "1京2345兆6789億123万4567", "一京二千三百四十五兆六千七百八十九億百二十三万四千五百六十七", "壱京弐仟参佰肆拾伍兆陸仟漆佰捌拾玖億壱佰弐拾参萬肆仟伍佰陸拾漆"),
+    (9_999_000_000_000_000, "9999兆", "九千九百九十九兆", "玖仟玖佰玖拾玖兆"),
+];
+
+
+#[test]
+fn numerals()
+{
+    let f: Formatter = Formatter::new();
+    let financial: Formatter = Formatter::new().set_cjk(CjkOptions { financial: true, ..CjkOptions::new() });
+
+
+    for (x, mixed, full, full_financial) in NUMERALS
+    {
+        assert_eq!(f.format_cjk(x, CjkStyle::MixedArabic), mixed);
+        assert_eq!(f.format_cjk(x, CjkStyle::FullKanji), full);
+        assert_eq!(financial.format_cjk(x, CjkStyle::FullKanji), full_financial);
+        assert_eq!(financial.format_cjk(x, CjkStyle::MixedArabic), mixed.replace('万', "萬"));
+    }
+}
+
+
+#[test]
+fn negative()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_cjk(-12345, CjkStyle::MixedArabic), "−1万2345");
+    assert_eq!(f.format_cjk(-12345, CjkStyle::FullKanji), "−一万二千三百四十五");
+    assert_eq!(f.clone().set_cjk(CjkOptions { spelled_minus: true, ..CjkOptions::new() }).format_cjk(-12345, CjkStyle::FullKanji), "マイナス一万二千三百四十五");
+    assert_eq!(f.clone().set_cjk(CjkOptions { spelled_minus: true, ..CjkOptions::new() }).format_cjk(0, CjkStyle::FullKanji), "〇"); // 0 is unsigned
+}
+
+
+#[test]
+fn extremes()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_cjk(i128::MAX, CjkStyle::MixedArabic), "170澗1411溝8346穣469𥝱2317垓3168京7303兆7158億8410万5727");
+    assert_eq!(f.format_cjk(i128::MIN, CjkStyle::MixedArabic), "−170澗1411溝8346穣469𥝱2317垓3168京7303兆7158億8410万5728");
+    assert!(f.format_cjk(i128::MIN, CjkStyle::FullKanji).starts_with("−百七十澗千四百十一溝"));
+}
+
+
+#[test]
+fn width_applies()
+{
+    let f: Formatter = Formatter::new().set_width(8, Align::Right, ' ');
+
+
+    assert_eq!(f.format_cjk(12345, CjkStyle::MixedArabic), "  1万2345"); // padded by characters, not bytes
+    assert_eq!(f.format_cjk(12345, CjkStyle::FullKanji), "一万二千三百四十五");
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, PartKind, Rounding, Scaling, ShowOriginal};
+
+
+#[test]
+fn inside_at_and_outside()
+{
+    let f: Formatter = Formatter::new().set_clamp(Some(0.0), Some(100.0)).set_clamp_markers("▼", "▲");
+
+
+    assert_eq!(f.format(50.0), "50,00");
+    assert_eq!(f.format(0.0), "0,000"); // at the bounds, not clamped
+    assert_eq!(f.format(-0.0), "0,000");
+    assert_eq!(f.format(100.0), "100,0");
+    assert_eq!(f.format(100.0001), "100,0▲"); // clamped before rounding
+    assert_eq!(f.format(1e9), "100,0▲"); // clamped before scaling
+    assert_eq!(f.format(-1.0), "0,000▼");
+    assert_eq!(f.clone().set_clamp_markers("", "").format(1e9), "100,0");
+    assert_eq!(f.clone().set_clamp(None, None).format(1e9), "1,000 G");
+}
+
+
+#[test]
+fn negative_range()
+{
+    let f: Formatter = Formatter::new().set_clamp(Some(-40.0), Some(-10.0)).set_clamp_markers("▼", "▲").set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(-1));
+
+
+    assert_eq!(f.format(-20.0), "-20,0");
+    assert_eq!(f.format(-40.0), "-40,0");
+    assert_eq!(f.format(-10.0), "-10,0");
+    assert_eq!(f.format(-50.0), "-40,0▼");
+    assert_eq!(f.format(-5.0), "-10,0▲");
+    assert_eq!(f.format(0.0), "-10,0▲");
+}
+
+
+#[test]
+fn special_values()
+{
+    let f: Formatter = Formatter::new().set_clamp(Some(-40.0), Some(-10.0)).set_clamp_markers("▼", "▲").set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(-1));
+
+
+    assert_eq!(f.format(f64::INFINITY), "-10,0▲");
+    assert_eq!(f.format(f64::NEG_INFINITY), "-40,0▼");
+    assert_eq!(f.format(f64::NAN), "NaN");
+    assert_eq!(f.clone().set_clamp(Some(-40.0), None).format(f64::INFINITY), "∞"); // no bound to clamp to
+    assert_eq!(f.clone().set_clamp(Some(f64::NAN), Some(f64::NAN)).format(-50.0), "-50,0"); // bounds of not a number clamp nothing
+}
+
+
+#[test]
+fn inverted_range_maximum_wins()
+{
+    let f: Formatter = Formatter::new().set_clamp(Some(10.0), Some(1.0)).set_clamp_markers("▼", "▲");
+
+
+    assert_eq!(f.format(5.0), "1,000▲");
+    assert_eq!(f.format(0.0), "1,000▲");
+    assert_eq!(f.format(20.0), "1,000▲");
+}
+
+
+#[test]
+fn interplay_with_other_options()
+{
+    let f: Formatter = Formatter::new().set_clamp(Some(0.0), Some(100.0)).set_clamp_markers("▼", "▲");
+
+
+    assert_eq!(f.clone().set_show_original(ShowOriginal::Raw, true).format(150.5), "100,0▲ (150.5)"); // original value before clamping
+    assert_eq!(f.clone().set_machine_readable(true).format(150.5), "150.5"); // never clamped
+    assert_eq!(f.clone().set_zero_pad(8, false).format(-5.0), "0000,000▼"); // marker after the padding
+    assert_eq!(Formatter::new().set_clamp(Some(0.0), Some(2e6)).set_display_ceiling(Some(999e3)).set_clamp_markers("", "!").format(5e6), "> 999,0 k!");
+    assert_eq!(f.format_to_parts(1e3).0.last().map(|part| (part.kind, part.value.as_str())), Some((PartKind::Threshold, "▲")));
+}
+
+
+#[test]
+fn layout_and_width_agree()
+{
+    let f: Formatter = Formatter::new().set_clamp(Some(1.0), Some(100.0)).set_clamp_markers("▼", "▲");
+
+
+    assert_eq!(f.prefix_for(5e6), Some("")); // "100,0▲", not "5,000 M"
+    assert_eq!(f.decimals_for(5e6), 1);
+    assert_eq!(f.max_width(), 6); // "1,000▼" and "100,0▲"
+    assert_eq!(f.width_hint(100.0, 1e9), 6);
+    assert_eq!(f.clone().set_zero_pad(8, false).max_width(), 9);
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+#![cfg(feature = "clap")]
+use clap::Parser;
+use scaler::{Formatter, FormatterArgs, Rounding, Scaling, Sign};
+
+
+/// # Summary
+/// Command line tool embedding the formatter flags.
+#[derive(clap::Parser)]
+struct Cli
+{
+    #[command(flatten)]
+    format: FormatterArgs,
+}
+
+
+/// # Summary
+/// Parses command line arguments and converts them into a formatter.
+///
+/// # Arguments
+/// - `args`: the arguments without the program name
+///
+/// # Returns
+/// - the formatter
+/// - or the error of clap while parsing or converting
+fn to_formatter(args: &[&str]) -> Result<Formatter, clap::Error>
+{
+    return Cli::try_parse_from(std::iter::once("tool").chain(args.iter().copied()))?.format.to_formatter();
+}
+
+
+#[test]
+fn args_map_to_formatter()
+{
+    for (args, expected) in [
+        (&[][..], Formatter::new()),
+        (&["--sig-digits", "3"], Formatter::new().set_rounding(Rounding::SignificantDigits(3))),
+        (&["--decimals", "2"], Formatter::new().set_rounding(Rounding::Magnitude(-2))),
+        (&["--decimals", "0"], Formatter::new().set_rounding(Rounding::Magnitude(0))),
+        (&["--binary"], Formatter::new().set_scaling(Scaling::Binary(true))),
+        (&["--scaling", "bin"], Formatter::new().set_scaling(Scaling::Binary(false))),
+        (&["--scaling", "Decimal+Space"], Formatter::new().set_scaling(Scaling::Decimal(true))),
+        (&["--scaling", "sci"], Formatter::new().set_scaling(Scaling::Scientific)),
+        (&["--sign", "space_for_positive"], Formatter::new().set_sign(Sign::SpaceForPositive)),
+        (&["--no-grouping"], Formatter::new().set_separators("", ",")),
+        (&["--group-sep", ",", "--decimal-sep", "."], Formatter::new().set_separators(",", ".")),
+        (&["--group-sep", "'"], Formatter::new().set_separators("'", ",")),
+        (&["--no-grouping", "--decimal-sep", "."], Formatter::new().set_separators("", ".")),
+        (
+            &["--binary", "--decimals", "1", "--sign", "always", "--group-sep", " "],
+            Formatter::new().set_scaling(Scaling::Binary(true)).set_rounding(Rounding::Magnitude(-1)).set_sign(Sign::Always).set_separators(" ", ","),
+        ),
+    ]
+    {
+        assert_eq!(to_formatter(args).unwrap(), expected, "Arguments {args:?} do not map to the expected formatter.");
+    }
+}
+
+
+#[test]
+fn rejects_conflicts_and_invalid_values()
+{
+    for (args, kind) in [
+        (&["--sig-digits", "3", "--decimals", "2"][..], clap::error::ErrorKind::ArgumentConflict),
+        (&["--binary", "--scaling", "none"], clap::error::ErrorKind::ArgumentConflict),
+        (&["--no-grouping", "--group-sep", "."], clap::error::ErrorKind::ArgumentConflict),
+        (&["--decimals", "-1"], clap::error::ErrorKind::UnknownArgument), // looks like a flag
+        (&["--decimals=-1"], clap::error::ErrorKind::ValueValidation),
+        (&["--sig-digits", "256"], clap::error::ErrorKind::ValueValidation),
+        (&["--scaling", "metric"], clap::error::ErrorKind::ValueValidation),
+        (&["--sign", "never"], clap::error::ErrorKind::ValueValidation),
+        (&["--decimal-sep", "."], clap::error::ErrorKind::ValueValidation), // same as default group separator
+        (&["--group-sep", "1"], clap::error::ErrorKind::ValueValidation),
+        (&["--decimal-sep", ""], clap::error::ErrorKind::ValueValidation),
+    ]
+    {
+        assert_eq!(to_formatter(args).map_err(|e| e.kind()).err(), Some(kind), "Arguments {args:?} are not rejected as expected.");
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Locale, Rounding, Scaling};
+
+
+#[test]
+fn localized()
+{
+    let cases: [(Locale, f64, &str); 22] = [
+        (Locale::DeDe, 999.0, "999"),
+        (Locale::DeDe, 12345.0, "12.300"),
+        (Locale::DeDe, 999999.0, "1,00 Mio."),
+        (Locale::DeDe, 1234567.0, "1,23 Mio."),
+        (Locale::DeDe, -1234567.0, "-1,23 Mio."),
+        (Locale::DeDe, 3.45e9, "3,45 Mrd."),
+        (Locale::DeDe, 1.5e12, "1,50 Bio."),
+        (Locale::DeDe, 2e15, "2.000 Bio."),
+        (Locale::JaJp, 0.5, "0.500"),
+        (Locale::JaJp, 1234.0, "1,230"),
+        (Locale::JaJp, 9999.0, "1.00万"),
+        (Locale::JaJp, 12345.0, "1.23万"),
+        (Locale::JaJp, 123456789.0, "1.23億"),
+        (Locale::JaJp, 1.2e12, "1.20兆"),
+        (Locale::JaJp, 5e16, "5.00京"),
+        (Locale::HiIn, 999.0, "999"),
+        (Locale::HiIn, 1234.0, "1.23 हज़ार"),
+        (Locale::HiIn, 99999.0, "1.00 लाख"),
+        (Locale::HiIn, 123456.0, "1.23 लाख"),
+        (Locale::HiIn, 12345678.0, "1.23 करोड़"),
+        (Locale::HiIn, 1.5e9, "1.50 अरब"),
+        (Locale::HiIn, 7.5e11, "7.50 खरब"),
+    ]; // locale, number, formatted
+
+
+    for (locale, x, formatted) in cases
+    {
+        let f: Formatter = Formatter::new().set_locale(locale).set_rounding(Rounding::SignificantDigits(3)).set_scaling(Scaling::CompactLocalized);
+        assert_eq!(f.format(x), formatted, "{locale} {x}");
+        assert!(f.format(x).len() <= f.max_len(), "{locale} {x}");
+    }
+}
+
+
+#[test]
+fn english_without_locale()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::SignificantDigits(3)).set_scaling(Scaling::CompactLocalized);
+
+
+    for (x, formatted) in [(999.0, "999"), (1234.0, "1,23K"), (1234567.0, "1,23M"), (3.45e9, "3,45B"), (1.5e12, "1,50T"), (f64::INFINITY, "∞")]
+    {
+        assert_eq!(f.format(x), formatted, "{x}");
+    }
+    assert_eq!(f.clone().set_locale(Locale::EnUs).format(1234.0), "1.23K");
+}
+
+
+#[test]
+fn rounding_to_magnitude()
+{
+    let f: Formatter = Formatter::new().set_locale(Locale::JaJp).set_rounding(Rounding::Magnitude(2)).set_scaling(Scaling::CompactLocalized);
+
+
+    for (x, formatted) in [(1234.0, "1,200"), (12345.0, "1.23万"), (123456789.0, "1.234568億")]
+    {
+        assert_eq!(f.format(x), formatted, "{x}");
+    }
+}
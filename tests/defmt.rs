@@ -0,0 +1,92 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+#![cfg(feature = "defmt")]
+use scaler::{Formatter, Rounding, Scaling};
+
+
+/// # Summary
+/// Encodes with the mocked defmt logger of the feature `unstable-test`, which interns strings by counting up instead of by address.
+///
+/// # Arguments
+/// - `write`: writes into the defmt formatter
+///
+/// # Returns
+/// - the first mocked string index used, the following ones count up from it
+/// - the encoded bytes
+fn encode(write: impl FnOnce(defmt::Formatter<'_>)) -> (u16, Vec<u8>)
+{
+    let index: u16; // first mocked string index
+
+
+    defmt::export::fetch_bytes(); // discard leftovers
+    index = defmt::export::fetch_string_index();
+    write(defmt::export::make_formatter());
+    return (index, defmt::export::fetch_bytes());
+}
+
+
+/// # Summary
+/// Expected encoding of `{=str}`: string index, length as u32, UTF-8 bytes.
+///
+/// # Arguments
+/// - `index`: mocked string index of "{=str}"
+/// - `s`: the string
+///
+/// # Returns
+/// - the encoded bytes
+fn encoded_str(index: u16, s: &str) -> Vec<u8>
+{
+    return [index.to_le_bytes().as_slice(), &(s.len() as u32).to_le_bytes(), s.as_bytes()].concat();
+}
+
+
+#[test]
+fn format_defmt_sends_formatted_str()
+{
+    let formatters: Vec<Formatter> = vec![
+        Formatter::new(),
+        Formatter::new().set_scaling(Scaling::Binary(true)).set_unit("B"),
+        Formatter::new().set_separators("\u{202F}", "·").set_rounding(Rounding::SignificantDigits(6)),
+    ]; // formatters with results fitting into the stack buffer
+
+
+    for f in formatters
+    {
+        for x in [0.0, -42069.0, 1536.0, 1e-7, 1e300, f64::INFINITY, f64::NAN]
+        {
+            let (index, bytes) = encode(|fmt| f.format_defmt(x, fmt)); // encoded number
+
+            assert_eq!(bytes, encoded_str(index, &f.format(x)), "{x} was not sent as formatted str.");
+        }
+    }
+}
+
+
+#[test]
+fn format_defmt_streams_long_results()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::None).set_rounding(Rounding::SignificantDigits(80)); // 1e70 has 71 integer digits plus separators
+    let (index, bytes) = encode(|fmt| f.format_defmt(1e70, fmt)); // encoded number
+
+
+    assert_eq!(bytes, [index.to_le_bytes().as_slice(), &(index + 1).to_le_bytes(), f.format(1e70).as_bytes(), &[0xFF]].concat()); // "{}", "{=__internal_Display}", text, terminator
+}
+
+
+#[test]
+fn display_implements_format()
+{
+    let f: Formatter = Formatter::new().set_unit("B"); // formatter to display with
+
+
+    for (x, s) in [(Some(42069.0), "42,07 kB"), (Some(f64::NEG_INFINITY), "-∞ B"), (None, "—")]
+    {
+        let (index, bytes) = encode(|fmt| defmt::write!(fmt, "{}", f.display_opt(x))); // encoded number
+
+        assert_eq!(
+            bytes,
+            [index.to_le_bytes().as_slice(), &(index + 1).to_le_bytes(), &encoded_str(index + 2, s), &[0, 0]].concat(), // "{}", format sequence, str, end of sequence
+            "{x:?} was not sent as {s:?}."
+        );
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![cfg(feature = "derive")]
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::ScaledDisplay;
+
+
+#[derive(ScaledDisplay)]
+#[scaled(sig = 2, sign = "always", sep = (",", "."))]
+struct Metrics
+{
+    cpu:      f32,
+    #[scaled(sign = "only_minus", scaling = "binary", unit = "B")]
+    memory:   u64,
+    #[scaled(mag = -1, scaling = "none")]
+    r#type:   i32,
+    requests: u128,
+}
+
+
+#[derive(ScaledDisplay)]
+#[scaled(fmt = "{{{value}}} {value:_<12}|")]
+struct Braced<T>
+where
+    T: scaler::ToScaled,
+{
+    value: T,
+}
+
+
+#[test]
+fn lines_with_struct_defaults_and_field_overrides()
+{
+    let m: Metrics = Metrics { cpu: 0.25, memory: 3 << 30, r#type: 1234, requests: 123_456_789_012_345_678_901 };
+
+
+    assert_eq!(m.to_string(), "cpu: +250 m\nmemory: 3.0 GiB\ntype: +1,234.0\nrequests: +120 E"); // raw identifier displayed without "r#"
+}
+
+
+#[test]
+fn template_with_literal_braces_and_generics()
+{
+    assert_eq!(Braced { value: 42069_u32 }.to_string(), "{42,07 k} 42,07 k_____|");
+    assert_eq!(Braced { value: -1.5_f64 }.to_string(), "{-1,500} -1,500______|");
+}
+
+
+#[test]
+fn attribute_errors()
+{
+    let t: trybuild::TestCases = trybuild::TestCases::new();
+
+
+    t.compile_fail("tests/derive_fail/*.rs");
+}
@@ -0,0 +1,10 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#[derive(scaler::ScaledDisplay)]
+struct Metrics
+{
+    #[scaled(sig = 3, decimals = 2)]
+    cpu: f64,
+}
+
+
+fn main() {}
@@ -0,0 +1,6 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#[derive(scaler::ScaledDisplay)]
+struct Metrics(f64, u64);
+
+
+fn main() {}
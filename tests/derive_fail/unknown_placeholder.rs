@@ -0,0 +1,12 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#[derive(scaler::ScaledDisplay)]
+#[scaled(fmt = "{cpu} {memory}")]
+struct Metrics
+{
+    cpu:    f64,
+    #[scaled(skip)]
+    memory: u64,
+}
+
+
+fn main() {}
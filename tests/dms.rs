@@ -0,0 +1,72 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Axis, Formatter, Rounding, Sign};
+
+
+#[test]
+fn zero()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(-1));
+
+
+    assert_eq!(f.format_dms(0.0, None), "0° 00′ 00,0″");
+    assert_eq!(f.format_dms(-0.0, None), "0° 00′ 00,0″");
+    assert_eq!(f.format_dms(0.0, Some(Axis::Latitude)), "0° 00′ 00,0″ N");
+    assert_eq!(f.format_dms(-0.0, Some(Axis::Longitude)), "0° 00′ 00,0″ E"); // -0 is positive
+    assert_eq!(f.format_dms(-1e-9, Some(Axis::Longitude)), "0° 00′ 00,0″ W"); // rounded to 0, still west
+    assert_eq!(f.format_dms(-1e-9, None), "-0° 00′ 00,0″");
+    assert_eq!(f.clone().set_sign(Sign::ExceptZero).format_dms(-1e-9, None), "0° 00′ 00,0″");
+    assert_eq!(f.format_dms(f64::from_bits(1), None), "0° 00′ 00,0″"); // subnormal
+}
+
+
+#[test]
+fn negative_longitudes()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(-1));
+
+
+    assert_eq!(f.format_dms(-2.2945, Some(Axis::Longitude)), "2° 17′ 40,2″ W");
+    assert_eq!(f.format_dms(2.2945, Some(Axis::Longitude)), "2° 17′ 40,2″ E");
+    assert_eq!(f.format_dms(-122.4194, Some(Axis::Longitude)), "122° 25′ 09,8″ W");
+    assert_eq!(f.format_dms(-180.0, Some(Axis::Longitude)), "180° 00′ 00,0″ W");
+    assert_eq!(f.format_dms(-33.8688, Some(Axis::Latitude)), "33° 52′ 07,7″ S");
+    assert_eq!(f.format_dms(-122.4194, None), "-122° 25′ 09,8″");
+    assert_eq!(f.clone().set_sign(Sign::Always).format_dms(122.4194, None), "+122° 25′ 09,8″");
+}
+
+
+#[test]
+fn seconds_carry()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(-1));
+
+
+    assert_eq!(f.format_dms(10.0 + 59.0 / 60.0 + 59.99 / 3600.0, None), "11° 00′ 00,0″"); // cascades to degrees
+    assert_eq!(f.format_dms(10.0 + 30.0 / 60.0 + 59.99 / 3600.0, None), "10° 31′ 00,0″"); // into minutes only
+    assert_eq!(f.format_dms(10.0 + 30.0 / 60.0 + 59.94 / 3600.0, None), "10° 30′ 59,9″"); // no carry
+    assert_eq!(f.format_dms(-(89.0 + 59.0 / 60.0 + 59.96 / 3600.0), Some(Axis::Latitude)), "90° 00′ 00,0″ S");
+    assert_eq!(f.clone().set_rounding(Rounding::Magnitude(0)).format_dms(359.9999, None), "360° 00′ 00″");
+    assert_eq!(f.clone().set_rounding(Rounding::Magnitude(-3)).format_dms(0.5 / 3600.0, None), "0° 00′ 00,500″");
+    assert_eq!(f.clone().set_rounding(Rounding::Magnitude(0)).format_dms(0.5 / 3600.0, None), "0° 00′ 00″"); // ties to even
+    assert_eq!(f.clone().set_rounding(Rounding::Magnitude(0)).format_dms(1.5 / 3600.0, None), "0° 00′ 02″");
+}
+
+
+#[test]
+fn options()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_dms(48.8584, None), "48° 51′ 30,24″"); // 4 significant digits of seconds
+    assert_eq!(f.clone().set_rounding(Rounding::SignificantDigits(1)).format_dms(48.8584, None), "48° 51′ 30″");
+    assert_eq!(f.clone().set_rounding(Rounding::Magnitude(2)).format_dms(48.8584, None), "48° 51′ 30″"); // whole seconds at least
+    assert_eq!(f.clone().set_trailing_zeros(false).set_rounding(Rounding::Magnitude(-3)).format_dms(48.5, None), "48° 30′ 00″");
+    assert_eq!(f.clone().set_separators(",", ".").format_dms(48.8584, None), "48° 51′ 30.24″");
+    assert_eq!(f.format_dms(123456.0, None), "123.456° 00′ 00,00″");
+    assert_eq!(f.format_dms(1e20, None), "100.000.000.000.000.000.000° 00′ 00,00″"); // whole degrees beyond 2^53
+    assert_eq!(f.clone().set_width(20, Align::Left, '_').format_dms(48.8584, Some(Axis::Latitude)), "48° 51′ 30,24″ N____");
+    assert_eq!(f.format_dms(f64::NEG_INFINITY, Some(Axis::Latitude)), "-∞");
+    assert_eq!(f.format_dms(f64::NAN, None), "NaN");
+}
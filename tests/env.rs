@@ -0,0 +1,129 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{EnvError, EnvErrorKind, Formatter, Rounding, Scaling, Sign};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+
+/// serializes tests changing the environment, which is shared by the whole process
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+
+/// # Summary
+/// Sets environment variables for the duration of a test and restores their previous values when dropped. Holds the environment lock meanwhile, so tests cannot observe each other's variables.
+struct ScopedEnv
+{
+    _lock:    MutexGuard<'static, ()>,                    // exclusive access to the environment
+    previous: Vec<(String, Option<std::ffi::OsString>)>, // variables set and their values before
+}
+
+
+impl ScopedEnv
+{
+    /// # Summary
+    /// Sets the variables, all others with the prefix are removed.
+    ///
+    /// # Arguments
+    /// - `prefix`: prefix of the variable names
+    /// - `variables`: variable names after the prefix and their values
+    ///
+    /// # Returns
+    /// - the guard restoring the environment when dropped
+    fn new(prefix: &str, variables: &[(&str, &str)]) -> Self
+    {
+        let lock: MutexGuard<'static, ()> = ENV_LOCK.lock().unwrap_or_else(PoisonError::into_inner); // other test may have panicked, environment is restored regardless
+        let mut previous: Vec<(String, Option<std::ffi::OsString>)> = Vec::new(); // values to restore
+
+
+        for name in ["DECIMAL_SEP", "GROUP_SEP", "PRECISION", "SCALING", "SIGN"]
+        {
+            let variable: String = format!("{prefix}_{name}"); // full variable name
+
+            previous.push((variable.clone(), std::env::var_os(&variable)));
+            match variables.iter().find(|(n, _value)| *n == name)
+            {
+                Some((_name, value)) => std::env::set_var(&variable, value),
+                None => std::env::remove_var(&variable),
+            }
+        }
+
+        return Self { _lock: lock, previous };
+    }
+}
+
+
+impl Drop for ScopedEnv
+{
+    fn drop(&mut self)
+    {
+        for (variable, value) in &self.previous
+        {
+            match value
+            {
+                Some(value) => std::env::set_var(variable, value),
+                None => std::env::remove_var(variable),
+            }
+        }
+    }
+}
+
+
+#[test]
+fn reads_variables()
+{
+    for (variables, expected) in [
+        (&[][..], Formatter::new()),
+        (&[("PRECISION", "sig:3")], Formatter::new().set_rounding(Rounding::SignificantDigits(3))),
+        (&[("PRECISION", "MAG:-2")], Formatter::new().set_rounding(Rounding::Magnitude(-2))),
+        (&[("SCALING", "bin+space")], Formatter::new().set_scaling(Scaling::Binary(true))),
+        (&[("SIGN", "always")], Formatter::new().set_sign(Sign::Always)),
+        (&[("GROUP_SEP", "")], Formatter::new().set_separators("", ",")),
+        (&[("GROUP_SEP", ","), ("DECIMAL_SEP", ".")], Formatter::new().set_separators(",", ".")),
+        (
+            &[("PRECISION", "mag:0"), ("SCALING", "none"), ("SIGN", "space_for_positive"), ("GROUP_SEP", "'")],
+            Formatter::new().set_rounding(Rounding::Magnitude(0)).set_scaling(Scaling::None).set_sign(Sign::SpaceForPositive).set_separators("'", ","),
+        ),
+    ]
+    {
+        let _env: ScopedEnv = ScopedEnv::new("TEST_READS", variables);
+
+        assert_eq!(Formatter::from_env("TEST_READS"), Ok(expected.clone()), "Variables {variables:?} do not configure the expected formatter.");
+        assert_eq!(Formatter::from_env_or_default("TEST_READS"), expected);
+    }
+}
+
+
+#[test]
+fn rejects_malformed_variables()
+{
+    for (variables, variable, separators) in [
+        (&[("PRECISION", "4")][..], "TEST_REJECTS_PRECISION", false),
+        (&[("SCALING", "metric")], "TEST_REJECTS_SCALING", false),
+        (&[("SIGN", "")], "TEST_REJECTS_SIGN", false),
+        (&[("GROUP_SEP", ",")], "TEST_REJECTS_GROUP_SEP", true), // same as default decimal separator
+        (&[("GROUP_SEP", "1")], "TEST_REJECTS_GROUP_SEP", true),
+        (&[("DECIMAL_SEP", "")], "TEST_REJECTS_DECIMAL_SEP", true),
+        (&[("GROUP_SEP", " "), ("DECIMAL_SEP", "0")], "TEST_REJECTS_DECIMAL_SEP", true),
+    ]
+    {
+        let _env: ScopedEnv = ScopedEnv::new("TEST_REJECTS", variables);
+        let error: EnvError = Formatter::from_env("TEST_REJECTS").unwrap_err(); // malformed variable
+
+        assert_eq!(error.variable, variable, "Variables {variables:?} are not rejected for the expected variable.");
+        assert_eq!(matches!(error.kind, EnvErrorKind::ProblematicSeparators(_)), separators, "Variables {variables:?} are not rejected for the expected reason.");
+        assert!(error.to_string().starts_with(&format!("Environment variable {variable}: ")), "Error message {error} does not name the variable.");
+        assert_eq!(Formatter::from_env_or_default("TEST_REJECTS"), Formatter::new());
+    }
+}
+
+
+#[test]
+fn unset_variables_are_restored()
+{
+    {
+        let _env: ScopedEnv = ScopedEnv::new("TEST_RESTORES", &[("SIGN", "always")]);
+        assert_eq!(Formatter::from_env("TEST_RESTORES"), Ok(Formatter::new().set_sign(Sign::Always)));
+    }
+    let _env: ScopedEnv = ScopedEnv::new("TEST_RESTORES", &[]); // holds lock while checking
+    assert_eq!(std::env::var_os("TEST_RESTORES_SIGN"), None);
+    assert_eq!(Formatter::from_env("TEST_RESTORES"), Ok(Formatter::new()));
+}
@@ -0,0 +1,38 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+
+
+#[test]
+fn expansion_equals_std()
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_separators("", ".");
+    let mut x: f64 = f64::from_bits(1); // smallest subnormal
+
+
+    while x.is_finite()
+    // every binary magnitude with neighbours, std prints the exact expansion with enough decimal places
+    {
+        for y in [x, f64::from_bits(x.to_bits() + 1), f64::from_bits(x.to_bits() + 0x0008_0000_0000_0001), -x]
+        {
+            let expected: String = format!("{y:.1074}").trim_end_matches('0').trim_end_matches('.').to_string();
+            assert_eq!(f.format_exact(y), expected, "{y:e}");
+        }
+        x *= 2.0;
+    }
+}
+
+
+#[test]
+fn cut_off()
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_exact_max_digits(3);
+
+
+    assert_eq!(f.format_exact(0.125), "0,125"); // exactly as long
+    assert_eq!(f.format_exact(0.0625), "0,062…");
+    assert_eq!(f.format_exact(1e-5), "0,000…");
+    assert_eq!(f.format_exact(1234.9375), "1.234,937…"); // not rounded up
+    assert_eq!(f.clone().set_unit("m").format_exact(0.0625), "0,062… m");
+    assert_eq!(f.clone().set_width(8, scaler::Align::Right, '_').format_exact(0.0625), "__0,062…"); // ellipsis counts
+    assert_eq!(f.format_exact(f64::MAX).chars().count(), 309 + 102); // integer, nothing to cut off
+}
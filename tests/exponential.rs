@@ -0,0 +1,161 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, PrefixTable, Rounding, Scaling};
+
+
+/// time units for base 60, [lower bound magnitude; upper bound magnitude[, unit prefix
+static TIME_UNITS: PrefixTable = PrefixTable::new(&[(0, 1, "s"), (1, 2, "min"), (2, 3, "h")], true);
+
+
+#[test]
+fn base_16_step_1()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 16, step: 1, prefixes: None });
+
+
+    assert_eq!(f.format(0), "0,000");
+    assert_eq!(f.format(1), "1,000 * 16^(0)");
+    assert_eq!(f.format(15), "15,00 * 16^(0)");
+    assert_eq!(f.format(16), "1,000 * 16^(1)");
+    assert_eq!(f.format(255), "15,94 * 16^(1)"); // 15,9375
+    assert_eq!(f.format(4096), "1,000 * 16^(3)"); // exact power, not 16,00 * 16^(2)
+    assert_eq!(f.format(16384), "4,000 * 16^(3)");
+    assert_eq!(f.format(65535), "1,000 * 16^(4)"); // 15,9998 rounded up to the next power
+    assert_eq!(f.format(-1e6), "-15,26 * 16^(4)"); // 15,2587890625
+    assert_eq!(f.format(0.5), "8,000 * 16^(-1)");
+    assert_eq!(f.format(1.0 / 3.0), "5,333 * 16^(-1)");
+    assert_eq!(f.format(f64::from_bits(1)), "4,000 * 16^(-269)"); // 2^(-1074), power below f64 range
+    assert_eq!(f.format(f64::MAX), "1,000 * 16^(256)"); // 2^(1024) rounded, power beyond f64 range
+    assert_eq!(f.format(f64::INFINITY), "∞");
+}
+
+
+#[test]
+fn base_60_step_1()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 60, step: 1, prefixes: None });
+
+
+    assert_eq!(f.format(59), "59,00 * 60^(0)");
+    assert_eq!(f.format(60), "1,000 * 60^(1)");
+    assert_eq!(f.format(90), "1,500 * 60^(1)");
+    assert_eq!(f.format(3600), "1,000 * 60^(2)");
+    assert_eq!(f.format(5400), "1,500 * 60^(2)");
+    assert_eq!(f.format(86400), "24,00 * 60^(2)");
+    assert_eq!(f.format(216000), "1,000 * 60^(3)");
+    assert_eq!(f.format(0.5), "30,00 * 60^(-1)");
+    assert_eq!(f.format(-0.25), "-15,00 * 60^(-1)");
+}
+
+
+#[test]
+fn step_groups_exponents()
+{
+    let engineering: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 10, step: 3, prefixes: None });
+    let octal: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 8, step: 2, prefixes: None });
+
+
+    assert_eq!(engineering.format(12345), "12,35 * 10^(3)");
+    assert_eq!(engineering.format(0.0012), "1,200 * 10^(-3)");
+    assert_eq!(octal.format(512), "8,000 * 8^(2)"); // 8^(3) is not a multiple of the step
+    assert_eq!(octal.format(4096), "1,000 * 8^(4)");
+    assert_eq!(octal.format(0.125), "8,000 * 8^(-2)");
+}
+
+
+#[test]
+fn magnitude_rounding_applies_before_scaling()
+{
+    let f: Formatter = Formatter::new()
+        .set_rounding(Rounding::Magnitude(0))
+        .set_scaling(Scaling::Exponential { base: 16, step: 1, prefixes: None });
+
+
+    assert_eq!(f.format(100.4), "6,25 * 16^(1)"); // 100 in units of 1
+    assert_eq!(f.format(16384), "4,0000 * 16^(3)"); // 1 is 0,0002 * 16^(3)
+}
+
+
+#[test]
+fn prefix_table_like_prefixed_modes()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 60, step: 1, prefixes: Some(TIME_UNITS) });
+    let tight: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 60, step: 1, prefixes: Some(PrefixTable::new(&[(0, 1, "s"), (1, 2, "min"), (2, 3, "h")], false)) });
+
+
+    assert_eq!(f.format(45), "45,00 s");
+    assert_eq!(f.format(90), "1,500 min");
+    assert_eq!(f.format(3599.9), "1,000 h"); // rounded up into the next unit
+    assert_eq!(f.format(5400), "1,500 h");
+    assert_eq!(f.format(777600), "3,600 * 60^(3)"); // beyond the table
+    assert_eq!(f.format(0.5), "30,00 * 60^(-1)");
+    assert_eq!(tight.format(90), "1,500min");
+    assert_eq!(f.format_scaled(5400).prefix, "h");
+    assert_eq!(f.format_scaled(5400).factor, 3600.0);
+}
+
+
+#[test]
+fn max_len_holds()
+{
+    let formatters: [Formatter; 3] = [
+        Formatter::new().set_scaling(Scaling::Exponential { base: 16, step: 1, prefixes: None }),
+        Formatter::new().set_scaling(Scaling::Exponential { base: 60, step: 1, prefixes: Some(TIME_UNITS) }).set_rounding(Rounding::Magnitude(-3)),
+        Formatter::new().set_scaling(Scaling::Exponential { base: 3, step: 5, prefixes: None }).set_rounding(Rounding::Magnitude(2)),
+    ];
+
+
+    for f in formatters.iter()
+    {
+        let mut x: f64 = 4.9e-324;
+        while x.is_finite()
+        {
+            for y in [x, -x]
+            {
+                assert!(f.format(y).len() <= f.max_len(), "{} is longer than {}.", f.format(y), f.max_len());
+            }
+            x *= 13.7;
+        }
+    }
+}
+
+
+#[test]
+fn parses_back()
+{
+    let formatters: [Formatter; 4] = [
+        Formatter::new().set_scaling(Scaling::Exponential { base: 16, step: 1, prefixes: None }),
+        Formatter::new().set_scaling(Scaling::Exponential { base: 60, step: 1, prefixes: None }),
+        Formatter::new().set_scaling(Scaling::Exponential { base: 8, step: 2, prefixes: None }).set_rounding(Rounding::SignificantDigits(6)),
+        Formatter::new().set_scaling(Scaling::Exponential { base: 3, step: 5, prefixes: None }).set_separators(".", ","),
+    ];
+    let hex: &Formatter = &formatters[0];
+    let hex_units: Formatter = hex.clone().set_rounding(Rounding::Magnitude(0)); // "6,25 * 16^(1)" for 100
+    let sexagesimal: &Formatter = &formatters[1];
+
+
+    assert_eq!(hex.parse(&hex.format(1.0)), Ok(1.0));
+    assert_eq!(hex.parse("15,94 * 16^(1)"), Ok(255.04));
+    assert_eq!(hex.parse("4,000 * 16^(-269)"), Ok(f64::from_bits(1))); // applied exactly as 2^(-1076)
+    assert_eq!(hex.parse("1,000 * 10^(3)"), Ok(1000.0)); // bases 10 and 2 are still understood
+    assert_eq!(hex.parse_u64("1,5 * 16^(2)"), Ok(384));
+    assert_eq!(hex_units.parse(&hex_units.format(100.4)), Ok(100.0));
+    assert_eq!(sexagesimal.parse(&sexagesimal.format(5400)), Ok(5400.0));
+    assert_eq!(sexagesimal.parse(&sexagesimal.format(-0.25)), Ok(-0.25));
+    assert_eq!(sexagesimal.parse_u64("1,500 * 60^(2)"), Ok(5400));
+    assert!(sexagesimal.parse("1,000 * 16^(1)").is_err()); // only the configured base
+    for f in formatters.iter()
+    {
+        let mut x: f64 = 1.234e-300;
+        while x < 1e300
+        // formatting what is parsed displays the same again
+        {
+            for y in [x, -x]
+            {
+                let s: String = f.format(y);
+                assert_eq!(f.format(f.parse(&s).unwrap_or_else(|e| panic!("{s:?} does not parse: {e}"))), s);
+            }
+            x *= 7.3;
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Rounding, Scaling};
+
+
+#[test]
+fn decimal_fallback()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::SignificantDigits(4)).set_fallback_rounding(Some(Rounding::SignificantDigits(2)));
+
+
+    assert_eq!(f.format(1e36), "1,0 * 10^(36)");
+    assert_eq!(f.format(-1.26e-31), "-1,3 * 10^(-31)");
+    assert_eq!(f.format(999.9e30), "999,9 Q"); // unit prefix with the main rounding
+    assert_eq!(f.format(999.96e30), "1,0 * 10^(33)"); // main rounding carries beyond the unit prefixes
+    assert_eq!(f.clone().set_fallback_rounding(Some(Rounding::Magnitude(34))).format(1.234e36), "1,23 * 10^(36)"); // digit at 10^(34)
+    assert_eq!(f.clone().set_fallback_rounding(Some(Rounding::SignificantDigits(0))).format(-1e36), "0");
+}
+
+
+#[test]
+fn binary_fallback_and_scientific()
+{
+    let binary: Formatter = Formatter::new().set_scaling(Scaling::Binary(true)).set_fallback_rounding(Some(Rounding::SignificantDigits(2)));
+    let scientific: Formatter = Formatter::new().set_scaling(Scaling::Scientific).set_fallback_rounding(Some(Rounding::SignificantDigits(2)));
+
+
+    assert_eq!(binary.format(2_f64.powi(100) * 1.2345), "1,3 * 2^(100)"); // significant digits of the number, 1,6 * 10^(30), like the main rounding
+    assert_eq!(binary.format(1024), "1,000 Ki");
+    assert_eq!(scientific.format(42069), "4,2 * 10^(4)");
+    assert_eq!(scientific.format(0.00123456), "1,2 * 10^(-3)");
+    assert_eq!(Formatter::new().set_scaling(Scaling::None).set_fallback_rounding(Some(Rounding::SignificantDigits(2))).format(1e36), Formatter::new().set_scaling(Scaling::None).format(1e36)); // never falls back
+}
+
+
+#[test]
+fn exact_integers_like_floats()
+{
+    let f: Formatter = Formatter::new().set_fallback_rounding(Some(Rounding::SignificantDigits(2)));
+
+
+    assert_eq!(f.format_int(1_234_567_890_123_456_789_012_345_678_901_234_567_i128), "1,2 * 10^(36)");
+    assert_eq!(f.format_int(-1_250_000_000_000_000_000_000_000_000_000_000_001_i128), "-1,3 * 10^(36)"); // rounded once from all digits, not from 1,250 to even
+    assert_eq!(f.clone().set_scaling(Scaling::Binary(true)).format_int(u128::MAX), "2,0 * 2^(127)");
+    assert_eq!(f.format_int(123_456_789_i128), "123,5 M");
+}
+
+
+#[test]
+fn buffer_bound_covers_both_roundings()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::SignificantDigits(2)).set_fallback_rounding(Some(Rounding::Magnitude(-300)));
+    let mut x: f64 = 4.9e-324; // smallest subnormal
+
+
+    while x.is_finite()
+    {
+        assert!(f.format(x).len() <= f.max_len());
+        x *= 13.7;
+    }
+}
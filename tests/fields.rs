@@ -0,0 +1,72 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{FieldProcessor, FieldSelector, Formatter, ParseError, ParseErrorKind};
+use std::borrow::Cow;
+
+
+#[test]
+fn tab_separated_with_header()
+{
+    let input: &str = "Name\tSize\tBlocks\r\ndisk.img\t1048576\t2048\r\nnotes.txt\t\t8\r\nempty\t0\t0";
+    let processor: FieldProcessor = Formatter::new().set_unit("B").field_processor(FieldSelector::Index(2)).set_delimiter(Some('\t')).set_header(1);
+
+
+    assert_eq!(processor.process_lines(input), Ok("Name\tSize\tBlocks\r\ndisk.img\t1,049 MB\t2048\r\nnotes.txt\t\t8\r\nempty\t0,000 B\t0".to_owned())); // line breaks and missing final one kept, empty field kept
+    assert_eq!(
+        processor.clone().set_header(0).process_lines(input),
+        Ok("Name\tSize\tBlocks\r\ndisk.img\t1,049 MB\t2048\r\nnotes.txt\t\t8\r\nempty\t0,000 B\t0".to_owned())
+    ); // header not a number anyway
+    assert_eq!(processor.clone().set_header(3).process_lines(input), Ok(input.replace("\t0\t", "\t0,000 B\t")));
+    assert_eq!(processor.clone().set_header(5).process_lines(input), Ok(input.to_owned()));
+    assert_eq!(processor.process_lines(""), Ok(String::new()));
+}
+
+
+#[test]
+fn whitespace_runs_kept()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(scaler::process_lines("  a    1000\t\t 2000  \n", FieldSelector::Index(2), &f), "  a    1,000 k\t\t 2000  \n");
+    assert_eq!(scaler::process_lines("  a    1000\t\t 2000  \n", FieldSelector::Range(2..=usize::MAX), &f), "  a    1,000 k\t\t 2,000 k  \n");
+    assert_eq!(scaler::process_lines("\n   \n1000\n", FieldSelector::Index(1), &f), "\n   \n1,000 k\n"); // lines without fields
+    assert_eq!(scaler::process_lines("a 1000", FieldSelector::Index(3), &f), "a 1000"); // field beyond the line
+}
+
+
+#[test]
+fn delimited_fields()
+{
+    let processor: FieldProcessor = Formatter::new().field_processor(FieldSelector::Range(1..=2)).set_delimiter(Some(';'));
+
+
+    assert_eq!(processor.process_line(" 1000 ;2000;3000"), Ok(" 1,000 k ;2,000 k;3000".into())); // whitespace around the number kept
+    assert_eq!(processor.process_line(";;1000"), Ok(";;1000".into()));
+    assert_eq!(processor.clone().set_delimiter(Some('→')).process_line("1000→2000→3000"), Ok("1,000 k→2,000 k→3000".into())); // multi-byte delimiter
+    assert_eq!(processor.clone().set_delimiter(Some(',')).process_line("1000,2000"), Ok("1,000 k,2,000 k".into()));
+}
+
+
+#[test]
+fn numeric_selects_numbers_only()
+{
+    let processor: FieldProcessor = Formatter::new().field_processor(FieldSelector::Numeric).set_strict(true);
+
+
+    assert_eq!(processor.process_line("GET /index.html 200 5120 0,25 0.25s inf"), Ok("GET /index.html 200,0 5,120 k 250,0 m 0.25s inf".into())); // unit s unknown to the formatter
+    assert!(matches!(processor.process_line("GET /index.html -"), Ok(Cow::Borrowed(_))));
+}
+
+
+#[test]
+fn strict_errors()
+{
+    let processor: FieldProcessor = Formatter::new().field_processor(FieldSelector::Range(2..=3)).set_strict(true);
+
+
+    assert_eq!(processor.process_line("a 1000 2000"), Ok("a 1,000 k 2,000 k".into()));
+    assert_eq!(processor.process_line("a 1000 x"), Err(ParseError { field: Some(2), kind: ParseErrorKind::NoDigits, position: 7 }));
+    assert_eq!(processor.process_lines("a 1 2\nb 3 x\n"), Err(ParseError { field: Some(2), kind: ParseErrorKind::NoDigits, position: 10 }));
+    assert_eq!(processor.clone().set_strict(false).process_line("a 1000 x"), Ok("a 1,000 k x".into()));
+}
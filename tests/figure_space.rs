@@ -0,0 +1,97 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Fill, Formatter, Rounding, Scaling};
+
+
+/// # Summary
+/// Reduces a formatted number to the widths of its glyphs in a font with tabular figures: digits and figure spaces are equally wide, as are separators and punctuation spaces.
+///
+/// # Arguments
+/// - `s`: the formatted number
+/// - `separators`: the group and decimal separator
+///
+/// # Returns
+/// - "0" for every character of digit width, "." for every one of separator width, everything else unchanged
+fn classes(s: &str, separators: (&str, &str)) -> String
+{
+    return s
+        .chars()
+        .map(|c| match c
+        {
+            '0'..='9' | '\u{2007}' => '0',
+            '\u{2008}' => '.',
+            c if separators.0.contains(c) || separators.1.contains(c) => '.',
+            c => c,
+        })
+        .collect();
+}
+
+
+#[test]
+fn columns_align_glyph_for_glyph()
+{
+    let separators: [(&str, &str); 4] = [(".", ","), (",", "."), ("'", "."), ("\u{202F}", ",")]; // group and decimal separator
+    let values: [f64; 7] = [1234567.891, 7.0, 7.5, 42.25, 1000.0, 0.25, 999999.99];
+
+
+    for (group_separator, decimal_separator) in separators
+    {
+        let f: Formatter = Formatter::new()
+            .set_fill_mode(Fill::FigureSpace)
+            .set_rounding(Rounding::Magnitude(-2))
+            .set_scaling(Scaling::None)
+            .set_separators(group_separator, decimal_separator)
+            .set_trailing_zeros(false);
+        let rows: Vec<String> = f.format_aligned(&values); // aligned column
+        let pattern: String = classes(&rows[0], (group_separator, decimal_separator)); // glyph widths of the longest number
+
+
+        assert_eq!(pattern, "0.000.000.00", "{group_separator:?} {decimal_separator:?}");
+        for (row, x) in rows.iter().zip(values)
+        {
+            assert_eq!(classes(row, (group_separator, decimal_separator)), pattern, "{x} as {row:?}");
+            assert_eq!(row.replace(['\u{2007}', '\u{2008}'], ""), f.format(x), "{x} as {row:?}");
+        }
+    }
+}
+
+
+#[test]
+fn rows_without_separator()
+{
+    let f: Formatter = Formatter::new().set_fill_mode(Fill::FigureSpace).set_rounding(Rounding::Magnitude(0)).set_scaling(Scaling::None);
+
+
+    assert_eq!(f.format_aligned(&[5.0, 12.0, 999.0]), vec!["\u{2007}\u{2007}5", "\u{2007}12", "999"]); // no row has a separator, only figure spaces
+    assert_eq!(f.format_aligned(&[5.0, 1000.0]), vec!["\u{2007}\u{2008}\u{2007}\u{2007}5", "1.000"]);
+    assert_eq!(f.format_aligned(&[f64::NAN, 1000.0]), vec!["\u{2007}\u{2008}NaN", "1.000"]); // special strings are aligned at their end
+}
+
+
+#[test]
+fn character_fill_unchanged()
+{
+    let values: [f64; 3] = [7.0, -1234.0, 100.0];
+    let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(0)).set_scaling(Scaling::None);
+
+
+    assert_eq!(f.clone().set_fill_mode(Fill::Character).format_aligned(&values), f.format_aligned(&values));
+    assert_eq!(f.format_aligned(&values), vec!["     7", "-1.234", "   100"]);
+}
+
+
+#[test]
+fn width()
+{
+    let f: Formatter = Formatter::new().set_fill_mode(Fill::FigureSpace).set_width(8, Align::Right, '_');
+    let mut buf: [u8; 128] = [0; 128]; // buffer as large as max_len needs
+
+
+    assert_eq!(f.format(1234), "\u{2007}1,234 k");
+    assert_eq!(f.format(1234).chars().count(), 8);
+    assert_eq!(f.clone().set_width(8, Align::Left, '_').format(0.5), "500,0 m\u{2007}");
+    assert_eq!(f.clone().set_fill_mode(Fill::Character).format(1234), "_1,234 k");
+    assert_eq!(format!("{}", f.display(1234)), "\u{2007}1,234 k");
+    assert_eq!(f.format_to_slice(1234, &mut buf), Ok("\u{2007}1,234 k"));
+    assert!(f.format(1234).len() <= f.max_len());
+}
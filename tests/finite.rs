@@ -0,0 +1,113 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{FormatToSliceError, Formatter, NonFiniteError, NonFinitePolicy, WriteScaled};
+use std::fmt::Write;
+
+
+/// # Summary
+/// Formats with an output method returning a String and catches the panic of a rejected number.
+///
+/// # Arguments
+/// - `format`: formats the number
+///
+/// # Returns
+/// - formatted number
+/// - or the panic message
+fn caught<F>(format: F) -> Result<String, String>
+where
+    F: FnOnce() -> String,
+{
+    return std::panic::catch_unwind(std::panic::AssertUnwindSafe(format)).map_err(|payload| payload.downcast_ref::<String>().cloned().unwrap_or_default());
+}
+
+
+#[test]
+fn try_format_finite_rejects()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.try_format_finite(1.5), Ok("1,500".to_string()));
+    assert_eq!(f.try_format_finite(-0.0), Ok("0,000".to_string()));
+    assert_eq!(f.try_format_finite(f64::MAX), Ok(f.format(f64::MAX)));
+    assert_eq!(f.try_format_finite(f64::NAN), Err(NonFiniteError::Nan));
+    assert_eq!(f.try_format_finite(f64::INFINITY), Err(NonFiniteError::PositiveInfinity));
+    assert_eq!(f.try_format_finite(f64::NEG_INFINITY), Err(NonFiniteError::NegativeInfinity));
+    assert_eq!(f.try_format_finite(f32::NAN), Err(NonFiniteError::Nan));
+    assert_eq!(f.try_format_finite(42_u32), Ok("42,00".to_string()));
+}
+
+
+#[test]
+fn rejected_before_other_processing()
+{
+    let f: Formatter = Formatter::new().set_clamp(Some(0.0), Some(1.0)).set_special_strings("inf", "nan");
+
+
+    assert_eq!(f.try_format_finite(f64::INFINITY), Err(NonFiniteError::PositiveInfinity)); // not clamped first
+    assert_eq!(f.clone().set_machine_readable(true).try_format_finite(f64::NAN), Err(NonFiniteError::Nan));
+    assert_eq!(f.format(f64::INFINITY), "1,000"); // formatted by default, clamped
+    assert_eq!(f.format(f64::NAN), "nan");
+}
+
+
+#[test]
+fn strict_policy()
+{
+    let f: Formatter = Formatter::new().set_non_finite_policy(NonFinitePolicy::Error).set_clamp(Some(0.0), Some(1.0));
+
+
+    assert_eq!(caught(|| f.format(0.5)), Ok("500,0 m".to_string()));
+    assert_eq!(caught(|| f.format(f64::NAN)), Err(NonFiniteError::Nan.to_string()));
+    assert_eq!(caught(|| f.format(f64::INFINITY)), Err(NonFiniteError::PositiveInfinity.to_string())); // not clamped first
+    assert_eq!(caught(|| f.format(f64::NEG_INFINITY)), Err(NonFiniteError::NegativeInfinity.to_string()));
+    assert_eq!(f.format_opt(None::<f64>), "—"); // missing numbers are no numbers
+    assert_eq!(f.max_width(), Formatter::new().set_clamp(Some(0.0), Some(1.0)).max_width()); // bounded as if formatted
+}
+
+
+#[test]
+fn strict_policy_string_methods_panic_with_reason()
+{
+    let f: Formatter = Formatter::new().set_non_finite_policy(NonFinitePolicy::Error).set_width(10, scaler::Align::Right, ' ');
+
+
+    assert_eq!(caught(|| f.format_opt(Some(f64::NAN))), Err(NonFiniteError::Nan.to_string()));
+    assert_eq!(caught(|| f.compile().format(f64::INFINITY)), Err(NonFiniteError::PositiveInfinity.to_string()));
+    assert_eq!(caught(|| f.format_joined(&[1.0, f64::NEG_INFINITY], "; ")), Err(NonFiniteError::NegativeInfinity.to_string()));
+    assert_eq!(caught(|| f.format_to_parts(f64::NAN).to_string()), Err(NonFiniteError::Nan.to_string()));
+}
+
+
+#[test]
+fn strict_policy_fallible_methods_return_error()
+{
+    let f: Formatter = Formatter::new().set_non_finite_policy(NonFinitePolicy::Error).set_width(10, scaler::Align::Right, ' ');
+    let mut buf: [u8; 64] = [0; 64];
+    let mut out: Vec<u8> = Vec::new();
+    let mut s: String = String::new();
+
+
+    assert_eq!(f.format_into(f64::NAN, &mut s), Err(std::fmt::Error));
+    assert_eq!(f.format_into(f64::NEG_INFINITY, &mut s), Err(std::fmt::Error));
+    assert_eq!(s, ""); // rejected before padding, nothing written
+    assert_eq!(f.compile().format_into(f64::INFINITY, &mut s), Err(std::fmt::Error));
+    assert_eq!(write!(s, "rate: {}", f.display(f64::NAN)), Err(std::fmt::Error));
+    assert_eq!(s, "rate: ");
+    s.clear();
+    assert_eq!(f.format_into(1.5, &mut s), Ok(()));
+    assert_eq!(s, "     1,500");
+    assert_eq!(f.format_to_slice(f64::NAN, &mut buf), Err(FormatToSliceError::NonFinite(NonFiniteError::Nan)));
+    assert_eq!(f.format_to_slice(1.5, &mut buf), Ok("     1,500"));
+    assert_eq!(out.write_scaled(&f, f64::INFINITY).map_err(|e| e.kind()), Err(std::io::ErrorKind::InvalidInput));
+    assert!(out.is_empty());
+    assert_eq!(f.unpadded_len(f64::NAN), 3); // measured as if formatted
+}
+
+
+#[test]
+fn messages_are_helpful()
+{
+    assert_eq!(NonFiniteError::Nan.to_string(), "Number is not a number, but only finite numbers are formatted.");
+    assert_eq!(NonFiniteError::NegativeInfinity.to_string(), "Number is negative infinity, but only finite numbers are formatted.");
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Formatter, FractionOptions, Sign, SignPlacement};
+
+
+#[test]
+fn midpoints_between_sixteenths()
+{
+    let f: Formatter = Formatter::new();
+
+
+    for k in 0..32_u32
+    // every midpoint between adjacent sixteenths in [0; 2]
+    {
+        let midpoint: f64 = (2 * k + 1) as f64 / 32.0; // exact in f64
+        let (below, above): (u32, u32) = (k, k + 1); // neighbouring sixteenths
+        let even: u32 = if k % 2 == 0 { below } else { above }; // ties to even numerator
+
+        assert_eq!(f.format_fraction(midpoint, 16), f.format_fraction(even as f64 / 16.0, 16), "{midpoint}");
+        assert_eq!(f.format_fraction(f64::from_bits(midpoint.to_bits() - 1), 16), f.format_fraction(below as f64 / 16.0, 16), "just below {midpoint}");
+        assert_eq!(f.format_fraction(f64::from_bits(midpoint.to_bits() + 1), 16), f.format_fraction(above as f64 / 16.0, 16), "just above {midpoint}");
+        assert_eq!(f.format_fraction(-midpoint, 16), format!("-{}", f.format_fraction(midpoint, 16)), "-{midpoint}"); // even "-0"
+    }
+    assert_eq!(f.format_fraction(1.0 / 32.0, 16), "0");
+    assert_eq!(f.format_fraction(3.0 / 32.0, 16), "1/8"); // 2/16
+    assert_eq!(f.format_fraction(5.0 / 32.0, 16), "1/8");
+    assert_eq!(f.format_fraction(0.15625000000000003, 16), "3/16");
+    assert_eq!(f.format_fraction(31.0 / 32.0, 16), "1");
+    assert_eq!(f.format_fraction(33.0 / 32.0, 16), "1");
+    assert_eq!(f.format_fraction(35.0 / 32.0, 16), "1 1/8");
+}
+
+
+#[test]
+fn reduced_and_rounded()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_fraction(0.744, 16), "3/4");
+    assert_eq!(f.format_fraction(2.37, 8), "2 3/8");
+    assert_eq!(f.format_fraction(1.0 / 3.0, 3), "1/3");
+    assert_eq!(f.format_fraction(1.0 / 3.0, 16), "5/16"); // nearest sixteenth, not nearest fraction of smaller denominator
+    assert_eq!(f.format_fraction(0.1, 10), "1/10"); // not exactly 1/10, but nearest
+    assert_eq!(f.format_fraction(2.6, 1), "3");
+    assert_eq!(f.format_fraction(2.5, 0), "2"); // like 1, tie to even
+    assert_eq!(f.format_fraction(0.499999, 1_000_000), "499.999/1.000.000");
+    assert_eq!(f.format_fraction(0.5, u32::MAX - 1), "1/2");
+    assert_eq!(f.format_fraction(0.5, u32::MAX), "2.147.483.648/4.294.967.295"); // odd denominator, tie to even numerator
+    assert_eq!(f.format_fraction(1e-300, 16), "0");
+    assert_eq!(f.format_fraction(f64::from_bits(1), u32::MAX), "0");
+    assert_eq!(f.format_fraction(4503599627370495.5, 2), "4.503.599.627.370.495 1/2"); // largest f64 with a fraction
+    assert_eq!(f.format_fraction(1e20, 16), "100.000.000.000.000.000.000");
+    assert_eq!(f.format_fraction(f64::NAN, 16), "NaN");
+    assert_eq!(f.format_fraction(f64::NEG_INFINITY, 16), "-∞");
+}
+
+
+#[test]
+fn signs_and_options()
+{
+    let f: Formatter = Formatter::new().set_unit("cup");
+
+
+    assert_eq!(f.format_fraction(-0.75, 4), "-3/4 cup");
+    assert_eq!(f.format_fraction(-0.01, 4), "-0 cup"); // negative, rounded to 0
+    assert_eq!(f.format_fraction(-0.0, 4), "0 cup");
+    assert_eq!(f.clone().set_sign(Sign::Always).format_fraction(1.5, 4), "+1 1/2 cup");
+    assert_eq!(f.clone().set_sign_placement(SignPlacement::Trailing).format_fraction(-1.5, 4), "1 1/2 cup-");
+    assert_eq!(f.clone().set_affixes("(", ")").format_fraction(-0.5, 4), "-(1/2 cup)");
+    assert_eq!(f.clone().set_fraction(FractionOptions { glyphs: true, mixed: true }).format_fraction(-1.5, 4), "-1½ cup");
+    assert_eq!(f.clone().set_fraction(FractionOptions { glyphs: true, mixed: false }).format_fraction(-1.5, 4), "-3/2 cup");
+    assert_eq!(f.clone().set_fraction(FractionOptions { glyphs: true, mixed: false }).format_fraction(-0.5, 4), "-½ cup");
+    assert_eq!(f.clone().set_min_integer_digits(2).format_fraction(1.5, 4), "01 1/2 cup");
+    assert_eq!(f.clone().set_min_integer_digits(2).format_fraction(0.5, 4), "1/2 cup"); // numerator not padded
+    assert_eq!(f.clone().set_width(12, Align::Right, ' ').format_fraction(1.5, 4), "   1 1/2 cup");
+    assert_eq!(Formatter::new().set_fraction(FractionOptions { glyphs: false, mixed: false }).format_fraction(1234.5, 2), "2.469/2");
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+
+
+/// # Summary
+/// Formatter with English separators and a unit, one of the two the global formatter is swapped between.
+fn english() -> scaler::Formatter
+{
+    return european().set_separators(",", ".").set_unit("m");
+}
+
+
+/// # Summary
+/// Formatter with European separators, the other one the global formatter is swapped between.
+fn european() -> scaler::Formatter
+{
+    return scaler::Formatter::new().set_scaling(scaler::Scaling::None).set_rounding(scaler::Rounding::Magnitude(-2));
+}
+
+
+#[test]
+fn concurrent_reads_while_swapping()
+{
+    let expected: [String; 2] = [european().format(1234.5), english().format(1234.5)]; // any read must see one of the formatters whole
+
+
+    scaler::set_global_formatter(european()); // readers may start before the first swap
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            for i in 0..10_000
+            {
+                scaler::set_global_formatter(if i % 2 == 0 { english() } else { european() });
+            }
+        });
+        for _ in 0..4
+        {
+            scope.spawn(|| {
+                for _ in 0..10_000
+                {
+                    let s: String = scaler::format(1234.5);
+                    assert!(expected.contains(&s), "Read \"{s}\" from the global formatter, expected one of {expected:?}.");
+                    scaler::with_global(|f| assert!(expected.contains(&f.format(1234.5)), "Formatter passed to with_global is not one of the set ones."));
+                }
+            });
+        }
+    });
+
+    scaler::set_global_formatter(english()); // after all threads finished, the last one set applies
+    assert_eq!(scaler::format(1234.5), "1,234.50 m");
+    assert_eq!(scaler::with_global(|f| f.format(-0.5)), "-0.50 m");
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Rounding, Scaling};
+
+
+/// # Summary
+/// Numbers covering every magnitude, sign, and special value.
+fn numbers() -> Vec<f64>
+{
+    let mut numbers: Vec<f64> = vec![0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN, f64::MAX, f64::MIN_POSITIVE, 5e-324];
+    let mut x: f64 = 1e-30;
+
+
+    while x < 1e30
+    {
+        numbers.push(x);
+        numbers.push(-x);
+        x *= 13.7;
+    }
+
+    return numbers;
+}
+
+
+#[test]
+fn digit_free_special_strings()
+{
+    let f: Formatter = Formatter::new().set_special_strings("unendlich", "keine Zahl").set_none_string("n/a").set_affixes("EUR ", " net");
+
+
+    for x in numbers()
+    {
+        assert!(!f.format(x).is_empty(), "{x}");
+    }
+    assert_eq!(f.format(f64::INFINITY), "EUR unendlich net");
+    assert_eq!(f.format_opt(None::<f64>), "n/a");
+}
+
+
+#[test]
+fn empty_output()
+{
+    let f: Formatter = Formatter::new().set_special_strings("", "");
+
+
+    assert_eq!(f.format(f64::NAN), "");
+    assert_eq!(f.format(f64::INFINITY), "");
+    assert_eq!(f.set_none_string("").format_opt(None::<f64>), "");
+}
+
+
+#[test]
+fn separators_with_letters()
+{
+    for f in [
+        Formatter::new().set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(-2)).set_separators("k", "dot"),
+        Formatter::new().set_scaling(Scaling::Scientific).set_separators("e", "E"),
+        Formatter::new().set_scaling(Scaling::Binary(true)).set_separators("Ki", "i").set_zero_pad(20, true),
+    ]
+    {
+        for x in numbers()
+        {
+            let _ = f.format(x); // must not panic
+        }
+    }
+    assert_eq!(Formatter::new().set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(-2)).set_separators("k", "dot").format(1234567.891), "1k234k567dot89");
+}
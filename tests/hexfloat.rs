@@ -0,0 +1,62 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+
+
+/// # Summary
+/// Constants and what glibc prints for them with `%a`, `%A`, and `%.13a`.
+const LIBC: [(f64, &str, &str, &str); 21] = [
+    (0.1, "0x1.999999999999ap-4", "0X1.999999999999AP-4", "0x1.999999999999ap-4"),
+    (1.0, "0x1p+0", "0X1P+0", "0x1.0000000000000p+0"),
+    (-1.0, "-0x1p+0", "-0X1P+0", "-0x1.0000000000000p+0"),
+    (0.0, "0x0p+0", "0X0P+0", "0x0.0000000000000p+0"),
+    (-0.0, "-0x0p+0", "-0X0P+0", "-0x0.0000000000000p+0"),
+    (0.5, "0x1p-1", "0X1P-1", "0x1.0000000000000p-1"),
+    (2.0, "0x1p+1", "0X1P+1", "0x1.0000000000000p+1"),
+    (3.0, "0x1.8p+1", "0X1.8P+1", "0x1.8000000000000p+1"),
+    (1.5, "0x1.8p+0", "0X1.8P+0", "0x1.8000000000000p+0"),
+    (std::f64::consts::PI, "0x1.921fb54442d18p+1", "0X1.921FB54442D18P+1", "0x1.921fb54442d18p+1"),
+    (1e300, "0x1.7e43c8800759cp+996", "0X1.7E43C8800759CP+996", "0x1.7e43c8800759cp+996"),
+    (1e-300, "0x1.56e1fc2f8f359p-997", "0X1.56E1FC2F8F359P-997", "0x1.56e1fc2f8f359p-997"),
+    (f64::MAX, "0x1.fffffffffffffp+1023", "0X1.FFFFFFFFFFFFFP+1023", "0x1.fffffffffffffp+1023"),
+    (f64::MIN_POSITIVE, "0x1p-1022", "0X1P-1022", "0x1.0000000000000p-1022"),
+    (f64::EPSILON, "0x1p-52", "0X1P-52", "0x1.0000000000000p-52"),
+    (f64::from_bits(1), "0x0.0000000000001p-1022", "0X0.0000000000001P-1022", "0x0.0000000000001p-1022"),
+    (f64::from_bits(0x000f_ffff_ffff_ffff), "0x0.fffffffffffffp-1022", "0X0.FFFFFFFFFFFFFP-1022", "0x0.fffffffffffffp-1022"),
+    (1.0 / 3.0, "0x1.5555555555555p-2", "0X1.5555555555555P-2", "0x1.5555555555555p-2"),
+    (255.0, "0x1.fep+7", "0X1.FEP+7", "0x1.fe00000000000p+7"),
+    (-2.5, "-0x1.4p+1", "-0X1.4P+1", "-0x1.4000000000000p+1"),
+    (1e23, "0x1.52d02c7e14af6p+76", "0X1.52D02C7E14AF6P+76", "0x1.52d02c7e14af6p+76"),
+];
+
+
+#[test]
+fn equals_libc()
+{
+    let lower: scaler::Formatter = scaler::Formatter::new();
+    let upper: scaler::Formatter = scaler::Formatter::new().set_hexfloat(scaler::HexfloatOptions { uppercase: true, ..scaler::HexfloatOptions::new() });
+    let all_digits: scaler::Formatter = scaler::Formatter::new().set_hexfloat(scaler::HexfloatOptions { all_digits: true, ..scaler::HexfloatOptions::new() });
+
+
+    for (x, a, a_upper, a_all_digits) in LIBC
+    {
+        assert_eq!(lower.format_hexfloat(x), a, "{x:e}");
+        assert_eq!(upper.format_hexfloat(x), a_upper, "{x:e}");
+        assert_eq!(all_digits.format_hexfloat(x), a_all_digits, "{x:e}");
+    }
+}
+
+
+#[test]
+fn options_for_decimal_numbers_ignored()
+{
+    let f: scaler::Formatter = scaler::Formatter::new()
+        .set_separators("'", "·")
+        .set_affixes("[", "]")
+        .set_unit("m")
+        .set_sign(scaler::Sign::Always)
+        .set_rounding(scaler::Rounding::SignificantDigits(1));
+
+
+    assert_eq!(f.format_hexfloat(0.1), "0x1.999999999999ap-4"); // always ".", unrounded
+    assert_eq!(f.format_hexfloat(f64::INFINITY), "∞");
+}
@@ -0,0 +1,52 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::*;
+
+
+#[test]
+fn deep_magnitudes()
+{
+    let f: Formatter = Formatter::idle_game();
+
+
+    assert_eq!(f.format(1e18), "1,000 Qi");
+    assert_eq!(f.format(999.9e18), "999,9 Qi");
+    assert_eq!(f.format(999.99e18), "1,000 aa"); // rounded into the next suffix
+    assert_eq!(f.format(1e63), "1,000 ao");
+    assert_eq!(f.format(1e99), "1,000 ba");
+    assert_eq!(f.format(-2.5e150), "-2,500 br");
+    assert_eq!(f.format(1e300), "1,000 dp");
+    assert_eq!(f.format(f64::MAX), "179,8 dr");
+    assert_eq!(f.format(0.5), "500,0 * 10^(-3)"); // below 1 no suffix
+}
+
+
+#[test]
+fn table_is_generated_sequence()
+{
+    let f: Formatter = Formatter::idle_game();
+
+
+    for exponent in (0..=306).step_by(3)
+    {
+        let formatted: String = f.format(format!("1e{exponent}").parse::<f64>().unwrap());
+        assert_eq!(formatted.trim_start_matches("1,000").trim_start(), idle_game_suffix(exponent), "10^{exponent}");
+    }
+}
+
+
+#[test]
+fn suffix_round_trip()
+{
+    for exponent in (0..3_000_000).step_by(3).chain([u32::MAX / 3 * 3])
+    {
+        assert_eq!(idle_game_exponent(&idle_game_suffix(exponent)), Some(exponent), "{}", idle_game_suffix(exponent));
+    }
+    assert_eq!(idle_game_suffix(20), "Qi"); // floored to a multiple of 3
+    assert_eq!(idle_game_suffix(96), "az");
+    assert_eq!(idle_game_suffix(99), "ba");
+    assert_eq!(idle_game_suffix(2046), "zz");
+    assert_eq!(idle_game_exponent("zzzzzzzzzzzzzzzz"), None); // beyond u32
+    assert_eq!(idle_game_exponent("Ab"), None);
+    assert_eq!(idle_game_exponent("k"), None);
+}
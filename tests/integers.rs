@@ -0,0 +1,104 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+
+
+/// # Summary
+/// Whole numbers to format: every integer around 0, powers of 2 and 10 with their neighbours, ties, and pseudorandom integers up to 2^(53).
+///
+/// # Returns
+/// - the numbers
+fn integers() -> Vec<i64>
+{
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d; // xorshift state
+    let mut x: Vec<i64> = (-2100..=2100).collect(); // result
+
+
+    for exponent in 0..53
+    {
+        x.extend([(1 << exponent) - 1, 1 << exponent, (1 << exponent) + 1, -(1 << exponent)]);
+    }
+    for exponent in 0..16
+    {
+        let power: i64 = 10_i64.pow(exponent);
+        x.extend([power - 1, power, 15 * power, 25 * power, (42069 * power as i128 % (1 << 53)) as i64, -35 * power]);
+    }
+    for _ in 0..2000
+    {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        x.push((state % (1 << 53)) as i64 / 10_i64.pow((state >> 53) as u32 % 16) * if state & 1 == 0 { 1 } else { -1 });
+    }
+
+    return x;
+}
+
+
+#[test]
+fn whole_numbers_without_scaling_format_like_exact_integers()
+{
+    let mut roundings: Vec<scaler::Rounding> = (1..=20).map(scaler::Rounding::SignificantDigits).collect(); // rounding to 0 significant digits never takes the integer path
+
+
+    roundings.extend((-4..=18).map(scaler::Rounding::Magnitude));
+    for rounding in &roundings
+    {
+        for trailing_zeros in [false, true]
+        {
+            let f: scaler::Formatter = scaler::Formatter::new()
+                .set_rounding(rounding.clone())
+                .set_scaling(scaler::Scaling::None)
+                .set_sign(scaler::Sign::Always)
+                .set_trailing_zeros(trailing_zeros)
+                .set_zero_pad(12, true);
+
+            for x in integers()
+            {
+                assert_eq!(f.format(x as f64), f.format_int(x), "{x} formatted differently as f64 than in decimal digit arithmetic with {f:?}.");
+            }
+        }
+    }
+}
+
+
+#[test]
+fn whole_mantissas_format_like_float_mantissas()
+{
+    let cases: [(scaler::Scaling, [&str; 8], [&str; 8]); 4] = [
+        (
+            scaler::Scaling::Binary(true),
+            ["0,000", "1,000", "-7,000", "250,0", "2,000 Ki", "4,883 Ki", "-2,861 Mi", "39,12 Gi"],
+            ["0.000.000", "0.000.001", "-000.007", "0.000.250", "00.002 Ki", "4,8828 Ki", "-2,8610229 Mi", "39,1155481339 Gi"],
+        ),
+        (
+            scaler::Scaling::Decimal(false),
+            ["0,000", "1,000", "-7,000", "250,0", "2,048k", "5,000k", "-3,000M", "42,00G"],
+            ["0.000.000", "0.000.001", "-000.007", "0.000.250", "002,048k", "000.005k", "-000.003M", "000.042G"],
+        ),
+        (
+            scaler::Scaling::Decimal(true),
+            ["0,000", "1,000", "-7,000", "250,0", "2,048 k", "5,000 k", "-3,000 M", "42,00 G"],
+            ["0.000.000", "0.000.001", "-000.007", "0.000.250", "002,048 k", "000.005 k", "-00.003 M", "000.042 G"],
+        ),
+        (
+            scaler::Scaling::Scientific,
+            ["0,000", "1,000 * 10^(0)", "-7,000 * 10^(0)", "2,500 * 10^(2)", "2,048 * 10^(3)", "5,000 * 10^(3)", "-3,000 * 10^(6)", "4,200 * 10^(10)"],
+            ["0.000.000", "1 * 10^(0)", "-7 * 10^(0)", "2,5 * 10^(2)", "2,048 * 10^(3)", "5 * 10^(3)", "-3 * 10^(6)", "4,2 * 10^(10)"],
+        ),
+    ]; // expected as formatted from float mantissas, before whole mantissas were written by integer conversion
+    let x: [f64; 8] = [0.0, 1.0, -7.0, 250.0, 2048.0, 5000.0, -3_000_000.0, 42_000_000_000.0];
+
+
+    for (scaling, expected_default, expected_trimmed) in cases
+    {
+        let f_default: scaler::Formatter = scaler::Formatter::new().set_scaling(scaling.clone());
+        let f_trimmed: scaler::Formatter = scaler::Formatter::new()
+            .set_rounding(scaler::Rounding::Magnitude(-2))
+            .set_scaling(scaling)
+            .set_trailing_zeros(false)
+            .set_zero_pad(9, true);
+
+        assert_eq!(x.map(|x| f_default.format(x)), expected_default);
+        assert_eq!(x.map(|x| f_trimmed.format(x)), expected_trimmed);
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, PartKind, Rounding, Scaling};
+
+
+/// # Summary
+/// Returns numbers across the whole range of f64, with both signs and numbers that round across a boundary.
+///
+/// # Returns
+/// - the numbers
+fn values() -> Vec<f64>
+{
+    let mut values: Vec<f64> = vec![0.0, -0.0, 1.0, 999.95, 999.9999, 0.000999995, 1023.9, 1e15, 123456.789, f64::MAX, f64::MIN_POSITIVE, f64::INFINITY, f64::NAN];
+    let mut x: f64 = 4.9e-324; // smallest subnormal
+
+
+    while x.is_finite()
+    {
+        values.push(x);
+        x *= 13.7;
+    }
+    values.extend(values.clone().iter().map(|x| -x));
+    return values;
+}
+
+
+/// # Summary
+/// Determines decimal places and unit prefix from the displayed pieces.
+///
+/// # Arguments
+/// - `f`: the formatter
+/// - `x`: the number to format
+///
+/// # Returns
+/// - number of fraction digits and unit prefix, empty if none displayed
+fn displayed(f: &Formatter, x: f64) -> (u16, String)
+{
+    let mut decimals: u16 = 0;
+    let mut prefix: String = String::new();
+
+
+    for part in f.format_to_parts(x).iter()
+    {
+        match part.kind
+        {
+            PartKind::FractionDigits => decimals += part.value.chars().count() as u16,
+            PartKind::Prefix => prefix.push_str(&part.value),
+            _ => {}
+        }
+    }
+    return (decimals, prefix);
+}
+
+
+#[test]
+fn agrees_with_format()
+{
+    let roundings: [Rounding; 7] = [Rounding::Magnitude(-3), Rounding::Magnitude(0), Rounding::Magnitude(2), Rounding::SignificantDigits(0), Rounding::SignificantDigits(1), Rounding::SignificantDigits(4), Rounding::SignificantDigits(17)];
+    let scalings: [Scaling; 8] = [
+        Scaling::Binary(true),
+        Scaling::CompactLocalized,
+        Scaling::Decimal(true),
+        Scaling::Decimal(false),
+        Scaling::Exponential { base: 16, step: 1, prefixes: None },
+        Scaling::None,
+        Scaling::Percent(true),
+        Scaling::Scientific,
+    ];
+    let values: Vec<f64> = values();
+
+
+    for scaling in scalings.iter()
+    {
+        for rounding in roundings.iter()
+        {
+            let f: Formatter = Formatter::new().set_scaling(scaling.clone()).set_rounding(rounding.clone());
+
+            for x in values.iter().copied()
+            {
+                assert_eq!((f.decimals_for(x), f.prefix_for(x).unwrap_or("").to_owned()), displayed(&f, x), "{x:e} with {scaling:?} and {rounding:?}");
+            }
+        }
+    }
+}
+
+
+#[test]
+fn agrees_with_other_options()
+{
+    let formatters: [Formatter; 4] = [
+        Formatter::new().set_display_floor(Some(0.01)).set_display_ceiling(Some(999e12)),
+        Formatter::new().set_fallback_rounding(Some(Rounding::SignificantDigits(2))),
+        Formatter::new().set_machine_readable(true),
+        Formatter::new().set_zero_pad(12, true).set_width(20, scaler::Align::Left, '_'),
+    ];
+
+
+    for f in formatters.iter()
+    {
+        for x in values()
+        {
+            assert_eq!((f.decimals_for(x), f.prefix_for(x).unwrap_or("").to_owned()), displayed(f, x), "{x:e} with {f:?}");
+        }
+    }
+}
+
+
+#[test]
+fn trailing_zeros_still_count()
+{
+    let f: Formatter = Formatter::new().set_trailing_zeros(false);
+
+
+    assert_eq!(f.format(1000), "1 k");
+    assert_eq!(f.decimals_for(1000), 3);
+    assert_eq!(f.prefix_for(1000), Some("k"));
+    assert_eq!(f.prefix_for(f64::INFINITY), None);
+}
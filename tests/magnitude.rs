@@ -0,0 +1,59 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Rounding, Scaling};
+
+
+#[test]
+fn decimal_places_at_prefix_boundaries()
+{
+    let expected: [(i16, [&str; 9]); 7] = [
+        (-3, ["1 m", "1 m", "1 m", "999 m", "1,000", "1,000", "999,400", "999,600", "1,000400 k"]),
+        (-2, ["0,00", "0,00", "0,00", "1,00", "1,00", "1,00", "999,40", "999,60", "1,00040 k"]),
+        (-1, ["0,0", "0,0", "0,0", "1,0", "1,0", "1,0", "999,4", "999,6", "1,0004 k"]),
+        (0, ["0", "0", "0", "1", "1", "1", "999", "1,000 k", "1,000 k"]),
+        (1, ["0", "0", "0", "0", "0", "0", "1,00 k", "1,00 k", "1,00 k"]),
+        (2, ["0", "0", "0", "0", "0", "0", "1,0 k", "1,0 k", "1,0 k"]),
+        (3, ["0", "0", "0", "0", "0", "0", "1 k", "1 k", "1 k"]),
+    ]; // precision and formatted values
+    let values: [f64; 9] = [0.0009994, 0.0009996, 0.0010004, 0.9994, 0.9996, 1.0004, 999.4, 999.6, 1000.4]; // just below and above 1e-3, 1, and 1e3, some rounding up across
+
+
+    for (precision, formatted) in expected
+    {
+        let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(precision));
+
+        for (x, s) in values.iter().zip(formatted)
+        {
+            assert_eq!(f.format(*x), s, "{x} at 10^({precision})");
+        }
+    }
+}
+
+
+#[test]
+fn decimal_places_never_finer_than_precision()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(-2));
+
+
+    assert_eq!(f.format(0.789), "790 m"); // 10 m granularity, no decimals in milli
+    assert_eq!(f.format(0.7), "700 m");
+    assert_eq!(f.format(12.345), "12,35");
+    assert_eq!(f.format(12345.0), "12,34500 k");
+}
+
+
+#[test]
+fn decimal_places_in_scientific_notation()
+{
+    let scientific: Formatter = Formatter::new().set_scaling(Scaling::Scientific);
+
+
+    assert_eq!(scientific.clone().set_rounding(Rounding::Magnitude(-3)).format(1234.5678), "1,234568 * 10^(3)");
+    assert_eq!(scientific.clone().set_rounding(Rounding::Magnitude(-3)).format(0.012345), "1,2 * 10^(-2)");
+    assert_eq!(scientific.clone().set_rounding(Rounding::Magnitude(2)).format(1234.5678), "1,2 * 10^(3)");
+    assert_eq!(scientific.clone().set_rounding(Rounding::Magnitude(3)).format(1234.5678), "1 * 10^(3)");
+    assert_eq!(scientific.set_rounding(Rounding::Magnitude(3)).format_int(1234), "1 * 10^(3)"); // exact path
+    assert_eq!(Formatter::new().set_rounding(Rounding::Magnitude(35)).format(1.2345e37), "1,23 * 10^(37)"); // fallback beyond unit prefixes
+    assert_eq!(Formatter::new().set_rounding(Rounding::Magnitude(35)).format_int(12_345_u128 * 10_u128.pow(33)), "1,23 * 10^(37)");
+}
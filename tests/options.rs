@@ -0,0 +1,56 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Rounding, Scaling, Sign};
+
+
+#[test]
+fn rounding_round_trips()
+{
+    let roundings = (i16::MIN..=i16::MAX).map(Rounding::Magnitude).chain((u8::MIN..=u8::MAX).map(Rounding::SignificantDigits)); // every variant with every payload
+
+
+    for rounding in roundings
+    {
+        let s: String = rounding.to_string(); // string form
+
+        assert_eq!(s.parse::<Rounding>(), Ok(rounding.clone()), "Rounding {rounding:?} does not round trip through {s:?}.");
+        assert_eq!(s.to_uppercase().parse::<Rounding>(), Ok(rounding.clone()), "Rounding {rounding:?} does not round trip through uppercase {s:?}.");
+    }
+    assert!("magnitude:32768".parse::<Rounding>().is_err());
+    assert!("significant:-1".parse::<Rounding>().is_err());
+    assert!("significant".parse::<Rounding>().is_err());
+    assert!("precise:4".parse::<Rounding>().is_err());
+}
+
+
+#[test]
+fn scaling_round_trips()
+{
+    for scaling in [Scaling::Binary(false), Scaling::Binary(true), Scaling::CompactLocalized, Scaling::Decimal(false), Scaling::Decimal(true), Scaling::Exponential { base: 16, step: 1, prefixes: None }, Scaling::None, Scaling::Percent(false), Scaling::Percent(true), Scaling::Scientific]
+    {
+        let s: String = scaling.to_string(); // string form
+
+        assert_eq!(s.parse::<Scaling>(), Ok(scaling.clone()), "Scaling {scaling:?} does not round trip through {s:?}.");
+        assert_eq!(s.to_uppercase().parse::<Scaling>(), Ok(scaling.clone()), "Scaling {scaling:?} does not round trip through uppercase {s:?}.");
+    }
+    assert_eq!("bin+space".parse::<Scaling>(), Ok(Scaling::Binary(true)));
+    assert!("scientific+space".parse::<Scaling>().is_err());
+    assert!("+space".parse::<Scaling>().is_err());
+    assert_eq!("exp:8:2".parse::<Scaling>(), Ok(Scaling::Exponential { base: 8, step: 2, prefixes: None }));
+    assert!("exponential:16".parse::<Scaling>().is_err());
+    assert!("exponential:16:1+space".parse::<Scaling>().is_err());
+}
+
+
+#[test]
+fn sign_round_trips()
+{
+    for sign in [Sign::Always, Sign::ExceptZero, Sign::OnlyMinus, Sign::SpaceForPositive]
+    {
+        let s: String = sign.to_string(); // string form
+
+        assert_eq!(s.parse::<Sign>(), Ok(sign.clone()), "Sign {sign:?} does not round trip through {s:?}.");
+        assert_eq!(s.to_uppercase().parse::<Sign>(), Ok(sign.clone()), "Sign {sign:?} does not round trip through uppercase {s:?}.");
+    }
+    assert!("".parse::<Sign>().is_err());
+}
@@ -0,0 +1,85 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, PartKind, Rounding, Scaling, ShowOriginal, Sign, SignPlacement};
+
+
+#[test]
+fn integers()
+{
+    let grouped: Formatter = Formatter::new().set_show_original(ShowOriginal::Grouped, false);
+    let raw: Formatter = Formatter::new().set_show_original(ShowOriginal::Raw, false);
+
+
+    assert_eq!(grouped.format(42069), "42,07 k (42.069)");
+    assert_eq!(raw.format(42069), "42,07 k (42069)");
+    assert_eq!(grouped.format(1_234_567_u32), "1,235 M (1.234.567)");
+    assert_eq!(grouped.format(1_000_000_u32), "1,000 M"); // nothing rounded away
+    assert_eq!(grouped.format(0), "0,000");
+    assert_eq!(grouped.clone().set_show_original(ShowOriginal::Grouped, true).format(0), "0,000 (0)");
+    assert_eq!(grouped.clone().set_scaling(Scaling::None).format(42069), "42.070 (42.069)");
+    assert_eq!(grouped.clone().set_scaling(Scaling::Binary(true)).format(2048), "2,000 Ki");
+    assert_eq!(grouped.clone().set_scaling(Scaling::Binary(true)).format(2049), "2,001 Ki (2.049)");
+    assert_eq!(grouped.format_int(42069_u64), "42,07 k"); // exact path, no f64 original
+}
+
+
+#[test]
+fn rounded_floats()
+{
+    let f: Formatter = Formatter::new().set_show_original(ShowOriginal::Grouped, false);
+
+
+    assert_eq!(f.format(0.1234567), "123,5 m (0,1234567)");
+    assert_eq!(f.format(0.1), "100,0 m"); // shortest digits of 0.1, not its expansion
+    assert_eq!(f.format(1e21 + 1e6), "1,000 Z (1.000.000.000.000.001.000.000)");
+    assert_eq!(f.format(2.5e-7), "250,0 n");
+    assert_eq!(f.format(2.50001e-7), "250,0 n (0,000000250001)");
+    assert_eq!(f.clone().set_scaling(Scaling::Scientific).format(12345.6), "1,235 * 10^(4) (12.345,6)");
+    assert_eq!(f.clone().set_rounding(Rounding::Magnitude(-2)).format(1.23456), "1,23 (1,23456)");
+    assert_eq!(f.clone().set_rounding(Rounding::Magnitude(-2)).format(3.25), "3,25");
+    assert_eq!(f.clone().set_separators("'", ".").set_unit("V").format(1234.56), "1.235 kV (1'234.56)");
+    assert_eq!(f.clone().set_show_original(ShowOriginal::Raw, false).format(1e-7 / 3.0), "33,33 n (0.000000033333333333333334)");
+    assert_eq!(f.format(f64::INFINITY), "∞");
+    assert_eq!(f.format(f64::NAN), "NaN");
+    assert_eq!(f.clone().set_machine_readable(true).format(0.1234567), "0.1235"); // parsed by machines
+}
+
+
+#[test]
+fn negatives()
+{
+    let f: Formatter = Formatter::new()
+        .set_rounding(Rounding::SignificantDigits(2))
+        .set_show_original(ShowOriginal::Grouped, false);
+
+
+    assert_eq!(f.format(-1234), "-1,2 k (-1.234)");
+    assert_eq!(f.clone().set_show_original(ShowOriginal::Raw, false).format(-1234), "-1,2 k (-1234)");
+    assert_eq!(f.clone().set_sign(Sign::Always).format(-1234), "-1,2 k (-1.234)");
+    assert_eq!(f.clone().set_sign(Sign::Always).format(1234), "+1,2 k (1.234)"); // only the formatted number carries "+"
+    assert_eq!(f.clone().set_sign_placement(SignPlacement::Trailing).format(-1234), "1,2 k- (-1.234)");
+    assert_eq!(f.clone().set_affixes("(", ")").format(-1234), "-(1,2 k) (-1.234)");
+    assert_eq!(f.format(-0.001), "-1,0 m");
+    assert_eq!(f.format(-0.0), "0,0");
+    assert_eq!(f.clone().set_show_original(ShowOriginal::Grouped, true).format(-0.0), "0,0 (0)"); // unsigned like the formatted number
+    assert_eq!(f.clone().set_rounding(Rounding::SignificantDigits(0)).format(-1234), "0 (-1.234)");
+}
+
+
+#[test]
+fn padding_and_parts()
+{
+    let f: Formatter = Formatter::new().set_show_original(ShowOriginal::Grouped, false);
+    let mut buffer: [u8; 64] = [0; 64];
+
+
+    assert_eq!(f.clone().set_width(20, scaler::Align::Right, ' ').format(42069), "    42,07 k (42.069)"); // padded as a whole
+    assert_eq!(f.clone().set_zero_pad(8, false).format(42069), "042,07 k (42.069)"); // zero padding only counts the number
+    assert_eq!(f.compile().format(42069), "42,07 k (42.069)");
+    assert_eq!(f.format_to_parts(42069).0.last().map(|part| (part.kind, part.value.as_str())), Some((PartKind::Original, " (42.069)")));
+    assert_eq!(f.format_scaled(42069).prefix, "k");
+    assert_eq!(f.format_range(1234, 5678), "1,234\u{2009}–\u{2009}5,678 k");
+    assert_eq!(f.try_format(42069_u64), Ok("42,07 k".to_string()));
+    assert!(f.max_len() >= f.format(-f64::MAX).len() && f.max_len() >= f.format(-f64::from_bits(1)).len() && f.max_len() >= f.format(-2.2250738585072014e-308).len());
+    assert!(f.format_to_slice(-1.2345678901234567e-300, &mut buffer).is_err()); // original longer than the buffer
+}
@@ -0,0 +1,200 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Formatter, FormatterOverrides, Overrides, Rounding, Scaling, Sign, SignPlacement, SignPosition};
+
+
+#[test]
+fn every_override_matches_its_setter()
+{
+    let base: Formatter = Formatter::new().set_separators(",", ".").set_unit("B").set_affixes("[", "]"); // texts set at runtime
+    let cases: Vec<(Overrides, Formatter)> = vec![
+        (Overrides { affixes: Some(("$", "")), ..Overrides::new() }, base.clone().set_affixes("$", "")),
+        (Overrides { machine_readable: Some(true), ..Overrides::new() }, base.clone().set_machine_readable(true)),
+        (Overrides { min_integer_digits: Some(3), ..Overrides::new() }, base.clone().set_min_integer_digits(3)),
+        (Overrides { rounding: Some(Rounding::Magnitude(-1)), ..Overrides::new() }, base.clone().set_rounding(Rounding::Magnitude(-1))),
+        (Overrides { scaling: Some(Scaling::Binary(true)), ..Overrides::new() }, base.clone().set_scaling(Scaling::Binary(true))),
+        (Overrides { separators: Some(("\u{202F}", ",")), ..Overrides::new() }, base.clone().set_separators("\u{202F}", ",")),
+        (Overrides { sign: Some(Sign::Always), ..Overrides::new() }, base.clone().set_sign(Sign::Always)),
+        (Overrides { sign_placement: Some(SignPlacement::Trailing), ..Overrides::new() }, base.clone().set_sign_placement(SignPlacement::Trailing)),
+        (Overrides { sign_position: Some(SignPosition::AfterAffix), ..Overrides::new() }, base.clone().set_sign_position(SignPosition::AfterAffix)),
+        (Overrides { special_strings: Some(("inf", "nan")), ..Overrides::new() }, base.clone().set_special_strings("inf", "nan")),
+        (Overrides { trailing_zeros: Some(false), ..Overrides::new() }, base.clone().set_trailing_zeros(false)),
+        (Overrides { unit: Some(("byte", "bytes")), ..Overrides::new() }, base.clone().set_unit_plural("byte", "bytes")),
+        (Overrides { unit: Some(("m", "")), ..Overrides::new() }, base.clone().set_unit("m")),
+        (Overrides { width: Some((14, Align::Center, '*')), ..Overrides::new() }, base.clone().set_width(14, Align::Center, '*')),
+        (Overrides { zero_exponent: Some(true), ..Overrides::new() }, base.clone().set_zero_exponent(true).set_scaling(Scaling::Scientific)),
+        (Overrides { zero_pad: Some((12, true)), ..Overrides::new() }, base.clone().set_zero_pad(12, true)),
+        (
+            Overrides {
+                rounding: Some(Rounding::SignificantDigits(6)),
+                scaling: Some(Scaling::None),
+                separators: Some(("", ".")),
+                sign: Some(Sign::Always),
+                ..Overrides::new()
+            },
+            base.clone().set_rounding(Rounding::SignificantDigits(6)).set_scaling(Scaling::None).set_separators("", ".").set_sign(Sign::Always),
+        ),
+    ]; // overrides and the formatter with the same options set
+
+
+    for (overrides, expected) in cases
+    {
+        let base: Formatter = if overrides.zero_exponent.is_some() { base.clone().set_scaling(Scaling::Scientific) } else { base.clone() }; // zero exponent only shows in scientific notation
+        for x in [0.0, -0.0, 1.0, -0.125, 1536.0, 1234567.891, -9.9999e-7, 1e300, f64::INFINITY, f64::NAN]
+        {
+            assert_eq!(base.format_overridden(x, &overrides), expected.format(x), "{overrides:?} for {x}");
+        }
+    }
+}
+
+
+#[test]
+fn no_overrides_change_nothing()
+{
+    let f: Formatter = Formatter::new().set_separators(",", ".").set_rounding(Rounding::Magnitude(-2)).set_unit("€");
+
+
+    for x in [0.0, -1.0, 1234.5678, 1e-9, f64::NEG_INFINITY]
+    {
+        assert_eq!(f.format_overridden(x, &Overrides::new()), f.format(x));
+        assert_eq!(f.format_overridden(x, &Overrides::default()), f.format(x));
+    }
+}
+
+
+#[test]
+fn single_option_shortcuts_match_setters()
+{
+    let f: Formatter = Formatter::new().set_separators(",", ".").set_unit("B");
+
+
+    for x in [0.0, -0.125, 1536.0, 1234567.891, f64::NAN]
+    {
+        for rounding in [Rounding::Magnitude(0), Rounding::Magnitude(-3), Rounding::SignificantDigits(1), Rounding::SignificantDigits(10)]
+        {
+            assert_eq!(f.format_with_rounding(x, rounding.clone()), f.clone().set_rounding(rounding.clone()).format(x));
+            assert_eq!(f.format_with_rounding(x, rounding.clone()), f.format_overridden(x, &Overrides { rounding: Some(rounding), ..Overrides::new() }));
+        }
+        for scaling in [Scaling::Binary(false), Scaling::Decimal(true), Scaling::None, Scaling::Scientific]
+        {
+            assert_eq!(f.format_with_scaling(x, scaling.clone()), f.clone().set_scaling(scaling.clone()).format(x));
+            assert_eq!(f.format_with_scaling(x, scaling.clone()), f.format_overridden(x, &Overrides { scaling: Some(scaling), ..Overrides::new() }));
+        }
+    }
+    assert_eq!(f.format(1536), "1.536 kB"); // unchanged
+}
+
+
+#[test]
+fn layers_take_precedence_option_by_option()
+{
+    let defaults: FormatterOverrides = FormatterOverrides {
+        decimal_separator: Some(".".to_string()),
+        group_separator: Some(",".to_string()),
+        rounding: Some(Rounding::SignificantDigits(3)),
+        scaling: Some(Scaling::Decimal(true)),
+        sign: Some(Sign::OnlyMinus),
+        unit: Some("byte".to_string()),
+        unit_plural: Some("bytes".to_string()),
+        width: Some(12),
+        ..Default::default()
+    }; // built-in
+    let settings: FormatterOverrides = FormatterOverrides {
+        rounding: Some(Rounding::Magnitude(-2)),
+        scaling: Some(Scaling::Binary(true)),
+        trailing_zeros: Some(false),
+        unit: Some("B".to_string()),
+        ..Default::default()
+    }; // user settings file
+    let widget: FormatterOverrides = FormatterOverrides {
+        rounding: Some(Rounding::Magnitude(0)),
+        sign: Some(Sign::Always),
+        width: Some(0),
+        ..Default::default()
+    }; // per widget
+    let merged: FormatterOverrides = defaults.clone().merge(settings.clone()).merge(widget.clone());
+
+
+    assert_eq!(merged.decimal_separator.as_deref(), Some(".")); // only in defaults
+    assert_eq!(merged.group_separator.as_deref(), Some(","));
+    assert_eq!(merged.rounding, Some(Rounding::Magnitude(0))); // in all, widget wins
+    assert_eq!(merged.scaling, Some(Scaling::Binary(true))); // settings beat defaults
+    assert_eq!(merged.sign, Some(Sign::Always)); // widget beats defaults
+    assert_eq!(merged.trailing_zeros, Some(false)); // only in settings
+    assert_eq!(merged.unit.as_deref(), Some("B"));
+    assert_eq!(merged.unit_plural, None); // belongs to the overridden unit
+    assert_eq!(merged.width, Some(0)); // widget beats defaults even with the default value
+    assert_eq!(merged.affix_prefix, None); // in none
+    assert_eq!(defaults.clone().merge(FormatterOverrides::default()), defaults); // nothing to layer
+    assert_eq!(FormatterOverrides::default().merge(defaults.clone()), defaults);
+    assert_eq!(defaults.clone().merge(settings.clone().merge(widget.clone())), merged); // associative
+
+    assert_eq!(
+        Formatter::new().apply(&merged),
+        Formatter::new()
+            .set_separators(",", ".")
+            .set_rounding(Rounding::Magnitude(0))
+            .set_scaling(Scaling::Binary(true))
+            .set_sign(Sign::Always)
+            .set_trailing_zeros(false)
+            .set_unit("B")
+    );
+    assert_eq!(Formatter::new().apply(&merged).format(1536), "+1.5 KiB"); // rounded before scaling
+    assert_eq!(Formatter::new().apply(&defaults).apply(&settings).apply(&widget), Formatter::new().apply(&merged)); // applying one after the other is the same
+}
+
+
+#[test]
+fn apply_matches_setters()
+{
+    let base: Formatter = Formatter::new().set_unit_plural("request", "requests").set_affixes("[", "]");
+    let overrides: FormatterOverrides = FormatterOverrides {
+        affix_prefix: Some("$".to_string()),
+        affix_suffix: Some(String::new()),
+        align: Some(Align::Left),
+        decimal_separator: Some(".".to_string()),
+        fill: Some('_'),
+        group_separator: Some("\u{202F}".to_string()),
+        infinity: Some("inf".to_string()),
+        machine_readable: Some(false),
+        min_integer_digits: Some(2),
+        nan: Some("nan".to_string()),
+        none_string: Some("n/a".to_string()),
+        rounding: Some(Rounding::SignificantDigits(6)),
+        scaling: Some(Scaling::None),
+        sign: Some(Sign::Always),
+        sign_placement: Some(SignPlacement::Trailing),
+        sign_position: Some(SignPosition::AfterAffix),
+        trailing_zeros: Some(false),
+        unit: Some("m".to_string()),
+        unit_plural: None,
+        width: Some(20),
+        zero_exponent: Some(true),
+        zero_pad: Some(4),
+        zero_pad_grouped: Some(true),
+    };
+
+
+    assert_eq!(
+        base.apply(&overrides),
+        base.clone()
+            .set_affixes("$", "")
+            .set_width(20, Align::Left, '_')
+            .set_separators("\u{202F}", ".")
+            .set_special_strings("inf", "nan")
+            .set_machine_readable(false)
+            .set_min_integer_digits(2)
+            .set_none_string("n/a")
+            .set_rounding(Rounding::SignificantDigits(6))
+            .set_scaling(Scaling::None)
+            .set_sign(Sign::Always)
+            .set_sign_placement(SignPlacement::Trailing)
+            .set_sign_position(SignPosition::AfterAffix)
+            .set_trailing_zeros(false)
+            .set_unit("m")
+            .set_zero_exponent(true)
+            .set_zero_pad(4, true)
+    );
+    assert_eq!(base.apply(&FormatterOverrides { unit_plural: Some("reqs".to_string()), ..Default::default() }), base.clone().set_unit_plural("request", "reqs")); // unit kept
+    assert_eq!(base.apply(&FormatterOverrides { decimal_separator: Some(".".to_string()), ..Default::default() }), base.clone().set_separators(".", ".")); // other separator kept
+}
@@ -0,0 +1,53 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![cfg(feature = "rayon")]
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+
+
+/// # Summary
+/// Generates random finite numbers of all magnitudes, reproducibly.
+///
+/// # Arguments
+/// - `n`: number of numbers to generate
+///
+/// # Returns
+/// - the numbers
+fn random_values(n: usize) -> Vec<f64>
+{
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15; // state of the linear congruential generator
+    let mut values: Vec<f64> = Vec::with_capacity(n);
+
+
+    while values.len() < n
+    {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        let x: f64 = f64::from_bits(state);
+        if x.is_finite()
+        {
+            values.push(x);
+        }
+    }
+
+    return values;
+}
+
+
+#[test]
+fn parallel_equals_serial()
+{
+    let values: Vec<f64> = random_values(100_000);
+
+
+    for f in [
+        scaler::Formatter::new(),
+        scaler::Formatter::new().set_scaling(scaler::Scaling::Scientific).set_rounding(scaler::Rounding::SignificantDigits(17)),
+        scaler::Formatter::new().set_scaling(scaler::Scaling::Binary(true)).set_width(12, scaler::Align::Right, ' ').set_unit("B"),
+    ]
+    {
+        let mut out: Vec<u8> = Vec::new(); // output of the parallel writer
+        let serial: Vec<String> = f.format_slice(&values);
+
+        assert_eq!(f.par_format_slice(&values), serial);
+        f.par_format_into_writer(&values, "\n", &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), serial.iter().map(|s| s.clone() + "\n").collect::<String>());
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, ParseError, ParseErrorKind, Parser, Scaling, Sign, SignPlacement};
+
+
+/// # Summary
+/// Strings formatted and typed in every way the parsers understand or reject.
+const INPUTS: [&str; 40] = [
+    "",
+    "   ",
+    "0",
+    "-0",
+    "42",
+    "42,07 k",
+    "42,07k",
+    " 42,07 K ",
+    "-1.234,5",
+    "1,234.5",
+    "1.234",
+    "1,234",
+    "+789,0 µ",
+    "1,000 * 10^(-31)",
+    "1,5 × 2¹⁰",
+    "1,5E-3",
+    "1,5E",
+    "1e400",
+    "-∞",
+    "∞ B",
+    "inf",
+    "NaN",
+    "12,3 MB",
+    "12,3 MB/s",
+    "12,3 mB",
+    "5 mm",
+    "1,5 GiB",
+    "2 gi",
+    "1 Yi",
+    "16 Ei",
+    "$1.234,5-",
+    "EUR 5",
+    " 5",
+    "12,5 %",
+    "3 ‰",
+    "0,5",
+    "1,5 k rows",
+    "5 Gremlins",
+    "x",
+    "4a2",
+];
+
+
+/// # Summary
+/// Makes parse results comparable, NaN included.
+///
+/// # Arguments
+/// - `result`: the parse result
+///
+/// # Returns
+/// - the bits of the number or the error
+fn bits(result: Result<f64, ParseError>) -> Result<u64, ParseError>
+{
+    return result.map(f64::to_bits);
+}
+
+
+#[test]
+fn builder_matches_formatter()
+{
+    let cases: [(Formatter, Parser); 4] = [
+        (Formatter::new(), Parser::builder().build().unwrap()),
+        (
+            Formatter::new().set_separators(",", ".").set_unit("B"),
+            Parser::builder().set_separators(",", ".").set_unit("B").build().unwrap(),
+        ),
+        (
+            Formatter::new().set_affixes("$", "").set_sign(Sign::SpaceForPositive),
+            Parser::builder().set_affixes("$", "").set_sign(Sign::SpaceForPositive).build().unwrap(),
+        ),
+        (
+            Formatter::new().set_special_strings("unbounded", "n/a").set_unit("m"),
+            Parser::builder().set_special_strings("unbounded", "n/a").set_unit("m").build().unwrap(),
+        ),
+    ]; // formatter and parser built independently with the same parsing settings
+
+
+    for (f, parser) in cases
+    {
+        for s in INPUTS
+        {
+            assert_eq!(bits(parser.parse(s)), bits(f.parse(s)), "{s:?}");
+            assert_eq!(bits(parser.parse_lenient(s)), bits(f.parse_lenient(s)), "{s:?}");
+        }
+    }
+}
+
+
+#[test]
+fn formatter_and_parser_agree()
+{
+    let formatters: [Formatter; 6] = [
+        Formatter::new(),
+        Formatter::new().set_unit("B"),
+        Formatter::new().set_separators(",", ".").set_affixes("$", "").set_sign_placement(scaler::SignPlacement::Trailing),
+        Formatter::new().set_affixes("EUR ", "").set_sign(Sign::SpaceForPositive),
+        Formatter::new().set_special_strings("unbounded", "0/0").set_scaling(Scaling::Binary(true)),
+        Formatter::new().set_separators("'", "·").set_unit("m"),
+    ];
+
+
+    for f in formatters
+    {
+        let parser: Parser = f.parser();
+        for s in INPUTS
+        {
+            assert_eq!(bits(parser.parse(s)), bits(f.parse(s)), "{s:?}");
+            assert_eq!(bits(parser.parse_lenient(s)), bits(f.parse_lenient(s)), "{s:?}");
+            assert_eq!(parser.parse_prefix(s).map(|(x, rest)| (x.to_bits(), rest)), f.parse_prefix(s).map(|(x, rest)| (x.to_bits(), rest)), "{s:?}");
+            assert_eq!(bits(parser.parse_percent(s)), bits(f.parse_percent(s)), "{s:?}");
+            assert_eq!(bits(parser.parse_percent_lenient(s)), bits(f.parse_percent_lenient(s)), "{s:?}");
+            assert_eq!(parser.parse_i64(s), f.parse_i64(s), "{s:?}");
+            assert_eq!(parser.parse_u64(s), f.parse_u64(s), "{s:?}");
+            assert_eq!(parser.parse_u128(s), f.parse_u128(s), "{s:?}");
+        }
+    }
+}
+
+
+#[test]
+fn lenient_parser()
+{
+    let f: Formatter = Formatter::new();
+    let parser: Parser = Parser::builder().set_lenient(true).build().unwrap();
+
+
+    for s in INPUTS
+    {
+        assert_eq!(bits(parser.parse(s)), bits(f.parse_lenient(s)), "{s:?}");
+        assert_eq!(bits(parser.parse_percent(s)), bits(f.parse_percent_lenient(s)), "{s:?}");
+        assert_eq!(parser.parse_prefix(s).map(|(x, rest)| (x.to_bits(), rest)), f.parse_prefix(s).map(|(x, rest)| (x.to_bits(), rest)), "{s:?}"); // always strict
+    }
+    assert_eq!(parser.parse_i64(" -1 500 "), Ok(-1500));
+    assert_eq!(f.parse_i64(" -1 500 "), Err(ParseError { field: None, kind: ParseErrorKind::NoDigits, position: 0 }));
+}
+
+
+#[test]
+fn prefixes()
+{
+    let decimal: Parser = Parser::builder().set_prefixes(true, false).build().unwrap();
+    let binary: Parser = Parser::builder().set_prefixes(false, true).set_lenient(true).build().unwrap();
+    let none: Parser = Parser::builder().set_prefixes(false, false).build().unwrap();
+
+
+    assert_eq!(decimal.parse("1,5 M"), Ok(1.5e6));
+    assert_eq!(decimal.parse("1,5 Mi"), Err(ParseError { field: None, kind: ParseErrorKind::UnknownPrefix("Mi".to_string()), position: 4 }));
+    assert_eq!(decimal.parse_lenient("1,5 K"), Ok(1500.0));
+    assert_eq!(binary.parse("1,5 mi"), Ok(1572864.0)); // unique ignoring case among binary unit prefixes only
+    assert!(binary.parse("1,5 M").is_err());
+    assert_eq!(none.parse("1,5"), Ok(1.5));
+    assert_eq!(none.parse("1,5e3"), Ok(1500.0)); // e-notation is no unit prefix
+    assert_eq!(none.parse("1,5 * 2^(10)"), Ok(1536.0));
+    assert!(none.parse("1,5 k").is_err());
+    assert_eq!(none.parse("-∞"), Ok(f64::NEG_INFINITY));
+}
+
+
+#[test]
+fn parser_outlives_formatter()
+{
+    let parser: Parser;
+
+
+    {
+        let f: Formatter = Formatter::new().set_separators(",", ".");
+        parser = f.parser();
+    }
+    assert_eq!(parser.parse("1,234.56 k"), Ok(1234560.0));
+    assert_eq!(Parser::builder().set_separators(",", ".").build(), Ok(parser.clone()));
+    assert_ne!(Parser::builder().set_separators(",", ".").set_lenient(true).build(), Ok(parser));
+}
+
+
+#[test]
+fn signs_round_trip()
+{
+    for sign in [Sign::Always, Sign::ExceptZero, Sign::OnlyMinus, Sign::SpaceForPositive]
+    {
+        for placement in [SignPlacement::Leading, SignPlacement::Parentheses, SignPlacement::Trailing]
+        {
+            for (prefix, suffix) in [("", ""), ("$", ""), ("", " USD"), ("$", " USD")]
+            {
+                for unit in ["", "B"]
+                {
+                    let f: Formatter = Formatter::new().set_sign(sign.clone()).set_sign_placement(placement.clone()).set_affixes(prefix, suffix).set_unit(unit);
+                    for x in [3.0, -3.0, 1234.5e3, -25.01e6, 0.0, -0.0, 0.000123, f64::INFINITY, f64::NEG_INFINITY]
+                    {
+                        let s: String = f.format(x);
+                        assert_eq!(f.parse(&s).map(|parsed| f.format(parsed)), Ok(s.clone()), "{s:?} with {sign:?}, {placement:?}, and unit {unit:?}");
+                    }
+                    assert!(f.parse(&f.format(f64::NAN)).unwrap().is_nan());
+                }
+            }
+        }
+    }
+    assert_eq!(Formatter::new().set_sign(Sign::SpaceForPositive).set_sign_placement(SignPlacement::Trailing).parse("3 "), Ok(3.0));
+    assert_eq!(Formatter::new().set_sign(Sign::SpaceForPositive).set_sign_placement(SignPlacement::Trailing).set_affixes("$", " USD").parse("$1,234 k USD "), Ok(1234.0));
+}
@@ -0,0 +1,119 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Formatter, ParseError, ParseErrorKind, PercentOfOptions, Rounding, Scaling, Sign};
+
+
+#[test]
+fn division_edge_cases()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(-1));
+    let cases: [(f64, f64, &str); 16] = [
+        (0.0, 0.0, "0,0 %"),
+        (-0.0, 0.0, "0,0 %"),
+        (0.0, -0.0, "0,0 %"),
+        (5.0, 0.0, "∞ %"),
+        (5.0, -0.0, "∞ %"), // sign of the part, not of the zero
+        (-5.0, 0.0, "-∞ %"),
+        (0.0, 5.0, "0,0 %"),
+        (-0.0, 5.0, "0,0 %"),
+        (1.0, f64::INFINITY, "0,0 %"),
+        (f64::INFINITY, 1.0, "∞ %"),
+        (f64::NEG_INFINITY, 1.0, "-∞ %"),
+        (f64::INFINITY, 0.0, "∞ %"),
+        (f64::INFINITY, f64::INFINITY, "NaN"),
+        (f64::NAN, 0.0, "NaN"),
+        (0.0, f64::NAN, "NaN"),
+        (1e-320, 1e300, "0,0 %"), // underflows
+    ]; // part, whole, and formatted percentage
+
+
+    for (part, whole, s) in cases
+    {
+        assert_eq!(f.format_percent_of(part, whole), s, "{part} of {whole}");
+    }
+    assert_eq!(f.format_percent_of(1e300, 1e-300), "∞ %"); // overflows
+    assert_eq!(f.clone().set_percent_of(PercentOfOptions { zero_of_zero_placeholder: true, ..PercentOfOptions::new() }).format_percent_of(0.0, 0.0), "—");
+    assert_eq!(f.clone().set_percent_of(PercentOfOptions { zero_of_zero_placeholder: true, ..PercentOfOptions::new() }).format_percent_of(-0.0, 0.0), "—");
+    assert_eq!(f.clone().set_percent_of(PercentOfOptions { zero_of_zero_placeholder: true, ..PercentOfOptions::new() }).set_none_string("n/a").format_percent_of(0.0, 0.0), "n/a");
+    assert_eq!(f.clone().set_percent_of(PercentOfOptions { zero_of_zero_placeholder: true, ..PercentOfOptions::new() }).format_percent_of(5.0, 0.0), "∞ %");
+}
+
+
+#[test]
+fn negative_ratios()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::Magnitude(-1));
+
+
+    assert_eq!(f.format_percent_of(-1.0, 4.0), "-25,0 %");
+    assert_eq!(f.format_percent_of(1.0, -4.0), "-25,0 %");
+    assert_eq!(f.format_percent_of(-1.0, -4.0), "25,0 %");
+    assert_eq!(f.format_percent_of(-1e-6, 4.0), "-0,0 %"); // rounded to 0 but negative
+    assert_eq!(f.clone().set_sign(Sign::Always).format_percent_of(1.0, 4.0), "+25,0 %");
+}
+
+
+#[test]
+fn clamped()
+{
+    let f: Formatter = Formatter::new()
+        .set_rounding(Rounding::Magnitude(0))
+        .set_percent_of(PercentOfOptions { clamp: true, ..PercentOfOptions::new() });
+
+
+    assert_eq!(f.format_percent_of(1.0, 2.0), "50 %");
+    assert_eq!(f.format_percent_of(3.0, 2.0), "100 %");
+    assert_eq!(f.format_percent_of(-1.0, 2.0), "0 %");
+    assert_eq!(f.format_percent_of(1.0, -2.0), "0 %");
+    assert_eq!(f.format_percent_of(5.0, 0.0), "100 %");
+    assert_eq!(f.format_percent_of(-5.0, 0.0), "0 %");
+    assert_eq!(f.format_percent_of(0.0, 0.0), "0 %");
+    assert_eq!(f.format_percent_of(f64::NAN, 2.0), "NaN");
+}
+
+
+#[test]
+fn formatter_options()
+{
+    let f: Formatter = Formatter::new()
+        .set_affixes("$", "")
+        .set_rounding(Rounding::Magnitude(-2))
+        .set_separators(",", ".")
+        .set_unit("B")
+        .set_width(10, Align::Right, ' ');
+
+
+    assert_eq!(f.format_percent_of(1.0, 3.0), "   33.33 %"); // no affixes, unit, or scaling
+    assert_eq!(f.format_percent_of(12345.0, 1.0), "1,234,500.00 %");
+    assert_eq!(f.format_percent_of(7.0, 100.0), "    7.00 %"); // noise of 0.07 * 100 rounded away
+}
+
+
+#[test]
+fn percent_scaling_parses_back()
+{
+    let formatters: [Formatter; 3] = [
+        Formatter::new().set_scaling(Scaling::Percent(true)),
+        Formatter::new().set_scaling(Scaling::Percent(false)).set_sign(Sign::Always),
+        Formatter::new().set_scaling(Scaling::Percent(true)).set_rounding(Rounding::Magnitude(-4)).set_separators(",", "."),
+    ];
+    let f: &Formatter = &formatters[0];
+
+
+    assert_eq!(f.parse("100,0 %"), Ok(1.0));
+    assert_eq!(f.parse("-12,50%"), Ok(-0.125));
+    assert_eq!(f.parse("∞ %"), Ok(f64::INFINITY));
+    assert_eq!(f.parse("-∞ %"), Ok(f64::NEG_INFINITY));
+    assert!(f.parse("NaN").unwrap().is_nan()); // displayed without percent sign
+    assert_eq!(f.parse("100,0"), Err(ParseError { field: None, kind: ParseErrorKind::MissingPercent, position: 5 }));
+    assert_eq!(f.parse_lenient(" 12.5 % "), Ok(0.125));
+    assert_eq!(f.parser().parse("1,5 ‰"), Ok(0.0015));
+    for f in formatters.iter()
+    {
+        for x in [0.0, 1.0, -0.125, 0.5e-9, 1.2345, 42069.0, -1e300, f64::INFINITY, f64::NEG_INFINITY]
+        {
+            assert_eq!(f.parse(&f.format(x)).map(|parsed| f.format(parsed)), Ok(f.format(x)), "{x}");
+        }
+        assert!(f.parse(&f.format(f64::NAN)).unwrap().is_nan());
+    }
+}
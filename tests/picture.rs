@@ -0,0 +1,77 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, PictureError, PictureErrorKind};
+
+
+#[test]
+fn masks()
+{
+    let cases: [(&str, f64, &str); 16] = [
+        ("#.##0,00", 1234.5, "1.234,50"),
+        ("#,##0.00", 1234.5, "1,234.50"),
+        ("#,##0", 1234567.0, "1,234,567"),
+        ("#'##0.00", 1234567.891, "1'234'567.89"),
+        ("# ##0,##", 1234.5, "1 234,5"),
+        ("0.000", 1.23456, "1.235"),
+        ("000", 7.0, "007"),
+        ("0,00 \"kg\"", 2.5, "2,50 kg"),
+        ("0.0 %", 0.125, "12.5 %"),
+        ("0%", 0.4567, "46%"),
+        ("0.0 % \"p.a.\"", 0.035, "3.5 % p.a."),
+        ("$#,##0.00;($#,##0.00)", -1234.5, "($1,234.50)"),
+        ("$#,##0.00;$(#,##0.00)", -5.0, "$(5.00)"),
+        ("#,##0.00 \"EUR\";-#,##0.00 \"EUR\"", -5.0, "-5.00 EUR"),
+        ("#,##0.00;#,##0.00-", -5.0, "5.00-"),
+        ("0.0%;(0.0%)", -0.125, "(12.5%)"),
+    ]; // mask, number, formatted
+
+
+    for (mask, x, formatted) in cases
+    {
+        assert_eq!(Formatter::from_picture(mask).map(|f| f.format(x)), Ok(formatted.to_owned()), "{mask:?}");
+    }
+}
+
+
+#[test]
+fn errors()
+{
+    let cases: [(&str, PictureErrorKind, usize); 11] = [
+        ("", PictureErrorKind::NoDigits, 0),
+        ("\"EUR\"", PictureErrorKind::NoDigits, 0),
+        ("\"EUR 0", PictureErrorKind::UnterminatedQuote, 0),
+        ("?0", PictureErrorKind::UnexpectedChar('?'), 0),
+        ("0#", PictureErrorKind::UnexpectedChar('#'), 1),
+        ("#,,##0", PictureErrorKind::UnexpectedChar(','), 2),
+        ("#.##0,00.0", PictureErrorKind::UnexpectedChar(','), 5),
+        ("0.00E+00", PictureErrorKind::UnexpectedChar('E'), 4),
+        ("0%%", PictureErrorKind::UnexpectedChar('%'), 2),
+        ("0.0#", PictureErrorKind::MixedFraction, 3),
+        ("0;[Red]0", PictureErrorKind::NegativeMismatch, 2),
+    ]; // mask, problem, position
+
+
+    for (mask, kind, position) in cases
+    {
+        assert_eq!(Formatter::from_picture(mask), Err(PictureError { kind, position }), "{mask:?}");
+    }
+    assert_eq!(Formatter::from_picture("0;0;0"), Err(PictureError { kind: PictureErrorKind::UnexpectedChar(';'), position: 3 }));
+}
+
+
+#[test]
+fn round_trip()
+{
+    let f: Formatter = Formatter::from_picture("$#,##0.00;($#,##0.00)").unwrap();
+    let percent: Formatter = Formatter::from_picture("0.0 %;(0.0 %)").unwrap();
+
+
+    for x in [-1234.5, -0.5, 0.0, 42.0, 1234567.89]
+    {
+        assert_eq!(f.parse(&f.format(x)), Ok(x), "{x}");
+    }
+    for x in [-0.125, 0.0, 0.5, 1.5]
+    {
+        assert_eq!(percent.parse_percent(&percent.format(x)), Ok(x), "{x}");
+    }
+}
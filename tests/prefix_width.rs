@@ -0,0 +1,81 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Locale, PrefixTable, Rounding, Scaling};
+
+
+#[test]
+fn mixed_column_has_equal_length()
+{
+    let cases: [(Formatter, &[f64], usize); 5] = [
+        (Formatter::new(), &[999.0, 1234.0, 0.000001, 0.5, 12.0, 1e6, 0.0], "999,0 k".len()),
+        (Formatter::new().set_scaling(Scaling::Decimal(false)), &[999.0, 1234.0, 0.000001, 0.5, 12.0], "999,0k".len()),
+        (Formatter::new().set_scaling(Scaling::Binary(true)).set_unit("B"), &[512.0, 1536.0, 3670016.0, 1.0], "512,0 KiB".len()),
+        (Formatter::new().set_scaling(Scaling::Binary(false)).set_unit("B"), &[512.0, 1536.0, 3670016.0, 1.0], "512,0KiB".len()),
+        (
+            Formatter::new().set_scaling(Scaling::Exponential { base: 60, step: 1, prefixes: Some(PrefixTable::new(&[(0, 1, "s"), (1, 2, "min"), (2, 3, "h")], true)) }),
+            &[30.0, 90.0, 7200.0],
+            "30,00 min".len(),
+        ),
+    ]; // formatter, column, characters of every number
+
+
+    for (f, column, len) in cases
+    {
+        let f: Formatter = f.set_reserve_prefix_width(true);
+
+        for x in column
+        {
+            assert_eq!(f.format(x).chars().count(), len, "{x} as {:?}", f.format(x));
+        }
+    }
+}
+
+
+#[test]
+fn padding()
+{
+    let f: Formatter = Formatter::new().set_reserve_prefix_width(true);
+    let bytes: Formatter = Formatter::new().set_scaling(Scaling::Binary(true)).set_unit("B").set_reserve_prefix_width(true);
+
+
+    assert_eq!(f.format(999), "999,0  ");
+    assert_eq!(f.format(0.000001), "1,000 µ"); // counted in characters, "µ" has 2 bytes
+    assert_eq!(f.format(f64::INFINITY), "∞  ");
+    assert_eq!(f.format(f64::NAN), "NaN"); // not a number has no unit prefix
+    assert_eq!(f.format(1e40), "1,000 * 10^(40)"); // scientific notation fallback, not padded
+    assert_eq!(f.clone().set_scaling(Scaling::Scientific).format(999), "9,990 * 10^(2)");
+    assert_eq!(f.clone().set_scaling(Scaling::None).format(999), "999,0");
+    assert_eq!(bytes.format(512), "512,0   B");
+    assert_eq!(bytes.format(1536), "1,500 KiB");
+    assert_eq!(bytes.format(f64::NEG_INFINITY), "-∞   B");
+    assert_eq!(Formatter::new().set_reserve_prefix_width(false).format(999), "999,0");
+}
+
+
+#[test]
+fn compact_suffixes()
+{
+    let f: Formatter = Formatter::new()
+        .set_locale(Locale::JaJp)
+        .set_rounding(Rounding::SignificantDigits(3))
+        .set_scaling(Scaling::CompactLocalized)
+        .set_reserve_prefix_width(true);
+
+
+    assert_eq!(f.format(999), "999 ");
+    assert_eq!(f.format(12345), "1.23万");
+    assert_eq!(f.clone().set_locale(Locale::DeDe).format(999), "999     "); // " Mio." is the widest
+}
+
+
+#[test]
+fn fits_max_len()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Binary(true)).set_unit("B").set_reserve_prefix_width(true);
+
+
+    for x in [0.0, 1.0, 999.0, 1024.0, 1e20, f64::MAX, f64::MIN_POSITIVE, f64::INFINITY]
+    {
+        assert!(f.format(x).len() <= f.max_len(), "{x}");
+    }
+}
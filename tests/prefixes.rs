@@ -0,0 +1,248 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, PartKind, Rounding, Scaling};
+
+
+/// unit prefixes for binary mode, [lower bound magnitude; upper bound magnitude[, unit prefix
+const BINARY_PREFIXES: [(i16, i16, &str); 9] = [(0, 10, ""), (10, 20, "Ki"), (20, 30, "Mi"), (30, 40, "Gi"), (40, 50, "Ti"), (50, 60, "Pi"), (60, 70, "Ei"), (70, 80, "Zi"), (80, 90, "Yi")];
+/// SI unit prefixes for decimal mode, [lower bound magnitude; upper bound magnitude[, unit prefix
+const DECIMAL_PREFIXES: [(i16, i16, &str); 21] = [
+    (-30, -27, "q"),
+    (-27, -24, "r"),
+    (-24, -21, "y"),
+    (-21, -18, "z"),
+    (-18, -15, "a"),
+    (-15, -12, "f"),
+    (-12, -9, "p"),
+    (-9, -6, "n"),
+    (-6, -3, "µ"),
+    (-3, 0, "m"),
+    (0, 3, ""),
+    (3, 6, "k"),
+    (6, 9, "M"),
+    (9, 12, "G"),
+    (12, 15, "T"),
+    (15, 18, "P"),
+    (18, 21, "E"),
+    (21, 24, "Z"),
+    (24, 27, "Y"),
+    (27, 30, "R"),
+    (30, 33, "Q"),
+];
+
+
+/// # Summary
+/// Looks up the unit prefix for a magnitude by searching the table, the way the lookup used to work.
+///
+/// # Arguments
+/// - `prefixes`: the unit prefix table to search
+/// - `magnitude`: magnitude of the number in the base of the table
+///
+/// # Returns
+/// - the unit prefix or none if magnitude is out of range
+fn linear_scan(prefixes: &[(i16, i16, &'static str)], magnitude: f64) -> Option<&'static str>
+{
+    return prefixes.iter().find(|(lower, upper, _prefix)| *lower as f64 <= magnitude && magnitude < *upper as f64).map(|(_lower, _upper, prefix)| *prefix);
+}
+
+
+/// # Summary
+/// Extracts what a formatted number is scaled by.
+///
+/// # Arguments
+/// - `f`: the formatter
+/// - `x`: the number to format
+///
+/// # Returns
+/// - the unit prefix, empty if there is none, or none if scientific notation was used instead
+fn scaled_by(f: &Formatter, x: f64) -> Option<String>
+{
+    let parts: scaler::FormattedParts = f.format_to_parts(x); // pieces of the formatted number
+
+
+    if parts.0.iter().any(|part| part.kind == PartKind::ExponentDigits)
+    {
+        return None;
+    }
+    return Some(parts.0.iter().filter(|part| part.kind == PartKind::Prefix).map(|part| part.value.as_str()).collect());
+}
+
+
+#[test]
+fn lookup_equals_linear_scan()
+{
+    for (scaling, base, prefixes, multipliers) in [
+        (Scaling::Decimal(true), 10_f64, &DECIMAL_PREFIXES[..], [1.5, 3.0, 9.5]), // away from exact powers of 10, whose f64 logarithm may fall just below
+        (Scaling::Binary(true), 2_f64, &BINARY_PREFIXES[..], [1.0, 1.25, 1.75]),
+    ]
+    {
+        let f: Formatter = Formatter::new().set_scaling(scaling).set_rounding(Rounding::SignificantDigits(4));
+        let compiled: scaler::CompiledFormatter = f.compile();
+
+        for magnitude in -35..=35
+        {
+            for multiplier in multipliers
+            {
+                let x: f64 = multiplier * base.powi(magnitude); // number with exactly this floored magnitude
+                let expected: Option<String> = linear_scan(prefixes, magnitude as f64).map(str::to_owned);
+
+                assert_eq!(scaled_by(&f, x), expected, "Unit prefix of {x} with {:?} differs from the linear scan.", f.format(x));
+                assert_eq!(scaled_by(&f, -x), expected, "Unit prefix of {} with {:?} differs from the linear scan.", -x, f.format(-x));
+                assert_eq!(compiled.format(x), f.format(x), "Compiled formatter scales {x} differently.");
+            }
+        }
+    }
+}
+
+
+#[test]
+fn forced_prefix_applies_to_every_number()
+{
+    for (scaling, base, prefixes) in [(Scaling::Decimal(true), 10_f64, &DECIMAL_PREFIXES[..]), (Scaling::Binary(true), 2_f64, &BINARY_PREFIXES[..])]
+    {
+        let f: Formatter = Formatter::new().set_scaling(scaling);
+
+        for (lower, _upper, prefix) in prefixes
+        {
+            let largest: f64 = 1.5 * base.powi(*lower as i32); // determines the shared unit prefix
+            let (formatted, shared) = f.format_common_scale(&[largest, largest / base.powi(4)]); // smaller number is forced to the same unit prefix
+
+            assert_eq!(shared, *prefix);
+            for s in formatted
+            {
+                assert!(s.ends_with(&format!(" {prefix}")) || prefix.is_empty() && !s.contains(' '), "{s:?} is not scaled by the shared unit prefix {prefix:?}.");
+            }
+        }
+    }
+}
+
+
+/// # Summary
+/// Determines the adjacent f64 of a positive finite number.
+///
+/// # Arguments
+/// - `x`: the number
+///
+/// # Returns
+/// - the next smaller and the next larger f64
+fn neighbours(x: f64) -> (f64, f64)
+{
+    return (f64::from_bits(x.to_bits() - 1), f64::from_bits(x.to_bits() + 1));
+}
+
+
+#[test]
+fn boundaries_are_exact()
+{
+    let decimal: Formatter = Formatter::new().set_scaling(Scaling::Decimal(true)); // 4 significant digits, neighbours round onto the boundary
+    let decimal_precise: Formatter = decimal.clone().set_rounding(Rounding::SignificantDigits(17)); // 17 significant digits keep every f64 as is
+    let binary: Formatter = Formatter::new().set_scaling(Scaling::Binary(true)).set_rounding(Rounding::SignificantDigits(17));
+    let scientific: Formatter = Formatter::new().set_scaling(Scaling::Scientific);
+    let scientific_precise: Formatter = scientific.clone().set_rounding(Rounding::SignificantDigits(17));
+
+
+    for magnitude in DECIMAL_PREFIXES.iter().map(|(lower, _upper, _prefix)| *lower).chain([33])
+    {
+        let x: f64 = format!("1e{magnitude}").parse().unwrap(); // f64 closest to the power of 10
+        let (below, above): (f64, f64) = neighbours(x);
+        let expected: Option<String> = linear_scan(&DECIMAL_PREFIXES, magnitude as f64).map(str::to_owned); // unit prefix of the boundary
+
+        for x in [x, below, above]
+        {
+            assert_eq!(scaled_by(&decimal, x), expected, "{x:e} as {:?}", decimal.format(x));
+            assert_eq!(scaled_by(&decimal, -x), expected, "{:e} as {:?}", -x, decimal.format(-x));
+            assert_eq!(decimal.compile().format(x), decimal.format(x), "{x:e}");
+        }
+        assert_eq!(scaled_by(&decimal_precise, below), linear_scan(&DECIMAL_PREFIXES, (magnitude - 1) as f64).map(str::to_owned), "{below:e} as {:?}", decimal_precise.format(below));
+        assert_eq!(scaled_by(&decimal_precise, above), expected, "{above:e} as {:?}", decimal_precise.format(above));
+    }
+    for magnitude in BINARY_PREFIXES.iter().map(|(lower, _upper, _prefix)| *lower).chain([90])
+    {
+        let x: f64 = 2_f64.powi(magnitude.into()); // powers of 2 are exact
+        let (below, above): (f64, f64) = neighbours(x);
+
+        for (x, magnitude) in [(x, magnitude), (below, magnitude - 1), (above, magnitude)]
+        {
+            assert_eq!(scaled_by(&binary, x), linear_scan(&BINARY_PREFIXES, magnitude as f64).map(str::to_owned), "{x} as {:?}", binary.format(x));
+            assert_eq!(binary.compile().format(x), binary.format(x), "{x}");
+        }
+    }
+    for magnitude in -307..=308
+    {
+        let x: f64 = format!("1e{magnitude}").parse().unwrap();
+        let (below, above): (f64, f64) = neighbours(x);
+
+        for x in [x, below, above]
+        {
+            assert_eq!(scientific.format_scaled(x).exponent_fallback, Some((10, magnitude)), "{x:e} as {:?}", scientific.format(x));
+        }
+        assert_eq!(scientific_precise.format_scaled(below).exponent_fallback, Some((10, magnitude - 1)), "{below:e} as {:?}", scientific_precise.format(below));
+        assert_eq!(scientific_precise.format_scaled(above).exponent_fallback, Some((10, magnitude)), "{above:e} as {:?}", scientific_precise.format(above));
+    }
+}
+
+
+#[test]
+fn byte_counts_prefixed_by_exact_count()
+{
+    for (i, unit) in ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"].iter().enumerate()
+    {
+        let power: u128 = 1 << (10 * i); // 1024^i
+        let next: u128 = 1023 * power + power / 2; // smallest count rounding to 1024 of the unit, into the next unit prefix
+
+        assert_eq!(scaler::format_bytes(power as u64), format!("1,00 {unit}"));
+        for bytes in [power, power + power / 3, 1000 * power - 1, 1000 * power, 1023 * power, next - 1, u64::MAX as u128]
+        {
+            if bytes.ilog2() / 10 != i as u32 || (0 < i && next <= bytes)
+            // other unit prefix
+            {
+                continue;
+            }
+            let s: String = scaler::format_bytes(bytes as u64);
+            let digits: usize = s.chars().filter(char::is_ascii_digit).count();
+            assert!(s.ends_with(&format!(" {unit}")), "{bytes} B as {s:?}, not in {unit}");
+            assert!((3..=4).contains(&digits), "{bytes} B as {s:?}");
+        }
+        if 0 < i && next <= u64::MAX as u128
+        // no half bytes below KiB
+        {
+            assert_eq!(scaler::format_bytes(next as u64), format!("1,00 {}", ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"][i])); // 1023,5 rounds to 1024, tie to even
+        }
+    }
+}
+
+
+#[test]
+fn binary_powers_keep_mantissa_in_range()
+{
+    const MANTISSAS: [&str; 10] = ["1,000", "2,000", "4,000", "8,000", "16,00", "32,00", "64,00", "128,0", "256,0", "512,0"]; // 2^(0) to 2^(9) with 4 significant digits
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Binary(true));
+
+
+    for magnitude in -1074..=1023_i32
+    {
+        let x: f64 = if magnitude < -1022 { f64::from_bits(1 << (magnitude + 1074)) } else { f64::from_bits(((magnitude + 1023) as u64) << 52) }; // subnormal or normal power of 2, exact
+        let scaled: scaler::ScaledValue = f.format_scaled(x);
+
+        match BINARY_PREFIXES.iter().find(|(lower, upper, _prefix)| (*lower as i32..*upper as i32).contains(&magnitude))
+        {
+            Some((lower, _upper, prefix)) => assert_eq!(f.format(x), format!("{} {prefix}", MANTISSAS[(magnitude - *lower as i32) as usize]).trim_end(), "2^({magnitude})"),
+            None =>
+            {
+                let (base, exponent): (u32, i32) = scaled.exponent_fallback.expect("Power of 2 beyond the unit prefixes is in scientific notation.");
+                assert_eq!((base, exponent), (2, magnitude - (scaled.rounded_mantissa_str != "1,000") as i32), "2^({magnitude}) as {:?}", f.format(x)); // the number is rounded to 4 significant digits, which may fall just below the power of 2
+                assert!((1.0..2.0).contains(&scaled.mantissa) && scaled.rounded_mantissa_str != "2,000", "2^({magnitude}) as {:?}", f.format(x));
+                assert_eq!(f.format(x), format!("{} * 2^({exponent})", scaled.rounded_mantissa_str), "2^({magnitude})");
+            }
+        }
+        assert_eq!(f.format(-x), format!("-{}", f.format(x)), "-2^({magnitude})");
+        assert_eq!(f.compile().format(x), f.format(x), "2^({magnitude}) compiled");
+    }
+    assert_eq!(f.format(f64::MIN_POSITIVE), "1,000 * 2^(-1022)");
+    assert_eq!(f.format(2_f64.powi(1023)), "1,000 * 2^(1023)");
+    assert_eq!(f.format(2_f64.powi(-30)), "1,000 * 2^(-30)");
+    assert_eq!(f.format(2_f64.powi(-9)), "1,000 * 2^(-9)");
+    assert_eq!(f.format(0.9999), "1,000"); // mantissa 1,9998 * 2^(-1) carries over into 2^(0), which has a unit prefix
+    assert_eq!(f.format(1.9999 * 2_f64.powi(-9)), "1,000 * 2^(-8)");
+    assert_eq!(f.format(1.9999 * 2_f64.powi(1023)), "1,000 * 2^(1024)"); // beyond f64, only the exponent
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{SpecError, SpecErrorKind};
+
+
+/// numbers formatted by every specification in `LIBC`
+const VALUES: [f64; 14] = [0.0, 1.0, -1.5, 0.5, 2.5, 123.456, 1234567.891, 0.000123456, 1e-5, 9.9999996, 99999.95, 123456789.0, -0.6, 1e21];
+
+/// specifications and how glibc's snprintf formats `VALUES` with them
+const LIBC: [(&str, [&str; 14]); 18] = [
+    ("%f", ["0.000000", "1.000000", "-1.500000", "0.500000", "2.500000", "123.456000", "1234567.891000", "0.000123", "0.000010", "10.000000", "99999.950000", "123456789.000000", "-0.600000", "1000000000000000000000.000000"]),
+    ("%.0f", ["0", "1", "-2", "0", "2", "123", "1234568", "0", "0", "10", "100000", "123456789", "-1", "1000000000000000000000"]),
+    ("%.2f", ["0.00", "1.00", "-1.50", "0.50", "2.50", "123.46", "1234567.89", "0.00", "0.00", "10.00", "99999.95", "123456789.00", "-0.60", "1000000000000000000000.00"]),
+    ("%+.3f", ["+0.000", "+1.000", "-1.500", "+0.500", "+2.500", "+123.456", "+1234567.891", "+0.000", "+0.000", "+10.000", "+99999.950", "+123456789.000", "-0.600", "+1000000000000000000000.000"]),
+    ("% .1f", [" 0.0", " 1.0", "-1.5", " 0.5", " 2.5", " 123.5", " 1234567.9", " 0.0", " 0.0", " 10.0", " 99999.9", " 123456789.0", "-0.6", " 1000000000000000000000.0"]),
+    ("%10.2f", ["      0.00", "      1.00", "     -1.50", "      0.50", "      2.50", "    123.46", "1234567.89", "      0.00", "      0.00", "     10.00", "  99999.95", "123456789.00", "     -0.60", "1000000000000000000000.00"]),
+    ("%-10.2f", ["0.00      ", "1.00      ", "-1.50     ", "0.50      ", "2.50      ", "123.46    ", "1234567.89", "0.00      ", "0.00      ", "10.00     ", "99999.95  ", "123456789.00", "-0.60     ", "1000000000000000000000.00"]),
+    ("%010.2f", ["0000000.00", "0000001.00", "-000001.50", "0000000.50", "0000002.50", "0000123.46", "1234567.89", "0000000.00", "0000000.00", "0000010.00", "0099999.95", "123456789.00", "-000000.60", "1000000000000000000000.00"]),
+    ("%e", ["0.000000e+00", "1.000000e+00", "-1.500000e+00", "5.000000e-01", "2.500000e+00", "1.234560e+02", "1.234568e+06", "1.234560e-04", "1.000000e-05", "1.000000e+01", "9.999995e+04", "1.234568e+08", "-6.000000e-01", "1.000000e+21"]),
+    ("%.0e", ["0e+00", "1e+00", "-2e+00", "5e-01", "2e+00", "1e+02", "1e+06", "1e-04", "1e-05", "1e+01", "1e+05", "1e+08", "-6e-01", "1e+21"]),
+    ("%.3e", ["0.000e+00", "1.000e+00", "-1.500e+00", "5.000e-01", "2.500e+00", "1.235e+02", "1.235e+06", "1.235e-04", "1.000e-05", "1.000e+01", "1.000e+05", "1.235e+08", "-6.000e-01", "1.000e+21"]),
+    ("%+.2e", ["+0.00e+00", "+1.00e+00", "-1.50e+00", "+5.00e-01", "+2.50e+00", "+1.23e+02", "+1.23e+06", "+1.23e-04", "+1.00e-05", "+1.00e+01", "+1.00e+05", "+1.23e+08", "-6.00e-01", "+1.00e+21"]),
+    ("%E", ["0.000000E+00", "1.000000E+00", "-1.500000E+00", "5.000000E-01", "2.500000E+00", "1.234560E+02", "1.234568E+06", "1.234560E-04", "1.000000E-05", "1.000000E+01", "9.999995E+04", "1.234568E+08", "-6.000000E-01", "1.000000E+21"]),
+    ("%g", ["0", "1", "-1.5", "0.5", "2.5", "123.456", "1.23457e+06", "0.000123456", "1e-05", "10", "99999.9", "1.23457e+08", "-0.6", "1e+21"]),
+    ("%.3g", ["0", "1", "-1.5", "0.5", "2.5", "123", "1.23e+06", "0.000123", "1e-05", "10", "1e+05", "1.23e+08", "-0.6", "1e+21"]),
+    ("%.10g", ["0", "1", "-1.5", "0.5", "2.5", "123.456", "1234567.891", "0.000123456", "1e-05", "9.9999996", "99999.95", "123456789", "-0.6", "1e+21"]),
+    ("%.0g", ["0", "1", "-2", "0.5", "2", "1e+02", "1e+06", "0.0001", "1e-05", "1e+01", "1e+05", "1e+08", "-0.6", "1e+21"]),
+    ("%G", ["0", "1", "-1.5", "0.5", "2.5", "123.456", "1.23457E+06", "0.000123456", "1E-05", "10", "99999.9", "1.23457E+08", "-0.6", "1E+21"]),
+];
+
+
+/// # Summary
+/// Reduces formatted output to its shape: the mantissa with "." as decimal separator and the decimal exponent if in scientific notation.
+///
+/// # Arguments
+/// - `s`: output of `scaler::printf` or of C
+///
+/// # Returns
+/// - mantissa and exponent
+fn shape(s: &str) -> (String, Option<i32>)
+{
+    let (mantissa, exponent): (&str, Option<&str>) = match s.split_once(" * 10^(")
+    {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent.trim_end_matches(')'))),
+        None => match s.split_once(['e', 'E'])
+        {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (s, None),
+        },
+    }; // split at the exponent in either notation
+
+
+    return (mantissa.replace(',', "."), exponent.map(|exponent| exponent.parse().unwrap()));
+}
+
+
+#[test]
+fn shapes_match_libc()
+{
+    for (spec, expected) in LIBC
+    {
+        for (x, expected) in VALUES.iter().zip(expected)
+        {
+            assert_eq!(shape(&scaler::printf(spec, *x).unwrap()), shape(expected), "{spec:?} formats {x} differently than libc.");
+        }
+    }
+}
+
+
+#[test]
+fn nonstandard_prefix_conversions()
+{
+    assert_eq!(scaler::printf("%h", 123456.789), Ok("123,5 k".to_owned()));
+    assert_eq!(scaler::printf("%.2h", 0.000123), Ok("120 µ".to_owned()));
+    assert_eq!(scaler::printf("%.0h", 1500.0), Ok("2 k".to_owned())); // 0 treated as 1
+    assert_eq!(scaler::printf("%+8.3h", 1500.0), Ok(" +1,50 k".to_owned()));
+    assert_eq!(scaler::printf("%H", 1536.0), Ok("1,500 Ki".to_owned()));
+    assert_eq!(scaler::printf("%-9.2H|", 1536.0).unwrap_err(), SpecError { kind: SpecErrorKind::UnexpectedChar('|'), position: 6 });
+}
+
+
+#[test]
+fn flags_combine_like_in_c()
+{
+    assert_eq!(scaler::printf("%+ .1f", 1.0), Ok("+1,0".to_owned())); // plus overrides space
+    assert_eq!(scaler::printf("% +.1f", 1.0), Ok("+1,0".to_owned()));
+    assert_eq!(scaler::printf("%-08.1f", 1.0), Ok("1,0     ".to_owned())); // minus overrides zero
+    assert_eq!(scaler::printf("%0-8.1f", 1.0), Ok("1,0     ".to_owned()));
+    assert_eq!(scaler::printf("%--8.1f", 1.0), Ok("1,0     ".to_owned())); // repeated
+    assert_eq!(scaler::printf("%.f", 2.7), Ok("3".to_owned())); // "." alone is precision 0
+    assert_eq!(scaler::printf("%f", f64::INFINITY), Ok("∞".to_owned()));
+    assert_eq!(scaler::printf("%g", f64::NAN), Ok("NaN".to_owned()));
+    assert_eq!(scaler::printf("%.1f", -0.04), Ok("-0,0".to_owned())); // signed like in C
+}
+
+
+#[test]
+fn unsupported_rejected()
+{
+    for (spec, kind, position) in [
+        ("", SpecErrorKind::MissingConversion, 0),
+        ("%", SpecErrorKind::MissingConversion, 1),
+        ("%-10", SpecErrorKind::MissingConversion, 4),
+        ("f", SpecErrorKind::UnexpectedChar('f'), 0),
+        ("x%f", SpecErrorKind::UnexpectedChar('x'), 0),
+        ("%f ", SpecErrorKind::UnexpectedChar(' '), 2),
+        ("%%", SpecErrorKind::UnexpectedChar('%'), 1),
+        ("%*f", SpecErrorKind::UnexpectedChar('*'), 1),
+        ("%'f", SpecErrorKind::UnexpectedChar('\''), 1),
+        ("%lf", SpecErrorKind::UnsupportedConversion('l'), 1),
+        ("%d", SpecErrorKind::UnsupportedConversion('d'), 1),
+        ("%5.2s", SpecErrorKind::UnsupportedConversion('s'), 4),
+        ("%a", SpecErrorKind::UnsupportedConversion('a'), 1),
+        ("%.256f", SpecErrorKind::Overflow, 2),
+        ("%99999999999999999999f", SpecErrorKind::Overflow, 1),
+    ]
+    {
+        assert_eq!(scaler::printf(spec, 1.0), Err(SpecError { kind, position }), "{spec:?} is rejected differently than expected.");
+    }
+    assert_eq!(scaler::printf("%d", 1.0).unwrap_err().to_string(), "Conversion 'd' at position 1 is not supported, expected one of \"eEfgGhH\".");
+}
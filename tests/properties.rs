@@ -0,0 +1,289 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::round::Round;
+use scaler::*;
+
+
+/// # Summary
+/// Deterministic pseudorandom numbers, so failures are reproducible without a seed to report.
+struct Xorshift(u64);
+
+
+impl Xorshift
+{
+    /// # Summary
+    /// Advances the state.
+    ///
+    /// # Returns
+    /// - the next pseudorandom number
+    fn next(&mut self) -> u64
+    {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return self.0;
+    }
+
+
+    /// # Summary
+    /// Picks a pseudorandom number below a bound.
+    ///
+    /// # Arguments
+    /// - `n`: the exclusive upper bound, must not be 0
+    ///
+    /// # Returns
+    /// - the number
+    fn below(&mut self, n: u64) -> u64
+    {
+        return self.next() % n;
+    }
+}
+
+
+/// # Summary
+/// Numbers to format: special values, extremes, powers of 2 and 10 with their neighbours, prefix boundaries and values rounding up to them, and pseudorandom bit patterns, which include subnormals and NaN payloads.
+///
+/// # Returns
+/// - the numbers
+fn values() -> Vec<f64>
+{
+    let mut rng: Xorshift = Xorshift(0x9e37_79b9_7f4a_7c15);
+    let mut x: Vec<f64> = vec![
+        0.0,
+        1e-400, // underflows to 0
+        f64::INFINITY,
+        f64::NAN,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        f64::from_bits(1), // smallest subnormal
+        f64::from_bits(0x000f_ffff_ffff_ffff), // largest subnormal
+        f64::EPSILON,
+        0.5,
+        2.5,
+        9.5,
+        99.95,
+    ]; // result
+
+
+    for exponent in (-1070..=1020).step_by(5) // every multiple of 10 for binary unit prefixes
+    {
+        let power: f64 = 2_f64.powi(exponent);
+        x.extend([power, f64::from_bits(power.to_bits() + 1), f64::from_bits(power.to_bits().saturating_sub(1))]);
+    }
+    for exponent in -323..=308
+    {
+        let power: f64 = format!("1e{exponent}").parse().unwrap(); // exact nearest f64, powi is off by ulps
+        x.extend([power, 999.5 * power, 999.95 * power, 1023.5 * power, 1.5 * power]);
+    }
+    for _ in 0..1000
+    {
+        x.push(f64::from_bits(rng.next()));
+    }
+
+    let negative: Vec<f64> = x.iter().map(|x| -x).collect();
+    x.extend(negative);
+    return x;
+}
+
+
+/// # Summary
+/// A configuration to check with the options the properties depend on, as `Formatter` does not expose them.
+struct Case
+{
+    f:        Formatter,
+    grouped:  bool, // whether the group separator is not empty
+    rounding: Rounding,
+    sign:     Sign,
+}
+
+
+/// # Summary
+/// Pseudorandom sane configurations: the given scalings with every sign and sign placement, roundings to both sides of the decimal separator, separators that are neither equal to each other nor digits, with and without trailing zeros, and minimum integer digits that need grouping.
+///
+/// # Arguments
+/// - `scalings`: the scalings to choose from
+/// - `count`: number of configurations
+///
+/// # Returns
+/// - the configurations
+fn cases(scalings: &[Scaling], count: usize) -> Vec<Case>
+{
+    let mut rng: Xorshift = Xorshift(0x2545_f491_4f6c_dd1d);
+    let separators: [(&str, &str); 7] = [(".", ","), (",", "."), ("", ","), (" ", "."), ("'", "."), ("\u{202f}", ","), ("_", "·")]; // group and decimal separator
+    let signs: [Sign; 4] = [Sign::Always, Sign::ExceptZero, Sign::OnlyMinus, Sign::SpaceForPositive];
+    let sign_placements: [SignPlacement; 2] = [SignPlacement::Leading, SignPlacement::Trailing];
+
+
+    return (0..count)
+        .map(|_| {
+            let (group_separator, decimal_separator): (&str, &str) = separators[rng.below(separators.len() as u64) as usize];
+            let rounding: Rounding = if rng.below(2) == 0 { Rounding::Magnitude(rng.below(41) as i16 - 20) } else { Rounding::SignificantDigits(rng.below(21) as u8) };
+            let sign: Sign = signs[rng.below(signs.len() as u64) as usize].clone();
+            Case {
+                f: Formatter::new()
+                    .set_min_integer_digits(rng.below(8) as usize)
+                    .set_rounding(rounding.clone())
+                    .set_scaling(scalings[rng.below(scalings.len() as u64) as usize].clone())
+                    .set_separators_static(group_separator, decimal_separator)
+                    .set_sign(sign.clone())
+                    .set_sign_placement(sign_placements[rng.below(sign_placements.len() as u64) as usize].clone())
+                    .set_trailing_zeros(rng.below(2) == 0),
+                grouped: !group_separator.is_empty(),
+                rounding,
+                sign,
+            }
+        })
+        .collect();
+}
+
+
+/// # Summary
+/// Checks the structure of a formatted number and returns what is wrong with it.
+///
+/// # Arguments
+/// - `case`: the configuration
+/// - `x`: the number to format
+///
+/// # Returns
+/// - nothing or the violated property
+fn violation(case: &Case, x: f64) -> Option<String>
+{
+    let mut decimal_separator_seen: bool = false;
+    let expected_sign: &str;
+    let mut groups: Vec<usize> = vec![0]; // lengths of the groups of integer digits
+    let parts: FormattedParts = case.f.format_to_parts(x);
+    let signs: Vec<&str> = parts.iter().filter(|part| part.kind == PartKind::Sign).map(|part| part.value.as_str()).collect();
+    let negative: bool; // whether the number is displayed as negative, even if 0 after rounding
+    let zero: bool; // whether the number is 0 after rounding
+
+
+    if parts.to_string() != case.f.format(x)
+    {
+        return Some("parts differ from format".to_string());
+    }
+    if parts.to_string().is_empty()
+    {
+        return Some("empty".to_string());
+    }
+
+    for part in parts.iter()
+    {
+        match part.kind
+        {
+            PartKind::DecimalSeparator if decimal_separator_seen => return Some("decimal separator more than once".to_string()),
+            PartKind::DecimalSeparator => decimal_separator_seen = true,
+            PartKind::GroupSeparator if decimal_separator_seen => return Some("group separator right of decimal separator".to_string()),
+            PartKind::GroupSeparator => groups.push(0),
+            PartKind::IntegerDigits => *groups.last_mut().expect("Groups are never empty.") += part.value.len(),
+            _ => {}
+        }
+    }
+    if case.grouped && (3 < groups[0] || groups[1..].iter().any(|len| *len != 3) || (1 < groups.len() && groups[0] == 0))
+    // first group 1 to 3 digits, all others exactly 3
+    {
+        return Some(format!("groups of {groups:?} digits"));
+    }
+
+    zero = x.is_finite() && parts.iter().filter(|part| matches!(part.kind, PartKind::IntegerDigits | PartKind::FractionDigits)).all(|part| part.value.bytes().all(|digit| digit == b'0'));
+    negative = x < 0.0 && (x.is_infinite() || case.rounding != Rounding::SignificantDigits(0)); // -0 and finite numbers rounded to 0 significant digits are unsigned
+    expected_sign = match (&case.sign, negative)
+    {
+        _ if x.is_nan() => "", // never signed
+        (Sign::ExceptZero, _) if zero => "",
+        (_, true) => "-",
+        (Sign::ExceptZero, false) if zero => "",
+        (Sign::Always | Sign::ExceptZero, false) => "+",
+        (Sign::OnlyMinus, false) => "",
+        (Sign::SpaceForPositive, false) => " ",
+    };
+    if signs.len() > 1 || signs.first().copied().unwrap_or("") != expected_sign
+    {
+        return Some(format!("sign {signs:?} instead of {expected_sign:?}"));
+    }
+
+    return None;
+}
+
+
+/// # Summary
+/// Formats every number with pseudorandom configurations of the given scalings and panics at the first violated property.
+///
+/// # Arguments
+/// - `scalings`: the scalings to choose from
+/// - `count`: number of configurations, bounded so the suite stays fast in debug builds
+fn check(scalings: &[Scaling], count: usize)
+{
+    let cases: Vec<Case> = cases(scalings, count);
+    let values: Vec<f64> = values();
+
+
+    for case in cases.iter()
+    {
+        for x in values.iter()
+        {
+            if let Some(violation) = violation(case, *x)
+            {
+                panic!("{violation} in {:?} formatting {x:e} ({:#x}) with {:?}", case.f.format(*x), x.to_bits(), case.f);
+            }
+        }
+    }
+}
+
+
+#[test]
+fn binary()
+{
+    check(&[Scaling::Binary(false), Scaling::Binary(true)], 8);
+}
+
+
+#[test]
+fn decimal()
+{
+    check(&[Scaling::Decimal(false), Scaling::Decimal(true)], 8);
+}
+
+
+#[test]
+fn none()
+{
+    check(&[Scaling::None], 3); // all digits of the f64, hundreds for extremes
+}
+
+
+#[test]
+fn scientific()
+{
+    check(&[Scaling::Scientific], 8);
+}
+
+
+#[test]
+fn machine_readable_parses_back()
+{
+    let cases: Vec<Case> = cases(&[Scaling::Binary(true), Scaling::Decimal(true), Scaling::None, Scaling::Scientific], 8);
+    let values: Vec<f64> = values();
+
+
+    for case in cases.iter()
+    {
+        let f: Formatter = case.f.clone().set_machine_readable(true);
+
+        for x in values.iter()
+        {
+            let rounded: f64 = match case.rounding
+            {
+                Rounding::Magnitude(magnitude) => x.round_mag(magnitude),
+                Rounding::SignificantDigits(significants) => x.round_sig(significants),
+            };
+            let s: String = f.format(*x);
+
+            match s.parse::<f64>()
+            {
+                Ok(parsed) if parsed == rounded || (parsed.is_nan() && x.is_nan()) => {}
+                Ok(parsed) => panic!("{s:?} parses to {parsed:e} instead of {rounded:e} formatting {x:e} ({:#x}) with {f:?}", x.to_bits()),
+                Err(e) => panic!("{s:?} does not parse ({e}) formatting {x:e} ({:#x}) with {f:?}", x.to_bits()),
+            }
+        }
+    }
+}
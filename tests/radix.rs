@@ -0,0 +1,56 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Formatter, Radix, RadixOptions};
+
+
+#[test]
+fn extremes()
+{
+    let f: Formatter = Formatter::new().set_separators("_", ".");
+
+
+    assert_eq!(f.format_radix(0, Radix::Binary), "0");
+    assert_eq!(f.format_radix(u64::MAX as u128, Radix::HexLower), "ffff_ffff_ffff_ffff");
+    assert_eq!(f.format_radix(u128::MAX, Radix::Hex), ["FFFF"; 8].join("_"));
+    assert_eq!(f.format_radix(u128::MAX, Radix::Binary), ["1111"; 32].join("_"));
+    assert_eq!(f.format_radix(u128::MAX, Radix::Octal), format!("377_{}", ["7777"; 10].join("_"))); // 128 bits are 43 octal digits
+    assert_eq!(f.format_radix_signed(i128::MIN, Radix::Hex, 128), format!("8000_{}", ["0000"; 7].join("_")));
+    assert_eq!(f.format_radix_signed(-1, Radix::Hex, 200), ["FFFF"; 8].join("_")); // clamped to 128 bits
+}
+
+
+#[test]
+fn padding()
+{
+    let f: Formatter = Formatter::new().set_separators("\u{202f}", ",").set_radix(RadixOptions { group_size: 2, min_digits: 4, prefix: true }); // multibyte separator
+
+
+    assert_eq!(f.format_radix(0, Radix::Hex), "0x00\u{202f}00");
+    assert_eq!(f.format_radix(0xABCD, Radix::Hex), "0xAB\u{202f}CD"); // exactly the minimum
+    assert_eq!(f.format_radix(0xABCDE, Radix::Hex), "0xA\u{202f}BC\u{202f}DE");
+    assert_eq!(f.format_radix_signed(-1, Radix::Hex, 32), "0xFF\u{202f}FF\u{202f}FF\u{202f}FF"); // bit width beyond the minimum
+    assert_eq!(f.clone().set_width(10, Align::Left, '.').format_radix(0xAB, Radix::Hex), "0x00\u{202f}AB...", "width in characters");
+    assert_eq!(f.clone().set_separators("", ",").format_radix(0xABCDE, Radix::Hex), "0xABCDE"); // no group separator, no grouping
+    assert_eq!(f.clone().set_zero_pad(12, true).set_affixes("[", "]").set_unit("B").format_radix(0xAB, Radix::Hex), "0x00\u{202f}AB"); // options for decimal numbers do not apply
+}
+
+
+#[test]
+fn bits_in_type_widths()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_bits_of(0_u8, 8), "0000.0000");
+    assert_eq!(f.format_bits_of(0b1011_0010_u8, 8), "1011.0010");
+    assert_eq!(f.format_bits_of(0b1011_0010_u8, 16), "0000.0000.1011.0010"); // leading zero groups
+    assert_eq!(f.format_bits_of(0b1_0000_0000_u16, 16), "0000.0001.0000.0000");
+    assert_eq!(f.format_bits_of(u16::MAX, 16), "1111.1111.1111.1111");
+    assert_eq!(f.format_bits_of(1_u32 << 31 | 1, 32), format!("1000.{}.0001", ["0000"; 6].join(".")));
+    assert_eq!(f.format_bits_of(1_u32, 32), format!("{}.0001", ["0000"; 7].join(".")));
+    assert_eq!(f.format_bits_of(u64::MAX, 64), ["1111"; 16].join("."));
+    assert_eq!(f.format_bits_of(1_u64, 64), format!("{}.0001", ["0000"; 15].join(".")));
+    assert_eq!(f.format_bits_of(0_u64, 64), f.format_radix_signed(0, Radix::Binary, 64)); // same grouping
+    assert_eq!(f.clone().set_radix(RadixOptions { group_size: 8, ..RadixOptions::new() }).format_bits_of(0b101_u8, 32), "00000000.00000000.00000000.00000101"); // bytes
+    assert_eq!(f.set_radix(RadixOptions { min_digits: 64, ..RadixOptions::new() }).format_bits_of(1_u8, 8).len(), 64 + 15); // larger minimum
+}
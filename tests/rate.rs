@@ -0,0 +1,20 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, RateBase};
+use std::time::Duration;
+
+
+#[test]
+fn nothing_in_no_time_is_not_a_number()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_rate(0.0, Duration::ZERO), "NaN /s"); // not "∞ /s"
+    assert_eq!(f.format_rate(-0.0, Duration::ZERO), "NaN /s");
+    assert_eq!(f.format_rate(0.0, Duration::from_secs(1)), "0,000 /s");
+    assert_eq!(f.format_rate(1.0, Duration::ZERO), "∞ /s");
+    assert_eq!(f.clone().set_unit("B").format_rate(0.0, Duration::ZERO), "NaN B/s");
+    assert_eq!(f.clone().set_rate_base(RateBase::PerHour).format_rate(0.0, Duration::ZERO), "NaN /h");
+    assert_eq!(f.clone().set_special_strings("inf", "n/a").format_rate(0.0, Duration::ZERO), "n/a /s");
+}
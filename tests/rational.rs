@@ -0,0 +1,101 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Formatter, Sign};
+
+
+/// # Summary
+/// Derives the reduced fraction of an f64 from its bit pattern, for numbers whose numerator and denominator fit into u128.
+///
+/// # Arguments
+/// - `x`: finite number
+///
+/// # Returns
+/// - numerator and denominator, or none if either is beyond u128
+fn fraction(x: f64) -> Option<(u128, u128)>
+{
+    let bits: u64 = x.to_bits();
+    let mut exponent: i32 = (bits >> 52 & 0x7FF) as i32; // binary exponent of the significand
+    let mut significand: u128 = (bits & ((1 << 52) - 1)) as u128;
+
+
+    if exponent == 0
+    // subnormal
+    {
+        exponent = -1074;
+    }
+    else
+    {
+        significand |= 1 << 52;
+        exponent -= 1075;
+    }
+    while significand != 0 && significand.is_multiple_of(2) && exponent < 0
+    {
+        significand /= 2;
+        exponent += 1;
+    }
+
+    return match exponent
+    {
+        _ if significand == 0 => Some((0, 1)),
+        0..=74 => Some((significand << exponent, 1)),
+        -127..=-1 => Some((significand, 1 << -exponent)),
+        _ => None,
+    };
+}
+
+
+#[test]
+fn bit_patterns()
+{
+    let f: Formatter = Formatter::new().set_separators("", ",");
+    let mut bits: u64 = 0x3FF0_0000_0000_0001; // pseudo-random bit patterns
+    let mut checked: usize = 0; // number of patterns whose fraction fits into u128
+
+
+    for _ in 0..20_000
+    {
+        bits = bits.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let x: f64 = f64::from_bits(bits & 0x83FF_FFFF_FFFF_FFFF | 0x3C00_0000_0000_0000); // exponents around 0
+        if let Some((numerator, denominator)) = fraction(x)
+        {
+            let sign: &str = if x < 0.0 { "-" } else { "" };
+            assert_eq!(f.format_rational(x), if denominator == 1 { format!("{sign}{numerator}") } else { format!("{sign}{numerator} / {denominator}") }, "{x:e}");
+            checked += 1;
+        }
+    }
+    assert!(10_000 < checked);
+}
+
+
+#[test]
+fn exact_values()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_rational(0.1), "3.602.879.701.896.397 / 36.028.797.018.963.968");
+    assert_eq!(f.format_rational(1.0 / 3.0), "6.004.799.503.160.661 / 18.014.398.509.481.984");
+    assert_eq!(f.format_rational(-0.75), "-3 / 4");
+    assert_eq!(f.format_rational(f64::MIN_POSITIVE), "1 / 44.942.328.371.557.897.693.232.629.769.725.618.340.449.424.473.557.664.318.357.520.289.433.168.951.375.240.783.177.119.330.601.884.005.280.028.469.967.848.339.414.697.442.203.604.155.623.211.857.659.868.531.094.441.973.356.216.371.319.075.554.900.311.523.529.863.270.738.021.251.442.209.537.670.585.615.720.368.478.277.635.206.809.290.837.627.671.146.574.559.986.811.484.619.929.076.208.839.082.406.056.034.304"); // 2^(-1022)
+    assert_eq!(f.format_rational(f64::from_bits(3)), "3 / 202.402.253.307.310.618.352.495.346.718.917.307.049.556.649.764.142.118.356.901.358.027.430.339.567.995.346.891.960.383.701.437.124.495.187.077.864.316.811.911.389.808.737.385.793.476.867.013.399.940.738.509.921.517.424.276.566.361.364.466.907.742.093.216.341.239.767.678.472.745.068.562.007.483.424.692.698.618.103.355.649.159.556.340.810.056.512.358.769.552.333.414.615.230.502.532.186.327.508.646.006.263.307.707.741.093.494.784"); // subnormal, 3 * 2^(-1074)
+    assert_eq!(f.format_rational(f64::MAX), f.format_exact(f64::MAX)); // whole, denominator 1
+    assert_eq!(f.format_rational(-f64::MAX), f.format_exact(-f64::MAX));
+    assert_eq!(f.format_rational(1e23), "99.999.999.999.999.991.611.392");
+}
+
+
+#[test]
+fn zero_signs_and_special_values()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_rational(0.0), "0");
+    assert_eq!(f.format_rational(-0.0), "0");
+    assert_eq!(f.clone().set_sign(Sign::Always).format_rational(0.5), "+1 / 2");
+    assert_eq!(f.clone().set_sign(Sign::ExceptZero).format_rational(-0.0), "0");
+    assert_eq!(f.clone().set_width(10, Align::Right, '_').format_rational(-0.5), "____-1 / 2");
+    assert_eq!(f.format_rational(f64::INFINITY), "∞");
+    assert_eq!(f.format_rational(f64::NEG_INFINITY), "-∞");
+    assert_eq!(f.format_rational(f64::NAN), "NaN");
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, RomanError, RomanLarge, RomanOptions};
+
+
+#[test]
+fn boundaries()
+{
+    let additive: Formatter = Formatter::new().set_roman(RomanOptions { additive: true, ..RomanOptions::new() });
+    let cases: [(i64, &str, &str); 20] = [
+        (1, "I", "I"),
+        (3, "III", "III"),
+        (4, "IV", "IIII"),
+        (5, "V", "V"),
+        (8, "VIII", "VIII"),
+        (9, "IX", "VIIII"),
+        (10, "X", "X"),
+        (14, "XIV", "XIIII"),
+        (40, "XL", "XXXX"),
+        (49, "XLIX", "XXXXVIIII"),
+        (50, "L", "L"),
+        (90, "XC", "LXXXX"),
+        (99, "XCIX", "LXXXXVIIII"),
+        (400, "CD", "CCCC"),
+        (500, "D", "D"),
+        (900, "CM", "DCCCC"),
+        (1444, "MCDXLIV", "MCCCCXXXXIIII"),
+        (1994, "MCMXCIV", "MDCCCCLXXXXIIII"),
+        (2024, "MMXXIV", "MMXXIIII"),
+        (3999, "MMMCMXCIX", "MMMDCCCCLXXXXVIIII"),
+    ]; // number, subtractive, and additive numeral
+    let f: Formatter = Formatter::new();
+    let lowercase: Formatter = Formatter::new().set_roman(RomanOptions { lowercase: true, ..RomanOptions::new() });
+
+
+    for (n, subtractive_numeral, additive_numeral) in cases
+    {
+        assert_eq!(f.format_roman(n), Ok(subtractive_numeral.to_string()), "{n}");
+        assert_eq!(additive.format_roman(n), Ok(additive_numeral.to_string()), "{n} additive");
+        assert_eq!(lowercase.format_roman(n), Ok(subtractive_numeral.to_lowercase()), "{n} lowercase");
+    }
+}
+
+
+#[test]
+fn out_of_range()
+{
+    let overline: Formatter = Formatter::new().set_roman(RomanOptions { large: RomanLarge::Overline, ..RomanOptions::new() });
+
+
+    for n in [0, -1, -3999, 4000, i64::MAX, i64::MIN]
+    {
+        assert_eq!(Formatter::new().format_roman(n), Err(RomanError::OutOfRange), "{n}");
+    }
+    for n in [0, -1, 4_000_000, i64::MAX]
+    {
+        assert_eq!(overline.format_roman(n), Err(RomanError::OutOfRange), "{n} with overline");
+    }
+    assert_eq!(RomanError::OutOfRange.to_string(), "Number has no Roman numeral.");
+}
+
+
+#[test]
+fn large_numbers()
+{
+    let overline: Formatter = Formatter::new().set_roman(RomanOptions { large: RomanLarge::Overline, ..RomanOptions::new() });
+    let parentheses: Formatter = Formatter::new().set_roman(RomanOptions { large: RomanLarge::Parentheses, ..RomanOptions::new() });
+
+
+    assert_eq!(overline.format_roman(3999), Ok("MMMCMXCIX".to_string())); // no overline needed
+    assert_eq!(overline.format_roman(4000), Ok("I\u{0305}V\u{0305}".to_string()));
+    assert_eq!(overline.format_roman(4001), Ok("I\u{0305}V\u{0305}I".to_string()));
+    assert_eq!(overline.format_roman(10_900), Ok("X\u{0305}CM".to_string()));
+    assert_eq!(overline.format_roman(3_999_999), Ok("M\u{0305}M\u{0305}M\u{0305}C\u{0305}M\u{0305}X\u{0305}C\u{0305}I\u{0305}X\u{0305}CMXCIX".to_string()));
+    assert_eq!(parentheses.format_roman(4000), Ok("(IV)".to_string()));
+    assert_eq!(parentheses.format_roman(1_000_000), Ok("(M)".to_string()));
+    assert_eq!(parentheses.format_roman(3_999_999), Ok("(MMMCMXCIX)CMXCIX".to_string()));
+    assert_eq!(Formatter::new().set_roman(RomanOptions { additive: true, large: RomanLarge::Parentheses, lowercase: true }).format_roman(4009), Ok("(iiii)viiii".to_string()));
+}
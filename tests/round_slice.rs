@@ -0,0 +1,94 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::round::{round_slice_mag, round_slice_mag_to, round_slice_sig, round_slice_sig_to, Round};
+
+
+/// # Summary
+/// Numbers of every magnitude and both signs from pseudo-random bit patterns, with 0, -0, subnormals, infinity, and NaN in between.
+///
+/// # Returns
+/// - the numbers
+fn values() -> Vec<f64>
+{
+    let mut bits: u64 = 7; // pseudo-random bit patterns
+    let mut values: Vec<f64> = vec![0.0, -0.0, f64::from_bits(1), -f64::MAX, f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 0.5, 2.5, 0.125, 1e23];
+
+
+    for _ in 0..20_000
+    {
+        bits = bits.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        values.push(f64::from_bits(bits));
+    }
+    return values;
+}
+
+
+/// # Summary
+/// Asserts that 2 slices hold the same numbers bit for bit, so NaN equals NaN and -0 differs from 0.
+///
+/// # Arguments
+/// - `actual`: the rounded numbers
+/// - `expected`: the numbers they should be
+fn assert_bits_eq(actual: &[f64], expected: &[f64])
+{
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected)
+    {
+        assert_eq!(a.to_bits(), e.to_bits(), "{a:e} instead of {e:e}");
+    }
+}
+
+
+#[test]
+fn identical_to_scalar_methods()
+{
+    let mut dst: Vec<f64> = vec![1.0; 100_000]; // reused, content replaced
+    let values: Vec<f64> = values();
+
+
+    for magnitude in [i16::MIN, -400, -324, -30, -22, -5, -1, 0, 1, 3, 22, 23, 308, 400, i16::MAX]
+    {
+        let expected: Vec<f64> = values.iter().map(|x| x.round_mag(magnitude)).collect();
+        let mut in_place: Vec<f64> = values.clone();
+
+        round_slice_mag(&mut in_place, magnitude);
+        round_slice_mag_to(&mut dst, &values, magnitude);
+        assert_bits_eq(&in_place, &expected);
+        assert_bits_eq(&dst, &expected);
+    }
+    for significants in [1, 2, 4, 15, 17, 30, u8::MAX]
+    {
+        let expected: Vec<f64> = values.iter().map(|x| x.round_sig(significants)).collect();
+        let mut in_place: Vec<f64> = values.clone();
+
+        round_slice_sig(&mut in_place, significants);
+        round_slice_sig_to(&mut dst, &values, significants);
+        assert_bits_eq(&in_place, &expected);
+        assert_bits_eq(&dst, &expected);
+    }
+}
+
+
+#[test]
+fn special_values_unchanged()
+{
+    let mut dst: Vec<f64> = Vec::new();
+    let mut values: [f64; 5] = [f64::NAN, f64::INFINITY, 1.5, f64::NEG_INFINITY, -f64::NAN];
+    let src: [f64; 5] = values;
+
+
+    round_slice_sig(&mut values, 0); // unlike the scalar method, NaN stays NaN
+    assert_bits_eq(&values, &[f64::NAN, f64::INFINITY, 0.0, f64::NEG_INFINITY, -f64::NAN]);
+    round_slice_sig_to(&mut dst, &src, 0);
+    assert_bits_eq(&dst, &values);
+
+    values = src;
+    round_slice_mag(&mut values, 0);
+    assert_bits_eq(&values, &[f64::NAN, f64::INFINITY, 2.0, f64::NEG_INFINITY, -f64::NAN]);
+    round_slice_mag_to(&mut dst, &src, 0);
+    assert_bits_eq(&dst, &values);
+
+    round_slice_mag_to(&mut dst, &[], 0);
+    assert!(dst.is_empty());
+    round_slice_sig(&mut [], 3);
+}
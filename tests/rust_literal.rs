@@ -0,0 +1,63 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Radix, RadixOptions};
+
+
+#[test]
+fn float_literals_parse_back()
+{
+    let f: Formatter = Formatter::new();
+    let mut bits: u64 = 42; // pseudo-random bit patterns of every magnitude
+
+
+    for _ in 0..100_000
+    {
+        bits = bits.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let x: f64 = f64::from_bits(bits);
+        let literal: String = f.format_rust_literal(x);
+        if !x.is_finite()
+        {
+            continue;
+        }
+        let parsed: f64 = literal.strip_suffix("_f64").expect("Suffix is missing.").replace('_', "").parse::<f64>().unwrap_or_else(|e| panic!("{literal:?} does not parse: {e}"));
+        assert_eq!(parsed.to_bits(), x.to_bits(), "{literal:?} parses to {parsed:e} instead of {x:e}");
+        assert!(literal.contains('.') || literal.contains('e'), "{literal:?} is no float literal");
+        assert!(!literal.contains("__") && !literal.contains("_.") && !literal.contains("_e"), "{literal:?} has misplaced underscores");
+    }
+}
+
+
+#[test]
+fn float_literal_edge_cases()
+{
+    let f: Formatter = Formatter::new().set_separators(" ", ",").set_affixes("$", "").set_unit("B");
+
+
+    assert_eq!(f.format_rust_literal(0.0), "0.0_f64"); // cosmetic options do not apply
+    assert_eq!(f.format_rust_literal(999.0), "999.0_f64");
+    assert_eq!(f.format_rust_literal(1000.0), "1_000.0_f64");
+    assert_eq!(f.format_rust_literal(123456.789), "123_456.789_f64");
+    assert_eq!(f.format_rust_literal(9999999999999998.0), "9_999_999_999_999_998.0_f64"); // largest without exponent
+    assert_eq!(f.format_rust_literal(1e-4), "0.0001_f64");
+    assert_eq!(f.format_rust_literal(-1e-5), "-1e-5_f64");
+    assert_eq!(f.format_rust_literal(f64::from_bits(1)), "5e-324_f64"); // smallest subnormal
+    assert_eq!(f.format_rust_literal(f64::INFINITY), "f64::INFINITY");
+    assert_eq!(f.format_rust_literal(-f64::NAN), "f64::NAN");
+}
+
+
+#[test]
+fn integer_literals()
+{
+    let f: Formatter = Formatter::new().set_width(30, scaler::Align::Right, '*');
+
+
+    assert_eq!(f.format_rust_literal_int(0_u8), "0_u8");
+    assert_eq!(f.format_rust_literal_int(i128::MIN), "-170_141_183_460_469_231_731_687_303_715_884_105_728_i128");
+    assert_eq!(f.format_rust_literal_int(-100_isize), "-100_isize");
+    assert_eq!(f.format_rust_literal_int(123_456_i32), "123_456_i32");
+    assert_eq!(f.format_rust_literal_radix(0xDEAD_BEEF, Radix::HexLower), "0xdead_beef");
+    assert_eq!(f.format_rust_literal_radix(0, Radix::Hex), "0x0");
+    assert_eq!(f.clone().set_radix(RadixOptions { group_size: 0, ..RadixOptions::new() }).format_rust_literal_radix(0xDEAD_BEEF, Radix::Hex), "0xDEADBEEF"); // no grouping
+    assert_eq!(f.set_rust_literal_suffix(false).format_rust_literal_int(-12_345_i64), "-12_345");
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Rounding, ScaledValue, Scaling};
+
+
+#[test]
+fn agrees_with_format()
+{
+    for f in [
+        Formatter::new(),
+        Formatter::new().set_scaling(Scaling::Binary(true)).set_unit("B"),
+        Formatter::new().set_scaling(Scaling::Decimal(false)).set_rounding(Rounding::Magnitude(-2)),
+        Formatter::new().set_scaling(Scaling::Scientific).set_trailing_zeros(false),
+        Formatter::new().set_scaling(Scaling::None).set_separators(",", "."),
+    ]
+    {
+        let mut x: f64 = 1e-40;
+
+        while x < 1e40
+        {
+            for x in [x, -x]
+            {
+                let rounded: f64 = f.parse(&f.format(x)).unwrap(); // number as displayed
+                let scaled: ScaledValue = f.format_scaled(x);
+
+                assert_eq!(scaled.to_string(), f.format(x), "{x}");
+                assert!(scaled.prefix.is_empty() || scaled.exponent_fallback.is_none(), "{x}: unit prefix and exponent at once");
+                assert!((scaled.mantissa * scaled.factor - rounded).abs() <= rounded.abs() * 1e-12, "{x}: {} * {} is not {rounded}", scaled.mantissa, scaled.factor);
+            }
+            x *= 7.3;
+        }
+    }
+}
+
+
+#[test]
+fn special_values()
+{
+    let f: Formatter = Formatter::new().set_unit("B");
+
+
+    for x in [f64::INFINITY, f64::NEG_INFINITY]
+    {
+        let scaled: ScaledValue = f.format_scaled(x);
+        assert_eq!((scaled.mantissa, scaled.factor, scaled.prefix, scaled.exponent_fallback), (x, 1.0, "", None));
+        assert_eq!(scaled.to_string(), f.format(x));
+    }
+    assert!(f.format_scaled(f64::NAN).mantissa.is_nan());
+    assert_eq!(f.format_scaled(0).mantissa, 0.0);
+}
@@ -0,0 +1,259 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+#![cfg(feature = "serde")]
+use scaler::{Align, CjkOptions, CommonScale, ComplexNotation, DeltaParts, Fill, Formatter, FormatterOverrides, FractionOptions, HexfloatOptions, Locale, NonFinitePolicy, PercentOfOptions, RadixOptions, RateBase, RomanLarge, RomanOptions, Rounding, Scaling, ShowOriginal, Sign, SignPlacement, SignPosition, UncertaintyNotation, WordsFraction, WordsOptions};
+
+
+/// # Summary
+/// Formatters covering every variant of every option at least once.
+fn formatters() -> Vec<Formatter>
+{
+    return vec![
+        Formatter::new(),
+        Formatter::new()
+            .set_affixes("[", "]")
+            .set_width(10, Align::Left, ' ')
+            .set_common_scale(CommonScale::Maximum)
+            .set_complex_notation(ComplexNotation::Polar, "j")
+            .set_rounding(Rounding::Magnitude(-2))
+            .set_scaling(Scaling::Binary(false))
+            .set_separators(",", ".")
+            .set_sign(Sign::Always)
+            .set_unit("B"),
+        Formatter::new()
+            .set_auto_precision_max(2)
+            .set_cjk(CjkOptions { financial: true, spelled_minus: true })
+            .set_clamp(Some(-1.0), Some(1e15))
+            .set_clamp_markers("▼", "▲")
+            .set_common_scale(CommonScale::Median)
+            .set_delta(DeltaParts::Percent, 2)
+            .set_display_ceiling(Some(1e12))
+            .set_display_floor(Some(1e-3))
+            .set_threshold_markers("≤ ", "≥ ")
+            .set_exact_max_digits(40)
+            .set_fallback_rounding(Some(Rounding::SignificantDigits(2)))
+            .set_min_integer_digits(3)
+            .set_non_finite_policy(NonFinitePolicy::Error)
+            .set_none_string("n/a")
+            .set_percent_of(PercentOfOptions { clamp: false, zero_of_zero_placeholder: true })
+            .set_radix(RadixOptions { group_size: 8, min_digits: 16, prefix: true })
+            .set_range_separator(" to ", true)
+            .set_rate_base(RateBase::Auto)
+            .set_roman(RomanOptions { additive: true, large: RomanLarge::Parentheses, lowercase: true })
+            .set_rounding(Rounding::SignificantDigits(2))
+            .set_rust_literal_suffix(false)
+            .set_scaling(Scaling::Scientific)
+            .set_show_original(ShowOriginal::Grouped, false)
+            .set_sign(Sign::SpaceForPositive)
+            .set_sign_placement(SignPlacement::Trailing)
+            .set_special_strings("inf", "nan")
+            .set_suppress_unit_mantissa(true)
+            .set_trailing_zeros(false)
+            .set_uncertainty_notation(UncertaintyNotation::Concise, "+/-")
+            .set_unit_plural("byte", "bytes")
+            .set_words(WordsOptions { and_after_hundreds: true, fraction: WordsFraction::Fraction, hyphenate: false }),
+        Formatter::new()
+            .set_fraction(FractionOptions { glyphs: true, mixed: false })
+            .set_hexfloat(HexfloatOptions { all_digits: true, uppercase: true })
+            .set_machine_readable(true)
+            .set_rounding(Rounding::Magnitude(3))
+            .set_scaling(Scaling::None)
+            .set_separators("", ",")
+            .set_sign_position(SignPosition::BeforeAffix)
+            .set_sortable_digits(6, 2)
+            .set_width(12, Align::Center, '*')
+            .set_zero_epsilon(1e-12)
+            .set_zero_exponent(true)
+            .set_zero_pad(10, true),
+        Formatter::new().set_overflow_marker('#').set_scaling(Scaling::Decimal(false)).set_sign(Sign::ExceptZero).set_width(4, Align::Right, ' '),
+        Formatter::new().set_scaling(Scaling::Exponential { base: 60, step: 1, prefixes: None }),
+        Formatter::new().set_reserve_prefix_width(true).set_scaling(Scaling::Binary(true)),
+        Formatter::new().set_fill_mode(Fill::FigureSpace).set_width(8, Align::Right, ' '),
+        Formatter::new().set_locale(Locale::JaJp).set_scaling(Scaling::CompactLocalized),
+        Formatter::new().set_scaling(Scaling::Percent(true)).set_sign_placement(scaler::SignPlacement::Parentheses),
+    ];
+}
+
+
+#[test]
+fn round_trip_json()
+{
+    for f in formatters()
+    {
+        let json: String = serde_json::to_string(&f).unwrap(); // serialized formatter
+
+        assert_eq!(serde_json::from_str::<Formatter>(&json).unwrap(), f, "Formatter serialized as {json} changed in the round trip.");
+    }
+}
+
+
+#[test]
+fn round_trip_toml()
+{
+    for f in formatters()
+    {
+        let toml: String = toml::to_string(&f).unwrap(); // serialized formatter
+
+        assert_eq!(toml::from_str::<Formatter>(&toml).unwrap(), f, "Formatter serialized as\n{toml}\nchanged in the round trip.");
+    }
+}
+
+
+#[test]
+fn representation()
+{
+    let json: serde_json::Value = serde_json::to_value(Formatter::new().set_rounding(Rounding::SignificantDigits(4)).set_scaling(Scaling::Binary(false))).unwrap(); // serialized formatter
+
+
+    assert_eq!(json["decimal_separator"], ",");
+    assert_eq!(json["group_separator"], ".");
+    assert_eq!(json["rounding"], serde_json::json!({"significant_digits": 4}));
+    assert_eq!(json["scaling"], serde_json::json!({"binary": {"space": false}}));
+    assert_eq!(json["sign"], "only_minus");
+    assert_eq!(json["sign_placement"], "leading");
+    assert!(json.get("prefix_override").is_none());
+}
+
+
+#[test]
+fn rejects_invalid()
+{
+    for (json, error) in [
+        (r#"{"decimal_separator": ""}"#, "Decimal separator is empty. This may lead to ambiguous formatting."),
+        (r#"{"decimal_separator": ".", "group_separator": "."}"#, "Group separator \".\" and decimal separator \".\" are the same. This may lead to ambiguous formatting."),
+        (r#"{"decimal_separator": "0"}"#, "Decimal separator \"0\" contains a digit. This may lead to ambiguous formatting."),
+        (r#"{"group_separator": "'1"}"#, "Group separator \"'1\" contains a digit. This may lead to ambiguous formatting."),
+        (r#"{"zero_epsilon": -1e-12}"#, "Epsilon below which numbers are formatted as 0 is negative, infinite, or not a number, so it snaps either nothing or every number."),
+        (r#"{"clamp_min": 1.0, "clamp_max": 0.0}"#, "Minimum of the clamp range is above its maximum, so every number would be displayed as the maximum."),
+    ]
+    {
+        assert_eq!(serde_json::from_str::<Formatter>(json).err().map(|e| e.to_string()).as_deref(), Some(error), "{json} was not rejected as expected.");
+    }
+    assert!(toml::from_str::<Formatter>("precision = 3").is_err(), "Unknown field was accepted.");
+    assert!(toml::from_str::<Formatter>("scaling = { decimal = { spaces = true } }").is_err(), "Unknown field in scaling was accepted.");
+    assert!(toml::from_str::<Formatter>("rounding = { significant = 4 }").is_err(), "Unknown rounding was accepted.");
+}
+
+
+/// # Summary
+/// Selects a binary formatter for `as_scaled_with`, without unit, so `from_scaled` can read it back.
+struct Binary;
+
+impl scaler::serde::SelectFormatter for Binary
+{
+    fn formatter() -> &'static Formatter
+    {
+        static F: Formatter = Formatter::new().set_scaling(Scaling::Binary(true));
+        return &F;
+    }
+}
+
+
+/// # Summary
+/// Report with every serialize helper and the matching deserialize helper.
+#[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+struct Report
+{
+    #[serde(serialize_with = "scaler::serde::as_scaled", deserialize_with = "scaler::serde::from_scaled")]
+    rate:       f64,
+    #[serde(serialize_with = "scaler::serde::as_scaled_with::<Binary, _>", deserialize_with = "scaler::serde::from_scaled")]
+    size:       f64,
+    #[serde(default, serialize_with = "scaler::serde::as_scaled_opt", deserialize_with = "scaler::serde::from_scaled_opt")]
+    limit:      Option<f64>,
+    #[serde(serialize_with = "scaler::serde::as_scaled_opt_with::<Binary, _>", deserialize_with = "scaler::serde::from_scaled_opt")]
+    quota:      Option<f64>,
+    #[serde(serialize_with = "scaler::serde::as_scaled_vec", deserialize_with = "scaler::serde::from_scaled_vec")]
+    latencies:  Vec<f64>,
+    #[serde(serialize_with = "scaler::serde::as_scaled_vec_with::<Binary, _>", deserialize_with = "scaler::serde::from_scaled_vec")]
+    chunks:     Vec<f64>,
+}
+
+
+#[test]
+fn scaled_representation()
+{
+    let report: Report = Report {
+        rate:      1234567.0,
+        size:      1536.0,
+        limit:     None,
+        quota:     Some(1048576.0),
+        latencies: vec![0.0123, f64::INFINITY, f64::NAN],
+        chunks:    vec![],
+    }; // report to serialize
+
+
+    assert_eq!(
+        serde_json::to_value(&report).unwrap(),
+        serde_json::json!({"rate": "1,235 M", "size": "1,500 Ki", "limit": null, "quota": "1,000 Mi", "latencies": ["12,30 m", "∞", "NaN"], "chunks": []})
+    );
+}
+
+
+#[test]
+fn scaled_round_trip()
+{
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d; // xorshift state
+    let mut x: Vec<f64> = vec![0.0, -0.0, 1.0, -1.0, f64::INFINITY, f64::NEG_INFINITY, 999.95, 1e-24, 1e24]; // numbers to round trip
+
+
+    for _ in 0..2000
+    {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        x.push((state >> 11) as f64 / (1_u64 << 53) as f64 * 10_f64.powi((state % 48) as i32 - 24) * if state & 1 == 0 { 1.0 } else { -1.0 });
+    }
+
+    for x in x
+    {
+        let report: Report = Report { rate: x, size: x, limit: Some(x), quota: Some(x), latencies: vec![x], chunks: vec![x, x] }; // report to round trip
+        let json: String = serde_json::to_string(&report).unwrap(); // serialized report
+        let back: Report = serde_json::from_str(&json).unwrap(); // deserialized report
+
+        for (y, tolerance) in [(back.rate, 5e-4), (back.size, 1e-3), (back.limit.unwrap(), 5e-4), (back.quota.unwrap(), 1e-3), (back.latencies[0], 5e-4), (back.chunks[1], 1e-3)]
+        // 4 significant digits, binary scaling rounds twice, to decimal significant digits first and then the mantissa
+        {
+            assert!(x == y || (x - y).abs() <= tolerance * x.abs(), "{x} serialized as {json} deserialized to {y}, outside of the rounding tolerance.");
+        }
+    }
+    assert!(serde_json::from_str::<Report>(r#"{"rate": "NaN", "size": 0, "quota": null, "latencies": [], "chunks": []}"#).unwrap().rate.is_nan());
+}
+
+
+#[test]
+fn overrides_layered_from_toml()
+{
+    let defaults: FormatterOverrides = toml::from_str(
+        r#"
+        decimal_separator = "."
+        group_separator = ","
+        significant_digits = 3
+        unit = "byte"
+        unit_plural = "bytes"
+    "#,
+    )
+    .unwrap();
+    let settings: FormatterOverrides = toml::from_str("decimals = 2\nscaling = { binary = { space = true } }\nunit = \"B\"").unwrap();
+    let widget: FormatterOverrides = toml::from_str("decimals = 0\nsign = \"always\"").unwrap();
+    let merged: FormatterOverrides = defaults.clone().merge(settings.clone()).merge(widget);
+
+
+    assert_eq!(defaults.rounding, Some(Rounding::SignificantDigits(3)));
+    assert_eq!(settings.rounding, Some(Rounding::Magnitude(-2)));
+    assert_eq!(merged.rounding, Some(Rounding::Magnitude(0)));
+    assert_eq!(merged.scaling, Some(Scaling::Binary(true)));
+    assert_eq!(merged.sign, Some(Sign::Always));
+    assert_eq!(merged.unit.as_deref(), Some("B"));
+    assert_eq!(merged.unit_plural, None);
+    assert_eq!(merged.decimal_separator.as_deref(), Some("."));
+    assert_eq!(Formatter::new().apply(&merged).format(1536), "+1.50 KiB"); // rounded before scaling
+    assert_eq!(toml::from_str::<FormatterOverrides>("").unwrap(), FormatterOverrides::default()); // all optional
+
+    for overrides in [defaults, settings, merged, FormatterOverrides { rounding: Some(Rounding::Magnitude(3)), ..Default::default() }]
+    {
+        assert_eq!(toml::from_str::<FormatterOverrides>(&toml::to_string(&overrides).unwrap()).unwrap(), overrides); // round trip
+        assert_eq!(serde_json::from_str::<FormatterOverrides>(&serde_json::to_string(&overrides).unwrap()).unwrap(), overrides);
+    }
+    assert!(toml::from_str::<FormatterOverrides>("decimals = 2\nrounding = { magnitude = -2 }").is_err()); // rounding given twice
+    assert!(toml::from_str::<FormatterOverrides>("decimal_separator = \",\"\ngroup_separator = \",\"").is_err()); // equal separators
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::round::{add_sig, div_sig, mul_sig, sub_sig};
+use scaler::{Formatter, Scaling};
+
+
+#[test]
+fn textbook_addition()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::None);
+    let sum: (f64, u8) = add_sig(add_sig((12.11, 4), (18.0, 3)), (1.013, 4)); // 31.123, tenths are the least precise place
+
+
+    assert_eq!(sum, (31.1, 3));
+    assert_eq!(f.format_sig(sum.0, sum.1), "31,1");
+    assert_eq!(add_sig((1.013, 4), add_sig((12.11, 4), (18.0, 3))), (31.1, 3)); // commutative here
+    assert_eq!(add_sig((83.5, 3), (23.28, 4)), (106.8, 4)); // 106.78
+    assert_eq!(add_sig((0.0045, 2), (0.05, 1)), (0.05, 1)); // 0.0545 to hundredths
+    assert_eq!(add_sig((150.0, 2), (0.5, 1)), (150.0, 2)); // tens, ties to even
+    assert_eq!(add_sig((f64::INFINITY, 3), (1.0, 2)), (f64::INFINITY, 2));
+    assert!(add_sig((f64::NAN, 3), (1.0, 2)).0.is_nan());
+}
+
+
+#[test]
+fn textbook_subtraction()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::None);
+    let difference: (f64, u8) = sub_sig((1.0, 2), (1.0, 2));
+
+
+    assert_eq!(sub_sig((13.72, 4), (1.5, 2)), (12.2, 3)); // 12.22
+    assert_eq!(sub_sig((25.624, 5), (25.1, 3)), (0.5, 1)); // cancellation
+    assert_eq!(sub_sig((1.0, 2), (2.05, 3)), (-1.0, 2)); // -1.05 to tenths, ties to even
+    assert_eq!(difference, (0.0, 2));
+    assert_eq!(f.format_sig(difference.0, difference.1), "0,0");
+}
+
+
+#[test]
+fn textbook_multiplication_and_division()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::None);
+    let product: (f64, u8) = mul_sig((4.56, 3), (1.4, 2)); // 6.384
+
+
+    assert_eq!(product, (6.4, 2));
+    assert_eq!(f.format_sig(product.0, product.1), "6,4");
+    assert_eq!(mul_sig((2.45, 3), (2.3, 2)), (5.6, 2)); // 5.635
+    assert_eq!(mul_sig((12.0, 3), (3.0, 1)), (40.0, 1)); // 36
+    assert_eq!(f.format_sig(40.0, 1), "40");
+    assert_eq!(div_sig((6.022e23, 4), (2.0, 1)), (3e23, 1));
+    assert_eq!(div_sig((25.624, 5), (0.0250, 3)), (1020.0, 3)); // 1024.96
+    assert_eq!(f.format_sig(1020.0, 3), "1.020");
+    assert_eq!(div_sig((1.0, 3), (0.0, 3)), (f64::INFINITY, 3));
+    assert!(div_sig((0.0, 3), (0.0, 3)).0.is_nan());
+}
+
+
+#[test]
+fn format_sig_ignores_default_rounding()
+{
+    let f: Formatter = Formatter::new().set_rounding(scaler::Rounding::Magnitude(-5));
+
+
+    assert_eq!(f.format_sig(31.1, 3), "31,1");
+    assert_eq!(f.format_sig(0.0050, 2), "5,0 m");
+    assert_eq!(f.format_sig(-123456.0, 2), "-120 k");
+    assert_eq!(f.clone().set_trailing_zeros(false).format_sig(6.0, 3), "6");
+    assert_eq!(f.format_sig(f64::NAN, 3), "NaN");
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, SpecError, SpecErrorKind};
+
+
+/// specifications and how they format 123456.789
+const FORMATTED: [(&str, &str); 24] = [
+    ("", "123,5 k"),
+    (",.4s", "123,5 k"),
+    ("s", "123,5 k"),
+    ("S", "123,5k"),
+    (".2s", "120 k"),
+    (".6~s", "123,457 k"),
+    (".9~s", "123,456789 k"),
+    ("b", "120,6 Ki"),
+    ("B", "120,6Ki"),
+    (".3e", "1,23 * 10^(5)"),
+    ("n", "123500"),
+    (",n", "123.500"),
+    (":-2n", "123456,79"),
+    (",:-2n", "123.456,79"),
+    (",:-4~n", "123.456,789"),
+    (",:3n", "123.000"),
+    ("+", "+123,5 k"),
+    ("-", "123,5 k"),
+    (" ", " 123,5 k"),
+    ("10", "   123,5 k"),
+    ("<10", "123,5 k   "),
+    ("*^11", "**123,5 k**"),
+    ("012:-2n", "000123456,79"),
+    ("012,:-2n", "0.123.456,79"),
+];
+
+
+#[test]
+fn formats_as_specified()
+{
+    for (spec, expected) in FORMATTED
+    {
+        let f: Formatter = Formatter::from_spec(spec).unwrap(); // formatter under test
+
+        assert_eq!(f.format(123456.789), expected, "Specification {spec:?} formats differently than expected.");
+        assert_eq!(Formatter::from_spec(&f.to_spec()), Ok(f.clone()), "Specification {spec:?} does not round trip through {:?}.", f.to_spec());
+    }
+}
+
+
+#[test]
+fn errors_point_at_offending_character()
+{
+    for (spec, kind, position) in [
+        ("x", SpecErrorKind::UnexpectedChar('x'), 0),
+        ("s+", SpecErrorKind::UnexpectedChar('+'), 1),
+        ("+-", SpecErrorKind::UnexpectedChar('-'), 1),
+        ("10.4e~", SpecErrorKind::UnexpectedChar('~'), 5),
+        ("~.4", SpecErrorKind::UnexpectedChar('.'), 1),
+        ("€^10.4x", SpecErrorKind::UnexpectedChar('x'), 8), // byte index after multibyte fill
+        (".", SpecErrorKind::MissingDigits, 1),
+        (":-", SpecErrorKind::MissingDigits, 1),
+        ("+10,.~", SpecErrorKind::MissingDigits, 5),
+        (".256", SpecErrorKind::Overflow, 1),
+        (":-40000", SpecErrorKind::Overflow, 1),
+        ("99999999999999999999999", SpecErrorKind::Overflow, 0),
+    ]
+    {
+        assert_eq!(Formatter::from_spec(spec), Err(SpecError { kind, position }), "Specification {spec:?} is not rejected as expected.");
+    }
+}
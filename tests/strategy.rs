@@ -0,0 +1,131 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Rounding, ScaleChoice, ScaleStrategy, Scaling};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+
+/// SI unit prefixes from 10^(-30) to 10^(30) in steps of 10^(3)
+const PREFIXES: [&str; 21] = ["q", "r", "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+
+
+/// # Summary
+/// Reimplements `Scaling::Decimal` as strategy: SI unit prefixes, beyond them scientific notation.
+#[derive(Debug)]
+struct Decimal
+{
+    space: bool, // whether or not to put space between number and unit prefix
+}
+
+
+impl ScaleStrategy for Decimal
+{
+    fn choose(&self, x: f64) -> ScaleChoice
+    {
+        let magnitude: i64 = if x == 0.0 { 0 } else { x.abs().log10().floor() as i64 }; // decimal magnitude of the rounded number
+        let space: &str = if self.space { " " } else { "" };
+
+
+        if (-30..33).contains(&magnitude)
+        // unit prefix
+        {
+            let lower: i64 = magnitude.div_euclid(3) * 3; // magnitude of the unit prefix
+            let prefix: &str = PREFIXES[(lower / 3 + 10) as usize];
+            return ScaleChoice { decimals_hint: None, divisor: 10_f64.powf(lower as f64), suffix: if prefix.is_empty() { Cow::Borrowed("") } else { Cow::Owned(format!("{space}{prefix}")) } };
+        }
+        return ScaleChoice { decimals_hint: None, divisor: 10_f64.powf(magnitude as f64), suffix: Cow::Owned(format!(" * 10^({magnitude})")) }; // fallback to scientific notation
+    }
+
+
+    fn max_suffix_len(&self) -> usize
+    {
+        return " * 10^(-324)".len();
+    }
+}
+
+
+/// # Summary
+/// Scales by the unit the user selected last, regardless of the number.
+#[derive(Debug)]
+struct Selected
+{
+    divisor: f64,          // factor of the unit
+    suffix:  &'static str, // the unit
+}
+
+
+impl ScaleStrategy for Selected
+{
+    fn choose(&self, _x: f64) -> ScaleChoice
+    {
+        return ScaleChoice { decimals_hint: Some(1), divisor: self.divisor, suffix: Cow::Borrowed(self.suffix) };
+    }
+}
+
+
+#[test]
+fn parity_with_decimal()
+{
+    for space in [false, true]
+    {
+        for rounding in (1..=15).map(Rounding::SignificantDigits).chain((-6..=6).map(Rounding::Magnitude))
+        {
+            let decimal: Formatter = Formatter::new().set_rounding(rounding.clone()).set_scaling(Scaling::Decimal(space));
+            let strategy: Formatter = Formatter::new().set_rounding(rounding.clone()).set_scaling(Scaling::Strategy(Arc::new(Decimal { space })));
+
+            for exponent in -40..40
+            {
+                for mantissa in [1.0, 1.234567891234, 9.999999, -4.2, 5.55]
+                {
+                    let x: f64 = mantissa * 10_f64.powi(exponent);
+
+                    if let Rounding::Magnitude(m) = rounding
+                    {
+                        if x.abs().log10() - m as f64 >= 15.0
+                        // more significant digits than f64 holds, decimal scaling renders those exactly
+                        {
+                            continue;
+                        }
+                    }
+                    assert_eq!(strategy.format(x), decimal.format(x), "{x} with {rounding:?}, space {space}");
+                }
+            }
+            for x in [0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 999.95, 999.9999, 0.00099995]
+            {
+                assert_eq!(strategy.format(x), decimal.format(x), "{x} with {rounding:?}, space {space}");
+            }
+            assert_eq!(strategy.format_int(1234567_u64), decimal.format_int(1234567_u64), "{rounding:?}, space {space}");
+        }
+    }
+}
+
+
+#[test]
+fn choice()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Strategy(Arc::new(Selected { divisor: 1e3, suffix: " km" })));
+
+
+    assert_eq!(f.format(250.0), "0,2 km"); // decimal places hinted
+    assert_eq!(f.format(1234567.0), "1.234,6 km");
+    assert_eq!(f.format(f64::INFINITY), "∞");
+    assert_eq!(f.clone().set_unit("/h").format(1234567.0), "1.234,6 km/h"); // unit directly after the suffix
+    assert_eq!(f.format_scaled(1234567.0).factor, 1e3);
+    assert!(f.format(1234567.0).len() <= f.max_len());
+}
+
+
+#[test]
+fn equality()
+{
+    let strategy: Arc<Decimal> = Arc::new(Decimal { space: true });
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Strategy(strategy.clone()));
+
+
+    assert_eq!(f.clone(), f);
+    assert_eq!(Formatter::new().set_scaling(Scaling::Strategy(strategy.clone())), f); // same shared strategy
+    assert_ne!(Formatter::new().set_scaling(Scaling::Strategy(Arc::new(Decimal { space: true }))), f); // same logic, but another strategy
+    assert_ne!(Formatter::new(), f);
+    assert_eq!(Scaling::Strategy(strategy).to_string(), "strategy");
+    assert!("strategy".parse::<Scaling>().is_err());
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, Rounding, Scaling, TemplateError};
+
+
+#[test]
+fn common_group_shares_prefix_of_largest()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::SignificantDigits(3)).set_unit("B");
+
+
+    assert_eq!(f.format_template("{used:common} of {total:common}", &[("used", 512e3), ("total", 2e9)]), Ok("0,000512 GB of 2,00 GB".to_owned()));
+    assert_eq!(f.format_template("{used:common} of {total:common}", &[("used", 2e9), ("total", 512e3)]), Ok("2,00 GB of 0,000512 GB".to_owned())); // largest, not last
+    assert_eq!(f.format_template("{used:common} of {total}", &[("used", 512e3), ("total", 2e9)]), Ok("512 kB of 2,00 GB".to_owned())); // alone in its group
+    assert_eq!(f.format_template("{a:common}, {a:common}", &[("a", 1500.0)]), Ok("1,50 kB, 1,50 kB".to_owned()));
+    assert_eq!(
+        f.clone().set_scaling(Scaling::Binary(true)).format_template("{used:common}/{total:common}", &[("used", 512.0), ("total", 3145728.0)]),
+        Ok("0,000488 MiB/3,00 MiB".to_owned())
+    );
+    assert_eq!(f.format_template("{x:common} {y:common}", &[("x", 0.0), ("y", f64::NAN)]), Ok(format!("{} {}", f.format(0.0), f.format(f64::NAN)))); // nothing to choose by
+}
+
+
+#[test]
+fn mixed_styles()
+{
+    let f: Formatter = Formatter::new().set_rounding(Rounding::SignificantDigits(3)).set_unit("B");
+    let values: [(&str, f64); 4] = [("used", 850e6), ("total", 2e9), ("pct", 0.425), ("files", 12345.0)];
+
+
+    assert_eq!(
+        f.format_template("{used:common} of {total:common} ({pct:%}) used by {files} files", &values),
+        Ok("0,850 GB of 2,00 GB (42,5 %) used by 12,3 kB files".to_owned())
+    );
+    assert_eq!(f.format_template("no placeholders", &values), Ok("no placeholders".to_owned()));
+    assert_eq!(f.format_template("", &values), Ok(String::new()));
+    assert_eq!(f.clone().set_affixes("~", "").format_template("{pct:%}", &values), Ok("42,5 %".to_owned())); // percentages without affixes
+}
+
+
+#[test]
+fn escaped_braces()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_template("{{x}} = {x}", &[("x", 1234.0)]), Ok("{x} = 1,234 k".to_owned()));
+    assert_eq!(f.format_template("{{{x}}}", &[("x", 1234.0)]), Ok("{1,234 k}".to_owned()));
+    assert_eq!(f.format_template("}}{{", &[]), Ok("}{".to_owned()));
+    assert_eq!(f.format_template("ä{x}ö", &[("x", 1.0)]), Ok("ä1,000ö".to_owned()));
+}
+
+
+#[test]
+fn errors()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.format_template("{free} left", &[("used", 1.0)]), Err(TemplateError::MissingValue("free".to_owned())));
+    assert_eq!(f.format_template("{free:common}", &[]), Err(TemplateError::MissingValue("free".to_owned())));
+    assert_eq!(f.format_template("{}", &[]), Err(TemplateError::MissingValue(String::new())));
+    assert_eq!(f.format_template("{x:bold}", &[("x", 1.0)]), Err(TemplateError::UnknownStyle { name: "x".to_owned(), style: "bold".to_owned() }));
+    assert_eq!(f.format_template("ä {x", &[("x", 1.0)]), Err(TemplateError::UnterminatedPlaceholder(3)));
+    assert_eq!(f.format_template("x} ", &[("x", 1.0)]), Err(TemplateError::UnmatchedBrace(1)));
+    assert_eq!(TemplateError::MissingValue("free".to_owned()).to_string(), "No value for placeholder \"free\".");
+    assert_eq!(TemplateError::UnmatchedBrace(1).to_string(), "Unmatched \"}\" at byte index 1, write \"}}\" for a literal one.");
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Align, Formatter, PartKind, Rounding, Scaling, ShowOriginal, Sign};
+
+
+/// # Summary
+/// Returns the next f64 towards infinity or negative infinity.
+///
+/// # Arguments
+/// - `x`: finite number other than 0
+/// - `up`: whether the next larger or smaller number
+///
+/// # Returns
+/// - the neighbour
+fn next(x: f64, up: bool) -> f64
+{
+    return f64::from_bits(if (0.0 < x) == up { x.to_bits() + 1 } else { x.to_bits() - 1 });
+}
+
+
+#[test]
+fn floor_boundaries()
+{
+    let f: Formatter = Formatter::new().set_display_floor(Some(0.01)).set_rounding(Rounding::Magnitude(-2)).set_scaling(Scaling::None);
+
+
+    assert_eq!(f.format(0.01), "0,01"); // the floor itself is displayed as it is
+    assert_eq!(f.format(next(0.01, false)), "< 0,01");
+    assert_eq!(f.format(next(0.01, true)), "0,01");
+    assert_eq!(f.format(0.004), "< 0,01"); // would round to 0
+    assert_eq!(f.format(f64::MIN_POSITIVE / 2.0), "< 0,01"); // subnormal
+    assert_eq!(f.format(0.0), "0,00");
+    assert_eq!(f.format(-0.0), "0,00"); // exact 0 unaffected
+    assert_eq!(f.format(-0.01), "-0,01");
+    assert_eq!(f.format(next(-0.01, true)), "> -0,01");
+    assert_eq!(f.format(-0.004), "> -0,01");
+}
+
+
+#[test]
+fn ceiling_boundaries()
+{
+    let f: Formatter = Formatter::new().set_display_ceiling(Some(999e12)).set_rounding(Rounding::SignificantDigits(3)).set_unit("B");
+
+
+    assert_eq!(f.format(999e12), "999 TB");
+    assert_eq!(f.format(next(999e12, true)), "> 999 TB");
+    assert_eq!(f.format(999.4e12), "> 999 TB"); // compared before rounding
+    assert_eq!(f.format(f64::MAX), "> 999 TB");
+    assert_eq!(f.format(-999e12), "-999 TB");
+    assert_eq!(f.format(-1e16), "< -999 TB");
+    assert_eq!(f.format(f64::INFINITY), "∞ B");
+    assert_eq!(f.format(f64::NAN), "NaN");
+    assert_eq!(f.clone().set_display_ceiling(None).format(1e16), "10,0 PB");
+    assert_eq!(f.clone().set_display_ceiling(Some(-999e12)).format(1e16), "> 999 TB"); // sign ignored
+}
+
+
+#[test]
+fn percent_compared_as_displayed()
+{
+    let f: Formatter = Formatter::new().set_display_floor(Some(0.01)).set_display_ceiling(Some(999.0)).set_rounding(Rounding::Magnitude(-4)).set_scaling(Scaling::Percent(true));
+
+
+    assert_eq!(f.format(0.000097), "< 0,01 %"); // 0,0097 %
+    assert_eq!(f.format(0.0002), "0,02 %");
+    assert_eq!(f.format(0), "0,00 %");
+    assert_eq!(f.format(-0.000097), "> -0,01 %");
+    assert_eq!(f.format(9.99), "999,00 %");
+    assert_eq!(f.format(10.0), "> 999,00 %");
+    assert_eq!(f.format(-10.0), "< -999,00 %");
+}
+
+
+#[test]
+fn sign_affixes_and_padding()
+{
+    let f: Formatter = Formatter::new().set_display_floor(Some(1e-3)).set_display_ceiling(Some(1e3)).set_affixes("$", "").set_sign(Sign::Always);
+
+
+    assert_eq!(f.format(1e-6), "< +$1,000 m");
+    assert_eq!(f.format(-1e-6), "> -$1,000 m");
+    assert_eq!(f.format(1e6), "> +$1,000 k");
+    assert_eq!(f.format(-1e6), "< -$1,000 k");
+    assert_eq!(f.clone().set_width(14, Align::Right, '_').format(1e6), "___> +$1,000 k"); // marker counts towards the width
+    assert_eq!(f.clone().set_show_original(ShowOriginal::Raw, true).format(1e6), "> +$1,000 k (1000000)"); // original value as it is
+    assert_eq!(f.clone().set_threshold_markers("", "").format(1e6), "+$1,000 k");
+    assert_eq!(f.clone().set_machine_readable(true).format(1e6), "+1000000"); // never clamped
+}
+
+
+#[test]
+fn floor_above_ceiling_wins()
+{
+    let f: Formatter = Formatter::new().set_display_floor(Some(10.0)).set_display_ceiling(Some(1.0)).set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(0));
+
+
+    assert_eq!(f.format(5), "< 10");
+    assert_eq!(f.format(50), "> 1");
+}
+
+
+#[test]
+fn parts_and_html()
+{
+    let f: Formatter = Formatter::new().set_display_floor(Some(0.01)).set_unit("s");
+
+
+    assert_eq!(f.format_to_parts(0.001).0.first().map(|part| (part.kind, part.value.as_str())), Some((PartKind::Threshold, "< ")));
+    assert_eq!(f.format_html(0.001), "&lt;&nbsp;10,00&nbsp;<span class=\"si-prefix\">m</span>s");
+}
@@ -0,0 +1,92 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{ticks, Formatter, Rounding, Scaling};
+
+
+/// # Summary
+/// Asserts that ticks are strictly ascending, finite, cover the range, and number no more than a few beyond the target.
+///
+/// # Arguments
+/// - `min`: the lower end of the data
+/// - `max`: the upper end of the data
+/// - `target_count`: the desired number of ticks
+fn assert_sane(min: f64, max: f64, target_count: usize)
+{
+    let result: Vec<(f64, String)> = ticks(min, max, target_count, &Formatter::new());
+
+
+    assert!(2 <= result.len() && result.len() <= 4 * target_count.clamp(2, 1 << 16) + 1, "{} ticks for [{min:e}; {max:e}]", result.len());
+    assert!(result.windows(2).all(|w| w[0].0 < w[1].0), "ticks for [{min:e}; {max:e}] not ascending: {result:?}");
+    assert!(result.iter().all(|(tick, _label)| tick.is_finite()));
+    assert!(result[0].0 <= min && max <= result[result.len() - 1].0, "ticks for [{min:e}; {max:e}] do not cover it: {result:?}");
+    assert!(min < result[1].0 && result[result.len() - 2].0 < max, "ticks for [{min:e}; {max:e}] beyond it: {result:?}");
+}
+
+
+#[test]
+fn labels_share_unit_and_precision()
+{
+    let f: Formatter = Formatter::new();
+    let labels = |min: f64, max: f64, target_count: usize, f: &Formatter| -> Vec<String> { ticks(min, max, target_count, f).into_iter().map(|(_tick, label)| label).collect() };
+
+
+    assert_eq!(labels(0.0, 1.0, 5, &f), ["0,0", "0,2", "0,4", "0,6", "0,8", "1,0"]); // not "200 m"
+    assert_eq!(labels(999.0, 1001.0, 5, &f), ["0,9990 k", "0,9995 k", "1,0000 k", "1,0005 k", "1,0010 k"]);
+    assert_eq!(labels(-0.002, 0.0105, 5, &f), ["-5 m", "0 m", "5 m", "10 m", "15 m"]);
+    assert_eq!(labels(0.0, 3000.0, 4, &f.clone().set_scaling(Scaling::Scientific)), ["0", "1 * 10^(3)", "2 * 10^(3)", "3 * 10^(3)"]);
+    assert_eq!(labels(0.0, 1.0, 5, &f.clone().set_fallback_rounding(Some(Rounding::SignificantDigits(2)))), ["0,0", "0,2", "0,4", "0,6", "0,8", "1,0"]);
+}
+
+
+#[test]
+fn subnormal_ranges()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(ticks(-5e-324, 5e-324, 5, &f), [(-1e-323, "-1 * 10^(-323)".to_string()), (0.0, "0".to_string()), (1e-323, "1 * 10^(-323)".to_string())]);
+    assert_eq!(ticks(0.0, 1e-320, 5, &f).iter().map(|(tick, _label)| *tick).collect::<Vec<f64>>(), [0.0, 2e-321, 4e-321, 6e-321, 8e-321, 1e-320]);
+    assert_sane(0.0, 1e-320, 5);
+    assert_sane(-5e-324, 5e-324, 5);
+    assert_sane(f64::from_bits(1), f64::from_bits(3), 10); // only 3 distinct numbers
+    assert_sane(f64::MIN_POSITIVE - 1e-320, f64::MIN_POSITIVE, 7);
+}
+
+
+#[test]
+fn tiny_ranges()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(ticks(0.0, 1e-300, 3, &f), [(0.0, "0,0".to_string()), (5e-301, "0,5 * 10^(-300)".to_string()), (1e-300, "1,0 * 10^(-300)".to_string())]); // exact multiples, none beyond the range, exponent of the farthest tick
+    assert_eq!(ticks(0.0, 3e-23, 3, &f), [(0.0, "0 y".to_string()), (2e-23, "20 y".to_string()), (4e-23, "40 y".to_string())]);
+    for exponent in -323..-22
+    {
+        let max: f64 = format!("1e{exponent}").parse::<f64>().unwrap();
+        assert_sane(0.0, max, 5);
+        assert_sane(-max, max * 3.0, 4);
+        for (tick, label) in ticks(0.0, max, 5, &f)
+        {
+            assert!(label.len() < 20, "label {label:?} of {tick:e}");
+        }
+    }
+}
+
+
+#[test]
+fn huge_ranges_and_target_counts()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(ticks(-f64::MAX, f64::MAX, 5, &f).iter().map(|(tick, _label)| *tick).collect::<Vec<f64>>(), [-1e308, 0.0, 1e308]); // multiples beyond f64 left out
+    assert_eq!(ticks(1e300, 1.7e308, 5, &f), [(0.0, "0,0".to_string()), (5e307, "0,5 * 10^(308)".to_string()), (1e308, "1,0 * 10^(308)".to_string()), (1.5e308, "1,5 * 10^(308)".to_string())]); // exponent of the farthest tick
+    assert_eq!(ticks(1e300, 1.7e308, 5, &f.clone().set_zero_exponent(true))[0].1, "0,0 * 10^(308)");
+    assert!(ticks(0.0, f64::MAX, 5, &f).iter().all(|(tick, _label)| tick.is_finite()));
+    assert_eq!(ticks(0.0, 1.0, usize::MAX, &f).len(), 50_001); // capped at 2^(16), steps of 2 * 10^(-5)
+    assert_sane(0.0, 1.0, usize::MAX);
+    assert_sane(0.0, 1.0, 0);
+    assert_sane(-1e300, 1e300, 1 << 20);
+    assert_sane(1e15, 1e15 + 0.5, 10);
+}
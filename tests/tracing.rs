@@ -0,0 +1,114 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![cfg(feature = "tracing")]
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, ScaledFields};
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::layer::SubscriberExt;
+
+
+/// # Summary
+/// Fields recorded so far as name and value, in order.
+type Recorded = Arc<Mutex<Vec<(String, String)>>>;
+
+
+/// # Summary
+/// Layer capturing every field recorded on spans and events.
+struct Capture(Recorded);
+
+
+/// # Summary
+/// Visitor writing the fields it visits into the capture.
+struct Visitor<'a>(&'a Recorded);
+
+
+impl tracing::field::Visit for Visitor<'_>
+{
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug)
+    {
+        self.0.lock().unwrap().push((field.name().to_owned(), format!("{value:?}")));
+    }
+
+
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str)
+    {
+        self.0.lock().unwrap().push((field.name().to_owned(), value.to_owned()));
+    }
+
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64)
+    {
+        self.0.lock().unwrap().push((field.name().to_owned(), value.to_string()));
+    }
+}
+
+
+impl<S> tracing_subscriber::Layer<S> for Capture
+where
+    S: tracing::Subscriber,
+{
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>)
+    {
+        event.record(&mut Visitor(&self.0));
+    }
+
+
+    fn on_record(&self, _span: &tracing::span::Id, values: &tracing::span::Record<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>)
+    {
+        values.record(&mut Visitor(&self.0));
+    }
+}
+
+
+/// # Summary
+/// Runs the closure with a subscriber capturing all fields.
+///
+/// # Arguments
+/// - `f`: emits spans and events
+///
+/// # Returns
+/// - the recorded fields other than messages
+fn capture(f: impl FnOnce()) -> Vec<(String, String)>
+{
+    let recorded: Recorded = Arc::new(Mutex::new(Vec::new()));
+
+
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(Capture(recorded.clone())), f);
+    return recorded.lock().unwrap().iter().filter(|(name, _value)| name != "message").cloned().collect();
+}
+
+
+#[test]
+fn event_fields()
+{
+    let f: Formatter = Formatter::new().set_unit("B");
+    let bytes: u64 = 381203512345;
+
+
+    assert_eq!(
+        capture(|| tracing::info!(bytes, bytes_human = f.traced_int(bytes), rate = f.traced(0.5), display = %f.display(1536), "Upload finished.")),
+        [
+            ("bytes".to_owned(), "381203512345".to_owned()),
+            ("bytes_human".to_owned(), "381,2 GB".to_owned()),
+            ("rate".to_owned(), "500,0 mB".to_owned()),
+            ("display".to_owned(), "1,536 kB".to_owned()),
+        ]
+    );
+    assert_eq!(capture(|| tracing::info!(min = f.traced_int(i64::MIN))), [("min".to_owned(), "-9,223 EB".to_owned())]); // exact, negative
+}
+
+
+#[test]
+fn span_fields_raw_and_human()
+{
+    let f: Formatter = Formatter::new().set_unit("B");
+
+
+    assert_eq!(
+        capture(|| {
+            let span: tracing::Span = tracing::info_span!("upload", bytes = tracing::field::Empty, bytes_human = tracing::field::Empty);
+            ScaledFields::new(&f).record(&span, "bytes", 381203512345_u64);
+            ScaledFields::new(&f).record(&span, "undeclared", 1_u64); // not declared, not recorded
+        }),
+        [("bytes".to_owned(), "381203512345".to_owned()), ("bytes_human".to_owned(), "381,2 GB".to_owned())]
+    );
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::{Formatter, PartKind, Rounding, Scaling};
+
+
+#[test]
+fn powers_of_ten_without_mantissa()
+{
+    let f: Formatter = Formatter::new().set_scaling(Scaling::Scientific).set_suppress_unit_mantissa(true);
+
+
+    assert_eq!(f.format(1000), "10^(3)");
+    assert_eq!(f.format(0.001), "10^(-3)");
+    assert_eq!(f.format(1), "10^(0)");
+    assert_eq!(f.format(-1000), "-10^(3)");
+    assert_eq!(f.format(10), "10^(1)"); // mantissa 1, not 10
+    assert_eq!(f.format(1001), "1,001 * 10^(3)");
+    assert_eq!(f.format(0), "0,000");
+    assert_eq!(f.clone().set_rounding(Rounding::SignificantDigits(3)).format(999.99), "10^(3)"); // 1,00 after rounding
+    assert_eq!(f.clone().set_rounding(Rounding::SignificantDigits(6)).format(999.99), "9,99990 * 10^(2)");
+    assert_eq!(f.clone().set_trailing_zeros(false).format(1000), "10^(3)");
+    assert_eq!(Formatter::new().set_scaling(Scaling::Scientific).format(1000), "1,000 * 10^(3)"); // disabled by default
+}
+
+
+#[test]
+fn fallbacks_and_exponential_scaling()
+{
+    let binary: Formatter = Formatter::new().set_scaling(Scaling::Binary(true)).set_suppress_unit_mantissa(true);
+    let decimal: Formatter = Formatter::new().set_suppress_unit_mantissa(true);
+    let hexadecimal: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 16, step: 1, prefixes: None }).set_suppress_unit_mantissa(true);
+
+
+    assert_eq!(binary.format(2_f64.powi(100)), "2^(100)");
+    assert_eq!(binary.format(2_f64.powi(20)), "1,000 Mi"); // unit prefix keeps the mantissa
+    assert_eq!(decimal.format(1e36), "10^(36)");
+    assert_eq!(decimal.format(1000), "1,000 k");
+    assert_eq!(hexadecimal.format(4096), "16^(3)");
+    assert_eq!(hexadecimal.format(16384), "4,000 * 16^(3)");
+}
+
+
+#[test]
+fn affixes_unit_and_parts()
+{
+    let f: Formatter = Formatter::new()
+        .set_affixes("[", "]")
+        .set_scaling(Scaling::Scientific)
+        .set_suppress_unit_mantissa(true)
+        .set_unit("Hz");
+
+
+    assert_eq!(f.format(1e6), "[10^(6) Hz]");
+    assert_eq!(f.format_html(1e6), "[10<sup>6</sup>&nbsp;Hz]");
+    assert!(!f.format_to_parts(1e6).iter().any(|part| matches!(part.kind, PartKind::IntegerDigits | PartKind::FractionDigits | PartKind::DecimalSeparator)));
+    assert_eq!(f.format_scaled(1e6).mantissa, 1.0);
+    assert_eq!(f.format_scaled(-1e6).mantissa, -1.0);
+    assert_eq!(f.clone().set_machine_readable(true).format(1e6), "1.000e6"); // e6 alone would not parse
+}
@@ -0,0 +1,62 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))] // run with `wasm-pack test --node -- --features wasm`
+use scaler::{Formatter, JsFormatter, Rounding, Scaling, Sign};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+
+/// # Summary
+/// Builds a JS options object.
+///
+/// # Arguments
+/// - `options`: keys and values
+///
+/// # Returns
+/// - the options object
+fn options(options: &[(&str, &str)]) -> js_sys::Object
+{
+    let object: js_sys::Object = js_sys::Object::new(); // result
+
+
+    for (key, value) in options
+    {
+        js_sys::Reflect::set(&object, &JsValue::from_str(key), &JsValue::from_str(value)).unwrap();
+    }
+    return object;
+}
+
+
+#[wasm_bindgen_test]
+fn formats_like_native()
+{
+    let f: JsFormatter = JsFormatter::new(Some(options(&[("scaling", "binary+space"), ("precision", "sig:3"), ("sign", "always")]))).unwrap(); // formatter configured from JS
+    let native: Formatter = Formatter::new().set_scaling(Scaling::Binary(true)).set_rounding(Rounding::SignificantDigits(3)).set_sign(Sign::Always); // same formatter configured natively
+
+
+    for x in [0.0, 1536.0, -42069.0, 1e-7, f64::INFINITY, f64::NAN]
+    {
+        assert_eq!(f.format(x), native.format(x));
+    }
+    assert_eq!(JsFormatter::new(None).unwrap().format(1234.5), Formatter::new().format(1234.5));
+}
+
+
+#[wasm_bindgen_test]
+fn parses_like_native()
+{
+    let f: JsFormatter = JsFormatter::new(Some(options(&[("groupSeparator", ","), ("decimalSeparator", ".")]))).unwrap(); // formatter configured from JS
+
+
+    assert_eq!(f.format(1234.5), "1.234 k");
+    assert_eq!(f.parse("1.234 k").unwrap(), 1234.0);
+    assert!(f.parse("1.234 x").is_err());
+}
+
+
+#[wasm_bindgen_test]
+fn rejects_invalid_options()
+{
+    assert!(JsFormatter::new(Some(options(&[("sign", "never")]))).is_err());
+    assert!(JsFormatter::new(Some(options(&[("decimalSeparator", ".")]))).is_err()); // same as default group separator
+}
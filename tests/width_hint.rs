@@ -0,0 +1,208 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+use scaler::*;
+
+
+/// # Summary
+/// Deterministic pseudorandom numbers, so failures are reproducible without a seed to report.
+struct Xorshift(u64);
+
+
+impl Xorshift
+{
+    /// # Summary
+    /// Advances the state.
+    ///
+    /// # Returns
+    /// - the next pseudorandom number
+    fn next(&mut self) -> u64
+    {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return self.0;
+    }
+
+
+    /// # Summary
+    /// Picks a pseudorandom number below a bound.
+    ///
+    /// # Arguments
+    /// - `n`: the exclusive upper bound, must not be 0
+    ///
+    /// # Returns
+    /// - the number
+    fn below(&mut self, n: u64) -> u64
+    {
+        return self.next() % n;
+    }
+
+
+    /// # Summary
+    /// Picks a pseudorandom number of any sign and decimal magnitude, sometimes 0, infinite, or just below a power of 10 so it rounds up to it.
+    ///
+    /// # Returns
+    /// - the number
+    fn number(&mut self) -> f64
+    {
+        let magnitude: i32 = self.below(640) as i32 - 325;
+        let sign: f64 = if self.below(2) == 0 { 1.0 } else { -1.0 };
+
+
+        return sign
+            * match self.below(8)
+            {
+                0 => 0.0,
+                1 => f64::INFINITY,
+                2 => format!("1e{magnitude}").parse::<f64>().unwrap() * (1.0 - 10_f64.powi(-(self.below(18) as i32))), // rounds up to a power of 10
+                3 => 2_f64.powi(magnitude.clamp(-1074, 1023)), // unit prefix or exponent boundary in binary
+                _ => format!("{}e{magnitude}", (self.next() >> 11) as f64 / (1_u64 << 53) as f64 * 10.0).parse::<f64>().unwrap(),
+            };
+    }
+}
+
+
+/// # Summary
+/// Pseudorandom configurations of the options that change the output length: scaling, rounding and its fallback, sign, separators, unit, original value, minimum digits, display floor and ceiling, and machine readable output.
+///
+/// # Arguments
+/// - `rng`: source of the choices
+///
+/// # Returns
+/// - the formatter
+fn formatter(rng: &mut Xorshift) -> Formatter
+{
+    let roundings: [Rounding; 6] = [Rounding::Magnitude(-3), Rounding::Magnitude(0), Rounding::Magnitude(2), Rounding::SignificantDigits(1), Rounding::SignificantDigits(4), Rounding::SignificantDigits(17)];
+    let scalings: [Scaling; 10] = [
+        Scaling::Binary(true),
+        Scaling::CompactLocalized,
+        Scaling::Decimal(true),
+        Scaling::Decimal(false),
+        Scaling::Exponential { base: 16, step: 1, prefixes: None },
+        Scaling::Exponential { base: 10, step: 3, prefixes: None },
+        Scaling::None,
+        Scaling::Percent(true),
+        Scaling::Scientific,
+        Scaling::Binary(false),
+    ];
+    let show_originals: [ShowOriginal; 3] = [ShowOriginal::Grouped, ShowOriginal::Off, ShowOriginal::Raw];
+    let signs: [Sign; 4] = [Sign::Always, Sign::ExceptZero, Sign::OnlyMinus, Sign::SpaceForPositive];
+    let mut f: Formatter;
+
+
+    f = Formatter::new()
+        .set_rounding(roundings[rng.below(roundings.len() as u64) as usize].clone())
+        .set_scaling(scalings[rng.below(scalings.len() as u64) as usize].clone())
+        .set_sign(signs[rng.below(signs.len() as u64) as usize].clone())
+        .set_trailing_zeros(rng.below(2) == 0);
+    if rng.below(4) == 0
+    {
+        f = f.set_fallback_rounding(Some(roundings[rng.below(roundings.len() as u64) as usize].clone()));
+    }
+    if rng.below(3) == 0
+    {
+        f = f.set_unit_plural("byte", "bytes").set_separators_static("\u{202f}", ",");
+    }
+    if rng.below(3) == 0
+    {
+        f = f.set_show_original(show_originals[rng.below(show_originals.len() as u64) as usize], rng.below(2) == 0);
+    }
+    if rng.below(4) == 0
+    {
+        f = f.set_min_integer_digits(rng.below(6) as usize).set_zero_pad(rng.below(10) as usize, rng.below(2) == 0);
+    }
+    if rng.below(4) == 0
+    {
+        f = f.set_display_floor(Some(0.01)).set_display_ceiling(Some(999e12));
+    }
+    if rng.below(8) == 0
+    {
+        f = f.set_machine_readable(true);
+    }
+    if rng.below(8) == 0
+    {
+        f = f.set_width(rng.below(30) as usize, Align::Right, '_');
+    }
+    return f;
+}
+
+
+#[test]
+fn bounds_every_number_in_range()
+{
+    let mut rng: Xorshift = Xorshift(0x9e37_79b9_7f4a_7c15);
+
+
+    for _ in 0..200
+    {
+        let f: Formatter = formatter(&mut rng);
+        let (min, max): (f64, f64) = (rng.number(), rng.number());
+        let hint: usize = f.width_hint(min, max);
+
+        for _ in 0..200
+        {
+            let x: f64 = rng.number();
+            if min.min(max) <= x && x <= min.max(max)
+            {
+                assert!(f.format(x).chars().count() <= hint, "{:?} is longer than {hint} for [{min:e}; {max:e}] with {f:?}", f.format(x));
+            }
+        }
+        for x in [min, max]
+        {
+            assert!(f.format(x).chars().count() <= hint, "{:?} is longer than {hint} for [{min:e}; {max:e}] with {f:?}", f.format(x));
+        }
+    }
+}
+
+
+#[test]
+fn max_width_bounds_everything()
+{
+    let mut rng: Xorshift = Xorshift(0x2545_f491_4f6c_dd1d);
+
+
+    for _ in 0..40
+    {
+        let f: Formatter = formatter(&mut rng);
+        let max_width: usize = f.max_width();
+
+        assert!(f.format(f64::NAN).chars().count() <= max_width);
+        for _ in 0..200
+        {
+            let x: f64 = rng.number();
+            assert!(f.format(x).chars().count() <= max_width, "{:?} is longer than {max_width} with {f:?}", f.format(x));
+        }
+    }
+}
+
+
+#[test]
+fn tight_for_common_cases()
+{
+    let f: Formatter = Formatter::new();
+
+
+    assert_eq!(f.width_hint(1.0, 100.0), "100,0".len());
+    assert_eq!(f.width_hint(1.0, 1e9), "1,000 G".len());
+    assert_eq!(f.width_hint(-1.0, 0.0), "-1,000 * 10^(-300)".len()); // tiny numbers fall back to scientific notation
+    assert_eq!(f.width_hint(100.0, 1.0), f.width_hint(1.0, 100.0)); // reversed
+    assert_eq!(f.width_hint(0.0, 0.0), "0,000".len());
+    assert_eq!(f.clone().set_unit_plural("byte", "bytes").width_hint(1.0, 1000.0), "1,000 kbytes".len());
+    assert_eq!(f.clone().set_scaling(Scaling::None).set_rounding(Rounding::Magnitude(0)).width_hint(0.0, 65535.0), "65.535".len());
+    assert_eq!(f.clone().set_scaling(Scaling::Percent(true)).set_rounding(Rounding::Magnitude(-3)).width_hint(0.0, 1.0), "100,0 %".len());
+    assert_eq!(f.width_hint(f64::NAN, 1.0), f.max_width());
+}
+
+
+#[test]
+fn mantissa_carry_and_zero_padding()
+{
+    let exponential: Formatter = Formatter::new().set_scaling(Scaling::Exponential { base: 16, step: 1, prefixes: None }).set_rounding(Rounding::SignificantDigits(1));
+    let zero_padded: Formatter = Formatter::new().set_scaling(Scaling::Binary(true)).set_rounding(Rounding::Magnitude(-3)).set_trailing_zeros(false).set_zero_pad(8, true);
+
+
+    assert_eq!(exponential.format(5.03561574928331e-256), "0,9 * 16^(-212)"); // 15 * 16^(-213) rounds up to 20 * 16^(-213)
+    assert!(exponential.format(5.03561574928331e-256).chars().count() <= exponential.width_hint(5e-256, 5.1e-256));
+    assert_eq!(zero_padded.format(-1e-54), "-000.000"); // no trailing zeros leave room for a group of padding zeros
+    assert!(zero_padded.format(-1e-54).chars().count() <= zero_padded.width_hint(-1e-50, -1e-60));
+}
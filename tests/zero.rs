@@ -0,0 +1,77 @@
+// Copyright (c) 2024 구FS, all rights reserved. Subject to the MIT licence in `licence.md`.
+#![allow(clippy::needless_late_init, clippy::needless_return)] // explicit returns and declarations at the top of the function are house style
+
+
+#[test]
+fn zeros_in_every_scaling()
+{
+    let scalings: [scaler::Scaling; 7] = [
+        scaler::Scaling::Binary(false),
+        scaler::Scaling::Binary(true),
+        scaler::Scaling::Decimal(false),
+        scaler::Scaling::Decimal(true),
+        scaler::Scaling::Exponential { base: 2, step: 1, prefixes: None },
+        scaler::Scaling::None,
+        scaler::Scaling::Scientific,
+    ];
+    let zeros: [f64; 4] = [0.0, -0.0, 1e-400, -1e-400]; // 1e-400 underflows to 0 when parsed
+
+
+    for scaling in scalings
+    {
+        let f: scaler::Formatter = scaler::Formatter::new().set_scaling(scaling.clone());
+
+        for x in zeros
+        {
+            assert_eq!(f.format(x), "0,000", "{scaling:?}, {x}");
+            assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(x), "+0,000", "{scaling:?}, {x}");
+            assert_eq!(f.clone().set_sign(scaler::Sign::ExceptZero).format(x), "0,000", "{scaling:?}, {x}");
+            assert_eq!(f.clone().set_trailing_zeros(false).format(x), "0", "{scaling:?}, {x}");
+            assert_eq!(f.compile().format(x), "0,000", "{scaling:?}, {x}");
+        }
+        assert_eq!(f.format_int(0), "0,000", "{scaling:?}"); // exact path
+    }
+}
+
+
+#[test]
+fn zero_exponent_kept()
+{
+    let f: scaler::Formatter = scaler::Formatter::new()
+        .set_scaling(scaler::Scaling::Scientific)
+        .set_zero_exponent(true);
+
+
+    for x in [0.0, -0.0, 1e-400]
+    {
+        assert_eq!(f.format(x), "0,000 * 10^(0)", "{x}");
+        assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(x), "+0,000 * 10^(0)", "{x}");
+        assert_eq!(f.compile().format(x), "0,000 * 10^(0)", "{x}");
+    }
+    assert_eq!(f.format_int(0), "0,000 * 10^(0)");
+    assert_eq!(scaler::Formatter::new().set_zero_exponent(true).format(0), "0,000"); // no exponent to keep with unit prefixes
+}
+
+
+#[test]
+fn noise_snapped_to_zero()
+{
+    let f: scaler::Formatter = scaler::Formatter::new().set_zero_epsilon(1e-9);
+
+
+    for x in [3.2e-17, -3.2e-17, -9.99e-10, f64::from_bits(1), -0.0]
+    {
+        assert_eq!(f.format(x), "0,000", "{x}");
+        assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(x), "+0,000", "{x}"); // never "-0"
+        assert_eq!(f.clone().set_sign(scaler::Sign::ExceptZero).format(x), "0,000", "{x}");
+        assert_eq!(f.compile().format(x), "0,000", "{x}");
+    }
+    assert_eq!(f.format(1e-9), "1,000 n"); // at epsilon, not snapped
+    assert_eq!(f.format(-1e-9), "-1,000 n");
+    assert_eq!(f.clone().set_sign(scaler::Sign::Always).format(1e-9), "+1,000 n");
+    assert_eq!(f.clone().set_zero_epsilon(0.0).format(-3.2e-17), "-32,00 a"); // off by default
+    assert_eq!(f.clone().set_scaling(scaler::Scaling::Percent(true)).format(5e-10), "0,0 %"); // compared before the multiplication by 100
+    assert_eq!(f.clone().set_show_original(scaler::ShowOriginal::Raw, true).format(-3.2e-17), "0,000 (-0.000000000000000032)"); // original value as it is
+    assert_eq!(f.clone().set_machine_readable(true).format(-3.2e-17), "-0.00000000000000003200"); // never snapped
+    assert_eq!(f.prefix_for(-3.2e-17), Some("")); // laid out like 0
+}